@@ -393,6 +393,15 @@ impl ProductCatalogPlugin {
         let mut cache = self.product_cache.write().await;
         cache.insert(product.id.clone(), (product, Time::now()));
     }
+
+    /// Reads may be cached downstream as long as we cache internally; mutations never
+    fn cache_directive(&self, method: &str) -> CacheDirective {
+        if method.eq_ignore_ascii_case("GET") || method.eq_ignore_ascii_case("HEAD") {
+            CacheDirective::MaxAge(self.config.cache_duration_secs)
+        } else {
+            CacheDirective::NoStore
+        }
+    }
 }
 
 impl Default for ProductCatalogPlugin {
@@ -465,38 +474,26 @@ impl Plugin for ProductCatalogPlugin {
     }
 
     fn ui_components(&self) -> Vec<UIComponent> {
+        let read_products = vec![Permission {
+            resource: "products".to_string(),
+            action: "read".to_string(),
+            scope: PermissionScope::Global,
+        }];
+
         vec![
-            UIComponent {
-                id: "product_list".to_string(),
-                name: "Product List".to_string(),
-                component_type: ComponentType::Page,
-                props: serde_json::json!({
+            UIComponent::new("product_list", "Product List", UIComponentType::Page)
+                .with_route("/plugins/product_catalog/products")
+                .with_icon("📦")
+                .with_props(serde_json::json!({
                     "title": "Products",
                     "searchable": true
-                }),
-                required_permissions: vec![
-                    Permission {
-                        resource: "products".to_string(),
-                        action: "read".to_string(),
-                        scope: PermissionScope::Global,
-                    }
-                ],
-            },
-            UIComponent {
-                id: "product_detail".to_string(),
-                name: "Product Detail".to_string(),
-                component_type: ComponentType::Page,
-                props: serde_json::json!({
+                }))
+                .with_permissions(read_products.clone()),
+            UIComponent::new("product_detail", "Product Detail", UIComponentType::Page)
+                .with_props(serde_json::json!({
                     "editable": false
-                }),
-                required_permissions: vec![
-                    Permission {
-                        resource: "products".to_string(),
-                        action: "read".to_string(),
-                        scope: PermissionScope::Global,
-                    }
-                ],
-            },
+                }))
+                .with_permissions(read_products),
         ]
     }
 
@@ -595,7 +592,7 @@ impl Plugin for ProductCatalogPlugin {
         vec![
             ApiRoute {
                 path: "/api/plugins/product_catalog/products".to_string(),
-                method: HttpMethod::GET,
+                method: HttpMethod::Get,
                 handler_id: "list_products".to_string(),
                 required_permissions: vec![
                     Permission {
@@ -608,6 +605,8 @@ impl Plugin for ProductCatalogPlugin {
                     requests_per_minute: 60,
                     burst_limit: 10,
                 }),
+                timeout_secs: None,
+                max_request_size: None,
                 documentation: ApiDocumentation {
                     summary: "List products".to_string(),
                     description: "Get a list of products with optional pagination".to_string(),
@@ -642,6 +641,7 @@ impl Plugin for ProductCatalogPlugin {
                                     }
                                 }
                             })),
+                            cache: None,
                         }
                     ],
                     examples: vec![],
@@ -706,6 +706,7 @@ impl Plugin for ProductCatalogPlugin {
                         description: "Success".to_string(),
                         schema: Some(serde_json::to_value(&products)
                             .map_err(|e| Error::plugin("product_catalog", format!("Serialization failed: {}", e)))?),
+                        cache: Some(self.cache_directive(&request.method)),
                     })
                 } else {
                     Err(Error::plugin("product_catalog", "Data source not initialized"))
@@ -942,4 +943,31 @@ mod tests {
         let schema = plugin.settings_schema();
         assert!(schema.is_some());
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_list_products_cache_headers() {
+        let mut plugin = ProductCatalogPlugin::new();
+        plugin.config.cache_duration_secs = 120;
+        plugin.data_source = Some(Arc::new(DatabaseDataSource::new("mock://database".to_string())));
+
+        let request = ApiRequest {
+            method: "GET".to_string(),
+            path: "/api/plugins/product_catalog/products".to_string(),
+            headers: HashMap::new(),
+            query_params: HashMap::new(),
+            path_params: HashMap::new(),
+            body: None,
+            user: None,
+            remote_addr: None,
+        };
+        let response = plugin.handle_api_request("list_products", request).await.unwrap();
+
+        assert_eq!(response.cache, Some(CacheDirective::MaxAge(120)));
+        let headers: HashMap<_, _> = response.http_headers().into_iter().collect();
+        assert_eq!(headers["Cache-Control"], "max-age=120");
+        assert!(headers.contains_key("ETag"));
+
+        assert_eq!(plugin.cache_directive("POST"), CacheDirective::NoStore);
+    }
 }
\ No newline at end of file
@@ -1,10 +1,9 @@
 use qorzen_oxide::{
-    plugin::{Plugin, PluginInfo, PluginContext, PluginDependency},
+    plugin::{Plugin, PluginInfo, PluginContext, PluginDependency, UIComponent, UIComponentType, MenuItem},
     manager::{Manager, ManagedState, ManagerStatus},
     error::{Result, Error},
     event::{Event, EventHandler},
     api::{ApiRoute, ApiHandler, HttpMethod},
-    ui::{UIComponent, MenuItem},
     types::{Permission, Metadata},
 };
 use async_trait::async_trait;
@@ -140,90 +139,60 @@ impl Plugin for ProductCatalogPlugin {
 
     fn ui_components(&self) -> Vec<UIComponent> {
         vec![
-            UIComponent {
-                id: "product_catalog_main".to_string(),
-                name: "Product Catalog".to_string(),
-                component_type: UIComponentType::Page,
-                route: "/products".to_string(),
-                icon: Some("shopping-bag".to_string()),
-                required_permissions: vec![
+            UIComponent::new("product_catalog_main", "Product Catalog", UIComponentType::Page)
+                .with_route("/products")
+                .with_icon("shopping-bag")
+                .with_permissions(vec![
                     Permission {
                         resource: "products".to_string(),
                         action: "read".to_string(),
                         scope: PermissionScope::Global,
                     }
-                ],
-                render_fn: Box::new(ProductCatalogUI::render_main_page),
-            },
-            UIComponent {
-                id: "product_catalog_sidebar".to_string(),
-                name: "Quick Add Product".to_string(),
-                component_type: UIComponentType::SidebarPanel,
-                route: "".to_string(),
-                icon: Some("plus".to_string()),
-                required_permissions: vec![
+                ])
+                .with_render_fn(|_props| ProductCatalogUI::render_main_page()),
+            UIComponent::new("product_catalog_sidebar", "Quick Add Product", UIComponentType::SidebarPanel)
+                .with_icon("plus")
+                .with_permissions(vec![
                     Permission {
                         resource: "products".to_string(),
                         action: "write".to_string(),
                         scope: PermissionScope::Global,
                     }
-                ],
-                render_fn: Box::new(ProductCatalogUI::render_quick_add_panel),
-            },
+                ])
+                .with_render_fn(|_props| ProductCatalogUI::render_quick_add_panel()),
         ]
     }
 
     fn menu_items(&self) -> Vec<MenuItem> {
         vec![
-            MenuItem {
-                id: "products_menu".to_string(),
-                label: "Products".to_string(),
-                icon: Some("shopping-bag".to_string()),
-                route: "/products".to_string(),
-                required_permissions: vec![
-                    Permission {
-                        resource: "products".to_string(),
-                        action: "read".to_string(),
-                        scope: PermissionScope::Global,
-                    }
-                ],
-                children: vec![
-                    MenuItem {
-                        id: "products_list".to_string(),
-                        label: "All Products".to_string(),
-                        icon: Some("list".to_string()),
-                        route: "/products/list".to_string(),
-                        required_permissions: vec![],
-                        children: vec![],
-                        order: 1,
-                    },
-                    MenuItem {
-                        id: "products_categories".to_string(),
-                        label: "Categories".to_string(),
-                        icon: Some("folder".to_string()),
-                        route: "/products/categories".to_string(),
-                        required_permissions: vec![],
-                        children: vec![],
-                        order: 2,
-                    },
-                    MenuItem {
-                        id: "products_inventory".to_string(),
-                        label: "Inventory".to_string(),
-                        icon: Some("warehouse".to_string()),
-                        route: "/products/inventory".to_string(),
-                        required_permissions: vec![
-                            Permission {
-                                resource: "inventory".to_string(),
-                                action: "read".to_string(),
-                                scope: PermissionScope::Global,
-                            }
-                        ],
-                        children: vec![],
-                        order: 3,
-                    },
-                ],
-                order: 10,
-            },
+            MenuItem::builder("products_menu", "Products")
+                .icon("shopping-bag")
+                .route("/products")
+                .permission("products", "read")
+                .order(10)
+                .child(
+                    MenuItem::builder("products_list", "All Products")
+                        .icon("list")
+                        .route("/products/list")
+                        .order(1)
+                        .build(),
+                )
+                .child(
+                    MenuItem::builder("products_categories", "Categories")
+                        .icon("folder")
+                        .route("/products/categories")
+                        .order(2)
+                        .build(),
+                )
+                .child(
+                    MenuItem::builder("products_inventory", "Inventory")
+                        .icon("warehouse")
+                        .route("/products/inventory")
+                        .permission("inventory", "read")
+                        .order(3)
+                        .build(),
+                )
+                .build(),
         ]
     }
 
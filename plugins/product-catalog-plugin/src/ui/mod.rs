@@ -1,10 +1,14 @@
 // src/ui/mod.rs
 use dioxus::prelude::*;
 use qorzen_oxide::{
-    ui::{UIComponent, UIComponentType},
+    plugin::{UIComponent, UIComponentType},
     types::Permission,
+    ui::components::Pagination,
 };
 
+/// Placeholder served from this plugin's `assets/` directory
+const PLACEHOLDER_IMAGE_URL: &str = "/plugins/com.example.product-catalog/assets/placeholder.svg";
+
 pub struct ProductCatalogUI;
 
 impl ProductCatalogUI {
@@ -13,6 +17,8 @@ impl ProductCatalogUI {
         let loading = use_state(cx, || true);
         let search_term = use_state(cx, String::new);
         let selected_category = use_state(cx, || None::<String>);
+        let current_page = use_state(cx, || 1u32);
+        let total_pages = use_state(cx, || 1u32);
 
         // Load products on component mount
         use_effect(cx, (), |_| {
@@ -96,9 +102,12 @@ impl ProductCatalogUI {
                     }
                 }
                 
-                // Pagination component
                 div { class: "pagination",
-                    // Pagination controls
+                    Pagination {
+                        current_page: **current_page,
+                        total_pages: **total_pages,
+                        on_page_change: move |page: u32| current_page.set(page),
+                    }
                 }
             }
         }
@@ -184,7 +193,7 @@ fn ProductCard(cx: Scope, product: Product, on_edit: EventHandler<String>, on_de
                 if let Some(image_url) = &product.image_url {
                     img { src: "{image_url}", alt: "{product.name}" }
                 } else {
-                    div { class: "no-image", "No Image" }
+                    img { class: "no-image", src: "{PLACEHOLDER_IMAGE_URL}", alt: "No Image" }
                 }
             }
             
@@ -8,28 +8,35 @@ use std::time::Duration;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use tokio::sync::{broadcast, Mutex, RwLock};
+use tokio::sync::{broadcast, mpsc, RwLock};
 use tokio::time::{interval, timeout};
 use uuid::Uuid;
 
 use crate::auth::{
-    AccountManager, MemorySessionStore, MemoryUserStore, SecurityPolicy, User, UserSession,
+    AccountManager, AuthResult, Credentials, MemoryUserStore, SecurityPolicy, User, UserSession,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use crate::concurrency::ConcurrencyManager;
 use crate::config::{ConfigurationTier, MemoryConfigStore, TieredConfigManager};
-use crate::error::{Error, ErrorKind, Result}; // Removed unused imports
-use crate::event::EventBusManager;
+use crate::error::{Error, ErrorKind, ManagerOperation, Result}; // Removed unused imports
+use crate::event::{Event, EventBusManager, EventFilter};
 #[cfg(not(target_arch = "wasm32"))]
 use crate::file::FileManager;
 #[cfg(not(target_arch = "wasm32"))]
-use crate::logging::LoggingManager;
-use crate::manager::{HealthStatus, ManagedState, Manager, ManagerState, ManagerStatus};
-use crate::platform::PlatformManager;
-use crate::plugin::PluginManager;
+use crate::logging::{LogHandles, LoggingManager, ReadyEvent};
+use crate::manager::{
+    HealthStatus, ManagedState, Manager, ManagerState, ManagerStatus, SharedManagers,
+};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::platform::native::SqliteConnector;
+use crate::platform::{DatabaseManager, PlatformManager, ReconnectPolicy};
+use crate::plugin::{
+    InstallRequest, PluginFactoryRegistry, PluginInstaller, PluginManager, PluginStats,
+};
 #[cfg(not(target_arch = "wasm32"))]
 use crate::task::TaskManager;
-use crate::ui::UILayoutManager;
+use crate::ui::{Theme, UILayoutManager};
+use crate::utils::Time;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ApplicationState {
@@ -41,15 +48,159 @@ pub enum ApplicationState {
     Error,
 }
 
+/// Detail recorded for a manager whose health check overran its window
+pub const HEALTH_CHECK_TIMED_OUT: &str = "health check timed out";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplicationHealth {
     pub status: HealthStatus,
     pub uptime: Duration,
     pub managers: HashMap<String, HealthStatus>,
+    /// Why each manager that is not healthy is in that state, when known
+    #[serde(default)]
+    pub reasons: HashMap<String, String>,
     pub last_check: DateTime<Utc>,
     pub details: HashMap<String, serde_json::Value>,
 }
 
+/// How a manager's start during `ApplicationCore::initialize` ended
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ManagerStartup {
+    Initialized,
+    /// `initialize` returned an error
+    Failed(String),
+    /// `initialize` did not finish within the manager's init timeout
+    TimedOut(Duration),
+    /// Not started because the named manager it depends on did not start
+    Skipped(String),
+}
+
+impl std::fmt::Display for ManagerStartup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Initialized => write!(f, "initialized"),
+            Self::Failed(error) => write!(f, "failed to initialize: {}", error),
+            Self::TimedOut(limit) => write!(f, "initialization timed out after {:?}", limit),
+            Self::Skipped(dependency) => write!(f, "skipped because {} did not start", dependency),
+        }
+    }
+}
+
+/// Core managers that cannot run without others, by manager name
+const CORE_DEPENDENCIES: &[(&str, &[&str])] = &[
+    ("logging_manager", &["tiered_config_manager"]),
+    ("database_manager", &["tiered_config_manager"]),
+    ("file_manager", &["tiered_config_manager"]),
+    ("task_manager", &["event_bus_manager"]),
+    (
+        "account_manager",
+        &["tiered_config_manager", "event_bus_manager"],
+    ),
+    (
+        "ui_layout_manager",
+        &["tiered_config_manager", "account_manager"],
+    ),
+    (
+        "plugin_manager",
+        &[
+            "tiered_config_manager",
+            "event_bus_manager",
+            "account_manager",
+        ],
+    ),
+];
+
+/// Names of registered managers, each after the registered managers it
+/// depends on; ties and cycles fall back to name order
+fn start_order(managers: &HashMap<String, Box<dyn Manager>>) -> Vec<String> {
+    let mut pending: Vec<&String> = managers.keys().collect();
+    pending.sort();
+
+    let mut order: Vec<String> = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let next = pending
+            .iter()
+            .position(|name| {
+                managers[*name].dependencies().iter().all(|dependency| {
+                    !managers.contains_key(dependency) || order.contains(dependency)
+                })
+            })
+            .unwrap_or(0);
+        order.push(pending.remove(next).clone());
+    }
+    order
+}
+
+/// Outcome of each manager's start, in start order
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StartupReport {
+    pub managers: Vec<(String, ManagerStartup)>,
+}
+
+impl StartupReport {
+    pub fn get(&self, name: &str) -> Option<&ManagerStartup> {
+        self.managers
+            .iter()
+            .find(|(manager, _)| manager == name)
+            .map(|(_, outcome)| outcome)
+    }
+
+    /// Managers that failed, timed out or were skipped
+    pub fn failures(&self) -> impl Iterator<Item = (&str, &ManagerStartup)> {
+        self.managers
+            .iter()
+            .filter(|(_, outcome)| *outcome != ManagerStartup::Initialized)
+            .map(|(name, outcome)| (name.as_str(), outcome))
+    }
+
+    /// One line listing which managers initialized, failed, timed out or
+    /// were skipped
+    pub fn summary(&self) -> String {
+        let names = |wanted: fn(&ManagerStartup) -> bool| {
+            self.managers
+                .iter()
+                .filter(|(_, outcome)| wanted(outcome))
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+        };
+        let initialized = names(|outcome| *outcome == ManagerStartup::Initialized);
+        let failed = names(|outcome| matches!(outcome, ManagerStartup::Failed(_)));
+        let timed_out = names(|outcome| matches!(outcome, ManagerStartup::TimedOut(_)));
+        let skipped = names(|outcome| matches!(outcome, ManagerStartup::Skipped(_)));
+
+        let mut parts = vec![format!("initialized: {}", list_or_none(&initialized))];
+        if !failed.is_empty() {
+            parts.push(format!("failed: {}", failed.join(", ")));
+        }
+        if !timed_out.is_empty() {
+            parts.push(format!("timed out: {}", timed_out.join(", ")));
+        }
+        if !skipped.is_empty() {
+            parts.push(format!("skipped: {}", skipped.join(", ")));
+        }
+        parts.join("; ")
+    }
+
+    fn record(&mut self, name: &str, outcome: ManagerStartup) {
+        match self
+            .managers
+            .iter_mut()
+            .find(|(manager, _)| manager == name)
+        {
+            Some((_, existing)) => *existing = outcome,
+            None => self.managers.push((name.to_string(), outcome)),
+        }
+    }
+}
+
+fn list_or_none(names: &[&str]) -> String {
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplicationStats {
     pub version: String,
@@ -62,6 +213,25 @@ pub struct ApplicationStats {
     pub memory_usage_bytes: u64,
     pub cpu_usage_percent: f64,
     pub system_info: SystemInfo,
+    /// How long each manager's `initialize` took during startup
+    #[serde(default)]
+    pub init_durations: HashMap<String, Duration>,
+    /// Loaded plugins and their runtime counters
+    #[serde(default)]
+    pub plugins: PluginStats,
+}
+
+impl ApplicationStats {
+    /// Managers by init time, slowest first
+    pub fn slowest_managers(&self) -> Vec<(&str, Duration)> {
+        let mut managers: Vec<(&str, Duration)> = self
+            .init_durations
+            .iter()
+            .map(|(name, duration)| (name.as_str(), *duration))
+            .collect();
+        managers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        managers
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +262,17 @@ impl SystemInfo {
     }
 }
 
+/// What woke `ApplicationCore::serve_until_shutdown`
+enum ServeWake {
+    /// Next bus event for plugin handlers, `None` once the bus is gone
+    Event(Option<Arc<dyn Event>>),
+    /// Plugin onboarded in the UI, `None` once the installer is replaced
+    Install(Option<InstallRequest>),
+    /// Changed plugin artifacts, `None` once the watcher stops
+    #[cfg(feature = "hot-reload")]
+    PluginsChanged(Option<Vec<std::path::PathBuf>>),
+}
+
 /// Enhanced Application Core with all systems integrated
 pub struct ApplicationCore {
     state: ManagedState,
@@ -102,11 +283,15 @@ pub struct ApplicationCore {
     platform_manager: Option<PlatformManager>,
 
     // Core configuration and settings
-    config_manager: Option<Arc<Mutex<TieredConfigManager>>>,
+    config_manager: Option<Arc<RwLock<TieredConfigManager>>>,
+    database_manager: Option<DatabaseManager>,
 
     // Enhanced core managers
     logging_manager: Option<LoggingManager>,
-    account_manager: Option<AccountManager>,
+    log_handles: Option<LogHandles>,
+    log_level: Option<String>,
+    log_format: Option<crate::config::LogFormat>,
+    account_manager: Option<Arc<RwLock<AccountManager>>>,
 
     // Existing managers (enhanced)
     event_bus_manager: Option<Arc<EventBusManager>>,
@@ -116,11 +301,24 @@ pub struct ApplicationCore {
 
     // New systems
     plugin_manager: Option<PluginManager>,
-    ui_layout_manager: Option<UILayoutManager>,
+    ui_layout_manager: Option<Arc<RwLock<UILayoutManager>>>,
+    /// IDs of compiled-in plugins registered when the plugin manager starts
+    builtin_plugins: Vec<String>,
+    #[cfg(feature = "hot-reload")]
+    plugin_watcher: Option<crate::plugin::PluginDirWatcher>,
+    /// Every event on the bus, delivered to plugin handlers while serving
+    plugin_events: Option<mpsc::UnboundedReceiver<Arc<dyn Event>>>,
+    /// Installs confirmed in the plugin onboarding wizard
+    plugin_installs: Option<mpsc::UnboundedReceiver<InstallRequest>>,
 
     // Application lifecycle
     shutdown_signal: broadcast::Sender<()>,
     health_check_interval: Duration,
+    health_check_timeout: Duration,
+    init_timeout: Duration,
+    init_timeouts: HashMap<String, Duration>,
+    startup: StartupReport,
+    init_durations: HashMap<String, Duration>,
 
     // Current user context
     current_user: Arc<RwLock<Option<User>>>,
@@ -129,6 +327,7 @@ pub struct ApplicationCore {
     // System monitoring
     system_info: SystemInfo,
     manager_registry: HashMap<String, Box<dyn Manager>>,
+    shared_managers: SharedManagers,
 }
 
 impl std::fmt::Debug for ApplicationCore {
@@ -146,12 +345,16 @@ impl ApplicationCore {
         let (shutdown_signal, _) = broadcast::channel(1);
 
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "application_core"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "application_core"),
             app_state: Arc::new(RwLock::new(ApplicationState::Created)),
             started_at: Utc::now(),
             platform_manager: None,
             config_manager: None,
+            database_manager: None,
             logging_manager: None,
+            log_handles: None,
+            log_level: None,
+            log_format: None,
             account_manager: None,
             event_bus_manager: None,
             file_manager: None,
@@ -159,12 +362,27 @@ impl ApplicationCore {
             task_manager: None,
             plugin_manager: None,
             ui_layout_manager: None,
+            builtin_plugins: Vec::new(),
+            #[cfg(feature = "hot-reload")]
+            plugin_watcher: None,
+            plugin_events: None,
+            plugin_installs: None,
             shutdown_signal,
             health_check_interval: Duration::from_secs(30),
+            health_check_timeout: Duration::from_millis(
+                crate::config::AppSettings::default().health_check_timeout_ms,
+            ),
+            init_timeout: Duration::from_millis(
+                crate::config::AppSettings::default().manager_init_timeout_ms,
+            ),
+            init_timeouts: HashMap::new(),
+            startup: StartupReport::default(),
+            init_durations: HashMap::new(),
             current_user: Arc::new(RwLock::new(None)),
             current_session: Arc::new(RwLock::new(None)),
             system_info: SystemInfo::collect(),
             manager_registry: HashMap::new(),
+            shared_managers: SharedManagers::new(),
         }
     }
 
@@ -176,52 +394,260 @@ impl ApplicationCore {
         Self::new()
     }
 
+    /// Hand the logging manager a subscriber installed before the config was
+    /// loaded, so it applies the logging config and later level changes to it
+    pub fn with_log_handles(mut self, handles: LogHandles) -> Self {
+        self.log_handles = Some(handles);
+        self
+    }
+
+    /// Use `directives` instead of the configured `logging.level`
+    pub fn with_log_level(mut self, directives: impl Into<String>) -> Self {
+        self.log_level = Some(directives.into());
+        self
+    }
+
+    /// Use `format` for console output instead of the configured one
+    pub fn with_log_format(mut self, format: crate::config::LogFormat) -> Self {
+        self.log_format = Some(format);
+        self
+    }
+
+    /// Plugins compiled into the host, registered when the plugin manager starts.
+    ///
+    /// Each ID names a factory in [`PluginFactoryRegistry::shared`].
+    pub fn with_builtin_plugins<I>(mut self, plugin_ids: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.builtin_plugins = plugin_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set how long each manager's health check may take.
+    ///
+    /// Overrides `app.health_check_timeout_ms` until the next `initialize`.
+    pub fn set_health_check_timeout(&mut self, timeout: Duration) {
+        self.health_check_timeout = timeout;
+    }
+
+    /// Set how long the named manager's `initialize` may take.
+    ///
+    /// Takes precedence over `app.manager_init_timeouts`.
+    pub fn set_manager_init_timeout(&mut self, name: impl Into<String>, timeout: Duration) {
+        self.init_timeouts.insert(name.into(), timeout);
+    }
+
+    /// How each manager's start went during the last `initialize`
+    pub fn startup_report(&self) -> &StartupReport {
+        &self.startup
+    }
+
+    /// Track an additional manager, initialized with the core ones and
+    /// included in `get_health` under its name
+    pub fn register_manager(&mut self, manager: Box<dyn Manager>) {
+        self.manager_registry
+            .insert(manager.name().to_string(), manager);
+    }
+
+    /// Look up a core manager by type, e.g. `manager::<AccountManager>()`.
+    ///
+    /// The configuration, account and UI layout managers are shared when
+    /// `initialize` sets them up; anything else, including a manager added
+    /// with `register_manager`, is not retrievable here. The handle is the
+    /// core's own instance behind a `tokio` lock, safe to hold across tasks
+    /// and threads. Hold a write lock briefly: it blocks every other user of
+    /// the manager. `shutdown` stops sharing the managers, though handles
+    /// taken earlier stay valid.
+    pub fn manager<T: Manager + 'static>(&self) -> Option<Arc<RwLock<T>>> {
+        self.shared_managers.get::<T>()
+    }
+
     /// Enhanced initialization with complete system setup
     pub async fn initialize(&mut self) -> Result<()> {
         *self.app_state.write().await = ApplicationState::Initializing;
         self.state.set_state(ManagerState::Initializing).await;
 
         tracing::info!("Starting Qorzen application initialization");
+        self.startup = StartupReport::default();
+        self.init_durations.clear();
 
         // 1. Initialize platform manager first (critical dependency)
-        self.init_platform_manager().await?;
+        let result = self.init_platform_manager().await;
+        self.note_startup("platform_manager", &result);
+        result?;
+
+        // Past the platform, a manager that fails or hangs is left out and
+        // the others start without it
 
         // 2. Initialize configuration system
-        self.init_config_manager().await?;
+        let result = self.init_config_manager().await;
+        self.note_startup("tiered_config_manager", &result);
 
         // 3. Initialize logging with configuration
-        self.init_logging_manager().await?;
+        let result = self.init_logging_manager().await;
+        self.note_startup("logging_manager", &result);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let result = self.init_database_manager().await;
+            self.note_startup("database_manager", &result);
+        }
 
         // 4. Initialize core application managers
-        self.init_concurrency_manager().await?;
-        self.init_event_bus_manager().await?;
-        self.init_file_manager().await?;
-        self.init_task_manager().await?;
+        let result = self.init_concurrency_manager().await;
+        self.note_startup("concurrency_manager", &result);
+        let result = self.init_event_bus_manager().await;
+        self.note_startup("event_bus_manager", &result);
+        let result = self.init_file_manager().await;
+        self.note_startup("file_manager", &result);
+        let result = self.init_task_manager().await;
+        self.note_startup("task_manager", &result);
 
         // 5. Initialize authentication and authorization
-        self.init_account_manager().await?;
+        let result = self.init_account_manager().await;
+        self.note_startup("account_manager", &result);
 
         // 6. Initialize UI and plugin systems
-        self.init_ui_layout_manager().await?;
-        self.init_plugin_manager().await?;
+        let result = self.init_ui_layout_manager().await;
+        self.note_startup("ui_layout_manager", &result);
+        let result = self.init_plugin_manager().await;
+        self.note_startup("plugin_manager", &result);
+
+        // 7. Initialize managers added with `register_manager`, dependencies first
+        let mut registered = std::mem::take(&mut self.manager_registry);
+        for name in start_order(&registered) {
+            let Some(manager) = registered.get_mut(&name) else {
+                continue;
+            };
+            let result = self.start_manager(manager.as_mut()).await;
+            self.note_startup(&name, &result);
+        }
+        self.manager_registry = registered;
 
-        // 7. Start background services
+        let summary = self.startup.summary();
+        if self.startup.failures().next().is_some() {
+            tracing::warn!("Startup summary: {}", summary);
+        } else {
+            tracing::info!("Startup summary: {}", summary);
+        }
+
+        // 8. Start background services
         self.start_background_services().await?;
 
-        // 8. Setup signal handling
+        // 9. Setup signal handling
         self.setup_signal_handlers().await?;
 
         *self.app_state.write().await = ApplicationState::Running;
         self.state.set_state(ManagerState::Running).await;
 
-        tracing::info!("Qorzen application initialization complete");
+        self.ready_event().await.emit();
         Ok(())
     }
 
+    /// Run `manager.initialize()` within its init timeout, recording how it
+    /// ended and how long it took.
+    ///
+    /// A manager is skipped when one of its dependencies, from
+    /// [`CORE_DEPENDENCIES`] or [`Manager::dependencies`], did not start.
+    async fn start_manager<M: Manager + ?Sized>(&mut self, manager: &mut M) -> Result<()> {
+        let name = manager.name().to_string();
+        if let Some(dependency) = self.failed_dependency(&name, manager.dependencies()) {
+            self.startup
+                .record(&name, ManagerStartup::Skipped(dependency.clone()));
+            return Err(Error::manager(
+                &name,
+                ManagerOperation::Initialize,
+                format!("{} depends on {}, which did not start", name, dependency),
+            ));
+        }
+
+        let limit = self
+            .init_timeouts
+            .get(&name)
+            .copied()
+            .unwrap_or(self.init_timeout);
+
+        let started = Time::monotonic_millis();
+        let initialized = timeout(limit, manager.initialize()).await;
+        self.init_durations
+            .insert(name.clone(), Time::elapsed_since(started));
+
+        let (outcome, result) = match initialized {
+            Ok(Ok(())) => (ManagerStartup::Initialized, Ok(())),
+            Ok(Err(e)) => (ManagerStartup::Failed(e.message.clone()), Err(e)),
+            Err(_) => (
+                ManagerStartup::TimedOut(limit),
+                Err(Error::timeout(format!(
+                    "{} did not initialize within {:?}",
+                    name, limit
+                ))),
+            ),
+        };
+        self.startup.record(&name, outcome);
+        result
+    }
+
+    /// The first of the manager's dependencies whose start did not succeed
+    fn failed_dependency(&self, name: &str, declared: Vec<String>) -> Option<String> {
+        let core = CORE_DEPENDENCIES
+            .iter()
+            .filter(|(manager, _)| *manager == name)
+            .flat_map(|(_, dependencies)| dependencies.iter().map(|d| d.to_string()));
+
+        core.chain(declared).find(|dependency| {
+            self.startup
+                .get(dependency)
+                .is_some_and(|outcome| *outcome != ManagerStartup::Initialized)
+        })
+    }
+
+    /// Log a manager that did not start, recording errors raised before its `initialize`
+    fn note_startup(&mut self, name: &str, result: &Result<()>) {
+        let Err(e) = result else {
+            return;
+        };
+        tracing::error!("{} did not start: {}", name, e);
+        if self.startup.get(name).is_none() {
+            self.startup
+                .record(name, ManagerStartup::Failed(e.message.clone()));
+        }
+    }
+
+    /// Readiness record for orchestration, built from the loaded configuration
+    async fn ready_event(&self) -> ReadyEvent {
+        let (app, network) = match &self.config_manager {
+            Some(config_manager) => {
+                let manager = config_manager.read().await;
+                let app: crate::config::AppSettings =
+                    manager.get("app").await.unwrap_or(None).unwrap_or_default();
+                let network: crate::config::NetworkConfig = manager
+                    .get("network")
+                    .await
+                    .unwrap_or(None)
+                    .unwrap_or_default();
+                (app, network)
+            }
+            None => Default::default(),
+        };
+
+        ReadyEvent {
+            version: crate::VERSION.to_string(),
+            pid: std::process::id(),
+            bind_address: format!("{}:{}", network.bind_address, network.port),
+            plugin_count: self
+                .plugin_manager
+                .as_ref()
+                .map_or(0, |plugins| plugins.stats().loaded.len()),
+            environment: app.environment,
+        }
+    }
+
     async fn init_platform_manager(&mut self) -> Result<()> {
         tracing::info!("Initializing platform manager");
         let mut platform_manager = PlatformManager::new()?;
-        platform_manager.initialize().await?;
+        self.start_manager(&mut platform_manager).await?;
         self.platform_manager = Some(platform_manager);
         Ok(())
     }
@@ -235,21 +661,38 @@ impl ApplicationCore {
             ConfigurationTier::System,
             Box::new(MemoryConfigStore::new(ConfigurationTier::System)),
         );
+        config_manager.add_store(
+            ConfigurationTier::User,
+            Box::new(MemoryConfigStore::new(ConfigurationTier::User)),
+        );
         config_manager.add_store(
             ConfigurationTier::Runtime,
             Box::new(MemoryConfigStore::new(ConfigurationTier::Runtime)),
         );
 
-        config_manager.initialize().await?;
-        self.config_manager = Some(Arc::new(Mutex::new(config_manager)));
+        self.start_manager(&mut config_manager).await?;
+        let app: crate::config::AppSettings = config_manager
+            .get("app")
+            .await
+            .unwrap_or(None)
+            .unwrap_or_default();
+        self.health_check_timeout = Duration::from_millis(app.health_check_timeout_ms);
+        self.init_timeout = Duration::from_millis(app.manager_init_timeout_ms);
+        for (name, timeout_ms) in app.manager_init_timeouts {
+            self.init_timeouts
+                .entry(name)
+                .or_insert(Duration::from_millis(timeout_ms));
+        }
+
+        self.config_manager = Some(self.shared_managers.insert(config_manager));
         Ok(())
     }
 
     async fn init_logging_manager(&mut self) -> Result<()> {
         tracing::info!("Initializing logging manager");
-        let config = if let Some(config_manager) = &self.config_manager {
+        let mut config = if let Some(config_manager) = &self.config_manager {
             // Get logging config from configuration system
-            let manager = config_manager.lock().await;
+            let manager = config_manager.read().await;
             manager
                 .get("logging")
                 .await
@@ -258,17 +701,52 @@ impl ApplicationCore {
         } else {
             crate::config::LoggingConfig::default()
         };
+        if let Some(level) = &self.log_level {
+            config.level = level.clone();
+        }
+        if let Some(format) = self.log_format {
+            config.console.format = Some(format);
+        }
 
         let mut logging_manager = LoggingManager::new(config);
-        logging_manager.initialize().await?;
+        if let Some(handles) = self.log_handles.clone() {
+            logging_manager = logging_manager.with_handles(handles);
+        }
+        self.start_manager(&mut logging_manager).await?;
+        if let Some(config_manager) = &self.config_manager {
+            logging_manager
+                .watch_config_changes(config_manager.read().await.subscribe_to_changes());
+        }
         self.logging_manager = Some(logging_manager);
         Ok(())
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn init_database_manager(&mut self) -> Result<()> {
+        tracing::info!("Initializing database manager");
+        let config = if let Some(config_manager) = &self.config_manager {
+            let manager = config_manager.read().await;
+            manager
+                .get("database")
+                .await
+                .unwrap_or(None)
+                .unwrap_or_default()
+        } else {
+            crate::config::DatabaseConfig::default()
+        };
+
+        let connector = SqliteConnector::from_url(&config.url)?;
+        let mut database_manager =
+            DatabaseManager::new(Arc::new(connector), ReconnectPolicy::from(&config));
+        self.start_manager(&mut database_manager).await?;
+        self.database_manager = Some(database_manager);
+        Ok(())
+    }
+
     async fn init_concurrency_manager(&mut self) -> Result<()> {
         tracing::info!("Initializing concurrency manager");
         let config = if let Some(config_manager) = &self.config_manager {
-            let manager = config_manager.lock().await;
+            let manager = config_manager.read().await;
             manager
                 .get("concurrency")
                 .await
@@ -279,7 +757,7 @@ impl ApplicationCore {
         };
 
         let mut concurrency_manager = ConcurrencyManager::new(config)?;
-        concurrency_manager.initialize().await?;
+        self.start_manager(&mut concurrency_manager).await?;
         self.concurrency_manager = Some(concurrency_manager);
         Ok(())
     }
@@ -287,7 +765,7 @@ impl ApplicationCore {
     async fn init_event_bus_manager(&mut self) -> Result<()> {
         tracing::info!("Initializing event bus manager");
         let config = if let Some(config_manager) = &self.config_manager {
-            let manager = config_manager.lock().await;
+            let manager = config_manager.read().await;
             manager
                 .get("event_bus")
                 .await
@@ -305,10 +783,12 @@ impl ApplicationCore {
             enable_metrics: config.enable_metrics,
             batch_size: 100,
             max_retry_delay: Duration::from_secs(60),
+            history_capacity: config.history_capacity,
+            persistence_path: config.persistence_path,
         };
 
         let mut event_bus_manager = EventBusManager::new(event_config);
-        event_bus_manager.initialize().await?;
+        self.start_manager(&mut event_bus_manager).await?;
         self.event_bus_manager = Some(Arc::new(event_bus_manager));
         Ok(())
     }
@@ -316,7 +796,7 @@ impl ApplicationCore {
     async fn init_file_manager(&mut self) -> Result<()> {
         tracing::info!("Initializing file manager");
         let config = if let Some(config_manager) = &self.config_manager {
-            let manager = config_manager.lock().await;
+            let manager = config_manager.read().await;
             manager
                 .get("files")
                 .await
@@ -333,7 +813,7 @@ impl ApplicationCore {
             file_manager.set_event_bus(Arc::clone(event_bus));
         }
 
-        file_manager.initialize().await?;
+        self.start_manager(&mut file_manager).await?;
         self.file_manager = Some(file_manager);
         Ok(())
     }
@@ -341,7 +821,7 @@ impl ApplicationCore {
     async fn init_task_manager(&mut self) -> Result<()> {
         tracing::info!("Initializing task manager");
         let config = if let Some(config_manager) = &self.config_manager {
-            let manager = config_manager.lock().await;
+            let manager = config_manager.read().await;
             manager
                 .get("tasks")
                 .await
@@ -358,38 +838,59 @@ impl ApplicationCore {
             task_manager.set_event_bus(Arc::clone(event_bus));
         }
 
-        task_manager.initialize().await?;
+        self.start_manager(&mut task_manager).await?;
         self.task_manager = Some(task_manager);
         Ok(())
     }
 
     async fn init_account_manager(&mut self) -> Result<()> {
         tracing::info!("Initializing account manager");
-        let security_policy = if let Some(config_manager) = &self.config_manager {
-            let manager = config_manager.lock().await;
-            manager
+        let (security_policy, session_store) = if let Some(config_manager) = &self.config_manager {
+            let manager = config_manager.read().await;
+            let policy = manager
+                .get("security")
+                .await
+                .unwrap_or(None)
+                .unwrap_or_else(SecurityPolicy::default);
+            let security: crate::config::SecurityConfig = manager
                 .get("security")
                 .await
                 .unwrap_or(None)
-                .unwrap_or_else(SecurityPolicy::default)
+                .unwrap_or_default();
+            (policy, security.session_store)
         } else {
-            SecurityPolicy::default()
+            (SecurityPolicy::default(), Default::default())
         };
 
-        let session_store = Box::new(MemorySessionStore::new());
+        let session_store = session_store.build();
         let user_store = Box::new(MemoryUserStore::new());
 
+        // The UI checks navigation, palette and slot permissions through the shared registries
+        crate::ui::UIComponentRegistry::shared()
+            .set_permission_hierarchy(security_policy.permission_hierarchy.clone());
+        crate::ui::CommandRegistry::shared()
+            .set_permission_hierarchy(security_policy.permission_hierarchy.clone());
+
         let mut account_manager = AccountManager::new(session_store, user_store, security_policy);
-        account_manager.initialize().await?;
-        self.account_manager = Some(account_manager);
+
+        // Set event bus for user and role audit events
+        if let Some(event_bus) = &self.event_bus_manager {
+            account_manager.set_event_bus(Arc::clone(event_bus));
+        }
+
+        self.start_manager(&mut account_manager).await?;
+        // Starting the manager restores the previous session, if any
+        *self.current_user.write().await = account_manager.current_user().await;
+        *self.current_session.write().await = account_manager.current_session().await;
+        self.account_manager = Some(self.shared_managers.insert(account_manager));
         Ok(())
     }
 
     async fn init_ui_layout_manager(&mut self) -> Result<()> {
         tracing::info!("Initializing UI layout manager");
         let mut ui_layout_manager = UILayoutManager::new();
-        ui_layout_manager.initialize().await?;
-        self.ui_layout_manager = Some(ui_layout_manager);
+        self.start_manager(&mut ui_layout_manager).await?;
+        self.ui_layout_manager = Some(self.shared_managers.insert(ui_layout_manager));
         Ok(())
     }
 
@@ -397,13 +898,126 @@ impl ApplicationCore {
         tracing::info!("Initializing plugin manager");
 
         // Create a simple plugin loader for this example
-        let loader = Box::new(SimplePluginLoader::new());
+        let (config, network, security) = if let Some(config_manager) = &self.config_manager {
+            let manager = config_manager.read().await;
+            let config: crate::config::PluginConfig = manager
+                .get("plugins")
+                .await
+                .unwrap_or(None)
+                .unwrap_or_default();
+            let network: crate::config::NetworkConfig = manager
+                .get("network")
+                .await
+                .unwrap_or(None)
+                .unwrap_or_default();
+            let security: crate::config::SecurityConfig = manager
+                .get("security")
+                .await
+                .unwrap_or(None)
+                .unwrap_or_default();
+            (config, network, security)
+        } else {
+            (
+                crate::config::PluginConfig::default(),
+                crate::config::NetworkConfig::default(),
+                crate::config::SecurityConfig::default(),
+            )
+        };
+
+        let loader = Box::new(crate::plugin::NativeLibraryLoader::new());
         let mut plugin_manager = PluginManager::new(loader);
-        plugin_manager.initialize().await?;
+        plugin_manager.set_shutdown_timeout(Duration::from_secs(config.load_timeout_secs));
+        plugin_manager.set_request_timeout(Duration::from_secs(network.request_timeout_secs));
+        plugin_manager.set_max_request_size(network.max_request_size);
+        plugin_manager.set_cors_policy(crate::plugin::CorsPolicy::from_config(&security)?);
+        plugin_manager.set_trusted_proxies(network.trusted_proxies.clone());
+        plugin_manager.set_policy(crate::plugin::PluginPolicy::from_config(&config));
+        plugin_manager.set_payload_guard(crate::plugin::EventPayloadGuard::from_config(&config));
+        if let Some(config_manager) = &self.config_manager {
+            plugin_manager.set_config_manager(Arc::clone(config_manager));
+        }
+        if let Some(account_manager) = &self.account_manager {
+            plugin_manager.set_permission_hierarchy(
+                account_manager.read().await.permission_hierarchy().clone(),
+            );
+            plugin_manager.set_account_manager(Arc::clone(account_manager));
+        }
+        if let Some(event_bus) = &self.event_bus_manager {
+            plugin_manager.set_event_bus(Arc::clone(event_bus));
+            self.plugin_events = Some(event_bus.subscribe(EventFilter::new()).await?);
+        }
+        if let Some(platform_manager) = &self.platform_manager {
+            plugin_manager.set_state_store(platform_manager.storage_arc());
+        }
+        // Plugins query through the database manager so they share its
+        // reconnects; the platform provider only stands in without one
+        match (&self.database_manager, &self.platform_manager) {
+            (Some(database_manager), _) => plugin_manager.set_database(database_manager.provider()),
+            (None, Some(platform_manager)) => {
+                plugin_manager.set_database(platform_manager.database_arc())
+            }
+            (None, None) => {}
+        }
+        plugin_manager
+            .register_builtin_plugins(
+                &PluginFactoryRegistry::shared(),
+                &std::mem::take(&mut self.builtin_plugins),
+            )
+            .await?;
+        self.start_manager(&mut plugin_manager).await?;
+        if config.hot_reload {
+            self.watch_plugin_dir(&mut plugin_manager, &config.plugin_dir);
+        }
+        if let Some(ui_layout_manager) = &self.ui_layout_manager {
+            ui_layout_manager
+                .read()
+                .await
+                .apply_plugin_menus(plugin_manager.menu_items())
+                .await;
+        }
+        self.plugin_installs = Some(PluginInstaller::shared().listen());
         self.plugin_manager = Some(plugin_manager);
         Ok(())
     }
 
+    #[cfg(feature = "hot-reload")]
+    fn watch_plugin_dir(&mut self, plugin_manager: &mut PluginManager, plugin_dir: &Path) {
+        match crate::plugin::PluginDirWatcher::new(plugin_dir) {
+            Ok(watcher) => {
+                tracing::info!("Hot-reloading plugins from {}", plugin_dir.display());
+                plugin_manager.set_hot_reload(true);
+                self.plugin_watcher = Some(watcher);
+            }
+            Err(e) => tracing::warn!("Plugin hot reload disabled: {}", e),
+        }
+    }
+
+    #[cfg(not(feature = "hot-reload"))]
+    fn watch_plugin_dir(&mut self, _plugin_manager: &mut PluginManager, _plugin_dir: &Path) {
+        tracing::warn!("plugins.hot_reload is set, but this build lacks the hot-reload feature");
+    }
+
+    /// Reload plugins from changed artifacts and refresh their menus
+    #[cfg(feature = "hot-reload")]
+    async fn reload_plugins(&mut self, artifacts: Vec<std::path::PathBuf>) {
+        let Some(plugin_manager) = self.plugin_manager.as_mut() else {
+            return;
+        };
+        for artifact in artifacts {
+            if let Err(e) = plugin_manager.reload_plugin(&artifact).await {
+                tracing::error!("Failed to reload plugin {}: {}", artifact.display(), e);
+            }
+        }
+
+        if let Some(ui_layout_manager) = &self.ui_layout_manager {
+            ui_layout_manager
+                .read()
+                .await
+                .apply_plugin_menus(plugin_manager.menu_items())
+                .await;
+        }
+    }
+
     async fn start_background_services(&self) -> Result<()> {
         tracing::info!("Starting background services");
 
@@ -417,7 +1031,7 @@ impl ApplicationCore {
                 loop {
                     interval.tick().await;
                     let result = {
-                        let manager = config_manager.lock().await;
+                        let manager = config_manager.read().await;
                         manager.sync().await
                     };
 
@@ -508,16 +1122,25 @@ impl ApplicationCore {
         tracing::info!("Shutting down Qorzen application");
 
         // Shutdown in reverse dependency order
+        // Each plugin's shutdown is bounded by the plugin manager's own timeout
+        self.plugin_events = None;
+        self.plugin_installs = None;
         if let Some(mut plugin_manager) = self.plugin_manager.take() {
-            let _ = timeout(Duration::from_secs(10), plugin_manager.shutdown()).await;
+            let _ = plugin_manager.shutdown().await;
         }
 
-        if let Some(mut ui_layout_manager) = self.ui_layout_manager.take() {
-            let _ = timeout(Duration::from_secs(5), ui_layout_manager.shutdown()).await;
+        if let Some(ui_layout_manager) = self.ui_layout_manager.take() {
+            let _ = timeout(Duration::from_secs(5), async {
+                ui_layout_manager.write().await.shutdown().await
+            })
+            .await;
         }
 
-        if let Some(mut account_manager) = self.account_manager.take() {
-            let _ = timeout(Duration::from_secs(5), account_manager.shutdown()).await;
+        if let Some(account_manager) = self.account_manager.take() {
+            let _ = timeout(Duration::from_secs(5), async {
+                account_manager.write().await.shutdown().await
+            })
+            .await;
         }
 
         if let Some(mut task_manager) = self.task_manager.take() {
@@ -528,12 +1151,6 @@ impl ApplicationCore {
             let _ = timeout(Duration::from_secs(5), file_manager.shutdown()).await;
         }
 
-        if let Some(event_bus_manager) = self.event_bus_manager.take() {
-            if let Ok(mut manager) = Arc::try_unwrap(event_bus_manager) {
-                let _ = timeout(Duration::from_secs(5), manager.shutdown()).await;
-            }
-        }
-
         if let Some(mut concurrency_manager) = self.concurrency_manager.take() {
             let _ = timeout(Duration::from_secs(10), concurrency_manager.shutdown()).await;
         }
@@ -542,14 +1159,32 @@ impl ApplicationCore {
             let _ = timeout(Duration::from_secs(5), logging_manager.shutdown()).await;
         }
 
+        if let Some(mut database_manager) = self.database_manager.take() {
+            let _ = timeout(Duration::from_secs(5), database_manager.shutdown()).await;
+        }
+
         if let Some(config_manager) = self.config_manager.take() {
-            let mut manager = config_manager.lock().await;
+            let mut manager = config_manager.write().await;
             let _ = timeout(Duration::from_secs(2), manager.shutdown()).await;
         }
 
         if let Some(mut platform_manager) = self.platform_manager.take() {
             let _ = timeout(Duration::from_secs(5), platform_manager.shutdown()).await;
         }
+        self.shared_managers = SharedManagers::new();
+
+        // Plugins and managers share the bus, so it goes last, once they are gone
+        if let Some(event_bus_manager) = self.event_bus_manager.take() {
+            match Arc::try_unwrap(event_bus_manager) {
+                Ok(mut manager) => {
+                    let _ = timeout(Duration::from_secs(5), manager.shutdown()).await;
+                }
+                Err(event_bus_manager) => tracing::warn!(
+                    "Event bus still has {} other owners; skipping its shutdown",
+                    Arc::strong_count(&event_bus_manager) - 1
+                ),
+            }
+        }
 
         *self.app_state.write().await = ApplicationState::Shutdown;
         self.state.set_state(ManagerState::Shutdown).await;
@@ -558,6 +1193,64 @@ impl ApplicationCore {
         Ok(())
     }
 
+    /// Waits for the shutdown signal, delivering bus events to plugin
+    /// handlers meanwhile. With the `hot-reload` feature and
+    /// `plugins.hot_reload` set, changed plugins are reloaded as well.
+    pub async fn serve_until_shutdown(&mut self) -> Result<()> {
+        let mut shutdown = self.shutdown_signal.subscribe();
+        loop {
+            let wake = {
+                let events = async {
+                    match self.plugin_events.as_mut() {
+                        Some(events) => ServeWake::Event(events.recv().await),
+                        None => std::future::pending().await,
+                    }
+                };
+                let installs = async {
+                    match self.plugin_installs.as_mut() {
+                        Some(installs) => ServeWake::Install(installs.recv().await),
+                        None => std::future::pending().await,
+                    }
+                };
+                #[cfg(feature = "hot-reload")]
+                let changes = async {
+                    match self.plugin_watcher.as_mut() {
+                        Some(watcher) => ServeWake::PluginsChanged(watcher.next_batch().await),
+                        None => std::future::pending().await,
+                    }
+                };
+                #[cfg(not(feature = "hot-reload"))]
+                let changes = std::future::pending::<ServeWake>();
+
+                tokio::select! {
+                    _ = shutdown.recv() => return Ok(()),
+                    wake = events => wake,
+                    wake = installs => wake,
+                    wake = changes => wake,
+                }
+            };
+
+            match wake {
+                ServeWake::Event(Some(event)) => {
+                    if let Some(plugin_manager) = &self.plugin_manager {
+                        plugin_manager.dispatch_event(event.as_ref()).await;
+                    }
+                }
+                ServeWake::Event(None) => self.plugin_events = None,
+                ServeWake::Install(Some(request)) => match self.plugin_manager.as_mut() {
+                    Some(plugin_manager) => plugin_manager.handle_install(request).await,
+                    // Dropping the request tells the wizard nothing installed it
+                    None => drop(request),
+                },
+                ServeWake::Install(None) => self.plugin_installs = None,
+                #[cfg(feature = "hot-reload")]
+                ServeWake::PluginsChanged(Some(artifacts)) => self.reload_plugins(artifacts).await,
+                #[cfg(feature = "hot-reload")]
+                ServeWake::PluginsChanged(None) => self.plugin_watcher = None,
+            }
+        }
+    }
+
     /// Waits for shutdown signal
     pub async fn wait_for_shutdown(&self) -> Result<()> {
         let mut receiver = self.shutdown_signal.subscribe();
@@ -570,35 +1263,126 @@ impl ApplicationCore {
         Ok(())
     }
 
-    /// Gets current application health
+    /// Gets current application health.
+    ///
+    /// Each manager gets the health-check timeout; one that does not answer
+    /// in time is reported `Unknown` with a [`HEALTH_CHECK_TIMED_OUT`] detail
+    /// instead of holding up the whole check.
     pub async fn get_health(&self) -> ApplicationHealth {
-        let mut manager_health = HashMap::new();
-        let mut overall_healthy = true;
+        let limit = self.health_check_timeout;
+        let mut checks: Vec<(String, Option<(HealthStatus, Option<String>)>)> = Vec::new();
 
         // Check each manager's health
         if let Some(platform_manager) = &self.platform_manager {
-            let health = platform_manager.health_check().await;
-            if health != HealthStatus::Healthy {
-                overall_healthy = false;
-            }
-            manager_health.insert("platform_manager".to_string(), health);
+            let health = timeout(limit, check_manager(platform_manager)).await;
+            checks.push(("platform_manager".to_string(), health.ok()));
         }
 
         if let Some(config_manager) = &self.config_manager {
-            let manager = config_manager.lock().await;
-            let health = manager.health_check().await;
-            if health != HealthStatus::Healthy {
-                overall_healthy = false;
+            let health = timeout(limit, async {
+                check_manager(&*config_manager.read().await).await
+            })
+            .await;
+            checks.push(("config_manager".to_string(), health.ok()));
+        }
+
+        if let Some(database_manager) = &self.database_manager {
+            let health = timeout(limit, check_manager(database_manager)).await;
+            checks.push(("database_manager".to_string(), health.ok()));
+        }
+
+        // A registered manager that did not start is reported by the
+        // failures below, not by its own status, which a timed-out
+        // `initialize` leaves at `Initializing`
+        let registered = futures::future::join_all(
+            self.manager_registry
+                .iter()
+                .filter(|(name, _)| {
+                    self.startup
+                        .get(name)
+                        .map_or(true, |outcome| *outcome == ManagerStartup::Initialized)
+                })
+                .map(|(name, manager)| async move {
+                    let health = timeout(limit, check_manager(manager.as_ref())).await;
+                    (name.clone(), health.ok())
+                }),
+        )
+        .await;
+        checks.extend(registered);
+
+        let mut manager_health = HashMap::new();
+        let mut reasons = HashMap::new();
+        let mut manager_errors = serde_json::Map::new();
+        for (name, check) in checks {
+            let (health, reason) = check.unwrap_or_else(|| {
+                tracing::warn!("Health check of {} timed out after {:?}", name, limit);
+                manager_errors.insert(name.clone(), HEALTH_CHECK_TIMED_OUT.into());
+                (
+                    HealthStatus::Unknown,
+                    Some(HEALTH_CHECK_TIMED_OUT.to_string()),
+                )
+            });
+            if let Some(reason) = reason {
+                reasons.insert(name.clone(), reason);
             }
-            manager_health.insert("config_manager".to_string(), health);
+            manager_health.insert(name, health);
         }
 
-        // Add other managers...
+        // Managers that never started have no instance left to check
+        for (name, outcome) in self.startup.failures() {
+            if !manager_health.contains_key(name) {
+                manager_health.insert(name.to_string(), HealthStatus::Unhealthy);
+                reasons.insert(name.to_string(), outcome.to_string());
+            }
+        }
 
-        let overall_status = if overall_healthy {
-            HealthStatus::Healthy
-        } else {
-            HealthStatus::Degraded
+        let mut details = HashMap::new();
+        if let Some(database_manager) = &self.database_manager {
+            details.insert(
+                "database".to_string(),
+                serde_json::to_value(database_manager.connection_state()).unwrap_or_default(),
+            );
+        }
+        if let Some(plugin_manager) = &self.plugin_manager {
+            let plugin_health = plugin_manager.health_check_all_with_timeout(limit).await;
+            let mut unhealthy: Vec<&str> = plugin_health
+                .iter()
+                .filter(|(_, health)| !health.is_healthy)
+                .map(|(plugin_id, _)| plugin_id.as_str())
+                .collect();
+            let all_healthy = unhealthy.is_empty();
+            if !all_healthy {
+                unhealthy.sort_unstable();
+                reasons.insert(
+                    "plugin_manager".to_string(),
+                    format!("Unhealthy plugins: {}", unhealthy.join(", ")),
+                );
+            }
+            manager_health.insert(
+                "plugin_manager".to_string(),
+                if all_healthy {
+                    HealthStatus::Healthy
+                } else {
+                    HealthStatus::Degraded
+                },
+            );
+            details.insert(
+                "plugins".to_string(),
+                serde_json::to_value(&plugin_health).unwrap_or_default(),
+            );
+        }
+
+        if !manager_errors.is_empty() {
+            details.insert(
+                "manager_errors".to_string(),
+                serde_json::Value::Object(manager_errors),
+            );
+        }
+
+        // The app cannot vouch for a manager it could not check
+        let overall_status = match HealthStatus::worst(manager_health.values().copied()) {
+            HealthStatus::Unknown => HealthStatus::Degraded,
+            worst => worst,
         };
 
         ApplicationHealth {
@@ -608,8 +1392,9 @@ impl ApplicationCore {
                 .to_std()
                 .unwrap_or_default(),
             managers: manager_health,
+            reasons,
             last_check: Utc::now(),
-            details: HashMap::new(),
+            details,
         }
     }
 
@@ -629,9 +1414,68 @@ impl ApplicationCore {
             memory_usage_bytes: 0,                             // Would use platform-specific APIs
             cpu_usage_percent: 0.0,                            // Would use platform-specific APIs
             system_info: self.system_info.clone(),
+            init_durations: self.init_durations.clone(),
+            plugins: self.get_plugin_stats(),
         }
     }
 
+    /// Loaded plugins with per-plugin status and activity counters
+    pub fn get_plugin_stats(&self) -> PluginStats {
+        self.plugin_manager
+            .as_ref()
+            .map(PluginManager::stats)
+            .unwrap_or_default()
+    }
+
+    /// Signs a user in and switches to the theme saved in their preferences
+    pub async fn login(
+        &self,
+        credentials: Credentials,
+        provider: Option<&str>,
+    ) -> Result<AuthResult> {
+        let account_manager = self
+            .account_manager
+            .as_ref()
+            .ok_or_else(|| Error::authentication("Account manager is not initialized"))?;
+        let result = account_manager
+            .read()
+            .await
+            .authenticate(credentials, provider)
+            .await?;
+
+        if let Some(ui_layout_manager) = &self.ui_layout_manager {
+            ui_layout_manager
+                .read()
+                .await
+                .apply_preferred_theme(&result.user.preferences)
+                .await;
+        }
+        *self.current_user.write().await = Some(result.user.clone());
+        *self.current_session.write().await = Some(result.session.clone());
+
+        Ok(result)
+    }
+
+    /// Switches the UI theme and remembers it in the current user's preferences
+    pub async fn set_theme(&self, theme_id: &str) -> Result<Theme> {
+        let (Some(ui_layout_manager), Some(account_manager)) =
+            (&self.ui_layout_manager, &self.account_manager)
+        else {
+            return Err(Error::config(
+                "UI layout and account managers are not initialized",
+            ));
+        };
+
+        let account_manager = account_manager.read().await;
+        let theme = ui_layout_manager
+            .read()
+            .await
+            .set_user_theme(&account_manager, theme_id)
+            .await?;
+        *self.current_user.write().await = account_manager.current_user().await;
+        Ok(theme)
+    }
+
     /// Gets current user
     pub async fn current_user(&self) -> Option<User> {
         self.current_user.read().await.clone()
@@ -701,40 +1545,14 @@ impl Manager for ApplicationCore {
     }
 }
 
-/// Simple plugin loader for demonstration
-struct SimplePluginLoader {
-    // Plugin loading implementation
-}
-
-impl SimplePluginLoader {
-    fn new() -> Self {
-        Self {}
-    }
-}
-
-#[async_trait]
-impl crate::plugin::PluginLoader for SimplePluginLoader {
-    async fn load_plugin(&self, _path: &str) -> Result<Box<dyn crate::plugin::Plugin>> {
-        Err(Error::plugin(
-            "loader",
-            "Plugin loading not implemented in example",
-        ))
-    }
-
-    async fn validate_plugin(
-        &self,
-        _plugin: &dyn crate::plugin::Plugin,
-    ) -> Result<crate::plugin::ValidationResult> {
-        Ok(crate::plugin::ValidationResult {
-            is_valid: true,
-            errors: Vec::new(),
-            warnings: Vec::new(),
-        })
-    }
-
-    async fn unload_plugin(&self, _plugin_id: &str) -> Result<()> {
-        Ok(())
-    }
+/// Health of one manager and, unless it is healthy, why
+async fn check_manager<M: Manager + ?Sized>(manager: &M) -> (HealthStatus, Option<String>) {
+    let health = manager.health_check().await;
+    let reason = match health {
+        HealthStatus::Healthy => None,
+        _ => manager.health_detail().await,
+    };
+    (health, reason)
 }
 
 #[cfg(test)]
@@ -750,8 +1568,15 @@ mod tests {
         app.initialize().await.unwrap();
         assert_eq!(app.get_state().await, ApplicationState::Running);
 
+        // Core managers can be looked up by type once initialized
+        let accounts = app.manager::<AccountManager>().unwrap();
+        assert!(accounts.read().await.current_user().await.is_none());
+        assert!(app.manager::<UILayoutManager>().is_some());
+        assert!(app.manager::<FileManager>().is_none());
+
         app.shutdown().await.unwrap();
         assert_eq!(app.get_state().await, ApplicationState::Shutdown);
+        assert!(app.manager::<AccountManager>().is_none());
     }
 
     #[tokio::test]
@@ -768,6 +1593,189 @@ mod tests {
         app.shutdown().await.unwrap();
     }
 
+    #[derive(Debug)]
+    struct SlowManager {
+        state: ManagedState,
+    }
+
+    #[async_trait]
+    impl Manager for SlowManager {
+        fn name(&self) -> &str {
+            "slow_manager"
+        }
+
+        fn id(&self) -> Uuid {
+            self.state.id()
+        }
+
+        async fn initialize(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn status(&self) -> ManagerStatus {
+            self.state.status().await
+        }
+
+        async fn health_check(&self) -> HealthStatus {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            HealthStatus::Healthy
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slow_manager_health_times_out() {
+        let mut app = ApplicationCore::new();
+        app.set_health_check_timeout(Duration::from_millis(50));
+        app.register_manager(Box::new(SlowManager {
+            state: ManagedState::new(Uuid::new_v4(), "slow_manager"),
+        }));
+
+        let health = timeout(Duration::from_secs(5), app.get_health())
+            .await
+            .expect("health check hung on a slow manager");
+
+        assert_eq!(health.managers["slow_manager"], HealthStatus::Unknown);
+        assert_eq!(health.status, HealthStatus::Degraded);
+        assert_eq!(
+            health.details["manager_errors"]["slow_manager"],
+            HEALTH_CHECK_TIMED_OUT
+        );
+        assert_eq!(health.reasons["slow_manager"], HEALTH_CHECK_TIMED_OUT);
+    }
+
+    #[derive(Debug)]
+    struct HangingManager {
+        state: ManagedState,
+    }
+
+    #[async_trait]
+    impl Manager for HangingManager {
+        fn name(&self) -> &str {
+            "hanging_manager"
+        }
+
+        fn id(&self) -> Uuid {
+            self.state.id()
+        }
+
+        async fn initialize(&mut self) -> Result<()> {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn status(&self) -> ManagerStatus {
+            self.state.status().await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hanging_manager_does_not_block_startup() {
+        let mut app = ApplicationCore::new();
+        app.set_manager_init_timeout("hanging_manager", Duration::from_millis(50));
+        app.register_manager(Box::new(HangingManager {
+            state: ManagedState::new(Uuid::new_v4(), "hanging_manager"),
+        }));
+
+        timeout(Duration::from_secs(10), app.initialize())
+            .await
+            .expect("startup blocked on a hanging manager")
+            .unwrap();
+
+        let report = app.startup_report();
+        assert_eq!(
+            report.get("hanging_manager"),
+            Some(&ManagerStartup::TimedOut(Duration::from_millis(50)))
+        );
+        assert_eq!(
+            report.get("account_manager"),
+            Some(&ManagerStartup::Initialized)
+        );
+        assert_eq!(report.failures().count(), 1);
+        assert!(report.summary().ends_with("; timed out: hanging_manager"));
+
+        // The timed-out manager is reported as failed, not still initializing
+        let health = app.get_health().await;
+        assert_eq!(health.managers["hanging_manager"], HealthStatus::Unhealthy);
+        assert_eq!(
+            health.reasons["hanging_manager"],
+            "initialization timed out after 50ms"
+        );
+
+        app.shutdown().await.unwrap();
+    }
+
+    #[derive(Debug)]
+    struct DependentManager {
+        state: ManagedState,
+    }
+
+    #[async_trait]
+    impl Manager for DependentManager {
+        fn name(&self) -> &str {
+            "dependent_manager"
+        }
+
+        fn id(&self) -> Uuid {
+            self.state.id()
+        }
+
+        async fn initialize(&mut self) -> Result<()> {
+            panic!("started although its dependency did not");
+        }
+
+        async fn shutdown(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn status(&self) -> ManagerStatus {
+            self.state.status().await
+        }
+
+        fn dependencies(&self) -> Vec<String> {
+            vec!["hanging_manager".to_string()]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dependents_of_failed_managers_are_skipped() {
+        let mut app = ApplicationCore::new();
+        app.set_manager_init_timeout("hanging_manager", Duration::from_millis(50));
+        // Registered first, but started after the manager it depends on
+        app.register_manager(Box::new(DependentManager {
+            state: ManagedState::new(Uuid::new_v4(), "dependent_manager"),
+        }));
+        app.register_manager(Box::new(HangingManager {
+            state: ManagedState::new(Uuid::new_v4(), "hanging_manager"),
+        }));
+
+        app.initialize().await.unwrap();
+
+        let report = app.startup_report();
+        assert_eq!(
+            report.get("dependent_manager"),
+            Some(&ManagerStartup::Skipped("hanging_manager".to_string()))
+        );
+        assert!(report
+            .summary()
+            .ends_with("; timed out: hanging_manager; skipped: dependent_manager"));
+
+        let health = app.get_health().await;
+        assert_eq!(
+            health.reasons["dependent_manager"],
+            "skipped because hanging_manager did not start"
+        );
+
+        app.shutdown().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_application_stats() {
         let mut app = ApplicationCore::new();
@@ -776,6 +1784,10 @@ mod tests {
         let stats = app.get_stats().await;
         assert_eq!(stats.version, crate::VERSION);
         assert_eq!(stats.state, ApplicationState::Running);
+        assert!(stats.init_durations.contains_key("platform_manager"));
+        let slowest = stats.slowest_managers();
+        assert_eq!(slowest.len(), stats.init_durations.len());
+        assert!(slowest.windows(2).all(|pair| pair[0].1 >= pair[1].1));
 
         app.shutdown().await.unwrap();
     }
@@ -6,20 +6,26 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
+use tokio::sync::RwLock;
 
 use crate::auth::{
-    AccountManager, MemorySessionStore, MemoryUserStore, SecurityPolicy, User, UserSession,
+    AccountManager, AuthResult, Credentials, MemoryUserStore, SecurityPolicy, User, UserSession,
+};
+use crate::config::tiered::ConfigStore;
+use crate::config::{
+    ConfigurationTier, IndexedDbConfigStore, MemoryConfigStore, TieredConfigManager,
 };
-use crate::config::{ConfigurationTier, MemoryConfigStore, TieredConfigManager};
 use crate::error::{Error, Result};
 use crate::event::EventBusManager;
-use crate::manager::{HealthStatus, ManagedState, Manager, ManagerState};
+use crate::manager::{HealthStatus, ManagedState, Manager, ManagerState, SharedManagers};
 use crate::platform::PlatformManager;
-use crate::plugin::PluginManager;
-use crate::ui::UILayoutManager;
+use crate::plugin::{PluginFactoryRegistry, PluginManager};
+use crate::ui::{Theme, UILayoutManager};
 use crate::utils::Time;
 
+/// IndexedDB database holding the user's settings
+const USER_CONFIG_STORE: &str = "qorzen_user_config";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ApplicationState {
     Created,
@@ -35,6 +41,9 @@ pub struct ApplicationHealth {
     pub status: HealthStatus,
     pub uptime: Duration,
     pub managers: HashMap<String, HealthStatus>,
+    /// Why each manager that is not healthy is in that state, when known
+    #[serde(default)]
+    pub reasons: HashMap<String, String>,
     pub last_check: f64,
     pub details: HashMap<String, serde_json::Value>,
 }
@@ -51,6 +60,22 @@ pub struct ApplicationStats {
     pub memory_usage_bytes: u64,
     pub cpu_usage_percent: f64,
     pub system_info: SystemInfo,
+    /// How long each manager's `initialize` took during startup
+    #[serde(default)]
+    pub init_durations: HashMap<String, Duration>,
+}
+
+impl ApplicationStats {
+    /// Managers by init time, slowest first
+    pub fn slowest_managers(&self) -> Vec<(&str, Duration)> {
+        let mut managers: Vec<(&str, Duration)> = self
+            .init_durations
+            .iter()
+            .map(|(name, duration)| (name.as_str(), *duration))
+            .collect();
+        managers.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        managers
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -84,11 +109,13 @@ pub struct ApplicationCore {
 
     // Core managers for web
     platform_manager: Option<PlatformManager>,
-    config_manager: Option<TieredConfigManager>,
+    config_manager: Option<Arc<RwLock<TieredConfigManager>>>,
     event_bus_manager: Option<Arc<EventBusManager>>,
-    account_manager: Option<AccountManager>,
+    account_manager: Option<Arc<RwLock<AccountManager>>>,
     plugin_manager: Option<PluginManager>,
-    ui_layout_manager: Option<UILayoutManager>,
+    ui_layout_manager: Option<Arc<RwLock<UILayoutManager>>>,
+    /// IDs of compiled-in plugins registered when the plugin manager starts
+    builtin_plugins: Vec<String>,
 
     // Current user context
     current_user: Option<User>,
@@ -96,6 +123,8 @@ pub struct ApplicationCore {
 
     // System info
     system_info: SystemInfo,
+    shared_managers: SharedManagers,
+    init_durations: HashMap<String, Duration>,
 }
 
 impl std::fmt::Debug for ApplicationCore {
@@ -110,7 +139,7 @@ impl std::fmt::Debug for ApplicationCore {
 impl ApplicationCore {
     pub fn new() -> Self {
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "application_core"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "application_core"),
             started_at: Time::now_millis() as f64,
             platform_manager: None,
             config_manager: None,
@@ -118,9 +147,12 @@ impl ApplicationCore {
             account_manager: None,
             plugin_manager: None,
             ui_layout_manager: None,
+            builtin_plugins: Vec::new(),
             current_user: None,
             current_session: None,
             system_info: SystemInfo::collect(),
+            shared_managers: SharedManagers::new(),
+            init_durations: HashMap::new(),
         }
     }
 
@@ -129,8 +161,30 @@ impl ApplicationCore {
         Self::new()
     }
 
+    /// Plugins compiled into the bundle, registered when the plugin manager starts.
+    ///
+    /// Each ID names a factory in [`PluginFactoryRegistry::shared`].
+    pub fn with_builtin_plugins<I>(mut self, plugin_ids: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.builtin_plugins = plugin_ids.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Look up a core manager by type, e.g. `manager::<AccountManager>()`.
+    ///
+    /// Only the configuration, account and UI layout managers are shared, and
+    /// only once `initialize` has set them up. Handles point at the core's
+    /// own instances until `shutdown`.
+    pub fn manager<T: Manager + 'static>(&self) -> Option<Arc<RwLock<T>>> {
+        self.shared_managers.get::<T>()
+    }
+
     pub async fn initialize(&mut self) -> Result<()> {
         self.state.set_state(ManagerState::Initializing).await;
+        self.init_durations.clear();
 
         web_sys::console::log_1(&"Starting Qorzen web application initialization".into());
 
@@ -171,10 +225,19 @@ impl ApplicationCore {
         Ok(())
     }
 
+    /// Run `manager.initialize()`, recording how long it took
+    async fn start_manager<M: Manager>(&mut self, manager: &mut M) -> Result<()> {
+        let started = Time::monotonic_millis();
+        let result = manager.initialize().await;
+        self.init_durations
+            .insert(manager.name().to_string(), Time::elapsed_since(started));
+        result
+    }
+
     async fn init_platform_manager(&mut self) -> Result<()> {
         web_sys::console::log_1(&"Initializing platform manager".into());
         let mut platform_manager = PlatformManager::new()?;
-        platform_manager.initialize().await?;
+        self.start_manager(&mut platform_manager).await?;
         self.platform_manager = Some(platform_manager);
         Ok(())
     }
@@ -187,13 +250,25 @@ impl ApplicationCore {
             ConfigurationTier::System,
             Box::new(MemoryConfigStore::new(ConfigurationTier::System)),
         );
+        // User settings persist across reloads when IndexedDB is available
+        let user_store: Box<dyn ConfigStore> =
+            match IndexedDbConfigStore::open(USER_CONFIG_STORE, ConfigurationTier::User).await {
+                Ok(store) => Box::new(store),
+                Err(e) => {
+                    web_sys::console::warn_1(
+                        &format!("User settings will not persist: {}", e).into(),
+                    );
+                    Box::new(MemoryConfigStore::new(ConfigurationTier::User))
+                }
+            };
+        config_manager.add_store(ConfigurationTier::User, user_store);
         config_manager.add_store(
             ConfigurationTier::Runtime,
             Box::new(MemoryConfigStore::new(ConfigurationTier::Runtime)),
         );
 
-        config_manager.initialize().await?;
-        self.config_manager = Some(config_manager);
+        self.start_manager(&mut config_manager).await?;
+        self.config_manager = Some(self.shared_managers.insert(config_manager));
         Ok(())
     }
 
@@ -207,39 +282,102 @@ impl ApplicationCore {
             enable_metrics: true,
             batch_size: 50,
             max_retry_delay: Duration::from_secs(10),
+            history_capacity: 100,
+            persistence_path: None,
         };
 
         let mut event_bus_manager = EventBusManager::new(event_config);
-        event_bus_manager.initialize().await?;
+        self.start_manager(&mut event_bus_manager).await?;
         self.event_bus_manager = Some(Arc::new(event_bus_manager));
         Ok(())
     }
 
     async fn init_account_manager(&mut self) -> Result<()> {
         web_sys::console::log_1(&"Initializing account manager".into());
-        let security_policy = SecurityPolicy::default();
-        let session_store = Box::new(MemorySessionStore::new());
+        let (security_policy, session_store) = match &self.config_manager {
+            Some(config_manager) => {
+                let manager = config_manager.read().await;
+                let policy = manager
+                    .get("security")
+                    .await
+                    .unwrap_or(None)
+                    .unwrap_or_default();
+                let security: crate::config::SecurityConfig = manager
+                    .get("security")
+                    .await
+                    .unwrap_or(None)
+                    .unwrap_or_default();
+                (policy, security.session_store)
+            }
+            None => (SecurityPolicy::default(), Default::default()),
+        };
+        let session_store = session_store.build();
         let user_store = Box::new(MemoryUserStore::new());
 
+        // The UI checks navigation, palette and slot permissions through the shared registries
+        crate::ui::UIComponentRegistry::shared()
+            .set_permission_hierarchy(security_policy.permission_hierarchy.clone());
+        crate::ui::CommandRegistry::shared()
+            .set_permission_hierarchy(security_policy.permission_hierarchy.clone());
+
         let mut account_manager = AccountManager::new(session_store, user_store, security_policy);
-        account_manager.initialize().await?;
-        self.account_manager = Some(account_manager);
+        if let Some(event_bus) = &self.event_bus_manager {
+            account_manager.set_event_bus(Arc::clone(event_bus));
+        }
+        self.start_manager(&mut account_manager).await?;
+        // Starting the manager restores the session saved before a reload
+        self.current_user = account_manager.current_user().await;
+        self.current_session = account_manager.current_session().await;
+        self.account_manager = Some(self.shared_managers.insert(account_manager));
         Ok(())
     }
 
     async fn init_ui_layout_manager(&mut self) -> Result<()> {
         web_sys::console::log_1(&"Initializing UI layout manager".into());
         let mut ui_layout_manager = UILayoutManager::new();
-        ui_layout_manager.initialize().await?;
-        self.ui_layout_manager = Some(ui_layout_manager);
+        self.start_manager(&mut ui_layout_manager).await?;
+        self.ui_layout_manager = Some(self.shared_managers.insert(ui_layout_manager));
         Ok(())
     }
 
     async fn init_plugin_manager(&mut self) -> Result<()> {
         web_sys::console::log_1(&"Initializing plugin manager".into());
+        let config: crate::config::PluginConfig = match &self.config_manager {
+            Some(config_manager) => config_manager
+                .read()
+                .await
+                .get("plugins")
+                .await
+                .unwrap_or(None)
+                .unwrap_or_default(),
+            None => crate::config::PluginConfig::default(),
+        };
         let loader = Box::new(SimplePluginLoader::new());
         let mut plugin_manager = PluginManager::new(loader);
-        plugin_manager.initialize().await?;
+        plugin_manager.set_payload_guard(crate::plugin::EventPayloadGuard::from_config(&config));
+        if let Some(config_manager) = &self.config_manager {
+            plugin_manager.set_config_manager(Arc::clone(config_manager));
+        }
+        if let Some(account_manager) = &self.account_manager {
+            plugin_manager.set_permission_hierarchy(
+                account_manager.read().await.permission_hierarchy().clone(),
+            );
+            plugin_manager.set_account_manager(Arc::clone(account_manager));
+        }
+        if let Some(event_bus) = &self.event_bus_manager {
+            plugin_manager.set_event_bus(Arc::clone(event_bus));
+        }
+        if let Some(platform_manager) = &self.platform_manager {
+            plugin_manager.set_state_store(platform_manager.storage_arc());
+            plugin_manager.set_database(platform_manager.database_arc());
+        }
+        plugin_manager
+            .register_builtin_plugins(
+                &PluginFactoryRegistry::shared(),
+                &std::mem::take(&mut self.builtin_plugins),
+            )
+            .await?;
+        self.start_manager(&mut plugin_manager).await?;
         self.plugin_manager = Some(plugin_manager);
         Ok(())
     }
@@ -254,12 +392,12 @@ impl ApplicationCore {
             let _ = plugin_manager.shutdown().await;
         }
 
-        if let Some(mut ui_layout_manager) = self.ui_layout_manager.take() {
-            let _ = ui_layout_manager.shutdown().await;
+        if let Some(ui_layout_manager) = self.ui_layout_manager.take() {
+            let _ = ui_layout_manager.write().await.shutdown().await;
         }
 
-        if let Some(mut account_manager) = self.account_manager.take() {
-            let _ = account_manager.shutdown().await;
+        if let Some(account_manager) = self.account_manager.take() {
+            let _ = account_manager.write().await.shutdown().await;
         }
 
         if let Some(event_bus_manager) = self.event_bus_manager.take() {
@@ -268,13 +406,14 @@ impl ApplicationCore {
             }
         }
 
-        if let Some(mut config_manager) = self.config_manager.take() {
-            let _ = config_manager.shutdown().await;
+        if let Some(config_manager) = self.config_manager.take() {
+            let _ = config_manager.write().await.shutdown().await;
         }
 
         if let Some(mut platform_manager) = self.platform_manager.take() {
             let _ = platform_manager.shutdown().await;
         }
+        self.shared_managers = SharedManagers::new();
 
         self.state.set_state(ManagerState::Shutdown).await;
 
@@ -290,23 +429,54 @@ impl ApplicationCore {
 
     pub async fn get_health(&self) -> ApplicationHealth {
         let mut manager_health = HashMap::new();
-        let mut overall_healthy = true;
+        let mut reasons = HashMap::new();
 
         // Check platform manager
         if let Some(platform_manager) = &self.platform_manager {
             let health = platform_manager.health_check().await;
             if health != HealthStatus::Healthy {
-                overall_healthy = false;
+                if let Some(reason) = platform_manager.health_detail().await {
+                    reasons.insert("platform_manager".to_string(), reason);
+                }
             }
             manager_health.insert("platform_manager".to_string(), health);
         }
 
+        let mut details = HashMap::new();
+        if let Some(plugin_manager) = &self.plugin_manager {
+            let plugin_health = plugin_manager.health_check_all().await;
+            let mut unhealthy: Vec<&str> = plugin_health
+                .iter()
+                .filter(|(_, health)| !health.is_healthy)
+                .map(|(plugin_id, _)| plugin_id.as_str())
+                .collect();
+            let all_healthy = unhealthy.is_empty();
+            if !all_healthy {
+                unhealthy.sort_unstable();
+                reasons.insert(
+                    "plugin_manager".to_string(),
+                    format!("Unhealthy plugins: {}", unhealthy.join(", ")),
+                );
+            }
+            manager_health.insert(
+                "plugin_manager".to_string(),
+                if all_healthy {
+                    HealthStatus::Healthy
+                } else {
+                    HealthStatus::Degraded
+                },
+            );
+            details.insert(
+                "plugins".to_string(),
+                serde_json::to_value(&plugin_health).unwrap_or_default(),
+            );
+        }
+
         // Check other managers...
 
-        let overall_status = if overall_healthy {
-            HealthStatus::Healthy
-        } else {
-            HealthStatus::Degraded
+        let overall_status = match HealthStatus::worst(manager_health.values().copied()) {
+            HealthStatus::Unknown => HealthStatus::Degraded,
+            worst => worst,
         };
 
         let current_time = Time::now_millis() as f64;
@@ -316,8 +486,9 @@ impl ApplicationCore {
             status: overall_status,
             uptime: uptime,
             managers: manager_health,
+            reasons,
             last_check: current_time,
-            details: HashMap::new(),
+            details,
         }
     }
 
@@ -336,9 +507,59 @@ impl ApplicationCore {
             memory_usage_bytes: 0, // Not available in web
             cpu_usage_percent: 0.0,
             system_info: self.system_info.clone(),
+            init_durations: self.init_durations.clone(),
         }
     }
 
+    /// Signs a user in and switches to the theme saved in their preferences
+    pub async fn login(
+        &mut self,
+        credentials: Credentials,
+        provider: Option<&str>,
+    ) -> Result<AuthResult> {
+        let account_manager = self
+            .account_manager
+            .as_ref()
+            .ok_or_else(|| Error::authentication("Account manager is not initialized"))?;
+        let result = account_manager
+            .read()
+            .await
+            .authenticate(credentials, provider)
+            .await?;
+
+        if let Some(ui_layout_manager) = &self.ui_layout_manager {
+            ui_layout_manager
+                .read()
+                .await
+                .apply_preferred_theme(&result.user.preferences)
+                .await;
+        }
+        self.current_user = Some(result.user.clone());
+        self.current_session = Some(result.session.clone());
+
+        Ok(result)
+    }
+
+    /// Switches the UI theme and remembers it in the current user's preferences
+    pub async fn set_theme(&mut self, theme_id: &str) -> Result<Theme> {
+        let (Some(ui_layout_manager), Some(account_manager)) =
+            (&self.ui_layout_manager, &self.account_manager)
+        else {
+            return Err(Error::config(
+                "UI layout and account managers are not initialized",
+            ));
+        };
+
+        let account_manager = account_manager.read().await;
+        let theme = ui_layout_manager
+            .read()
+            .await
+            .set_user_theme(&account_manager, theme_id)
+            .await?;
+        self.current_user = account_manager.current_user().await;
+        Ok(theme)
+    }
+
     pub async fn current_user(&self) -> Option<User> {
         self.current_user.clone()
     }
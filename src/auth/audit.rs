@@ -0,0 +1,267 @@
+// src/auth/audit.rs - Audit trail for user and role administration
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::RwLock;
+
+use super::{Permission, Role, User, UserId};
+use crate::error::Result;
+use crate::event::Event;
+use crate::types::Metadata;
+use crate::utils::Time;
+
+/// Placeholder stored instead of the value of a sensitive field
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Field names whose values never appear in an audit diff
+const SENSITIVE_FIELDS: &[&str] = &["password", "secret", "token", "mfa", "api_key"];
+
+/// Kind of administrative change recorded in the audit trail
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountAuditKind {
+    UserUpdated,
+    RoleUpdated,
+    RoleAssigned,
+}
+
+impl AccountAuditKind {
+    /// Event type published on the event bus
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::UserUpdated => "user.updated",
+            Self::RoleUpdated => "role.updated",
+            Self::RoleAssigned => "role.assigned",
+        }
+    }
+}
+
+/// A single field that changed, using dotted paths for nested fields
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old_value: Value,
+    pub new_value: Value,
+}
+
+/// Audit event emitted when an administrator edits a user or role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountAuditEvent {
+    pub kind: AccountAuditKind,
+    /// User who made the change, if one is signed in
+    pub actor: Option<UserId>,
+    /// ID of the user or role that was changed
+    pub target: String,
+    pub changes: Vec<FieldChange>,
+    pub timestamp: DateTime<Utc>,
+    pub source: String,
+    pub metadata: Metadata,
+}
+
+impl AccountAuditEvent {
+    pub fn new(
+        kind: AccountAuditKind,
+        actor: Option<UserId>,
+        target: impl Into<String>,
+        changes: Vec<FieldChange>,
+    ) -> Self {
+        Self {
+            kind,
+            actor,
+            target: target.into(),
+            changes,
+            timestamp: Time::now(),
+            source: "account_manager".to_string(),
+            metadata: Metadata::new(),
+        }
+    }
+}
+
+impl Event for AccountAuditEvent {
+    fn event_type(&self) -> &'static str {
+        self.kind.event_type()
+    }
+
+    fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+}
+
+/// Persistent storage for audit events
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait AuditStore: Send + Sync {
+    async fn record(&self, event: AccountAuditEvent) -> Result<()>;
+    async fn list(&self, limit: Option<usize>) -> Result<Vec<AccountAuditEvent>>;
+}
+
+/// In-memory audit store, newest events last
+#[derive(Debug, Default)]
+pub struct MemoryAuditStore {
+    events: Arc<RwLock<Vec<AccountAuditEvent>>>,
+}
+
+impl MemoryAuditStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl AuditStore for MemoryAuditStore {
+    async fn record(&self, event: AccountAuditEvent) -> Result<()> {
+        self.events.write().await.push(event);
+        Ok(())
+    }
+
+    async fn list(&self, limit: Option<usize>) -> Result<Vec<AccountAuditEvent>> {
+        let events = self.events.read().await;
+        let skip = limit.map_or(0, |limit| events.len().saturating_sub(limit));
+        Ok(events[skip..].to_vec())
+    }
+}
+
+/// Field-level diff between two versions of a user.
+///
+/// Roles and permissions are compared by ID rather than by their full
+/// definitions so the diff shows what was granted or revoked.
+pub fn diff_users(old: &User, new: &User) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_values("", &user_snapshot(old), &user_snapshot(new), &mut changes);
+    changes
+}
+
+/// Field-level diff between two versions of a role
+pub fn diff_roles(old: &Role, new: &Role) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    diff_values("", &role_snapshot(old), &role_snapshot(new), &mut changes);
+    changes
+}
+
+fn user_snapshot(user: &User) -> Value {
+    let mut value = serde_json::to_value(user).unwrap_or_default();
+    if let Value::Object(fields) = &mut value {
+        fields.remove("last_login");
+        fields.insert(
+            "roles".to_string(),
+            user.roles.iter().map(|r| r.id.clone()).collect(),
+        );
+        fields.insert("permissions".to_string(), permission_ids(&user.permissions));
+    }
+    value
+}
+
+fn role_snapshot(role: &Role) -> Value {
+    let mut value = serde_json::to_value(role).unwrap_or_default();
+    if let Value::Object(fields) = &mut value {
+        fields.insert("permissions".to_string(), permission_ids(&role.permissions));
+    }
+    value
+}
+
+fn permission_ids(permissions: &[Permission]) -> Value {
+    permissions
+        .iter()
+        .map(|p| format!("{}:{}", p.resource, p.action))
+        .collect()
+}
+
+fn diff_values(path: &str, old: &Value, new: &Value, changes: &mut Vec<FieldChange>) {
+    if old == new {
+        return;
+    }
+
+    if is_sensitive(path) {
+        changes.push(FieldChange {
+            field: path.to_string(),
+            old_value: Value::from(REDACTED),
+            new_value: Value::from(REDACTED),
+        });
+        return;
+    }
+
+    if let (Value::Object(old_fields), Value::Object(new_fields)) = (old, new) {
+        let mut keys: Vec<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+        keys.sort();
+        keys.dedup();
+
+        for key in keys {
+            let field = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            diff_values(
+                &field,
+                old_fields.get(key).unwrap_or(&Value::Null),
+                new_fields.get(key).unwrap_or(&Value::Null),
+                changes,
+            );
+        }
+        return;
+    }
+
+    changes.push(FieldChange {
+        field: path.to_string(),
+        old_value: old.clone(),
+        new_value: new.clone(),
+    });
+}
+
+fn is_sensitive(path: &str) -> bool {
+    let field = path
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    SENSITIVE_FIELDS.iter().any(|s| field.contains(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_redacts_sensitive_fields() {
+        let mut changes = Vec::new();
+        diff_values(
+            "",
+            &json!({ "profile": { "bio": "old", "api_key": "abc" } }),
+            &json!({ "profile": { "bio": "new", "api_key": "xyz" } }),
+            &mut changes,
+        );
+
+        assert_eq!(
+            changes,
+            [
+                FieldChange {
+                    field: "profile.api_key".to_string(),
+                    old_value: json!(REDACTED),
+                    new_value: json!(REDACTED),
+                },
+                FieldChange {
+                    field: "profile.bio".to_string(),
+                    old_value: json!("old"),
+                    new_value: json!("new"),
+                },
+            ]
+        );
+    }
+}
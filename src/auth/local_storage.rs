@@ -0,0 +1,142 @@
+// src/auth/local_storage.rs - Browser localStorage session store for the web build
+
+use async_trait::async_trait;
+use uuid::Uuid;
+use web_sys::Storage;
+
+use super::{SessionStore, UserSession};
+use crate::error::{Error, Result};
+use crate::utils::Time;
+
+/// Default key prefix for sessions saved by [`LocalStorageSessionStore`]
+pub const SESSION_KEY_PREFIX: &str = "qorzen.session:";
+
+/// Sessions kept in `window.localStorage`, so a page reload keeps the user
+/// signed in.
+///
+/// Each session is stored as JSON under the store's namespace followed by its
+/// ID. Entries that no longer parse are treated as absent and left alone.
+#[derive(Debug, Clone)]
+pub struct LocalStorageSessionStore {
+    namespace: String,
+}
+
+impl Default for LocalStorageSessionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LocalStorageSessionStore {
+    pub fn new() -> Self {
+        Self::with_namespace(SESSION_KEY_PREFIX)
+    }
+
+    /// Store sessions under keys starting with `namespace`
+    pub fn with_namespace(namespace: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+        }
+    }
+
+    fn storage() -> Result<Storage> {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok())
+            .flatten()
+            .ok_or_else(|| Error::platform("web", "storage", "localStorage not available"))
+    }
+
+    fn key(&self, session_id: Uuid) -> String {
+        format!("{}{}", self.namespace, session_id)
+    }
+
+    fn write(&self, session: &UserSession) -> Result<()> {
+        let json = serde_json::to_string(session).map_err(|e| {
+            Error::platform("web", "storage", format!("Failed to encode session: {}", e))
+        })?;
+
+        Self::storage()?
+            .set_item(&self.key(session.id), &json)
+            .map_err(|e| {
+                Error::platform("web", "storage", format!("Failed to save session: {:?}", e))
+            })
+    }
+
+    /// The session saved under `key`, or `None` if it is missing or corrupt
+    fn read(&self, storage: &Storage, key: &str) -> Option<UserSession> {
+        let json = storage.get_item(key).ok().flatten()?;
+        match serde_json::from_str(&json) {
+            Ok(session) => Some(session),
+            Err(e) => {
+                tracing::warn!("Skipping unreadable session {}: {}", key, e);
+                None
+            }
+        }
+    }
+
+    /// Keys of every session in this store's namespace
+    fn session_keys(&self, storage: &Storage) -> Vec<String> {
+        let length = storage.length().unwrap_or(0);
+        (0..length)
+            .filter_map(|index| storage.key(index).ok().flatten())
+            .filter(|key| key.starts_with(&self.namespace))
+            .collect()
+    }
+}
+
+#[async_trait(?Send)]
+impl SessionStore for LocalStorageSessionStore {
+    async fn create_session(&self, session: UserSession) -> Result<()> {
+        self.write(&session)
+    }
+
+    async fn get_session(&self, session_id: Uuid) -> Result<Option<UserSession>> {
+        let storage = Self::storage()?;
+        Ok(self.read(&storage, &self.key(session_id)))
+    }
+
+    async fn update_session(&self, session: UserSession) -> Result<()> {
+        self.write(&session)
+    }
+
+    async fn delete_session(&self, session_id: Uuid) -> Result<()> {
+        Self::storage()?
+            .remove_item(&self.key(session_id))
+            .map_err(|e| {
+                Error::platform(
+                    "web",
+                    "storage",
+                    format!("Failed to delete session: {:?}", e),
+                )
+            })
+    }
+
+    async fn cleanup_expired_sessions(&self) -> Result<u64> {
+        let now = Time::now();
+        let storage = Self::storage()?;
+
+        // Keys are collected first since removing items reorders the rest
+        let mut removed = 0;
+        for key in self.session_keys(&storage) {
+            let Some(session) = self.read(&storage, &key) else {
+                continue;
+            };
+            if session.expires_at <= now && storage.remove_item(&key).is_ok() {
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+
+    async fn latest_session(&self) -> Result<Option<UserSession>> {
+        let now = Time::now();
+        let storage = Self::storage()?;
+
+        Ok(self
+            .session_keys(&storage)
+            .iter()
+            .filter_map(|key| self.read(&storage, key))
+            .filter(|session| session.is_active && session.expires_at > now)
+            .max_by_key(|session| session.last_activity))
+    }
+}
@@ -0,0 +1,321 @@
+// src/auth/mfa.rs - Time-based one-time passwords (RFC 6238) for MFA
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use tokio::sync::RwLock;
+
+use super::UserId;
+use crate::error::{Error, Result};
+use crate::utils::rand;
+
+/// Seconds each code is valid for
+pub const TOTP_PERIOD_SECS: i64 = 30;
+
+/// Digits in each code
+pub const TOTP_DIGITS: u32 = 6;
+
+/// Bytes of randomness in a generated secret (160 bits, as RFC 4226 recommends)
+const SECRET_BYTES: usize = 20;
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// What a user needs to add a new secret to their authenticator app
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotpProvisioning {
+    /// Base32 secret, for manual entry
+    pub secret: String,
+    /// `otpauth://` URI understood by authenticator apps
+    pub uri: String,
+    /// The URI as an SVG QR code
+    pub qr_svg: String,
+}
+
+impl TotpProvisioning {
+    pub fn new(secret: String, issuer: &str, account: &str) -> Result<Self> {
+        let uri = provisioning_uri(&secret, issuer, account);
+        let qr_svg = qr_svg(&uri)?;
+        Ok(Self {
+            secret,
+            uri,
+            qr_svg,
+        })
+    }
+}
+
+/// Server-side store for users' TOTP secrets.
+///
+/// Secrets are kept apart from [`super::User`] so they never travel with a
+/// user to the UI or to anyone holding only the password.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait CredentialStore: Send + Sync {
+    /// The base32 TOTP secret `user_id` enrolled, if any
+    async fn totp_secret(&self, user_id: UserId) -> Result<Option<String>>;
+
+    /// Save a new secret, forgetting which codes the old one used
+    async fn set_totp_secret(&self, user_id: UserId, secret: String) -> Result<()>;
+
+    /// Mark the time step of an accepted code as used.
+    ///
+    /// Returns false if that step, or a later one, was already used, so each
+    /// code logs in at most once.
+    async fn use_totp_step(&self, user_id: UserId, step: i64) -> Result<bool>;
+}
+
+#[derive(Debug, Clone)]
+struct TotpCredential {
+    secret: String,
+    last_used_step: Option<i64>,
+}
+
+/// In-memory credential store
+#[derive(Debug, Default)]
+pub struct MemoryCredentialStore {
+    totp: RwLock<HashMap<UserId, TotpCredential>>,
+}
+
+impl MemoryCredentialStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl CredentialStore for MemoryCredentialStore {
+    async fn totp_secret(&self, user_id: UserId) -> Result<Option<String>> {
+        Ok(self
+            .totp
+            .read()
+            .await
+            .get(&user_id)
+            .map(|credential| credential.secret.clone()))
+    }
+
+    async fn set_totp_secret(&self, user_id: UserId, secret: String) -> Result<()> {
+        self.totp.write().await.insert(
+            user_id,
+            TotpCredential {
+                secret,
+                last_used_step: None,
+            },
+        );
+        Ok(())
+    }
+
+    async fn use_totp_step(&self, user_id: UserId, step: i64) -> Result<bool> {
+        let mut totp = self.totp.write().await;
+        let Some(credential) = totp.get_mut(&user_id) else {
+            return Ok(false);
+        };
+        if credential.last_used_step.is_some_and(|used| used >= step) {
+            return Ok(false);
+        }
+        credential.last_used_step = Some(step);
+        Ok(true)
+    }
+}
+
+/// A new random secret, base32 encoded
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; SECRET_BYTES];
+    rand::fill_bytes(&mut bytes);
+    base32_encode(&bytes)
+}
+
+/// The code for `secret` at `unix_time`
+pub fn totp_code(secret: &str, unix_time: i64) -> Result<String> {
+    let key = base32_decode(secret)?;
+    Ok(format!(
+        "{:0width$}",
+        hotp(&key, (unix_time / TOTP_PERIOD_SECS) as u64),
+        width = TOTP_DIGITS as usize
+    ))
+}
+
+/// Whether `code` is valid for `secret` at `unix_time`.
+///
+/// The codes for one period either side are also accepted, to allow for
+/// clock drift between the server and the user's device.
+pub fn verify_totp(secret: &str, code: &str, unix_time: i64) -> bool {
+    totp_step(secret, code, unix_time).is_some()
+}
+
+/// The time step `code` is valid for, as accepted by [`verify_totp`]
+pub fn totp_step(secret: &str, code: &str, unix_time: i64) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != TOTP_DIGITS as usize || !code.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (Ok(key), Ok(expected)) = (base32_decode(secret), code.parse::<u32>()) else {
+        return None;
+    };
+
+    let counter = unix_time / TOTP_PERIOD_SECS;
+    (counter - 1..=counter + 1)
+        .filter(|step| *step >= 0)
+        .find(|step| hotp(&key, *step as u64) == expected)
+}
+
+/// `otpauth://` URI for `secret`, labelled `issuer:account`
+pub fn provisioning_uri(secret: &str, issuer: &str, account: &str) -> String {
+    let label = format!("{}:{}", issuer, account);
+    format!(
+        "otpauth://totp/{}?secret={}&issuer={}&algorithm=SHA1&digits={}&period={}",
+        uri_encode(&label),
+        secret,
+        uri_encode(issuer),
+        TOTP_DIGITS,
+        TOTP_PERIOD_SECS
+    )
+}
+
+/// Renders `data` as an SVG QR code
+pub fn qr_svg(data: &str) -> Result<String> {
+    let code = qrcode::QrCode::new(data.as_bytes())
+        .map_err(|e| Error::authentication(format!("Failed to encode QR code: {}", e)))?;
+    Ok(code
+        .render::<qrcode::render::svg::Color<'_>>()
+        .min_dimensions(200, 200)
+        .build())
+}
+
+/// HOTP value (RFC 4226) for `counter`, truncated to [`TOTP_DIGITS`]
+fn hotp(key: &[u8], counter: u64) -> u32 {
+    let mut mac = Hmac::<Sha1>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(&counter.to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let binary = u32::from_be_bytes([
+        hash[offset],
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]) & 0x7fff_ffff;
+    binary % 10u32.pow(TOTP_DIGITS)
+}
+
+fn base32_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for byte in bytes {
+        buffer = (buffer << 8) | u32::from(*byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            encoded.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        encoded.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize] as char);
+    }
+    encoded
+}
+
+/// Decodes base32, ignoring case, spaces and padding as authenticator apps do
+fn base32_decode(encoded: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in encoded.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|a| *a as char == c.to_ascii_uppercase())
+            .ok_or_else(|| Error::authentication("TOTP secret is not valid base32"))?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    if bytes.is_empty() {
+        return Err(Error::authentication("TOTP secret is empty"));
+    }
+    Ok(bytes)
+}
+
+fn uri_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 6238 appendix B uses the ASCII key "12345678901234567890"
+    const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+    #[test]
+    fn test_totp_matches_rfc_vectors() {
+        assert_eq!(base32_encode(b"12345678901234567890"), RFC_SECRET);
+        assert_eq!(base32_decode(RFC_SECRET).unwrap(), b"12345678901234567890");
+
+        assert_eq!(totp_code(RFC_SECRET, 59).unwrap(), "287082");
+        assert_eq!(totp_code(RFC_SECRET, 1111111109).unwrap(), "081804");
+        assert_eq!(totp_code(RFC_SECRET, 1234567890).unwrap(), "005924");
+    }
+
+    #[test]
+    fn test_verify_totp_allows_one_step_of_drift() {
+        let secret = generate_secret();
+        let now = 1_700_000_000;
+        let code = totp_code(&secret, now).unwrap();
+
+        assert!(verify_totp(&secret, &code, now));
+        assert!(verify_totp(
+            &secret,
+            &format!(" {} ", code),
+            now + TOTP_PERIOD_SECS
+        ));
+        assert!(!verify_totp(&secret, &code, now + 3 * TOTP_PERIOD_SECS));
+        assert!(!verify_totp(&secret, "12345", now));
+        assert!(!verify_totp(&secret, "abcdef", now));
+        assert!(!verify_totp("not base32!", &code, now));
+    }
+
+    #[tokio::test]
+    async fn test_credential_store_rejects_reused_steps() {
+        let store = MemoryCredentialStore::new();
+        let user_id = rand::uuid();
+        assert!(!store.use_totp_step(user_id, 10).await.unwrap());
+
+        store
+            .set_totp_secret(user_id, RFC_SECRET.to_string())
+            .await
+            .unwrap();
+        assert_eq!(
+            store.totp_secret(user_id).await.unwrap().as_deref(),
+            Some(RFC_SECRET)
+        );
+        assert!(store.use_totp_step(user_id, 10).await.unwrap());
+        assert!(!store.use_totp_step(user_id, 10).await.unwrap());
+        assert!(!store.use_totp_step(user_id, 9).await.unwrap());
+        assert!(store.use_totp_step(user_id, 11).await.unwrap());
+    }
+
+    #[test]
+    fn test_provisioning() {
+        let provisioning =
+            TotpProvisioning::new(RFC_SECRET.to_string(), "Qorzen", "ada@example.com").unwrap();
+        assert_eq!(
+            provisioning.uri,
+            "otpauth://totp/Qorzen%3Aada%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=Qorzen&algorithm=SHA1&digits=6&period=30"
+        );
+        assert!(provisioning.qr_svg.starts_with("<?xml"));
+    }
+}
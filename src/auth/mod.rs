@@ -11,8 +11,28 @@ use tokio::sync::RwLock;
 use uuid::Uuid;
 
 use crate::error::{Error, Result};
+use crate::event::EventBusManager;
 use crate::manager::{ManagedState, Manager, ManagerStatus, PlatformRequirements};
 
+pub mod audit;
+#[cfg(target_arch = "wasm32")]
+pub mod local_storage;
+pub mod mfa;
+pub mod oauth2;
+pub mod rate_limit;
+
+#[cfg(target_arch = "wasm32")]
+pub use local_storage::LocalStorageSessionStore;
+
+pub use crate::config::SessionStoreKind;
+
+pub use audit::{AccountAuditEvent, AccountAuditKind, AuditStore, FieldChange, MemoryAuditStore};
+pub use mfa::{CredentialStore, MemoryCredentialStore, TotpProvisioning};
+pub use oauth2::{IdentityLinkStore, MemoryIdentityLinkStore, OAuth2AuthProvider, OAuth2Config};
+pub use rate_limit::{
+    RateDecision, RateLimitBackend, RateLimitBucket, RateLimiterStore, TokenBucketLimit,
+};
+
 pub type UserId = Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -53,8 +73,121 @@ pub enum PermissionScope {
     Global,             // All resources
 }
 
+/// Action implications and resource inheritance used when evaluating grants.
+///
+/// A grant on `plugin` covers `plugin.inventory`, and a `manage` grant covers
+/// `write` and, through it, `read`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PermissionHierarchy {
+    /// Actions each action directly implies; resolved transitively
+    pub action_implications: HashMap<String, Vec<String>>,
+    /// Separator between resource path segments
+    pub resource_separator: String,
+    /// Whether a grant on a parent resource covers its children
+    pub inherit_resources: bool,
+}
+
+impl Default for PermissionHierarchy {
+    fn default() -> Self {
+        let mut action_implications = HashMap::new();
+        action_implications.insert("admin".to_string(), vec!["manage".to_string()]);
+        action_implications.insert(
+            "manage".to_string(),
+            vec![
+                "write".to_string(),
+                "delete".to_string(),
+                "execute".to_string(),
+            ],
+        );
+        action_implications.insert("write".to_string(), vec!["read".to_string()]);
+
+        Self {
+            action_implications,
+            resource_separator: ".".to_string(),
+            inherit_resources: true,
+        }
+    }
+}
+
+impl PermissionHierarchy {
+    /// Create a hierarchy without implications or inheritance (exact matching)
+    pub fn flat() -> Self {
+        Self {
+            action_implications: HashMap::new(),
+            resource_separator: ".".to_string(),
+            inherit_resources: false,
+        }
+    }
+
+    /// Declare that `action` implies each of `implied`
+    pub fn with_implication(mut self, action: &str, implied: &[&str]) -> Self {
+        self.action_implications
+            .entry(action.to_string())
+            .or_default()
+            .extend(implied.iter().map(|a| a.to_string()));
+        self
+    }
+
+    /// Check whether a granted action covers the requested one
+    pub fn implies_action(&self, granted: &str, requested: &str) -> bool {
+        if granted == requested || granted == "*" {
+            return true;
+        }
+
+        let mut pending = vec![granted];
+        let mut seen = std::collections::HashSet::new();
+
+        while let Some(action) = pending.pop() {
+            if !seen.insert(action) {
+                continue;
+            }
+            if let Some(implied) = self.action_implications.get(action) {
+                for next in implied {
+                    if next == requested {
+                        return true;
+                    }
+                    pending.push(next);
+                }
+            }
+        }
+
+        false
+    }
+
+    /// Check whether a granted resource covers the requested one.
+    ///
+    /// Supports `*`, trailing wildcards (`plugin.*`) and, when inheritance is
+    /// enabled, parent resources (`plugin` covers `plugin.inventory`).
+    pub fn covers_resource(&self, granted: &str, requested: &str) -> bool {
+        if granted == requested || granted == "*" {
+            return true;
+        }
+
+        let sep = self.resource_separator.as_str();
+        if let Some(parent) = granted.strip_suffix(&format!("{}*", sep)) {
+            return Self::is_descendant(parent, requested, sep);
+        }
+
+        self.inherit_resources && Self::is_descendant(granted, requested, sep)
+    }
+
+    /// Check whether a permission grants `action` on `resource`
+    pub fn grants(&self, permission: &Permission, resource: &str, action: &str) -> bool {
+        self.covers_resource(&permission.resource, resource)
+            && self.implies_action(&permission.action, action)
+    }
+
+    fn is_descendant(parent: &str, resource: &str, sep: &str) -> bool {
+        !sep.is_empty()
+            && resource
+                .strip_prefix(parent)
+                .is_some_and(|rest| rest.starts_with(sep))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UserPreferences {
+    /// Theme ID; empty follows the system color scheme
     pub theme: String,
     pub language: String,
     pub timezone: String,
@@ -65,7 +198,7 @@ pub struct UserPreferences {
 impl Default for UserPreferences {
     fn default() -> Self {
         Self {
-            theme: "default".to_string(),
+            theme: String::new(),
             language: "en".to_string(),
             timezone: "UTC".to_string(),
             notifications_enabled: true,
@@ -184,6 +317,9 @@ pub trait SessionStore: Send + Sync {
     async fn update_session(&self, session: UserSession) -> Result<()>;
     async fn delete_session(&self, session_id: Uuid) -> Result<()>;
     async fn cleanup_expired_sessions(&self) -> Result<u64>;
+    /// The most recently active session that is still live, for restoring
+    /// the sign-in on start
+    async fn latest_session(&self) -> Result<Option<UserSession>>;
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -194,6 +330,9 @@ pub trait SessionStore: Sync {
     async fn update_session(&self, session: UserSession) -> Result<()>;
     async fn delete_session(&self, session_id: Uuid) -> Result<()>;
     async fn cleanup_expired_sessions(&self) -> Result<u64>;
+    /// The most recently active session that is still live, for restoring
+    /// the sign-in on start
+    async fn latest_session(&self) -> Result<Option<UserSession>>;
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -251,6 +390,22 @@ impl PermissionCache {
     }
 }
 
+impl SessionStoreKind {
+    /// Create a store of this kind, falling back to memory where it is unavailable
+    pub fn build(&self) -> Box<dyn SessionStore> {
+        match self {
+            Self::Memory => Box::new(MemorySessionStore::new()),
+            #[cfg(target_arch = "wasm32")]
+            Self::LocalStorage => Box::new(LocalStorageSessionStore::new()),
+            #[cfg(not(target_arch = "wasm32"))]
+            Self::LocalStorage => {
+                tracing::warn!("localStorage sessions are web only; keeping sessions in memory");
+                Box::new(MemorySessionStore::new())
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityPolicy {
     pub password_min_length: u32,
@@ -263,6 +418,14 @@ pub struct SecurityPolicy {
     pub lockout_duration_minutes: u64,
     pub require_mfa: bool,
     pub allowed_login_methods: Vec<AuthProviderType>,
+    #[serde(default)]
+    pub permission_hierarchy: PermissionHierarchy,
+    /// Persist user and role edits to the audit store
+    #[serde(default)]
+    pub audit_enabled: bool,
+    /// OAuth2 identity providers users can log in with, registered by name
+    #[serde(default)]
+    pub oauth2_providers: Vec<OAuth2Config>,
 }
 
 impl Default for SecurityPolicy {
@@ -278,19 +441,40 @@ impl Default for SecurityPolicy {
             lockout_duration_minutes: 30,
             require_mfa: false,
             allowed_login_methods: vec![AuthProviderType::Local],
+            permission_hierarchy: PermissionHierarchy::default(),
+            audit_enabled: false,
+            oauth2_providers: Vec::new(),
         }
     }
 }
 
+/// How long a password-verified login waits for its MFA code
+pub const MFA_CHALLENGE_MINUTES: i64 = 5;
+
+/// A login waiting for [`AccountManager::verify_mfa`]
+struct PendingMfa {
+    result: AuthResult,
+    expires_at: DateTime<Utc>,
+    failed_attempts: u32,
+    /// Secret from [`AccountManager::enroll_mfa`], saved once a code confirms it
+    enrollment: Option<String>,
+}
+
 pub struct AccountManager {
     state: ManagedState,
     auth_providers: HashMap<String, Box<dyn AuthProvider>>,
     session_store: Box<dyn SessionStore>,
     permission_cache: Arc<RwLock<PermissionCache>>,
-    user_store: Box<dyn UserStore>,
+    user_store: Arc<dyn UserStore>,
+    identity_links: Arc<dyn IdentityLinkStore>,
     security_policy: SecurityPolicy,
     current_user: Arc<RwLock<Option<User>>>,
     current_session: Arc<RwLock<Option<UserSession>>>,
+    event_bus: Option<Arc<EventBusManager>>,
+    audit_store: Box<dyn AuditStore>,
+    credential_store: Box<dyn CredentialStore>,
+    rate_limiter: RateLimiterStore,
+    pending_mfa: Arc<RwLock<HashMap<Uuid, PendingMfa>>>,
 }
 
 impl std::fmt::Debug for AccountManager {
@@ -302,27 +486,82 @@ impl std::fmt::Debug for AccountManager {
 }
 
 impl AccountManager {
+    /// Create a manager, registering an [`OAuth2AuthProvider`] for each
+    /// provider in `security_policy.oauth2_providers`
     pub fn new(
         session_store: Box<dyn SessionStore>,
         user_store: Box<dyn UserStore>,
         security_policy: SecurityPolicy,
     ) -> Self {
-        Self {
-            state: ManagedState::new(Uuid::new_v4(), "account_manager"),
+        let mut manager = Self {
+            state: ManagedState::new(crate::utils::rand::uuid(), "account_manager"),
             auth_providers: HashMap::new(),
             session_store,
             permission_cache: Arc::new(RwLock::new(PermissionCache::new())),
-            user_store,
+            user_store: Arc::from(user_store),
+            identity_links: Arc::new(MemoryIdentityLinkStore::new()),
             security_policy,
             current_user: Arc::new(RwLock::new(None)),
             current_session: Arc::new(RwLock::new(None)),
+            event_bus: None,
+            audit_store: Box::new(MemoryAuditStore::new()),
+            credential_store: Box::new(MemoryCredentialStore::new()),
+            rate_limiter: RateLimiterStore::shared(),
+            pending_mfa: Arc::new(RwLock::new(HashMap::new())),
+        };
+        for config in manager.security_policy.oauth2_providers.clone() {
+            manager.register_oauth2_provider(config);
         }
+        manager
+    }
+
+    /// Set the event bus that receives user and role audit events
+    pub fn set_event_bus(&mut self, event_bus: Arc<EventBusManager>) {
+        self.event_bus = Some(event_bus);
+    }
+
+    /// Replace the store audit events are persisted to when audit is enabled
+    pub fn set_audit_store(&mut self, store: Box<dyn AuditStore>) {
+        self.audit_store = store;
+    }
+
+    /// Replace the store that keeps users' MFA secrets
+    pub fn set_credential_store(&mut self, store: Box<dyn CredentialStore>) {
+        self.credential_store = store;
+    }
+
+    /// The configured permission hierarchy, for checks made outside this manager
+    pub fn permission_hierarchy(&self) -> &PermissionHierarchy {
+        &self.security_policy.permission_hierarchy
+    }
+
+    /// Replace the permission hierarchy and drop cached permission decisions
+    pub async fn set_permission_hierarchy(&mut self, hierarchy: PermissionHierarchy) {
+        self.security_policy.permission_hierarchy = hierarchy;
+        *self.permission_cache.write().await = PermissionCache::new();
+    }
+
+    /// Replace the store that tracks failed login attempts
+    pub fn set_rate_limiter(&mut self, store: RateLimiterStore) {
+        self.rate_limiter = store;
     }
 
     pub fn register_auth_provider(&mut self, name: String, provider: Box<dyn AuthProvider>) {
         self.auth_providers.insert(name, provider);
     }
 
+    /// Register an OAuth2 provider under its name, sharing this manager's
+    /// user store and identity links
+    pub fn register_oauth2_provider(&mut self, config: OAuth2Config) {
+        let name = config.provider.clone();
+        let provider = OAuth2AuthProvider::new(
+            config,
+            Arc::clone(&self.user_store),
+            Arc::clone(&self.identity_links),
+        );
+        self.register_auth_provider(name, Box::new(provider));
+    }
+
     pub async fn authenticate(
         &self,
         credentials: Credentials,
@@ -336,14 +575,233 @@ impl AccountManager {
             ))
         })?;
 
-        let auth_result = auth_provider.authenticate(&credentials).await?;
+        // Password logins are locked after `max_login_attempts` failures, one
+        // attempt returning every `lockout_duration_minutes / max_login_attempts`
+        let login_key = match &credentials {
+            Credentials::Password { username, .. } => Some(rate_limit::login_bucket_key(username)),
+            _ => None,
+        };
+        if let Some(RateDecision::Limited { retry_after }) = login_key
+            .as_ref()
+            .and_then(|key| self.rate_limiter.peek(key))
+        {
+            return Err(Error::authentication(format!(
+                "Too many failed login attempts; try again in {} seconds",
+                retry_after.as_secs().max(1)
+            )));
+        }
+
+        let auth_result = match auth_provider.authenticate(&credentials).await {
+            Ok(result) => result,
+            Err(e) => {
+                if let Some(key) = &login_key {
+                    self.rate_limiter.take_token(
+                        key,
+                        TokenBucketLimit::per_window(
+                            self.security_policy.max_login_attempts,
+                            std::time::Duration::from_secs(
+                                self.security_policy.lockout_duration_minutes * 60,
+                            ),
+                        ),
+                    );
+                }
+                return Err(e);
+            }
+        };
+        if let Some(key) = &login_key {
+            self.rate_limiter.reset(key);
+        }
+
+        if self.security_policy.require_mfa || auth_result.requires_mfa {
+            return Ok(self.challenge_mfa(auth_result).await);
+        }
+
+        self.start_session(&auth_result).await?;
+        Ok(auth_result)
+    }
+
+    /// Complete a login that [`Self::authenticate`] held back for MFA.
+    ///
+    /// `pending_session` is the session ID of the `requires_mfa` result, and
+    /// `code` the user's current TOTP code. Each code is accepted once. On
+    /// success the real session is started and returned with its tokens.
+    /// After `max_login_attempts` wrong codes, or [`MFA_CHALLENGE_MINUTES`],
+    /// the user must log in again.
+    pub async fn verify_mfa(&self, pending_session: Uuid, code: &str) -> Result<AuthResult> {
+        let now = Time::now();
+        let mut pending = self.pending_mfa.write().await;
+        pending.retain(|_, login| login.expires_at > now);
+
+        let mut login = pending.remove(&pending_session).ok_or_else(|| {
+            Error::authentication("MFA login has expired or does not exist; log in again")
+        })?;
+        let user = self
+            .user_store
+            .get_user(login.result.user.id)
+            .await?
+            .unwrap_or_else(|| login.result.user.clone());
+        let secret = match login.enrollment.clone() {
+            Some(secret) => secret,
+            None => match self.credential_store.totp_secret(user.id).await? {
+                Some(secret) => secret,
+                None => {
+                    // Keep the login so the user can enroll and retry
+                    pending.insert(pending_session, login);
+                    return Err(Error::authentication(
+                        "MFA is not set up for this user; enroll first",
+                    ));
+                }
+            },
+        };
+
+        let accepted = match mfa::totp_step(&secret, code, now.timestamp()) {
+            Some(step) => {
+                if login.enrollment.is_some() {
+                    self.credential_store
+                        .set_totp_secret(user.id, secret)
+                        .await?;
+                }
+                self.credential_store.use_totp_step(user.id, step).await?
+            }
+            None => false,
+        };
+        if !accepted {
+            login.failed_attempts += 1;
+            if login.failed_attempts >= self.security_policy.max_login_attempts {
+                return Err(Error::authentication(
+                    "Too many invalid MFA codes; log in again",
+                ));
+            }
+            pending.insert(pending_session, login);
+            return Err(Error::authentication("Invalid MFA code"));
+        }
+        drop(pending);
+
+        let mut result = login.result;
+        result.user = user;
+        result.requires_mfa = false;
+        self.start_session(&result).await?;
+        Ok(result)
+    }
+
+    /// Start TOTP enrollment for a held-back login whose user has no secret.
+    ///
+    /// This lets users log in for the first time when MFA is required. The
+    /// returned URI and QR code add the secret to an authenticator app, and
+    /// it is only saved once [`Self::verify_mfa`] accepts a code for it.
+    /// Users who already enrolled must use their existing secret.
+    pub async fn enroll_mfa(
+        &self,
+        pending_session: Uuid,
+        issuer: &str,
+    ) -> Result<TotpProvisioning> {
+        let now = Time::now();
+        let mut pending = self.pending_mfa.write().await;
+        pending.retain(|_, login| login.expires_at > now);
+
+        let login = pending.get_mut(&pending_session).ok_or_else(|| {
+            Error::authentication("MFA login has expired or does not exist; log in again")
+        })?;
+        let user = &login.result.user;
+        if self.credential_store.totp_secret(user.id).await?.is_some() {
+            return Err(Error::authentication("MFA is already set up for this user"));
+        }
+
+        let provisioning = TotpProvisioning::new(mfa::generate_secret(), issuer, &user.email)?;
+        login.enrollment = Some(provisioning.secret.clone());
+        Ok(provisioning)
+    }
 
-        // Store session
+    /// Whether the user of a held-back login still has to enroll in MFA
+    pub async fn mfa_enrollment_required(&self, pending_session: Uuid) -> Result<bool> {
+        let user_id = self
+            .pending_mfa
+            .read()
+            .await
+            .get(&pending_session)
+            .map(|login| login.result.user.id)
+            .ok_or_else(|| {
+                Error::authentication("MFA login has expired or does not exist; log in again")
+            })?;
+        Ok(self.credential_store.totp_secret(user_id).await?.is_none())
+    }
+
+    /// Give a user a new TOTP secret, replacing any they had.
+    ///
+    /// The secret is saved in the credential store; the returned URI and QR
+    /// code add it to an authenticator app. Requires `users.write` unless it
+    /// is the current user.
+    pub async fn provision_totp(&self, user_id: UserId, issuer: &str) -> Result<TotpProvisioning> {
+        let is_current = self
+            .current_user
+            .read()
+            .await
+            .as_ref()
+            .is_some_and(|u| u.id == user_id);
+        if !is_current {
+            self.require_current_user_permission("users", "write")
+                .await?;
+        }
+
+        let user = self.user_store.get_user(user_id).await?.ok_or_else(|| {
+            Error::authorization(user_id.to_string(), "provision_totp", "User not found")
+        })?;
+        let provisioning = TotpProvisioning::new(mfa::generate_secret(), issuer, &user.email)?;
+        self.credential_store
+            .set_totp_secret(user_id, provisioning.secret.clone())
+            .await?;
+
+        Ok(provisioning)
+    }
+
+    /// Hold back a verified login until [`Self::verify_mfa`] succeeds.
+    ///
+    /// The returned result carries a pending session that is not stored or
+    /// made current, and no tokens.
+    async fn challenge_mfa(&self, result: AuthResult) -> AuthResult {
+        let now = Time::now();
+        let expires_at = now + chrono::Duration::minutes(MFA_CHALLENGE_MINUTES);
+        let challenge = AuthResult {
+            user: result.user.clone(),
+            session: UserSession {
+                id: crate::utils::rand::uuid(),
+                user_id: result.user.id,
+                created_at: now,
+                expires_at,
+                last_activity: now,
+                ip_address: result.session.ip_address.clone(),
+                user_agent: result.session.user_agent.clone(),
+                is_active: false,
+            },
+            tokens: TokenPair {
+                access_token: String::new(),
+                refresh_token: String::new(),
+                token_type: result.tokens.token_type.clone(),
+                expires_in: 0,
+            },
+            requires_mfa: true,
+        };
+
+        let mut pending = self.pending_mfa.write().await;
+        pending.retain(|_, login| login.expires_at > now);
+        pending.insert(
+            challenge.session.id,
+            PendingMfa {
+                result,
+                expires_at,
+                failed_attempts: 0,
+                enrollment: None,
+            },
+        );
+        challenge
+    }
+
+    /// Store the session and make its user current
+    async fn start_session(&self, auth_result: &AuthResult) -> Result<()> {
         self.session_store
             .create_session(auth_result.session.clone())
             .await?;
 
-        // Update current user and session
         *self.current_user.write().await = Some(auth_result.user.clone());
         *self.current_session.write().await = Some(auth_result.session.clone());
 
@@ -353,7 +811,7 @@ impl AccountManager {
             .await
             .clear_user_cache(auth_result.user.id);
 
-        Ok(auth_result)
+        Ok(())
     }
 
     pub async fn validate_token(&self, token: &str, provider: Option<&str>) -> Result<Claims> {
@@ -406,6 +864,28 @@ impl AccountManager {
         self.current_session.read().await.clone()
     }
 
+    /// Make the session store's latest live session and its user current
+    /// again, e.g. after a page reload.
+    ///
+    /// Sessions whose user no longer exists or is deactivated are deleted.
+    /// Returns the restored user.
+    pub async fn restore_session(&self) -> Result<Option<User>> {
+        let Some(session) = self.session_store.latest_session().await? else {
+            return Ok(None);
+        };
+        let user = match self.user_store.get_user(session.user_id).await? {
+            Some(user) if user.is_active => user,
+            _ => {
+                self.session_store.delete_session(session.id).await?;
+                return Ok(None);
+            }
+        };
+
+        *self.current_user.write().await = Some(user.clone());
+        *self.current_session.write().await = Some(session);
+        Ok(Some(user))
+    }
+
     pub async fn check_permission(
         &self,
         user_id: UserId,
@@ -458,7 +938,7 @@ impl AccountManager {
     pub async fn create_user(&self, mut user: User) -> Result<()> {
         // Set creation timestamp
         user.created_at = Time::now();
-        user.id = Uuid::new_v4();
+        user.id = crate::utils::rand::uuid();
 
         // Store user
         self.user_store.create_user(user).await?;
@@ -467,6 +947,126 @@ impl AccountManager {
     }
 
     pub async fn update_user(&self, user: User) -> Result<()> {
+        let previous = self.user_store.get_user(user.id).await?;
+        self.store_user(user.clone()).await?;
+
+        if let Some(previous) = previous {
+            let changes = audit::diff_users(&previous, &user);
+            if !changes.is_empty() {
+                self.record_audit(AccountAuditKind::UserUpdated, user.id.to_string(), changes)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Give a user a role, recording a `role.assigned` audit event
+    pub async fn assign_role(&self, user_id: UserId, role: Role) -> Result<()> {
+        let mut user = self.user_store.get_user(user_id).await?.ok_or_else(|| {
+            Error::authorization(user_id.to_string(), "assign_role", "User not found")
+        })?;
+
+        if user.roles.iter().any(|r| r.id == role.id) {
+            return Ok(());
+        }
+
+        let previous = user.clone();
+        user.roles.push(role);
+        self.store_user(user.clone()).await?;
+
+        let changes = audit::diff_users(&previous, &user);
+        self.record_audit(AccountAuditKind::RoleAssigned, user_id.to_string(), changes)
+            .await
+    }
+
+    /// Update a role definition on every user holding it, recording a `role.updated` audit event.
+    ///
+    /// Returns the number of users whose copy of the role changed.
+    pub async fn update_role(&self, role: Role) -> Result<usize> {
+        let mut changes = Vec::new();
+        let mut updated = 0;
+
+        for mut user in self.user_store.list_users(None, None).await? {
+            let Some(existing) = user.roles.iter_mut().find(|r| r.id == role.id) else {
+                continue;
+            };
+            if *existing == role {
+                continue;
+            }
+
+            if changes.is_empty() {
+                changes = audit::diff_roles(existing, &role);
+            }
+            *existing = role.clone();
+            self.store_user(user).await?;
+            updated += 1;
+        }
+
+        if updated > 0 {
+            self.record_audit(AccountAuditKind::RoleUpdated, role.id.clone(), changes)
+                .await?;
+        }
+
+        Ok(updated)
+    }
+
+    /// Active rate limit buckets whose key starts with `prefix`.
+    ///
+    /// Requires the `rate_limits.read` permission.
+    pub async fn rate_limit_buckets(&self, prefix: &str) -> Result<Vec<RateLimitBucket>> {
+        self.require_current_user_permission("rate_limits", "read")
+            .await?;
+        Ok(self.rate_limiter.list(prefix))
+    }
+
+    /// Restores a bucket to full quota, e.g. to unlock a locked-out user.
+    ///
+    /// Requires the `rate_limits.reset` permission. Returns whether the bucket existed.
+    pub async fn reset_rate_limit(&self, key: &str) -> Result<bool> {
+        self.require_current_user_permission("rate_limits", "reset")
+            .await?;
+        Ok(self.rate_limiter.reset(key))
+    }
+
+    async fn require_current_user_permission(&self, resource: &str, action: &str) -> Result<()> {
+        if self.check_current_user_permission(resource, action).await? {
+            Ok(())
+        } else {
+            Err(Error::authorization(
+                resource,
+                action,
+                "Permission denied for current user",
+            ))
+        }
+    }
+
+    /// Most recent persisted audit events, oldest first
+    pub async fn audit_log(&self, limit: Option<usize>) -> Result<Vec<AccountAuditEvent>> {
+        self.audit_store.list(limit).await
+    }
+
+    async fn record_audit(
+        &self,
+        kind: AccountAuditKind,
+        target: String,
+        changes: Vec<FieldChange>,
+    ) -> Result<()> {
+        let actor = self.current_user.read().await.as_ref().map(|u| u.id);
+        let event = AccountAuditEvent::new(kind, actor, target, changes);
+
+        if let Some(event_bus) = &self.event_bus {
+            let _ = event_bus.publish(event.clone()).await;
+        }
+
+        if self.security_policy.audit_enabled {
+            self.audit_store.record(event).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn store_user(&self, user: User) -> Result<()> {
         self.user_store.update_user(user.clone()).await?;
 
         // Clear permission cache for updated user
@@ -476,10 +1076,9 @@ impl AccountManager {
             .clear_user_cache(user.id);
 
         // Update current user if it's the same
-        if let Some(current) = self.current_user.read().await.as_ref() {
-            if current.id == user.id {
-                *self.current_user.write().await = Some(user);
-            }
+        let mut current = self.current_user.write().await;
+        if current.as_ref().is_some_and(|c| c.id == user.id) {
+            *current = Some(user);
         }
 
         Ok(())
@@ -497,6 +1096,17 @@ impl AccountManager {
         self.user_store.list_users(limit, offset).await
     }
 
+    /// Active users granted `action` on `resource`, directly or through a role
+    pub async fn users_with_permission(&self, resource: &str, action: &str) -> Result<Vec<User>> {
+        Ok(self
+            .user_store
+            .list_users(None, None)
+            .await?
+            .into_iter()
+            .filter(|user| user.is_active && self.user_has_permission(user, resource, action))
+            .collect())
+    }
+
     pub async fn cleanup_expired_sessions(&self) -> Result<u64> {
         self.session_store.cleanup_expired_sessions().await
     }
@@ -522,9 +1132,9 @@ impl AccountManager {
     }
 
     fn permission_matches(&self, permission: &Permission, resource: &str, action: &str) -> bool {
-        // Simple string matching - in practice you'd want more sophisticated matching
-        (permission.resource == resource || permission.resource == "*")
-            && (permission.action == action || permission.action == "*")
+        self.security_policy
+            .permission_hierarchy
+            .grants(permission, resource, action)
     }
 }
 
@@ -546,6 +1156,12 @@ impl Manager for AccountManager {
 
         // Initialize default auth providers, create admin user if needed, etc.
 
+        match self.restore_session().await {
+            Ok(Some(user)) => tracing::info!("Restored session for {}", user.username),
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to restore the previous session: {}", e),
+        }
+
         self.state
             .set_state(crate::manager::ManagerState::Running)
             .await;
@@ -624,6 +1240,12 @@ impl Manager for AccountManager {
 
         // Initialize default auth providers, create admin user if needed, etc.
 
+        match self.restore_session().await {
+            Ok(Some(user)) => tracing::info!("Restored session for {}", user.username),
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to restore the previous session: {}", e),
+        }
+
         self.state
             .set_state(crate::manager::ManagerState::Running)
             .await;
@@ -738,6 +1360,18 @@ impl SessionStore for MemorySessionStore {
 
         Ok((original_count - sessions.len()) as u64)
     }
+
+    async fn latest_session(&self) -> Result<Option<UserSession>> {
+        let now = Time::now();
+        Ok(self
+            .sessions
+            .read()
+            .await
+            .values()
+            .filter(|session| session.is_active && session.expires_at > now)
+            .max_by_key(|session| session.last_activity)
+            .cloned())
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -771,6 +1405,18 @@ impl SessionStore for MemorySessionStore {
 
         Ok((original_count - sessions.len()) as u64)
     }
+
+    async fn latest_session(&self) -> Result<Option<UserSession>> {
+        let now = Time::now();
+        Ok(self
+            .sessions
+            .read()
+            .await
+            .values()
+            .filter(|session| session.is_active && session.expires_at > now)
+            .max_by_key(|session| session.last_activity)
+            .cloned())
+    }
 }
 
 pub struct MemoryUserStore {
@@ -907,10 +1553,126 @@ impl UserStore for MemoryUserStore {
     }
 }
 
+/// An active user with `permissions` and no roles, shared by tests
+#[cfg(test)]
+pub(crate) fn test_user(username: &str, permissions: Vec<Permission>) -> User {
+    User {
+        id: Uuid::new_v4(),
+        username: username.to_string(),
+        email: format!("{}@example.com", username),
+        roles: vec![],
+        permissions,
+        preferences: UserPreferences::default(),
+        profile: UserProfile {
+            display_name: username.to_string(),
+            avatar_url: None,
+            bio: None,
+            department: None,
+            title: None,
+            contact_info: ContactInfo {
+                phone: None,
+                address: None,
+                emergency_contact: None,
+            },
+        },
+        created_at: Time::now(),
+        last_login: None,
+        is_active: true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_session_store_selected_by_security_config() {
+        let security: crate::config::SecurityConfig = serde_json::from_value(serde_json::json!({
+            "jwt_secret": "secret",
+            "jwt_expiration_secs": 3600,
+            "api_key": null,
+            "enable_rate_limiting": true,
+            "rate_limit_rpm": 1000,
+            "enable_cors": false,
+            "cors_origins": [],
+            "session_store": "local_storage"
+        }))
+        .unwrap();
+        assert_eq!(security.session_store, SessionStoreKind::LocalStorage);
+        assert_eq!(
+            crate::config::SecurityConfig::default().session_store,
+            SessionStoreKind::Memory
+        );
+
+        // Off the web, local storage falls back to memory
+        let store = security.session_store.build();
+        let now = Time::now();
+        let session = UserSession {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            created_at: now,
+            expires_at: now - chrono::Duration::minutes(1),
+            last_activity: now,
+            ip_address: None,
+            user_agent: None,
+            is_active: true,
+        };
+        store.create_session(session.clone()).await.unwrap();
+        assert!(store.get_session(session.id).await.unwrap().is_some());
+        assert_eq!(store.cleanup_expired_sessions().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_restores_latest_session() {
+        let user = test_user("testuser", vec![]);
+        let user_store = MemoryUserStore::new();
+        user_store.create_user(user.clone()).await.unwrap();
+
+        let now = Time::now();
+        let session = |last_activity: DateTime<Utc>, user_id: UserId| UserSession {
+            id: Uuid::new_v4(),
+            user_id,
+            created_at: now,
+            expires_at: now + chrono::Duration::hours(1),
+            last_activity,
+            ip_address: None,
+            user_agent: None,
+            is_active: true,
+        };
+        let older = session(now - chrono::Duration::minutes(10), user.id);
+        let latest = session(now, user.id);
+        let session_store = MemorySessionStore::new();
+        session_store.create_session(older).await.unwrap();
+        session_store.create_session(latest.clone()).await.unwrap();
+
+        let mut manager = AccountManager::new(
+            Box::new(session_store),
+            Box::new(user_store),
+            SecurityPolicy::default(),
+        );
+        manager.initialize().await.unwrap();
+        assert_eq!(manager.current_user().await.unwrap().id, user.id);
+        assert_eq!(manager.current_session().await.unwrap().id, latest.id);
+
+        // A session whose user is gone is dropped instead of restored
+        let orphan = session(now, Uuid::new_v4());
+        let session_store = MemorySessionStore::new();
+        session_store.create_session(orphan.clone()).await.unwrap();
+        let manager = AccountManager::new(
+            Box::new(session_store),
+            Box::new(MemoryUserStore::new()),
+            SecurityPolicy::default(),
+        );
+        assert!(manager.restore_session().await.unwrap().is_none());
+        assert!(manager.current_user().await.is_none());
+        assert!(manager
+            .session_store
+            .get_session(orphan.id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
     #[tokio::test]
     async fn test_user_creation() {
         let user_store = Box::new(MemoryUserStore::new());
@@ -1006,4 +1768,292 @@ mod tests {
             .unwrap();
         assert!(!no_permission);
     }
+
+    fn grant(resource: &str, action: &str) -> Permission {
+        Permission {
+            resource: resource.to_string(),
+            action: action.to_string(),
+            scope: PermissionScope::Global,
+        }
+    }
+
+    fn account_manager() -> AccountManager {
+        AccountManager::new(
+            Box::new(MemorySessionStore::new()),
+            Box::new(MemoryUserStore::new()),
+            SecurityPolicy::default(),
+        )
+    }
+
+    #[test]
+    fn test_manage_implies_read() {
+        let manager = account_manager();
+        let user = test_user("testuser", vec![grant("plugin.inventory", "manage")]);
+
+        assert!(manager.user_has_permission(&user, "plugin.inventory", "read"));
+        assert!(manager.user_has_permission(&user, "plugin.inventory", "write"));
+        assert!(!manager.user_has_permission(&user, "plugin.inventory", "admin"));
+    }
+
+    #[test]
+    fn test_parent_resource_covers_children() {
+        let manager = account_manager();
+        let user = test_user("testuser", vec![grant("plugin", "read")]);
+
+        assert!(manager.user_has_permission(&user, "plugin.inventory.items", "read"));
+        assert!(!manager.user_has_permission(&user, "plugins", "read"));
+        assert!(!manager.user_has_permission(&user, "plugin.inventory", "write"));
+    }
+
+    #[tokio::test]
+    async fn test_configurable_hierarchy() {
+        let mut manager = account_manager();
+        let user = test_user("testuser", vec![grant("system", "manage")]);
+        assert!(manager.user_has_permission(&user, "system.config", "read"));
+
+        manager
+            .set_permission_hierarchy(PermissionHierarchy::flat())
+            .await;
+        assert!(!manager.user_has_permission(&user, "system.config", "read"));
+        assert!(manager.user_has_permission(&user, "system", "manage"));
+
+        let hierarchy = PermissionHierarchy::flat().with_implication("publish", &["read"]);
+        assert!(hierarchy.grants(&grant("docs.*", "publish"), "docs.guide", "read"));
+        assert!(!hierarchy.grants(&grant("docs.*", "publish"), "docs", "read"));
+    }
+
+    fn role(id: &str) -> Role {
+        Role {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: String::new(),
+            permissions: vec![grant("docs", "write")],
+            ui_layout: None,
+            is_system_role: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_role_change_emits_user_updated() {
+        use crate::event::EventBusConfig;
+
+        let mut event_bus = EventBusManager::new(EventBusConfig {
+            worker_count: 1,
+            ..EventBusConfig::default()
+        });
+        event_bus.initialize().await.unwrap();
+        let event_bus = Arc::new(event_bus);
+
+        let mut manager = AccountManager::new(
+            Box::new(MemorySessionStore::new()),
+            Box::new(MemoryUserStore::new()),
+            SecurityPolicy {
+                audit_enabled: true,
+                ..SecurityPolicy::default()
+            },
+        );
+        manager.set_event_bus(Arc::clone(&event_bus));
+
+        manager
+            .create_user(test_user("testuser", vec![]))
+            .await
+            .unwrap();
+        let mut user = manager
+            .get_user_by_username("testuser")
+            .await
+            .unwrap()
+            .unwrap();
+        user.roles.push(role("editor"));
+        manager.update_user(user.clone()).await.unwrap();
+
+        let events = event_bus.recent_events(10).await;
+        assert_eq!(events.last().unwrap().event_type, "user.updated");
+
+        let log = manager.audit_log(None).await.unwrap();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].kind, AccountAuditKind::UserUpdated);
+        assert_eq!(log[0].target, user.id.to_string());
+        assert_eq!(
+            log[0].changes,
+            [FieldChange {
+                field: "roles".to_string(),
+                old_value: serde_json::json!([]),
+                new_value: serde_json::json!(["editor"]),
+            }]
+        );
+
+        // Editing the role itself is recorded against the role
+        let mut renamed = role("editor");
+        renamed.name = "Editor".to_string();
+        assert_eq!(manager.update_role(renamed).await.unwrap(), 1);
+
+        let log = manager.audit_log(Some(1)).await.unwrap();
+        assert_eq!(log[0].kind, AccountAuditKind::RoleUpdated);
+        assert_eq!(log[0].changes[0].field, "name");
+    }
+
+    /// Accepts any credentials as the given user
+    struct StaticAuthProvider {
+        user: User,
+    }
+
+    #[async_trait]
+    impl AuthProvider for StaticAuthProvider {
+        async fn authenticate(&self, _credentials: &Credentials) -> Result<AuthResult> {
+            let now = Time::now();
+            Ok(AuthResult {
+                user: self.user.clone(),
+                session: UserSession {
+                    id: Uuid::new_v4(),
+                    user_id: self.user.id,
+                    created_at: now,
+                    expires_at: now + chrono::Duration::hours(1),
+                    last_activity: now,
+                    ip_address: None,
+                    user_agent: None,
+                    is_active: true,
+                },
+                tokens: TokenPair {
+                    access_token: "access".to_string(),
+                    refresh_token: "refresh".to_string(),
+                    token_type: "Bearer".to_string(),
+                    expires_in: 3600,
+                },
+                requires_mfa: false,
+            })
+        }
+
+        async fn refresh_token(&self, _refresh_token: &str) -> Result<TokenPair> {
+            Err(Error::authentication("not supported"))
+        }
+
+        async fn validate_token(&self, _token: &str) -> Result<Claims> {
+            Err(Error::authentication("not supported"))
+        }
+
+        fn provider_type(&self) -> AuthProviderType {
+            AuthProviderType::Local
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mfa_required_before_session_starts() {
+        let secret = mfa::generate_secret();
+        let user = test_user("testuser", vec![]);
+        let credential_store = MemoryCredentialStore::new();
+        credential_store
+            .set_totp_secret(user.id, secret.clone())
+            .await
+            .unwrap();
+        let user_store = MemoryUserStore::new();
+        user_store.create_user(user.clone()).await.unwrap();
+
+        let policy = SecurityPolicy {
+            require_mfa: true,
+            max_login_attempts: 2,
+            ..SecurityPolicy::default()
+        };
+        let mut manager = AccountManager::new(
+            Box::new(MemorySessionStore::new()),
+            Box::new(user_store),
+            policy,
+        );
+        manager.set_credential_store(Box::new(credential_store));
+        manager.register_auth_provider(
+            "local".to_string(),
+            Box::new(StaticAuthProvider { user: user.clone() }),
+        );
+        let credentials = Credentials::Password {
+            username: "testuser".to_string(),
+            password: "secret".to_string(),
+        };
+
+        let challenge = manager
+            .authenticate(credentials.clone(), None)
+            .await
+            .unwrap();
+        assert!(challenge.requires_mfa);
+        assert!(challenge.tokens.access_token.is_empty());
+        assert!(manager.current_user().await.is_none());
+        assert!(challenge.user.preferences.custom_settings.is_empty());
+
+        let pending = challenge.session.id;
+        assert!(!manager.mfa_enrollment_required(pending).await.unwrap());
+        // An enrolled user cannot swap in a secret of the attacker's choosing
+        assert!(manager.enroll_mfa(pending, "Qorzen").await.is_err());
+        let code = mfa::totp_code(&secret, Time::now().timestamp()).unwrap();
+        let wrong = if code == "000000" { "111111" } else { "000000" };
+        assert!(manager.verify_mfa(pending, wrong).await.is_err());
+        let result = manager.verify_mfa(pending, &code).await.unwrap();
+        assert!(!result.requires_mfa);
+        assert_eq!(result.tokens.access_token, "access");
+        assert_eq!(manager.current_user().await.unwrap().id, user.id);
+
+        // Each pending login completes once, each code is used once, and too
+        // many bad codes cancel a login
+        assert!(manager.verify_mfa(pending, &code).await.is_err());
+        let replay = manager
+            .authenticate(credentials.clone(), None)
+            .await
+            .unwrap()
+            .session
+            .id;
+        assert!(manager.verify_mfa(replay, &code).await.is_err());
+        let pending = manager
+            .authenticate(credentials, None)
+            .await
+            .unwrap()
+            .session
+            .id;
+        assert!(manager.verify_mfa(pending, wrong).await.is_err());
+        assert!(manager.verify_mfa(pending, wrong).await.is_err());
+        assert!(manager.verify_mfa(pending, &code).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unenrolled_user_enrolls_during_mfa_challenge() {
+        let user = test_user("testuser", vec![]);
+        let user_store = MemoryUserStore::new();
+        user_store.create_user(user.clone()).await.unwrap();
+        let mut manager = AccountManager::new(
+            Box::new(MemorySessionStore::new()),
+            Box::new(user_store),
+            SecurityPolicy {
+                require_mfa: true,
+                ..SecurityPolicy::default()
+            },
+        );
+        manager.register_auth_provider(
+            "local".to_string(),
+            Box::new(StaticAuthProvider { user: user.clone() }),
+        );
+        let credentials = Credentials::Password {
+            username: "testuser".to_string(),
+            password: "secret".to_string(),
+        };
+
+        let pending = manager
+            .authenticate(credentials.clone(), None)
+            .await
+            .unwrap()
+            .session
+            .id;
+        assert!(manager.mfa_enrollment_required(pending).await.unwrap());
+        // Without a secret the login is kept for enrollment rather than failing
+        assert!(manager.verify_mfa(pending, "123456").await.is_err());
+
+        let provisioning = manager.enroll_mfa(pending, "Qorzen").await.unwrap();
+        let code = mfa::totp_code(&provisioning.secret, Time::now().timestamp()).unwrap();
+        manager.verify_mfa(pending, &code).await.unwrap();
+        assert_eq!(manager.current_user().await.unwrap().id, user.id);
+
+        let pending = manager
+            .authenticate(credentials, None)
+            .await
+            .unwrap()
+            .session
+            .id;
+        assert!(!manager.mfa_enrollment_required(pending).await.unwrap());
+        assert!(manager.enroll_mfa(pending, "Qorzen").await.is_err());
+    }
 }
@@ -0,0 +1,791 @@
+// src/auth/oauth2.rs - OAuth2 authorization code login
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::RwLock;
+
+use super::{
+    AuthProvider, AuthProviderType, AuthResult, Claims, ContactInfo, Credentials, TokenPair, User,
+    UserId, UserPreferences, UserProfile, UserSession, UserStore,
+};
+use crate::error::{Error, Result};
+use crate::utils::{rand, Time};
+
+/// Endpoints and client credentials for one OAuth2 identity provider
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OAuth2Config {
+    /// Name matched against `Credentials::OAuth2::provider`
+    pub provider: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub userinfo_endpoint: String,
+    pub redirect_uri: String,
+    #[serde(default = "default_scopes")]
+    pub scopes: Vec<String>,
+    /// How long an issued `state` stays valid
+    #[serde(default = "default_state_ttl_minutes")]
+    pub state_ttl_minutes: u64,
+    #[serde(default = "default_session_timeout_minutes")]
+    pub session_timeout_minutes: u64,
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    /// Link a first login to the local account with the same email, if the
+    /// provider reports the email as verified. Only enable this for
+    /// providers trusted to prove email ownership.
+    #[serde(default)]
+    pub link_verified_email: bool,
+}
+
+fn default_scopes() -> Vec<String> {
+    vec![
+        "openid".to_string(),
+        "profile".to_string(),
+        "email".to_string(),
+    ]
+}
+
+fn default_state_ttl_minutes() -> u64 {
+    10
+}
+
+fn default_session_timeout_minutes() -> u64 {
+    480
+}
+
+fn default_request_timeout_secs() -> u64 {
+    30
+}
+
+/// Which local user each provider identity logs in as.
+///
+/// Identities are keyed by provider name and the provider's stable `sub`
+/// claim, never by the username or email the provider reports.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait IdentityLinkStore: Send + Sync {
+    async fn linked_user(&self, provider: &str, subject: &str) -> Result<Option<UserId>>;
+    async fn link(&self, provider: &str, subject: &str, user_id: UserId) -> Result<()>;
+}
+
+/// In-memory identity links
+#[derive(Debug, Default)]
+pub struct MemoryIdentityLinkStore {
+    links: RwLock<HashMap<(String, String), UserId>>,
+}
+
+impl MemoryIdentityLinkStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl IdentityLinkStore for MemoryIdentityLinkStore {
+    async fn linked_user(&self, provider: &str, subject: &str) -> Result<Option<UserId>> {
+        Ok(self
+            .links
+            .read()
+            .await
+            .get(&(provider.to_string(), subject.to_string()))
+            .copied())
+    }
+
+    async fn link(&self, provider: &str, subject: &str, user_id: UserId) -> Result<()> {
+        self.links
+            .write()
+            .await
+            .insert((provider.to_string(), subject.to_string()), user_id);
+        Ok(())
+    }
+}
+
+/// Tokens returned by the provider's token endpoint
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    refresh_token: Option<String>,
+    #[serde(default)]
+    token_type: Option<String>,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+impl TokenResponse {
+    fn into_pair(self, previous_refresh_token: Option<&str>) -> TokenPair {
+        TokenPair {
+            access_token: self.access_token,
+            refresh_token: self
+                .refresh_token
+                .or_else(|| previous_refresh_token.map(str::to_string))
+                .unwrap_or_default(),
+            token_type: self.token_type.unwrap_or_else(|| "Bearer".to_string()),
+            expires_in: self.expires_in.unwrap_or(3600),
+        }
+    }
+}
+
+/// Logs users in with the OAuth2 authorization code flow.
+///
+/// Call [`Self::authorization_url`] to send the user to the provider, then pass
+/// the `code` and `state` it redirects back with as [`Credentials::OAuth2`].
+/// Each state is accepted once and only before it expires, so a login cannot
+/// be completed with a callback the app did not start.
+///
+/// A returning identity logs in as the user its `sub` claim was linked to.
+/// A new identity gets a new user, unless [`OAuth2Config::link_verified_email`]
+/// allows linking it to the account with the same verified email. An identity
+/// whose email belongs to another account is refused rather than merged, and
+/// a reported username that is already taken is not reused.
+pub struct OAuth2AuthProvider {
+    config: OAuth2Config,
+    user_store: Arc<dyn UserStore>,
+    links: Arc<dyn IdentityLinkStore>,
+    pending_states: Mutex<HashMap<String, DateTime<Utc>>>,
+}
+
+impl std::fmt::Debug for OAuth2AuthProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuth2AuthProvider")
+            .field("provider", &self.config.provider)
+            .field("token_endpoint", &self.config.token_endpoint)
+            .finish()
+    }
+}
+
+impl OAuth2AuthProvider {
+    pub fn new(
+        config: OAuth2Config,
+        user_store: Arc<dyn UserStore>,
+        links: Arc<dyn IdentityLinkStore>,
+    ) -> Self {
+        Self {
+            config,
+            user_store,
+            links,
+            pending_states: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn config(&self) -> &OAuth2Config {
+        &self.config
+    }
+
+    /// URL to send the user to, with a fresh `state` recorded for the callback
+    pub fn authorization_url(&self) -> String {
+        let state = rand::secure_token(32);
+        let expires_at = Time::now() + Duration::minutes(self.config.state_ttl_minutes as i64);
+        {
+            let mut pending = self.lock_states();
+            let now = Time::now();
+            pending.retain(|_, expiry| *expiry > now);
+            pending.insert(state.clone(), expires_at);
+        }
+
+        let scope = self.config.scopes.join(" ");
+        let separator = if self.config.authorization_endpoint.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        format!(
+            "{}{}{}",
+            self.config.authorization_endpoint,
+            separator,
+            form_encode(&[
+                ("response_type", "code"),
+                ("client_id", self.config.client_id.as_str()),
+                ("redirect_uri", self.config.redirect_uri.as_str()),
+                ("scope", scope.as_str()),
+                ("state", state.as_str()),
+            ])
+        )
+    }
+
+    /// Consumes `state` if this provider issued it and it has not expired
+    fn take_state(&self, state: Option<&str>) -> Result<()> {
+        let state =
+            state.ok_or_else(|| Error::authentication("OAuth2 callback is missing state"))?;
+        match self.lock_states().remove(state) {
+            Some(expires_at) if expires_at > Time::now() => Ok(()),
+            Some(_) => Err(Error::authentication("OAuth2 state has expired")),
+            None => Err(Error::authentication("OAuth2 state does not match a login")),
+        }
+    }
+
+    fn lock_states(&self) -> std::sync::MutexGuard<'_, HashMap<String, DateTime<Utc>>> {
+        self.pending_states
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn request_tokens(&self, params: &[(&str, &str)]) -> Result<TokenResponse> {
+        let mut form = vec![
+            ("client_id", self.config.client_id.as_str()),
+            ("client_secret", self.config.client_secret.as_str()),
+        ];
+        form.extend_from_slice(params);
+
+        let response = self
+            .send(
+                "POST",
+                &self.config.token_endpoint,
+                &[("Content-Type", "application/x-www-form-urlencoded")],
+                Some(form_encode(&form)),
+            )
+            .await?;
+        serde_json::from_value(response)
+            .map_err(|e| Error::authentication(format!("Invalid OAuth2 token response: {}", e)))
+    }
+
+    async fn fetch_userinfo(&self, access_token: &str) -> Result<Value> {
+        let authorization = format!("Bearer {}", access_token);
+        self.send(
+            "GET",
+            &self.config.userinfo_endpoint,
+            &[
+                ("Authorization", authorization.as_str()),
+                ("Accept", "application/json"),
+            ],
+            None,
+        )
+        .await
+    }
+
+    /// The user linked to the identity in `claims`
+    async fn linked_user(&self, claims: &Value) -> Result<Option<User>> {
+        let Some(user_id) = self
+            .links
+            .linked_user(&self.config.provider, subject(claims)?)
+            .await?
+        else {
+            return Ok(None);
+        };
+        self.user_store
+            .get_user(user_id)
+            .await?
+            .map(Some)
+            .ok_or_else(|| Error::authentication("Linked OAuth2 user no longer exists"))
+    }
+
+    /// The stored user for `claims`, created and linked on first login
+    async fn resolve_user(&self, claims: &Value) -> Result<User> {
+        if let Some(user) = self.linked_user(claims).await? {
+            return self.record_login(user).await;
+        }
+
+        let subject = subject(claims)?;
+        let mut user = user_from_claims(claims)?;
+        if !user.email.is_empty() {
+            if let Some(existing) = self.user_store.get_user_by_email(&user.email).await? {
+                if !(self.config.link_verified_email && email_verified(claims)) {
+                    return Err(Error::authentication(
+                        "An account with this email already exists and is not linked to this provider",
+                    ));
+                }
+                self.links
+                    .link(&self.config.provider, subject, existing.id)
+                    .await?;
+                return self.record_login(existing).await;
+            }
+        }
+
+        if self
+            .user_store
+            .get_user_by_username(&user.username)
+            .await?
+            .is_some()
+        {
+            user.username = format!("{}:{}", self.config.provider, subject);
+        }
+        user.last_login = Some(Time::now());
+        self.user_store.create_user(user.clone()).await?;
+        self.links
+            .link(&self.config.provider, subject, user.id)
+            .await?;
+        Ok(user)
+    }
+
+    async fn record_login(&self, mut user: User) -> Result<User> {
+        if !user.is_active {
+            return Err(Error::authentication("User account is disabled"));
+        }
+        user.last_login = Some(Time::now());
+        self.user_store.update_user(user.clone()).await?;
+        Ok(user)
+    }
+
+    /// Sends a request and parses the JSON body of a successful response
+    async fn send(
+        &self,
+        method: &str,
+        url: &str,
+        headers: &[(&str, &str)],
+        body: Option<String>,
+    ) -> Result<Value> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let client = reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(
+                    self.config.request_timeout_secs,
+                ))
+                .build()
+                .map_err(|e| {
+                    Error::platform(
+                        "native",
+                        "network",
+                        format!("Failed to create HTTP client: {}", e),
+                    )
+                })?;
+            let method = reqwest::Method::from_bytes(method.as_bytes()).map_err(|e| {
+                Error::platform("native", "network", format!("Invalid HTTP method: {}", e))
+            })?;
+
+            let mut request = client.request(method, url);
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            if let Some(body) = body {
+                request = request.body(body);
+            }
+
+            let response = request.send().await.map_err(|e| {
+                Error::platform("native", "network", format!("OAuth2 request failed: {}", e))
+            })?;
+            let status = response.status();
+            let text = response.text().await.map_err(|e| {
+                Error::platform(
+                    "native",
+                    "network",
+                    format!("Failed to read OAuth2 response: {}", e),
+                )
+            })?;
+            parse_response(status.is_success(), status.as_u16(), &text)
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::JsCast;
+            use wasm_bindgen_futures::JsFuture;
+            use web_sys::{Request, RequestInit, Response};
+
+            let window = web_sys::window()
+                .ok_or_else(|| Error::platform("web", "network", "No window object available"))?;
+
+            // Everything non-Send is scoped and dropped before .await
+            let fetch_promise = {
+                let opts = RequestInit::new();
+                opts.set_method(method);
+                if let Some(body) = &body {
+                    opts.set_body(&wasm_bindgen::JsValue::from_str(body));
+                }
+
+                let request = Request::new_with_str_and_init(url, &opts).map_err(|e| {
+                    Error::platform(
+                        "web",
+                        "network",
+                        format!("Failed to create request: {:?}", e),
+                    )
+                })?;
+                for (name, value) in headers {
+                    request.headers().set(name, value).map_err(|e| {
+                        Error::platform("web", "network", format!("Invalid header: {:?}", e))
+                    })?;
+                }
+                window.fetch_with_request(&request)
+            };
+
+            let response: Response = JsFuture::from(fetch_promise)
+                .await
+                .map_err(|e| Error::platform("web", "network", format!("Fetch failed: {:?}", e)))?
+                .dyn_into()
+                .map_err(|_| Error::platform("web", "network", "Invalid response object"))?;
+
+            let text_promise = response.text().map_err(|e| {
+                Error::platform(
+                    "web",
+                    "network",
+                    format!("Failed to read OAuth2 response: {:?}", e),
+                )
+            })?;
+            let text = JsFuture::from(text_promise)
+                .await
+                .map_err(|e| {
+                    Error::platform(
+                        "web",
+                        "network",
+                        format!("Failed to read OAuth2 response: {:?}", e),
+                    )
+                })?
+                .as_string()
+                .unwrap_or_default();
+            parse_response(response.ok(), response.status(), &text)
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl AuthProvider for OAuth2AuthProvider {
+    async fn authenticate(&self, credentials: &Credentials) -> Result<AuthResult> {
+        let Credentials::OAuth2 {
+            provider,
+            code,
+            state,
+        } = credentials
+        else {
+            return Err(Error::authentication(
+                "OAuth2 provider only accepts OAuth2 credentials",
+            ));
+        };
+        if *provider != self.config.provider {
+            return Err(Error::authentication(format!(
+                "OAuth2 credentials are for '{}', not '{}'",
+                provider, self.config.provider
+            )));
+        }
+        self.take_state(state.as_deref())?;
+
+        let tokens = self
+            .request_tokens(&[
+                ("grant_type", "authorization_code"),
+                ("code", code.as_str()),
+                ("redirect_uri", self.config.redirect_uri.as_str()),
+            ])
+            .await?
+            .into_pair(None);
+        let claims = self.fetch_userinfo(&tokens.access_token).await?;
+        let user = self.resolve_user(&claims).await?;
+
+        let now = Time::now();
+        let session = UserSession {
+            id: rand::uuid(),
+            user_id: user.id,
+            created_at: now,
+            expires_at: now + Duration::minutes(self.config.session_timeout_minutes as i64),
+            last_activity: now,
+            ip_address: None,
+            user_agent: None,
+            is_active: true,
+        };
+
+        Ok(AuthResult {
+            user,
+            session,
+            tokens,
+            requires_mfa: false,
+        })
+    }
+
+    async fn refresh_token(&self, refresh_token: &str) -> Result<TokenPair> {
+        Ok(self
+            .request_tokens(&[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token),
+            ])
+            .await?
+            .into_pair(Some(refresh_token)))
+    }
+
+    /// Checks the access token against the userinfo endpoint.
+    ///
+    /// The token is opaque to this app, so the claims describe the matching
+    /// stored user and expire after the session timeout unless the provider
+    /// reports an `exp`.
+    async fn validate_token(&self, token: &str) -> Result<Claims> {
+        let claims = self.fetch_userinfo(token).await?;
+        let user = self
+            .linked_user(&claims)
+            .await?
+            .ok_or_else(|| Error::authentication("OAuth2 token belongs to an unknown user"))?;
+
+        let iat = Time::now().timestamp();
+        let exp = claims
+            .get("exp")
+            .and_then(Value::as_i64)
+            .unwrap_or(iat + self.config.session_timeout_minutes as i64 * 60);
+
+        Ok(Claims {
+            sub: user.id.to_string(),
+            iat,
+            exp,
+            aud: self.config.client_id.clone(),
+            iss: self.config.provider.clone(),
+            roles: user.roles.iter().map(|r| r.id.clone()).collect(),
+            permissions: user
+                .permissions
+                .iter()
+                .map(|p| format!("{}:{}", p.resource, p.action))
+                .collect(),
+        })
+    }
+
+    fn provider_type(&self) -> AuthProviderType {
+        AuthProviderType::OAuth2 {
+            provider: self.config.provider.clone(),
+        }
+    }
+}
+
+/// Builds a new user from OpenID Connect style userinfo claims.
+///
+/// The username is `preferred_username`, falling back to `email` and then
+/// `sub`; a response with none of them is rejected.
+pub fn user_from_claims(claims: &Value) -> Result<User> {
+    let claim = |name: &str| {
+        claims
+            .get(name)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+
+    let email = claim("email").unwrap_or_default();
+    let username = claim("preferred_username")
+        .or_else(|| (!email.is_empty()).then(|| email.clone()))
+        .or_else(|| claim("sub"))
+        .ok_or_else(|| Error::authentication("OAuth2 userinfo has no usable identity"))?;
+
+    Ok(User {
+        id: rand::uuid(),
+        email,
+        roles: Vec::new(),
+        permissions: Vec::new(),
+        preferences: UserPreferences {
+            language: claim("locale").unwrap_or_else(|| UserPreferences::default().language),
+            timezone: claim("zoneinfo").unwrap_or_else(|| UserPreferences::default().timezone),
+            ..UserPreferences::default()
+        },
+        profile: UserProfile {
+            display_name: claim("name").unwrap_or_else(|| username.clone()),
+            avatar_url: claim("picture"),
+            bio: None,
+            department: None,
+            title: None,
+            contact_info: ContactInfo {
+                phone: claim("phone_number"),
+                address: None,
+                emergency_contact: None,
+            },
+        },
+        username,
+        created_at: Time::now(),
+        last_login: None,
+        is_active: true,
+    })
+}
+
+/// The provider's stable identifier for the user
+fn subject(claims: &Value) -> Result<&str> {
+    claims
+        .get("sub")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|sub| !sub.is_empty())
+        .ok_or_else(|| Error::authentication("OAuth2 userinfo has no subject"))
+}
+
+fn email_verified(claims: &Value) -> bool {
+    match claims.get("email_verified") {
+        Some(Value::Bool(verified)) => *verified,
+        // Some providers send the flag as a string
+        Some(Value::String(verified)) => verified == "true",
+        _ => false,
+    }
+}
+
+fn parse_response(success: bool, status: u16, body: &str) -> Result<Value> {
+    if !success {
+        // Providers report errors as `{"error": ..., "error_description": ...}`
+        let detail = serde_json::from_str::<Value>(body)
+            .ok()
+            .and_then(|v| {
+                v.get("error_description")
+                    .or_else(|| v.get("error"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| format!("status {}", status));
+        return Err(Error::authentication(format!(
+            "OAuth2 provider rejected the request: {}",
+            detail
+        )));
+    }
+
+    serde_json::from_str(body)
+        .map_err(|e| Error::authentication(format!("Invalid OAuth2 response: {}", e)))
+}
+
+/// Encodes pairs as `application/x-www-form-urlencoded`
+fn form_encode(pairs: &[(&str, &str)]) -> String {
+    fn encode(value: &str) -> String {
+        value
+            .bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                    (b as char).to_string()
+                }
+                b' ' => "+".to_string(),
+                _ => format!("%{:02X}", b),
+            })
+            .collect()
+    }
+
+    pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::MemoryUserStore;
+
+    fn provider() -> OAuth2AuthProvider {
+        provider_with_store(Arc::new(MemoryUserStore::new()), false)
+    }
+
+    fn provider_with_store(
+        user_store: Arc<dyn UserStore>,
+        link_verified_email: bool,
+    ) -> OAuth2AuthProvider {
+        OAuth2AuthProvider::new(
+            OAuth2Config {
+                provider: "example".to_string(),
+                client_id: "client".to_string(),
+                client_secret: "secret".to_string(),
+                authorization_endpoint: "https://id.example.com/authorize".to_string(),
+                token_endpoint: "https://id.example.com/token".to_string(),
+                userinfo_endpoint: "https://id.example.com/userinfo".to_string(),
+                redirect_uri: "https://app.example.com/callback".to_string(),
+                scopes: default_scopes(),
+                state_ttl_minutes: default_state_ttl_minutes(),
+                session_timeout_minutes: default_session_timeout_minutes(),
+                request_timeout_secs: default_request_timeout_secs(),
+                link_verified_email,
+            },
+            user_store,
+            Arc::new(MemoryIdentityLinkStore::new()),
+        )
+    }
+
+    #[test]
+    fn test_authorization_url_issues_single_use_state() {
+        let provider = provider();
+        let url = provider.authorization_url();
+        assert!(url.starts_with("https://id.example.com/authorize?response_type=code"));
+        assert!(url.contains("redirect_uri=https%3A%2F%2Fapp.example.com%2Fcallback"));
+        assert!(url.contains("scope=openid+profile+email"));
+
+        let state = url.rsplit("state=").next().unwrap().to_string();
+        assert!(provider.take_state(Some(&state)).is_ok());
+        assert!(provider.take_state(Some(&state)).is_err());
+        assert!(provider.take_state(None).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_forged_state_is_rejected_before_token_exchange() {
+        let provider = provider();
+        provider.authorization_url();
+
+        let credentials = Credentials::OAuth2 {
+            provider: "example".to_string(),
+            code: "code".to_string(),
+            state: Some("forged".to_string()),
+        };
+        let err = provider.authenticate(&credentials).await.unwrap_err();
+        assert!(err.message.contains("state"));
+
+        let credentials = Credentials::OAuth2 {
+            provider: "other".to_string(),
+            code: "code".to_string(),
+            state: None,
+        };
+        assert!(provider.authenticate(&credentials).await.is_err());
+    }
+
+    #[test]
+    fn test_user_from_claims() {
+        let user = user_from_claims(&serde_json::json!({
+            "sub": "1234",
+            "email": "ada@example.com",
+            "name": "Ada Lovelace",
+            "picture": "https://example.com/ada.png"
+        }))
+        .unwrap();
+        assert_eq!(user.username, "ada@example.com");
+        assert_eq!(user.email, "ada@example.com");
+        assert_eq!(user.profile.display_name, "Ada Lovelace");
+        assert_eq!(
+            user.profile.avatar_url.as_deref(),
+            Some("https://example.com/ada.png")
+        );
+
+        let user = user_from_claims(&serde_json::json!({ "sub": "1234" })).unwrap();
+        assert_eq!(user.username, "1234");
+        assert!(user.email.is_empty());
+
+        assert!(user_from_claims(&serde_json::json!({})).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_identities_link_by_subject_not_username_or_email() {
+        let store: Arc<dyn UserStore> = Arc::new(MemoryUserStore::new());
+        let mut admin = user_from_claims(&serde_json::json!({
+            "sub": "local",
+            "preferred_username": "admin",
+            "email": "admin@example.com"
+        }))
+        .unwrap();
+        admin.roles.push(crate::auth::Role {
+            id: "admin".to_string(),
+            name: "Administrator".to_string(),
+            description: String::new(),
+            permissions: vec![],
+            ui_layout: None,
+            is_system_role: true,
+        });
+        store.create_user(admin.clone()).await.unwrap();
+        let provider = provider_with_store(Arc::clone(&store), false);
+
+        // A provider user calling themselves "admin" gets a new account
+        let user = provider
+            .resolve_user(&serde_json::json!({ "sub": "42", "preferred_username": "admin" }))
+            .await
+            .unwrap();
+        assert_ne!(user.id, admin.id);
+        assert_eq!(user.username, "example:42");
+        assert!(user.roles.is_empty());
+
+        // The same subject logs in as that account again, whatever it reports
+        let again = provider
+            .resolve_user(&serde_json::json!({ "sub": "42", "preferred_username": "root" }))
+            .await
+            .unwrap();
+        assert_eq!(again.id, user.id);
+
+        // Claiming the admin's email is refused, verified or not, unless linking is enabled
+        let claims = serde_json::json!({
+            "sub": "99",
+            "email": "admin@example.com",
+            "email_verified": true
+        });
+        assert!(provider.resolve_user(&claims).await.is_err());
+
+        let linking = provider_with_store(Arc::clone(&store), true);
+        let unverified = serde_json::json!({ "sub": "99", "email": "admin@example.com" });
+        assert!(linking.resolve_user(&unverified).await.is_err());
+        assert_eq!(linking.resolve_user(&claims).await.unwrap().id, admin.id);
+    }
+}
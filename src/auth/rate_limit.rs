@@ -0,0 +1,419 @@
+// src/auth/rate_limit.rs - Rate limit buckets shared by login throttling and API routes
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::utils::Time;
+
+/// Key prefix for failed login attempts, followed by the username
+pub const LOGIN_BUCKET_PREFIX: &str = "login:";
+/// Key prefix for plugin API routes, followed by `<plugin>:<METHOD> <path>:<client>`
+pub const ROUTE_BUCKET_PREFIX: &str = "route:";
+
+/// Buckets a [`RateLimiterStore`] keeps before evicting
+pub const DEFAULT_MAX_BUCKETS: usize = 100_000;
+
+/// Bucket key for failed logins of `username`
+pub fn login_bucket_key(username: &str) -> String {
+    format!("{}{}", LOGIN_BUCKET_PREFIX, username.to_lowercase())
+}
+
+/// Bucket key for one client of a plugin API route
+pub fn route_bucket_key(plugin_id: &str, method: &str, path: &str, client: &str) -> String {
+    format!(
+        "{}{}:{} {}:{}",
+        ROUTE_BUCKET_PREFIX,
+        plugin_id,
+        method.to_uppercase(),
+        path,
+        client
+    )
+}
+
+/// Snapshot of one rate limit bucket, as reported to admins
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitBucket {
+    pub key: String,
+    pub capacity: u32,
+    pub remaining: u32,
+    /// When the bucket will be full again
+    pub reset_at: DateTime<Utc>,
+}
+
+impl RateLimitBucket {
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// Token bucket parameters: `capacity` hits at once, refilled with one
+/// token every `refill_every`; `None` never refills
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenBucketLimit {
+    pub capacity: u32,
+    pub refill_every: Option<std::time::Duration>,
+}
+
+impl TokenBucketLimit {
+    /// `capacity` tokens that take `window` to refill from empty
+    pub fn per_window(capacity: u32, window: std::time::Duration) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            refill_every: (!window.is_zero()).then(|| window / capacity),
+        }
+    }
+
+    /// `capacity` tokens refilled at `per_minute` tokens a minute
+    pub fn per_minute(capacity: u32, per_minute: u32) -> Self {
+        Self {
+            capacity,
+            refill_every: (per_minute > 0).then(|| std::time::Duration::from_secs(60) / per_minute),
+        }
+    }
+}
+
+/// Outcome of asking a [`RateLimitBackend`] for a token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateDecision {
+    Allowed {
+        /// Whole tokens left after this hit
+        remaining: u32,
+    },
+    Limited {
+        /// How long until the next token is available
+        retry_after: std::time::Duration,
+    },
+}
+
+impl RateDecision {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, Self::Allowed { .. })
+    }
+}
+
+/// Where token buckets live.
+///
+/// [`RateLimiterStore`] keeps them in process memory; a backend shared
+/// between servers can be swapped in without changing the callers.
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait RateLimitBackend: Send + Sync {
+    /// Takes one token from `key`'s bucket, creating it full on first use
+    async fn acquire(&self, key: &str, limit: TokenBucketLimit) -> Result<RateDecision>;
+}
+
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    limit: TokenBucketLimit,
+    tokens: f64,
+    updated_at: DateTime<Utc>,
+}
+
+impl TokenBucket {
+    fn full(limit: TokenBucketLimit, now: DateTime<Utc>) -> Self {
+        Self {
+            limit,
+            tokens: f64::from(limit.capacity),
+            updated_at: now,
+        }
+    }
+
+    /// Tokens the bucket holds at `now`, without touching it
+    fn tokens_at(&self, now: DateTime<Utc>) -> f64 {
+        match self.limit.refill_every {
+            Some(every) => {
+                let elapsed = (now - self.updated_at).to_std().unwrap_or_default();
+                (self.tokens + elapsed.as_secs_f64() / every.as_secs_f64())
+                    .min(f64::from(self.limit.capacity))
+            }
+            None => self.tokens,
+        }
+    }
+
+    fn refill(&mut self, now: DateTime<Utc>) {
+        self.tokens = self.tokens_at(now);
+        self.updated_at = now;
+    }
+
+    /// Time until the bucket holds `tokens`; a minute if it never refills
+    fn time_until(&self, tokens: f64) -> std::time::Duration {
+        let missing = (tokens - self.tokens).max(0.0);
+        if missing <= 0.0 {
+            return std::time::Duration::ZERO;
+        }
+        match self.limit.refill_every {
+            Some(every) => every.mul_f64(missing),
+            None => std::time::Duration::from_secs(60),
+        }
+    }
+
+    fn decision(&self) -> RateDecision {
+        if self.tokens < 1.0 {
+            RateDecision::Limited {
+                retry_after: self.time_until(1.0),
+            }
+        } else {
+            RateDecision::Allowed {
+                remaining: self.tokens.floor() as u32,
+            }
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.tokens >= f64::from(self.limit.capacity)
+    }
+}
+
+/// In-memory token buckets keyed by subject, for both login throttling and
+/// API routes. Clones share the same buckets.
+///
+/// Once `max_buckets` subjects are tracked, adding another drops the full
+/// buckets, which behave like missing ones, and then the least recently used.
+#[derive(Debug, Clone)]
+pub struct RateLimiterStore {
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
+    max_buckets: usize,
+}
+
+impl Default for RateLimiterStore {
+    fn default() -> Self {
+        Self {
+            buckets: Arc::default(),
+            max_buckets: DEFAULT_MAX_BUCKETS,
+        }
+    }
+}
+
+impl RateLimiterStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `max_buckets` buckets
+    pub fn with_max_buckets(mut self, max_buckets: usize) -> Self {
+        self.max_buckets = max_buckets.max(1);
+        self
+    }
+
+    /// The process-wide store used by the account manager, plugin routes and admin UI
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<RateLimiterStore> = OnceLock::new();
+        SHARED.get_or_init(Self::new).clone()
+    }
+
+    /// Takes a token from `key`'s token bucket, creating it full on first use
+    pub fn take_token(&self, key: &str, limit: TokenBucketLimit) -> RateDecision {
+        let now = Time::now();
+        let mut buckets = self.lock();
+        if buckets.len() >= self.max_buckets && !buckets.contains_key(key) {
+            evict_buckets(&mut buckets, self.max_buckets, now);
+        }
+        let bucket = buckets
+            .entry(key.to_string())
+            .or_insert_with(|| TokenBucket::full(limit, now));
+        bucket.limit = limit;
+        bucket.refill(now);
+
+        let decision = bucket.decision();
+        if decision.is_allowed() {
+            bucket.tokens -= 1.0;
+            return RateDecision::Allowed {
+                remaining: bucket.tokens.floor() as u32,
+            };
+        }
+        decision
+    }
+
+    /// Whether `key`'s bucket has a token, without taking it
+    pub fn peek(&self, key: &str) -> Option<RateDecision> {
+        let now = Time::now();
+        let mut buckets = self.lock();
+        let bucket = buckets.get_mut(key)?;
+        bucket.refill(now);
+        Some(bucket.decision())
+    }
+
+    /// Current state of a bucket; `None` when it is full
+    pub fn get(&self, key: &str) -> Option<RateLimitBucket> {
+        let now = Time::now();
+        let mut buckets = self.lock();
+        let bucket = buckets.get_mut(key)?;
+        bucket.refill(now);
+        (!bucket.is_full()).then(|| snapshot(key, bucket, now))
+    }
+
+    /// Active buckets whose key starts with `prefix`, sorted by key
+    pub fn list(&self, prefix: &str) -> Vec<RateLimitBucket> {
+        let now = Time::now();
+        let mut buckets = self.lock();
+        buckets.retain(|_, bucket| {
+            bucket.refill(now);
+            !bucket.is_full()
+        });
+
+        let mut listed: Vec<RateLimitBucket> = buckets
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, bucket)| snapshot(key, bucket, now))
+            .collect();
+        listed.sort_by(|a, b| a.key.cmp(&b.key));
+        listed
+    }
+
+    /// Restores a bucket to full quota, returning whether it existed
+    pub fn reset(&self, key: &str) -> bool {
+        self.lock().remove(key).is_some()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, TokenBucket>> {
+        self.buckets.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl RateLimitBackend for RateLimiterStore {
+    async fn acquire(&self, key: &str, limit: TokenBucketLimit) -> Result<RateDecision> {
+        Ok(self.take_token(key, limit))
+    }
+}
+
+/// Drops full buckets, then the least recently used ones until an eighth of
+/// `max_buckets` is free, so a flood of new subjects evicts in batches
+fn evict_buckets(
+    buckets: &mut HashMap<String, TokenBucket>,
+    max_buckets: usize,
+    now: DateTime<Utc>,
+) {
+    buckets.retain(|_, bucket| bucket.tokens_at(now) < f64::from(bucket.limit.capacity));
+
+    let target = max_buckets.saturating_sub((max_buckets / 8).max(1));
+    if buckets.len() > target {
+        let mut by_use: Vec<(DateTime<Utc>, String)> = buckets
+            .iter()
+            .map(|(key, bucket)| (bucket.updated_at, key.clone()))
+            .collect();
+        by_use.sort_unstable();
+        let excess = buckets.len() - target;
+        for (_, key) in by_use.into_iter().take(excess) {
+            buckets.remove(&key);
+        }
+    }
+}
+
+/// Buckets are reported as resetting when they will be full again
+fn snapshot(key: &str, bucket: &TokenBucket, now: DateTime<Utc>) -> RateLimitBucket {
+    let until_full = bucket.time_until(f64::from(bucket.limit.capacity));
+    RateLimitBucket {
+        key: key.to_string(),
+        capacity: bucket.limit.capacity,
+        remaining: bucket.tokens.floor() as u32,
+        reset_at: now + Duration::from_std(until_full).unwrap_or_else(|_| Duration::minutes(1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listing_and_reset() {
+        let store = RateLimiterStore::new();
+        let login = login_bucket_key("Alice");
+        let route = route_bucket_key("catalog", "get", "/api/products", "10.0.0.1");
+        let lockout = TokenBucketLimit::per_window(3, std::time::Duration::from_secs(30 * 60));
+
+        for _ in 0..3 {
+            assert!(store.take_token(&login, lockout).is_allowed());
+        }
+        let Some(RateDecision::Limited { retry_after }) = store.peek(&login) else {
+            panic!("login bucket should be exhausted");
+        };
+        // One attempt comes back after a third of the lockout
+        assert!(retry_after <= std::time::Duration::from_secs(10 * 60));
+        store.take_token(&route, TokenBucketLimit::per_minute(60, 60));
+
+        let buckets = store.list("");
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].key, "login:alice");
+        assert!(buckets[0].is_exhausted());
+        assert_eq!(buckets[1].key, "route:catalog:GET /api/products:10.0.0.1");
+        assert_eq!(buckets[1].remaining, 59);
+        assert_eq!(store.list(LOGIN_BUCKET_PREFIX).len(), 1);
+
+        // Resetting unlocks the subject with its full quota
+        assert!(store.reset(&login));
+        assert!(store.get(&login).is_none());
+        assert!(store.peek(&login).is_none());
+        assert_eq!(
+            store.take_token(&login, lockout),
+            RateDecision::Allowed { remaining: 2 }
+        );
+        assert!(!store.reset("login:nobody"));
+    }
+
+    #[test]
+    fn test_token_bucket_allows_burst_then_refills() {
+        let store = RateLimiterStore::new();
+        let key = route_bucket_key("catalog", "get", "/api/products", "user-1");
+        let limit = TokenBucketLimit::per_minute(3, 60);
+
+        for remaining in [2, 1, 0] {
+            assert_eq!(
+                store.take_token(&key, limit),
+                RateDecision::Allowed { remaining }
+            );
+        }
+        let RateDecision::Limited { retry_after } = store.take_token(&key, limit) else {
+            panic!("bucket should be empty");
+        };
+        assert!(retry_after <= std::time::Duration::from_secs(1));
+        assert_eq!(store.list(ROUTE_BUCKET_PREFIX)[0].remaining, 0);
+
+        // A bucket that never refills asks callers to wait a minute
+        let frozen = TokenBucketLimit::per_minute(1, 0);
+        assert!(store.take_token("route:frozen", frozen).is_allowed());
+        assert_eq!(
+            store.take_token("route:frozen", frozen),
+            RateDecision::Limited {
+                retry_after: std::time::Duration::from_secs(60)
+            }
+        );
+
+        assert!(store.reset(&key));
+        assert!(store.take_token(&key, limit).is_allowed());
+    }
+
+    #[test]
+    fn test_store_evicts_when_full() {
+        let store = RateLimiterStore::new().with_max_buckets(8);
+
+        // Buckets that refill are dropped once they are full again
+        let quick = TokenBucketLimit::per_window(1, std::time::Duration::from_millis(5));
+        for i in 0..8 {
+            assert!(store
+                .take_token(&format!("route:quick-{}", i), quick)
+                .is_allowed());
+        }
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        assert!(store.take_token("route:next", quick).is_allowed());
+        assert_eq!(store.lock().len(), 1);
+
+        // Buckets that never refill are dropped least recently used first
+        let frozen = TokenBucketLimit::per_minute(2, 0);
+        for i in 0..16 {
+            assert!(store
+                .take_token(&format!("login:user-{:02}", i), frozen)
+                .is_allowed());
+            assert!(store.lock().len() <= 8);
+        }
+        assert!(store.peek("login:user-00").is_none());
+        assert!(store.peek("login:user-15").is_some());
+    }
+}
@@ -645,7 +645,7 @@
 //         vec![
 //             ApiRoute {
 //                 path: "/api/plugins/todo/items".to_string(),
-//                 method: HttpMethod::GET,
+//                 method: HttpMethod::Get,
 //                 handler_id: "list_items".to_string(),
 //                 required_permissions: vec![
 //                     Permission {
@@ -699,7 +699,7 @@
 //             },
 //             ApiRoute {
 //                 path: "/api/plugins/todo/items".to_string(),
-//                 method: HttpMethod::POST,
+//                 method: HttpMethod::Post,
 //                 handler_id: "create_item".to_string(),
 //                 required_permissions: vec![
 //                     Permission {
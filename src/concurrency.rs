@@ -13,7 +13,8 @@
 
 use std::collections::HashMap;
 use std::future::Future;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use std::{fmt, thread};
@@ -22,7 +23,7 @@ use async_trait::async_trait;
 use crossbeam::queue::SegQueue;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
-use tokio::sync::{mpsc, oneshot, Semaphore};
+use tokio::sync::{mpsc, oneshot, OwnedSemaphorePermit, Semaphore};
 use uuid::Uuid;
 
 use crate::config::ConcurrencyConfig;
@@ -577,6 +578,104 @@ impl AsyncWorkCoordinator {
     }
 }
 
+/// The result of a task started with one of the `ConcurrencyManager::spawn_*` methods.
+///
+/// Awaiting the handle yields the task's return value. Dropping it does not
+/// cancel the task; its result is discarded.
+#[derive(Debug)]
+pub struct TaskHandle<R> {
+    receiver: oneshot::Receiver<R>,
+}
+
+impl<R> Future for TaskHandle<R> {
+    type Output = Result<R>;
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        Pin::new(&mut self.receiver).poll(cx).map(|result| {
+            result.map_err(|_| {
+                Error::new(
+                    ErrorKind::Concurrency {
+                        thread_id: None,
+                        operation: ConcurrencyOperation::ThreadPool,
+                    },
+                    "Task panicked or was dropped before completing",
+                )
+            })
+        })
+    }
+}
+
+/// Load on a pool's governed task queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskQueueMetrics {
+    /// Tasks accepted but not yet running
+    pub queued: usize,
+    /// Tasks currently running on the pool's threads
+    pub active: usize,
+    /// Most tasks accepted at once: the pool's threads plus `max_queue_size`
+    pub capacity: usize,
+    /// Tasks refused with a queue-full error
+    pub rejected: u64,
+}
+
+/// Bounds the tasks outstanding on one pool, counting those running and queued
+#[derive(Debug)]
+struct TaskGate {
+    capacity: usize,
+    permits: Arc<Semaphore>,
+    active: Arc<AtomicUsize>,
+    rejected: AtomicU64,
+}
+
+impl TaskGate {
+    fn new(threads: usize, max_queue_size: usize) -> Self {
+        let capacity = threads + max_queue_size;
+        Self {
+            capacity,
+            permits: Arc::new(Semaphore::new(capacity)),
+            active: Arc::new(AtomicUsize::new(0)),
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    fn metrics(&self) -> TaskQueueMetrics {
+        let outstanding = self.capacity - self.permits.available_permits();
+        let active = self.active.load(Ordering::SeqCst);
+        TaskQueueMetrics {
+            queued: outstanding.saturating_sub(active),
+            active,
+            capacity: self.capacity,
+            rejected: self.rejected.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Decrements the active count when a task finishes, even by panicking
+struct ActiveTask(Arc<AtomicUsize>);
+
+impl ActiveTask {
+    fn start(active: &Arc<AtomicUsize>) -> Self {
+        active.fetch_add(1, Ordering::SeqCst);
+        Self(Arc::clone(active))
+    }
+}
+
+impl Drop for ActiveTask {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Pools whose queues are bounded by `ConcurrencyConfig::max_queue_size`
+const GOVERNED_POOLS: [ThreadPoolType; 3] = [
+    ThreadPoolType::Compute,
+    ThreadPoolType::Io,
+    ThreadPoolType::Blocking,
+];
+
 #[derive(Debug)]
 pub struct ConcurrencyManager {
     state: ManagedState,
@@ -584,6 +683,7 @@ pub struct ConcurrencyManager {
     config: ConcurrencyConfig,
     thread_pools: HashMap<ThreadPoolType, ThreadPool>,
     async_coordinator: AsyncWorkCoordinator,
+    task_gates: HashMap<ThreadPoolType, TaskGate>,
 }
 
 impl ConcurrencyManager {
@@ -619,14 +719,155 @@ impl ConcurrencyManager {
         let blocking_pool = ThreadPool::new(ThreadPoolType::Blocking, blocking_config)?;
         thread_pools.insert(ThreadPoolType::Blocking, blocking_pool);
 
+        let task_gates = GOVERNED_POOLS
+            .iter()
+            .map(|pool_type| {
+                let threads = thread_pools[pool_type].config.thread_count;
+                (*pool_type, TaskGate::new(threads, config.max_queue_size))
+            })
+            .collect();
+
         Ok(Self {
-            state: ManagedState::new(Uuid::new_v4(), "concurrency_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "concurrency_manager"),
             config,
             thread_pools,
             async_coordinator,
+            task_gates,
         })
     }
 
+    /// Start CPU-bound work on the compute pool.
+    ///
+    /// Fails with [`ConcurrencyOperation::QueueFull`] when `max_queue_size`
+    /// tasks are already waiting for the pool; see [`Self::spawn_when_ready`]
+    /// to wait for room instead.
+    pub fn spawn_cpu<F, R>(&self, task: F) -> Result<TaskHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.try_spawn(ThreadPoolType::Compute, task)
+    }
+
+    /// Start I/O-bound work on the I/O pool, failing when its queue is full
+    pub fn spawn_io<F, R>(&self, task: F) -> Result<TaskHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.try_spawn(ThreadPoolType::Io, task)
+    }
+
+    /// Start blocking work on the blocking pool, failing when its queue is full
+    pub fn spawn_blocking<F, R>(&self, task: F) -> Result<TaskHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.try_spawn(ThreadPoolType::Blocking, task)
+    }
+
+    /// Start work on `pool_type` once its queue has room, waiting as long as needed
+    pub async fn spawn_when_ready<F, R>(
+        &self,
+        pool_type: ThreadPoolType,
+        task: F,
+    ) -> Result<TaskHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let gate = self.task_gate(pool_type)?;
+        let permit = Arc::clone(&gate.permits)
+            .acquire_owned()
+            .await
+            .map_err(|_| {
+                Error::new(
+                    ErrorKind::Concurrency {
+                        thread_id: None,
+                        operation: ConcurrencyOperation::Sync,
+                    },
+                    "Task queue was closed",
+                )
+            })?;
+        self.spawn_with_permit(pool_type, gate, permit, task)
+    }
+
+    /// Queue depth and active tasks for each governed pool
+    pub fn task_queue_metrics(&self) -> HashMap<ThreadPoolType, TaskQueueMetrics> {
+        self.task_gates
+            .iter()
+            .map(|(pool_type, gate)| (*pool_type, gate.metrics()))
+            .collect()
+    }
+
+    fn try_spawn<F, R>(&self, pool_type: ThreadPoolType, task: F) -> Result<TaskHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let gate = self.task_gate(pool_type)?;
+        let Ok(permit) = Arc::clone(&gate.permits).try_acquire_owned() else {
+            gate.rejected.fetch_add(1, Ordering::Relaxed);
+            return Err(Error::new(
+                ErrorKind::Concurrency {
+                    thread_id: None,
+                    operation: ConcurrencyOperation::QueueFull,
+                },
+                format!(
+                    "{:?} task queue is full ({} tasks outstanding)",
+                    pool_type, gate.capacity
+                ),
+            ));
+        };
+        self.spawn_with_permit(pool_type, gate, permit, task)
+    }
+
+    fn task_gate(&self, pool_type: ThreadPoolType) -> Result<&TaskGate> {
+        self.task_gates.get(&pool_type).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Concurrency {
+                    thread_id: None,
+                    operation: ConcurrencyOperation::ThreadPool,
+                },
+                format!("{:?} thread pool does not accept governed tasks", pool_type),
+            )
+        })
+    }
+
+    /// Submit `task`, holding `permit` until it finishes
+    fn spawn_with_permit<F, R>(
+        &self,
+        pool_type: ThreadPoolType,
+        gate: &TaskGate,
+        permit: OwnedSemaphorePermit,
+        task: F,
+    ) -> Result<TaskHandle<R>>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let pool = self.thread_pools.get(&pool_type).ok_or_else(|| {
+            Error::new(
+                ErrorKind::Concurrency {
+                    thread_id: None,
+                    operation: ConcurrencyOperation::ThreadPool,
+                },
+                format!("{:?} thread pool not available", pool_type),
+            )
+        })?;
+
+        let (sender, receiver) = oneshot::channel();
+        let active = Arc::clone(&gate.active);
+        pool.submit(move || {
+            let _permit = permit;
+            let _active = ActiveTask::start(&active);
+            let _ = sender.send(task());
+        })?;
+
+        Ok(TaskHandle { receiver })
+    }
+
     pub async fn execute_compute<F, R>(&self, task: F) -> Result<R>
     where
         F: FnOnce() -> R + Send + 'static,
@@ -736,7 +977,7 @@ impl Manager for ConcurrencyManager {
     }
 
     fn id(&self) -> Uuid {
-        Uuid::new_v4()
+        crate::utils::rand::uuid()
     }
 
     async fn initialize(&mut self) -> Result<()> {
@@ -802,6 +1043,22 @@ impl Manager for ConcurrencyManager {
             );
         }
 
+        for (pool_type, metrics) in self.task_queue_metrics() {
+            let pool = format!("{:?}", pool_type).to_lowercase();
+            status.add_metadata(
+                format!("{}_queued_tasks", pool),
+                serde_json::Value::from(metrics.queued),
+            );
+            status.add_metadata(
+                format!("{}_active_tasks", pool),
+                serde_json::Value::from(metrics.active),
+            );
+            status.add_metadata(
+                format!("{}_rejected_tasks", pool),
+                serde_json::Value::from(metrics.rejected),
+            );
+        }
+
         status.add_metadata(
             "total_active_threads",
             serde_json::Value::from(total_active_threads),
@@ -1007,6 +1264,67 @@ mod tests {
         assert_eq!(result, 499500);
     }
 
+    #[tokio::test]
+    async fn test_spawn_applies_backpressure() {
+        let manager = ConcurrencyManager::new(ConcurrencyConfig {
+            thread_pool_size: 1,
+            max_queue_size: 1,
+            ..ConcurrencyConfig::default()
+        })
+        .unwrap();
+
+        let (started_tx, started_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let running = manager
+            .spawn_cpu(move || {
+                started_tx.send(()).unwrap();
+                release_rx.recv().unwrap();
+                1
+            })
+            .unwrap();
+        started_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        let queued = manager.spawn_cpu(|| 2).unwrap();
+
+        let metrics = manager.task_queue_metrics()[&ThreadPoolType::Compute];
+        assert_eq!(
+            (metrics.active, metrics.queued, metrics.capacity),
+            (1, 1, 2)
+        );
+
+        let error = manager.spawn_cpu(|| 3).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            ErrorKind::Concurrency {
+                operation: ConcurrencyOperation::QueueFull,
+                ..
+            }
+        ));
+        assert_eq!(
+            manager.task_queue_metrics()[&ThreadPoolType::Compute].rejected,
+            1
+        );
+        let status = manager.status().await;
+        assert_eq!(
+            status.metadata.get("compute_queued_tasks"),
+            Some(&serde_json::json!(1))
+        );
+
+        // Other pools have queues of their own
+        assert_eq!(manager.spawn_io(|| 4).unwrap().await.unwrap(), 4);
+
+        let mut waiting = Box::pin(manager.spawn_when_ready(ThreadPoolType::Compute, || 5));
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), &mut waiting)
+                .await
+                .is_err()
+        );
+
+        release_tx.send(()).unwrap();
+        assert_eq!(running.await.unwrap(), 1);
+        assert_eq!(queued.await.unwrap(), 2);
+        assert_eq!(waiting.await.unwrap().await.unwrap(), 5);
+    }
+
     #[tokio::test]
     async fn test_thread_pool_stats() {
         let config = ThreadPoolConfig {
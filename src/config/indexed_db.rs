@@ -0,0 +1,248 @@
+// src/config/indexed_db.rs - IndexedDB persistence for web configuration stores
+
+use async_trait::async_trait;
+use serde_json::{Map, Value};
+use tokio::sync::{broadcast, Mutex};
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{IdbDatabase, IdbRequest, IdbTransaction, IdbTransactionMode};
+
+use super::tiered::{ConfigChangeEvent, ConfigStore, ConfigWatcher, ConfigurationTier};
+use crate::error::{Error, Result};
+use crate::utils::Time;
+
+/// Object store holding the configuration document inside each database
+pub const CONFIG_OBJECT_STORE: &str = "config";
+
+/// Key of the configuration document within [`CONFIG_OBJECT_STORE`]
+pub const CONFIG_DOCUMENT_KEY: &str = "document";
+
+fn idb_error(operation: &str, error: JsValue) -> Error {
+    Error::platform(
+        "web",
+        "indexeddb",
+        format!("Failed to {}: {:?}", operation, error),
+    )
+}
+
+/// Waits for `request` to fire `success` or `error`
+async fn request_done(request: &IdbRequest, operation: &str) -> Result<JsValue> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    JsFuture::from(promise)
+        .await
+        .map_err(|e| idb_error(operation, e))?;
+    request.result().map_err(|e| idb_error(operation, e))
+}
+
+/// Waits for `transaction` to commit or abort
+async fn transaction_done(transaction: &IdbTransaction, operation: &str) -> Result<()> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        transaction.set_oncomplete(Some(&resolve));
+        transaction.set_onerror(Some(&reject));
+        transaction.set_onabort(Some(&reject));
+    });
+    JsFuture::from(promise)
+        .await
+        .map(|_| ())
+        .map_err(|e| idb_error(operation, e))
+}
+
+/// Opens the database named `store`, creating its object store on first use
+async fn open(store: &str) -> Result<IdbDatabase> {
+    let factory = web_sys::window()
+        .and_then(|w| w.indexed_db().ok())
+        .flatten()
+        .ok_or_else(|| Error::platform("web", "indexeddb", "IndexedDB not available"))?;
+    let request = factory
+        .open_with_u32(store, 1)
+        .map_err(|e| idb_error("open config database", e))?;
+
+    let upgrade_request = request.clone();
+    let on_upgrade = wasm_bindgen::closure::Closure::once(move |_: web_sys::Event| {
+        if let Ok(db) = upgrade_request.result() {
+            let db: IdbDatabase = db.unchecked_into();
+            if !db.object_store_names().contains(CONFIG_OBJECT_STORE) {
+                let _ = db.create_object_store(CONFIG_OBJECT_STORE);
+            }
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+
+    // The upgrade callback must stay alive until the open request settles
+    let db = request_done(&request, "open config database").await;
+    request.set_onupgradeneeded(None);
+    drop(on_upgrade);
+    Ok(db?.unchecked_into())
+}
+
+/// Reads the configuration document saved in `store`.
+///
+/// A store that has never been written yields an empty object, so a fresh
+/// browser profile starts from the lower tiers alone.
+pub async fn load_document(store: &str) -> Result<Value> {
+    let db = open(store).await?;
+    let request = db
+        .transaction_with_str(CONFIG_OBJECT_STORE)
+        .and_then(|tx| tx.object_store(CONFIG_OBJECT_STORE))
+        .and_then(|objects| objects.get(&JsValue::from_str(CONFIG_DOCUMENT_KEY)))
+        .map_err(|e| idb_error("read config document", e));
+    let result = match request {
+        Ok(request) => request_done(&request, "read config document").await,
+        Err(e) => Err(e),
+    };
+    db.close();
+
+    match result?.as_string() {
+        Some(json) => serde_json::from_str(&json).map_err(|e| {
+            Error::config(format!(
+                "Failed to parse config document in IndexedDB store '{}': {}",
+                store, e
+            ))
+        }),
+        None => Ok(Value::Object(Map::new())),
+    }
+}
+
+/// Replaces the configuration document saved in `store`
+pub async fn save_document(store: &str, document: &Value) -> Result<()> {
+    let json = serde_json::to_string(document)
+        .map_err(|e| Error::config(format!("Failed to encode config document: {}", e)))?;
+
+    let db = open(store).await?;
+    let transaction = db
+        .transaction_with_str_and_mode(CONFIG_OBJECT_STORE, IdbTransactionMode::Readwrite)
+        .map_err(|e| idb_error("write config document", e));
+    let result = match transaction {
+        Ok(transaction) => {
+            let put = transaction
+                .object_store(CONFIG_OBJECT_STORE)
+                .and_then(|objects| {
+                    objects.put_with_key(
+                        &JsValue::from_str(&json),
+                        &JsValue::from_str(CONFIG_DOCUMENT_KEY),
+                    )
+                })
+                .map_err(|e| idb_error("write config document", e));
+            match put {
+                Ok(_) => transaction_done(&transaction, "write config document").await,
+                Err(e) => Err(e),
+            }
+        }
+        Err(e) => Err(e),
+    };
+    db.close();
+    result
+}
+
+/// A [`ConfigStore`] whose values are saved to IndexedDB, so settings written
+/// to its tier survive a page reload.
+///
+/// Values are kept in memory and the whole document is saved after every
+/// change; memory only changes once the save succeeds. Saves run one at a
+/// time in the order the changes were made, so an older document never
+/// overwrites a newer one. [`ConfigSource::IndexedDb`](super::ConfigSource)
+/// layers read the same document.
+pub struct IndexedDbConfigStore {
+    store: String,
+    tier: ConfigurationTier,
+    data: Mutex<Map<String, Value>>,
+    change_sender: broadcast::Sender<ConfigChangeEvent>,
+}
+
+impl IndexedDbConfigStore {
+    /// Open the store backed by the IndexedDB database `store`, loading the
+    /// values saved there
+    pub async fn open(store: impl Into<String>, tier: ConfigurationTier) -> Result<Self> {
+        let store = store.into();
+        let data = match load_document(&store).await? {
+            Value::Object(data) => data,
+            _ => {
+                return Err(Error::config(format!(
+                    "Config document in IndexedDB store '{}' is not an object",
+                    store
+                )))
+            }
+        };
+        let (change_sender, _) = broadcast::channel(100);
+
+        Ok(Self {
+            store,
+            tier,
+            data: Mutex::new(data),
+            change_sender,
+        })
+    }
+
+    /// Apply `change` to a copy of the values and save it, keeping the copy
+    /// only if the save succeeds; the lock is held throughout
+    async fn update<R>(&self, change: impl FnOnce(&mut Map<String, Value>) -> R) -> Result<R> {
+        let mut data = self.data.lock().await;
+        let mut updated = data.clone();
+        let result = change(&mut updated);
+
+        let document = Value::Object(updated);
+        save_document(&self.store, &document).await?;
+        if let Value::Object(updated) = document {
+            *data = updated;
+        }
+        Ok(result)
+    }
+
+    fn notify(&self, key: &str, value: Option<Value>, old_value: Option<Value>) {
+        let _ = self.change_sender.send(ConfigChangeEvent {
+            key: key.to_string(),
+            value,
+            old_value,
+            tier: self.tier,
+            timestamp: Time::now(),
+            source: "indexed_db_store".to_string(),
+            correlation_id: None,
+        });
+    }
+}
+
+#[async_trait(?Send)]
+impl ConfigStore for IndexedDbConfigStore {
+    async fn get(&self, key: &str) -> Result<Option<Value>> {
+        Ok(self.data.lock().await.get(key).cloned())
+    }
+
+    async fn set(&self, key: &str, value: Value) -> Result<()> {
+        let old_value = self
+            .update(|data| data.insert(key.to_string(), value.clone()))
+            .await?;
+        self.notify(key, Some(value), old_value);
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let old_value = self.update(|data| data.remove(key)).await?;
+        if old_value.is_some() {
+            self.notify(key, None, old_value);
+        }
+        Ok(())
+    }
+
+    async fn list_keys(&self, prefix: &str) -> Result<Vec<String>> {
+        Ok(self
+            .data
+            .lock()
+            .await
+            .keys()
+            .filter(|k| k.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+
+    async fn watch(&self, _key: &str) -> Result<ConfigWatcher> {
+        Ok(ConfigWatcher::new(self.change_sender.subscribe()))
+    }
+
+    fn tier(&self) -> ConfigurationTier {
+        self.tier
+    }
+}
@@ -15,9 +15,10 @@ use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use crate::utils::Time;
+use crate::utils::{JsonLimits, Time};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Number, Value};
 use tokio::sync::broadcast;
@@ -29,7 +30,11 @@ use crate::event::{Event, EventBusManager};
 use crate::manager::{ManagedState, Manager, ManagerStatus};
 use crate::types::Metadata;
 
+#[cfg(target_arch = "wasm32")]
+pub mod indexed_db;
 pub mod tiered;
+#[cfg(target_arch = "wasm32")]
+pub use indexed_db::IndexedDbConfigStore;
 pub use tiered::{ConfigurationTier, MemoryConfigStore, TieredConfigManager};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +47,25 @@ pub struct ConfigChangeEvent {
     pub metadata: Metadata,
 }
 
+/// `key` of a [`ConfigChangeEvent`] published by [`ConfigManager::set_many`]
+pub const CONFIG_BATCH_KEY: &str = "_batch";
+
+impl ConfigChangeEvent {
+    /// Keys this event changed.
+    ///
+    /// A batch lists every key it set; its `value` and `old_value` are
+    /// objects mapping each of those keys to its new and previous value.
+    pub fn changed_keys(&self) -> Vec<&str> {
+        if self.key != CONFIG_BATCH_KEY {
+            return vec![self.key.as_str()];
+        }
+        self.value
+            .as_object()
+            .map(|values| values.keys().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+}
+
 impl Event for ConfigChangeEvent {
     fn event_type(&self) -> &'static str {
         "config.changed"
@@ -71,6 +95,71 @@ pub struct SettingsSchema {
     pub defaults: Value,
 }
 
+impl SettingsSchema {
+    /// Check settings against the schema, one error per offending field.
+    ///
+    /// Validation is done by the `jsonschema` crate. Error keys are dotted
+    /// paths into `values`, empty for the root; a missing required setting or
+    /// an unknown one under `additionalProperties: false` is reported under
+    /// its own key. An invalid schema yields a single root error.
+    pub fn validate(&self, values: &Value) -> Vec<ValidationError> {
+        let compiled = match jsonschema::JSONSchema::compile(&self.schema) {
+            Ok(compiled) => compiled,
+            Err(e) => {
+                return vec![ValidationError {
+                    key: String::new(),
+                    message: format!("settings schema is invalid: {}", e),
+                }]
+            }
+        };
+        let Err(failures) = compiled.validate(values) else {
+            return Vec::new();
+        };
+
+        let mut errors = Vec::new();
+        for failure in failures {
+            let path = pointer_to_path(&failure.instance_path.to_string());
+            match &failure.kind {
+                jsonschema::error::ValidationErrorKind::Required { property } => {
+                    errors.push(ValidationError {
+                        key: join_path(&path, property.as_str().unwrap_or_default()),
+                        message: "is required".to_string(),
+                    })
+                }
+                jsonschema::error::ValidationErrorKind::AdditionalProperties { unexpected } => {
+                    errors.extend(unexpected.iter().map(|key| ValidationError {
+                        key: join_path(&path, key),
+                        message: "is not a known setting".to_string(),
+                    }))
+                }
+                _ => errors.push(ValidationError {
+                    key: path,
+                    message: failure.to_string(),
+                }),
+            }
+        }
+        errors
+    }
+}
+
+/// Dotted settings path for a JSON pointer such as `/sync/interval`
+fn pointer_to_path(pointer: &str) -> String {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ValidationError {
     pub key: String,
@@ -102,10 +191,17 @@ impl ConfigFormat {
 }
 
 #[derive(Debug, Clone)]
+#[rustfmt::skip]
 pub enum ConfigSource {
     File { path: PathBuf, format: ConfigFormat },
     Environment { prefix: String },
     Memory { data: Value },
+    /// The document an [`IndexedDbConfigStore`] saves in the browser's
+    /// IndexedDB database `store`. The layer only reads it; on the web,
+    /// settings are written through the store as the user tier of a
+    /// [`TieredConfigManager`], so this layer sees them after a reload.
+    #[cfg(target_arch = "wasm32")]
+    IndexedDb { store: String },
 }
 
 #[derive(Debug, Clone)]
@@ -116,21 +212,35 @@ pub struct ConfigLayer {
     pub hot_reload: bool,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct AppConfig {
+    /// Application identity and directories
     pub app: AppSettings,
+    /// Log level, format and outputs
     pub logging: LoggingConfig,
+    /// Event bus workers and history
     pub event_bus: EventBusConfig,
+    /// File manager limits
     pub files: FileConfig,
+    /// Background task limits
     pub tasks: TaskConfig,
+    /// Thread pool sizes
     pub concurrency: ConcurrencyConfig,
+    /// Plugin loading and policy
     pub plugins: PluginConfig,
+    /// Database connection
     pub database: DatabaseConfig,
+    /// Network listener and request limits
     pub network: NetworkConfig,
+    /// Authentication, rate limiting and CORS
     pub security: SecurityConfig,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct AppSettings {
     pub name: String,
     pub version: String,
@@ -141,6 +251,15 @@ pub struct AppSettings {
     pub config_dir: PathBuf,
     pub log_dir: PathBuf,
     pub pid_file: Option<PathBuf>,
+    /// How long each manager's health check may take before it is reported `Unknown`
+    #[serde(default = "default_health_check_timeout_ms")]
+    pub health_check_timeout_ms: u64,
+    /// How long a manager's `initialize` may take before startup moves on without it
+    #[serde(default = "default_manager_init_timeout_ms")]
+    pub manager_init_timeout_ms: u64,
+    /// Per-manager overrides of `manager_init_timeout_ms`, keyed by manager name
+    #[serde(default)]
+    pub manager_init_timeouts: HashMap<String, u64>,
 }
 
 impl Default for AppSettings {
@@ -155,11 +274,24 @@ impl Default for AppSettings {
             config_dir: PathBuf::from("./config"),
             log_dir: PathBuf::from("./logs"),
             pid_file: None,
+            health_check_timeout_ms: default_health_check_timeout_ms(),
+            manager_init_timeout_ms: default_manager_init_timeout_ms(),
+            manager_init_timeouts: HashMap::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+fn default_health_check_timeout_ms() -> u64 {
+    5000
+}
+
+fn default_manager_init_timeout_ms() -> u64 {
+    30_000
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct LoggingConfig {
     pub level: String,
     pub format: LogFormat,
@@ -178,18 +310,38 @@ impl Default for LoggingConfig {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+impl LoggingConfig {
+    /// Format of console output, falling back to [`LoggingConfig::format`]
+    pub fn console_format(&self) -> LogFormat {
+        self.console.format.unwrap_or(self.format)
+    }
+
+    /// Format of file output, falling back to [`LoggingConfig::format`]
+    pub fn file_format(&self) -> LogFormat {
+        self.file
+            .as_ref()
+            .and_then(|file| file.format)
+            .unwrap_or(self.format)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum LogFormat {
     Json,
     Pretty,
     Compact,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct ConsoleLogConfig {
     pub enabled: bool,
     pub level: String,
     pub colored: bool,
+    /// Overrides [`LoggingConfig::format`] for console output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<LogFormat>,
 }
 
 impl Default for ConsoleLogConfig {
@@ -198,16 +350,22 @@ impl Default for ConsoleLogConfig {
             enabled: true,
             level: "info".to_string(),
             colored: true,
+            format: None,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct FileLogConfig {
     pub path: PathBuf,
     pub max_size: u64,
     pub max_files: u32,
     pub compress: bool,
+    /// Overrides [`LoggingConfig::format`] for file output
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<LogFormat>,
 }
 
 impl Default for FileLogConfig {
@@ -217,6 +375,7 @@ impl Default for FileLogConfig {
             max_size: 100 * 1024 * 1024, // 100MB
             max_files: 10,
             compress: true,
+            format: None,
         }
     }
 }
@@ -233,13 +392,23 @@ fn get_default_cpu_count() -> usize {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct EventBusConfig {
     pub worker_count: usize,
     pub queue_size: usize,
     pub publish_timeout_ms: u64,
     pub enable_persistence: bool,
     pub enable_metrics: bool,
+    #[serde(default = "default_event_history_capacity")]
+    pub history_capacity: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub persistence_path: Option<PathBuf>,
+}
+
+fn default_event_history_capacity() -> usize {
+    1000
 }
 
 impl Default for EventBusConfig {
@@ -250,11 +419,15 @@ impl Default for EventBusConfig {
             publish_timeout_ms: 5000,
             enable_persistence: false,
             enable_metrics: true,
+            history_capacity: default_event_history_capacity(),
+            persistence_path: None,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct FileConfig {
     pub default_permissions: u32,
     pub max_file_size: u64,
@@ -263,6 +436,9 @@ pub struct FileConfig {
     pub operation_timeout_secs: u64,
     pub enable_watching: bool,
     pub enable_compression: bool,
+    /// Directories streamed file operations may touch; empty allows any path
+    #[serde(default)]
+    pub allowed_roots: Vec<PathBuf>,
 }
 
 impl Default for FileConfig {
@@ -274,6 +450,7 @@ impl Default for FileConfig {
             operation_timeout_secs: 30,
             enable_watching: true,
             enable_compression: false,
+            allowed_roots: Vec::new(),
         }
     }
 }
@@ -297,10 +474,9 @@ fn get_default_temp_dir() -> Option<PathBuf> {
     #[cfg(not(target_arch = "wasm32"))]
     {
         use std::fs;
-        use uuid::Uuid;
 
         let mut temp = std::env::temp_dir();
-        temp.push(format!("qorzen_{}", Uuid::new_v4()));
+        temp.push(format!("qorzen_{}", crate::utils::rand::uuid()));
 
         if let Err(e) = fs::create_dir_all(&temp) {
             eprintln!("Failed to create temp dir: {}", e);
@@ -311,7 +487,9 @@ fn get_default_temp_dir() -> Option<PathBuf> {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct TaskConfig {
     pub max_concurrent: usize,
     pub default_timeout_ms: u64,
@@ -330,7 +508,9 @@ impl Default for TaskConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct ConcurrencyConfig {
     pub thread_pool_size: usize,
     pub io_thread_pool_size: usize,
@@ -352,13 +532,34 @@ impl Default for ConcurrencyConfig {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct PluginConfig {
     pub plugin_dir: PathBuf,
     pub auto_load: bool,
     pub load_timeout_secs: u64,
     pub max_plugins: usize,
+    /// Reload plugins whose artifacts change in `plugin_dir`.
+    ///
+    /// Needs a native build with the `hot-reload` feature; see `plugin::hot_reload`.
     pub hot_reload: bool,
+    /// Plugin ID globs allowed to load; empty allows every plugin
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+    /// Plugin ID globs refused even if they are also allow-listed
+    #[serde(default)]
+    pub denylist: Vec<String>,
+    /// Publisher globs allowed to load; empty trusts every publisher
+    #[serde(default)]
+    pub trusted_publishers: Vec<String>,
+    /// Largest event payload, in bytes, delivered to plugin handlers
+    #[serde(default = "default_max_event_payload_bytes")]
+    pub max_event_payload_bytes: usize,
+}
+
+fn default_max_event_payload_bytes() -> usize {
+    crate::plugin::DEFAULT_MAX_EVENT_PAYLOAD_BYTES
 }
 
 impl Default for PluginConfig {
@@ -369,11 +570,17 @@ impl Default for PluginConfig {
             load_timeout_secs: 30,
             max_plugins: 100,
             hot_reload: false,
+            allowlist: Vec::new(),
+            denylist: Vec::new(),
+            trusted_publishers: Vec::new(),
+            max_event_payload_bytes: default_max_event_payload_bytes(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct DatabaseConfig {
     pub url: String,
     pub max_connections: u32,
@@ -381,6 +588,23 @@ pub struct DatabaseConfig {
     pub query_timeout_secs: u64,
     pub enable_pooling: bool,
     pub enable_query_logging: bool,
+    /// Delay before the first reconnect attempt after the connection drops
+    #[serde(default = "default_reconnect_initial_backoff_ms")]
+    pub reconnect_initial_backoff_ms: u64,
+    /// Upper bound for the delay between reconnect attempts
+    #[serde(default = "default_reconnect_max_backoff_ms")]
+    pub reconnect_max_backoff_ms: u64,
+    /// Reconnect attempts before the database is reported unhealthy; zero retries forever
+    #[serde(default)]
+    pub reconnect_max_attempts: u32,
+}
+
+fn default_reconnect_initial_backoff_ms() -> u64 {
+    500
+}
+
+fn default_reconnect_max_backoff_ms() -> u64 {
+    30_000
 }
 
 impl Default for DatabaseConfig {
@@ -392,19 +616,35 @@ impl Default for DatabaseConfig {
             query_timeout_secs: 60,
             enable_pooling: true,
             enable_query_logging: false,
+            reconnect_initial_backoff_ms: default_reconnect_initial_backoff_ms(),
+            reconnect_max_backoff_ms: default_reconnect_max_backoff_ms(),
+            reconnect_max_attempts: 0,
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct NetworkConfig {
+    /// Address the server listens on
     pub bind_address: String,
+    /// Port the server listens on
     pub port: u16,
     pub enable_tls: bool,
     pub tls_cert_path: Option<PathBuf>,
     pub tls_key_path: Option<PathBuf>,
+    /// Default time limit for a request, in seconds
     pub request_timeout_secs: u64,
+    /// Largest accepted request body, in bytes
     pub max_request_size: u64,
+    /// Response compression negotiated via `Accept-Encoding`
+    #[serde(default)]
+    pub compression: CompressionConfig,
+    /// Reverse proxies whose `X-Forwarded-For` and `X-Real-IP` headers are
+    /// believed when identifying clients
+    #[serde(default)]
+    pub trusted_proxies: Vec<std::net::IpAddr>,
 }
 
 impl Default for NetworkConfig {
@@ -417,11 +657,68 @@ impl Default for NetworkConfig {
             tls_key_path: None,
             request_timeout_secs: 30,
             max_request_size: 16 * 1024 * 1024, // 16MB
+            compression: CompressionConfig::default(),
+            trusted_proxies: Vec::new(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
+pub struct CompressionConfig {
+    /// Compress responses for clients that accept gzip or brotli
+    pub enabled: bool,
+    /// Bodies smaller than this many bytes are sent as-is
+    pub min_size_bytes: usize,
+    /// Content types worth compressing; `text/*` matches any subtype
+    pub content_types: Vec<String>,
+}
+
+impl CompressionConfig {
+    /// Whether a body of this type and size should be compressed
+    pub fn should_compress(&self, content_type: &str, len: usize) -> bool {
+        if !self.enabled || len < self.min_size_bytes {
+            return false;
+        }
+
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        self.content_types
+            .iter()
+            .any(|allowed| match allowed.strip_suffix("/*") {
+                Some(prefix) => mime
+                    .split_once('/')
+                    .is_some_and(|(kind, _)| kind.eq_ignore_ascii_case(prefix)),
+                None => mime.eq_ignore_ascii_case(allowed),
+            })
+    }
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_size_bytes: 1024,
+            // Raster images, archives and fonts are already compressed
+            content_types: vec![
+                "application/json".to_string(),
+                "application/javascript".to_string(),
+                "application/xml".to_string(),
+                "image/svg+xml".to_string(),
+                "text/*".to_string(),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+#[schemars(default)]
 pub struct SecurityConfig {
     pub jwt_secret: String,
     pub jwt_expiration_secs: u64,
@@ -430,6 +727,44 @@ pub struct SecurityConfig {
     pub rate_limit_rpm: u64,
     pub enable_cors: bool,
     pub cors_origins: Vec<String>,
+    /// Allow cookies and credentials on cross-origin requests
+    #[serde(default)]
+    pub cors_allow_credentials: bool,
+    /// Where the account manager keeps sessions
+    #[serde(default)]
+    pub session_store: SessionStoreKind,
+}
+
+/// Where the account manager keeps sessions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionStoreKind {
+    /// In process memory, lost on restart or page reload
+    #[default]
+    Memory,
+    /// The browser's `localStorage`; web only
+    LocalStorage,
+}
+
+impl SecurityConfig {
+    /// Refuses credentials combined with a `*` origin.
+    ///
+    /// Browsers never send credentials to a `*` origin, and echoing the
+    /// caller's origin instead would let every site make credentialed calls.
+    pub fn validate_cors(&self) -> Result<()> {
+        if self.cors_allow_credentials && self.cors_origins.iter().any(|o| o == "*") {
+            let message = "cors_allow_credentials cannot be combined with a \"*\" entry in cors_origins; list the allowed origins explicitly";
+            return Err(Error::new(
+                crate::error::ErrorKind::Configuration {
+                    key: Some("security.cors_origins".to_string()),
+                    validation_errors: vec![message.to_string()],
+                },
+                format!("Configuration value rejected: {}", message),
+            )
+            .severity(crate::error::ErrorSeverity::High));
+        }
+        Ok(())
+    }
 }
 
 impl Default for SecurityConfig {
@@ -442,18 +777,118 @@ impl Default for SecurityConfig {
             rate_limit_rpm: 1000,
             enable_cors: true,
             cors_origins: vec!["*".to_string()],
+            cors_allow_credentials: false,
+            session_store: SessionStoreKind::Memory,
+        }
+    }
+}
+
+/// A top-level configuration section that can be watched as a typed value
+pub trait ConfigSection: for<'de> Deserialize<'de> + Send + 'static {
+    /// Key of the section in the merged configuration
+    const SECTION: &'static str;
+}
+
+macro_rules! impl_config_section {
+    ($($section:ty => $key:literal),* $(,)?) => {
+        $(
+            impl ConfigSection for $section {
+                const SECTION: &'static str = $key;
+            }
+        )*
+    };
+}
+
+impl_config_section! {
+    AppSettings => "app",
+    LoggingConfig => "logging",
+    EventBusConfig => "event_bus",
+    FileConfig => "files",
+    TaskConfig => "tasks",
+    ConcurrencyConfig => "concurrency",
+    PluginConfig => "plugins",
+    DatabaseConfig => "database",
+    NetworkConfig => "network",
+    SecurityConfig => "security",
+}
+
+/// Quiet period used to coalesce bursts of changes in `watch_section`
+const SECTION_WATCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(50);
+
+/// Whether a change to `key` may affect `section`
+fn change_affects_section(key: &str, section: &str) -> bool {
+    key == "_reload"
+        || key == section
+        || key
+            .strip_prefix(section)
+            .is_some_and(|rest| rest.starts_with('.'))
+        || section
+            .strip_prefix(key)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+async fn sleep_for(duration: std::time::Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Retry policy for file layers that cannot be opened during startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoadRetryPolicy {
+    /// Retries after the first failed attempt; zero disables retrying
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled after each further attempt
+    pub initial_backoff: std::time::Duration,
+    /// Upper bound for the delay between attempts
+    pub max_backoff: std::time::Duration,
+}
+
+impl Default for LoadRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: std::time::Duration::from_millis(250),
+            max_backoff: std::time::Duration::from_secs(2),
+        }
+    }
+}
+
+impl LoadRetryPolicy {
+    /// Policy that fails on the first error
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
         }
     }
 }
 
+/// Whether a layer's backing file is currently missing or unreadable.
+///
+/// Parse errors in a readable file are not transient and are never retried.
+fn layer_file_unavailable(layer: &ConfigLayer) -> bool {
+    match &layer.source {
+        #[cfg(not(target_arch = "wasm32"))]
+        ConfigSource::File { path, .. } => std::fs::File::open(path).is_err(),
+        _ => false,
+    }
+}
+
 pub struct ConfigManager {
     state: ManagedState,
     layers: Vec<ConfigLayer>,
     merged_config: Arc<RwLock<Value>>,
+    value_sources: Arc<RwLock<ValueSources>>,
     change_notifier: broadcast::Sender<ConfigChangeEvent>,
     watch_enabled: bool,
     env_prefix: String,
     event_bus: Option<Arc<EventBusManager>>,
+    includes_enabled: bool,
+    sensitive_keys: Vec<String>,
+    value_limits: JsonLimits,
+    load_retry: LoadRetryPolicy,
 }
 
 impl fmt::Debug for ConfigManager {
@@ -462,6 +897,7 @@ impl fmt::Debug for ConfigManager {
             .field("layers", &self.layers.len())
             .field("watch_enabled", &self.watch_enabled)
             .field("env_prefix", &self.env_prefix)
+            .field("includes_enabled", &self.includes_enabled)
             .finish()
     }
 }
@@ -496,6 +932,46 @@ fn set_nested_env_value(config: &mut Map<String, Value>, keys: &[&str], value: S
     }
 }
 
+/// Layer name and priority that supplied each leaf key of the merged config
+type ValueSources = HashMap<String, (String, u32)>;
+
+/// Source recorded for values written through [`ConfigManager::set`]
+const RUNTIME_SOURCE: &str = "config_manager";
+
+/// Records `layer` as the source of every leaf in `value`, replacing sources
+/// of any keys the value overrides
+fn record_value_sources(
+    sources: &mut ValueSources,
+    prefix: &str,
+    value: &Value,
+    layer: &str,
+    priority: u32,
+) {
+    match value {
+        Value::Object(map) => {
+            if !prefix.is_empty() {
+                sources.remove(prefix);
+            }
+            for (key, item) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                record_value_sources(sources, &path, item, layer, priority);
+            }
+        }
+        _ => {
+            if prefix.is_empty() {
+                return;
+            }
+            let nested = format!("{}.", prefix);
+            sources.retain(|key, _| !key.starts_with(&nested));
+            sources.insert(prefix.to_string(), (layer.to_string(), priority));
+        }
+    }
+}
+
 fn merge_values(target: &mut Value, source: Value) {
     match (target, source) {
         (Value::Object(target_map), Value::Object(source_map)) => {
@@ -516,18 +992,264 @@ fn merge_values(target: &mut Value, source: Value) {
     }
 }
 
+/// Placeholder written in place of sensitive values
+pub const REDACTED_VALUE: &str = "***REDACTED***";
+
+fn default_sensitive_keys() -> Vec<String> {
+    ["jwt_secret", "api_key", "password", "secret", "token"]
+        .iter()
+        .map(|k| k.to_string())
+        .collect()
+}
+
+fn is_sensitive_key(key: &str, sensitive_keys: &[String]) -> bool {
+    let key = key.to_lowercase();
+    sensitive_keys
+        .iter()
+        .any(|pattern| key.contains(&pattern.to_lowercase()))
+}
+
+fn redact_value(value: &mut Value, sensitive_keys: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if is_sensitive_key(key, sensitive_keys) {
+                    *item = Value::String(REDACTED_VALUE.to_string());
+                } else {
+                    redact_value(item, sensitive_keys);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                redact_value(item, sensitive_keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Directive used to include another file.
+///
+/// YAML files use the `!include path` tag; JSON and TOML files use an object
+/// with this single key, e.g. `{ "!include": "path" }`.
+#[cfg(not(target_arch = "wasm32"))]
+const INCLUDE_DIRECTIVE: &str = "!include";
+
+/// Loads a config file, resolving include directives relative to the including file.
+///
+/// `depth` is the nesting depth at which the file's value is placed, so the
+/// combined document stays within `limits` however the files are split.
+#[cfg(not(target_arch = "wasm32"))]
+fn load_config_file_with_includes(
+    path: &Path,
+    format: ConfigFormat,
+    include_stack: &mut Vec<PathBuf>,
+    limits: &JsonLimits,
+    depth: usize,
+) -> Result<Value> {
+    let canonical = path.canonicalize().map_err(|e| {
+        Error::config(format!(
+            "Failed to resolve config file '{}': {}",
+            path.display(),
+            e
+        ))
+    })?;
+
+    if include_stack.contains(&canonical) {
+        let chain = include_stack
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        return Err(Error::config(format!(
+            "Config include cycle detected: {}",
+            chain
+        )));
+    }
+
+    let content = std::fs::read_to_string(&canonical)
+        .map_err(|e| Error::config(format!("Failed to read config file: {}", e)))?;
+    let remaining = JsonLimits::new(limits.max_depth.saturating_sub(depth), limits.max_nodes);
+    remaining.check_text(&content).map_err(|e| {
+        Error::config(format!(
+            "Config file '{}' rejected: {}",
+            canonical.display(),
+            e.message
+        ))
+    })?;
+    let base_dir = canonical
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    include_stack.push(canonical);
+
+    let parsed = match format {
+        ConfigFormat::Json => serde_json::from_str(&content)
+            .map_err(|e| Error::config(format!("Failed to parse JSON config: {}", e))),
+        ConfigFormat::Toml => toml::from_str(&content)
+            .map_err(|e| Error::config(format!("Failed to parse TOML config: {}", e))),
+        ConfigFormat::Yaml => serde_yaml::from_str::<serde_yaml::Value>(&content)
+            .map_err(|e| Error::config(format!("Failed to parse YAML config: {}", e)))
+            .and_then(|mut yaml| {
+                yaml.apply_merge().map_err(|e| {
+                    Error::config(format!("Failed to apply YAML merge keys: {}", e))
+                })?;
+                yaml_to_json(yaml, &base_dir, format, include_stack, limits, depth)
+            }),
+    };
+
+    let result = parsed
+        .and_then(|value| resolve_includes(value, &base_dir, format, include_stack, limits, depth));
+
+    include_stack.pop();
+    result
+}
+
+/// Loads the target of an include directive
+#[cfg(not(target_arch = "wasm32"))]
+fn load_include(
+    target: &str,
+    base_dir: &Path,
+    parent_format: ConfigFormat,
+    include_stack: &mut Vec<PathBuf>,
+    limits: &JsonLimits,
+    depth: usize,
+) -> Result<Value> {
+    let path = base_dir.join(target);
+    let format = ConfigFormat::from_extension(&path).unwrap_or(parent_format);
+    load_config_file_with_includes(&path, format, include_stack, limits, depth)
+}
+
+/// Refuses values nested deeper than `limits` allow
+#[cfg(not(target_arch = "wasm32"))]
+fn check_include_depth(limits: &JsonLimits, depth: usize) -> Result<()> {
+    if depth > limits.max_depth {
+        return Err(Error::config(format!(
+            "Included config exceeds the maximum nesting depth of {}",
+            limits.max_depth
+        )));
+    }
+    Ok(())
+}
+
+/// Converts a YAML value found at `depth` to JSON, expanding `!include` tags
+/// along the way
+#[cfg(not(target_arch = "wasm32"))]
+fn yaml_to_json(
+    value: serde_yaml::Value,
+    base_dir: &Path,
+    format: ConfigFormat,
+    include_stack: &mut Vec<PathBuf>,
+    limits: &JsonLimits,
+    depth: usize,
+) -> Result<Value> {
+    match value {
+        serde_yaml::Value::Tagged(tagged) if tagged.tag == INCLUDE_DIRECTIVE => {
+            match tagged.value {
+                serde_yaml::Value::String(target) => {
+                    load_include(&target, base_dir, format, include_stack, limits, depth)
+                }
+                _ => Err(Error::config("The !include directive expects a file path")),
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => {
+            yaml_to_json(tagged.value, base_dir, format, include_stack, limits, depth)
+        }
+        serde_yaml::Value::Sequence(items) => {
+            check_include_depth(limits, depth + 1)?;
+            items
+                .into_iter()
+                .map(|item| yaml_to_json(item, base_dir, format, include_stack, limits, depth + 1))
+                .collect::<Result<Vec<_>>>()
+                .map(Value::Array)
+        }
+        serde_yaml::Value::Mapping(mapping) => {
+            check_include_depth(limits, depth + 1)?;
+            let mut map = Map::new();
+            for (key, item) in mapping {
+                let key = match key {
+                    serde_yaml::Value::String(key) => key,
+                    other => serde_json::to_value(other)
+                        .map_err(|e| Error::config(format!("Invalid YAML mapping key: {}", e)))?
+                        .to_string(),
+                };
+                map.insert(
+                    key,
+                    yaml_to_json(item, base_dir, format, include_stack, limits, depth + 1)?,
+                );
+            }
+            Ok(Value::Object(map))
+        }
+        scalar => serde_json::to_value(scalar)
+            .map_err(|e| Error::config(format!("Failed to convert YAML value: {}", e))),
+    }
+}
+
+/// Expands `{ "!include": "path" }` objects in an already parsed value found
+/// at `depth`
+#[cfg(not(target_arch = "wasm32"))]
+fn resolve_includes(
+    value: Value,
+    base_dir: &Path,
+    format: ConfigFormat,
+    include_stack: &mut Vec<PathBuf>,
+    limits: &JsonLimits,
+    depth: usize,
+) -> Result<Value> {
+    match value {
+        Value::Object(map) if map.len() == 1 && map.contains_key(INCLUDE_DIRECTIVE) => {
+            match &map[INCLUDE_DIRECTIVE] {
+                Value::String(target) => {
+                    load_include(target, base_dir, format, include_stack, limits, depth)
+                }
+                _ => Err(Error::config("The !include directive expects a file path")),
+            }
+        }
+        Value::Object(map) => {
+            check_include_depth(limits, depth + 1)?;
+            let mut resolved = Map::new();
+            for (key, item) in map {
+                resolved.insert(
+                    key,
+                    resolve_includes(item, base_dir, format, include_stack, limits, depth + 1)?,
+                );
+            }
+            Ok(Value::Object(resolved))
+        }
+        Value::Array(items) => {
+            check_include_depth(limits, depth + 1)?;
+            items
+                .into_iter()
+                .map(|item| {
+                    resolve_includes(item, base_dir, format, include_stack, limits, depth + 1)
+                })
+                .collect::<Result<Vec<_>>>()
+                .map(Value::Array)
+        }
+        other => Ok(other),
+    }
+}
+
 impl ConfigManager {
     pub fn new() -> Self {
         let (change_notifier, _) = broadcast::channel(100);
 
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "config_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "config_manager"),
             layers: Vec::new(),
             merged_config: Arc::new(RwLock::new(Value::Object(Map::new()))),
+            value_sources: Arc::new(RwLock::new(HashMap::new())),
             change_notifier,
             watch_enabled: true,
             env_prefix: "QORZEN".to_string(),
             event_bus: None,
+            includes_enabled: false,
+            sensitive_keys: default_sensitive_keys(),
+            value_limits: JsonLimits::default(),
+            load_retry: LoadRetryPolicy::default(),
         }
     }
 
@@ -592,10 +1314,48 @@ impl ConfigManager {
         self.layers.sort_by_key(|l| l.priority);
     }
 
+    /// Adds a layer read from the IndexedDB database `store`
+    #[cfg(target_arch = "wasm32")]
+    pub fn add_indexed_db_layer(
+        &mut self,
+        name: impl Into<String>,
+        store: impl Into<String>,
+        priority: u32,
+    ) {
+        let layer = ConfigLayer {
+            name: name.into(),
+            source: ConfigSource::IndexedDb {
+                store: store.into(),
+            },
+            priority,
+            hot_reload: false,
+        };
+
+        self.layers.push(layer);
+        self.layers.sort_by_key(|l| l.priority);
+    }
+
     pub fn set_event_bus(&mut self, event_bus: Arc<EventBusManager>) {
         self.event_bus = Some(event_bus);
     }
 
+    /// Enables `!include` directives and YAML merge keys in file layers.
+    ///
+    /// Disabled by default so plain configuration files are loaded verbatim.
+    pub fn enable_includes(&mut self, enabled: bool) {
+        self.includes_enabled = enabled;
+    }
+
+    /// Sets the maximum depth and node count accepted from any layer or `set` call
+    pub fn set_value_limits(&mut self, limits: JsonLimits) {
+        self.value_limits = limits;
+    }
+
+    /// Sets how file layers that are unavailable at startup are retried
+    pub fn set_load_retry(&mut self, policy: LoadRetryPolicy) {
+        self.load_retry = policy;
+    }
+
     pub async fn set<T>(&self, key: &str, value: T) -> Result<()>
     where
         T: Serialize,
@@ -609,11 +1369,24 @@ impl ConfigManager {
                 format!("Failed to serialize config value: {}", e),
             )
         })?;
+        self.check_value_limits(&serialized_value, Some(key))?;
 
         let mut config = self.merged_config.write().await;
         let old_value = self.get_nested_value(&config, key);
 
         self.set_nested_value(&mut config, key, serialized_value.clone());
+        {
+            let mut sources = self.value_sources.write().await;
+            let nested = format!("{}.", key);
+            sources.retain(|path, _| !path.starts_with(&nested));
+            record_value_sources(
+                &mut sources,
+                key,
+                &serialized_value,
+                RUNTIME_SOURCE,
+                u32::MAX,
+            );
+        }
 
         // Publish change event
         let change_event = ConfigChangeEvent {
@@ -634,6 +1407,58 @@ impl ConfigManager {
         Ok(())
     }
 
+    /// Set several keys at once, publishing a single change event.
+    ///
+    /// Keys are applied in sorted order under one write lock, so subscribers
+    /// never see some of them updated without the rest. The event's key is
+    /// [`CONFIG_BATCH_KEY`]; see [`ConfigChangeEvent::changed_keys`]. Nothing
+    /// is written if any value exceeds the configured limits.
+    pub async fn set_many(&self, values: HashMap<String, Value>) -> Result<()> {
+        if values.is_empty() {
+            return Ok(());
+        }
+        let mut values: Vec<(String, Value)> = values.into_iter().collect();
+        values.sort_by(|(a, _), (b, _)| a.cmp(b));
+        for (key, value) in &values {
+            self.check_value_limits(value, Some(key))?;
+        }
+
+        let mut new_values = Map::new();
+        let mut old_values = Map::new();
+        {
+            let mut config = self.merged_config.write().await;
+            let mut sources = self.value_sources.write().await;
+            for (key, value) in values {
+                let old_value = self.get_nested_value(&config, &key);
+                self.set_nested_value(&mut config, &key, value.clone());
+
+                let nested = format!("{}.", key);
+                sources.retain(|path, _| !path.starts_with(&nested));
+                record_value_sources(&mut sources, &key, &value, RUNTIME_SOURCE, u32::MAX);
+
+                old_values.insert(key.clone(), old_value.unwrap_or(Value::Null));
+                new_values.insert(key, value);
+            }
+        }
+
+        let change_event = ConfigChangeEvent {
+            key: CONFIG_BATCH_KEY.to_string(),
+            value: Value::Object(new_values),
+            old_value: Some(Value::Object(old_values)),
+            timestamp: Time::now(),
+            source: "config_manager".to_string(),
+            metadata: HashMap::new(),
+        };
+
+        if let Some(event_bus) = &self.event_bus {
+            let _ = event_bus.publish(change_event.clone()).await;
+        }
+
+        let _ = self.change_notifier.send(change_event);
+
+        Ok(())
+    }
+
     pub async fn get<T>(&self, key: &str) -> Result<T>
     where
         T: for<'de> Deserialize<'de>,
@@ -660,24 +1485,113 @@ impl ConfigManager {
         })
     }
 
+    /// Name and priority of the layer that supplied `key` in the merged config.
+    ///
+    /// For a section key, the highest-priority layer among its values is
+    /// returned. Values written with [`Self::set`] report `config_manager`.
+    pub async fn value_source(&self, key: &str) -> Option<(String, u32)> {
+        let sources = self.value_sources.read().await;
+        if let Some(source) = sources.get(key) {
+            return Some(source.clone());
+        }
+
+        let nested = format!("{}.", key);
+        sources
+            .iter()
+            .filter(|(path, _)| path.starts_with(&nested))
+            .map(|(_, source)| source)
+            .max_by_key(|(_, priority)| *priority)
+            .cloned()
+    }
+
     pub async fn get_config(&self) -> AppConfig {
         let config = self.merged_config.read().await;
         match serde_json::from_value(config.clone()) {
             Ok(parsed) => parsed,
             Err(e) => {
-                eprintln!("⚠️ Failed to deserialize config: {:?}", e);
-                eprintln!("🔎 Raw config: {}", config);
+                tracing::warn!(error = %e, raw = %config, "Failed to deserialize config, using defaults");
                 AppConfig::default()
             }
         }
     }
 
+    /// Serializes the merged configuration in the requested format
+    pub async fn export(&self, format: ConfigFormat) -> Result<String> {
+        let config = self.merged_config.read().await;
+
+        match format {
+            ConfigFormat::Json => serde_json::to_string_pretty(&*config)
+                .map_err(|e| Error::config(format!("Failed to export JSON config: {}", e))),
+            ConfigFormat::Yaml => serde_yaml::to_string(&*config)
+                .map_err(|e| Error::config(format!("Failed to export YAML config: {}", e))),
+            ConfigFormat::Toml => {
+                if !config.is_object() {
+                    return Err(Error::config(
+                        "Cannot export TOML config: the root value must be a table",
+                    ));
+                }
+                toml::to_string_pretty(&*config)
+                    .map_err(|e| Error::config(format!("Failed to export TOML config: {}", e)))
+            }
+        }
+    }
+
     pub fn subscribe_to_changes(&self) -> broadcast::Receiver<ConfigChangeEvent> {
         self.change_notifier.subscribe()
     }
 
+    /// Watch a configuration section as a typed value.
+    ///
+    /// Yields the freshly deserialized section after any key inside it changes.
+    /// Bursts of changes are debounced into a single item, and values that fail
+    /// to deserialize are skipped with a warning.
+    pub fn watch_section<S: ConfigSection>(&self) -> impl futures::Stream<Item = S> {
+        let receiver = self.change_notifier.subscribe();
+        let merged_config = Arc::clone(&self.merged_config);
+
+        futures::stream::unfold(
+            (receiver, merged_config),
+            |(mut receiver, merged_config)| async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(event)
+                            if event
+                                .changed_keys()
+                                .iter()
+                                .any(|key| change_affects_section(key, S::SECTION)) => {}
+                        Ok(_) => continue,
+                        Err(broadcast::error::RecvError::Lagged(_)) => {}
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+
+                    // Coalesce changes that arrive during the quiet period
+                    sleep_for(SECTION_WATCH_DEBOUNCE).await;
+                    while !receiver.is_empty() {
+                        if let Err(broadcast::error::RecvError::Closed) = receiver.recv().await {
+                            break;
+                        }
+                    }
+
+                    let section = merged_config.read().await.get(S::SECTION).cloned();
+                    match section.map(serde_json::from_value::<S>) {
+                        Some(Ok(value)) => return Some((value, (receiver, merged_config))),
+                        Some(Err(e)) => {
+                            tracing::warn!(
+                                "Failed to deserialize config section '{}': {}",
+                                S::SECTION,
+                                e
+                            );
+                        }
+                        None => {}
+                    }
+                }
+            },
+        )
+    }
+
     pub async fn reload(&self) -> Result<()> {
-        self.merge_configurations().await?;
+        self.merge_configurations(LoadRetryPolicy::disabled())
+            .await?;
 
         // Publish reload event
         let reload_event = ConfigChangeEvent {
@@ -698,6 +1612,29 @@ impl ConfigManager {
         Ok(())
     }
 
+    /// JSON Schema for [`AppConfig`], including defaults and field docs.
+    ///
+    /// Editors can validate YAML and JSON config files against it.
+    pub fn json_schema() -> Value {
+        serde_json::to_value(schemars::schema_for!(AppConfig)).unwrap_or_default()
+    }
+
+    /// Writes [`Self::json_schema`] to `path` as pretty-printed JSON
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn write_json_schema(path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let schema = serde_json::to_string_pretty(&Self::json_schema())
+            .map_err(|e| Error::config(format!("Failed to serialize config schema: {}", e)))?;
+
+        std::fs::write(path, schema).map_err(|e| {
+            Error::file(
+                path.display().to_string(),
+                crate::error::FileOperation::Write,
+                format!("Failed to write config schema: {}", e),
+            )
+        })
+    }
+
     pub async fn validate(&self) -> Result<Vec<ValidationError>> {
         let _config = self.merged_config.read().await;
         let errors = Vec::new();
@@ -709,25 +1646,79 @@ impl ConfigManager {
         Ok(errors)
     }
 
-    async fn merge_configurations(&self) -> Result<()> {
+    async fn merge_configurations(&self, retry: LoadRetryPolicy) -> Result<()> {
         let mut merged = Value::Object(Map::new());
+        let mut sources = ValueSources::new();
 
         // Process layers in priority order (lowest to highest)
         for layer in &self.layers {
-            let layer_config = self.load_layer_config(layer).await?;
+            let layer_config = self.load_layer_with_retry(layer, retry).await?;
+            self.check_value_limits(&layer_config, None)
+                .map_err(|e| e.metadata("layer", Value::String(layer.name.clone())))?;
+            record_value_sources(&mut sources, "", &layer_config, &layer.name, layer.priority);
             merge_values(&mut merged, layer_config);
         }
+        check_cors_settings(&merged)?;
 
         *self.merged_config.write().await = merged;
+        *self.value_sources.write().await = sources;
         Ok(())
     }
 
+    /// Loads a layer, retrying with backoff while its file cannot be opened
+    async fn load_layer_with_retry(
+        &self,
+        layer: &ConfigLayer,
+        retry: LoadRetryPolicy,
+    ) -> Result<Value> {
+        let mut backoff = retry.initial_backoff;
+        let mut attempt = 0;
+
+        loop {
+            match self.load_layer_config(layer).await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < retry.max_retries && layer_file_unavailable(layer) => {
+                    attempt += 1;
+                    tracing::warn!(
+                        "Config layer '{}' unavailable (attempt {}/{}), retrying in {:?}: {}",
+                        layer.name,
+                        attempt,
+                        retry.max_retries + 1,
+                        backoff,
+                        e
+                    );
+                    sleep_for(backoff).await;
+                    backoff = (backoff * 2).min(retry.max_backoff);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
     async fn load_layer_config(&self, layer: &ConfigLayer) -> Result<Value> {
         match &layer.source {
             #[cfg(not(target_arch = "wasm32"))]
             ConfigSource::File { path, format } => {
+                if self.includes_enabled {
+                    let mut include_stack = Vec::new();
+                    return load_config_file_with_includes(
+                        path,
+                        *format,
+                        &mut include_stack,
+                        &self.value_limits,
+                        0,
+                    );
+                }
+
                 let content = std::fs::read_to_string(path)
                     .map_err(|e| Error::config(format!("Failed to read config file: {}", e)))?;
+                self.value_limits.check_text(&content).map_err(|e| {
+                    Error::config(format!(
+                        "Config file '{}' rejected: {}",
+                        path.display(),
+                        e.message
+                    ))
+                })?;
 
                 match format {
                     ConfigFormat::Json => serde_json::from_str(&content)
@@ -767,9 +1758,43 @@ impl ConfigManager {
             ConfigSource::Environment { .. } => Ok(Value::Object(serde_json::Map::new())),
 
             ConfigSource::Memory { data } => Ok(data.clone()),
+
+            #[cfg(target_arch = "wasm32")]
+            ConfigSource::IndexedDb { store } => {
+                let saved = indexed_db::load_document(store).await?;
+                self.value_limits.check(&saved).map_err(|e| {
+                    Error::config(format!(
+                        "IndexedDB config store '{}' rejected: {}",
+                        store, e.message
+                    ))
+                })?;
+
+                // The store keeps dotted keys side by side; layers are nested
+                let mut document = Value::Object(Map::new());
+                if let Value::Object(values) = saved {
+                    for (key, value) in values {
+                        self.set_nested_value(&mut document, &key, value);
+                    }
+                }
+                Ok(document)
+            }
         }
     }
 
+    /// Guards the recursive merge against oversized values
+    fn check_value_limits(&self, value: &Value, key: Option<&str>) -> Result<()> {
+        self.value_limits.check(value).map_err(|e| {
+            Error::new(
+                crate::error::ErrorKind::Configuration {
+                    key: key.map(str::to_string),
+                    validation_errors: vec![e.message.clone()],
+                },
+                format!("Configuration value rejected: {}", e.message),
+            )
+            .severity(crate::error::ErrorSeverity::High)
+        })
+    }
+
     fn get_nested_value(&self, config: &Value, key: &str) -> Option<Value> {
         let keys: Vec<&str> = key.split('.').collect();
         let mut current = config;
@@ -813,6 +1838,48 @@ impl ConfigManager {
         config.clone()
     }
 
+    /// Sets the key patterns treated as sensitive by the redacted accessors.
+    ///
+    /// A key is sensitive when it contains any pattern, case-insensitively.
+    pub fn set_sensitive_keys(&mut self, keys: Vec<String>) {
+        self.sensitive_keys = keys;
+    }
+
+    /// Returns the merged configuration with sensitive values redacted
+    pub async fn debug_config_redacted(&self) -> Value {
+        let mut config = self.debug_config().await;
+        redact_value(&mut config, &self.sensitive_keys);
+        config
+    }
+
+    /// Returns layer metadata with file paths reduced to their file names
+    pub fn get_metadata_redacted(&self) -> Value {
+        let file_name = |path: &PathBuf| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| REDACTED_VALUE.to_string())
+        };
+
+        let mut metadata = self.get_metadata();
+        if let Some(layers) = metadata.get_mut("layer_info").and_then(Value::as_array_mut) {
+            for (info, layer) in layers.iter_mut().zip(&self.layers) {
+                if let ConfigSource::File { path, .. } = &layer.source {
+                    info["source_type"] = Value::String(file_name(path));
+                }
+            }
+        }
+        metadata["config_path"] = self
+            .layers
+            .iter()
+            .find_map(|l| match &l.source {
+                ConfigSource::File { path, .. } => Some(Value::String(file_name(path))),
+                _ => None,
+            })
+            .unwrap_or_else(|| Value::String("none".to_string()));
+
+        metadata
+    }
+
     pub fn get_metadata(&self) -> Value {
         serde_json::json!({
             "layers": self.layers.len(),
@@ -829,6 +1896,10 @@ impl ConfigManager {
                             format!("env:{}", prefix)
                         }
                         ConfigSource::Memory { .. } => "memory".to_string(),
+                        #[cfg(target_arch = "wasm32")]
+                        ConfigSource::IndexedDb { store } => {
+                            format!("indexeddb:{}", store)
+                        }
                     }
                 })
             }).collect::<Vec<_>>(),
@@ -846,6 +1917,24 @@ impl ConfigManager {
     }
 }
 
+/// Refuses a merged `security` section that allows credentials from any origin
+fn check_cors_settings(merged: &Value) -> Result<()> {
+    let Some(security) = merged.get("security") else {
+        return Ok(());
+    };
+    let mut config = SecurityConfig::default();
+    if let Some(origins) = security.get("cors_origins") {
+        config.cors_origins = serde_json::from_value(origins.clone()).unwrap_or_default();
+    }
+    if let Some(allow) = security
+        .get("cors_allow_credentials")
+        .and_then(Value::as_bool)
+    {
+        config.cors_allow_credentials = allow;
+    }
+    config.validate_cors()
+}
+
 impl Default for ConfigManager {
     fn default() -> Self {
         Self::new()
@@ -873,8 +1962,8 @@ impl Manager for ConfigManager {
         let env_prefix = self.env_prefix.clone();
         self.add_env_layer("environment", &env_prefix, 1000);
 
-        // Load and merge all configurations
-        self.merge_configurations().await?;
+        // Load and merge all configurations, retrying files that are briefly unavailable
+        self.merge_configurations(self.load_retry).await?;
 
         // TODO: Setup file watching for hot-reload
 
@@ -926,8 +2015,8 @@ impl Manager for ConfigManager {
         let env_prefix = self.env_prefix.clone();
         self.add_env_layer("environment", &env_prefix, 1000);
 
-        // Load and merge all configurations
-        self.merge_configurations().await?;
+        // Load and merge all configurations, retrying files that are briefly unavailable
+        self.merge_configurations(self.load_retry).await?;
 
         // TODO: Setup file watching for hot-reload
 
@@ -994,6 +2083,235 @@ mod tests {
         assert!(debug);
     }
 
+    #[tokio::test]
+    async fn test_include_directive() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("database.yaml"),
+            "url: \"postgres://localhost/test\"\nmax_connections: 5\n",
+        )
+        .unwrap();
+        let main_path = dir.path().join("config.yaml");
+        std::fs::write(
+            &main_path,
+            "app:\n  name: \"Include App\"\ndatabase: !include database.yaml\n",
+        )
+        .unwrap();
+
+        let mut manager = ConfigManager::new();
+        manager.enable_includes(true);
+        manager
+            .add_file_layer("main", &main_path, 0, false)
+            .unwrap();
+        manager.initialize().await.unwrap();
+
+        let url: String = manager.get("database.url").await.unwrap();
+        assert_eq!(url, "postgres://localhost/test");
+
+        let max_connections: u32 = manager.get("database.max_connections").await.unwrap();
+        assert_eq!(max_connections, 5);
+    }
+
+    #[tokio::test]
+    async fn test_include_cycle_detected() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("a.json"),
+            r#"{"b": {"!include": "b.json"}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("b.json"),
+            r#"{"a": {"!include": "a.json"}}"#,
+        )
+        .unwrap();
+
+        let mut manager = ConfigManager::new();
+        manager.enable_includes(true);
+        manager
+            .add_file_layer("main", dir.path().join("a.json"), 0, false)
+            .unwrap();
+
+        assert!(manager.initialize().await.is_err());
+    }
+
+    #[test]
+    fn test_include_limits_cover_every_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let limits = JsonLimits::new(4, 1_000);
+        let load = |name: &str| {
+            let path = dir.path().join(name);
+            let format = ConfigFormat::from_extension(&path).unwrap();
+            load_config_file_with_includes(&path, format, &mut Vec::new(), &limits, 0)
+        };
+        std::fs::write(dir.path().join("flow.json"), r#"{"a": {"b": [1]}}"#).unwrap();
+        std::fs::write(dir.path().join("block.yaml"), "a:\n  b:\n    c: 1\n").unwrap();
+
+        // Each file fits on its own
+        std::fs::write(dir.path().join("top.json"), r#"{"!include": "flow.json"}"#).unwrap();
+        assert_eq!(load("top.json").unwrap()["a"]["b"][0], 1);
+
+        // Included text is checked against the depth left at the include point
+        std::fs::write(
+            dir.path().join("nested.json"),
+            r#"{"x": {"y": {"!include": "flow.json"}}}"#,
+        )
+        .unwrap();
+        let error = load("nested.json").unwrap_err();
+        assert!(error.message.contains("flow.json' rejected"), "{}", error);
+
+        // Block-style YAML has no brackets, so its parsed depth is counted
+        std::fs::write(
+            dir.path().join("nested.yaml"),
+            "x:\n  y: !include block.yaml\n",
+        )
+        .unwrap();
+        let error = load("nested.yaml").unwrap_err();
+        assert!(
+            error.message.contains("maximum nesting depth of 4"),
+            "{}",
+            error
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_round_trip() {
+        let mut manager = ConfigManager::new();
+        manager.add_memory_layer(
+            "memory",
+            serde_json::json!({
+                "app": { "name": "Export App", "debug": true },
+                "network": { "port": 9090 }
+            }),
+            0,
+        );
+        manager.initialize().await.unwrap();
+
+        let yaml = manager.export(ConfigFormat::Yaml).await.unwrap();
+        let from_yaml: Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(from_yaml["app"]["name"], "Export App");
+
+        let json = manager.export(ConfigFormat::Json).await.unwrap();
+        let from_json: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(from_json["network"]["port"], 9090);
+
+        let toml = manager.export(ConfigFormat::Toml).await.unwrap();
+        let from_toml: Value = toml::from_str(&toml).unwrap();
+        assert_eq!(from_toml["app"]["debug"], true);
+    }
+
+    #[tokio::test]
+    async fn test_export_toml_requires_table_root() {
+        let manager = ConfigManager::new();
+        *manager.merged_config.write().await = Value::String("scalar".to_string());
+
+        assert!(manager.export(ConfigFormat::Toml).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_debug_config_redacted() {
+        let mut manager = ConfigManager::new();
+        manager.add_memory_layer(
+            "memory",
+            serde_json::json!({
+                "security": {
+                    "jwt_secret": "super-secret",
+                    "api_key": "key-123",
+                    "enable_cors": true
+                },
+                "database": { "password": "hunter2", "url": "sqlite://test.db" },
+                "integrations": [{ "access_token": "abc" }]
+            }),
+            0,
+        );
+        manager.initialize().await.unwrap();
+
+        let redacted = manager.debug_config_redacted().await;
+        assert_eq!(redacted["security"]["jwt_secret"], REDACTED_VALUE);
+        assert_eq!(redacted["security"]["api_key"], REDACTED_VALUE);
+        assert_eq!(redacted["security"]["enable_cors"], true);
+        assert_eq!(redacted["database"]["password"], REDACTED_VALUE);
+        assert_eq!(redacted["database"]["url"], "sqlite://test.db");
+        assert_eq!(redacted["integrations"][0]["access_token"], REDACTED_VALUE);
+
+        manager.set_sensitive_keys(vec!["url".to_string()]);
+        let redacted = manager.debug_config_redacted().await;
+        assert_eq!(redacted["database"]["url"], REDACTED_VALUE);
+        assert_eq!(redacted["database"]["password"], "hunter2");
+    }
+
+    #[tokio::test]
+    async fn test_deeply_nested_file_rejected() {
+        let depth = 10_000;
+        let content = format!("{}1{}", "{\"a\":".repeat(depth), "}".repeat(depth));
+
+        let mut temp_file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        temp_file.write_all(content.as_bytes()).unwrap();
+
+        let mut manager = ConfigManager::new();
+        manager
+            .add_file_layer("deep", temp_file.path(), 0, false)
+            .unwrap();
+
+        let err = manager.initialize().await.unwrap_err();
+        assert!(err.message.contains("maximum nesting depth"));
+    }
+
+    #[tokio::test]
+    async fn test_oversized_value_rejected_by_set() {
+        let mut manager = ConfigManager::new();
+        manager.set_value_limits(JsonLimits::new(4, 100));
+
+        let nested = serde_json::json!({ "a": { "b": { "c": { "d": { "e": 1 } } } } });
+        assert!(manager.set("deep", nested).await.is_err());
+        assert!(manager
+            .set("shallow", serde_json::json!({ "a": 1 }))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_watch_section() {
+        use futures::StreamExt;
+
+        let mut manager = ConfigManager::new();
+        manager.add_memory_layer(
+            "defaults",
+            serde_json::to_value(AppConfig::default()).unwrap(),
+            0,
+        );
+        manager.initialize().await.unwrap();
+
+        let stream = manager.watch_section::<DatabaseConfig>();
+        futures::pin_mut!(stream);
+
+        manager
+            .set("database.url", "postgres://localhost/watched")
+            .await
+            .unwrap();
+        let database = tokio::time::timeout(std::time::Duration::from_secs(1), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(database.url, "postgres://localhost/watched");
+
+        manager.set("network.port", 9999).await.unwrap();
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(200), stream.next())
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_change_affects_section() {
+        assert!(change_affects_section("database.url", "database"));
+        assert!(change_affects_section("database", "database"));
+        assert!(change_affects_section("_reload", "database"));
+        assert!(!change_affects_section("databases.url", "database"));
+        assert!(!change_affects_section("network.port", "database"));
+    }
+
     #[tokio::test]
     async fn test_environment_layer() {
         let mut manager = ConfigManager::new();
@@ -1016,6 +2334,43 @@ mod tests {
         std::env::remove_var("TEST_APP_DEBUG");
     }
 
+    #[tokio::test]
+    async fn test_value_source_reports_overriding_layer() {
+        let mut manager = ConfigManager::new();
+        manager.add_memory_layer(
+            "defaults",
+            serde_json::json!({
+                "database": { "url": "sqlite://default.db", "pool_size": 5 }
+            }),
+            0,
+        );
+
+        std::env::set_var("PROVTEST_DATABASE_URL", "postgres://env/db");
+        manager.add_env_layer("env", "PROVTEST", 100);
+        manager.initialize().await.unwrap();
+        std::env::remove_var("PROVTEST_DATABASE_URL");
+
+        assert_eq!(
+            manager.value_source("database.url").await,
+            Some(("env".to_string(), 100))
+        );
+        assert_eq!(
+            manager.value_source("database.pool_size").await,
+            Some(("defaults".to_string(), 0))
+        );
+        assert_eq!(
+            manager.value_source("database").await,
+            Some(("env".to_string(), 100))
+        );
+        assert!(manager.value_source("network.port").await.is_none());
+
+        manager.set("database.pool_size", 10).await.unwrap();
+        assert_eq!(
+            manager.value_source("database.pool_size").await,
+            Some((RUNTIME_SOURCE.to_string(), u32::MAX))
+        );
+    }
+
     #[tokio::test]
     async fn test_memory_layer() {
         let mut manager = ConfigManager::new();
@@ -1034,6 +2389,33 @@ mod tests {
         assert_eq!(app_name, "Memory App");
     }
 
+    #[tokio::test]
+    async fn test_rejects_credentials_with_any_origin() {
+        let mut manager = ConfigManager::new();
+        manager.add_memory_layer(
+            "memory",
+            serde_json::json!({ "security": { "cors_allow_credentials": true } }),
+            50,
+        );
+        let error = manager.initialize().await.unwrap_err();
+        assert!(matches!(
+            error.kind,
+            crate::error::ErrorKind::Configuration { key: Some(ref key), .. }
+                if key == "security.cors_origins"
+        ));
+
+        let mut manager = ConfigManager::new();
+        manager.add_memory_layer(
+            "memory",
+            serde_json::json!({ "security": {
+                "cors_allow_credentials": true,
+                "cors_origins": ["https://app.example.com"]
+            } }),
+            50,
+        );
+        manager.initialize().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_configuration_change() {
         let mut manager = ConfigManager::new();
@@ -1050,4 +2432,247 @@ mod tests {
 
         assert_eq!(change.value, Value::Bool(false));
     }
+
+    #[test]
+    fn test_settings_schema_validation() {
+        let schema = SettingsSchema {
+            version: "1".to_string(),
+            schema: serde_json::json!({
+                "type": "object",
+                "required": ["warehouse"],
+                "additionalProperties": false,
+                "properties": {
+                    "warehouse": { "type": "string", "minLength": 2 },
+                    "threshold": { "type": "integer", "minimum": 0, "maximum": 100 },
+                    "unit": { "type": "string", "enum": ["kg", "lb"] },
+                    "tags": { "type": "array", "items": { "type": "string" } }
+                }
+            }),
+            defaults: serde_json::json!({}),
+        };
+
+        let valid = serde_json::json!({ "warehouse": "north", "threshold": 5, "tags": ["a"] });
+        assert!(schema.validate(&valid).is_empty());
+
+        let errors = schema.validate(&serde_json::json!({
+            "threshold": 250,
+            "unit": "oz",
+            "tags": ["a", 3],
+            "colour": "red"
+        }));
+        let keys: std::collections::BTreeSet<&str> =
+            errors.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(errors.len(), 5);
+        assert_eq!(
+            keys,
+            ["colour", "tags.1", "threshold", "unit", "warehouse"].into()
+        );
+        let message = |key: &str| {
+            errors
+                .iter()
+                .find(|e| e.key == key)
+                .map(|e| e.message.as_str())
+        };
+        assert_eq!(message("warehouse"), Some("is required"));
+        assert_eq!(message("colour"), Some("is not a known setting"));
+
+        let errors = schema.validate(&serde_json::json!({ "warehouse": 7 }));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].key, "warehouse");
+
+        // Keywords beyond the basics are enforced too
+        let schema = SettingsSchema {
+            version: "1".to_string(),
+            schema: serde_json::json!({
+                "type": "object",
+                "$defs": { "sku": { "type": "string", "pattern": "^[A-Z]{2}-[0-9]+$" } },
+                "properties": {
+                    "sku": { "$ref": "#/$defs/sku" },
+                    "limit": { "oneOf": [{ "type": "integer" }, { "const": "unlimited" }] },
+                    "sync": {
+                        "type": "object",
+                        "properties": { "interval": { "type": "integer", "minimum": 1 } }
+                    }
+                }
+            }),
+            defaults: serde_json::json!({}),
+        };
+        let valid = serde_json::json!({ "sku": "AB-12", "limit": "unlimited" });
+        assert!(schema.validate(&valid).is_empty());
+        let errors = schema.validate(&serde_json::json!({
+            "sku": "ab12",
+            "limit": "lots",
+            "sync": { "interval": 0 }
+        }));
+        let keys: std::collections::BTreeSet<&str> =
+            errors.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, ["limit", "sku", "sync.interval"].into());
+    }
+
+    #[tokio::test]
+    async fn test_set_many_publishes_one_event() {
+        let mut manager = ConfigManager::new();
+        manager.add_memory_layer(
+            "memory",
+            serde_json::json!({ "database": { "url": "sqlite://old.db" } }),
+            0,
+        );
+        manager.initialize().await.unwrap();
+        let mut changes = manager.subscribe_to_changes();
+        manager
+            .set_many(HashMap::from([
+                (
+                    "database.url".to_string(),
+                    Value::from("postgres://localhost/batch"),
+                ),
+                ("database.max_connections".to_string(), Value::from(4)),
+            ]))
+            .await
+            .unwrap();
+
+        let url: String = manager.get("database.url").await.unwrap();
+        let max_connections: u32 = manager.get("database.max_connections").await.unwrap();
+        assert_eq!(url, "postgres://localhost/batch");
+        assert_eq!(max_connections, 4);
+
+        let event = changes.try_recv().unwrap();
+        assert_eq!(event.key, CONFIG_BATCH_KEY);
+        assert_eq!(
+            event.changed_keys(),
+            ["database.max_connections", "database.url"]
+        );
+        let old_value = event.old_value.clone().unwrap();
+        assert_eq!(old_value["database.url"], "sqlite://old.db");
+        assert_eq!(old_value["database.max_connections"], Value::Null);
+        assert!(changes.try_recv().is_err());
+        assert!(change_affects_section(event.changed_keys()[1], "database"));
+    }
+
+    #[tokio::test]
+    async fn test_file_layer_retried_until_available() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("late.yaml");
+
+        let mut manager = ConfigManager::new();
+        manager.add_file_layer("late", &path, 0, false).unwrap();
+        manager.set_load_retry(LoadRetryPolicy {
+            max_retries: 5,
+            initial_backoff: std::time::Duration::from_millis(100),
+            max_backoff: std::time::Duration::from_millis(100),
+        });
+
+        let writer_path = path.clone();
+        let writer = tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+            std::fs::write(writer_path, "app:\n  name: \"Late App\"\n").unwrap();
+        });
+
+        manager.initialize().await.unwrap();
+        writer.await.unwrap();
+
+        let app_name: String = manager.get("app.name").await.unwrap();
+        assert_eq!(app_name, "Late App");
+    }
+
+    #[tokio::test]
+    async fn test_file_layer_retry_exhausted() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut manager = ConfigManager::new();
+        manager
+            .add_file_layer("missing", dir.path().join("missing.yaml"), 0, false)
+            .unwrap();
+        manager.set_load_retry(LoadRetryPolicy {
+            max_retries: 2,
+            initial_backoff: std::time::Duration::from_millis(1),
+            max_backoff: std::time::Duration::from_millis(1),
+        });
+
+        assert!(manager.initialize().await.is_err());
+    }
+
+    #[test]
+    fn test_json_schema_includes_network_port() {
+        let schema = ConfigManager::json_schema();
+
+        assert!(schema["properties"]["network"].is_object());
+        let port = &schema["definitions"]["NetworkConfig"]["properties"]["port"];
+        assert_eq!(port["type"], "integer");
+        assert_eq!(port["description"], "Port the server listens on");
+    }
+
+    #[test]
+    fn test_partial_app_config_uses_schema_defaults() {
+        let config: AppConfig = serde_json::from_value(serde_json::json!({
+            "app": { "name": "Partial" },
+            "network": { "port": 9000 }
+        }))
+        .unwrap();
+
+        assert_eq!(config.app.name, "Partial");
+        assert_eq!(config.app.version, AppSettings::default().version);
+        assert_eq!(config.network.port, 9000);
+        assert_eq!(
+            config.network.bind_address,
+            NetworkConfig::default().bind_address
+        );
+        assert_eq!(config.logging.level, LoggingConfig::default().level);
+
+        let schema = ConfigManager::json_schema();
+        assert_eq!(
+            schema["definitions"]["NetworkConfig"]["properties"]["bind_address"]["default"],
+            serde_json::json!(config.network.bind_address)
+        );
+    }
+
+    #[test]
+    fn test_database_config_without_reconnect_settings() {
+        let config: DatabaseConfig = serde_json::from_value(serde_json::json!({
+            "url": "sqlite://./data/app.db",
+            "max_connections": 10,
+            "connect_timeout_secs": 30,
+            "query_timeout_secs": 60,
+            "enable_pooling": true,
+            "enable_query_logging": false
+        }))
+        .unwrap();
+
+        let defaults = DatabaseConfig::default();
+        assert_eq!(
+            config.reconnect_initial_backoff_ms,
+            defaults.reconnect_initial_backoff_ms
+        );
+        assert_eq!(
+            config.reconnect_max_backoff_ms,
+            defaults.reconnect_max_backoff_ms
+        );
+        assert_eq!(config.reconnect_max_attempts, 0);
+    }
+
+    #[test]
+    fn test_app_settings_without_timeouts() {
+        let settings: AppSettings = serde_json::from_value(serde_json::json!({
+            "name": "Qorzen",
+            "version": "0.1.0",
+            "description": "",
+            "environment": "production",
+            "debug": false,
+            "data_dir": "./data",
+            "config_dir": "./config",
+            "log_dir": "./logs",
+            "pid_file": null
+        }))
+        .unwrap();
+
+        let defaults = AppSettings::default();
+        assert_eq!(
+            settings.health_check_timeout_ms,
+            defaults.health_check_timeout_ms
+        );
+        assert_eq!(
+            settings.manager_init_timeout_ms,
+            defaults.manager_init_timeout_ms
+        );
+        assert!(settings.manager_init_timeouts.is_empty());
+    }
 }
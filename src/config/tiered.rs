@@ -438,7 +438,7 @@ impl TieredConfigManager {
     /// Creates a new tiered configuration manager
     pub fn new() -> Self {
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "tiered_config_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "tiered_config_manager"),
             stores: HashMap::new(),
             merger: ConfigMerger::new(),
             sync_manager: Some(ConfigSyncManager::new(Duration::from_secs(300))), // 5 minutes
@@ -467,15 +467,7 @@ impl TieredConfigManager {
         }
 
         // Collect values from all tiers
-        let mut tier_values = Vec::new();
-
-        for tier in ConfigurationTier::all_tiers() {
-            if let Some(store) = self.stores.get(&tier) {
-                if let Some(value) = store.get(key).await? {
-                    tier_values.push((tier, value));
-                }
-            }
-        }
+        let tier_values = self.tier_values(key).await?;
 
         if tier_values.is_empty() {
             return Ok(None);
@@ -496,6 +488,47 @@ impl TieredConfigManager {
         Ok(Some(result))
     }
 
+    /// Gets a merged value together with the tier that takes precedence for it.
+    ///
+    /// The tier is the highest one holding the key, so a value set at
+    /// [`ConfigurationTier::User`] over a system default reports `User`.
+    /// Object values are merged across tiers exactly as in [`Self::get`].
+    pub async fn get_with_source(&self, key: &str) -> Result<(Value, ConfigurationTier)> {
+        let tier_values = self.tier_values(key).await?;
+        let source = tier_values
+            .iter()
+            .map(|(tier, _)| *tier)
+            .max_by_key(|tier| tier.precedence())
+            .ok_or_else(|| Error::config(format!("Configuration key '{}' not found", key)))?;
+
+        Ok((self.merger.merge(tier_values), source))
+    }
+
+    /// Reads a value from a single tier, ignoring every other tier
+    pub async fn get_from_tier(&self, tier: ConfigurationTier, key: &str) -> Result<Option<Value>> {
+        let store = self
+            .stores
+            .get(&tier)
+            .ok_or_else(|| Error::config(format!("No store configured for tier {:?}", tier)))?;
+
+        store.get(key).await
+    }
+
+    /// Values of `key` in each tier holding it, lowest precedence first
+    async fn tier_values(&self, key: &str) -> Result<Vec<(ConfigurationTier, Value)>> {
+        let mut tier_values = Vec::new();
+
+        for tier in ConfigurationTier::all_tiers() {
+            if let Some(store) = self.stores.get(&tier) {
+                if let Some(value) = store.get(key).await? {
+                    tier_values.push((tier, value));
+                }
+            }
+        }
+
+        Ok(tier_values)
+    }
+
     /// Sets a configuration value in a specific tier
     pub async fn set(&mut self, key: &str, value: Value, tier: ConfigurationTier) -> Result<()> {
         // Validate the value
@@ -938,6 +971,32 @@ mod tests {
         // Get merged value (user tier should override system)
         let app_name: Option<String> = manager.get("app.name").await.unwrap();
         assert_eq!(app_name, Some("User App".to_string()));
+
+        let (value, tier) = manager.get_with_source("app.name").await.unwrap();
+        assert_eq!(value, "User App");
+        assert_eq!(tier, ConfigurationTier::User);
+        assert!(manager.get_with_source("app.missing").await.is_err());
+
+        // Each tier can be read on its own
+        assert_eq!(
+            manager
+                .get_from_tier(ConfigurationTier::System, "app.name")
+                .await
+                .unwrap(),
+            Some(Value::String("System App".to_string()))
+        );
+        assert!(manager
+            .get_from_tier(ConfigurationTier::Runtime, "app.name")
+            .await
+            .is_err());
+
+        manager
+            .delete("app.name", ConfigurationTier::User)
+            .await
+            .unwrap();
+        let (value, tier) = manager.get_with_source("app.name").await.unwrap();
+        assert_eq!(value, "System App");
+        assert_eq!(tier, ConfigurationTier::System);
     }
 
     #[test]
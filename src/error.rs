@@ -3,10 +3,14 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::Arc;
 use uuid::Uuid;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Metadata flag marking an error as safe to retry
+pub const RETRYABLE_METADATA_KEY: &str = "retryable";
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ErrorSeverity {
     Low,
@@ -59,6 +63,11 @@ pub enum ErrorKind {
         plugin_name: Option<String>,
         dependency_missing: Option<String>,
     },
+    PluginLoad {
+        path: String,
+        symbol: Option<String>,
+        failure: PluginLoadFailure,
+    },
     Platform {
         platform: String,
         feature: String,
@@ -93,6 +102,12 @@ pub enum ErrorKind {
         action: String,
         user_id: Option<String>,
     },
+    NotFound {
+        resource: Option<String>,
+    },
+    RateLimited {
+        retry_after_secs: Option<u64>,
+    },
     Application,
     Io,
     Serialization,
@@ -113,6 +128,24 @@ pub enum ManagerOperation {
     Reload,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PluginLoadFailure {
+    /// No file exists at the library path
+    LibraryNotFound,
+    /// The file exists but could not be opened as a dynamic library
+    InvalidLibrary,
+    /// A required export is missing from the library
+    MissingSymbol,
+    /// `get_plugin_info` returned a null pointer
+    NullPluginInfo,
+    /// `get_plugin_info` returned something that is not a valid `PluginInfo`
+    InvalidPluginInfo,
+    /// `create_plugin` returned a null pointer
+    NullPlugin,
+    /// The library was built against a different plugin ABI version
+    AbiMismatch,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConfigOperation {
     Get,
@@ -151,6 +184,8 @@ pub enum ConcurrencyOperation {
     Sync,
     Channel,
     Lock,
+    /// A governed pool already has `max_queue_size` tasks waiting
+    QueueFull,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -164,15 +199,28 @@ pub struct Error {
     pub correlation_id: Option<Uuid>,
     pub timestamp: DateTime<Utc>,
     pub metadata: crate::types::Metadata,
+    /// Structured fields describing what failed, e.g. `key` or `retry_after_secs`
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub context: crate::types::Metadata,
     pub backtrace: Option<String>,
     pub causes: Vec<String>,
+    /// The underlying error returned by [`std::error::Error::source`].
+    ///
+    /// Shared rather than boxed so `Error` stays `Clone`; it is not serialized,
+    /// but its message is also recorded in `causes`.
+    #[serde(skip)]
+    pub cause: Option<Arc<dyn std::error::Error + Send + Sync>>,
 }
 
 impl Error {
-    /// Creates a new error with the specified kind and message
+    /// Creates a new error with the specified kind and message.
+    ///
+    /// Identifying fields of the kind, such as a configuration `key` or a
+    /// `retry_after_secs`, are copied into the context.
     pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
         Self {
-            id: Uuid::new_v4(),
+            id: crate::utils::rand::uuid(),
+            context: kind_context(&kind),
             kind,
             message: message.into(),
             severity: ErrorSeverity::Medium,
@@ -183,9 +231,22 @@ impl Error {
             metadata: std::collections::HashMap::new(),
             backtrace: Self::capture_backtrace(),
             causes: Vec::new(),
+            cause: None,
         }
     }
 
+    /// Creates an error wrapping an underlying error, kept as the source.
+    ///
+    /// `message` should say what failed; the source's own message is only
+    /// shown through the chain, e.g. by `{:#}`, so it is not repeated.
+    pub fn from_source(
+        kind: ErrorKind,
+        message: impl Into<String>,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        Self::new(kind, message).with_source_error(source)
+    }
+
     /// Capture backtrace if available on the platform
     fn capture_backtrace() -> Option<String> {
         #[cfg(not(target_arch = "wasm32"))]
@@ -234,12 +295,47 @@ impl Error {
         self
     }
 
+    /// Adds a structured context field
+    pub fn with_context_field(
+        mut self,
+        key: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.context.insert(key.into(), value.into());
+        self
+    }
+
+    /// A context field, if set
+    pub fn context_field(&self, key: &str) -> Option<&serde_json::Value> {
+        self.context.get(key)
+    }
+
+    /// A context field holding a string
+    pub fn context_str(&self, key: &str) -> Option<&str> {
+        self.context_field(key).and_then(serde_json::Value::as_str)
+    }
+
+    /// A context field holding an unsigned integer
+    pub fn context_u64(&self, key: &str) -> Option<u64> {
+        self.context_field(key).and_then(serde_json::Value::as_u64)
+    }
+
     /// Adds a cause to the error chain
     pub fn caused_by(mut self, cause: impl fmt::Display) -> Self {
         self.causes.push(cause.to_string());
         self
     }
 
+    /// Sets the underlying error returned by [`std::error::Error::source`]
+    pub fn with_source_error(
+        mut self,
+        source: impl std::error::Error + Send + Sync + 'static,
+    ) -> Self {
+        self.causes.push(source.to_string());
+        self.cause = Some(Arc::new(source));
+        self
+    }
+
     /// Checks if the error should be handled automatically
     pub fn should_handle(&self) -> bool {
         matches!(self.severity, ErrorSeverity::Low | ErrorSeverity::Medium)
@@ -250,6 +346,14 @@ impl Error {
         matches!(self.severity, ErrorSeverity::Critical)
     }
 
+    /// Checks if the operation may succeed when tried again later
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::Timeout | ErrorKind::RateLimited { .. }
+        ) || self.metadata.get(RETRYABLE_METADATA_KEY) == Some(&serde_json::Value::Bool(true))
+    }
+
     /// Creates a configuration error
     pub fn config(message: impl Into<String>) -> Self {
         Self::new(
@@ -320,6 +424,24 @@ impl Error {
         .severity(ErrorSeverity::Medium)
     }
 
+    /// Creates a plugin library loading error
+    pub fn plugin_load(
+        path: impl Into<String>,
+        symbol: Option<&str>,
+        failure: PluginLoadFailure,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            ErrorKind::PluginLoad {
+                path: path.into(),
+                symbol: symbol.map(str::to_string),
+                failure,
+            },
+            message,
+        )
+        .severity(ErrorSeverity::High)
+    }
+
     /// Creates an authentication error
     pub fn authentication(message: impl Into<String>) -> Self {
         let msg = message.into();
@@ -385,29 +507,149 @@ impl Error {
     pub fn timeout(message: impl Into<String>) -> Self {
         Self::new(ErrorKind::Timeout, message)
     }
+
+    /// Creates an error for a resource that does not exist
+    pub fn not_found(resource: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(
+            ErrorKind::NotFound {
+                resource: Some(resource.into()),
+            },
+            message,
+        )
+        .severity(ErrorSeverity::Low)
+    }
+
+    /// Creates an error for a caller that exceeded a rate limit
+    pub fn rate_limited(retry_after_secs: Option<u64>, message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::RateLimited { retry_after_secs }, message).severity(ErrorSeverity::Low)
+    }
+
+    /// The HTTP status code an API response for this error should carry
+    pub fn http_status(&self) -> u16 {
+        match &self.kind {
+            ErrorKind::Validation { .. } | ErrorKind::Serialization => 400,
+            ErrorKind::Authentication { .. } => 401,
+            ErrorKind::Authorization { .. }
+            | ErrorKind::Permission { .. }
+            | ErrorKind::Security { .. } => 403,
+            ErrorKind::NotFound { .. } => 404,
+            ErrorKind::RateLimited { .. } => 429,
+            ErrorKind::Platform { .. } => 501,
+            ErrorKind::Network {
+                status_code: Some(status),
+                ..
+            } if (400..600).contains(status) => *status,
+            ErrorKind::Network { .. } => 502,
+            ErrorKind::ResourceExhausted
+            | ErrorKind::Concurrency {
+                operation: ConcurrencyOperation::QueueFull,
+                ..
+            } => 503,
+            ErrorKind::Database { .. } if self.is_retryable() => 503,
+            ErrorKind::Timeout => 504,
+            _ => 500,
+        }
+    }
+
+    /// Creates a database error
+    pub fn database(message: impl Into<String>) -> Self {
+        Self::new(
+            ErrorKind::Database {
+                query: None,
+                connection_id: None,
+            },
+            message,
+        )
+        .severity(ErrorSeverity::High)
+    }
+
+    /// Creates a retryable error for a database connection that is down
+    pub fn database_unavailable(message: impl Into<String>) -> Self {
+        Self::database(message).metadata(RETRYABLE_METADATA_KEY, serde_json::Value::Bool(true))
+    }
+
+    /// Checks if the error reports a lost or unavailable database connection
+    pub fn is_database_unavailable(&self) -> bool {
+        matches!(self.kind, ErrorKind::Database { .. }) && self.is_retryable()
+    }
+}
+
+/// Context fields identifying what an error of `kind` is about
+fn kind_context(kind: &ErrorKind) -> crate::types::Metadata {
+    let mut context = crate::types::Metadata::new();
+    let mut insert = |key: &str, value: serde_json::Value| {
+        context.insert(key.to_string(), value);
+    };
+
+    match kind {
+        ErrorKind::Configuration { key: Some(key), .. } => insert("key", key.as_str().into()),
+        ErrorKind::Plugin {
+            plugin_id: Some(plugin_id),
+            ..
+        } => insert("plugin_id", plugin_id.as_str().into()),
+        ErrorKind::File {
+            path: Some(path), ..
+        } => insert("path", path.as_str().into()),
+        ErrorKind::Permission {
+            required_permission,
+            ..
+        } => insert("permission", required_permission.as_str().into()),
+        ErrorKind::Network {
+            status_code: Some(status_code),
+            ..
+        } => insert("status_code", (*status_code).into()),
+        ErrorKind::Validation {
+            field: Some(field), ..
+        } => insert("field", field.as_str().into()),
+        ErrorKind::Authorization {
+            resource, action, ..
+        } => {
+            insert("resource", resource.as_str().into());
+            insert("action", action.as_str().into());
+        }
+        ErrorKind::NotFound {
+            resource: Some(resource),
+        } => insert("resource", resource.as_str().into()),
+        ErrorKind::RateLimited {
+            retry_after_secs: Some(retry_after_secs),
+        } => insert("retry_after_secs", (*retry_after_secs).into()),
+        _ => {}
+    }
+    context
 }
 
 impl fmt::Display for Error {
+    /// The alternate form (`{:#}`) appends every message in the source chain
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
             "[{}] {} ({}): {}",
             self.severity, self.source, self.id, self.message
-        )
+        )?;
+
+        if f.alternate() {
+            let mut source = std::error::Error::source(self);
+            while let Some(cause) = source {
+                write!(f, ": {}", cause)?;
+                source = cause.source();
+            }
+        }
+        Ok(())
     }
 }
 
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        None
+        self.cause
+            .as_deref()
+            .map(|cause| cause as &(dyn std::error::Error + 'static))
     }
 }
 
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Self {
-        let msg = err.to_string();
-
-        let mut error = Error::new(ErrorKind::Io, msg);
+        let message = format!("I/O error: {}", err.kind());
+        let mut error = Error::from_source(ErrorKind::Io, message, err);
         error.source = "std::io::Error".to_string();
         error.severity = ErrorSeverity::High;
 
@@ -432,6 +674,18 @@ pub trait ResultExt<T> {
     fn with_correlation(self, correlation_id: Uuid) -> Result<T>;
 }
 
+/// Extension trait for Results whose error converts into an [`Error`]
+pub trait ResultFieldExt<T> {
+    /// Adds a structured context field, keeping an [`Error`]'s kind intact
+    fn with_field(self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Result<T>;
+}
+
+impl<T, E: Into<Error>> ResultFieldExt<T> for std::result::Result<T, E> {
+    fn with_field(self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Result<T> {
+        self.map_err(|e| e.into().with_context_field(key, value))
+    }
+}
+
 impl<T, E> ResultExt<T> for std::result::Result<T, E>
 where
     E: std::error::Error + Send + Sync + 'static,
@@ -440,14 +694,14 @@ where
     where
         F: FnOnce() -> String,
     {
-        self.map_err(|e| Error::new(ErrorKind::Application, f()).caused_by(e))
+        self.map_err(|e| Error::new(ErrorKind::Application, f()).with_source_error(e))
     }
 
     fn with_source(self, source: impl Into<String>) -> Result<T> {
         self.map_err(|e| {
             Error::new(ErrorKind::Application, e.to_string())
                 .source(source)
-                .caused_by(e)
+                .with_source_error(e)
         })
     }
 
@@ -461,7 +715,7 @@ where
                 },
                 e.to_string(),
             )
-            .caused_by(e)
+            .with_source_error(e)
         })
     }
 
@@ -469,7 +723,7 @@ where
         self.map_err(|e| {
             Error::new(ErrorKind::Application, e.to_string())
                 .correlation_id(correlation_id)
-                .caused_by(e)
+                .with_source_error(e)
         })
     }
 }
@@ -500,6 +754,109 @@ mod tests {
         assert_eq!(error.severity, ErrorSeverity::Medium);
     }
 
+    #[test]
+    fn test_http_status() {
+        let error = Error::rate_limited(Some(30), "Supplier quota exhausted");
+        assert_eq!(error.http_status(), 429);
+        assert!(error.is_retryable());
+
+        assert_eq!(
+            Error::not_found("product", "No such product").http_status(),
+            404
+        );
+        assert_eq!(
+            Error::permission("admin.users.read", "Denied").http_status(),
+            403
+        );
+        assert_eq!(
+            Error::new(
+                ErrorKind::Authentication {
+                    provider: None,
+                    reason: "expired".to_string(),
+                },
+                "Session expired",
+            )
+            .http_status(),
+            401
+        );
+        assert_eq!(
+            Error::new(
+                ErrorKind::Validation {
+                    field: Some("sku".to_string()),
+                    rules: Vec::new(),
+                },
+                "SKU is required",
+            )
+            .http_status(),
+            400
+        );
+        assert_eq!(Error::timeout("Too slow").http_status(), 504);
+        assert_eq!(
+            Error::database_unavailable("Pool closed").http_status(),
+            503
+        );
+        assert_eq!(Error::plugin("inventory", "Broken").http_status(), 500);
+    }
+
+    #[test]
+    fn test_context_fields() {
+        let error = Error::rate_limited(Some(30), "Supplier quota exhausted");
+        assert_eq!(error.context_u64("retry_after_secs"), Some(30));
+
+        let error = Error::new(
+            ErrorKind::Configuration {
+                key: Some("database.host".to_string()),
+                validation_errors: Vec::new(),
+            },
+            "Invalid host",
+        );
+        assert_eq!(error.context_str("key"), Some("database.host"));
+
+        // Our errors keep their kind through `with_field`
+        let result: Result<()> = Err(Error::not_found("product", "No such product"));
+        let error = result.with_field("sku", "ABC-123").unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::NotFound { .. }));
+        assert_eq!(error.context_str("resource"), Some("product"));
+        assert_eq!(error.context_str("sku"), Some("ABC-123"));
+
+        let result: std::result::Result<(), std::io::Error> =
+            Err(std::io::Error::other("disk full"));
+        let error = result.with_field("attempt", 3).unwrap_err();
+        assert!(matches!(error.kind, ErrorKind::Io));
+        assert_eq!(error.context_u64("attempt"), Some(3));
+    }
+
+    #[test]
+    fn test_source_chain() {
+        let io_error = std::io::Error::new(std::io::ErrorKind::NotFound, "catalog.csv missing");
+        let result: std::result::Result<(), _> = Err(io_error);
+        let error = result
+            .with_context(|| "Failed to import catalog".to_string())
+            .unwrap_err();
+
+        assert_eq!(error.message, "Failed to import catalog");
+        let source = std::error::Error::source(&error).unwrap();
+        let io_error = source.downcast_ref::<std::io::Error>().unwrap();
+        assert_eq!(io_error.kind(), std::io::ErrorKind::NotFound);
+        assert!(std::error::Error::source(&error.clone()).is_some());
+
+        let outer = Error::from_source(ErrorKind::Application, "Nightly sync failed", error);
+        let rendered = format!("{:#}", outer);
+        assert!(rendered.contains(": Nightly sync failed: "));
+        assert!(rendered.ends_with(": Failed to import catalog: catalog.csv missing"));
+        assert_eq!(rendered.matches("Failed to import catalog").count(), 1);
+        assert!(!format!("{}", outer).contains("catalog.csv missing"));
+
+        // Wrapping an I/O error names the failure once and keeps its text in the chain
+        let io: Error =
+            std::io::Error::new(std::io::ErrorKind::NotFound, "catalog.csv missing").into();
+        assert_eq!(io.message, "I/O error: entity not found");
+        assert_eq!(
+            format!("{:#}", io).matches("catalog.csv missing").count(),
+            1
+        );
+    }
+
     #[test]
     fn test_permission_error() {
         let error = Error::permission("admin.users.read", "Access denied");
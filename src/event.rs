@@ -12,7 +12,7 @@
 //! - Event serialization for network transport
 
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::{self, Debug};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
@@ -23,9 +23,10 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Notify, RwLock};
 use uuid::Uuid;
 
+use crate::auth::{PermissionHierarchy, User};
 use crate::error::{Error, ErrorKind, EventOperation, Result};
 use crate::manager::{ManagedState, Manager, ManagerStatus};
 use crate::types::Metadata;
@@ -66,6 +67,11 @@ pub trait Event: Send + Sync + Debug {
     fn should_persist(&self) -> bool {
         false
     }
+
+    /// Event data as JSON, if the event carries a payload
+    fn payload(&self) -> Option<serde_json::Value> {
+        None
+    }
 }
 
 /// Event priority levels
@@ -139,6 +145,8 @@ pub trait EventHandler: Sync + Debug {
 pub struct EventFilter {
     /// Event types to match (empty means all)
     pub event_types: Vec<String>,
+    /// Event type prefixes to match, e.g. `"config."` (empty means all)
+    pub event_type_prefixes: Vec<String>,
     /// Source patterns to match
     pub source_patterns: Vec<String>,
     /// Metadata filters
@@ -152,6 +160,7 @@ impl EventFilter {
     pub fn new() -> Self {
         Self {
             event_types: Vec::new(),
+            event_type_prefixes: Vec::new(),
             source_patterns: Vec::new(),
             metadata_filters: HashMap::new(),
             min_priority: EventPriority::Low,
@@ -164,6 +173,12 @@ impl EventFilter {
         self
     }
 
+    /// Add event type prefix filter
+    pub fn with_event_type_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.event_type_prefixes.push(prefix.into());
+        self
+    }
+
     /// Add source pattern filter
     pub fn with_source_pattern(mut self, pattern: impl Into<String>) -> Self {
         self.source_patterns.push(pattern.into());
@@ -191,6 +206,16 @@ impl EventFilter {
             return false;
         }
 
+        // Check event type prefixes
+        if !self.event_type_prefixes.is_empty()
+            && !self
+                .event_type_prefixes
+                .iter()
+                .any(|prefix| event.event_type().starts_with(prefix.as_str()))
+        {
+            return false;
+        }
+
         // Check source patterns
         if !self.source_patterns.is_empty() {
             let source = event.source();
@@ -296,6 +321,10 @@ pub struct EventBusConfig {
     pub batch_size: usize,
     /// Maximum retry delay
     pub max_retry_delay: Duration,
+    /// Number of recent events kept for polling and replay
+    pub history_capacity: usize,
+    /// File receiving newline-delimited JSON event records when persistence is enabled
+    pub persistence_path: Option<std::path::PathBuf>,
 }
 
 fn get_default_worker_count() -> usize {
@@ -319,10 +348,140 @@ impl Default for EventBusConfig {
             enable_metrics: true,
             batch_size: 100,
             max_retry_delay: Duration::from_secs(60),
+            history_capacity: 1000,
+            persistence_path: None,
         }
     }
 }
 
+/// Serializable snapshot of a published event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Position of the event in the bus history
+    pub cursor: u64,
+    /// Event type identifier
+    pub event_type: String,
+    /// Event source
+    pub source: String,
+    /// Event timestamp
+    pub timestamp: DateTime<Utc>,
+    /// Event priority
+    pub priority: EventPriority,
+    /// Event metadata
+    pub metadata: Metadata,
+}
+
+impl EventRecord {
+    fn from_event(cursor: u64, event: &dyn Event) -> Self {
+        Self {
+            cursor,
+            event_type: event.event_type().to_string(),
+            source: event.source().to_string(),
+            timestamp: event.timestamp(),
+            priority: event.priority(),
+            metadata: event.metadata().clone(),
+        }
+    }
+
+    /// Check whether a user may see this event.
+    ///
+    /// An event of type `config.changed` is visible with a permission that
+    /// `hierarchy` says grants `read` on `events` or `events.config`, such as
+    /// `manage` on `events.*` or `read` on `*`.
+    pub fn is_visible_to(&self, user: &User, hierarchy: &PermissionHierarchy) -> bool {
+        let category = self.event_type.split('.').next().unwrap_or_default();
+        let scoped_resource = format!("events.{}", category);
+
+        user.permissions
+            .iter()
+            .chain(user.roles.iter().flat_map(|role| role.permissions.iter()))
+            .any(|p| {
+                hierarchy.grants(p, "events", "read")
+                    || hierarchy.grants(p, &scoped_resource, "read")
+            })
+    }
+}
+
+/// Path of the long-poll endpoint for clients without WebSocket support
+pub const EVENT_POLL_PATH: &str = "/events/poll";
+
+/// Parameters of a long-poll request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventPollQuery {
+    /// Cursor of the last event the client has seen
+    pub since: u64,
+    /// How long to wait for new events when none are buffered
+    pub timeout: Duration,
+    /// Maximum number of events returned in one response
+    pub limit: usize,
+}
+
+impl EventPollQuery {
+    /// Default time to wait for new events
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+    /// Upper bound on the wait time a client can request
+    pub const MAX_TIMEOUT: Duration = Duration::from_secs(120);
+    /// Default maximum number of events per response
+    pub const DEFAULT_LIMIT: usize = 100;
+
+    /// Build a query from `since`, `timeout_ms` and `limit` query parameters
+    pub fn from_query_params(params: &HashMap<String, String>) -> Result<Self> {
+        fn parse<T: std::str::FromStr>(
+            params: &HashMap<String, String>,
+            name: &str,
+        ) -> Result<Option<T>> {
+            params
+                .get(name)
+                .map(|raw| {
+                    raw.parse::<T>().map_err(|_| {
+                        Error::new(
+                            ErrorKind::Validation {
+                                field: Some(name.to_string()),
+                                rules: vec!["must be a non-negative integer".to_string()],
+                            },
+                            format!("Invalid '{}' parameter: {}", name, raw),
+                        )
+                    })
+                })
+                .transpose()
+        }
+
+        let timeout = parse::<u64>(params, "timeout_ms")?
+            .map(Duration::from_millis)
+            .unwrap_or(Self::DEFAULT_TIMEOUT)
+            .min(Self::MAX_TIMEOUT);
+
+        Ok(Self {
+            since: parse(params, "since")?.unwrap_or(0),
+            timeout,
+            limit: parse(params, "limit")?
+                .unwrap_or(Self::DEFAULT_LIMIT)
+                .max(1),
+        })
+    }
+}
+
+impl Default for EventPollQuery {
+    fn default() -> Self {
+        Self {
+            since: 0,
+            timeout: Self::DEFAULT_TIMEOUT,
+            limit: Self::DEFAULT_LIMIT,
+        }
+    }
+}
+
+/// Response of a long-poll request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventPollResponse {
+    /// Events newer than the requested cursor
+    pub events: Vec<EventRecord>,
+    /// Cursor to send with the next poll
+    pub cursor: u64,
+    /// Whether the poll ended without new events
+    pub timed_out: bool,
+}
+
 /// Internal event wrapper for the bus
 #[derive(Debug)]
 struct EventEnvelope {
@@ -348,6 +507,10 @@ pub struct EventBusManager {
     stats: Arc<RwLock<EventStats>>,
     event_counter: Arc<AtomicU64>,
     worker_handles: Vec<tokio::task::JoinHandle<()>>,
+    history: Arc<RwLock<VecDeque<EventRecord>>>,
+    history_notify: Arc<Notify>,
+    #[cfg(not(target_arch = "wasm32"))]
+    persistence_file: Option<Arc<tokio::sync::Mutex<tokio::fs::File>>>,
 }
 
 impl Debug for EventBusManager {
@@ -365,7 +528,7 @@ impl EventBusManager {
         let (event_sender, _event_receiver) = mpsc::unbounded_channel::<EventEnvelope>();
 
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "event_bus_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "event_bus_manager"),
             config,
             subscriptions: Arc::new(DashMap::new()),
             event_queue: event_sender,
@@ -381,6 +544,10 @@ impl EventBusManager {
             })),
             event_counter: Arc::new(AtomicU64::new(0)),
             worker_handles: Vec::new(),
+            history: Arc::new(RwLock::new(VecDeque::new())),
+            history_notify: Arc::new(Notify::new()),
+            #[cfg(not(target_arch = "wasm32"))]
+            persistence_file: None,
         }
     }
 
@@ -389,7 +556,7 @@ impl EventBusManager {
         let event_arc: Arc<dyn Event> = Arc::new(event);
 
         // Update statistics
-        self.event_counter.fetch_add(1, Ordering::Relaxed);
+        self.record_history(event_arc.as_ref()).await;
         {
             let mut stats = self.stats.write().await;
             stats.total_published += 1;
@@ -432,7 +599,7 @@ impl EventBusManager {
         filter: EventFilter,
     ) -> Result<mpsc::UnboundedReceiver<Arc<dyn Event>>> {
         let (sender, receiver) = mpsc::unbounded_channel::<Arc<dyn Event>>();
-        let subscription_id = Uuid::new_v4();
+        let subscription_id = crate::utils::rand::uuid();
 
         let subscription = EventSubscription {
             id: subscription_id,
@@ -456,6 +623,17 @@ impl EventBusManager {
         Ok(receiver)
     }
 
+    /// Subscribe to events whose type starts with `prefix`.
+    ///
+    /// Filtering happens on the bus, so unrelated events never reach the channel.
+    pub async fn subscribe_filtered(
+        &self,
+        prefix: &str,
+    ) -> Result<mpsc::UnboundedReceiver<Arc<dyn Event>>> {
+        self.subscribe(EventFilter::new().with_event_type_prefix(prefix))
+            .await
+    }
+
     /// Subscribe with a handler
     #[cfg(not(target_arch = "wasm32"))]
     pub async fn subscribe_with_handler<H: EventHandler + 'static + Send>(
@@ -495,7 +673,7 @@ impl EventBusManager {
         drop(handle);
 
         // Return a dummy subscription ID
-        Ok(Uuid::new_v4())
+        Ok(crate::utils::rand::uuid())
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -534,7 +712,7 @@ impl EventBusManager {
         });
 
         // Return a dummy subscription ID
-        Ok(Uuid::new_v4())
+        Ok(crate::utils::rand::uuid())
     }
 
     /// Unsubscribe from events
@@ -570,6 +748,177 @@ impl EventBusManager {
         self.stats.read().await.clone()
     }
 
+    /// Assign the next cursor, append the event to the history, persist it
+    /// and wake waiting pollers.
+    ///
+    /// Ordering: the cursor is taken while the history lock is held, so the
+    /// in-memory history only ever grows in cursor order and a poller never
+    /// advances past a cursor whose event is not buffered yet. Events from
+    /// concurrent publishers may be appended to the persistence file in a
+    /// different order; use the `cursor` field to reorder records read back
+    /// from disk.
+    async fn record_history(&self, event: &dyn Event) {
+        let record = {
+            let mut history = self.history.write().await;
+            let cursor = self.event_counter.fetch_add(1, Ordering::Relaxed) + 1;
+            let record = EventRecord::from_event(cursor, event);
+            if self.config.history_capacity > 0 {
+                history.push_back(record.clone());
+                while history.len() > self.config.history_capacity {
+                    history.pop_front();
+                }
+            }
+            record
+        };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(file) = &self.persistence_file {
+            Self::persist_record(file, &record).await;
+        }
+
+        self.history_notify.notify_waiters();
+    }
+
+    /// Append a record to the persistence file as a single JSON line
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn persist_record(file: &tokio::sync::Mutex<tokio::fs::File>, record: &EventRecord) {
+        use tokio::io::AsyncWriteExt;
+
+        let mut line = match serde_json::to_vec(record) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::warn!("Failed to serialize event {}: {}", record.cursor, e);
+                return;
+            }
+        };
+        line.push(b'\n');
+
+        let mut file = file.lock().await;
+        if let Err(e) = file.write_all(&line).await {
+            tracing::warn!("Failed to persist event {}: {}", record.cursor, e);
+        } else if let Err(e) = file.flush().await {
+            tracing::warn!("Failed to flush persisted event {}: {}", record.cursor, e);
+        }
+    }
+
+    /// Open the persistence file when persistence is enabled
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn open_persistence_file(&mut self) -> Result<()> {
+        let path = match (
+            &self.config.persistence_path,
+            self.config.enable_persistence,
+        ) {
+            (Some(path), true) => path.clone(),
+            _ => return Ok(()),
+        };
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .map_err(|e| {
+                Error::file(
+                    path.display().to_string(),
+                    crate::error::FileOperation::Write,
+                    format!("Failed to open event persistence file: {}", e),
+                )
+            })?;
+
+        self.persistence_file = Some(Arc::new(tokio::sync::Mutex::new(file)));
+        tracing::info!("Persisting events to {}", path.display());
+        Ok(())
+    }
+
+    /// Get the most recent events, oldest first
+    pub async fn recent_events(&self, limit: usize) -> Vec<EventRecord> {
+        let history = self.history.read().await;
+        let skip = history.len().saturating_sub(limit);
+        history.iter().skip(skip).cloned().collect()
+    }
+
+    /// Get buffered events published after the given cursor
+    pub async fn events_since(&self, cursor: u64, limit: usize) -> Vec<EventRecord> {
+        self.history
+            .read()
+            .await
+            .iter()
+            .filter(|record| record.cursor > cursor)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    /// Long-poll for events newer than `query.since`.
+    ///
+    /// Returns immediately when matching events are buffered, otherwise waits
+    /// up to `query.timeout` for new ones. Events the user is not allowed to
+    /// see under `hierarchy` are skipped but still advance the cursor.
+    /// Dropping the future or cancelling the token ends the wait and releases
+    /// the subscription.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn poll_events(
+        &self,
+        query: &EventPollQuery,
+        user: Option<&User>,
+        hierarchy: &PermissionHierarchy,
+        cancellation: &crate::task::CancellationToken,
+    ) -> Result<EventPollResponse> {
+        let deadline = tokio::time::Instant::now() + query.timeout;
+        let mut cursor = query.since;
+
+        loop {
+            // Register interest before reading so no publish slips in between
+            let notified = self.history_notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+
+            let mut events = Vec::new();
+            for record in self.events_since(cursor, usize::MAX).await {
+                if events.len() >= query.limit {
+                    break;
+                }
+                cursor = record.cursor;
+                if user.is_some_and(|user| record.is_visible_to(user, hierarchy)) {
+                    events.push(record);
+                }
+            }
+
+            if !events.is_empty() {
+                return Ok(EventPollResponse {
+                    events,
+                    cursor,
+                    timed_out: false,
+                });
+            }
+
+            tokio::select! {
+                _ = &mut notified => continue,
+                _ = tokio::time::sleep_until(deadline) => {}
+                _ = cancellation.cancelled() => {
+                    return Err(Error::new(
+                        ErrorKind::Event {
+                            event_type: None,
+                            subscriber_id: None,
+                            operation: EventOperation::Subscribe,
+                        },
+                        "Event poll cancelled",
+                    ));
+                }
+            }
+
+            return Ok(EventPollResponse {
+                events: Vec::new(),
+                cursor,
+                timed_out: true,
+            });
+        }
+    }
+
     /// Start event processing workers
     async fn start_workers(&mut self) -> Result<()> {
         let (event_sender, event_receiver) = mpsc::unbounded_channel::<EventEnvelope>();
@@ -686,7 +1035,7 @@ impl Manager for EventBusManager {
     }
 
     fn id(&self) -> Uuid {
-        Uuid::new_v4() // Simplified
+        crate::utils::rand::uuid() // Simplified
     }
 
     async fn initialize(&mut self) -> Result<()> {
@@ -694,6 +1043,9 @@ impl Manager for EventBusManager {
             .set_state(crate::manager::ManagerState::Initializing)
             .await;
 
+        // Open the event log before accepting events
+        self.open_persistence_file().await?;
+
         // Start event processing workers
         self.start_workers().await?;
 
@@ -715,6 +1067,9 @@ impl Manager for EventBusManager {
         // Stop processing new events
         self.stop_workers().await;
 
+        // Close the event log
+        self.persistence_file = None;
+
         // Clear subscriptions
         self.subscriptions.clear();
 
@@ -763,7 +1118,7 @@ impl Manager for EventBusManager {
     }
 
     fn id(&self) -> Uuid {
-        Uuid::new_v4() // Simplified
+        crate::utils::rand::uuid() // Simplified
     }
 
     async fn initialize(&mut self) -> Result<()> {
@@ -960,6 +1315,219 @@ mod tests {
         bus.shutdown().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_poll_returns_buffered_events_and_advances_cursor() {
+        let mut bus = EventBusManager::new(EventBusConfig::default());
+        bus.initialize().await.unwrap();
+
+        for data in ["first", "second"] {
+            bus.publish(TestEvent {
+                source: "test".to_string(),
+                metadata: HashMap::new(),
+                data: data.to_string(),
+            })
+            .await
+            .unwrap();
+        }
+
+        let user = crate::auth::test_user(
+            "poller",
+            vec![crate::auth::Permission {
+                resource: "events.test".to_string(),
+                action: "read".to_string(),
+                scope: crate::auth::PermissionScope::Global,
+            }],
+        );
+        let cancellation = crate::task::CancellationToken::new();
+        let query = EventPollQuery::default();
+
+        let response = bus
+            .poll_events(
+                &query,
+                Some(&user),
+                &PermissionHierarchy::default(),
+                &cancellation,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.events.len(), 2);
+        assert_eq!(response.cursor, 2);
+        assert!(!response.timed_out);
+
+        let next_query = EventPollQuery {
+            since: response.cursor,
+            timeout: Duration::from_millis(20),
+            ..EventPollQuery::default()
+        };
+        let response = bus
+            .poll_events(
+                &next_query,
+                Some(&user),
+                &PermissionHierarchy::default(),
+                &cancellation,
+            )
+            .await
+            .unwrap();
+        assert!(response.events.is_empty());
+        assert_eq!(response.cursor, 2);
+        assert!(response.timed_out);
+
+        bus.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_recent_events_and_persistence() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let log_path = temp_dir.path().join("events.ndjson");
+
+        let config = EventBusConfig {
+            enable_persistence: true,
+            history_capacity: 2,
+            persistence_path: Some(log_path.clone()),
+            ..EventBusConfig::default()
+        };
+        let mut bus = EventBusManager::new(config);
+        bus.initialize().await.unwrap();
+
+        for data in ["one", "two", "three"] {
+            bus.publish(TestEvent {
+                source: "test".to_string(),
+                metadata: HashMap::new(),
+                data: data.to_string(),
+            })
+            .await
+            .unwrap();
+        }
+
+        let recent = bus.recent_events(10).await;
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].cursor, 2);
+        assert_eq!(recent[1].cursor, 3);
+        assert_eq!(recent[1].event_type, "test.event");
+
+        let persisted = std::fs::read_to_string(&log_path).unwrap();
+        let records: Vec<EventRecord> = persisted
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].source, "test");
+
+        bus.shutdown().await.unwrap();
+    }
+
+    #[test]
+    fn test_event_visibility_follows_permission_hierarchy() {
+        let record = EventRecord {
+            cursor: 1,
+            event_type: "config.changed".to_string(),
+            source: "test".to_string(),
+            timestamp: Time::now(),
+            priority: EventPriority::Normal,
+            metadata: HashMap::new(),
+        };
+        let user = |resource: &str, action: &str| {
+            crate::auth::test_user(
+                "viewer",
+                vec![crate::auth::Permission {
+                    resource: resource.to_string(),
+                    action: action.to_string(),
+                    scope: crate::auth::PermissionScope::Global,
+                }],
+            )
+        };
+        let hierarchy = PermissionHierarchy::default();
+        let visible = |resource: &str, action: &str| {
+            record.is_visible_to(&user(resource, action), &hierarchy)
+        };
+
+        assert!(visible("events.config", "read"));
+        assert!(visible("events", "read"));
+        assert!(visible("*", "read"));
+        assert!(visible("events.*", "read"));
+        // Actions implying `read` are enough
+        assert!(visible("events.config", "manage"));
+        assert!(visible("events.*", "write"));
+        assert!(!visible("events.auth", "read"));
+        assert!(!visible("events.config", "execute"));
+        assert!(!visible("config", "read"));
+
+        // Without implications only `read` itself counts
+        let flat = PermissionHierarchy::flat();
+        assert!(!record.is_visible_to(&user("events.config", "manage"), &flat));
+        assert!(record.is_visible_to(&user("events", "read"), &flat));
+    }
+
+    #[test]
+    fn test_poll_query_from_params() {
+        let mut params = HashMap::new();
+        params.insert("since".to_string(), "42".to_string());
+        params.insert("timeout_ms".to_string(), "500".to_string());
+
+        let query = EventPollQuery::from_query_params(&params).unwrap();
+        assert_eq!(query.since, 42);
+        assert_eq!(query.timeout, Duration::from_millis(500));
+
+        params.insert("since".to_string(), "abc".to_string());
+        assert!(EventPollQuery::from_query_params(&params).is_err());
+    }
+
+    #[derive(Debug)]
+    struct TypedEvent {
+        event_type: &'static str,
+        metadata: Metadata,
+    }
+
+    impl Event for TypedEvent {
+        fn event_type(&self) -> &'static str {
+            self.event_type
+        }
+
+        fn source(&self) -> &str {
+            "test"
+        }
+
+        fn metadata(&self) -> &Metadata {
+            &self.metadata
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_filtered_by_prefix() {
+        let mut bus = EventBusManager::new(EventBusConfig::default());
+        bus.initialize().await.unwrap();
+
+        let mut receiver = bus.subscribe_filtered("config.").await.unwrap();
+
+        for event_type in ["plugin.loaded", "config.changed"] {
+            bus.publish(TypedEvent {
+                event_type,
+                metadata: HashMap::new(),
+            })
+            .await
+            .unwrap();
+        }
+
+        let received = tokio::time::timeout(Duration::from_secs(1), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(received.event_type(), "config.changed");
+
+        // Nothing else should arrive
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), receiver.recv())
+                .await
+                .is_err()
+        );
+
+        bus.shutdown().await.unwrap();
+    }
+
     #[test]
     fn test_event_filter() {
         let filter = EventFilter::new()
@@ -10,10 +10,11 @@
 //! - File compression and decompression
 //! - Temporary file management
 //! - File type detection and validation
-//! - Progress tracking for large operations
+//! - Chunked streaming with progress tracking for large files
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+#[cfg(feature = "file-watch")]
 use notify::{Event as NotifyEvent, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -22,8 +23,9 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, UNIX_EPOCH};
 use tokio::fs;
-use tokio::io::AsyncReadExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::sync::{broadcast, RwLock};
+use tokio::time::Instant;
 use uuid::Uuid;
 
 use crate::config::FileConfig;
@@ -32,6 +34,9 @@ use crate::event::{Event, EventBusManager};
 use crate::manager::{ManagedState, Manager, ManagerStatus};
 use crate::types::Metadata;
 
+/// Default chunk size for streamed reads
+pub const DEFAULT_STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
 /// File type enumeration based on content and extension
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum FileType {
@@ -225,6 +230,30 @@ impl Default for FileOperationOptions {
     }
 }
 
+/// Options for [`FileManager::read_stream`] and [`FileManager::write_stream`]
+#[derive(Debug, Clone)]
+pub struct FileStreamOptions {
+    /// Bytes read from disk per chunk
+    pub chunk_size: usize,
+    /// Gzip the file on write and gunzip it on read; requires `enable_compression`
+    pub gzip: bool,
+    /// Expected size of a written stream, used for progress and checked up front
+    pub expected_size: Option<u64>,
+    /// Options applied when writing
+    pub write: FileOperationOptions,
+}
+
+impl Default for FileStreamOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_STREAM_CHUNK_SIZE,
+            gzip: false,
+            expected_size: None,
+            write: FileOperationOptions::default(),
+        }
+    }
+}
+
 /// File operation progress information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileOperationProgress {
@@ -304,6 +333,41 @@ impl Event for FileChangeEvent {
     }
 }
 
+/// Progress of a streamed file operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileProgressEvent {
+    /// Operation progress
+    pub progress: FileOperationProgress,
+    /// When the progress was recorded
+    pub timestamp: DateTime<Utc>,
+    /// Additional event data
+    pub metadata: Metadata,
+    /// Source of the event
+    pub source: String,
+}
+
+impl Event for FileProgressEvent {
+    fn event_type(&self) -> &'static str {
+        "file.progress"
+    }
+
+    fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+}
+
 /// File change types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FileChangeType {
@@ -319,8 +383,11 @@ pub enum FileChangeType {
     MetadataChanged,
 }
 
-/// File watcher for monitoring file system changes
+/// File watcher for monitoring file system changes.
+///
+/// Watching needs the `file-watch` feature; without it `watch_path` fails.
 pub struct FileWatcher {
+    #[cfg(feature = "file-watch")]
     watcher: Option<RecommendedWatcher>,
     event_sender: broadcast::Sender<FileChangeEvent>,
     watched_paths: RwLock<HashMap<PathBuf, bool>>, // path -> recursive
@@ -332,6 +399,7 @@ impl FileWatcher {
         let (event_sender, _) = broadcast::channel(1000);
 
         Ok(Self {
+            #[cfg(feature = "file-watch")]
             watcher: None,
             event_sender,
             watched_paths: RwLock::new(HashMap::new()),
@@ -339,6 +407,7 @@ impl FileWatcher {
     }
 
     /// Start watching a path
+    #[cfg(feature = "file-watch")]
     pub async fn watch_path(&mut self, path: impl AsRef<Path>, recursive: bool) -> Result<()> {
         let path = path.as_ref().to_path_buf();
 
@@ -391,10 +460,23 @@ impl FileWatcher {
         Ok(())
     }
 
+    /// Start watching a path
+    #[cfg(not(feature = "file-watch"))]
+    pub async fn watch_path(&mut self, path: impl AsRef<Path>, _recursive: bool) -> Result<()> {
+        Err(Error::new(
+            crate::error::ErrorKind::File {
+                path: Some(path.as_ref().display().to_string()),
+                operation: FileOperation::Watch,
+            },
+            "File watching needs the file-watch feature",
+        ))
+    }
+
     /// Stop watching a path
     pub async fn unwatch_path(&mut self, path: impl AsRef<Path>) -> Result<()> {
         let path = path.as_ref().to_path_buf();
 
+        #[cfg(feature = "file-watch")]
         if let Some(ref mut watcher) = self.watcher {
             watcher.unwatch(&path).map_err(|e| {
                 Error::new(
@@ -419,6 +501,7 @@ impl FileWatcher {
     }
 
     /// Handle notify events and convert to our event format
+    #[cfg(feature = "file-watch")]
     fn handle_notify_event(event: NotifyEvent, sender: &broadcast::Sender<FileChangeEvent>) {
         let change_type = match event.kind {
             EventKind::Create(_) => FileChangeType::Created,
@@ -464,7 +547,7 @@ impl FileManager {
     /// Create a new file manager
     pub fn new(config: FileConfig) -> Self {
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "file_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "file_manager"),
             config,
             watcher: None,
             event_bus: None,
@@ -576,7 +659,7 @@ impl FileManager {
                 .await
                 .with_context(|| format!("Failed to write file: {}", path.display()))?;
 
-            self.apply_file_options(path, &options).await
+            Self::apply_file_options(path, &options).await
         }
     }
 
@@ -595,7 +678,7 @@ impl FileManager {
             .with_context(|| format!("Failed to write temporary file: {}", temp_path.display()))?;
 
         // Apply options to temporary file
-        self.apply_file_options(&temp_path, options).await?;
+        Self::apply_file_options(&temp_path, options).await?;
 
         // Atomically rename temporary file to target
         fs::rename(&temp_path, path).await.with_context(|| {
@@ -610,7 +693,7 @@ impl FileManager {
     }
 
     /// Apply file operation options
-    async fn apply_file_options(&self, _path: &Path, options: &FileOperationOptions) -> Result<()> {
+    async fn apply_file_options(_path: &Path, options: &FileOperationOptions) -> Result<()> {
         // Set permissions if specified
         if let Some(_permissions) = options.permissions {
             #[cfg(unix)]
@@ -710,7 +793,7 @@ impl FileManager {
         }
 
         // Apply other options
-        self.apply_file_options(destination, &options).await?;
+        Self::apply_file_options(destination, &options).await?;
 
         Ok(bytes_copied)
     }
@@ -883,7 +966,7 @@ impl FileManager {
     ) -> Result<PathBuf> {
         let prefix = prefix.unwrap_or("temp");
         let suffix = suffix.unwrap_or(".tmp");
-        let filename = format!("{}_{}_{}", prefix, Uuid::new_v4(), suffix);
+        let filename = format!("{}_{}_{}", prefix, crate::utils::rand::uuid(), suffix);
         // let temp_path = temp_dir.join(filename);
         let temp_path = self
             .config
@@ -1029,6 +1112,207 @@ impl FileManager {
         Ok(())
     }
 
+    /// Open a file for chunked reading.
+    ///
+    /// The whole read must finish within `operation_timeout_secs`; gzipped
+    /// files are decompressed when `options.gzip` is set.
+    pub async fn read_stream(
+        &self,
+        path: impl AsRef<Path>,
+        options: Option<FileStreamOptions>,
+    ) -> Result<FileReadStream> {
+        let options = options.unwrap_or_default();
+        let path = self.check_allowed_path(path.as_ref(), FileOperation::Read)?;
+        self.check_stream_compression(&path, &options, FileOperation::Decompress)?;
+
+        let metadata = fs::metadata(&path)
+            .await
+            .with_context(|| format!("Failed to get metadata for: {}", path.display()))?;
+        if metadata.len() > self.config.max_file_size {
+            return Err(size_limit_error(
+                &path,
+                FileOperation::Read,
+                metadata.len(),
+                self.config.max_file_size,
+            ));
+        }
+
+        let file = fs::File::open(&path)
+            .await
+            .with_context(|| format!("Failed to open file: {}", path.display()))?;
+        let tracker = StreamTracker::start(
+            FileOperation::Read,
+            Some(path.clone()),
+            None,
+            metadata.len(),
+            Arc::clone(&self.operations),
+            self.event_bus.clone(),
+        )
+        .await;
+
+        Ok(FileReadStream {
+            path,
+            file,
+            chunk_size: options.chunk_size.max(1),
+            decoder: options
+                .gzip
+                .then(|| flate2::write::GzDecoder::new(Vec::new())),
+            max_size: self.config.max_file_size,
+            produced: 0,
+            deadline: self.stream_deadline(),
+            tracker,
+            done: false,
+        })
+    }
+
+    /// Open a file for chunked writing; call [`FileWriteStream::finish`] once
+    /// all chunks are written.
+    ///
+    /// Atomic writes go to a temporary file that only replaces `path` on
+    /// finish. The whole write must finish within `operation_timeout_secs`.
+    pub async fn write_stream(
+        &self,
+        path: impl AsRef<Path>,
+        options: Option<FileStreamOptions>,
+    ) -> Result<FileWriteStream> {
+        let options = options.unwrap_or_default();
+        let path = self.check_allowed_path(path.as_ref(), FileOperation::Write)?;
+        self.check_stream_compression(&path, &options, FileOperation::Compress)?;
+
+        if let Some(expected_size) = options.expected_size {
+            if expected_size > self.config.max_file_size {
+                return Err(size_limit_error(
+                    &path,
+                    FileOperation::Write,
+                    expected_size,
+                    self.config.max_file_size,
+                ));
+            }
+        }
+
+        if options.write.create_parents {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).await.with_context(|| {
+                    format!(
+                        "Failed to create parent directories for: {}",
+                        path.display()
+                    )
+                })?;
+            }
+        }
+
+        if !options.write.overwrite && path.exists() {
+            return Err(Error::new(
+                crate::error::ErrorKind::File {
+                    path: Some(path.display().to_string()),
+                    operation: FileOperation::Write,
+                },
+                "File already exists and overwrite is disabled",
+            ));
+        }
+
+        let target = if options.write.atomic {
+            path.with_extension("tmp")
+        } else {
+            path.clone()
+        };
+        let file = fs::File::create(&target)
+            .await
+            .with_context(|| format!("Failed to create file: {}", target.display()))?;
+        let tracker = StreamTracker::start(
+            FileOperation::Write,
+            None,
+            Some(path.clone()),
+            options.expected_size.unwrap_or(0),
+            Arc::clone(&self.operations),
+            self.event_bus.clone(),
+        )
+        .await;
+
+        Ok(FileWriteStream {
+            path,
+            target,
+            file: Some(file),
+            encoder: options
+                .gzip
+                .then(|| flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default())),
+            options: options.write,
+            max_size: self.config.max_file_size,
+            written: 0,
+            deadline: self.stream_deadline(),
+            tracker,
+            finished: false,
+        })
+    }
+
+    fn stream_deadline(&self) -> Instant {
+        Instant::now() + Duration::from_secs(self.config.operation_timeout_secs)
+    }
+
+    fn check_stream_compression(
+        &self,
+        path: &Path,
+        options: &FileStreamOptions,
+        operation: FileOperation,
+    ) -> Result<()> {
+        if options.gzip && !self.config.enable_compression {
+            return Err(Error::new(
+                crate::error::ErrorKind::File {
+                    path: Some(path.display().to_string()),
+                    operation,
+                },
+                "File compression is disabled in configuration",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Resolve `path` and make sure it lies within one of the configured
+    /// `allowed_roots`, following symlinks of the parts that already exist
+    fn check_allowed_path(&self, path: &Path, operation: FileOperation) -> Result<PathBuf> {
+        if self.config.allowed_roots.is_empty() {
+            return Ok(path.to_path_buf());
+        }
+
+        let outside_roots = || {
+            Error::new(
+                crate::error::ErrorKind::File {
+                    path: Some(path.display().to_string()),
+                    operation: operation.clone(),
+                },
+                "Path is outside the allowed roots",
+            )
+        };
+
+        // Canonicalize the deepest existing ancestor so files that are yet to
+        // be written can still be checked
+        let mut existing = path.to_path_buf();
+        let mut missing = Vec::new();
+        while !existing.exists() {
+            let name = existing.file_name().ok_or_else(outside_roots)?.to_owned();
+            missing.push(name);
+            existing = match existing.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+                _ => PathBuf::from("."),
+            };
+        }
+        let mut resolved = existing
+            .canonicalize()
+            .map_err(|e| canonicalization_error(&existing, operation.clone(), &e))?;
+        resolved.extend(missing.iter().rev());
+
+        let allowed = self.config.allowed_roots.iter().any(|root| {
+            root.canonicalize()
+                .map(|root| resolved.starts_with(root))
+                .unwrap_or(false)
+        });
+        if allowed {
+            Ok(resolved)
+        } else {
+            Err(outside_roots())
+        }
+    }
+
     /// Get active file operations
     pub async fn get_active_operations(&self) -> Vec<FileOperationProgress> {
         self.operations
@@ -1057,7 +1341,7 @@ impl Manager for FileManager {
     }
 
     fn id(&self) -> Uuid {
-        Uuid::new_v4() // Simplified
+        crate::utils::rand::uuid() // Simplified
     }
 
     async fn initialize(&mut self) -> Result<()> {
@@ -1143,6 +1427,417 @@ impl Manager for FileManager {
     }
 }
 
+/// Minimum time between progress events of a streamed operation
+const STREAM_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Registers a streamed operation as active and reports its progress
+struct StreamTracker {
+    progress: FileOperationProgress,
+    operations: Arc<RwLock<HashMap<Uuid, FileOperationProgress>>>,
+    event_bus: Option<Arc<EventBusManager>>,
+    last_report: Instant,
+}
+
+impl StreamTracker {
+    async fn start(
+        operation: FileOperation,
+        source: Option<PathBuf>,
+        destination: Option<PathBuf>,
+        total_bytes: u64,
+        operations: Arc<RwLock<HashMap<Uuid, FileOperationProgress>>>,
+        event_bus: Option<Arc<EventBusManager>>,
+    ) -> Self {
+        let progress = FileOperationProgress {
+            operation_id: crate::utils::rand::uuid(),
+            operation,
+            current_file: source.clone().or_else(|| destination.clone()),
+            source,
+            destination,
+            total_bytes,
+            processed_bytes: 0,
+            started_at: Utc::now(),
+            estimated_completion: None,
+            status: FileOperationStatus::InProgress,
+        };
+        operations
+            .write()
+            .await
+            .insert(progress.operation_id, progress.clone());
+
+        let mut tracker = Self {
+            progress,
+            operations,
+            event_bus,
+            last_report: Instant::now(),
+        };
+        tracker.report().await;
+        tracker
+    }
+
+    async fn advance(&mut self, bytes: u64) {
+        self.progress.processed_bytes += bytes;
+        self.progress.total_bytes = self.progress.total_bytes.max(self.progress.processed_bytes);
+        if self.last_report.elapsed() >= STREAM_PROGRESS_INTERVAL {
+            self.report().await;
+        }
+    }
+
+    async fn finish(&mut self, status: FileOperationStatus) {
+        self.progress.status = status;
+        self.operations
+            .write()
+            .await
+            .remove(&self.progress.operation_id);
+        self.report().await;
+    }
+
+    async fn report(&mut self) {
+        self.last_report = Instant::now();
+
+        let elapsed = (Utc::now() - self.progress.started_at)
+            .to_std()
+            .unwrap_or_default();
+        if self.progress.processed_bytes > 0 && self.progress.total_bytes > 0 {
+            let remaining = self.progress.total_bytes - self.progress.processed_bytes;
+            let estimate = elapsed.mul_f64(remaining as f64 / self.progress.processed_bytes as f64);
+            self.progress.estimated_completion = chrono::Duration::from_std(estimate)
+                .ok()
+                .map(|estimate| Utc::now() + estimate);
+        }
+
+        if self.progress.status == FileOperationStatus::InProgress {
+            if let Some(operation) = self
+                .operations
+                .write()
+                .await
+                .get_mut(&self.progress.operation_id)
+            {
+                *operation = self.progress.clone();
+            }
+        }
+
+        if let Some(event_bus) = &self.event_bus {
+            let event = FileProgressEvent {
+                progress: self.progress.clone(),
+                timestamp: Utc::now(),
+                metadata: HashMap::new(),
+                source: "file_manager".to_string(),
+            };
+            let _ = event_bus.publish(event).await;
+        }
+    }
+}
+
+impl Drop for StreamTracker {
+    fn drop(&mut self) {
+        // Streams dropped part way are no longer active
+        if self.progress.status == FileOperationStatus::InProgress {
+            if let Ok(mut operations) = self.operations.try_write() {
+                operations.remove(&self.progress.operation_id);
+            }
+        }
+    }
+}
+
+/// Chunked reader returned by [`FileManager::read_stream`]
+pub struct FileReadStream {
+    path: PathBuf,
+    file: fs::File,
+    chunk_size: usize,
+    decoder: Option<flate2::write::GzDecoder<Vec<u8>>>,
+    max_size: u64,
+    produced: u64,
+    deadline: Instant,
+    tracker: StreamTracker,
+    done: bool,
+}
+
+impl std::fmt::Debug for FileReadStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileReadStream")
+            .field("path", &self.path)
+            .field("chunk_size", &self.chunk_size)
+            .field("gzip", &self.decoder.is_some())
+            .field("produced", &self.produced)
+            .finish()
+    }
+}
+
+impl FileReadStream {
+    /// Read the next chunk, or `None` once the file is exhausted
+    pub async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let result = self.read_chunk().await;
+        match &result {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                self.done = true;
+                self.tracker.finish(FileOperationStatus::Completed).await;
+            }
+            Err(_) => {
+                self.done = true;
+                self.tracker.finish(FileOperationStatus::Failed).await;
+            }
+        }
+        result
+    }
+
+    /// Read the rest of the stream into memory
+    pub async fn read_to_end(mut self) -> Result<Vec<u8>> {
+        let mut contents = Vec::new();
+        while let Some(chunk) = self.next_chunk().await? {
+            contents.extend_from_slice(&chunk);
+        }
+        Ok(contents)
+    }
+
+    /// Bytes handed out so far, after decompression
+    pub fn bytes_read(&self) -> u64 {
+        self.produced
+    }
+
+    async fn read_chunk(&mut self) -> Result<Option<Vec<u8>>> {
+        use std::io::Write;
+
+        loop {
+            let mut buffer = vec![0; self.chunk_size];
+            let read = tokio::time::timeout_at(self.deadline, self.file.read(&mut buffer))
+                .await
+                .map_err(|_| stream_timeout_error(&self.path, FileOperation::Read))?
+                .with_context(|| format!("Failed to read file: {}", self.path.display()))?;
+            self.tracker.advance(read as u64).await;
+
+            if read == 0 {
+                let rest = match self.decoder.take() {
+                    Some(decoder) => decoder
+                        .finish()
+                        .map_err(|e| stream_io_error(&self.path, FileOperation::Decompress, &e))?,
+                    None => Vec::new(),
+                };
+                if rest.is_empty() {
+                    return Ok(None);
+                }
+                self.count(rest.len())?;
+                return Ok(Some(rest));
+            }
+
+            buffer.truncate(read);
+            let chunk = match &mut self.decoder {
+                Some(decoder) => {
+                    decoder
+                        .write_all(&buffer)
+                        .map_err(|e| stream_io_error(&self.path, FileOperation::Decompress, &e))?;
+                    std::mem::take(decoder.get_mut())
+                }
+                None => buffer,
+            };
+
+            // The decoder may hold on to a chunk until it has a full block
+            if chunk.is_empty() {
+                continue;
+            }
+            self.count(chunk.len())?;
+            return Ok(Some(chunk));
+        }
+    }
+
+    /// Guard against archives that inflate past `max_file_size`
+    fn count(&mut self, bytes: usize) -> Result<()> {
+        self.produced += bytes as u64;
+        if self.produced > self.max_size {
+            return Err(size_limit_error(
+                &self.path,
+                FileOperation::Read,
+                self.produced,
+                self.max_size,
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Chunked writer returned by [`FileManager::write_stream`]
+pub struct FileWriteStream {
+    path: PathBuf,
+    /// Where chunks are written: a temporary file for atomic writes
+    target: PathBuf,
+    file: Option<fs::File>,
+    encoder: Option<flate2::write::GzEncoder<Vec<u8>>>,
+    options: FileOperationOptions,
+    max_size: u64,
+    written: u64,
+    deadline: Instant,
+    tracker: StreamTracker,
+    finished: bool,
+}
+
+impl std::fmt::Debug for FileWriteStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileWriteStream")
+            .field("path", &self.path)
+            .field("target", &self.target)
+            .field("gzip", &self.encoder.is_some())
+            .field("written", &self.written)
+            .finish()
+    }
+}
+
+impl FileWriteStream {
+    /// Append a chunk to the file
+    pub async fn write_chunk(&mut self, data: &[u8]) -> Result<()> {
+        let result = self.write_data(data).await;
+        if result.is_err() {
+            self.tracker.finish(FileOperationStatus::Failed).await;
+        }
+        result
+    }
+
+    /// Flush the remaining data and move the file into place, returning the
+    /// number of bytes written before compression
+    pub async fn finish(mut self) -> Result<u64> {
+        let result = self.complete().await;
+        let status = if result.is_ok() {
+            FileOperationStatus::Completed
+        } else {
+            FileOperationStatus::Failed
+        };
+        self.tracker.finish(status).await;
+        result
+    }
+
+    /// Bytes accepted so far, before compression
+    pub fn bytes_written(&self) -> u64 {
+        self.written
+    }
+
+    async fn write_data(&mut self, data: &[u8]) -> Result<()> {
+        use std::io::Write;
+
+        if self.written + data.len() as u64 > self.max_size {
+            return Err(size_limit_error(
+                &self.path,
+                FileOperation::Write,
+                self.written + data.len() as u64,
+                self.max_size,
+            ));
+        }
+
+        match &mut self.encoder {
+            Some(encoder) => {
+                encoder
+                    .write_all(data)
+                    .map_err(|e| stream_io_error(&self.path, FileOperation::Compress, &e))?;
+                let compressed = std::mem::take(encoder.get_mut());
+                self.write_raw(&compressed).await?;
+            }
+            None => self.write_raw(data).await?,
+        }
+
+        self.written += data.len() as u64;
+        self.tracker.advance(data.len() as u64).await;
+        Ok(())
+    }
+
+    async fn write_raw(&mut self, data: &[u8]) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let file = self.file.as_mut().ok_or_else(|| {
+            Error::new(
+                crate::error::ErrorKind::File {
+                    path: Some(self.path.display().to_string()),
+                    operation: FileOperation::Write,
+                },
+                "Write stream is already closed",
+            )
+        })?;
+        tokio::time::timeout_at(self.deadline, file.write_all(data))
+            .await
+            .map_err(|_| stream_timeout_error(&self.path, FileOperation::Write))?
+            .with_context(|| format!("Failed to write file: {}", self.target.display()))
+    }
+
+    async fn complete(&mut self) -> Result<u64> {
+        if let Some(encoder) = self.encoder.take() {
+            let rest = encoder
+                .finish()
+                .map_err(|e| stream_io_error(&self.path, FileOperation::Compress, &e))?;
+            self.write_raw(&rest).await?;
+        }
+
+        if let Some(mut file) = self.file.take() {
+            tokio::time::timeout_at(self.deadline, file.flush())
+                .await
+                .map_err(|_| stream_timeout_error(&self.path, FileOperation::Write))?
+                .with_context(|| format!("Failed to flush file: {}", self.target.display()))?;
+        }
+
+        FileManager::apply_file_options(&self.target, &self.options).await?;
+        if self.target != self.path {
+            fs::rename(&self.target, &self.path)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to rename {} to {}",
+                        self.target.display(),
+                        self.path.display()
+                    )
+                })?;
+        }
+
+        self.finished = true;
+        Ok(self.written)
+    }
+}
+
+impl Drop for FileWriteStream {
+    fn drop(&mut self) {
+        // An unfinished atomic write leaves the destination untouched
+        if !self.finished && self.target != self.path {
+            let _ = std::fs::remove_file(&self.target);
+        }
+    }
+}
+
+fn size_limit_error(path: &Path, operation: FileOperation, size: u64, max_size: u64) -> Error {
+    Error::new(
+        crate::error::ErrorKind::File {
+            path: Some(path.display().to_string()),
+            operation,
+        },
+        format!(
+            "File size ({} bytes) exceeds maximum allowed size ({} bytes)",
+            size, max_size
+        ),
+    )
+}
+
+fn stream_timeout_error(path: &Path, operation: FileOperation) -> Error {
+    Error::new(
+        crate::error::ErrorKind::File {
+            path: Some(path.display().to_string()),
+            operation,
+        },
+        format!(
+            "Streamed operation on {} exceeded the operation timeout",
+            path.display()
+        ),
+    )
+}
+
+fn stream_io_error(path: &Path, operation: FileOperation, source: &std::io::Error) -> Error {
+    Error::new(
+        crate::error::ErrorKind::File {
+            path: Some(path.display().to_string()),
+            operation,
+        },
+        format!("Failed to stream {}: {}", path.display(), source),
+    )
+}
+
 /// Calculate SHA-256 hash of a file
 pub async fn calculate_file_hash(path: impl AsRef<Path>) -> Result<String> {
     let mut file = fs::File::open(path.as_ref()).await.with_context(|| {
@@ -1302,6 +1997,55 @@ mod tests {
         manager.shutdown().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_stream_operations() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = FileConfig::default();
+        config.enable_compression = true;
+        config.max_file_size = 1024 * 1024;
+        config.allowed_roots = vec![temp_dir.path().join("imports")];
+        let manager = FileManager::new(config);
+        std::fs::create_dir_all(temp_dir.path().join("imports")).unwrap();
+
+        let catalog = temp_dir.path().join("imports/catalog.csv.gz");
+        let line = b"sku,name,price\n";
+        let options = FileStreamOptions {
+            chunk_size: 16,
+            gzip: true,
+            ..FileStreamOptions::default()
+        };
+
+        let mut writer = manager
+            .write_stream(&catalog, Some(options.clone()))
+            .await
+            .unwrap();
+        for _ in 0..100 {
+            writer.write_chunk(line).await.unwrap();
+        }
+        assert_eq!(manager.get_active_operations().await.len(), 1);
+        assert_eq!(writer.finish().await.unwrap(), 100 * line.len() as u64);
+        assert!(manager.get_active_operations().await.is_empty());
+
+        let reader = manager.read_stream(&catalog, Some(options)).await.unwrap();
+        assert_eq!(reader.read_to_end().await.unwrap(), line.repeat(100));
+
+        // Paths outside the allowed roots are refused, even via `..`
+        let escaped = temp_dir.path().join("imports/../outside.csv");
+        assert!(manager.write_stream(&escaped, None).await.is_err());
+        assert!(!temp_dir.path().join("outside.csv").exists());
+
+        // An oversized atomic write fails and leaves nothing behind
+        let large = temp_dir.path().join("imports/large.bin");
+        let mut writer = manager.write_stream(&large, None).await.unwrap();
+        let chunk = vec![0u8; 512 * 1024];
+        writer.write_chunk(&chunk).await.unwrap();
+        writer.write_chunk(&chunk).await.unwrap();
+        assert!(writer.write_chunk(&chunk).await.is_err());
+        drop(writer);
+        assert!(!large.exists());
+        assert!(!large.with_extension("tmp").exists());
+    }
+
     #[tokio::test]
     async fn test_directory_operations() {
         let temp_dir = TempDir::new().unwrap();
@@ -53,6 +53,12 @@ pub mod ui;
 pub mod utils;
 pub mod utils_general;
 
+/// Re-exports used by this crate's macros; not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    pub use serde_json;
+}
+
 // Native-only modules
 #[cfg(not(target_arch = "wasm32"))]
 pub mod concurrency;
@@ -65,7 +71,7 @@ pub mod task;
 
 // Re-export commonly used types
 pub use app::ApplicationCore;
-pub use error::{Error, ErrorKind, Result, ResultExt};
+pub use error::{Error, ErrorKind, Result, ResultExt, ResultFieldExt};
 pub use manager::{Manager, ManagerState, ManagerStatus};
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -9,24 +9,40 @@
 //! - Performance metrics and tracing integration
 //! - Dynamic log level configuration
 //! - Context-aware logging with correlation IDs
+//!
+//! The log filter can be changed at runtime through the `logging.level`
+//! config key, including per-target directives such as
+//! `info,qorzen_oxide::plugin=debug`. This only works when the global
+//! subscriber was built with a reloadable [`EnvFilter`] directly on top of
+//! [`Registry`]: either let [`LoggingManager`] install the subscriber, or build
+//! it with [`reloadable_filter`] and pass the handle to
+//! [`LoggingManager::with_filter_handle`].
+//!
+//! The configured console and file outputs likewise only take effect in a
+//! subscriber whose outputs can be swapped. A binary that logs before its
+//! config is loaded should install one with [`install_subscriber`] and pass
+//! the handles to [`LoggingManager::with_handles`].
 
 use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::utils::Time;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{broadcast, mpsc, RwLock};
 use tracing::{Event, Subscriber};
 use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::layer::Identity;
+use tracing_subscriber::layer::Layered;
 use tracing_subscriber::{
-    fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer, Registry,
+    fmt, layer::SubscriberExt, reload, util::SubscriberInitExt, EnvFilter, Layer, Registry,
 };
 use uuid::Uuid;
 
-use crate::config::{LogFormat, LoggingConfig};
+use crate::config::{ConfigChangeEvent, FileLogConfig, LogFormat, LoggingConfig, CONFIG_BATCH_KEY};
 use crate::error::{Error, ErrorKind, Result, ResultExt};
 use crate::manager::{ManagedState, Manager, ManagerStatus};
 
@@ -203,6 +219,182 @@ impl LogWriter for HttpLogWriter {
     }
 }
 
+/// Target of application lifecycle records such as [`ReadyEvent`]
+pub const LIFECYCLE_TARGET: &str = "qorzen::lifecycle";
+
+/// Logged once when initialization completes so orchestration can detect readiness.
+///
+/// The record has `event = "ready"` on the [`LIFECYCLE_TARGET`] target; with the
+/// JSON log format every field below is a JSON field on a single line. It is
+/// logged at WARN so the default filter lets it through.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadyEvent {
+    pub version: String,
+    pub pid: u32,
+    /// `host:port` the application listens on
+    pub bind_address: String,
+    pub plugin_count: usize,
+    pub environment: String,
+}
+
+impl ReadyEvent {
+    pub fn emit(&self) {
+        tracing::warn!(
+            target: LIFECYCLE_TARGET,
+            event = "ready",
+            version = %self.version,
+            pid = self.pid,
+            bind_address = %self.bind_address,
+            plugin_count = self.plugin_count,
+            environment = %self.environment,
+            "Application ready"
+        );
+    }
+}
+
+/// Size and count of the log files written by a [`RotatingFileWriter`]
+#[derive(Debug, Clone, Default)]
+pub struct RotationStatus {
+    pub current_file_size: u64,
+    pub rotated_files: u32,
+    pub last_rotation: Option<DateTime<Utc>>,
+}
+
+/// Log file writer that rotates by size.
+///
+/// Once a write would take the file past `max_size` bytes, `app.log` moves to
+/// `app.log.1` (`app.log.1.gz` when compressing), older files shift up by one
+/// and those beyond `max_files` are deleted. A `max_size` of 0 never rotates.
+#[derive(Debug)]
+pub struct RotatingFileWriter {
+    path: PathBuf,
+    max_size: u64,
+    max_files: u32,
+    compress: bool,
+    file: File,
+    size: u64,
+    status: Arc<std::sync::Mutex<RotationStatus>>,
+}
+
+impl RotatingFileWriter {
+    pub fn new(config: &FileLogConfig) -> io::Result<Self> {
+        if let Some(parent) = config.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = Self::open(&config.path)?;
+        let size = file.metadata()?.len();
+
+        let mut writer = Self {
+            path: config.path.clone(),
+            max_size: config.max_size,
+            max_files: config.max_files,
+            compress: config.compress,
+            file,
+            size,
+            status: Arc::default(),
+        };
+        let rotated_files = (1..=writer.max_files)
+            .take_while(|index| writer.rotated_path(*index).exists())
+            .count() as u32;
+        writer.update_status(|status| {
+            status.current_file_size = size;
+            status.rotated_files = rotated_files;
+        });
+        Ok(writer)
+    }
+
+    /// Shared view of the files written, updated on every write
+    pub fn status(&self) -> Arc<std::sync::Mutex<RotationStatus>> {
+        Arc::clone(&self.status)
+    }
+
+    fn open(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn rotated_path(&self, index: u32) -> PathBuf {
+        let mut name = self.path.clone().into_os_string();
+        name.push(format!(".{}", index));
+        if self.compress {
+            name.push(".gz");
+        }
+        PathBuf::from(name)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        if self.max_files == 0 {
+            self.file = OpenOptions::new()
+                .write(true)
+                .truncate(true)
+                .open(&self.path)?;
+        } else {
+            remove_if_exists(&self.rotated_path(self.max_files))?;
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    std::fs::rename(&from, self.rotated_path(index + 1))?;
+                }
+            }
+
+            let first = self.rotated_path(1);
+            if self.compress {
+                let mut source = File::open(&self.path)?;
+                let mut encoder = flate2::write::GzEncoder::new(
+                    File::create(&first)?,
+                    flate2::Compression::default(),
+                );
+                io::copy(&mut source, &mut encoder)?;
+                encoder.finish()?;
+                std::fs::remove_file(&self.path)?;
+            } else {
+                std::fs::rename(&self.path, &first)?;
+            }
+            self.file = Self::open(&self.path)?;
+        }
+
+        self.size = 0;
+        let max_files = self.max_files;
+        self.update_status(|status| {
+            status.rotated_files = (status.rotated_files + 1).min(max_files);
+            status.last_rotation = Some(Time::now());
+        });
+        Ok(())
+    }
+
+    fn update_status(&self, update: impl FnOnce(&mut RotationStatus)) {
+        if let Ok(mut status) = self.status.lock() {
+            update(&mut status);
+        }
+    }
+}
+
+impl Write for RotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.max_size > 0 && self.size > 0 && self.size + buf.len() as u64 > self.max_size {
+            self.rotate()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        let size = self.size;
+        self.update_status(|status| status.current_file_size = size);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
 #[derive(Clone, Debug)]
 struct QorzenLayer {
     entry_sender: mpsc::UnboundedSender<LogEntry>,
@@ -238,7 +430,7 @@ where
 
         // Create log entry
         let entry = LogEntry {
-            id: Uuid::new_v4(),
+            id: crate::utils::rand::uuid(),
             level,
             timestamp: Time::now(),
             source: event.metadata().target().to_string(),
@@ -270,10 +462,138 @@ where
     }
 }
 
+/// Build a fmt layer writing `format` to `writer`.
+///
+/// `Pretty` is fmt's default single-line format with the source location,
+/// `Compact` drops the location, and `Json` writes one JSON object per line
+/// and never uses ANSI colors.
+pub fn format_layer<S, W>(
+    format: LogFormat,
+    writer: W,
+    ansi: bool,
+) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'lookup> tracing_subscriber::registry::LookupSpan<'lookup>,
+    W: for<'writer> fmt::MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let layer = fmt::layer().with_writer(writer);
+    match format {
+        LogFormat::Json => layer.json().with_ansi(false).boxed(),
+        LogFormat::Pretty => layer
+            .with_ansi(ansi)
+            .with_line_number(true)
+            .with_file(true)
+            .boxed(),
+        LogFormat::Compact => layer.compact().with_ansi(ansi).boxed(),
+    }
+}
+
+/// Config key holding the log filter directives
+pub const LOG_LEVEL_CONFIG_KEY: &str = "logging.level";
+
+/// Handle for swapping the filter of a running subscriber
+pub type LogFilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Build a filter layer that can be swapped at runtime.
+///
+/// The layer must be added directly on top of [`Registry`], before any other
+/// layer, for the handle to reach it.
+pub fn reloadable_filter(
+    directives: &str,
+) -> Result<(reload::Layer<EnvFilter, Registry>, LogFilterHandle)> {
+    Ok(reload::Layer::new(parse_filter(directives)?))
+}
+
+/// [`Registry`] with the reloadable filter, which the output layers sit on
+pub type FilteredRegistry = Layered<reload::Layer<EnvFilter, Registry>, Registry>;
+
+/// The output layers of a subscriber built by [`install_subscriber`]
+pub type LogOutputs = Vec<Box<dyn Layer<FilteredRegistry> + Send + Sync>>;
+
+/// Handle for swapping the output layers of a running subscriber
+#[derive(Clone)]
+pub struct LogOutputHandle(reload::Handle<LogOutputs, FilteredRegistry>);
+
+impl std::fmt::Debug for LogOutputHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LogOutputHandle")
+    }
+}
+
+impl LogOutputHandle {
+    fn replace(&self, outputs: LogOutputs) -> Result<()> {
+        self.0
+            .reload(outputs)
+            .map_err(|e| Error::config(format!("Failed to reload log outputs: {}", e)))
+    }
+}
+
+/// Handles to the subscriber installed by [`install_subscriber`]
+#[derive(Clone, Debug)]
+pub struct LogHandles {
+    pub filter: LogFilterHandle,
+    pub outputs: LogOutputHandle,
+}
+
+/// Install the global subscriber with a reloadable filter and outputs.
+///
+/// Returns `None` if a global subscriber is already set. Pass the handles to
+/// [`LoggingManager::with_handles`] so the logging config replaces `outputs`
+/// once it is loaded.
+pub fn install_subscriber(directives: &str, outputs: LogOutputs) -> Result<Option<LogHandles>> {
+    Ok(install(parse_filter(directives)?, outputs))
+}
+
+fn install(filter: EnvFilter, outputs: LogOutputs) -> Option<LogHandles> {
+    let (filter, filter_handle) = reload::Layer::new(filter);
+    let (outputs, output_handle) = reload::Layer::new(outputs);
+    Registry::default()
+        .with(filter)
+        .with(outputs)
+        .try_init()
+        .ok()
+        .map(|()| LogHandles {
+            filter: filter_handle,
+            outputs: LogOutputHandle(output_handle),
+        })
+}
+
+fn parse_filter(directives: &str) -> Result<EnvFilter> {
+    EnvFilter::try_new(directives)
+        .map_err(|e| Error::config(format!("Invalid log filter '{}': {}", directives, e)))
+}
+
+fn reload_filter(handle: &LogFilterHandle, directives: &str) -> Result<()> {
+    handle
+        .reload(parse_filter(directives)?)
+        .map_err(|e| Error::config(format!("Failed to reload log filter: {}", e)))
+}
+
+/// The new log filter set by `event`, if it touched [`LOG_LEVEL_CONFIG_KEY`]
+fn changed_log_filter(event: &ConfigChangeEvent) -> Option<&str> {
+    let section_level = |section: &serde_json::Value| section.get("level");
+    let value = match event.key.as_str() {
+        LOG_LEVEL_CONFIG_KEY => Some(&event.value),
+        "logging" => section_level(&event.value),
+        CONFIG_BATCH_KEY => event
+            .value
+            .get(LOG_LEVEL_CONFIG_KEY)
+            .or_else(|| event.value.get("logging").and_then(section_level)),
+        _ => None,
+    };
+    value?.as_str()
+}
+
 #[derive(Debug)]
 pub struct LoggingManager {
     state: ManagedState,
     config: LoggingConfig,
+    /// Filter directives currently applied
+    active_filter: Arc<RwLock<String>>,
+    filter_handle: Option<LogFilterHandle>,
+    output_handle: Option<LogOutputHandle>,
+    config_watch_handle: Option<tokio::task::JoinHandle<()>>,
+    rotation: Option<Arc<std::sync::Mutex<RotationStatus>>>,
     custom_layer: Option<QorzenLayer>,
     _guards: Vec<WorkerGuard>, // Keep guards alive
     writers: Vec<Arc<dyn LogWriter>>,
@@ -284,8 +604,13 @@ pub struct LoggingManager {
 impl LoggingManager {
     pub fn new(config: LoggingConfig) -> Self {
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "logging_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "logging_manager"),
+            active_filter: Arc::new(RwLock::new(config.level.clone())),
             config,
+            filter_handle: None,
+            output_handle: None,
+            config_watch_handle: None,
+            rotation: None,
             custom_layer: None,
             _guards: Vec::new(),
             writers: Vec::new(),
@@ -294,61 +619,53 @@ impl LoggingManager {
         }
     }
 
+    /// Use the filter of a subscriber built elsewhere with [`reloadable_filter`]
+    pub fn with_filter_handle(mut self, handle: LogFilterHandle) -> Self {
+        self.filter_handle = Some(handle);
+        self
+    }
+
+    /// Take over a subscriber built with [`install_subscriber`], replacing its
+    /// filter and outputs with the configured ones on initialization
+    pub fn with_handles(mut self, handles: LogHandles) -> Self {
+        self.filter_handle = Some(handles.filter);
+        self.output_handle = Some(handles.outputs);
+        self
+    }
+
+    /// `RUST_LOG` if set, otherwise the configured level
+    fn configured_filter(&self) -> EnvFilter {
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&self.config.level))
+    }
+
     async fn setup_tracing(&mut self) -> Result<()> {
-        let filter = EnvFilter::try_from_default_env()
-            .unwrap_or_else(|_| EnvFilter::new(&self.config.level));
-
-        let registry = Registry::default().with(filter);
-
-        // Console output
-        let registry = if self.config.console.enabled {
-            let console_layer = if self.config.console.colored {
-                fmt::layer()
-                    .with_ansi(true)
-                    .with_target(true)
-                    .with_line_number(true)
-                    .with_file(true)
-                    .boxed()
-            } else {
-                fmt::layer()
-                    .with_ansi(false)
-                    .with_target(true)
-                    .with_line_number(true)
-                    .with_file(true)
-                    .boxed()
-            };
-
-            registry.with(console_layer)
-        } else {
-            registry.with(Identity::new().boxed())
-        };
+        // A subscriber installed before the config was loaded switches to the configured filter
+        if let Some(handle) = &self.filter_handle {
+            handle
+                .reload(self.configured_filter())
+                .map_err(|e| Error::config(format!("Failed to reload log filter: {}", e)))?;
+        }
 
-        // File output
-        let registry = if let Some(file_config) = &self.config.file {
-            let file_appender = tracing_appender::rolling::daily(
-                file_config
-                    .path
-                    .parent()
-                    .unwrap_or_else(|| std::path::Path::new(".")),
-                file_config
-                    .path
-                    .file_name()
-                    .unwrap_or_else(|| std::ffi::OsStr::new("app.log")),
-            );
+        let mut outputs: LogOutputs = Vec::new();
+        if self.config.console.enabled {
+            outputs.push(format_layer(
+                self.config.console_format(),
+                std::io::stdout,
+                self.config.console.colored,
+            ));
+        }
+
+        if let Some(file_config) = &self.config.file {
+            let file_appender = RotatingFileWriter::new(file_config).with_context(|| {
+                format!("Failed to open log file: {}", file_config.path.display())
+            })?;
+            self.rotation = Some(file_appender.status());
 
             let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
             self._guards.push(guard);
 
-            let file_layer = match self.config.format {
-                LogFormat::Json => fmt::layer().json().with_writer(non_blocking).boxed(),
-                LogFormat::Pretty => fmt::layer().pretty().with_writer(non_blocking).boxed(),
-                LogFormat::Compact => fmt::layer().compact().with_writer(non_blocking).boxed(),
-            };
-
-            registry.with(file_layer)
-        } else {
-            registry.with(Identity::new().boxed())
-        };
+            outputs.push(format_layer(self.config.file_format(), non_blocking, false));
+        }
 
         // Setup custom layer with channel
         let (entry_sender, mut entry_receiver) = mpsc::unbounded_channel::<LogEntry>();
@@ -375,10 +692,19 @@ impl LoggingManager {
         self.writer_task_handle = Some(writer_task);
 
         // Add our custom layer
-        let registry = registry.with(custom_layer);
-
-        // Initialize the global subscriber
-        registry.try_init().ok();
+        outputs.push(custom_layer.boxed());
+
+        // Replace the outputs of a subscriber installed at startup, or install our own
+        if let Some(handle) = &self.output_handle {
+            handle.replace(outputs)?;
+        } else if let Some(handles) = install(self.configured_filter(), outputs) {
+            self.filter_handle = Some(handles.filter);
+            self.output_handle = Some(handles.outputs);
+        } else if self.filter_handle.is_none() {
+            tracing::debug!(
+                "A global subscriber is already set; logging config changes are not applied"
+            );
+        }
 
         Ok(())
     }
@@ -389,7 +715,7 @@ impl LoggingManager {
     }
 
     pub async fn get_stats(&self) -> LogStats {
-        if let Some(layer) = &self.custom_layer {
+        let mut stats = if let Some(layer) = &self.custom_layer {
             layer.get_stats().await
         } else {
             LogStats {
@@ -400,15 +726,72 @@ impl LoggingManager {
                 rotated_files: 0,
                 last_rotation: None,
             }
+        };
+
+        if let Some(Ok(rotation)) = self.rotation.as_ref().map(|status| status.lock()) {
+            stats.current_file_size = rotation.current_file_size;
+            stats.rotated_files = rotation.rotated_files;
+            stats.last_rotation = rotation.last_rotation;
         }
+        stats
     }
 
     pub async fn set_log_level(&mut self, level: LogLevel) -> Result<()> {
-        // This would update the filter in a real implementation
-        tracing::info!("Log level updated to: {:?}", level);
+        let level = tracing::Level::from(level).to_string().to_lowercase();
+        self.set_filter(&level).await
+    }
+
+    /// Replace the log filter, e.g. `warn,qorzen_oxide::plugin=debug`
+    pub async fn set_filter(&mut self, directives: &str) -> Result<()> {
+        let handle = self.filter_handle.as_ref().ok_or_else(|| {
+            Error::config("The log filter is not reloadable; see the logging module docs")
+        })?;
+        reload_filter(handle, directives)?;
+        *self.active_filter.write().await = directives.to_string();
+        tracing::info!("Log filter updated to: {}", directives);
         Ok(())
     }
 
+    /// The filter directives currently applied
+    pub async fn active_filter(&self) -> String {
+        self.active_filter.read().await.clone()
+    }
+
+    /// Reload the log filter whenever [`LOG_LEVEL_CONFIG_KEY`] changes.
+    ///
+    /// Invalid directives are logged and the previous filter kept.
+    pub fn watch_config_changes(&mut self, mut changes: broadcast::Receiver<ConfigChangeEvent>) {
+        let Some(handle) = self.filter_handle.clone() else {
+            tracing::warn!("The log filter is not reloadable; ignoring logging.level changes");
+            return;
+        };
+        let active_filter = Arc::clone(&self.active_filter);
+
+        if let Some(previous) = self.config_watch_handle.take() {
+            previous.abort();
+        }
+        self.config_watch_handle = Some(tokio::spawn(async move {
+            loop {
+                let event = match changes.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                let Some(directives) = changed_log_filter(&event) else {
+                    continue;
+                };
+
+                match reload_filter(&handle, directives) {
+                    Ok(()) => {
+                        *active_filter.write().await = directives.to_string();
+                        tracing::info!("Log filter updated to: {}", directives);
+                    }
+                    Err(e) => tracing::warn!("Ignoring logging.level change: {}", e),
+                }
+            }
+        }));
+    }
+
     pub async fn flush(&self) -> Result<()> {
         for writer in &self.writers {
             writer
@@ -431,7 +814,7 @@ impl Manager for LoggingManager {
     }
 
     fn id(&self) -> Uuid {
-        Uuid::new_v4() // Simplified
+        crate::utils::rand::uuid() // Simplified
     }
 
     async fn initialize(&mut self) -> Result<()> {
@@ -439,18 +822,9 @@ impl Manager for LoggingManager {
             .set_state(crate::manager::ManagerState::Initializing)
             .await;
 
-        // Setup tracing
+        // Setup tracing; the file writer creates its own directory
         self.setup_tracing().await?;
 
-        // Create log directories if needed
-        if let Some(file_config) = &self.config.file {
-            if let Some(parent) = file_config.path.parent() {
-                tokio::fs::create_dir_all(parent).await.with_context(|| {
-                    format!("Failed to create log directory: {}", parent.display())
-                })?;
-            }
-        }
-
         self.state
             .set_state(crate::manager::ManagerState::Running)
             .await;
@@ -476,6 +850,10 @@ impl Manager for LoggingManager {
                 .with_context(|| "Failed to close log writer".to_string())?;
         }
 
+        if let Some(handle) = self.config_watch_handle.take() {
+            handle.abort();
+        }
+
         // Stop the writer task
         if let Some(handle) = self.writer_task_handle.take() {
             handle.abort();
@@ -507,7 +885,7 @@ impl Manager for LoggingManager {
         );
         status.add_metadata(
             "log_level",
-            serde_json::Value::String(self.config.level.clone()),
+            serde_json::Value::String(self.active_filter().await),
         );
 
         status
@@ -669,6 +1047,326 @@ mod tests {
         }
     }
 
+    #[derive(Clone, Default)]
+    struct CapturedOutput(Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for CapturedOutput {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ready_event_is_one_json_line() {
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let subscriber = fmt().json().with_writer(move || writer.clone()).finish();
+
+        let event = ReadyEvent {
+            version: "0.1.0".to_string(),
+            pid: 4242,
+            bind_address: "127.0.0.1:8080".to_string(),
+            plugin_count: 3,
+            environment: "production".to_string(),
+        };
+        tracing::subscriber::with_default(subscriber, || event.emit());
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        let line: serde_json::Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(line["target"], LIFECYCLE_TARGET);
+        assert_eq!(line["level"], "WARN");
+        let fields = &line["fields"];
+        assert_eq!(fields["event"], "ready");
+        assert_eq!(fields["version"], "0.1.0");
+        assert_eq!(fields["pid"], 4242);
+        assert_eq!(fields["bind_address"], "127.0.0.1:8080");
+        assert_eq!(fields["plugin_count"], 3);
+        assert_eq!(fields["environment"], "production");
+    }
+
+    fn config_change(key: &str, value: serde_json::Value) -> ConfigChangeEvent {
+        ConfigChangeEvent {
+            key: key.to_string(),
+            value,
+            old_value: None,
+            timestamp: Time::now(),
+            source: "test".to_string(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_changed_log_filter() {
+        let event = config_change(LOG_LEVEL_CONFIG_KEY, serde_json::json!("debug"));
+        assert_eq!(changed_log_filter(&event), Some("debug"));
+
+        let event = config_change("logging", serde_json::json!({ "level": "warn" }));
+        assert_eq!(changed_log_filter(&event), Some("warn"));
+
+        let event = config_change(
+            CONFIG_BATCH_KEY,
+            serde_json::json!({ "logging.level": "error", "app.name": "x" }),
+        );
+        assert_eq!(changed_log_filter(&event), Some("error"));
+
+        let event = config_change("logging.format", serde_json::json!("json"));
+        assert_eq!(changed_log_filter(&event), None);
+    }
+
+    #[tokio::test]
+    async fn test_log_filter_reloads_on_config_change() {
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let (filter, handle) = reloadable_filter("warn").unwrap();
+        let subscriber = Registry::default()
+            .with(filter)
+            .with(fmt::layer().with_writer(move || writer.clone()));
+
+        let mut manager = LoggingManager::new(LoggingConfig::default()).with_filter_handle(handle);
+        let (sender, receiver) = broadcast::channel(8);
+        manager.watch_config_changes(receiver);
+
+        sender
+            .send(config_change(
+                LOG_LEVEL_CONFIG_KEY,
+                serde_json::json!("warn,qorzen_oxide::plugin=debug"),
+            ))
+            .unwrap();
+        // Invalid directives keep the previous filter
+        sender
+            .send(config_change(LOG_LEVEL_CONFIG_KEY, serde_json::json!("=[")))
+            .unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        assert_eq!(
+            manager.active_filter().await,
+            "warn,qorzen_oxide::plugin=debug"
+        );
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!(target: "qorzen_oxide::plugin", "plugin detail");
+            tracing::debug!(target: "qorzen_oxide::event", "event detail");
+        });
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("plugin detail"));
+        assert!(!output.contains("event detail"));
+
+        manager.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_startup_subscriber_takes_configured_level() {
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let (filter, handle) = reloadable_filter("warn").unwrap();
+        let subscriber = Registry::default()
+            .with(filter)
+            .with(fmt::layer().with_writer(move || writer.clone()));
+
+        let config = LoggingConfig {
+            level: "error".to_string(),
+            file: None,
+            ..Default::default()
+        };
+        let mut manager = LoggingManager::new(config).with_filter_handle(handle);
+        manager.initialize().await.unwrap();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!("startup warning");
+            tracing::error!("startup error");
+        });
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(!output.contains("startup warning"));
+        assert!(output.contains("startup error"));
+
+        manager.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_startup_subscriber_takes_configured_outputs() {
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let (filter, filter_handle) = reloadable_filter("info").unwrap();
+        let (outputs, output_handle) = reload::Layer::new(vec![format_layer(
+            LogFormat::Pretty,
+            move || writer.clone(),
+            false,
+        )]);
+        let subscriber = Arc::new(Registry::default().with(filter).with(outputs));
+
+        tracing::subscriber::with_default(Arc::clone(&subscriber), || {
+            tracing::info!("before config");
+        });
+
+        // The configured outputs replace the startup console entirely
+        let mut config = LoggingConfig {
+            file: None,
+            ..Default::default()
+        };
+        config.console.enabled = false;
+        let mut manager = LoggingManager::new(config).with_handles(LogHandles {
+            filter: filter_handle,
+            outputs: LogOutputHandle(output_handle),
+        });
+        manager.initialize().await.unwrap();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("after config");
+        });
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains("before config"));
+        assert!(!output.contains("after config"));
+
+        manager.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_file_output_reaches_startup_subscriber() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("logs").join("app.log");
+        let (filter, filter_handle) = reloadable_filter("info").unwrap();
+        let (outputs, output_handle) = reload::Layer::new(LogOutputs::new());
+        let subscriber = Registry::default().with(filter).with(outputs);
+
+        let mut config = LoggingConfig {
+            format: LogFormat::Json,
+            file: Some(FileLogConfig {
+                path: path.clone(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        config.console.enabled = false;
+        let mut manager = LoggingManager::new(config).with_handles(LogHandles {
+            filter: filter_handle,
+            outputs: LogOutputHandle(output_handle),
+        });
+        manager.initialize().await.unwrap();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(sku = "ABC-123", "Imported product");
+        });
+
+        let mut contents = String::new();
+        for _ in 0..100 {
+            contents = std::fs::read_to_string(&path).unwrap_or_default();
+            if !contents.is_empty() {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+        let line: serde_json::Value = serde_json::from_str(contents.trim()).unwrap();
+        assert_eq!(line["fields"]["sku"], "ABC-123");
+        assert_eq!(
+            manager.get_stats().await.current_file_size,
+            contents.len() as u64
+        );
+
+        manager.shutdown().await.unwrap();
+    }
+
+    #[test]
+    fn test_pretty_format_is_single_line() {
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let subscriber = Registry::default().with(format_layer(
+            LogFormat::Pretty,
+            move || writer.clone(),
+            false,
+        ));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(sku = "ABC-123", "Imported product");
+        });
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.contains("Imported product sku=\"ABC-123\""));
+    }
+
+    #[test]
+    fn test_json_format_layer_emits_json_lines() {
+        let output = CapturedOutput::default();
+        let writer = output.clone();
+        let subscriber =
+            Registry::default().with(format_layer(LogFormat::Json, move || writer.clone(), true));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(sku = "ABC-123", "Imported product");
+            tracing::warn!("Catalog import slow");
+        });
+
+        let output = String::from_utf8(output.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["level"], "INFO");
+        assert_eq!(lines[0]["fields"]["message"], "Imported product");
+        assert_eq!(lines[0]["fields"]["sku"], "ABC-123");
+        assert_eq!(lines[1]["level"], "WARN");
+    }
+
+    #[test]
+    fn test_output_formats_fall_back_to_logging_format() {
+        let mut config = LoggingConfig {
+            format: LogFormat::Compact,
+            ..LoggingConfig::default()
+        };
+        assert_eq!(config.console_format(), LogFormat::Compact);
+        assert_eq!(config.file_format(), LogFormat::Compact);
+
+        config.console.format = Some(LogFormat::Pretty);
+        if let Some(file) = config.file.as_mut() {
+            file.format = Some(LogFormat::Json);
+        }
+        assert_eq!(config.console_format(), LogFormat::Pretty);
+        assert_eq!(config.file_format(), LogFormat::Json);
+    }
+
+    #[test]
+    fn test_rotating_file_writer() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config = FileLogConfig {
+            path: temp_dir.path().join("logs/app.log"),
+            max_size: 32,
+            max_files: 2,
+            compress: true,
+            format: None,
+        };
+        let mut writer = RotatingFileWriter::new(&config).unwrap();
+        let status = writer.status();
+
+        for line in ["first line of the log\n", "second line\n", "third line\n"] {
+            writer.write_all(line.as_bytes()).unwrap();
+        }
+        writer.write_all(b"fourth line of the log\n").unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&config.path).unwrap(),
+            "fourth line of the log\n"
+        );
+        let newest = std::fs::read(temp_dir.path().join("logs/app.log.1.gz")).unwrap();
+        assert_eq!(
+            crate::utils_general::compression::decompress_gzip(&newest).unwrap(),
+            b"second line\nthird line\n"
+        );
+        assert!(temp_dir.path().join("logs/app.log.2.gz").exists());
+        assert!(!temp_dir.path().join("logs/app.log.3.gz").exists());
+
+        let status = status.lock().unwrap();
+        assert_eq!(status.rotated_files, 2);
+        assert_eq!(status.current_file_size, 23);
+        assert!(status.last_rotation.is_some());
+    }
+
     #[tokio::test]
     async fn test_logging_manager_initialization() {
         let config = LoggingConfig::default();
@@ -7,9 +7,11 @@
 )]
 
 #[cfg(not(target_arch = "wasm32"))]
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 #[cfg(not(target_arch = "wasm32"))]
 use std::process;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::OnceLock;
 
 #[cfg(not(target_arch = "wasm32"))]
 use clap::{Parser, Subcommand};
@@ -37,6 +39,10 @@ struct Cli {
     #[arg(long)]
     headless: bool,
 
+    /// Console log format, overriding the config; `json` emits one JSON object per line
+    #[arg(long, value_name = "FORMAT", value_parser = ["pretty", "compact", "json"])]
+    log_format: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -110,23 +116,59 @@ fn main() {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+/// The subscriber installed from the command line, handed to the application
+/// so its logging manager can apply the logging config to it
+struct LogSetup {
+    handles: qorzen_oxide::logging::LogHandles,
+    /// Level chosen with `--debug` or `--verbose`, which wins over the config
+    level: Option<&'static str>,
+    /// Format chosen with `--log-format`, which wins over the config
+    format: Option<qorzen_oxide::config::LogFormat>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+static LOG_SETUP: OnceLock<LogSetup> = OnceLock::new();
+
+/// Becomes `true` once the desktop core has shut down, so the window can close
+/// with it after a SIGINT or SIGTERM
+#[cfg(not(target_arch = "wasm32"))]
+static CORE_STOPPED: OnceLock<tokio::sync::watch::Receiver<bool>> = OnceLock::new();
+
 #[cfg(not(target_arch = "wasm32"))]
 fn setup_logging(cli: &Cli) {
+    use qorzen_oxide::config::LogFormat;
+
     let level = if cli.debug {
-        tracing::Level::DEBUG
+        Some("debug")
     } else if cli.verbose {
-        tracing::Level::INFO
+        Some("info")
     } else {
-        tracing::Level::WARN
+        None
     };
 
-    tracing_subscriber::fmt()
-        .with_max_level(level)
-        .with_target(false)
-        .try_init()
-        .ok();
+    let format = cli.log_format.as_deref().map(|format| match format {
+        "json" => LogFormat::Json,
+        "compact" => LogFormat::Compact,
+        _ => LogFormat::Pretty,
+    });
+
+    let console = qorzen_oxide::logging::format_layer(
+        format.unwrap_or(LogFormat::Pretty),
+        std::io::stdout,
+        true,
+    );
+    let handles = qorzen_oxide::logging::install_subscriber(level.unwrap_or("warn"), vec![console])
+        .expect("built-in log levels are valid filters");
+    if let Some(handles) = handles {
+        let _ = LOG_SETUP.set(LogSetup {
+            handles,
+            level,
+            format,
+        });
+    }
 
-    tracing::info!("Logging initialized at level: {:?}", level);
+    tracing::info!("Logging initialized at level: {}", level.unwrap_or("warn"));
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -154,14 +196,54 @@ fn print_system_info() {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn run_ui_application(_cli: &Cli) {
+fn run_ui_application(cli: &Cli) {
     tracing::info!(
         "Starting Qorzen Oxide v{} (Desktop UI)",
         qorzen_oxide::VERSION
     );
 
+    // The core serves plugin events, installs and reloads beside the window,
+    // which owns the main thread. Each stops the other: closing the window
+    // shuts the core down, and a core stopped by a signal closes the window.
+    let (window_closed, window_closed_rx) = tokio::sync::watch::channel(false);
+    let (core_stopped, core_stopped_rx) = tokio::sync::watch::channel(false);
+    let _ = CORE_STOPPED.set(core_stopped_rx);
+
+    let config = cli.config.clone();
+    let core = std::thread::Builder::new()
+        .name("qorzen-core".to_string())
+        .spawn(move || {
+            let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
+            rt.block_on(async {
+                if let Err(e) =
+                    run_application_async(config.as_deref(), false, Some(window_closed_rx)).await
+                {
+                    tracing::error!("Application error: {}", e);
+                    eprintln!("Application error: {}", e);
+                }
+            });
+            let _ = core_stopped.send(true);
+        })
+        .expect("Failed to start the application core");
+
+    // The event loop exits the process itself, so the core is joined from its
+    // last event rather than after `launch` returns
+    let mut core = Some(core);
+    let desktop = dioxus::desktop::Config::new().with_custom_event_handler(move |event, _| {
+        if matches!(event, dioxus::desktop::tao::event::Event::LoopDestroyed) {
+            let _ = window_closed.send(true);
+            if let Some(core) = core.take() {
+                if core.join().is_err() {
+                    tracing::error!("Application core panicked during shutdown");
+                }
+            }
+        }
+    });
+
     // For Dioxus desktop, we use the launch function with custom CSS injected via the App component
-    dioxus::launch(AppWithDesktopCSS);
+    dioxus::LaunchBuilder::desktop()
+        .with_cfg(desktop)
+        .launch(AppWithDesktopCSS);
 }
 
 // Wrapper component for desktop that includes CSS
@@ -170,6 +252,16 @@ fn run_ui_application(_cli: &Cli) {
 fn AppWithDesktopCSS() -> Element {
     use dioxus::prelude::*;
 
+    use_hook(|| {
+        if let Some(mut core_stopped) = CORE_STOPPED.get().cloned() {
+            let window = dioxus::desktop::window();
+            spawn(async move {
+                let _ = core_stopped.wait_for(|stopped| *stopped).await;
+                window.close();
+            });
+        }
+    });
+
     rsx! {
         head {
             style {
@@ -185,7 +277,7 @@ fn AppWithDesktopCSS() -> Element {
 fn run_headless_application(cli: &Cli) {
     let rt = tokio::runtime::Runtime::new().expect("Failed to create Tokio runtime");
     rt.block_on(async {
-        if let Err(e) = run_application_async(cli, true).await {
+        if let Err(e) = run_application_async(cli.config.as_deref(), true, None).await {
             tracing::error!("Application error: {}", e);
             eprintln!("Application error: {}", e);
             process::exit(1);
@@ -208,25 +300,52 @@ where
     });
 }
 
+/// Runs the core until a shutdown signal, or until `window_closed` becomes
+/// `true` when it serves a desktop window
 #[cfg(not(target_arch = "wasm32"))]
-async fn run_application_async(cli: &Cli, headless: bool) -> Result<()> {
-    tracing::info!(
-        "Starting Qorzen Oxide v{} (Headless)",
-        qorzen_oxide::VERSION
-    );
+async fn run_application_async(
+    config: Option<&Path>,
+    headless: bool,
+    window_closed: Option<tokio::sync::watch::Receiver<bool>>,
+) -> Result<()> {
+    if headless {
+        tracing::info!(
+            "Starting Qorzen Oxide v{} (Headless)",
+            qorzen_oxide::VERSION
+        );
+    }
 
-    let mut app = if let Some(config_path) = &cli.config {
+    let mut app = if let Some(config_path) = config {
         ApplicationCore::with_config_file(config_path)
     } else {
         ApplicationCore::new()
     };
+    if let Some(setup) = LOG_SETUP.get() {
+        app = app.with_log_handles(setup.handles.clone());
+        if let Some(level) = setup.level {
+            app = app.with_log_level(level);
+        }
+        if let Some(format) = setup.format {
+            app = app.with_log_format(format);
+        }
+    }
 
     // Initialize the application core
     app.initialize().await?;
 
     if headless {
         tracing::info!("Running in headless mode - waiting for shutdown signal");
-        app.wait_for_shutdown().await?;
+    }
+    match window_closed {
+        Some(mut window_closed) => {
+            tokio::select! {
+                result = app.serve_until_shutdown() => result?,
+                _ = window_closed.wait_for(|closed| *closed) => {
+                    tracing::info!("Window closed, shutting down");
+                }
+            }
+        }
+        None => app.serve_until_shutdown().await?,
     }
 
     // Graceful shutdown
@@ -239,8 +358,11 @@ fn run_dev_server(port: u16, host: String) {
     tracing::info!("Starting development server on {}:{}", host, port);
 
     // For now, just launch the regular desktop app
-    println!("Development server would start here");
-    println!("Open http://{}:{} in your browser", host, port);
+    tracing::warn!(
+        "Development server is not implemented yet; launching the desktop app instead of http://{}:{}",
+        host,
+        port
+    );
 
     // Launch desktop app for now
     dioxus::launch(AppWithDesktopCSS);
@@ -262,6 +384,28 @@ fn show_status() -> Result<()> {
         println!("Uptime: {:?}", stats.uptime);
         println!("Managers: {}", stats.manager_count);
 
+        let slowest = stats.slowest_managers();
+        if !slowest.is_empty() {
+            println!("Slowest managers:");
+            for (name, duration) in slowest {
+                println!("  {}: {:?}", name, duration);
+            }
+        }
+
+        let mut plugins: Vec<_> = stats.plugins.per_plugin.iter().collect();
+        plugins.sort_by_key(|(plugin_id, _)| plugin_id.as_str());
+        println!("Plugins: {}", stats.plugins.loaded.len());
+        println!("  (counters cover only this status check, not a running instance)");
+        for (plugin_id, plugin) in plugins {
+            let last_error = plugin
+                .last_error
+                .map_or_else(|| "never".to_string(), |at| at.to_rfc3339());
+            println!(
+                "  {}: {:?}, {} events, {} API requests, last error {}",
+                plugin_id, plugin.status, plugin.events_handled, plugin.api_requests, last_error
+            );
+        }
+
         app.shutdown().await?;
         Ok(())
     })
@@ -279,6 +423,28 @@ fn check_health() -> Result<()> {
         println!("========================");
         println!("Overall status: {:?}", health.status);
 
+        let mut managers: Vec<_> = health.managers.iter().collect();
+        managers.sort_by_key(|(name, _)| name.as_str());
+        for (name, status) in managers {
+            match health.reasons.get(name) {
+                Some(reason) => println!("  {}: {:?} ({})", name, status, reason),
+                None => println!("  {}: {:?}", name, status),
+            }
+        }
+
+        if let Some(serde_json::Value::Object(plugins)) = health.details.get("plugins") {
+            for (plugin_id, plugin_health) in plugins {
+                let healthy = plugin_health["is_healthy"].as_bool().unwrap_or(false);
+                match plugin_health["error_message"].as_str() {
+                    Some(error) if !healthy => {
+                        println!("  plugin {}: unhealthy ({})", plugin_id, error)
+                    }
+                    _ if !healthy => println!("  plugin {}: unhealthy", plugin_id),
+                    _ => println!("  plugin {}: healthy", plugin_id),
+                }
+            }
+        }
+
         // Exit with appropriate code based on health
         let exit_code = match health.status {
             qorzen_oxide::manager::HealthStatus::Healthy => 0,
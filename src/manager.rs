@@ -1,5 +1,6 @@
 // src/manager.rs - Enhanced manager system with plugin support
 
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
@@ -26,6 +27,33 @@ pub enum ManagerState {
     Maintenance,
 }
 
+impl ManagerState {
+    /// Whether a manager in this state may move to `next`.
+    ///
+    /// The lifecycle runs Created → Initializing → Running → ShuttingDown →
+    /// Shutdown. A running manager may pause or enter maintenance and come
+    /// back, any state may fail into `Error`, and a failed or shut down
+    /// manager may only start over from `Initializing`. Staying in the same
+    /// state is always allowed.
+    pub fn can_transition_to(&self, next: ManagerState) -> bool {
+        use ManagerState::*;
+
+        *self == next
+            || next == Error
+            || matches!(
+                (self, next),
+                (Created, Initializing | ShuttingDown)
+                    | (Initializing, Running | ShuttingDown)
+                    | (Running, Paused | Maintenance | ShuttingDown)
+                    | (Paused, Running | Maintenance | ShuttingDown)
+                    | (Maintenance, Running | Paused | ShuttingDown)
+                    | (ShuttingDown, Shutdown)
+                    | (Shutdown, Initializing)
+                    | (Error, Initializing | ShuttingDown)
+            )
+    }
+}
+
 impl fmt::Display for ManagerState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -49,6 +77,28 @@ pub enum HealthStatus {
     Unknown,
 }
 
+impl HealthStatus {
+    /// Rank for comparing statuses, higher is worse
+    fn severity(&self) -> u8 {
+        match self {
+            Self::Healthy => 0,
+            Self::Unknown => 1,
+            Self::Degraded => 2,
+            Self::Unhealthy => 3,
+        }
+    }
+
+    /// The most severe of `statuses`, or healthy if there are none.
+    ///
+    /// An unknown status outranks healthy but not degraded.
+    pub fn worst(statuses: impl IntoIterator<Item = HealthStatus>) -> HealthStatus {
+        statuses
+            .into_iter()
+            .max_by_key(HealthStatus::severity)
+            .unwrap_or(HealthStatus::Healthy)
+    }
+}
+
 impl fmt::Display for HealthStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -60,6 +110,9 @@ impl fmt::Display for HealthStatus {
     }
 }
 
+/// `ManagerStatus` metadata key a manager can set to explain its health
+pub const HEALTH_DETAIL_KEY: &str = "health_detail";
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlatformRequirements {
     pub requires_filesystem: bool,
@@ -211,6 +264,20 @@ pub trait Manager: PlatformSync + fmt::Debug {
         }
     }
 
+    /// Why the manager is not healthy, for operators.
+    ///
+    /// Defaults to the [`HEALTH_DETAIL_KEY`] status metadata, then the status
+    /// message.
+    async fn health_detail(&self) -> Option<String> {
+        let status = self.status().await;
+        status
+            .metadata
+            .get(HEALTH_DETAIL_KEY)
+            .and_then(|detail| detail.as_str())
+            .map(str::to_string)
+            .or(status.message)
+    }
+
     /// Pauses the manager
     async fn pause(&mut self) -> Result<()> {
         Err(Error::manager(
@@ -329,10 +396,25 @@ impl ManagedState {
         &self.name
     }
 
-    /// Sets the manager state
-    pub async fn set_state(&self, state: ManagerState) {
+    /// Sets the manager state, failing if the lifecycle does not allow it
+    pub async fn try_set_state(&self, state: ManagerState) -> Result<()> {
         let mut status = self.status.write().await;
+        if !status.state.can_transition_to(state) {
+            return Err(Error::manager(
+                &self.name,
+                ManagerOperation::Operation("set_state".to_string()),
+                format!("Illegal state transition {} -> {}", status.state, state),
+            ));
+        }
         status.update_state(state);
+        Ok(())
+    }
+
+    /// Sets the manager state; an illegal transition is logged and ignored
+    pub async fn set_state(&self, state: ManagerState) {
+        if let Err(e) = self.try_set_state(state).await {
+            tracing::warn!("{}: {}", self.name, e.message);
+        }
     }
 
     /// Sets the health status
@@ -384,6 +466,63 @@ impl fmt::Debug for ManagedState {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+type SharedManager = Arc<dyn Any + Send + Sync>;
+#[cfg(target_arch = "wasm32")]
+type SharedManager = Arc<dyn Any>;
+
+/// Managers shared by type, for code that has no handle threaded to it.
+///
+/// Each manager lives behind an `Arc<RwLock<T>>`; a handle from [`get`](Self::get)
+/// points at the same instance as the owner's, so it can be kept and locked
+/// from any task. Only one manager of each type is held.
+#[derive(Default)]
+pub struct SharedManagers {
+    managers: HashMap<TypeId, SharedManager>,
+}
+
+impl SharedManagers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Share `manager`, replacing any other of the same type, and return its handle
+    pub fn insert<T: Manager + 'static>(&mut self, manager: T) -> Arc<RwLock<T>> {
+        let shared = Arc::new(RwLock::new(manager));
+        self.managers
+            .insert(TypeId::of::<T>(), Arc::clone(&shared) as SharedManager);
+        shared
+    }
+
+    /// The shared manager of type `T`, if one was inserted
+    pub fn get<T: Manager + 'static>(&self) -> Option<Arc<RwLock<T>>> {
+        Arc::clone(self.managers.get(&TypeId::of::<T>())?)
+            .downcast::<RwLock<T>>()
+            .ok()
+    }
+
+    /// Stop sharing the manager of type `T`; outstanding handles stay valid
+    pub fn remove<T: Manager + 'static>(&mut self) -> bool {
+        self.managers.remove(&TypeId::of::<T>()).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.managers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.managers.is_empty()
+    }
+}
+
+impl fmt::Debug for SharedManagers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedManagers")
+            .field("count", &self.managers.len())
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,6 +544,33 @@ mod tests {
         }
     }
 
+    #[async_trait::async_trait]
+    impl Manager for TestManager {
+        fn name(&self) -> &str {
+            self.state.name()
+        }
+
+        fn id(&self) -> Uuid {
+            self.state.id()
+        }
+
+        async fn initialize(&mut self) -> Result<()> {
+            self.state.set_state(ManagerState::Initializing).await;
+            self.state.set_state(ManagerState::Running).await;
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<()> {
+            self.state.set_state(ManagerState::ShuttingDown).await;
+            self.state.set_state(ManagerState::Shutdown).await;
+            Ok(())
+        }
+
+        async fn status(&self) -> ManagerStatus {
+            self.state.status().await
+        }
+    }
+
     #[test]
     fn test_manager_status_creation() {
         let manager = TestManager::new("test_manager");
@@ -417,6 +583,7 @@ mod tests {
 
         assert_eq!(state.state().await, ManagerState::Created);
 
+        state.set_state(ManagerState::Initializing).await;
         state.set_state(ManagerState::Running).await;
         assert_eq!(state.state().await, ManagerState::Running);
 
@@ -427,4 +594,104 @@ mod tests {
         let status = state.status().await;
         assert_eq!(status.message, Some("Test message".to_string()));
     }
+
+    #[test]
+    fn test_state_transitions() {
+        use ManagerState::*;
+
+        let legal = [
+            (Created, Initializing),
+            (Initializing, Running),
+            (Running, Paused),
+            (Paused, Running),
+            (Running, Maintenance),
+            (Running, ShuttingDown),
+            (ShuttingDown, Shutdown),
+            (Shutdown, Initializing),
+            (Error, Initializing),
+            (Running, Running),
+        ];
+        for (from, to) in legal {
+            assert!(from.can_transition_to(to), "{} -> {}", from, to);
+        }
+        for from in [Created, Running, ShuttingDown, Shutdown] {
+            assert!(from.can_transition_to(Error));
+        }
+
+        let illegal = [
+            (Created, Running),
+            (Shutdown, Running),
+            (Shutdown, ShuttingDown),
+            (ShuttingDown, Running),
+            (Initializing, Shutdown),
+            (Error, Running),
+            (Paused, Shutdown),
+        ];
+        for (from, to) in illegal {
+            assert!(!from.can_transition_to(to), "{} -> {}", from, to);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_illegal_transition_is_refused() {
+        let state = ManagedState::new(Uuid::new_v4(), "strict");
+        assert!(state.try_set_state(ManagerState::Running).await.is_err());
+        assert_eq!(state.state().await, ManagerState::Created);
+
+        state.set_state(ManagerState::Initializing).await;
+        state.set_state(ManagerState::Running).await;
+        state.set_state(ManagerState::ShuttingDown).await;
+        state.set_state(ManagerState::Shutdown).await;
+
+        // Ignored rather than reviving the manager
+        state.set_state(ManagerState::Running).await;
+        assert_eq!(state.state().await, ManagerState::Shutdown);
+    }
+
+    #[tokio::test]
+    async fn test_health_detail_and_worst_status() {
+        use HealthStatus::*;
+
+        assert_eq!(HealthStatus::worst([]), Healthy);
+        assert_eq!(HealthStatus::worst([Healthy, Unknown]), Unknown);
+        assert_eq!(HealthStatus::worst([Unknown, Degraded, Healthy]), Degraded);
+        assert_eq!(HealthStatus::worst([Degraded, Unhealthy]), Unhealthy);
+
+        let manager = TestManager::new("detail");
+        assert_eq!(manager.health_detail().await, None);
+        manager.state.set_message("Cache is cold").await;
+        assert_eq!(
+            manager.health_detail().await.as_deref(),
+            Some("Cache is cold")
+        );
+        manager
+            .state
+            .add_metadata(HEALTH_DETAIL_KEY, "Disk 95% full".into())
+            .await;
+        assert_eq!(
+            manager.health_detail().await.as_deref(),
+            Some("Disk 95% full")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shared_managers_by_type() {
+        let mut shared = SharedManagers::new();
+        assert!(shared.get::<TestManager>().is_none());
+
+        let owned = shared.insert(TestManager::new("shared"));
+        let found = shared.get::<TestManager>().unwrap();
+        assert!(Arc::ptr_eq(&owned, &found));
+
+        // Both handles see the same manager
+        found.write().await.initialize().await.unwrap();
+        assert_eq!(
+            owned.read().await.current_state().await,
+            ManagerState::Running
+        );
+
+        assert!(shared.remove::<TestManager>());
+        assert!(shared.is_empty());
+        assert_eq!(found.read().await.name(), "shared");
+    }
 }
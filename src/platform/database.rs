@@ -1,9 +1,14 @@
 // src/platform/database.rs
 
-use crate::error::Result;
+use crate::config::DatabaseConfig;
+use crate::error::{Error, Result};
+use crate::manager::{HealthStatus, ManagedState, Manager, ManagerState, ManagerStatus};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use uuid::Uuid;
 
 /// Database query result
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,12 +46,27 @@ pub type DynDatabase = dyn DatabaseProvider + Sync;
 pub type DatabaseArc = Arc<DynDatabase>;
 
 /// Database operations - made dyn compatible by removing generic transaction method
+///
+/// Providers report a dropped connection with [`Error::database_unavailable`]
+/// so a [`DatabaseManager`] knows to reconnect.
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 pub trait DatabaseProvider: DatabaseBounds + std::fmt::Debug {
     async fn execute(&self, query: &str, params: &[serde_json::Value]) -> Result<QueryResult>;
     async fn query(&self, query: &str, params: &[serde_json::Value]) -> Result<Vec<Row>>;
     async fn migrate(&self, migrations: &[Migration]) -> Result<()>;
+
+    /// Number of tables whose names start with `prefix`, or `None` when the
+    /// backend can't tell
+    async fn table_count(&self, _prefix: &str) -> Result<Option<u64>> {
+        Ok(None)
+    }
+
+    /// Estimated bytes used by tables whose names start with `prefix`, or
+    /// `None` when the backend can't tell
+    async fn storage_used(&self, _prefix: &str) -> Result<Option<u64>> {
+        Ok(None)
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -54,3 +74,524 @@ pub trait DatabaseBounds: Send + Sync + std::fmt::Debug {}
 
 #[cfg(target_arch = "wasm32")]
 pub trait DatabaseBounds: Sync {}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub type ConnectorArc = Arc<dyn DatabaseConnector + Send + Sync>;
+
+#[cfg(target_arch = "wasm32")]
+pub type ConnectorArc = Arc<dyn DatabaseConnector + Sync>;
+
+/// Establishes the connection pool a [`DatabaseManager`] runs queries on
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+pub trait DatabaseConnector: DatabaseBounds {
+    async fn connect(&self) -> Result<DatabaseArc>;
+}
+
+/// Backoff between attempts to re-establish a dropped connection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReconnectPolicy {
+    /// Delay after the first failed attempt; doubled after each further one
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between attempts
+    pub max_backoff: Duration,
+    /// Attempts before the database is reported unhealthy; zero retries forever
+    pub max_attempts: u32,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self::from(&DatabaseConfig::default())
+    }
+}
+
+impl From<&DatabaseConfig> for ReconnectPolicy {
+    fn from(config: &DatabaseConfig) -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(config.reconnect_initial_backoff_ms),
+            max_backoff: Duration::from_millis(config.reconnect_max_backoff_ms),
+            max_attempts: config.reconnect_max_attempts,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Delay after failed attempt number `attempt`, counting from one
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Connection details a [`DatabaseManager`] adds to its status
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionState {
+    pub connected: bool,
+    pub reconnecting: bool,
+    /// Attempts made by the current or last reconnect
+    pub attempts: u32,
+    pub last_error: Option<String>,
+}
+
+#[derive(Default)]
+struct SharedConnection {
+    pool: Mutex<Option<DatabaseArc>>,
+    connection: Mutex<ConnectionState>,
+    active: AtomicBool,
+}
+
+impl SharedConnection {
+    fn pool(&self) -> Option<DatabaseArc> {
+        self.pool.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn set_pool(&self, pool: Option<DatabaseArc>) {
+        *self.pool.lock().unwrap_or_else(|e| e.into_inner()) = pool;
+    }
+
+    fn connection(&self) -> std::sync::MutexGuard<'_, ConnectionState> {
+        self.connection.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Owns the database connection and recovers it after an outage.
+///
+/// When a query reports the connection lost, the manager turns `Degraded`
+/// and re-establishes the pool in the background, backing off per its
+/// [`ReconnectPolicy`]. Queries made meanwhile fail straight away with a
+/// retryable [`Error::database_unavailable`] instead of waiting on the
+/// outage. Once connected again it is `Healthy`; after `max_attempts`
+/// failures it gives up and is `Unhealthy` until the next query tries again.
+#[derive(Clone)]
+pub struct DatabaseManager {
+    state: Arc<ManagedState>,
+    connector: ConnectorArc,
+    policy: ReconnectPolicy,
+    shared: Arc<SharedConnection>,
+}
+
+impl std::fmt::Debug for DatabaseManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabaseManager")
+            .field("policy", &self.policy)
+            .field("connection", &self.connection_state())
+            .finish()
+    }
+}
+
+impl DatabaseManager {
+    pub fn new(connector: ConnectorArc, policy: ReconnectPolicy) -> Self {
+        Self {
+            state: Arc::new(ManagedState::new(
+                crate::utils::rand::uuid(),
+                "database_manager",
+            )),
+            connector,
+            policy,
+            shared: Arc::new(SharedConnection::default()),
+        }
+    }
+
+    /// Provider running queries on this manager's connection, for components
+    /// that only need a [`DatabaseProvider`]
+    pub fn provider(&self) -> DatabaseArc {
+        Arc::new(self.clone())
+    }
+
+    /// Current connection details
+    pub fn connection_state(&self) -> ConnectionState {
+        self.shared.connection().clone()
+    }
+
+    /// The live pool, or a retryable error while the connection is down
+    fn pool(&self) -> Result<DatabaseArc> {
+        if let Some(pool) = self.shared.pool() {
+            return Ok(pool);
+        }
+        if !self.shared.active.load(Ordering::SeqCst) {
+            return Err(Error::database("Database manager is not running"));
+        }
+        // A reconnect that gave up is started again by the next query
+        self.start_reconnect(None);
+        Err(Error::database_unavailable(
+            "Database connection is down; reconnecting",
+        ))
+    }
+
+    /// Pass a query result through, reconnecting if it reports a lost connection
+    fn observe<T>(&self, pool: &DatabaseArc, result: Result<T>) -> Result<T> {
+        if let Err(e) = &result {
+            if e.is_database_unavailable() {
+                self.start_reconnect(Some((pool, e)));
+            }
+        }
+        result
+    }
+
+    /// Drop the failed pool and reconnect in the background, once
+    fn start_reconnect(&self, failure: Option<(&DatabaseArc, &Error)>) {
+        {
+            let mut connection = self.shared.connection();
+            if connection.reconnecting || !self.shared.active.load(Ordering::SeqCst) {
+                return;
+            }
+            if let Some((failed, error)) = failure {
+                let mut pool = self.shared.pool.lock().unwrap_or_else(|e| e.into_inner());
+                // Another query may already have replaced the pool
+                if !pool.as_ref().is_some_and(|p| Arc::ptr_eq(p, failed)) {
+                    return;
+                }
+                *pool = None;
+                connection.last_error = Some(error.message.clone());
+                tracing::warn!("Database connection lost: {}", error.message);
+            }
+            connection.connected = false;
+            connection.reconnecting = true;
+            connection.attempts = 0;
+        }
+
+        let run = reconnect(
+            Arc::clone(&self.state),
+            Arc::clone(&self.connector),
+            self.policy,
+            Arc::clone(&self.shared),
+        );
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::spawn(run);
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(run);
+    }
+}
+
+/// Retry `connector` with backoff until it connects, gives up or the manager stops
+async fn reconnect(
+    state: Arc<ManagedState>,
+    connector: ConnectorArc,
+    policy: ReconnectPolicy,
+    shared: Arc<SharedConnection>,
+) {
+    state.set_health(HealthStatus::Degraded).await;
+    state
+        .set_message("Database connection lost; reconnecting")
+        .await;
+
+    let mut attempt = 0;
+    loop {
+        if !shared.active.load(Ordering::SeqCst) {
+            return;
+        }
+        attempt += 1;
+
+        match connector.connect().await {
+            Ok(pool) => {
+                if !shared.active.load(Ordering::SeqCst) {
+                    return;
+                }
+                shared.set_pool(Some(pool));
+                {
+                    let mut connection = shared.connection();
+                    connection.connected = true;
+                    connection.reconnecting = false;
+                    connection.attempts = attempt;
+                    connection.last_error = None;
+                }
+                tracing::info!("Database connection restored after {} attempt(s)", attempt);
+                state.set_health(HealthStatus::Healthy).await;
+                state.set_message("Database connection restored").await;
+                return;
+            }
+            Err(e) => {
+                {
+                    let mut connection = shared.connection();
+                    connection.attempts = attempt;
+                    connection.last_error = Some(e.message.clone());
+                }
+                if policy.max_attempts > 0 && attempt >= policy.max_attempts {
+                    shared.connection().reconnecting = false;
+                    tracing::error!(
+                        "Giving up reconnecting to the database after {} attempt(s): {}",
+                        attempt,
+                        e
+                    );
+                    state.set_health(HealthStatus::Unhealthy).await;
+                    state
+                        .set_message(format!("Database unreachable: {}", e.message))
+                        .await;
+                    return;
+                }
+
+                let backoff = policy.backoff(attempt);
+                tracing::warn!(
+                    "Database reconnect attempt {} failed, retrying in {:?}: {}",
+                    attempt,
+                    backoff,
+                    e
+                );
+                #[cfg(not(target_arch = "wasm32"))]
+                tokio::time::sleep(backoff).await;
+                #[cfg(target_arch = "wasm32")]
+                gloo_timers::future::sleep(backoff).await;
+            }
+        }
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl Manager for DatabaseManager {
+    fn name(&self) -> &str {
+        "database_manager"
+    }
+
+    fn id(&self) -> Uuid {
+        self.state.id()
+    }
+
+    async fn initialize(&mut self) -> Result<()> {
+        self.state.set_state(ManagerState::Initializing).await;
+
+        let pool = match self.connector.connect().await {
+            Ok(pool) => pool,
+            Err(e) => {
+                self.shared.connection().last_error = Some(e.message.clone());
+                self.state.set_state(ManagerState::Error).await;
+                self.state.set_health(HealthStatus::Unhealthy).await;
+                return Err(e);
+            }
+        };
+        self.shared.set_pool(Some(pool));
+        *self.shared.connection() = ConnectionState {
+            connected: true,
+            attempts: 1,
+            ..ConnectionState::default()
+        };
+        self.shared.active.store(true, Ordering::SeqCst);
+
+        self.state.set_state(ManagerState::Running).await;
+        self.state.set_health(HealthStatus::Healthy).await;
+        Ok(())
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        self.state.set_state(ManagerState::ShuttingDown).await;
+        self.shared.active.store(false, Ordering::SeqCst);
+        self.shared.set_pool(None);
+        *self.shared.connection() = ConnectionState::default();
+        self.state.set_state(ManagerState::Shutdown).await;
+        Ok(())
+    }
+
+    async fn status(&self) -> ManagerStatus {
+        let mut status = self.state.status().await;
+        status.add_metadata(
+            "connection",
+            serde_json::to_value(self.connection_state()).unwrap_or_default(),
+        );
+        status
+    }
+
+    async fn health_check(&self) -> HealthStatus {
+        match self.state.state().await {
+            ManagerState::Running => {
+                let connection = self.connection_state();
+                if connection.connected {
+                    HealthStatus::Healthy
+                } else if connection.reconnecting {
+                    HealthStatus::Degraded
+                } else {
+                    HealthStatus::Unhealthy
+                }
+            }
+            ManagerState::Error => HealthStatus::Unhealthy,
+            _ => HealthStatus::Unknown,
+        }
+    }
+
+    async fn health_detail(&self) -> Option<String> {
+        let connection = self.connection_state();
+        if connection.connected {
+            return None;
+        }
+        let cause = connection
+            .last_error
+            .map_or(String::new(), |error| format!(": {}", error));
+        Some(if connection.reconnecting {
+            format!(
+                "Connection lost, reconnect attempt {}{}",
+                connection.attempts, cause
+            )
+        } else {
+            format!("Not connected{}", cause)
+        })
+    }
+
+    fn is_essential(&self) -> bool {
+        true
+    }
+}
+
+impl DatabaseBounds for DatabaseManager {}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+impl DatabaseProvider for DatabaseManager {
+    async fn execute(&self, query: &str, params: &[serde_json::Value]) -> Result<QueryResult> {
+        let pool = self.pool()?;
+        let result = pool.execute(query, params).await;
+        self.observe(&pool, result)
+    }
+
+    async fn query(&self, query: &str, params: &[serde_json::Value]) -> Result<Vec<Row>> {
+        let pool = self.pool()?;
+        let result = pool.query(query, params).await;
+        self.observe(&pool, result)
+    }
+
+    async fn migrate(&self, migrations: &[Migration]) -> Result<()> {
+        let pool = self.pool()?;
+        let result = pool.migrate(migrations).await;
+        self.observe(&pool, result)
+    }
+
+    async fn table_count(&self, prefix: &str) -> Result<Option<u64>> {
+        let pool = self.pool()?;
+        let result = pool.table_count(prefix).await;
+        self.observe(&pool, result)
+    }
+
+    async fn storage_used(&self, prefix: &str) -> Result<Option<u64>> {
+        let pool = self.pool()?;
+        let result = pool.storage_used(prefix).await;
+        self.observe(&pool, result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+
+    /// Database whose connection drops while `up` is false
+    #[derive(Debug)]
+    struct FlakyDatabase {
+        up: Arc<AtomicBool>,
+    }
+
+    impl DatabaseBounds for FlakyDatabase {}
+
+    #[async_trait::async_trait]
+    impl DatabaseProvider for FlakyDatabase {
+        async fn execute(
+            &self,
+            _query: &str,
+            _params: &[serde_json::Value],
+        ) -> Result<QueryResult> {
+            if !self.up.load(Ordering::SeqCst) {
+                return Err(Error::database_unavailable("connection reset by peer"));
+            }
+            Ok(QueryResult {
+                rows_affected: 1,
+                last_insert_id: None,
+            })
+        }
+
+        async fn query(&self, _query: &str, _params: &[serde_json::Value]) -> Result<Vec<Row>> {
+            Ok(Vec::new())
+        }
+
+        async fn migrate(&self, _migrations: &[Migration]) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct FlakyConnector {
+        up: Arc<AtomicBool>,
+        connects: AtomicU32,
+    }
+
+    impl DatabaseBounds for FlakyConnector {}
+
+    #[async_trait::async_trait]
+    impl DatabaseConnector for FlakyConnector {
+        async fn connect(&self) -> Result<DatabaseArc> {
+            self.connects.fetch_add(1, Ordering::SeqCst);
+            if !self.up.load(Ordering::SeqCst) {
+                return Err(Error::database_unavailable("connection refused"));
+            }
+            Ok(Arc::new(FlakyDatabase {
+                up: Arc::clone(&self.up),
+            }))
+        }
+    }
+
+    #[test]
+    fn test_reconnect_backoff_is_capped() {
+        let policy = ReconnectPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            max_attempts: 0,
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(40), Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_manager_recovers_dropped_connection() {
+        let up = Arc::new(AtomicBool::new(true));
+        let connector = Arc::new(FlakyConnector {
+            up: Arc::clone(&up),
+            connects: AtomicU32::new(0),
+        });
+        let mut manager = DatabaseManager::new(
+            connector.clone(),
+            ReconnectPolicy {
+                initial_backoff: Duration::from_millis(10),
+                max_backoff: Duration::from_millis(20),
+                max_attempts: 0,
+            },
+        );
+        manager.initialize().await.unwrap();
+        assert!(manager.execute("UPDATE stock", &[]).await.is_ok());
+        assert_eq!(manager.health_check().await, HealthStatus::Healthy);
+
+        // The connection drops mid-query
+        up.store(false, Ordering::SeqCst);
+        let error = manager.execute("UPDATE stock", &[]).await.unwrap_err();
+        assert!(error.is_retryable());
+        assert_eq!(manager.health_check().await, HealthStatus::Degraded);
+
+        // Queries during the outage fail fast without touching the pool
+        let error = manager.query("SELECT 1", &[]).await.unwrap_err();
+        assert!(error.is_database_unavailable());
+
+        // Wait for a few failed reconnects, then bring the database back
+        while connector.connects.load(Ordering::SeqCst) < 3 {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        up.store(true, Ordering::SeqCst);
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while manager.health_check().await != HealthStatus::Healthy {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("manager should reconnect");
+
+        assert!(manager.execute("UPDATE stock", &[]).await.is_ok());
+        let status = manager.status().await;
+        assert_eq!(status.health, HealthStatus::Healthy);
+        assert_eq!(status.metadata["connection"]["connected"], true);
+        assert!(manager.connection_state().attempts >= 3);
+
+        // Providers handed out share the manager's connection and lifetime
+        let provider = manager.provider();
+        assert!(provider.execute("UPDATE stock", &[]).await.is_ok());
+        manager.shutdown().await.unwrap();
+        assert!(provider.execute("UPDATE stock", &[]).await.is_err());
+    }
+}
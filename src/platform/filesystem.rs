@@ -45,6 +45,12 @@ pub trait FileSystemProvider: FileSystemBounds + std::fmt::Debug {
     async fn create_directory(&self, path: &str) -> Result<()>;
     async fn file_exists(&self, path: &str) -> bool;
     async fn get_metadata(&self, path: &str) -> Result<FileMetadata>;
+
+    /// Where `path` lives on the local disk, for providers backed by one, so
+    /// sandboxes can check where symlinks lead
+    fn local_path(&self, _path: &str) -> Option<std::path::PathBuf> {
+        None
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -26,10 +26,22 @@ use crate::platform::filesystem::FileSystemArc;
 use crate::platform::network::NetworkArc;
 use crate::platform::storage::StorageArc;
 use crate::Error;
-pub use database::{DatabaseProvider, Migration, QueryResult, Row, Transaction};
+pub use database::{
+    ConnectionState, DatabaseConnector, DatabaseManager, DatabaseProvider, Migration, QueryResult,
+    ReconnectPolicy, Row, Transaction,
+};
 pub use filesystem::{FileInfo, FileMetadata, FileSystemProvider};
 pub use network::{NetworkProvider, NetworkRequest, NetworkResponse};
-pub use storage::StorageProvider;
+pub use storage::{MemoryStorage, StorageProvider};
+
+/// Persistent key-value storage of the current platform, usable without a `PlatformManager`
+pub fn default_storage() -> Result<StorageArc> {
+    #[cfg(not(target_arch = "wasm32"))]
+    return Ok(Arc::new(native::NativeStorage::new()?));
+
+    #[cfg(target_arch = "wasm32")]
+    return Ok(Arc::new(web::WebStorage::new()?));
+}
 
 /// Platform capabilities detection
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,7 +107,7 @@ impl PlatformManager {
         let providers = web::create_providers()?;
 
         Ok(Self {
-            state: ManagedState::new(Uuid::new_v4(), "platform_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "platform_manager"),
             filesystem: providers.filesystem,
             database: providers.database,
             network: providers.network,
@@ -143,6 +155,9 @@ impl PlatformManager {
     pub fn storage(&self) -> &dyn StorageProvider {
         self.storage.as_ref()
     }
+    pub fn storage_arc(&self) -> StorageArc {
+        Arc::clone(&self.storage)
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
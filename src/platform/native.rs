@@ -2,6 +2,7 @@
 
 use async_trait::async_trait;
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs;
 
@@ -110,6 +111,10 @@ impl filesystem::FileSystemBounds for NativeFileSystem {}
 
 #[async_trait]
 impl FileSystemProvider for NativeFileSystem {
+    fn local_path(&self, path: &str) -> Option<std::path::PathBuf> {
+        Some(self.resolve_path(path))
+    }
+
     async fn read_file(&self, path: &str) -> Result<Vec<u8>> {
         let full_path = self.resolve_path(path);
         fs::read(&full_path).await.map_err(|e| {
@@ -237,10 +242,12 @@ impl FileSystemProvider for NativeFileSystem {
 }
 
 /// SQLite database implementation
+///
+/// Queries run on the blocking thread pool against a single connection.
 #[derive(Debug)]
 pub struct SqliteDatabase {
-    // Database connection would be here
-    _db_path: std::path::PathBuf,
+    path: PathBuf,
+    connection: Arc<std::sync::Mutex<rusqlite::Connection>>,
 }
 
 impl SqliteDatabase {
@@ -250,33 +257,256 @@ impl SqliteDatabase {
             .join("qorzen")
             .join("app.db");
 
-        Ok(Self { _db_path: db_path })
+        Self::open(db_path)
+    }
+
+    /// Opens the database at `path`, creating it and its directory if missing
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                Error::database_unavailable(format!(
+                    "Failed to create database directory {}: {}",
+                    parent.display(),
+                    e
+                ))
+            })?;
+        }
+        let connection = rusqlite::Connection::open(&path).map_err(sqlite_error)?;
+
+        Ok(Self {
+            path,
+            connection: Arc::new(std::sync::Mutex::new(connection)),
+        })
+    }
+
+    /// Path of the database file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Runs `work` on the connection without blocking the async runtime
+    async fn with_connection<T, F>(&self, work: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut rusqlite::Connection) -> rusqlite::Result<T> + Send + 'static,
+    {
+        let connection = Arc::clone(&self.connection);
+        tokio::task::spawn_blocking(move || {
+            let mut connection = connection.lock().unwrap_or_else(|e| e.into_inner());
+            work(&mut connection)
+        })
+        .await
+        .map_err(|e| Error::database(format!("Database task failed: {}", e)))?
+        .map_err(sqlite_error)
     }
 }
 
 impl DatabaseBounds for SqliteDatabase {}
 
+/// Opens the SQLite database for a [`DatabaseManager`]
+#[derive(Debug, Clone)]
+pub struct SqliteConnector {
+    path: PathBuf,
+}
+
+impl SqliteConnector {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Connector for a `sqlite://<path>` URL such as `DatabaseConfig::url`
+    pub fn from_url(url: &str) -> Result<Self> {
+        url.strip_prefix("sqlite://")
+            .filter(|path| !path.is_empty())
+            .map(Self::new)
+            .ok_or_else(|| {
+                Error::config(format!(
+                    "Unsupported database URL '{}': expected sqlite://<path>",
+                    url
+                ))
+            })
+    }
+}
+
+impl DatabaseBounds for SqliteConnector {}
+
+#[async_trait]
+impl DatabaseConnector for SqliteConnector {
+    async fn connect(&self) -> Result<database::DatabaseArc> {
+        let path = self.path.clone();
+        let database = tokio::task::spawn_blocking(move || SqliteDatabase::open(path))
+            .await
+            .map_err(|e| Error::database(format!("Database task failed: {}", e)))??;
+        Ok(Arc::new(database))
+    }
+}
+
 #[async_trait]
 impl DatabaseProvider for SqliteDatabase {
-    async fn execute(&self, _query: &str, _params: &[serde_json::Value]) -> Result<QueryResult> {
-        // Implementation would use SQLite
-        Ok(QueryResult {
-            rows_affected: 0,
-            last_insert_id: None,
+    async fn execute(&self, query: &str, params: &[serde_json::Value]) -> Result<QueryResult> {
+        let query = query.to_string();
+        let params = sqlite_params(params);
+        self.with_connection(move |connection| {
+            let rows_affected =
+                connection.execute(&query, rusqlite::params_from_iter(params.iter()))?;
+            Ok(QueryResult {
+                rows_affected: rows_affected as u64,
+                last_insert_id: (rows_affected > 0).then(|| connection.last_insert_rowid()),
+            })
         })
+        .await
     }
 
-    async fn query(&self, _query: &str, _params: &[serde_json::Value]) -> Result<Vec<Row>> {
-        // Implementation would use SQLite
-        Ok(Vec::new())
+    async fn query(&self, query: &str, params: &[serde_json::Value]) -> Result<Vec<Row>> {
+        let query = query.to_string();
+        let params = sqlite_params(params);
+        self.with_connection(move |connection| {
+            let mut statement = connection.prepare(&query)?;
+            let names: Vec<String> = statement
+                .column_names()
+                .into_iter()
+                .map(String::from)
+                .collect();
+            let mut rows = statement.query(rusqlite::params_from_iter(params.iter()))?;
+            let mut result = Vec::new();
+            while let Some(row) = rows.next()? {
+                let mut columns = HashMap::with_capacity(names.len());
+                for (index, name) in names.iter().enumerate() {
+                    columns.insert(name.clone(), sqlite_json(row.get_ref(index)?));
+                }
+                result.push(Row { columns });
+            }
+            Ok(result)
+        })
+        .await
     }
 
-    async fn migrate(&self, _migrations: &[Migration]) -> Result<()> {
-        // Implementation would apply migrations
-        Ok(())
+    async fn migrate(&self, migrations: &[Migration]) -> Result<()> {
+        let mut migrations = migrations.to_vec();
+        migrations.sort_by_key(|migration| migration.version);
+        self.with_connection(move |connection| {
+            connection.execute_batch(
+                "CREATE TABLE IF NOT EXISTS _migrations (\
+                     version INTEGER PRIMARY KEY, \
+                     description TEXT NOT NULL, \
+                     applied_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP)",
+            )?;
+            for migration in migrations {
+                let applied: bool = connection.query_row(
+                    "SELECT EXISTS(SELECT 1 FROM _migrations WHERE version = ?1)",
+                    [migration.version],
+                    |row| row.get(0),
+                )?;
+                if applied {
+                    continue;
+                }
+                let transaction = connection.transaction()?;
+                transaction.execute_batch(&migration.up_sql)?;
+                transaction.execute(
+                    "INSERT INTO _migrations (version, description) VALUES (?1, ?2)",
+                    rusqlite::params![migration.version, migration.description],
+                )?;
+                transaction.commit()?;
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn table_count(&self, prefix: &str) -> Result<Option<u64>> {
+        let rows = self
+            .query(
+                "SELECT COUNT(*) AS count FROM sqlite_master \
+                 WHERE type = 'table' AND name LIKE ? ESCAPE '\\'",
+                &[sqlite_prefix_pattern(prefix)],
+            )
+            .await?;
+        Ok(Some(first_u64(&rows, "count")))
+    }
+
+    async fn storage_used(&self, prefix: &str) -> Result<Option<u64>> {
+        let rows = self
+            .query(
+                "SELECT COALESCE(SUM(pgsize), 0) AS bytes FROM dbstat \
+                 WHERE name LIKE ? ESCAPE '\\'",
+                &[sqlite_prefix_pattern(prefix)],
+            )
+            .await;
+        match rows {
+            Ok(rows) => Ok(Some(first_u64(&rows, "bytes"))),
+            // SQLite builds without the dbstat table can't measure usage
+            Err(e) if e.message.contains("no such table: dbstat") => Ok(None),
+            Err(e) => Err(e),
+        }
     }
 }
 
+/// Maps a SQLite error, flagging the ones that mean the file is unreachable
+fn sqlite_error(error: rusqlite::Error) -> Error {
+    use rusqlite::ErrorCode;
+
+    let unavailable = matches!(
+        error.sqlite_error_code(),
+        Some(ErrorCode::CannotOpen | ErrorCode::SystemIoFailure | ErrorCode::NotADatabase)
+    );
+    let message = format!("SQLite error: {}", error);
+    if unavailable {
+        Error::database_unavailable(message)
+    } else {
+        Error::database(message)
+    }
+}
+
+/// Converts JSON parameters to SQLite values; arrays and objects bind as JSON text
+fn sqlite_params(params: &[serde_json::Value]) -> Vec<rusqlite::types::Value> {
+    use rusqlite::types::Value;
+
+    params
+        .iter()
+        .map(|param| match param {
+            serde_json::Value::Null => Value::Null,
+            serde_json::Value::Bool(b) => Value::Integer(i64::from(*b)),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Value::Integer(i),
+                None => Value::Real(n.as_f64().unwrap_or_default()),
+            },
+            serde_json::Value::String(s) => Value::Text(s.clone()),
+            other => Value::Text(other.to_string()),
+        })
+        .collect()
+}
+
+/// Converts a SQLite column value to JSON; blobs become byte arrays
+fn sqlite_json(value: rusqlite::types::ValueRef<'_>) -> serde_json::Value {
+    use rusqlite::types::ValueRef;
+
+    match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => serde_json::Value::from(i),
+        ValueRef::Real(f) => serde_json::Value::from(f),
+        ValueRef::Text(text) => serde_json::Value::from(String::from_utf8_lossy(text)),
+        ValueRef::Blob(bytes) => serde_json::Value::from(bytes.to_vec()),
+    }
+}
+
+/// `LIKE` pattern matching names that start with `prefix`
+fn sqlite_prefix_pattern(prefix: &str) -> serde_json::Value {
+    let escaped = prefix
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    serde_json::Value::String(format!("{}%", escaped))
+}
+
+/// First row's `column` as an integer, zero when absent
+fn first_u64(rows: &[Row], column: &str) -> u64 {
+    rows.first()
+        .and_then(|row| row.columns.get(column))
+        .and_then(|value| value.as_u64())
+        .unwrap_or(0)
+}
+
 /// Native network implementation
 pub struct NativeNetwork {
     client: reqwest::Client,
@@ -381,7 +611,7 @@ pub struct NativeStorage {
 }
 
 impl NativeStorage {
-    fn new() -> Result<Self> {
+    pub fn new() -> Result<Self> {
         let storage_path = dirs::data_dir()
             .unwrap_or_else(|| std::env::current_dir().unwrap_or_default().join("data"))
             .join("qorzen")
@@ -497,3 +727,59 @@ impl StorageProvider for NativeStorage {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sqlite_connector_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!(
+            "sqlite://{}",
+            dir.path().join("data").join("app.db").display()
+        );
+        let database = SqliteConnector::from_url(&url)
+            .unwrap()
+            .connect()
+            .await
+            .unwrap();
+
+        let migration = Migration {
+            version: 1,
+            description: "create parts".to_string(),
+            up_sql: "CREATE TABLE plugin_inventory_parts (id INTEGER PRIMARY KEY, name TEXT)"
+                .to_string(),
+            down_sql: "DROP TABLE plugin_inventory_parts".to_string(),
+        };
+        database.migrate(&[migration.clone()]).await.unwrap();
+        // Applied migrations are skipped on the next run
+        database.migrate(&[migration]).await.unwrap();
+
+        let inserted = database
+            .execute(
+                "INSERT INTO plugin_inventory_parts (name) VALUES (?)",
+                &[serde_json::json!("bolt")],
+            )
+            .await
+            .unwrap();
+        assert_eq!(inserted.rows_affected, 1);
+        assert_eq!(inserted.last_insert_id, Some(1));
+
+        let rows = database
+            .query(
+                "SELECT id, name FROM plugin_inventory_parts WHERE name = ?",
+                &[serde_json::json!("bolt")],
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].columns["name"], "bolt");
+        assert_eq!(
+            database.table_count("plugin_inventory_").await.unwrap(),
+            Some(1)
+        );
+
+        assert!(SqliteConnector::from_url("postgres://localhost/app").is_err());
+    }
+}
@@ -0,0 +1,236 @@
+// src/plugin/assets.rs - Static asset serving for plugins
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, FileOperation, Result};
+use crate::platform::filesystem::FileSystemArc;
+
+/// URL prefix under which plugin assets are served
+pub const PLUGIN_ASSET_PREFIX: &str = "/plugins/";
+
+/// Directory inside a plugin sandbox that is exposed publicly
+pub const PLUGIN_ASSET_DIR: &str = "assets";
+
+/// A static file served on behalf of a plugin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginAsset {
+    pub plugin_id: String,
+    pub path: String,
+    pub content_type: String,
+    pub cache_control: String,
+    pub body: Vec<u8>,
+}
+
+/// Serves files from `plugins/<id>/assets/` at `/plugins/<id>/assets/...`
+#[derive(Debug, Clone)]
+pub struct PluginAssetServer {
+    provider: FileSystemArc,
+    max_age_secs: u64,
+}
+
+impl PluginAssetServer {
+    /// Create an asset server backed by the platform file system
+    pub fn new(provider: FileSystemArc) -> Self {
+        Self {
+            provider,
+            max_age_secs: 3600,
+        }
+    }
+
+    /// Set the `max-age` advertised in the `Cache-Control` header
+    pub fn with_max_age(mut self, max_age_secs: u64) -> Self {
+        self.max_age_secs = max_age_secs;
+        self
+    }
+
+    /// Build the public URL of a plugin asset.
+    ///
+    /// On WASM the bundle serves the same layout statically, so this URL can be
+    /// used directly in `src` attributes.
+    pub fn asset_url(plugin_id: &str, asset_path: &str) -> Result<String> {
+        validate_plugin_id(plugin_id)?;
+        let asset_path = sanitize_asset_path(asset_path)?;
+        Ok(format!(
+            "{}{}/{}/{}",
+            PLUGIN_ASSET_PREFIX, plugin_id, PLUGIN_ASSET_DIR, asset_path
+        ))
+    }
+
+    /// Split a request path into the plugin ID and the sanitized asset path
+    pub fn parse_request_path(request_path: &str) -> Result<(String, String)> {
+        let path = request_path.split(['?', '#']).next().unwrap_or_default();
+        let rest = path
+            .strip_prefix(PLUGIN_ASSET_PREFIX)
+            .ok_or_else(|| invalid_asset_path(request_path))?;
+
+        let (plugin_id, rest) = rest
+            .split_once('/')
+            .ok_or_else(|| invalid_asset_path(request_path))?;
+        let asset_path = rest
+            .strip_prefix(PLUGIN_ASSET_DIR)
+            .and_then(|p| p.strip_prefix('/'))
+            .ok_or_else(|| invalid_asset_path(request_path))?;
+
+        validate_plugin_id(plugin_id)?;
+        let asset_path = sanitize_asset_path(asset_path)?;
+
+        Ok((plugin_id.to_string(), asset_path))
+    }
+
+    /// Resolve and read the asset addressed by a request path
+    pub async fn serve(&self, request_path: &str) -> Result<PluginAsset> {
+        let (plugin_id, asset_path) = Self::parse_request_path(request_path)?;
+        let sandbox_root = format!("plugins/{}/{}/", plugin_id, PLUGIN_ASSET_DIR);
+        let sandbox_path = format!("{}{}", sandbox_root, asset_path);
+        if !super::stays_on_disk_within(&self.provider, &sandbox_root, &sandbox_path) {
+            return Err(invalid_asset_path(request_path));
+        }
+
+        if !self.provider.file_exists(&sandbox_path).await {
+            return Err(Error::file(
+                sandbox_path,
+                FileOperation::Read,
+                "Plugin asset not found",
+            ));
+        }
+
+        let body = self.provider.read_file(&sandbox_path).await?;
+
+        Ok(PluginAsset {
+            content_type: content_type_for(&asset_path).to_string(),
+            cache_control: format!("public, max-age={}", self.max_age_secs),
+            plugin_id,
+            path: asset_path,
+            body,
+        })
+    }
+}
+
+/// Guess the MIME type of an asset from its extension
+pub fn content_type_for(path: &str) -> &'static str {
+    let extension = path
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "ico" => "image/x-icon",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" => "text/javascript; charset=utf-8",
+        "json" => "application/json",
+        "html" | "htm" => "text/html; charset=utf-8",
+        "txt" => "text/plain; charset=utf-8",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        "ttf" => "font/ttf",
+        "wasm" => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+fn validate_plugin_id(plugin_id: &str) -> Result<()> {
+    let valid = !plugin_id.is_empty()
+        && plugin_id != "."
+        && plugin_id != ".."
+        && plugin_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::permission(
+            "plugin.assets",
+            format!("Invalid plugin ID in asset path: {}", plugin_id),
+        ))
+    }
+}
+
+/// Normalize an asset path, rejecting anything that could leave the sandbox
+fn sanitize_asset_path(path: &str) -> Result<String> {
+    super::normalize_sandbox_path(path).ok_or_else(|| invalid_asset_path(path))
+}
+
+fn invalid_asset_path(path: &str) -> Error {
+    Error::permission(
+        "plugin.assets",
+        format!("Invalid plugin asset path: {}", path),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::platform::filesystem::FileSystemProvider;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parse_request_path() {
+        let (plugin_id, path) =
+            PluginAssetServer::parse_request_path("/plugins/product_catalog/assets/img/a.png?v=2")
+                .unwrap();
+        assert_eq!(plugin_id, "product_catalog");
+        assert_eq!(path, "img/a.png");
+        assert_eq!(content_type_for(&path), "image/png");
+    }
+
+    #[test]
+    fn test_path_traversal_rejected() {
+        for request in [
+            "/plugins/product_catalog/assets/../../secrets.json",
+            "/plugins/product_catalog/assets/img/../../../etc/passwd",
+            "/plugins/product_catalog/assets/%2e%2e/config.yaml",
+            "/plugins/product_catalog/assets/..\\config.yaml",
+            "/plugins/../assets/config.yaml",
+            "/plugins/product_catalog/data/db.sqlite",
+        ] {
+            let err = PluginAssetServer::parse_request_path(request).unwrap_err();
+            assert!(
+                matches!(err.kind, ErrorKind::Permission { .. }),
+                "{} should be rejected",
+                request
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_serve_asset() {
+        let fs = Arc::new(crate::platform::MockFileSystem::new());
+        fs.write_file("plugins/example/assets/style.css", b"body {}")
+            .await
+            .unwrap();
+
+        let server = PluginAssetServer::new(fs).with_max_age(60);
+        let asset = server
+            .serve("/plugins/example/assets/style.css")
+            .await
+            .unwrap();
+        assert_eq!(asset.body, b"body {}");
+        assert_eq!(asset.content_type, "text/css; charset=utf-8");
+        assert_eq!(asset.cache_control, "public, max-age=60");
+    }
+
+    #[tokio::test]
+    async fn test_serve_rejects_traversal_before_reading() {
+        let server = PluginAssetServer::new(Arc::new(crate::platform::MockFileSystem::new()));
+        assert!(server
+            .serve("/plugins/example/assets/../../../etc/passwd")
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_asset_url() {
+        assert_eq!(
+            PluginAssetServer::asset_url("example", "icons/logo.svg").unwrap(),
+            "/plugins/example/assets/icons/logo.svg"
+        );
+        assert!(PluginAssetServer::asset_url("example", "../logo.svg").is_err());
+    }
+}
@@ -0,0 +1,141 @@
+// src/plugin/components.rs - Native Dioxus components supplied by plugins
+
+use std::sync::Arc;
+
+use dioxus::prelude::*;
+
+use super::UIComponent;
+use crate::ui::UIComponentRegistry;
+
+/// Component resolved for `/plugin/<id>` when no page is given
+pub const DEFAULT_PLUGIN_PAGE: &str = "index";
+
+/// A plugin component found in the [`UIComponentRegistry`].
+///
+/// Holds only IDs and props; the render closure stays in the registry and is
+/// looked up each time [`PluginComponentHost`] renders.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedComponent {
+    pub plugin_id: String,
+    pub component_id: String,
+    /// Props declared when the component was registered
+    pub props: serde_json::Value,
+}
+
+impl UIComponentRegistry {
+    /// A handle that can only register components for `plugin_id`
+    pub fn scoped(&self, plugin_id: impl Into<String>) -> PluginComponents {
+        PluginComponents {
+            plugin_id: plugin_id.into(),
+            registry: self.clone(),
+        }
+    }
+}
+
+/// A plugin's view of the [`UIComponentRegistry`], handed out in its `PluginContext`.
+///
+/// Closures are dropped when their plugin shuts down or is unloaded.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PluginComponents {
+    plugin_id: String,
+    registry: UIComponentRegistry,
+}
+
+impl PluginComponents {
+    /// Registers a render closure with empty props, replacing any previous one
+    pub fn register(
+        &self,
+        component_id: &str,
+        render: impl Fn(&serde_json::Value) -> Element + Send + Sync + 'static,
+    ) {
+        self.registry.register_component(
+            &self.plugin_id,
+            component_id,
+            None,
+            serde_json::Value::Null,
+            Arc::new(render),
+        );
+    }
+
+    /// Registers a component's `render_fn` along with its route and props.
+    ///
+    /// Returns `false` if the component has no render function.
+    pub fn register_component(&self, component: &UIComponent) -> bool {
+        let Some(render) = &component.render_fn else {
+            return false;
+        };
+
+        self.registry.register_component(
+            &self.plugin_id,
+            &component.id,
+            component.route.clone(),
+            component.props.clone(),
+            Arc::clone(render),
+        );
+        true
+    }
+
+    /// Removes one of this plugin's components
+    pub fn unregister(&self, component_id: &str) {
+        self.registry
+            .unregister_component(&self.plugin_id, component_id);
+    }
+}
+
+/// Renders a plugin component in its own scope so its hooks never mix with the host's
+#[component]
+pub fn PluginComponentHost(component: ResolvedComponent) -> Element {
+    let registry =
+        try_use_context::<UIComponentRegistry>().unwrap_or_else(UIComponentRegistry::shared);
+    registry.render_component(&component, &component.props)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::UIComponentType;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_register_resolve_and_clear() {
+        let registry = UIComponentRegistry::new();
+        let components = registry.scoped("catalog");
+        let renders = Arc::new(AtomicUsize::new(0));
+
+        let counter = Arc::clone(&renders);
+        components.register_component(
+            &UIComponent::new("products", "Products", UIComponentType::Page)
+                .with_route("/plugin/catalog/list")
+                .with_props(serde_json::json!({ "page_size": 25 }))
+                .with_render_fn(move |props| {
+                    assert_eq!(props["page_size"], 25);
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    VNode::empty()
+                }),
+        );
+        components.register(DEFAULT_PLUGIN_PAGE, |_| VNode::empty());
+        assert!(!components.register_component(&UIComponent::new(
+            "declarative",
+            "Declarative",
+            UIComponentType::Widget
+        )));
+
+        let routed = registry.resolve_route("catalog", Some("list")).unwrap();
+        assert_eq!(routed.component_id, "products");
+        let _ = registry.render_component(&routed, &routed.props);
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+
+        let index = registry.resolve_route("catalog", None).unwrap();
+        assert_eq!(index.component_id, DEFAULT_PLUGIN_PAGE);
+        assert!(registry.resolve_route("catalog", Some("missing")).is_none());
+        assert!(registry.resolve_route("other", None).is_none());
+
+        // Removal drops the closures; resolved handles no longer render them
+        assert_eq!(Arc::strong_count(&renders), 2);
+        assert_eq!(registry.remove_plugin("catalog"), 2);
+        assert!(registry.component_ids("catalog").is_empty());
+        assert_eq!(Arc::strong_count(&renders), 1);
+        let _ = registry.render_component(&routed, &routed.props);
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,189 @@
+// src/plugin/compression.rs - Response compression negotiated via Accept-Encoding
+
+use std::io::Write;
+
+use crate::config::CompressionConfig;
+use crate::error::{Error, ErrorKind, Result};
+
+/// Encodings the server can apply to a response body
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentEncoding {
+    Brotli,
+    Gzip,
+    Identity,
+}
+
+impl ContentEncoding {
+    /// Value of the `Content-Encoding` header, `None` for identity
+    pub fn header_value(&self) -> Option<&'static str> {
+        match self {
+            Self::Brotli => Some("br"),
+            Self::Gzip => Some("gzip"),
+            Self::Identity => None,
+        }
+    }
+
+    /// Picks the best encoding from an `Accept-Encoding` header.
+    ///
+    /// Highest q-value wins; brotli is preferred over gzip on ties.
+    /// Encodings with `q=0` are never chosen.
+    pub fn negotiate(accept_encoding: &str) -> Self {
+        let mut wildcard = None;
+        let mut brotli = None;
+        let mut gzip = None;
+
+        for part in accept_encoding.split(',') {
+            let mut params = part.split(';');
+            let name = params
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase();
+            let quality = params
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            match name.as_str() {
+                "br" => brotli = Some(quality),
+                "gzip" | "x-gzip" => gzip = Some(quality),
+                "*" => wildcard = Some(quality),
+                _ => {}
+            }
+        }
+
+        let brotli = brotli.or(wildcard).unwrap_or(0.0);
+        let gzip = gzip.or(wildcard).unwrap_or(0.0);
+        if brotli > 0.0 && brotli >= gzip {
+            Self::Brotli
+        } else if gzip > 0.0 {
+            Self::Gzip
+        } else {
+            Self::Identity
+        }
+    }
+}
+
+/// A response body ready to send, with the encoding that was applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedBody {
+    pub encoding: ContentEncoding,
+    pub body: Vec<u8>,
+}
+
+impl EncodedBody {
+    /// `Content-Encoding` and `Vary` headers to send along with the body
+    pub fn http_headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![("Vary".to_string(), "Accept-Encoding".to_string())];
+        if let Some(encoding) = self.encoding.header_value() {
+            headers.push(("Content-Encoding".to_string(), encoding.to_string()));
+        }
+        headers
+    }
+}
+
+/// Compresses `body` when the config allows it and the client accepts a supported encoding
+pub fn compress_response(
+    body: Vec<u8>,
+    content_type: &str,
+    accept_encoding: Option<&str>,
+    config: &CompressionConfig,
+) -> Result<EncodedBody> {
+    let encoding = match accept_encoding {
+        Some(accept) if config.should_compress(content_type, body.len()) => {
+            ContentEncoding::negotiate(accept)
+        }
+        _ => ContentEncoding::Identity,
+    };
+
+    let body = match encoding {
+        ContentEncoding::Brotli => {
+            let mut encoder = brotli::CompressorWriter::new(Vec::new(), 4096, 5, 22);
+            encoder.write_all(&body).map_err(compression_error)?;
+            encoder.into_inner()
+        }
+        ContentEncoding::Gzip => {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(&body).map_err(compression_error)?;
+            encoder.finish().map_err(compression_error)?
+        }
+        ContentEncoding::Identity => body,
+    };
+
+    Ok(EncodedBody { encoding, body })
+}
+
+fn compression_error(e: std::io::Error) -> Error {
+    Error::new(ErrorKind::Io, format!("Failed to compress response: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn test_negotiate_honours_quality() {
+        assert_eq!(
+            ContentEncoding::negotiate("gzip, br"),
+            ContentEncoding::Brotli
+        );
+        assert_eq!(
+            ContentEncoding::negotiate("br;q=0.5, gzip"),
+            ContentEncoding::Gzip
+        );
+        assert_eq!(
+            ContentEncoding::negotiate("br;q=0, gzip;q=0"),
+            ContentEncoding::Identity
+        );
+        assert_eq!(
+            ContentEncoding::negotiate("*;q=0.1, br;q=0"),
+            ContentEncoding::Gzip
+        );
+        assert_eq!(
+            ContentEncoding::negotiate("deflate"),
+            ContentEncoding::Identity
+        );
+    }
+
+    #[test]
+    fn test_large_json_compressed_small_left_alone() {
+        let config = CompressionConfig::default();
+        let products: Vec<_> = (0..200)
+            .map(|i| serde_json::json!({ "id": i, "name": format!("Product {}", i) }))
+            .collect();
+        let large = serde_json::to_vec(&products).unwrap();
+        assert!(large.len() > config.min_size_bytes);
+
+        let encoded = compress_response(
+            large.clone(),
+            "application/json; charset=utf-8",
+            Some("gzip"),
+            &config,
+        )
+        .unwrap();
+        assert_eq!(encoded.encoding, ContentEncoding::Gzip);
+        assert!(encoded.body.len() < large.len());
+        assert!(encoded
+            .http_headers()
+            .contains(&("Content-Encoding".to_string(), "gzip".to_string())));
+
+        let mut decoded = Vec::new();
+        flate2::read::GzDecoder::new(encoded.body.as_slice())
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, large);
+
+        // Below the threshold the body is sent as-is
+        let small = br#"{"id":1}"#.to_vec();
+        let encoded =
+            compress_response(small.clone(), "application/json", Some("gzip"), &config).unwrap();
+        assert_eq!(encoded.encoding, ContentEncoding::Identity);
+        assert_eq!(encoded.body, small);
+
+        // Already-compressed images are skipped regardless of size
+        let encoded = compress_response(large.clone(), "image/png", Some("gzip"), &config).unwrap();
+        assert_eq!(encoded.encoding, ContentEncoding::Identity);
+    }
+}
@@ -0,0 +1,311 @@
+// src/plugin/cors.rs - Cross-origin resource sharing for plugin API routes
+
+use super::{error_response, ApiRequest, ApiResponse, HttpMethod};
+use crate::config::SecurityConfig;
+use crate::error::Result;
+
+/// Seconds a browser may reuse a preflight answer, sent as `Access-Control-Max-Age`
+pub const CORS_PREFLIGHT_MAX_AGE_SECS: u64 = 600;
+
+/// Which browser origins may call the API, usually built from [`SecurityConfig`].
+///
+/// The default policy is disabled, leaving requests and responses untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CorsPolicy {
+    pub enabled: bool,
+    /// Origins allowed to call the API, matched exactly; `*` allows any origin
+    /// but never with credentials, which config loading refuses
+    pub origins: Vec<String>,
+    /// Whether cross-origin requests may carry cookies and credentials
+    pub allow_credentials: bool,
+}
+
+impl CorsPolicy {
+    /// Fails if the config allows credentials from any origin
+    pub fn from_config(config: &SecurityConfig) -> Result<Self> {
+        config.validate_cors()?;
+        Ok(Self {
+            enabled: config.enable_cors,
+            origins: config.cors_origins.clone(),
+            allow_credentials: config.cors_allow_credentials,
+        })
+    }
+
+    /// The `Access-Control-Allow-Origin` value for `origin`, or `None` if it
+    /// may not call the API.
+    ///
+    /// A wildcard match is answered with `*`, never the caller's origin, so
+    /// browsers refuse to send credentials to it.
+    pub fn allowed_origin(&self, origin: &str) -> Option<String> {
+        if !self.enabled || origin.is_empty() {
+            return None;
+        }
+
+        let exact = self
+            .origins
+            .iter()
+            .any(|allowed| allowed.trim_end_matches('/').eq_ignore_ascii_case(origin));
+        if exact {
+            Some(origin.to_string())
+        } else if self.allows_any_origin() {
+            Some("*".to_string())
+        } else {
+            None
+        }
+    }
+
+    fn allows_any_origin(&self) -> bool {
+        self.origins.iter().any(|allowed| allowed == "*")
+    }
+
+    /// Whether the answer depends on the request's `Origin`, which is true
+    /// unless every origin is allowed through the wildcard
+    fn varies_by_origin(&self) -> bool {
+        self.enabled && self.origins.iter().any(|allowed| allowed != "*")
+    }
+
+    /// Caches must not serve one origin's CORS decision to another
+    fn add_vary(&self, response: &mut ApiResponse) {
+        if self.varies_by_origin() {
+            response
+                .headers
+                .push(("Vary".to_string(), "Origin".to_string()));
+        }
+    }
+
+    /// Answer `request` if it is a preflight: 204 with the allowed methods and
+    /// headers, or 403 if its origin or method may not be used.
+    ///
+    /// Returns `None` for anything else, including all requests while disabled.
+    pub fn preflight(&self, request: &ApiRequest) -> Option<ApiResponse> {
+        if !self.enabled || !HttpMethod::Options.matches(&request.method) {
+            return None;
+        }
+        let origin = request.header("Origin")?;
+        let requested_method = request.header("Access-Control-Request-Method")?;
+
+        let mut response = self.preflight_response(request, origin, requested_method);
+        self.add_vary(&mut response);
+        Some(response)
+    }
+
+    fn preflight_response(
+        &self,
+        request: &ApiRequest,
+        origin: &str,
+        requested_method: &str,
+    ) -> ApiResponse {
+        let Some(allow_origin) = self.allowed_origin(origin) else {
+            return error_response(
+                403,
+                "Forbidden",
+                format!("Origin {} is not allowed", origin),
+            );
+        };
+        if requested_method.parse::<HttpMethod>().is_err() {
+            return error_response(
+                403,
+                "Forbidden",
+                format!("Method {} is not allowed", requested_method),
+            );
+        }
+
+        let mut response = ApiResponse {
+            status_code: 204,
+            description: "No Content".to_string(),
+            schema: None,
+            cache: None,
+            retry_after_secs: None,
+            headers: Vec::new(),
+        };
+        self.add_origin_headers(&mut response, allow_origin);
+        let methods: Vec<&str> = HttpMethod::ALL.iter().map(HttpMethod::as_str).collect();
+        response.headers.push((
+            "Access-Control-Allow-Methods".to_string(),
+            methods.join(", "),
+        ));
+        if let Some(headers) = request.header("Access-Control-Request-Headers") {
+            response.headers.push((
+                "Access-Control-Allow-Headers".to_string(),
+                headers.to_string(),
+            ));
+        }
+        response.headers.push((
+            "Access-Control-Max-Age".to_string(),
+            CORS_PREFLIGHT_MAX_AGE_SECS.to_string(),
+        ));
+        response
+    }
+
+    /// Add CORS headers to a response for a request sent from `origin`.
+    ///
+    /// Responses to disallowed origins get no `Access-Control-*` headers, so
+    /// the browser withholds them from the calling page.
+    pub fn apply(&self, origin: Option<&str>, mut response: ApiResponse) -> ApiResponse {
+        self.add_vary(&mut response);
+        if let Some(allow_origin) = origin.and_then(|origin| self.allowed_origin(origin)) {
+            self.add_origin_headers(&mut response, allow_origin);
+        }
+        response
+    }
+
+    fn add_origin_headers(&self, response: &mut ApiResponse, allow_origin: String) {
+        response
+            .headers
+            .push(("Access-Control-Allow-Origin".to_string(), allow_origin));
+        if self.allow_credentials {
+            response.headers.push((
+                "Access-Control-Allow-Credentials".to_string(),
+                "true".to_string(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(origins: &[&str], allow_credentials: bool) -> CorsPolicy {
+        CorsPolicy {
+            enabled: true,
+            origins: origins.iter().map(|o| o.to_string()).collect(),
+            allow_credentials,
+        }
+    }
+
+    fn preflight_request(origin: &str) -> ApiRequest {
+        ApiRequest::new("OPTIONS", "/api/products")
+            .with_header("origin", origin)
+            .with_header("Access-Control-Request-Method", "POST")
+            .with_header("Access-Control-Request-Headers", "content-type")
+    }
+
+    fn header<'a>(response: &'a ApiResponse, name: &str) -> Option<&'a str> {
+        response
+            .headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    #[test]
+    fn test_allowed_origin() {
+        let exact = policy(&["https://app.example.com/"], true);
+        assert_eq!(
+            exact.allowed_origin("https://app.example.com").as_deref(),
+            Some("https://app.example.com")
+        );
+        assert_eq!(exact.allowed_origin("https://evil.example.com"), None);
+
+        assert_eq!(
+            policy(&["*"], false)
+                .allowed_origin("https://any.example.com")
+                .as_deref(),
+            Some("*")
+        );
+        // A wildcard is never turned into a credentialed echo of the origin
+        assert_eq!(
+            policy(&["*"], true)
+                .allowed_origin("https://any.example.com")
+                .as_deref(),
+            Some("*")
+        );
+
+        assert!(CorsPolicy::from_config(&SecurityConfig {
+            cors_allow_credentials: true,
+            ..Default::default()
+        })
+        .is_err());
+
+        let disabled = CorsPolicy {
+            enabled: false,
+            ..policy(&["*"], false)
+        };
+        assert_eq!(disabled.allowed_origin("https://any.example.com"), None);
+        assert!(disabled
+            .preflight(&preflight_request("https://any.example.com"))
+            .is_none());
+    }
+
+    #[test]
+    fn test_preflight() {
+        let cors = policy(&["https://app.example.com"], true);
+
+        let response = cors
+            .preflight(&preflight_request("https://app.example.com"))
+            .unwrap();
+        assert_eq!(response.status_code, 204);
+        assert_eq!(
+            header(&response, "Access-Control-Allow-Origin"),
+            Some("https://app.example.com")
+        );
+        assert_eq!(
+            header(&response, "Access-Control-Allow-Credentials"),
+            Some("true")
+        );
+        assert_eq!(
+            header(&response, "Access-Control-Allow-Headers"),
+            Some("content-type")
+        );
+        assert_eq!(header(&response, "Vary"), Some("Origin"));
+        assert!(header(&response, "Access-Control-Allow-Methods")
+            .unwrap()
+            .contains("POST"));
+
+        let response = cors
+            .preflight(&preflight_request("https://evil.example.com"))
+            .unwrap();
+        assert_eq!(response.status_code, 403);
+        assert_eq!(header(&response, "Access-Control-Allow-Origin"), None);
+        assert_eq!(header(&response, "Vary"), Some("Origin"));
+
+        // A plain OPTIONS request is not a preflight
+        let mut request = preflight_request("https://app.example.com");
+        request.headers.remove("Access-Control-Request-Method");
+        assert!(cors.preflight(&request).is_none());
+    }
+
+    #[test]
+    fn test_vary_origin() {
+        let ok = || ApiResponse {
+            status_code: 200,
+            description: "OK".to_string(),
+            schema: None,
+            cache: None,
+            retry_after_secs: None,
+            headers: Vec::new(),
+        };
+        let cors = policy(&["https://app.example.com"], false);
+
+        // The allowed and refused answers differ, so both name Origin
+        let allowed = cors.apply(Some("https://app.example.com"), ok());
+        assert_eq!(header(&allowed, "Vary"), Some("Origin"));
+        let refused = cors.apply(Some("https://evil.example.com"), ok());
+        assert_eq!(header(&refused, "Vary"), Some("Origin"));
+        assert_eq!(header(&refused, "Access-Control-Allow-Origin"), None);
+        let same_origin = cors.apply(None, ok());
+        assert_eq!(header(&same_origin, "Vary"), Some("Origin"));
+
+        // Every origin gets `*`, so the answer doesn't depend on it
+        let any = policy(&["*"], false);
+        let response = any.apply(Some("https://any.example.com"), ok());
+        assert_eq!(header(&response, "Vary"), None);
+        assert_eq!(header(&response, "Access-Control-Allow-Origin"), Some("*"));
+        let response = any
+            .preflight(&preflight_request("https://any.example.com"))
+            .unwrap();
+        assert_eq!(header(&response, "Vary"), None);
+
+        let mixed = policy(&["https://app.example.com", "*"], false);
+        let response = mixed.apply(Some("https://any.example.com"), ok());
+        assert_eq!(header(&response, "Vary"), Some("Origin"));
+
+        let disabled = CorsPolicy {
+            enabled: false,
+            ..cors
+        };
+        let response = disabled.apply(Some("https://app.example.com"), ok());
+        assert!(response.headers.is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+// src/plugin/hot_reload.rs - Reload plugins whose artifacts change on disk
+
+//! Plugin hot reload, compiled on native targets with the `hot-reload` feature.
+//!
+//! A [`PluginDirWatcher`] reports plugin artifacts (shared libraries or
+//! `.wasm` modules) created or modified under the plugin directory, and
+//! [`PluginManager::reload_plugin`] swaps the running instance for the new one
+//! when `plugins.hot_reload` is enabled. Menus, API routes and search providers
+//! are read from the registry, so they follow the new instance; components and
+//! reports are registered again by its `initialize`. The plugin's configuration
+//! in the context carries over, and settings saved through the API client live
+//! in the config manager, so nothing the plugin persisted is lost.
+//!
+//! Caveats:
+//!
+//! - Requests and events already dispatched to the old instance finish before
+//!   the swap, because reloading needs `&mut PluginManager`. Tasks the plugin
+//!   spawned itself, and receivers from `PluginContext::subscribe`, are not
+//!   stopped by the manager; `shutdown` must end them.
+//! - Rendered UI may still hold render closures from the old instance until the
+//!   next render. The loader is therefore not asked to unload the old
+//!   artifact, so its code stays mapped for the life of the process.
+//! - `dlopen` hands back the already loaded library for a path it has seen;
+//!   [`NativeLibraryLoader`](super::NativeLibraryLoader) opens a fresh copy of
+//!   each artifact so a reload gets the new code.
+//! - Host and plugin must still be built with the same compiler and
+//!   `qorzen_oxide` version; a reload does not relax the ABI contract.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+
+use super::{with_timeout, PluginManager, PluginStatus};
+use crate::error::{Error, ErrorKind, FileOperation, Result};
+
+/// How long artifact writes must be quiet before a change is reported
+pub const HOT_RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Whether `path` looks like a loadable plugin artifact
+pub fn is_plugin_artifact(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION || ext == "wasm")
+}
+
+fn watch_error(dir: &Path, message: String) -> Error {
+    Error::new(
+        ErrorKind::File {
+            path: Some(dir.display().to_string()),
+            operation: FileOperation::Watch,
+        },
+        message,
+    )
+}
+
+/// Watches a plugin directory for created or modified plugin artifacts
+#[derive(Debug)]
+pub struct PluginDirWatcher {
+    _watcher: RecommendedWatcher,
+    changes: mpsc::UnboundedReceiver<PathBuf>,
+}
+
+impl PluginDirWatcher {
+    /// Watch `dir` and everything below it
+    pub fn new(dir: &Path) -> Result<Self> {
+        let (sender, changes) = mpsc::unbounded_channel();
+        let mut watcher = RecommendedWatcher::new(
+            move |result: notify::Result<notify::Event>| {
+                let Ok(event) = result else {
+                    return;
+                };
+                if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                    return;
+                }
+                for path in event.paths {
+                    if is_plugin_artifact(&path) {
+                        let _ = sender.send(path);
+                    }
+                }
+            },
+            notify::Config::default(),
+        )
+        .map_err(|e| watch_error(dir, format!("Failed to create plugin watcher: {}", e)))?;
+
+        watcher
+            .watch(dir, RecursiveMode::Recursive)
+            .map_err(|e| watch_error(dir, format!("Failed to watch plugin directory: {}", e)))?;
+
+        Ok(Self {
+            _watcher: watcher,
+            changes,
+        })
+    }
+
+    /// Wait for the next changed artifacts.
+    ///
+    /// Returns once writes have been quiet for [`HOT_RELOAD_DEBOUNCE`], so a
+    /// file copied in several writes is reported once.
+    pub async fn next_batch(&mut self) -> Option<Vec<PathBuf>> {
+        let mut changed = vec![self.changes.recv().await?];
+        while let Ok(Some(path)) =
+            tokio::time::timeout(HOT_RELOAD_DEBOUNCE, self.changes.recv()).await
+        {
+            if !changed.contains(&path) {
+                changed.push(path);
+            }
+        }
+        Some(changed)
+    }
+}
+
+impl PluginManager {
+    /// Allow [`Self::reload_plugin`] to replace running plugins
+    pub fn set_hot_reload(&mut self, enabled: bool) {
+        self.hot_reload = enabled;
+    }
+
+    pub fn hot_reload_enabled(&self) -> bool {
+        self.hot_reload
+    }
+
+    /// Load the plugin at `artifact` and swap it in for the running instance
+    /// with the same ID, returning that ID.
+    ///
+    /// The new plugin is loaded and validated before the old one is touched,
+    /// so a broken artifact leaves the running plugin in place. A plugin not
+    /// yet registered is simply added.
+    pub async fn reload_plugin(&mut self, artifact: &Path) -> Result<String> {
+        if !self.hot_reload {
+            return Err(Error::plugin(
+                artifact.display().to_string(),
+                "Hot reload is disabled",
+            ));
+        }
+
+        let plugin = self.loader.load_plugin(&artifact.to_string_lossy()).await?;
+        let info = plugin.info();
+        self.admit_plugin(plugin.as_ref()).await?;
+
+        let plugin_id = info.id;
+        let counters = self.runtime.counters(&plugin_id);
+        let previous_context = self.plugin_contexts.remove(&plugin_id);
+        self.unregister_search_providers(&plugin_id).await;
+        if let Some(mut old) = self.registry.remove(&plugin_id) {
+            counters.set_status(PluginStatus::Stopping);
+            match with_timeout(self.shutdown_timeout, old.shutdown()).await {
+                Some(Ok(())) => {}
+                Some(Err(e)) => {
+                    tracing::warn!("Plugin {} failed to shut down for reload: {}", plugin_id, e)
+                }
+                None => tracing::warn!(
+                    "Plugin {} did not shut down within {:?}; reloading anyway",
+                    plugin_id,
+                    self.shutdown_timeout
+                ),
+            }
+            self.registry.dependencies.remove(&plugin_id);
+            self.components.remove_plugin(&plugin_id);
+            self.reports.cancel_plugin(&plugin_id);
+        }
+
+        self.registry.register(plugin)?;
+        let mut context = self.create_plugin_context(&plugin_id).await?;
+        if let Some(previous) = previous_context {
+            context.config = previous.config;
+        }
+        self.plugin_contexts
+            .insert(plugin_id.clone(), context.clone());
+
+        counters.set_status(PluginStatus::Loading);
+        if let Some(plugin) = self.registry.plugins.get_mut(&plugin_id) {
+            if let Err(e) = plugin.initialize(context).await {
+                counters.set_status(PluginStatus::Failed);
+                counters.record_error();
+                return Err(Error::plugin(
+                    &plugin_id,
+                    format!("Plugin initialization failed after reload: {}", e),
+                ));
+            }
+        }
+        counters.set_status(PluginStatus::Running);
+        self.register_search_providers(&plugin_id).await;
+        if !self.registry.load_order.contains(&plugin_id) {
+            self.registry.load_order.push(plugin_id.clone());
+        }
+
+        tracing::info!("Reloaded plugin {} from {}", plugin_id, artifact.display());
+        Ok(plugin_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_is_plugin_artifact() {
+        let library = format!("catalog.{}", std::env::consts::DLL_EXTENSION);
+        assert!(is_plugin_artifact(Path::new(&library)));
+        assert!(is_plugin_artifact(Path::new("plugins/catalog.wasm")));
+        assert!(!is_plugin_artifact(Path::new("plugins/plugin.json")));
+        assert!(!is_plugin_artifact(Path::new("plugins/catalog")));
+    }
+
+    #[tokio::test]
+    async fn test_watcher_reports_changed_artifacts_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut watcher = PluginDirWatcher::new(temp_dir.path()).unwrap();
+
+        let library = temp_dir
+            .path()
+            .join(format!("catalog.{}", std::env::consts::DLL_EXTENSION));
+        std::fs::write(&library, b"v1").unwrap();
+        std::fs::write(&library, b"v2").unwrap();
+        std::fs::write(temp_dir.path().join("plugin.json"), b"{}").unwrap();
+
+        let changed = tokio::time::timeout(Duration::from_secs(10), watcher.next_batch())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(
+            changed[0].file_name(),
+            library.file_name(),
+            "only the plugin artifact is reported"
+        );
+    }
+}
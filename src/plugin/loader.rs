@@ -9,7 +9,9 @@ use tokio::sync::{Mutex, RwLock};
 use uuid::Uuid;
 
 use super::manifest::PluginManifest;
-use super::{Plugin, PluginContext, ValidationResult};
+use super::{Plugin, PluginContext, PluginInfo, ValidationResult};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::PluginLoadFailure;
 use crate::error::{Error, Result};
 use crate::manager::{ManagedState, Manager, ManagerStatus};
 use crate::platform::filesystem::FileSystemProvider;
@@ -45,6 +47,90 @@ pub struct PluginInstallation {
 /// Plugin factory function type
 pub type PluginFactory = fn() -> Box<dyn Plugin>;
 
+/// Fails unless the running core is at least the plugin's `minimum_core_version`
+pub fn check_core_compatibility(info: &PluginInfo) -> Result<()> {
+    let required = super::parse_version(&info.minimum_core_version).ok_or_else(|| {
+        Error::plugin(
+            &info.id,
+            format!(
+                "Invalid minimum_core_version '{}'",
+                info.minimum_core_version
+            ),
+        )
+    })?;
+    let running = super::parse_version(crate::VERSION).expect("crate version is valid semver");
+
+    if running < required {
+        return Err(Error::plugin(
+            &info.id,
+            format!(
+                "Plugin {} {} requires core {} or newer, but this is core {}",
+                info.id,
+                info.version,
+                info.minimum_core_version,
+                crate::VERSION
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Plugin factories registered by ID, used where plugins are compiled in
+/// rather than loaded from a library, as on WASM.
+///
+/// This is the one place compiled-in plugins are registered. `export_plugin!`
+/// generates a `register_plugin()` function on WASM that adds the plugin to
+/// [`PluginFactoryRegistry::shared`]; the host calls it, or registers its own
+/// factories, at startup. The loaders, the install dialog and
+/// `ApplicationCore::with_builtin_plugins` all read from it.
+#[derive(Debug, Clone, Default)]
+pub struct PluginFactoryRegistry {
+    factories: Arc<std::sync::RwLock<HashMap<String, PluginFactory>>>,
+}
+
+impl PluginFactoryRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide registry consulted by the WASM plugin loader
+    pub fn shared() -> Self {
+        static SHARED: std::sync::OnceLock<PluginFactoryRegistry> = std::sync::OnceLock::new();
+        SHARED.get_or_init(Self::new).clone()
+    }
+
+    /// Register `factory` for `plugin_id`, replacing any earlier registration
+    pub fn register(&self, plugin_id: impl Into<String>, factory: PluginFactory) {
+        self.factories
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(plugin_id.into(), factory);
+    }
+
+    /// The factory registered for `plugin_id`
+    pub fn get(&self, plugin_id: &str) -> Option<PluginFactory> {
+        self.factories
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(plugin_id)
+            .copied()
+    }
+
+    /// IDs of every registered plugin, sorted
+    pub fn plugin_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .factories
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect();
+        ids.sort();
+        ids
+    }
+}
+
 /// Plugin loader trait for different loading mechanisms
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -67,34 +153,31 @@ pub trait PluginLoader: Send + Sync + std::fmt::Debug {
     async fn hot_reload_plugin(&self, plugin_id: &str) -> Result<Box<dyn Plugin>>;
 }
 
-/// Safe plugin loader using registered factories
+/// Safe plugin loader using factories from a [`PluginFactoryRegistry`]
 #[derive(Debug)]
 pub struct SafePluginLoader {
-    registered_plugins: Arc<Mutex<HashMap<String, PluginFactory>>>,
+    factories: PluginFactoryRegistry,
     loaded_plugins: Arc<Mutex<HashMap<String, String>>>, // plugin_id -> factory_name
 }
 
 impl SafePluginLoader {
-    /// Create a new safe plugin loader
+    /// Create a loader using [`PluginFactoryRegistry::shared`]
     pub fn new() -> Self {
+        Self::with_factories(PluginFactoryRegistry::shared())
+    }
+
+    /// Create a loader using the factories in `factories`
+    pub fn with_factories(factories: PluginFactoryRegistry) -> Self {
         Self {
-            registered_plugins: Arc::new(Mutex::new(HashMap::new())),
+            factories,
             loaded_plugins: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
-    /// Register a plugin factory
-    #[allow(dead_code)]
-    pub async fn register_plugin_factory(&self, name: String, factory: PluginFactory) {
-        let mut registered = self.registered_plugins.lock().await;
-        registered.insert(name, factory);
-    }
-
     /// Get available plugin factories
     #[allow(dead_code)]
-    pub async fn list_available_plugins(&self) -> Vec<String> {
-        let registered = self.registered_plugins.lock().await;
-        registered.keys().cloned().collect()
+    pub fn list_available_plugins(&self) -> Vec<String> {
+        self.factories.plugin_ids()
     }
 }
 
@@ -108,10 +191,8 @@ impl Default for SafePluginLoader {
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 impl PluginLoader for SafePluginLoader {
     async fn load_plugin(&self, installation: &PluginInstallation) -> Result<Box<dyn Plugin>> {
-        let registered = self.registered_plugins.lock().await;
-
         // Try to find a factory for this plugin
-        if let Some(factory) = registered.get(&installation.id) {
+        if let Some(factory) = self.factories.get(&installation.id) {
             let plugin = factory();
 
             // Store the loaded plugin reference
@@ -128,9 +209,7 @@ impl PluginLoader for SafePluginLoader {
     }
 
     async fn validate_plugin(&self, installation: &PluginInstallation) -> Result<ValidationResult> {
-        let registered = self.registered_plugins.lock().await;
-
-        if registered.contains_key(&installation.id) {
+        if self.factories.get(&installation.id).is_some() {
             // Basic validation for manifest structure
             let mut errors = Vec::new();
             let mut warnings = Vec::new();
@@ -198,12 +277,457 @@ impl PluginLoader for SafePluginLoader {
     }
 }
 
-/// WASM plugin loader for web environment
+// ABI contract between `export_plugin!` and `NativePluginLibrary`:
+//
+// - `create_plugin() -> *mut c_void` returns null on failure, or a thin pointer
+//   from `Box::into_raw(Box::new(plugin))` where `plugin: Box<dyn Plugin>`. The
+//   trait-object (fat) pointer never crosses the boundary itself.
+// - `destroy_plugin(*mut c_void)` takes back a pointer of that same layout and
+//   drops it with the library's allocator; null is ignored.
+// - `get_plugin_info() -> *const c_char` returns null or NUL-terminated JSON
+//   for `PluginInfo`, owned by the library and valid while it stays loaded.
+// - `qorzen_plugin_abi_version() -> u32` returns the `PLUGIN_ABI_VERSION` the
+//   library was built with; the host refuses libraries reporting another.
+// - No function unwinds across the boundary.
+//
+// `dyn Plugin` has no stable layout, so host and plugin must be built with the
+// same compiler and `qorzen_oxide` version, and the library must outlive every
+// plugin it created.
+
+/// Symbol exported by `export_plugin!` that constructs the plugin
+pub const CREATE_PLUGIN_SYMBOL: &str = "create_plugin";
+/// Symbol exported by `export_plugin!` that drops a plugin it created
+pub const DESTROY_PLUGIN_SYMBOL: &str = "destroy_plugin";
+/// Symbol exported by `export_plugin!` that describes the plugin as JSON
+pub const PLUGIN_INFO_SYMBOL: &str = "get_plugin_info";
+/// Symbol exported by `export_plugin!` that reports the ABI it was built for
+pub const PLUGIN_ABI_VERSION_SYMBOL: &str = "qorzen_plugin_abi_version";
+
+/// Version of the native plugin ABI, stamped into libraries by `export_plugin!`.
+///
+/// Bump this whenever the exported functions or the `Plugin` trait change, so
+/// libraries built against the old layout are rejected instead of crashing.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+#[cfg(not(target_arch = "wasm32"))]
+type CreatePluginFn = unsafe extern "C" fn() -> *mut std::ffi::c_void;
+#[cfg(not(target_arch = "wasm32"))]
+type DestroyPluginFn = unsafe extern "C" fn(*mut std::ffi::c_void);
+#[cfg(not(target_arch = "wasm32"))]
+type PluginInfoFn = unsafe extern "C" fn() -> *const std::ffi::c_char;
+#[cfg(not(target_arch = "wasm32"))]
+type AbiVersionFn = unsafe extern "C" fn() -> u32;
+
+/// A native plugin library opened from disk.
+///
+/// Every failure is reported as an [`ErrorKind::PluginLoad`](crate::error::ErrorKind::PluginLoad)
+/// carrying the library path and, where relevant, the symbol involved. Plugins
+/// created from the library hold it open, so it is only unloaded once the
+/// last of them has been handed back to `destroy_plugin`.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct NativePluginLibrary {
+    path: PathBuf,
+    library: libloading::Library,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl NativePluginLibrary {
+    /// Opens the library at `path`
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        if !path.is_file() {
+            return Err(Error::plugin_load(
+                path.display().to_string(),
+                None,
+                PluginLoadFailure::LibraryNotFound,
+                format!("Plugin library not found: {}", path.display()),
+            ));
+        }
+
+        // SAFETY: loading runs the library's initializers; plugin libraries are
+        // trusted to the same degree as the host once they are installed.
+        let library = unsafe { libloading::Library::new(&path) }.map_err(|e| {
+            Error::plugin_load(
+                path.display().to_string(),
+                None,
+                PluginLoadFailure::InvalidLibrary,
+                format!("Failed to open plugin library {}", path.display()),
+            )
+            .caused_by(e)
+        })?;
+
+        Ok(Self { path, library })
+    }
+
+    /// Path the library was opened from
+    pub fn path(&self) -> &std::path::Path {
+        &self.path
+    }
+
+    /// Fails unless the library was built for this host's [`PLUGIN_ABI_VERSION`]
+    pub fn check_abi_version(&self) -> Result<()> {
+        let abi_version = self.symbol::<AbiVersionFn>(PLUGIN_ABI_VERSION_SYMBOL)?;
+
+        // SAFETY: `qorzen_plugin_abi_version` takes nothing and returns a plain integer
+        let version = unsafe { abi_version() };
+        if version != PLUGIN_ABI_VERSION {
+            return Err(self.error(
+                Some(PLUGIN_ABI_VERSION_SYMBOL),
+                PluginLoadFailure::AbiMismatch,
+                format!(
+                    "Plugin ABI version {} does not match host ABI version {}",
+                    version, PLUGIN_ABI_VERSION
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reads the plugin info exported through `get_plugin_info`
+    pub fn plugin_info(&self) -> Result<PluginInfo> {
+        self.check_abi_version()?;
+        let get_info = self.symbol::<PluginInfoFn>(PLUGIN_INFO_SYMBOL)?;
+
+        // SAFETY: `get_plugin_info` is generated by `export_plugin!` and returns
+        // either null or a NUL-terminated string that lives as long as the library.
+        let raw = unsafe { get_info() };
+        if raw.is_null() {
+            return Err(self.error(
+                Some(PLUGIN_INFO_SYMBOL),
+                PluginLoadFailure::NullPluginInfo,
+                "get_plugin_info returned null",
+            ));
+        }
+
+        let json = unsafe { std::ffi::CStr::from_ptr(raw) };
+        let json = json.to_str().map_err(|e| {
+            self.error(
+                Some(PLUGIN_INFO_SYMBOL),
+                PluginLoadFailure::InvalidPluginInfo,
+                "Plugin info is not valid UTF-8",
+            )
+            .caused_by(e)
+        })?;
+
+        serde_json::from_str(json).map_err(|e| {
+            self.error(
+                Some(PLUGIN_INFO_SYMBOL),
+                PluginLoadFailure::InvalidPluginInfo,
+                "Failed to parse plugin info",
+            )
+            .caused_by(e)
+        })
+    }
+
+    /// Creates a plugin instance through `create_plugin`.
+    ///
+    /// The ABI version and `minimum_core_version` are checked first, so no
+    /// code from an incompatible library runs beyond those two exports. The
+    /// returned [`NativePlugin`] keeps the library loaded while it lives.
+    pub fn create_plugin(self: &Arc<Self>) -> Result<NativePlugin> {
+        check_core_compatibility(&self.plugin_info()?)?;
+        let create = self.symbol::<CreatePluginFn>(CREATE_PLUGIN_SYMBOL)?;
+        // Resolve the destructor up front so a library we cannot clean up after is rejected
+        self.symbol::<DestroyPluginFn>(DESTROY_PLUGIN_SYMBOL)?;
+
+        // SAFETY: `create_plugin` is generated by `export_plugin!` and returns
+        // either null or a leaked `Box<Box<dyn Plugin>>`.
+        let raw = unsafe { create() };
+        if raw.is_null() {
+            return Err(self.error(
+                Some(CREATE_PLUGIN_SYMBOL),
+                PluginLoadFailure::NullPlugin,
+                "create_plugin returned null",
+            ));
+        }
+
+        Ok(NativePlugin {
+            plugin: Some(*unsafe { Box::from_raw(raw as *mut Box<dyn Plugin>) }),
+            library: Arc::clone(self),
+        })
+    }
+
+    /// Hands a plugin back to the library's `destroy_plugin`
+    fn destroy_plugin(&self, plugin: Box<dyn Plugin>) -> Result<()> {
+        let destroy = self.symbol::<DestroyPluginFn>(DESTROY_PLUGIN_SYMBOL)?;
+        let raw = Box::into_raw(Box::new(plugin)) as *mut std::ffi::c_void;
+
+        // SAFETY: `raw` has the layout `create_plugin` produces and is not used again.
+        unsafe { destroy(raw) };
+        Ok(())
+    }
+
+    fn symbol<T: Copy>(&self, name: &str) -> Result<T> {
+        // SAFETY: the caller picks `T` to match the signature `export_plugin!` emits.
+        unsafe { self.library.get::<T>(name.as_bytes()) }
+            .map(|symbol| *symbol)
+            .map_err(|e| {
+                self.error(
+                    Some(name),
+                    PluginLoadFailure::MissingSymbol,
+                    format!("Symbol '{}' not found", name),
+                )
+                .caused_by(e)
+            })
+    }
+
+    fn error(
+        &self,
+        symbol: Option<&str>,
+        failure: PluginLoadFailure,
+        message: impl std::fmt::Display,
+    ) -> Error {
+        Error::plugin_load(
+            self.path.display().to_string(),
+            symbol,
+            failure,
+            format!("{} in plugin library {}", message, self.path.display()),
+        )
+    }
+}
+
+/// A plugin created by a [`NativePluginLibrary`].
+///
+/// The plugin's vtable and drop glue live in the library, so the library is
+/// held open for as long as the plugin exists and the plugin is freed by the
+/// library's own `destroy_plugin`. Render closures and search providers the
+/// plugin hands out also point into the library and must be dropped with it.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct NativePlugin {
+    // Declared before `library` so the plugin is always released first
+    plugin: Option<Box<dyn Plugin>>,
+    library: Arc<NativePluginLibrary>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl NativePlugin {
+    /// The library this plugin was created from
+    pub fn library(&self) -> &NativePluginLibrary {
+        &self.library
+    }
+
+    fn inner(&self) -> &dyn Plugin {
+        self.plugin
+            .as_deref()
+            .expect("native plugin is only released on drop")
+    }
+
+    fn inner_mut(&mut self) -> &mut Box<dyn Plugin> {
+        self.plugin
+            .as_mut()
+            .expect("native plugin is only released on drop")
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for NativePlugin {
+    fn drop(&mut self) {
+        if let Some(plugin) = self.plugin.take() {
+            if let Err(e) = self.library.destroy_plugin(plugin) {
+                tracing::error!("Failed to release native plugin: {}", e);
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl Plugin for NativePlugin {
+    fn info(&self) -> PluginInfo {
+        self.inner().info()
+    }
+
+    fn required_dependencies(&self) -> Vec<super::PluginDependency> {
+        self.inner().required_dependencies()
+    }
+
+    fn required_permissions(&self) -> Vec<crate::auth::Permission> {
+        self.inner().required_permissions()
+    }
+
+    async fn initialize(&mut self, context: PluginContext) -> Result<()> {
+        self.inner_mut().initialize(context).await
+    }
+
+    async fn shutdown(&mut self) -> Result<()> {
+        self.inner_mut().shutdown().await
+    }
+
+    fn ui_components(&self) -> Vec<super::UIComponent> {
+        self.inner().ui_components()
+    }
+
+    fn menu_items(&self) -> Vec<super::MenuItem> {
+        self.inner().menu_items()
+    }
+
+    fn settings_schema(&self) -> Option<crate::config::SettingsSchema> {
+        self.inner().settings_schema()
+    }
+
+    fn api_routes(&self) -> Vec<super::ApiRoute> {
+        self.inner().api_routes()
+    }
+
+    fn event_handlers(&self) -> Vec<super::EventHandler> {
+        self.inner().event_handlers()
+    }
+
+    fn search_providers(&self) -> Vec<Arc<dyn super::SearchProvider>> {
+        self.inner().search_providers()
+    }
+
+    fn render_component(
+        &self,
+        component_id: &str,
+        props: serde_json::Value,
+    ) -> Result<dioxus::prelude::VNode> {
+        self.inner().render_component(component_id, props)
+    }
+
+    async fn handle_api_request(
+        &self,
+        route_id: &str,
+        request: super::ApiRequest,
+    ) -> Result<super::ApiResponse> {
+        self.inner().handle_api_request(route_id, request).await
+    }
+
+    async fn handle_event(&self, handler_id: &str, event: &dyn crate::event::Event) -> Result<()> {
+        self.inner().handle_event(handler_id, event).await
+    }
+
+    async fn handle_event_propagation(
+        &self,
+        handler_id: &str,
+        event: &dyn crate::event::Event,
+    ) -> Result<super::EventPropagation> {
+        self.inner()
+            .handle_event_propagation(handler_id, event)
+            .await
+    }
+}
+
+/// Loads plugins from native libraries built with `export_plugin!`.
+///
+/// Each library is copied to a unique path under the staging directory and
+/// opened from there. `dlopen` returns the already loaded library for a path
+/// it has seen, so opening the original again would hand a reload the old
+/// code; the copy also leaves the original free to be overwritten.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct NativeLibraryLoader {
+    staging_dir: PathBuf,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Default for NativeLibraryLoader {
+    fn default() -> Self {
+        Self::with_staging_dir(std::env::temp_dir().join("qorzen-plugins"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl NativeLibraryLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stage libraries in `staging_dir` instead of the system temp directory
+    pub fn with_staging_dir(staging_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            staging_dir: staging_dir.into(),
+        }
+    }
+
+    /// Copy the library at `path` to a path no earlier load has used
+    fn stage(&self, path: &std::path::Path) -> Result<PathBuf> {
+        if !path.is_file() {
+            return Err(Error::plugin_load(
+                path.display().to_string(),
+                None,
+                PluginLoadFailure::LibraryNotFound,
+                format!("Plugin library not found: {}", path.display()),
+            ));
+        }
+
+        let stem = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("plugin");
+        let staged = self.staging_dir.join(format!(
+            "{}-{}.{}",
+            stem,
+            crate::utils::rand::uuid().simple(),
+            std::env::consts::DLL_EXTENSION
+        ));
+        std::fs::create_dir_all(&self.staging_dir)
+            .and_then(|_| std::fs::copy(path, &staged))
+            .map_err(|e| {
+                Error::plugin_load(
+                    path.display().to_string(),
+                    None,
+                    PluginLoadFailure::LibraryNotFound,
+                    format!(
+                        "Failed to stage plugin library {} at {}",
+                        path.display(),
+                        staged.display()
+                    ),
+                )
+                .caused_by(e)
+            })?;
+        Ok(staged)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl super::PluginLoader for NativeLibraryLoader {
+    async fn load_plugin(&self, path: &str) -> Result<Box<dyn Plugin>> {
+        let staged = self.stage(std::path::Path::new(path))?;
+        let opened = NativePluginLibrary::open(&staged);
+        // A mapped library no longer needs its file on Unix; elsewhere the
+        // copy stays until the staging directory is cleared
+        if cfg!(unix) || opened.is_err() {
+            let _ = std::fs::remove_file(&staged);
+        }
+        let library = Arc::new(opened?);
+        Ok(Box::new(library.create_plugin()?))
+    }
+
+    async fn validate_plugin(&self, plugin: &dyn Plugin) -> Result<ValidationResult> {
+        let info = plugin.info();
+        let mut errors = Vec::new();
+        if info.id.trim().is_empty() {
+            errors.push("Plugin ID is empty".to_string());
+        }
+        if super::parse_version(&info.version).is_none() {
+            errors.push(format!("Invalid plugin version '{}'", info.version));
+        }
+        Ok(ValidationResult {
+            is_valid: errors.is_empty(),
+            errors,
+            warnings: Vec::new(),
+        })
+    }
+
+    /// Libraries unload themselves once their last plugin is dropped
+    async fn unload_plugin(&self, _plugin_id: &str) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// WASM plugin loader for web environment.
+///
+/// Plugins come from [`PluginFactoryRegistry::shared`], where `export_plugin!`
+/// puts them.
 #[cfg(target_arch = "wasm32")]
 #[derive(Debug)]
 pub struct WasmPluginLoader {
     loaded_modules: Arc<RwLock<HashMap<String, String>>>, // Just track loaded plugin IDs
-    registered_plugins: Arc<Mutex<HashMap<String, PluginFactory>>>,
+    factories: PluginFactoryRegistry,
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -212,15 +736,9 @@ impl WasmPluginLoader {
     pub fn new() -> Self {
         Self {
             loaded_modules: Arc::new(RwLock::new(HashMap::new())),
-            registered_plugins: Arc::new(Mutex::new(HashMap::new())),
+            factories: PluginFactoryRegistry::shared(),
         }
     }
-
-    /// Register a plugin factory for WASM
-    pub async fn register_plugin_factory(&self, name: String, factory: PluginFactory) {
-        let mut registered = self.registered_plugins.lock().await;
-        registered.insert(name, factory);
-    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -234,9 +752,7 @@ impl Default for WasmPluginLoader {
 #[async_trait(?Send)]
 impl PluginLoader for WasmPluginLoader {
     async fn load_plugin(&self, installation: &PluginInstallation) -> Result<Box<dyn Plugin>> {
-        let registered = self.registered_plugins.lock().await;
-
-        if let Some(factory) = registered.get(&installation.id) {
+        if let Some(factory) = self.factories.get(&installation.id) {
             let plugin = factory();
 
             // Track the loaded plugin
@@ -253,9 +769,7 @@ impl PluginLoader for WasmPluginLoader {
     }
 
     async fn validate_plugin(&self, installation: &PluginInstallation) -> Result<ValidationResult> {
-        let registered = self.registered_plugins.lock().await;
-
-        if registered.contains_key(&installation.id) {
+        if self.factories.get(&installation.id).is_some() {
             Ok(ValidationResult {
                 is_valid: true,
                 errors: vec![],
@@ -310,7 +824,7 @@ impl PluginInstallationManager {
         let loader: Arc<dyn PluginLoader> = Arc::new(WasmPluginLoader::new());
 
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "plugin_installation_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "plugin_installation_manager"),
             installations: Arc::new(RwLock::new(HashMap::new())),
             plugin_loader: loader,
             plugins_directory,
@@ -325,7 +839,7 @@ impl PluginInstallationManager {
         filesystem_provider: Arc<dyn FileSystemProvider>,
     ) -> Self {
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "plugin_installation_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "plugin_installation_manager"),
             installations: Arc::new(RwLock::new(HashMap::new())),
             plugin_loader: loader,
             plugins_directory,
@@ -762,10 +1276,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_safe_plugin_loader() {
-        let loader = SafePluginLoader::new();
+        let loader = SafePluginLoader::with_factories(PluginFactoryRegistry::new());
 
         // Test that we can list available plugins (should be empty initially)
-        let available = loader.list_available_plugins().await;
+        let available = loader.list_available_plugins();
         assert!(available.is_empty());
 
         // Test validation without registered plugin
@@ -783,4 +1297,155 @@ mod tests {
         let validation = loader.validate_plugin(&installation).await.unwrap();
         assert!(!validation.is_valid);
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_failure(error: &Error) -> (PluginLoadFailure, Option<String>) {
+        match &error.kind {
+            crate::error::ErrorKind::PluginLoad {
+                failure, symbol, ..
+            } => (*failure, symbol.clone()),
+            other => panic!("expected a plugin load error, got {:?}", other),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    /// A system library that certainly does not export `create_plugin`
+    fn system_library() -> Option<PathBuf> {
+        [
+            "/lib/x86_64-linux-gnu/libc.so.6",
+            "/lib/aarch64-linux-gnu/libc.so.6",
+            "/usr/lib/x86_64-linux-gnu/libc.so.6",
+            "/usr/lib/libc.so.6",
+            "/lib64/libc.so.6",
+            "/usr/lib64/libc.so.6",
+            "/lib/libc.musl-x86_64.so.1",
+            "/usr/lib/libSystem.B.dylib",
+            "C:\\Windows\\System32\\kernel32.dll",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .find(|path| path.is_file())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_native_library_not_found() {
+        let temp_dir = TempDir::new().unwrap();
+        let error = NativePluginLibrary::open(temp_dir.path().join("missing.so")).unwrap_err();
+        assert_eq!(
+            load_failure(&error),
+            (PluginLoadFailure::LibraryNotFound, None)
+        );
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_native_library_invalid() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("not_a_library.so");
+        std::fs::write(&path, b"definitely not an object file").unwrap();
+
+        let error = NativePluginLibrary::open(&path).unwrap_err();
+        assert_eq!(
+            load_failure(&error),
+            (PluginLoadFailure::InvalidLibrary, None)
+        );
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[tokio::test]
+    async fn test_native_loader_opens_a_fresh_copy() {
+        use super::super::PluginLoader;
+
+        let temp_dir = TempDir::new().unwrap();
+        let staging = temp_dir.path().join("staging");
+        let loader = NativeLibraryLoader::with_staging_dir(&staging);
+
+        let missing = temp_dir.path().join("missing.so");
+        let error = loader
+            .load_plugin(&missing.to_string_lossy())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            load_failure(&error),
+            (PluginLoadFailure::LibraryNotFound, None)
+        );
+
+        let path = temp_dir.path().join("catalog.so");
+        std::fs::write(&path, b"definitely not an object file").unwrap();
+        let first = loader.stage(&path).unwrap();
+        let second = loader.stage(&path).unwrap();
+        assert_ne!(first, second);
+        assert!(first.starts_with(&staging));
+        assert_eq!(
+            std::fs::read(&second).unwrap(),
+            std::fs::read(&path).unwrap()
+        );
+
+        // A library that fails to open leaves no copy behind
+        let error = loader
+            .load_plugin(&path.to_string_lossy())
+            .await
+            .unwrap_err();
+        assert_eq!(
+            load_failure(&error),
+            (PluginLoadFailure::InvalidLibrary, None)
+        );
+        assert_eq!(std::fs::read_dir(&staging).unwrap().count(), 2);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_native_library_missing_exports() {
+        let path = system_library().expect("a system C library to open");
+
+        // The ABI stamp is checked before anything else is called
+        let library = Arc::new(NativePluginLibrary::open(&path).unwrap());
+        let error = library.create_plugin().unwrap_err();
+        assert_eq!(
+            load_failure(&error),
+            (
+                PluginLoadFailure::MissingSymbol,
+                Some(PLUGIN_ABI_VERSION_SYMBOL.to_string())
+            )
+        );
+        assert!(error.message.contains(&path.display().to_string()));
+
+        let error = library.plugin_info().unwrap_err();
+        assert_eq!(
+            load_failure(&error),
+            (
+                PluginLoadFailure::MissingSymbol,
+                Some(PLUGIN_ABI_VERSION_SYMBOL.to_string())
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_core_compatibility() {
+        let mut info = PluginInfo {
+            id: "versioned".to_string(),
+            name: "Versioned".to_string(),
+            version: "1.0.0".to_string(),
+            description: String::new(),
+            author: String::new(),
+            license: "MIT".to_string(),
+            homepage: None,
+            repository: None,
+            minimum_core_version: "0.0.1".to_string(),
+            supported_platforms: vec![crate::plugin::Platform::All],
+        };
+        assert!(check_core_compatibility(&info).is_ok());
+
+        info.minimum_core_version = crate::VERSION.to_string();
+        assert!(check_core_compatibility(&info).is_ok());
+
+        info.minimum_core_version = "999.0.0".to_string();
+        let error = check_core_compatibility(&info).unwrap_err();
+        assert!(error.message.contains("999.0.0"));
+        assert!(error.message.contains(crate::VERSION));
+
+        info.minimum_core_version = "not a version".to_string();
+        assert!(check_core_compatibility(&info).is_err());
+    }
 }
@@ -12,7 +12,7 @@ use super::{
     loader::{PluginInstallationManager, PluginStatus},
     manifest::PluginManifest,
     search::{SearchCoordinator, SearchProvider},
-    Plugin, PluginApiClient, PluginContext, PluginFileSystem,
+    DeclaredPermissions, Plugin, PluginApiClient, PluginContext, PluginFileSystem,
 };
 use crate::config::SettingsSchema;
 use crate::error::{Error, Result};
@@ -53,7 +53,7 @@ impl PluginManager {
     /// Create a new enhanced plugin manager
     pub fn new(plugins_directory: PathBuf) -> Self {
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "enhanced_plugin_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "enhanced_plugin_manager"),
             installation_manager: Arc::new(Mutex::new(PluginInstallationManager::new(
                 plugins_directory.clone(),
             ))),
@@ -187,6 +187,7 @@ impl PluginManager {
         if let Some(plugin_arc) = self.active_plugins.write().await.remove(plugin_id) {
             let mut plugin = plugin_arc.lock().await;
             plugin.shutdown().await?;
+            super::ReportScheduler::shared().cancel_plugin(plugin_id);
 
             // Update status
             let installation_manager = self.installation_manager.lock().await;
@@ -432,6 +433,9 @@ impl PluginManager {
             validation_rules: vec![],
         };
 
+        let components = crate::ui::UIComponentRegistry::shared().scoped(plugin_id.clone());
+        let reports = super::ReportScheduler::shared().scoped(plugin_id.clone());
+
         Ok(PluginContext {
             plugin_id,
             config,
@@ -439,6 +443,9 @@ impl PluginManager {
             event_bus,
             database,
             file_system,
+            components,
+            reports,
+            declared_permissions: DeclaredPermissions::new(manifest.get_required_permissions()),
         })
     }
 
@@ -1,28 +1,86 @@
 // src/plugin/mod.rs - Plugin system with hot-reloading and sandboxing
 
+mod assets;
+mod components;
+#[cfg(not(target_arch = "wasm32"))]
+mod compression;
+mod cors;
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+mod hot_reload;
 mod loader;
 mod manager;
 mod manifest;
+mod onboarding;
+mod payload;
+mod reports;
+mod router;
 mod sdk;
-mod search;
+pub mod search;
+mod stats;
+#[cfg(all(any(test, feature = "testing"), not(target_arch = "wasm32")))]
+pub mod testing;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::net::IpAddr;
 use std::sync::Arc;
-
-use crate::auth::{Permission, User};
-use crate::config::SettingsSchema;
+use std::time::Duration;
+
+use crate::auth::rate_limit::route_bucket_key;
+use crate::auth::{
+    AccountManager, Permission, RateDecision, RateLimitBackend, RateLimiterStore, TokenBucketLimit,
+    User,
+};
+use crate::config::{ConfigurationTier, SettingsSchema, TieredConfigManager};
 use crate::error::{Error, Result};
 use crate::event::{Event, EventBusManager};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::event::{EventPollQuery, EVENT_POLL_PATH};
 use crate::manager::{ManagedState, Manager, ManagerStatus, PlatformRequirements};
 use crate::platform::database::DatabaseArc;
 use crate::platform::filesystem::FileSystemArc;
+use crate::platform::storage::StorageArc;
+use crate::ui::{CommandRegistry, UIComponentRegistry};
+use crate::utils::{JsonLimits, Time};
 use async_trait::async_trait;
 use dioxus::prelude::*;
 use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
+pub use assets::{content_type_for, PluginAsset, PluginAssetServer, PLUGIN_ASSET_PREFIX};
+pub use components::{
+    PluginComponentHost, PluginComponents, ResolvedComponent, DEFAULT_PLUGIN_PAGE,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use compression::{compress_response, ContentEncoding, EncodedBody};
+pub use cors::{CorsPolicy, CORS_PREFLIGHT_MAX_AGE_SECS};
+#[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+pub use hot_reload::{is_plugin_artifact, PluginDirWatcher, HOT_RELOAD_DEBOUNCE};
+pub use loader::{
+    check_core_compatibility, PluginFactory, PluginFactoryRegistry, PluginStatus,
+    PLUGIN_ABI_VERSION,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use loader::{NativeLibraryLoader, NativePlugin};
+pub use onboarding::{
+    InstallRequest, OnboardingStep, PluginInstaller, PluginOnboarding, SettingField, SettingKind,
+};
+pub use payload::{
+    EventPayloadGuard, PayloadRejection, DEFAULT_MAX_EVENT_PAYLOAD_BYTES, EVENT_REJECTED_METRIC,
+};
+pub use reports::{
+    CronSchedule, PluginReports, Report, ReportDeliveredEvent, ReportFuture, ReportGenerator,
+    ReportSchedule, ReportScheduler, PLUGIN_REPORT_DELIVERED,
+};
+pub use router::{ApiRouter, RouteMatch};
+pub use search::{
+    ProviderHealth, SearchProvider, SearchProviderChange, SearchProviderEvent,
+    SEARCH_PROVIDER_REGISTERED, SEARCH_PROVIDER_UNREGISTERED,
+};
+pub use stats::{PluginRuntimeCounters, PluginRuntimeStats, PluginRuntimeTracker};
+
 /// Plugin information structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PluginInfo {
     pub id: String,
     pub name: String,
@@ -86,42 +144,208 @@ pub enum ValidationType {
     Custom(String),
 }
 
-/// UI component provided by a plugin
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Renders a plugin component from its props
+pub type ComponentRenderFn = Arc<dyn Fn(&serde_json::Value) -> Element + Send + Sync>;
+
+/// UI component provided by a plugin.
+///
+/// Components are declarative (`props` interpreted by the host) and may also
+/// carry a `render_fn` the host calls to draw them. The render function is
+/// never serialized.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct UIComponent {
     pub id: String,
     pub name: String,
-    pub component_type: ComponentType,
+    pub component_type: UIComponentType,
+    /// Route the component is mounted at, for pages
+    #[serde(default)]
+    pub route: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
     pub props: serde_json::Value,
+    #[serde(default)]
     pub required_permissions: Vec<Permission>,
+    #[serde(skip)]
+    pub render_fn: Option<ComponentRenderFn>,
+}
+
+impl std::fmt::Debug for UIComponent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UIComponent")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("component_type", &self.component_type)
+            .field("route", &self.route)
+            .field("icon", &self.icon)
+            .field("props", &self.props)
+            .field("required_permissions", &self.required_permissions)
+            .field("render_fn", &self.render_fn.is_some())
+            .finish()
+    }
+}
+
+impl UIComponent {
+    /// Creates a component with empty props and no render function
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        component_type: UIComponentType,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            component_type,
+            route: None,
+            icon: None,
+            props: serde_json::Value::Object(serde_json::Map::new()),
+            required_permissions: Vec::new(),
+            render_fn: None,
+        }
+    }
+
+    pub fn with_route(mut self, route: impl Into<String>) -> Self {
+        self.route = Some(route.into());
+        self
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn with_props(mut self, props: serde_json::Value) -> Self {
+        self.props = props;
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<Permission>) -> Self {
+        self.required_permissions = permissions;
+        self
+    }
+
+    pub fn with_render_fn(
+        mut self,
+        render: impl Fn(&serde_json::Value) -> Element + Send + Sync + 'static,
+    ) -> Self {
+        self.render_fn = Some(Arc::new(render));
+        self
+    }
+
+    /// Render with the component's own props, if it has a render function
+    pub fn render(&self) -> Option<Element> {
+        self.render_fn.as_ref().map(|render| render(&self.props))
+    }
 }
 
 /// Types of UI components
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub enum ComponentType {
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UIComponentType {
     Page,
     Widget,
     Modal,
-    Sidebar,
+    #[serde(alias = "Sidebar")]
+    SidebarPanel,
     Header,
     Footer,
     Menu,
     Form,
 }
 
+/// Former name of [`UIComponentType`]
+#[deprecated(note = "use UIComponentType; `Sidebar` is now `SidebarPanel`")]
+pub type ComponentType = UIComponentType;
+
 /// Menu item for navigation
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MenuItem {
     pub id: String,
     pub label: String,
+    #[serde(default)]
     pub icon: Option<String>,
+    #[serde(default)]
     pub route: Option<String>,
+    #[serde(default)]
     pub action: Option<String>,
+    #[serde(default)]
     pub required_permissions: Vec<Permission>,
+    #[serde(default)]
     pub order: i32,
+    #[serde(default)]
     pub children: Vec<MenuItem>,
 }
 
+impl MenuItem {
+    /// Starts a menu item with only an ID and label set
+    pub fn builder(id: impl Into<String>, label: impl Into<String>) -> MenuItemBuilder {
+        MenuItemBuilder {
+            item: MenuItem {
+                id: id.into(),
+                label: label.into(),
+                icon: None,
+                route: None,
+                action: None,
+                required_permissions: Vec::new(),
+                order: 0,
+                children: Vec::new(),
+            },
+        }
+    }
+}
+
+/// Builder for [`MenuItem`], created with [`MenuItem::builder`]
+#[derive(Debug, Clone)]
+pub struct MenuItemBuilder {
+    item: MenuItem,
+}
+
+impl MenuItemBuilder {
+    /// Set the icon name
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.item.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the route navigated to when the item is selected
+    pub fn route(mut self, route: impl Into<String>) -> Self {
+        self.item.route = Some(route.into());
+        self
+    }
+
+    /// Set the action ID invoked when the item is selected
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.item.action = Some(action.into());
+        self
+    }
+
+    /// Add a global permission required to see the item
+    pub fn permission(mut self, resource: &str, action: &str) -> Self {
+        self.item.required_permissions.push(Permission {
+            resource: resource.to_string(),
+            action: action.to_string(),
+            scope: crate::auth::PermissionScope::Global,
+        });
+        self
+    }
+
+    /// Set the sort order among siblings
+    pub fn order(mut self, order: i32) -> Self {
+        self.item.order = order;
+        self
+    }
+
+    /// Add a child item
+    pub fn child(mut self, child: MenuItem) -> Self {
+        self.item.children.push(child);
+        self
+    }
+
+    /// Finish building the item
+    pub fn build(self) -> MenuItem {
+        self.item
+    }
+}
+
 /// API route definition
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiRoute {
@@ -130,28 +354,113 @@ pub struct ApiRoute {
     pub handler_id: String,
     pub required_permissions: Vec<Permission>,
     pub rate_limit: Option<RateLimit>,
+    /// Overrides the server-wide request timeout for this route
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    /// Overrides the server-wide request body limit for this route, in bytes
+    #[serde(default)]
+    pub max_request_size: Option<u64>,
     pub documentation: ApiDocumentation,
 }
 
 /// HTTP methods
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Serialized in upper case as on the wire; the capitalized names written by
+/// older plugin manifests (`"Get"`) are still accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum HttpMethod {
-    GET,
-    POST,
-    PUT,
-    DELETE,
-    PATCH,
-    HEAD,
-    OPTIONS,
+    #[serde(rename = "GET", alias = "Get")]
+    Get,
+    #[serde(rename = "POST", alias = "Post")]
+    Post,
+    #[serde(rename = "PUT", alias = "Put")]
+    Put,
+    #[serde(rename = "DELETE", alias = "Delete")]
+    Delete,
+    #[serde(rename = "PATCH", alias = "Patch")]
+    Patch,
+    #[serde(rename = "HEAD", alias = "Head")]
+    Head,
+    #[serde(rename = "OPTIONS", alias = "Options")]
+    Options,
+}
+
+impl HttpMethod {
+    pub const ALL: [Self; 7] = [
+        Self::Get,
+        Self::Post,
+        Self::Put,
+        Self::Delete,
+        Self::Patch,
+        Self::Head,
+        Self::Options,
+    ];
+
+    /// The method's name as sent in a request line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Put => "PUT",
+            Self::Delete => "DELETE",
+            Self::Patch => "PATCH",
+            Self::Head => "HEAD",
+            Self::Options => "OPTIONS",
+        }
+    }
+
+    /// Whether a raw request method names this method, ignoring case
+    pub fn matches(&self, method: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(method.trim())
+    }
+}
+
+impl std::fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for HttpMethod {
+    type Err = Error;
+
+    /// Parses a request method in any case, e.g. `get` or `GET`
+    fn from_str(method: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|candidate| candidate.matches(method))
+            .ok_or_else(|| {
+                Error::new(
+                    crate::error::ErrorKind::Validation {
+                        field: Some("method".to_string()),
+                        rules: vec![format!(
+                            "one_of={}",
+                            Self::ALL.map(|m| m.as_str()).join(",")
+                        )],
+                    },
+                    format!("Unsupported HTTP method '{}'", method),
+                )
+            })
+    }
 }
 
 /// Rate limiting configuration
+///
+/// Enforced as a token bucket per route and client: up to `burst_limit`
+/// requests at once, refilled at `requests_per_minute`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RateLimit {
     pub requests_per_minute: u32,
     pub burst_limit: u32,
 }
 
+impl RateLimit {
+    /// Bucket parameters for this limit; a zero burst still allows one request
+    pub fn token_bucket(&self) -> TokenBucketLimit {
+        TokenBucketLimit::per_minute(self.burst_limit.max(1), self.requests_per_minute)
+    }
+}
+
 /// API documentation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiDocumentation {
@@ -196,6 +505,87 @@ pub struct ApiResponse {
     pub status_code: u16,
     pub description: String,
     pub schema: Option<serde_json::Value>,
+    /// Caching directive the HTTP server turns into `Cache-Control`/`ETag`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CacheDirective>,
+    /// Seconds the client should wait before retrying, sent as `Retry-After`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after_secs: Option<u64>,
+    /// Extra headers set by the manager, such as CORS
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<(String, String)>,
+}
+
+impl ApiResponse {
+    /// Attach a caching directive to the response
+    pub fn with_cache(mut self, cache: CacheDirective) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Weak ETag over the response body, present only for cacheable responses
+    pub fn etag(&self) -> Option<String> {
+        match (&self.cache, &self.schema) {
+            (Some(CacheDirective::MaxAge(_)), Some(body)) => {
+                // FNV-1a keeps the tag stable across builds and platforms
+                let hash = body
+                    .to_string()
+                    .bytes()
+                    .fold(0xcbf29ce484222325_u64, |h, b| {
+                        (h ^ u64::from(b)).wrapping_mul(0x100000001b3)
+                    });
+                Some(format!("W/\"{:016x}\"", hash))
+            }
+            _ => None,
+        }
+    }
+
+    /// JSON body compressed for a client's `Accept-Encoding`, per the network config
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn encode_body(
+        &self,
+        accept_encoding: Option<&str>,
+        config: &crate::config::CompressionConfig,
+    ) -> Result<EncodedBody> {
+        let body = match &self.schema {
+            Some(body) => serde_json::to_vec(body)
+                .map_err(|e| Error::new(crate::error::ErrorKind::Serialization, e.to_string()))?,
+            None => Vec::new(),
+        };
+        compress_response(body, "application/json", accept_encoding, config)
+    }
+
+    /// Headers the HTTP server should send along with the body
+    pub fn http_headers(&self) -> Vec<(String, String)> {
+        let mut headers = Vec::new();
+        if let Some(cache) = &self.cache {
+            headers.push(("Cache-Control".to_string(), cache.header_value()));
+        }
+        if let Some(etag) = self.etag() {
+            headers.push(("ETag".to_string(), etag));
+        }
+        headers.extend(self.headers.iter().cloned());
+        headers
+    }
+}
+
+/// How downstream HTTP caches may store an API response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CacheDirective {
+    /// Cacheable for the given number of seconds
+    MaxAge(u64),
+    /// Must not be stored, e.g. responses to mutations
+    NoStore,
+}
+
+impl CacheDirective {
+    /// Value of the `Cache-Control` header
+    pub fn header_value(&self) -> String {
+        match self {
+            Self::MaxAge(secs) => format!("max-age={}", secs),
+            Self::NoStore => "no-store".to_string(),
+        }
+    }
 }
 
 /// Event handler registration
@@ -203,9 +593,178 @@ pub struct ApiResponse {
 pub struct EventHandler {
     pub event_type: String,
     pub handler_id: String,
+    /// Handlers with a higher priority run first, across all plugins
     pub priority: i32,
 }
 
+impl EventHandler {
+    /// Whether the handler is registered for `event_type`, directly or via `*`
+    pub fn handles(&self, event_type: &str) -> bool {
+        self.event_type == event_type || self.event_type == "*"
+    }
+}
+
+/// Whether an event is passed on to lower-priority handlers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EventPropagation {
+    #[default]
+    Continue,
+    /// The handler consumed the event
+    Stop,
+}
+
+/// The permissions a plugin declared through `required_permissions()`.
+///
+/// Shared by the plugin's context and its sandboxed wrappers, which refuse
+/// permission checks, queries and file access the plugin never declared.
+/// Declarations follow a [`PermissionHierarchy`](crate::auth::PermissionHierarchy),
+/// the default one unless [`with_hierarchy`](Self::with_hierarchy) supplies the
+/// configured one, so declaring `database.write` also covers `database.read`.
+#[derive(Debug, Clone, Default)]
+pub struct DeclaredPermissions {
+    permissions: Arc<Vec<Permission>>,
+    hierarchy: Arc<crate::auth::PermissionHierarchy>,
+}
+
+impl DeclaredPermissions {
+    pub fn new(permissions: Vec<Permission>) -> Self {
+        Self {
+            permissions: Arc::new(permissions),
+            hierarchy: Arc::default(),
+        }
+    }
+
+    /// Evaluate declarations with `hierarchy` instead of the default one
+    pub fn with_hierarchy(mut self, hierarchy: crate::auth::PermissionHierarchy) -> Self {
+        self.hierarchy = Arc::new(hierarchy);
+        self
+    }
+
+    /// Whether a declared permission covers `action` on `resource`
+    pub fn allows(&self, resource: &str, action: &str) -> bool {
+        self.permissions
+            .iter()
+            .any(|permission| self.hierarchy.grants(permission, resource, action))
+    }
+
+    /// Fails with a permission error naming `resource.action` unless it was declared
+    pub fn require(&self, plugin_id: &str, resource: &str, action: &str) -> Result<()> {
+        if self.allows(resource, action) {
+            return Ok(());
+        }
+        Err(Error::permission(
+            format!("{}.{}", resource, action),
+            format!(
+                "Plugin {} did not declare permission {}.{}",
+                plugin_id, resource, action
+            ),
+        ))
+    }
+
+    pub fn permissions(&self) -> &[Permission] {
+        &self.permissions
+    }
+}
+
+/// Checks `resource.action` against the declarations a sandboxed wrapper was
+/// given; wrappers built without any are unrestricted
+fn require_declared(
+    declared: &Option<DeclaredPermissions>,
+    plugin_id: &str,
+    resource: &str,
+    action: &str,
+) -> Result<()> {
+    match declared {
+        Some(declared) => declared.require(plugin_id, resource, action),
+        None => Ok(()),
+    }
+}
+
+/// Metadata key naming the plugin that published an event through its context
+pub const SOURCE_PLUGIN_METADATA_KEY: &str = "source_plugin";
+
+/// An event published through [`PluginContext::publish`], tagged with the
+/// publishing plugin.
+///
+/// The type, payload and priority are those of the wrapped event, and
+/// `as_any` exposes it, so subscribers downcast to the original type as usual.
+/// `source()` is the plugin ID, which is also stored in the metadata under
+/// [`SOURCE_PLUGIN_METADATA_KEY`].
+#[derive(Debug)]
+pub struct PluginPublishedEvent<E> {
+    plugin_id: String,
+    metadata: crate::types::Metadata,
+    event: E,
+}
+
+impl<E: Event> PluginPublishedEvent<E> {
+    pub fn new(plugin_id: impl Into<String>, event: E) -> Self {
+        let plugin_id = plugin_id.into();
+        let mut metadata = event.metadata().clone();
+        metadata.insert(
+            SOURCE_PLUGIN_METADATA_KEY.to_string(),
+            serde_json::Value::String(plugin_id.clone()),
+        );
+        Self {
+            plugin_id,
+            metadata,
+            event,
+        }
+    }
+
+    /// The plugin that published the event
+    pub fn plugin_id(&self) -> &str {
+        &self.plugin_id
+    }
+
+    /// The event as the plugin published it
+    pub fn inner(&self) -> &E {
+        &self.event
+    }
+}
+
+impl<E: Event + 'static> Event for PluginPublishedEvent<E> {
+    fn event_type(&self) -> &'static str {
+        self.event.event_type()
+    }
+
+    fn source(&self) -> &str {
+        &self.plugin_id
+    }
+
+    fn metadata(&self) -> &crate::types::Metadata {
+        &self.metadata
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self.event.as_any()
+    }
+
+    fn timestamp(&self) -> chrono::DateTime<chrono::Utc> {
+        self.event.timestamp()
+    }
+
+    fn priority(&self) -> crate::event::EventPriority {
+        self.event.priority()
+    }
+
+    fn should_persist(&self) -> bool {
+        self.event.should_persist()
+    }
+
+    fn payload(&self) -> Option<serde_json::Value> {
+        self.event.payload()
+    }
+}
+
+/// The plugin that published `event` through its context, if any
+pub fn event_source_plugin(event: &dyn Event) -> Option<&str> {
+    event
+        .metadata()
+        .get(SOURCE_PLUGIN_METADATA_KEY)
+        .and_then(|v| v.as_str())
+}
+
 /// Plugin execution context
 #[derive(Clone, Debug)]
 pub struct PluginContext {
@@ -215,43 +774,303 @@ pub struct PluginContext {
     pub event_bus: Arc<EventBusManager>,
     pub database: Option<PluginDatabase>,
     pub file_system: PluginFileSystem,
+    /// Where the plugin registers native render functions during `initialize`
+    pub components: PluginComponents,
+    /// Recurring reports, cancelled when the plugin shuts down
+    pub reports: PluginReports,
+    /// What the plugin declared it needs; anything else is refused at runtime
+    pub declared_permissions: DeclaredPermissions,
+}
+
+impl PluginContext {
+    /// Publish `event` on the platform event bus for other plugins and the core.
+    ///
+    /// The event is tagged with this plugin's ID, see [`PluginPublishedEvent`].
+    pub async fn publish<E: Event + 'static>(&self, event: E) -> Result<()> {
+        self.event_bus
+            .publish(PluginPublishedEvent::new(&self.plugin_id, event))
+            .await
+    }
+
+    /// Receive every bus event whose type starts with `prefix`, such as
+    /// `"inventory."`.
+    ///
+    /// Use [`event_source_plugin`] to tell which plugin published an event.
+    /// Dropping the receiver ends the subscription.
+    pub async fn subscribe(
+        &self,
+        prefix: &str,
+    ) -> Result<tokio::sync::mpsc::UnboundedReceiver<Arc<dyn Event>>> {
+        self.event_bus.subscribe_filtered(prefix).await
+    }
+
+    /// Whether the plugin declared a permission covering `action` on `resource`
+    pub fn has_declared_permission(&self, resource: &str, action: &str) -> bool {
+        self.declared_permissions.allows(resource, action)
+    }
+
+    /// Run `generator` on `schedule` and deliver each result as a
+    /// [`ReportDeliveredEvent`] to every active user granted `permission`.
+    ///
+    /// Returns the report ID. Users who turned notifications off are skipped.
+    pub fn register_report<F, Fut>(
+        &self,
+        schedule: ReportSchedule,
+        permission: Permission,
+        generator: F,
+    ) -> Result<Uuid>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<Report>> + Send + 'static,
+    {
+        let accounts = self.api_client.accounts.clone().ok_or_else(|| {
+            Error::plugin(
+                &self.plugin_id,
+                "Scheduled reports need an account manager to resolve recipients",
+            )
+        })?;
+        let generator: ReportGenerator = Arc::new(move || Box::pin(generator()));
+
+        self.reports.register(
+            schedule,
+            reports::ReportDelivery {
+                accounts,
+                event_bus: Arc::clone(&self.event_bus),
+                permission,
+            },
+            generator,
+        )
+    }
 }
 
+/// Key prefix for plugin runtime state in the platform key-value store
+pub const PLUGIN_STATE_PREFIX: &str = "plugin_state:";
+
+/// Config section holding each plugin's settings, apart from the `plugins`
+/// section the plugin manager itself reads
+pub const PLUGIN_SETTINGS_NAMESPACE: &str = "plugin_settings";
+
 /// API client for plugin to core communication
 #[derive(Debug, Clone)]
 pub struct PluginApiClient {
-    #[allow(dead_code)]
     plugin_id: String,
+    config: Option<Arc<RwLock<TieredConfigManager>>>,
+    accounts: Option<Arc<RwLock<AccountManager>>>,
+    state: Option<StorageArc>,
+    settings_schema: Option<SettingsSchema>,
+    declared: Option<DeclaredPermissions>,
 }
 
 impl PluginApiClient {
     /// Create a new API client for a plugin
     pub fn new(plugin_id: String) -> Self {
-        Self { plugin_id }
+        Self {
+            plugin_id,
+            config: None,
+            accounts: None,
+            state: None,
+            settings_schema: None,
+            declared: None,
+        }
+    }
+
+    /// Refuse permission checks outside what the plugin declared
+    pub fn with_declared_permissions(mut self, declared: DeclaredPermissions) -> Self {
+        self.declared = Some(declared);
+        self
+    }
+
+    /// Validate `set_config` values against the plugin's settings schema
+    pub fn with_settings_schema(mut self, schema: SettingsSchema) -> Self {
+        self.settings_schema = Some(schema);
+        self
+    }
+
+    /// Back configuration calls with a config manager.
+    ///
+    /// Keys are namespaced under [`PLUGIN_SETTINGS_NAMESPACE`]`.<plugin_id>`
+    /// and written to the user tier.
+    pub fn with_config(mut self, config: Arc<RwLock<TieredConfigManager>>) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Back user and permission calls with an account manager
+    pub fn with_account_manager(mut self, accounts: Arc<RwLock<AccountManager>>) -> Self {
+        self.accounts = Some(accounts);
+        self
+    }
+
+    /// Back `state_get`/`state_set` with a key-value store.
+    ///
+    /// Keys are namespaced per plugin and never show up in settings.
+    pub fn with_state_store(mut self, state: StorageArc) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Validate one setting, ignoring problems with settings other than `key`
+    fn check_setting(
+        &self,
+        schema: &SettingsSchema,
+        key: &str,
+        value: &serde_json::Value,
+    ) -> Result<()> {
+        let settings = key.rsplit('.').fold(
+            value.clone(),
+            |inner, segment| serde_json::json!({ segment: inner }),
+        );
+        let nested = format!("{}.", key);
+        let errors: Vec<String> = schema
+            .validate(&settings)
+            .into_iter()
+            .filter(|e| e.key == key || e.key.starts_with(&nested))
+            .map(|e| e.to_string())
+            .collect();
+        if errors.is_empty() {
+            return Ok(());
+        }
+
+        Err(Error::new(
+            crate::error::ErrorKind::Configuration {
+                key: Some(key.to_string()),
+                validation_errors: errors.clone(),
+            },
+            format!("Invalid setting '{}': {}", key, errors.join("; ")),
+        )
+        .plugin_id(&self.plugin_id))
+    }
+
+    /// Resolve `key` inside the plugin's namespace, rejecting empty segments
+    fn config_key(&self, key: &str) -> Result<String> {
+        if key.split('.').any(|segment| segment.trim().is_empty()) {
+            return Err(Error::plugin(
+                &self.plugin_id,
+                format!("Invalid configuration key '{}'", key),
+            ));
+        }
+        Ok(format!(
+            "{}.{}.{}",
+            PLUGIN_SETTINGS_NAMESPACE, self.plugin_id, key
+        ))
+    }
+
+    /// Resolve a state key inside the plugin's namespace.
+    ///
+    /// The ID length keeps namespaces apart even when a store flattens
+    /// separators, e.g. `a_b` + `c` versus `a` + `b_c`.
+    fn state_key(&self, key: &str) -> Result<String> {
+        if key.trim().is_empty() {
+            return Err(Error::plugin(
+                &self.plugin_id,
+                "State key must not be empty",
+            ));
+        }
+        Ok(format!(
+            "{}{}:{}:{}",
+            PLUGIN_STATE_PREFIX,
+            self.plugin_id.len(),
+            self.plugin_id,
+            key
+        ))
     }
 
     /// Get a configuration value
-    pub async fn get_config(&self, _key: &str) -> Result<Option<serde_json::Value>> {
-        // Implementation would call core config system
-        Ok(None)
+    pub async fn get_config(&self, key: &str) -> Result<Option<serde_json::Value>> {
+        let key = self.config_key(key)?;
+        match &self.config {
+            Some(config) => Ok(config.read().await.get(&key).await.ok().flatten()),
+            None => Ok(None),
+        }
     }
 
-    /// Set a configuration value
-    pub async fn set_config(&self, _key: &str, _value: serde_json::Value) -> Result<()> {
-        // Implementation would call core config system
-        Ok(())
+    /// Set a configuration value in the plugin's own namespace.
+    ///
+    /// With a settings schema the value is checked against it first and
+    /// refused with the field-level messages if it does not conform.
+    pub async fn set_config(&self, key: &str, value: serde_json::Value) -> Result<()> {
+        let namespaced = self.config_key(key)?;
+        if let Some(schema) = &self.settings_schema {
+            self.check_setting(schema, key, &value)?;
+        }
+        match &self.config {
+            Some(config) => {
+                config
+                    .write()
+                    .await
+                    .set(&namespaced, value, ConfigurationTier::User)
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Remove a configuration value from the plugin's own namespace
+    pub async fn remove_config(&self, key: &str) -> Result<()> {
+        let namespaced = self.config_key(key)?;
+        match &self.config {
+            Some(config) => {
+                config
+                    .read()
+                    .await
+                    .delete(&namespaced, ConfigurationTier::User)
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Get a value from the plugin's private runtime state
+    pub async fn state_get(&self, key: &str) -> Result<Option<serde_json::Value>> {
+        let key = self.state_key(key)?;
+        let Some(state) = &self.state else {
+            return Ok(None);
+        };
+
+        match state.get(&key).await? {
+            Some(bytes) => serde_json::from_slice(&bytes).map(Some).map_err(|e| {
+                Error::plugin(
+                    &self.plugin_id,
+                    format!("Corrupt state value for '{}': {}", key, e),
+                )
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Store a value in the plugin's private runtime state, e.g. a sync cursor or ETag
+    pub async fn state_set(&self, key: &str, value: serde_json::Value) -> Result<()> {
+        let key = self.state_key(key)?;
+        match &self.state {
+            Some(state) => state.set(&key, value.to_string().as_bytes()).await,
+            None => Ok(()),
+        }
     }
 
     /// Get the current user
     pub async fn get_current_user(&self) -> Result<Option<User>> {
-        // Implementation would call account manager
-        Ok(None)
+        match &self.accounts {
+            Some(accounts) => Ok(accounts.read().await.current_user().await),
+            None => Ok(None),
+        }
     }
 
-    /// Check if current user has permission
-    pub async fn check_permission(&self, _resource: &str, _action: &str) -> Result<bool> {
-        // Implementation would call account manager
-        Ok(false)
+    /// Check if current user has permission.
+    ///
+    /// Fails if the plugin did not declare the permission itself, so it cannot
+    /// probe resources it never asked for.
+    pub async fn check_permission(&self, resource: &str, action: &str) -> Result<bool> {
+        require_declared(&self.declared, &self.plugin_id, resource, action)?;
+        match &self.accounts {
+            Some(accounts) => {
+                accounts
+                    .read()
+                    .await
+                    .check_current_user_permission(resource, action)
+                    .await
+            }
+            None => Ok(false),
+        }
     }
 }
 
@@ -261,6 +1080,19 @@ pub struct PluginDatabase {
     plugin_id: String,
     provider: DatabaseArc,
     permissions: DatabasePermissions,
+    usage: Arc<std::sync::Mutex<DatabaseUsage>>,
+    declared: Option<DeclaredPermissions>,
+}
+
+/// How long a storage estimate is trusted before it is measured again
+pub const STORAGE_ESTIMATE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Cached quota measurements for a plugin's tables
+#[derive(Debug, Default)]
+struct DatabaseUsage {
+    table_count: Option<u32>,
+    /// Estimated bytes and when they were measured, in milliseconds
+    storage: Option<(u64, u64)>,
 }
 
 /// Database permissions for plugins
@@ -280,34 +1112,114 @@ impl PluginDatabase {
             plugin_id,
             provider,
             permissions,
+            usage: Arc::new(std::sync::Mutex::new(DatabaseUsage::default())),
+            declared: None,
         }
     }
 
+    /// Refuse statements needing `database` actions the plugin did not declare:
+    /// `read` for queries, `write` for other DML and `manage` for DDL
+    pub fn with_declared_permissions(mut self, declared: DeclaredPermissions) -> Self {
+        self.declared = Some(declared);
+        self
+    }
+
     /// Execute a database query with permission checks
     pub async fn execute(
         &self,
         query: &str,
         params: &[serde_json::Value],
     ) -> Result<crate::platform::database::QueryResult> {
-        // Check permissions before executing
-        if query.to_uppercase().contains("CREATE TABLE") && !self.permissions.can_create_tables {
-            return Err(Error::permission(
-                "database.create_table",
-                "Plugin not allowed to create tables",
-            ));
+        let statement = self.check_statement(query)?;
+        let is_ddl = statement.action() == "manage";
+
+        if let Some(max_tables) = self.permissions.max_table_count {
+            if statement.is_table_ddl("CREATE")
+                && self
+                    .table_count()
+                    .await?
+                    .is_some_and(|count| count >= max_tables)
+            {
+                return Err(Error::permission(
+                    "database.max_table_count",
+                    format!(
+                        "Plugin {} has reached its limit of {} tables",
+                        self.plugin_id, max_tables
+                    ),
+                ));
+            }
         }
 
-        if query.to_uppercase().contains("DROP TABLE") && !self.permissions.can_drop_tables {
-            return Err(Error::permission(
-                "database.drop_table",
-                "Plugin not allowed to drop tables",
-            ));
+        if let Some(max_bytes) = self.permissions.max_storage_size {
+            if statement.action() == "write" {
+                let param_bytes: usize = params.iter().map(|p| p.to_string().len()).sum();
+                let write_bytes = (query.len() + param_bytes) as u64;
+                if self
+                    .storage_used()
+                    .await?
+                    .is_some_and(|used| used.saturating_add(write_bytes) > max_bytes)
+                {
+                    return Err(Error::permission(
+                        "database.max_storage_size",
+                        format!(
+                            "Plugin {} would exceed its storage limit of {} bytes",
+                            self.plugin_id, max_bytes
+                        ),
+                    ));
+                }
+            }
         }
 
         // Add plugin prefix to table names to isolate data
-        let prefixed_query = self.add_table_prefix(query);
+        let prefixed_query = self.add_table_prefix(query)?;
+        let result = self.provider.execute(&prefixed_query, params).await;
+
+        if is_ddl {
+            *self.usage() = DatabaseUsage::default();
+        }
+
+        result
+    }
 
-        self.provider.execute(&prefixed_query, params).await
+    /// Number of tables owned by the plugin, cached until the next DDL
+    /// statement; `None` when the database backend can't count them
+    pub async fn table_count(&self) -> Result<Option<u32>> {
+        if let Some(count) = self.usage().table_count {
+            return Ok(Some(count));
+        }
+
+        let prefix = plugin_table_prefix(&self.plugin_id);
+        let Some(count) = self.provider.table_count(&prefix).await? else {
+            return Ok(None);
+        };
+        let count = u32::try_from(count).unwrap_or(u32::MAX);
+
+        self.usage().table_count = Some(count);
+        Ok(Some(count))
+    }
+
+    /// Estimated bytes used by the plugin's tables, re-measured every
+    /// [`STORAGE_ESTIMATE_INTERVAL`] and after DDL statements; `None` when
+    /// the database backend can't measure them
+    pub async fn storage_used(&self) -> Result<Option<u64>> {
+        let now = Time::now_millis();
+        if let Some((bytes, measured_at)) = self.usage().storage {
+            if now.saturating_sub(measured_at) < STORAGE_ESTIMATE_INTERVAL.as_millis() as u64 {
+                return Ok(Some(bytes));
+            }
+        }
+
+        let prefix = plugin_table_prefix(&self.plugin_id);
+        let Some(bytes) = self.provider.storage_used(&prefix).await? else {
+            return Ok(None);
+        };
+
+        self.usage().storage = Some((bytes, now));
+        Ok(Some(bytes))
+    }
+
+    fn usage(&self) -> std::sync::MutexGuard<'_, DatabaseUsage> {
+        self.usage.lock().unwrap_or_else(|e| e.into_inner())
     }
 
     /// Query the database with permission checks
@@ -316,23 +1228,613 @@ impl PluginDatabase {
         query: &str,
         params: &[serde_json::Value],
     ) -> Result<Vec<crate::platform::database::Row>> {
-        let prefixed_query = self.add_table_prefix(query);
+        self.check_statement(query)?;
+        let prefixed_query = self.add_table_prefix(query)?;
         self.provider.query(&prefixed_query, params).await
     }
 
-    fn add_table_prefix(&self, query: &str) -> String {
-        // Simple implementation - in practice would need proper SQL parsing
-        query.replace("TABLE ", &format!("TABLE plugin_{}_ ", self.plugin_id))
-    }
-}
-
-/// File system access for plugins with sandboxing
-#[derive(Clone, Debug)]
+    /// Refuses batches of statements, statements the table prefixing doesn't
+    /// understand, statements needing an undeclared `database` action and
+    /// schema changes the plugin's [`DatabasePermissions`] don't allow
+    fn check_statement(&self, query: &str) -> Result<SqlStatement> {
+        let statement = SqlStatement::parse(query).ok_or_else(|| {
+            Error::permission(
+                "database.statement",
+                format!(
+                    "Plugin {} may only run one SQL statement at a time",
+                    self.plugin_id
+                ),
+            )
+        })?;
+        if !statement.is_supported() {
+            return Err(Error::permission(
+                "database.statement",
+                format!(
+                    "Plugin {} may not run this kind of SQL statement",
+                    self.plugin_id
+                ),
+            ));
+        }
+        require_declared(
+            &self.declared,
+            &self.plugin_id,
+            "database",
+            statement.action(),
+        )?;
+
+        if statement.action() == "manage" {
+            let (allowed, permission, message) = if statement.is_table_ddl("CREATE") {
+                (
+                    self.permissions.can_create_tables,
+                    "database.create_table",
+                    "Plugin not allowed to create tables",
+                )
+            } else if statement.is_table_ddl("DROP") {
+                (
+                    self.permissions.can_drop_tables,
+                    "database.drop_table",
+                    "Plugin not allowed to drop tables",
+                )
+            } else {
+                (
+                    self.permissions.can_modify_schema,
+                    "database.modify_schema",
+                    "Plugin not allowed to modify the schema",
+                )
+            };
+            if !allowed {
+                return Err(Error::permission(permission, message));
+            }
+        }
+
+        Ok(statement)
+    }
+
+    fn add_table_prefix(&self, query: &str) -> Result<String> {
+        prefix_table_names(query, &plugin_table_prefix(&self.plugin_id)).ok_or_else(|| {
+            Error::permission(
+                "database.statement",
+                format!(
+                    "Plugin {} may only name its own tables, without a schema",
+                    self.plugin_id
+                ),
+            )
+        })
+    }
+}
+
+/// The upper-cased words and symbols of a single SQL statement, without
+/// comments, string literals or quoted identifiers
+#[derive(Debug)]
+struct SqlStatement {
+    tokens: Vec<String>,
+}
+
+impl SqlStatement {
+    /// Tokenizes `query`, or returns `None` if it holds more than one statement
+    fn parse(query: &str) -> Option<Self> {
+        let chars: Vec<char> = query.chars().collect();
+        let mut tokens = Vec::new();
+        let mut terminated = false;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let (end, token) = match c {
+                '\'' | '"' | '`' | '[' => {
+                    let end = if c == '[' {
+                        chars[i..]
+                            .iter()
+                            .position(|&c| c == ']')
+                            .map_or(chars.len(), |p| i + p + 1)
+                    } else {
+                        quoted_end(&chars, i)
+                    };
+                    (end, Some("?".to_string()))
+                }
+                '-' if chars.get(i + 1) == Some(&'-') => {
+                    let end = chars[i..]
+                        .iter()
+                        .position(|&c| c == '\n')
+                        .map_or(chars.len(), |p| i + p);
+                    (end, None)
+                }
+                '/' if chars.get(i + 1) == Some(&'*') => {
+                    let end = chars[i + 2..]
+                        .windows(2)
+                        .position(|w| w == ['*', '/'])
+                        .map_or(chars.len(), |p| i + 2 + p + 2);
+                    (end, None)
+                }
+                c if c.is_whitespace() => (i + 1, None),
+                c if c.is_alphanumeric() || c == '_' => {
+                    let mut end = i;
+                    while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+                        end += 1;
+                    }
+                    let word: String = chars[i..end].iter().collect();
+                    (end, Some(word.to_ascii_uppercase()))
+                }
+                ';' => {
+                    if terminated {
+                        return None;
+                    }
+                    terminated = true;
+                    (i + 1, None)
+                }
+                c => (i + 1, Some(c.to_string())),
+            };
+
+            if token.is_some() && terminated {
+                return None;
+            }
+            tokens.extend(token);
+            i = end;
+        }
+
+        Some(Self { tokens })
+    }
+
+    /// The tokens after any EXPLAIN prefix; some backends run the statement
+    /// behind it, so that is what gets classified
+    fn statement(&self) -> &[String] {
+        self.tokens
+            .iter()
+            .position(|token| {
+                !matches!(
+                    token.as_str(),
+                    "EXPLAIN" | "QUERY" | "PLAN" | "ANALYZE" | "VERBOSE"
+                )
+            })
+            .map_or(&[][..], |start| &self.tokens[start..])
+    }
+
+    /// Whether [`prefix_table_names`] can isolate the statement: queries,
+    /// DML, DDL on tables, indexes and views, and the pragmas in
+    /// [`TABLE_PRAGMAS`] applied to a single name
+    fn is_supported(&self) -> bool {
+        let statement = self.statement();
+        match statement.first().map(String::as_str) {
+            Some("SELECT" | "VALUES" | "WITH" | "INSERT" | "REPLACE" | "UPDATE" | "DELETE") => true,
+            Some("CREATE" | "DROP") => statement[1..]
+                .iter()
+                .find(|token| {
+                    !matches!(token.as_str(), "TEMP" | "TEMPORARY" | "UNIQUE" | "VIRTUAL")
+                })
+                .is_some_and(|token| matches!(token.as_str(), "TABLE" | "INDEX" | "VIEW")),
+            Some("ALTER") => statement.get(1).map(String::as_str) == Some("TABLE"),
+            Some("PRAGMA") => matches!(
+                statement,
+                [_, name, open, _, close]
+                    if TABLE_PRAGMAS.contains(&name.as_str()) && open == "(" && close == ")"
+            ),
+            _ => false,
+        }
+    }
+
+    /// The `database` action the statement needs: `manage` for DDL, `read`
+    /// for queries and `write` for everything else
+    fn action(&self) -> &'static str {
+        let statement = self.statement();
+
+        match statement.first().map(String::as_str) {
+            Some("CREATE" | "DROP" | "ALTER") => "manage",
+            // `PRAGMA name = value` changes settings; other forms only report them
+            Some("PRAGMA") if statement.iter().any(|token| token == "=") => "write",
+            Some("SELECT" | "VALUES" | "PRAGMA") => "read",
+            // A CTE may modify data in its own bodies as well as its final statement
+            Some("WITH") if !Self::modifies_data(statement) => "read",
+            _ => "write",
+        }
+    }
+
+    fn modifies_data(tokens: &[String]) -> bool {
+        tokens
+            .iter()
+            .enumerate()
+            .any(|(i, token)| match token.as_str() {
+                "INSERT" | "UPDATE" | "DELETE" | "MERGE" => true,
+                // `REPLACE INTO`, not the `replace()` function
+                "REPLACE" => tokens.get(i + 1).map(String::as_str) != Some("("),
+                _ => false,
+            })
+    }
+
+    /// Whether the statement is `keyword [TEMP|VIRTUAL|...] TABLE ...`
+    fn is_table_ddl(&self, keyword: &str) -> bool {
+        let statement = self.statement();
+        statement.first().map(String::as_str) == Some(keyword)
+            && statement.iter().take(4).any(|token| token == "TABLE")
+    }
+}
+
+/// Read-only pragmas a plugin may run, each taking the name of one of its
+/// tables or indexes
+const TABLE_PRAGMAS: &[&str] = &[
+    "TABLE_INFO",
+    "TABLE_XINFO",
+    "INDEX_LIST",
+    "INDEX_INFO",
+    "INDEX_XINFO",
+    "FOREIGN_KEY_LIST",
+];
+
+/// Keywords after which the next identifier names a table, index, view or CTE
+const TABLE_KEYWORDS: &[&str] = &[
+    "FROM",
+    "INTO",
+    "UPDATE",
+    "JOIN",
+    "TABLE",
+    "INDEX",
+    "VIEW",
+    "REFERENCES",
+    "WITH",
+];
+
+/// Words that may sit between a table keyword and the table name
+const TABLE_NAME_MODIFIERS: &[&str] = &["IF", "NOT", "EXISTS", "ONLY", "RECURSIVE"];
+
+/// Words that start a subquery, including where a table name may also appear
+const SUBQUERY_KEYWORDS: &[&str] = &["SELECT", "VALUES", "WITH"];
+
+/// Words that start a statement or subquery, and so end a CTE list
+const QUERY_KEYWORDS: &[&str] = &[
+    "SELECT",
+    "VALUES",
+    "WITH",
+    "INSERT",
+    "REPLACE",
+    "UPDATE",
+    "DELETE",
+    "UNION",
+    "EXCEPT",
+    "INTERSECT",
+];
+
+/// Clauses that end a comma-separated `FROM` list
+const FROM_LIST_TERMINATORS: &[&str] = &[
+    "WHERE",
+    "GROUP",
+    "ORDER",
+    "LIMIT",
+    "HAVING",
+    "UNION",
+    "EXCEPT",
+    "INTERSECT",
+    "SET",
+    "VALUES",
+    "RETURNING",
+    "WINDOW",
+];
+
+/// Prefix every table name in `query` with `prefix`, or `None` if a name
+/// can't be isolated.
+///
+/// A minimal rewriter rather than a SQL parser: the identifier following
+/// `FROM`, `INTO`, `UPDATE`, `JOIN`, `TABLE`, `INDEX`, `VIEW`, `REFERENCES`,
+/// `WITH` (each entry of a `FROM` or CTE list), `RENAME TO`, `INDEXED BY` and
+/// the `ON` of `CREATE INDEX` is prefixed, as is any `table.column` qualifier
+/// naming one of those tables and the argument of a pragma. String literals and
+/// comments are copied verbatim. Names are always prefixed, so a plugin cannot
+/// reach another plugin's tables by spelling out their prefixed names; schema
+/// qualified names and strings in table position are refused. Callers check
+/// [`SqlStatement::is_supported`] first.
+fn prefix_table_names(query: &str, prefix: &str) -> Option<String> {
+    let chars: Vec<char> = query.chars().collect();
+    // Qualifiers may come before the table they name, as in `SELECT foo.id FROM foo`
+    let mut tables = HashSet::new();
+    rewrite_table_names(&chars, prefix, &HashSet::new(), &mut tables)?;
+    rewrite_table_names(&chars, prefix, &tables.clone(), &mut tables)
+}
+
+/// The table prefix for `plugin_id`: `plugin_`, the ID with `_` and `-`
+/// escaped as `_5f` and `_2d`, then `__`. The escaped ID never contains `__`,
+/// so no plugin's prefix is the start of another's.
+fn plugin_table_prefix(plugin_id: &str) -> String {
+    let mut prefix = String::from("plugin_");
+    for c in plugin_id.chars() {
+        match c {
+            '_' => prefix.push_str("_5f"),
+            '-' => prefix.push_str("_2d"),
+            c => prefix.push(c),
+        }
+    }
+    prefix.push_str("__");
+    prefix
+}
+
+/// What [`rewrite_table_names`] tracks for the statement and each
+/// parenthesized section in it
+#[derive(Debug, Default)]
+struct RewriteScope {
+    /// Commas separate the entries of a `FROM` list
+    in_from_list: bool,
+    /// Commas separate CTE definitions
+    in_cte_list: bool,
+    /// Whether `FROM` names a table here, as it does in statements and
+    /// subqueries but not in arguments like `EXTRACT(YEAR FROM col)`;
+    /// decided by the scope's first word
+    is_query: Option<bool>,
+}
+
+/// One pass of [`prefix_table_names`], prefixing qualifiers found in
+/// `qualifiers` and recording every table name it prefixes in `tables`
+fn rewrite_table_names(
+    chars: &[char],
+    prefix: &str,
+    qualifiers: &HashSet<String>,
+    tables: &mut HashSet<String>,
+) -> Option<String> {
+    let mut out = String::with_capacity(chars.len() + prefix.len() * 4);
+    let mut scopes = vec![RewriteScope {
+        is_query: Some(true),
+        ..Default::default()
+    }];
+    let mut expect_table = false;
+    // The next `ON` names the table of a `CREATE INDEX`
+    let mut index_target = false;
+    let mut is_pragma = false;
+    let mut previous = String::new();
+    let mut i = 0;
+    let qualifies = |end: usize, name: &str| {
+        chars.get(end) == Some(&'.') && qualifiers.contains(&name.to_lowercase())
+    };
+    // `schema.table` would escape the prefix
+    let schema_qualified =
+        |end: usize| chars[end..].iter().find(|c| !c.is_whitespace()) == Some(&'.');
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\'' => {
+                // SQLite reads a string in table position as a name
+                if expect_table {
+                    return None;
+                }
+                let end = quoted_end(chars, i);
+                out.extend(&chars[i..end]);
+                previous.clear();
+                i = end;
+            }
+            '"' | '`' | '[' => {
+                let end = if c == '[' {
+                    chars[i..]
+                        .iter()
+                        .position(|&c| c == ']')
+                        .map_or(chars.len(), |p| i + p + 1)
+                } else {
+                    quoted_end(chars, i)
+                };
+                let name: String = chars[i + 1..end.saturating_sub(1).max(i + 1)]
+                    .iter()
+                    .collect();
+                out.push(c);
+                if expect_table {
+                    if schema_qualified(end) {
+                        return None;
+                    }
+                    tables.insert(name.to_lowercase());
+                    out.push_str(prefix);
+                } else if qualifies(end, &name) {
+                    out.push_str(prefix);
+                }
+                out.extend(&chars[i + 1..end]);
+                expect_table = false;
+                previous.clear();
+                i = end;
+            }
+            '-' if chars.get(i + 1) == Some(&'-') => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|&c| c == '\n')
+                    .map_or(chars.len(), |p| i + p);
+                out.extend(&chars[i..end]);
+                i = end;
+            }
+            '/' if chars.get(i + 1) == Some(&'*') => {
+                let end = chars[i + 2..]
+                    .windows(2)
+                    .position(|w| w == ['*', '/'])
+                    .map_or(chars.len(), |p| i + 2 + p + 2);
+                out.extend(&chars[i..end]);
+                i = end;
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let word: String = chars[start..i].iter().collect();
+                let upper = word.to_ascii_uppercase();
+                let after_dot = start > 0 && chars[start - 1] == '.';
+                let scope = scopes.last_mut()?;
+                let is_query = *scope
+                    .is_query
+                    .get_or_insert(QUERY_KEYWORDS.contains(&upper.as_str()));
+                if QUERY_KEYWORDS.contains(&upper.as_str()) && upper != "WITH" {
+                    scope.in_cte_list = false;
+                }
+
+                // `FROM (SELECT ...)` rather than `FROM (items)`
+                if expect_table && SUBQUERY_KEYWORDS.contains(&upper.as_str()) {
+                    expect_table = false;
+                    scope.in_from_list = false;
+                }
+
+                if expect_table {
+                    if !TABLE_NAME_MODIFIERS.contains(&upper.as_str()) {
+                        if schema_qualified(i) {
+                            return None;
+                        }
+                        tables.insert(word.to_lowercase());
+                        out.push_str(prefix);
+                        expect_table = false;
+                    }
+                } else if TABLE_KEYWORDS.contains(&upper.as_str())
+                    && !(upper == "FROM" && (!is_query || previous == "DISTINCT"))
+                    && !(upper == "UPDATE" && matches!(previous.as_str(), "DO" | "FOR"))
+                {
+                    expect_table = true;
+                    match upper.as_str() {
+                        "FROM" => scope.in_from_list = true,
+                        "JOIN" => {}
+                        "WITH" => scope.in_cte_list = true,
+                        "INDEX" => index_target = true,
+                        _ => scope.in_from_list = false,
+                    }
+                } else if (upper == "ON" && index_target)
+                    || (upper == "TO" && previous == "RENAME")
+                    || (upper == "BY" && previous == "INDEXED")
+                {
+                    expect_table = true;
+                    index_target = false;
+                } else if upper == "PRAGMA" {
+                    is_pragma = true;
+                } else if FROM_LIST_TERMINATORS.contains(&upper.as_str()) {
+                    scope.in_from_list = false;
+                } else if !after_dot && qualifies(i, &word) {
+                    out.push_str(prefix);
+                }
+                out.push_str(&word);
+                previous = upper;
+            }
+            ',' => {
+                let scope = scopes.last()?;
+                out.push(c);
+                expect_table = scope.in_from_list || scope.in_cte_list;
+                previous.clear();
+                i += 1;
+            }
+            '(' => {
+                // `FROM (items)`, or the argument of `PRAGMA table_info(items)`
+                let table_position = expect_table || (is_pragma && scopes.len() == 1);
+                scopes.push(RewriteScope {
+                    in_from_list: expect_table,
+                    ..Default::default()
+                });
+                out.push(c);
+                expect_table = table_position;
+                previous.clear();
+                i += 1;
+            }
+            ')' | ';' => {
+                if c == ')' && scopes.len() > 1 {
+                    scopes.pop();
+                }
+                out.push(c);
+                expect_table = false;
+                previous.clear();
+                i += 1;
+            }
+            c => {
+                out.push(c);
+                if !c.is_whitespace() {
+                    expect_table = false;
+                    previous.clear();
+                }
+                i += 1;
+            }
+        }
+    }
+
+    Some(out)
+}
+
+/// Index just past the quoted section opening at `start`; doubled quotes escape
+fn quoted_end(chars: &[char], start: usize) -> usize {
+    let quote = chars[start];
+    let mut i = start + 1;
+    while i < chars.len() {
+        if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                i += 2;
+                continue;
+            }
+            return i + 1;
+        }
+        i += 1;
+    }
+    chars.len()
+}
+
+/// Normalize a path relative to a plugin sandbox, or `None` if it could
+/// leave it.
+///
+/// Works lexically since providers may not be backed by a real file system:
+/// absolute paths, backslashes, drive letters, NULs and percent-encoded dots,
+/// slashes and NULs are refused, and `..` may only step back out of
+/// directories it entered. Used for plugin files and served assets alike.
+pub(crate) fn normalize_sandbox_path(path: &str) -> Option<String> {
+    let lowered = path.to_ascii_lowercase();
+    if path.is_empty()
+        || path.starts_with('/')
+        || path.contains('\\')
+        || path.contains('\0')
+        || path.contains(':')
+        || lowered.contains("%2e")
+        || lowered.contains("%2f")
+        || lowered.contains("%5c")
+        || lowered.contains("%00")
+    {
+        return None;
+    }
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => continue,
+            ".." => {
+                segments.pop()?;
+            }
+            s => segments.push(s),
+        }
+    }
+
+    (!segments.is_empty()).then(|| segments.join("/"))
+}
+
+/// Whether `path` is still inside `root` once symlinks are resolved, for
+/// providers backed by a real file system; always true for the others
+pub(crate) fn stays_on_disk_within(provider: &FileSystemArc, root: &str, path: &str) -> bool {
+    match (provider.local_path(root), provider.local_path(path)) {
+        (Some(root), Some(path)) => match (resolve_existing(&root), resolve_existing(&path)) {
+            (Ok(root), Ok(path)) => path.starts_with(root),
+            _ => false,
+        },
+        _ => true,
+    }
+}
+
+/// Canonicalize the longest existing prefix of `path` and append the rest,
+/// which cannot hold symlinks since it does not exist yet
+fn resolve_existing(path: &std::path::Path) -> std::io::Result<std::path::PathBuf> {
+    let mut missing = Vec::new();
+    let mut existing = path;
+    loop {
+        match existing.canonicalize() {
+            Ok(resolved) => {
+                return Ok(missing.iter().rev().fold(resolved, |p, c| p.join(c)));
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let (Some(parent), Some(name)) = (existing.parent(), existing.file_name()) else {
+                    return Err(e);
+                };
+                missing.push(name);
+                existing = parent;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// File system access for plugins with sandboxing
+#[derive(Clone, Debug)]
 pub struct PluginFileSystem {
-    #[allow(dead_code)]
     plugin_id: String,
     provider: FileSystemArc,
     base_path: String,
+    declared: Option<DeclaredPermissions>,
 }
 
 impl PluginFileSystem {
@@ -342,28 +1844,45 @@ impl PluginFileSystem {
             plugin_id: plugin_id.clone(),
             provider,
             base_path: format!("plugins/{}/", plugin_id),
+            declared: None,
         }
     }
 
+    /// Refuse reads and writes unless the plugin declared `files.read` or `files.write`
+    pub fn with_declared_permissions(mut self, declared: DeclaredPermissions) -> Self {
+        self.declared = Some(declared);
+        self
+    }
+
     /// Read a file with sandboxing
     pub async fn read_file(&self, path: &str) -> Result<Vec<u8>> {
+        require_declared(&self.declared, &self.plugin_id, "files", "read")?;
         let safe_path = self.make_safe_path(path)?;
         self.provider.read_file(&safe_path).await
     }
 
     /// Write a file with sandboxing
     pub async fn write_file(&self, path: &str, data: &[u8]) -> Result<()> {
+        require_declared(&self.declared, &self.plugin_id, "files", "write")?;
         let safe_path = self.make_safe_path(path)?;
         self.provider.write_file(&safe_path, data).await
     }
 
+    /// Resolve a plugin-relative path inside the sandbox.
+    ///
+    /// The path is normalized by [`normalize_sandbox_path`]; on providers
+    /// backed by a real file system it must also still be inside the sandbox
+    /// once symlinks are resolved.
     fn make_safe_path(&self, path: &str) -> Result<String> {
-        // Prevent directory traversal
-        if path.contains("..") || path.starts_with('/') {
-            return Err(Error::permission("file.access", "Invalid file path"));
+        let invalid = || Error::permission("file.access", format!("Invalid file path: {}", path));
+
+        let relative = normalize_sandbox_path(path).ok_or_else(invalid)?;
+        let safe_path = format!("{}{}", self.base_path, relative);
+        if !stays_on_disk_within(&self.provider, &self.base_path, &safe_path) {
+            return Err(invalid());
         }
 
-        Ok(format!("{}{}", self.base_path, path))
+        Ok(safe_path)
     }
 }
 
@@ -448,6 +1967,11 @@ pub trait Plugin: Send + Sync + std::fmt::Debug {
     /// Get event handlers provided by this plugin
     fn event_handlers(&self) -> Vec<EventHandler>;
 
+    /// Get search providers contributed by this plugin
+    fn search_providers(&self) -> Vec<Arc<dyn SearchProvider>> {
+        Vec::new()
+    }
+
     /// Render a UI component
     fn render_component(&self, component_id: &str, props: serde_json::Value) -> Result<VNode>;
 
@@ -456,6 +1980,19 @@ pub trait Plugin: Send + Sync + std::fmt::Debug {
 
     /// Handle an event
     async fn handle_event(&self, handler_id: &str, event: &dyn Event) -> Result<()>;
+
+    /// Handle an event and decide whether lower-priority handlers still see it.
+    ///
+    /// This is what dispatch calls; the default runs [`Plugin::handle_event`]
+    /// and continues. Override it to consume events.
+    async fn handle_event_propagation(
+        &self,
+        handler_id: &str,
+        event: &dyn Event,
+    ) -> Result<EventPropagation> {
+        self.handle_event(handler_id, event).await?;
+        Ok(EventPropagation::Continue)
+    }
 }
 
 /// Plugin loader trait for different loading mechanisms
@@ -487,16 +2024,85 @@ pub struct ApiRequest {
     pub path: String,
     pub headers: HashMap<String, String>,
     pub query_params: HashMap<String, String>,
+    /// Values of `{name}` segments in the matched route's path
+    pub path_params: HashMap<String, String>,
     pub body: Option<serde_json::Value>,
     pub user: Option<User>,
+    /// Address of the peer that sent the request, when known
+    pub remote_addr: Option<IpAddr>,
+}
+
+impl ApiRequest {
+    /// Value of the header `name`, compared case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Parse a raw JSON request body, rejecting bodies that exceed the limits
+    pub fn parse_body(raw: &str, limits: &JsonLimits) -> Result<Option<serde_json::Value>> {
+        if raw.trim().is_empty() {
+            return Ok(None);
+        }
+
+        limits.parse(raw).map(Some)
+    }
+}
+
+/// Builder used by the plugin tests and the `testing` harness
+#[cfg(any(test, feature = "testing"))]
+#[cfg_attr(not(test), allow(dead_code))]
+impl ApiRequest {
+    /// Request for `method` and `path` with no headers, parameters, body or user
+    pub(crate) fn new(method: &str, path: &str) -> Self {
+        Self {
+            method: method.to_string(),
+            path: path.to_string(),
+            headers: HashMap::new(),
+            query_params: HashMap::new(),
+            path_params: HashMap::new(),
+            body: None,
+            user: None,
+            remote_addr: None,
+        }
+    }
+
+    pub(crate) fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub(crate) fn with_query_params(mut self, params: HashMap<String, String>) -> Self {
+        self.query_params = params;
+        self
+    }
+
+    pub(crate) fn with_body(mut self, body: Option<serde_json::Value>) -> Self {
+        self.body = body;
+        self
+    }
+
+    pub(crate) fn with_user(mut self, user: Option<User>) -> Self {
+        self.user = user;
+        self
+    }
+
+    pub(crate) fn with_remote_addr(mut self, addr: &str) -> Self {
+        self.remote_addr = Some(addr.parse().expect("valid IP address"));
+        self
+    }
 }
 
 /// Plugin registry for managing loaded plugins
 #[derive(Debug)]
 pub struct PluginRegistry {
     plugins: HashMap<String, Box<dyn Plugin>>,
-    dependencies: HashMap<String, Vec<String>>,
+    dependencies: HashMap<String, Vec<PluginDependency>>,
     load_order: Vec<String>,
+    /// Routes of the registered plugins, kept in step with `plugins`
+    router: ApiRouter,
 }
 
 impl Default for PluginRegistry {
@@ -512,30 +2118,40 @@ impl PluginRegistry {
             plugins: HashMap::new(),
             dependencies: HashMap::new(),
             load_order: Vec::new(),
+            router: ApiRouter::new(),
         }
     }
 
-    /// Register a plugin
+    /// Register a plugin.
+    ///
+    /// Dependencies are only checked by [`PluginRegistry::resolve_load_order`],
+    /// so plugins may be registered in any order.
     pub fn register(&mut self, plugin: Box<dyn Plugin>) -> Result<()> {
         let info = plugin.info();
-        let deps = plugin
-            .required_dependencies()
-            .into_iter()
-            .map(|d| d.plugin_id)
-            .collect();
+        let deps = plugin.required_dependencies();
 
         if self.plugins.contains_key(&info.id) {
             return Err(Error::plugin(&info.id, "Plugin already registered"));
         }
 
+        self.router.register(&info.id, plugin.api_routes());
         self.plugins.insert(info.id.clone(), plugin);
         self.dependencies.insert(info.id.clone(), deps);
 
-        self.calculate_load_order()?;
-
         Ok(())
     }
 
+    /// Remove a plugin and its API routes
+    pub fn remove(&mut self, plugin_id: &str) -> Option<Box<dyn Plugin>> {
+        self.router.unregister(plugin_id);
+        self.plugins.remove(plugin_id)
+    }
+
+    /// The API routes of every registered plugin
+    pub fn router(&self) -> &ApiRouter {
+        &self.router
+    }
+
     /// Get a plugin by ID
     pub fn get(&self, plugin_id: &str) -> Option<&dyn Plugin> {
         self.plugins.get(plugin_id).map(|p| p.as_ref())
@@ -551,51 +2167,106 @@ impl PluginRegistry {
         &self.load_order
     }
 
-    fn calculate_load_order(&mut self) -> Result<()> {
+    /// Build the dependency graph and compute the initialization order.
+    ///
+    /// Fails on cycles, missing required dependencies and version mismatches.
+    /// Missing optional dependencies are logged and ignored.
+    pub fn resolve_load_order(&mut self) -> Result<&[String]> {
+        let resolver = DependencyResolver::new();
+        let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+
+        for (plugin_id, deps) in &self.dependencies {
+            let mut resolved = Vec::new();
+            for dep in deps {
+                match self.plugins.get(&dep.plugin_id) {
+                    Some(dependency) => {
+                        let installed = dependency.info().version;
+                        if !resolver
+                            .check_version_compatibility(&dep.version_requirement, &installed)
+                        {
+                            let message = format!(
+                                "Dependency {} {} does not satisfy requirement '{}'",
+                                dep.plugin_id, installed, dep.version_requirement
+                            );
+                            if dep.optional {
+                                tracing::warn!("Plugin {}: {}", plugin_id, message);
+                                continue;
+                            }
+                            return Err(Error::plugin(plugin_id, message));
+                        }
+                        resolved.push(dep.plugin_id.clone());
+                    }
+                    None if dep.optional => {
+                        tracing::warn!(
+                            "Plugin {}: optional dependency {} is not installed",
+                            plugin_id,
+                            dep.plugin_id
+                        );
+                    }
+                    None => {
+                        return Err(Error::new(
+                            crate::error::ErrorKind::Plugin {
+                                plugin_id: Some(plugin_id.clone()),
+                                plugin_name: None,
+                                dependency_missing: Some(dep.plugin_id.clone()),
+                            },
+                            format!("Missing dependency: {}", dep.plugin_id),
+                        ));
+                    }
+                }
+            }
+            edges.insert(plugin_id.clone(), resolved);
+        }
+
+        // Sort IDs so the resulting order is deterministic
+        let mut plugin_ids: Vec<&String> = self.plugins.keys().collect();
+        plugin_ids.sort();
+
         let mut order = Vec::new();
         let mut visited = std::collections::HashSet::new();
-        let mut visiting = std::collections::HashSet::new();
+        let mut path = Vec::new();
 
-        for plugin_id in self.plugins.keys() {
-            if !visited.contains(plugin_id) {
-                self.visit_plugin(plugin_id, &mut order, &mut visited, &mut visiting)?;
-            }
+        for plugin_id in plugin_ids {
+            Self::visit_plugin(plugin_id, &edges, &mut order, &mut visited, &mut path)?;
         }
 
         self.load_order = order;
-        Ok(())
+        Ok(&self.load_order)
     }
 
     fn visit_plugin(
-        &self,
         plugin_id: &str,
+        edges: &HashMap<String, Vec<String>>,
         order: &mut Vec<String>,
         visited: &mut std::collections::HashSet<String>,
-        visiting: &mut std::collections::HashSet<String>,
+        path: &mut Vec<String>,
     ) -> Result<()> {
-        if visiting.contains(plugin_id) {
-            return Err(Error::plugin(plugin_id, "Circular dependency detected"));
+        if let Some(position) = path.iter().position(|id| id == plugin_id) {
+            let cycle = path[position..]
+                .iter()
+                .chain(std::iter::once(&plugin_id.to_string()))
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(Error::plugin(
+                plugin_id,
+                format!("Circular dependency detected: {}", cycle),
+            ));
         }
 
         if visited.contains(plugin_id) {
             return Ok(());
         }
 
-        visiting.insert(plugin_id.to_string());
+        path.push(plugin_id.to_string());
 
-        if let Some(deps) = self.dependencies.get(plugin_id) {
+        if let Some(deps) = edges.get(plugin_id) {
             for dep in deps {
-                if !self.plugins.contains_key(dep) {
-                    return Err(Error::plugin(
-                        plugin_id,
-                        format!("Missing dependency: {}", dep),
-                    ));
-                }
-                self.visit_plugin(dep, order, visited, visiting)?;
+                Self::visit_plugin(dep, edges, order, visited, path)?;
             }
         }
 
-        visiting.remove(plugin_id);
+        path.pop();
         visited.insert(plugin_id.to_string());
         order.push(plugin_id.to_string());
 
@@ -624,23 +2295,394 @@ impl DependencyResolver {
         let mut resolved = Vec::new();
 
         for dep in deps {
-            if registry.get(&dep.plugin_id).is_some() {
-                resolved.push(dep.plugin_id);
-            } else if !dep.optional {
-                return Err(Error::plugin(
-                    &plugin.info().id,
-                    format!("Required dependency not found: {}", dep.plugin_id),
-                ));
+            match registry.get(&dep.plugin_id) {
+                Some(dependency) => {
+                    let installed = dependency.info().version;
+                    if !self.check_version_compatibility(&dep.version_requirement, &installed) {
+                        return Err(Error::plugin(
+                            &plugin.info().id,
+                            format!(
+                                "Dependency {} {} does not satisfy requirement '{}'",
+                                dep.plugin_id, installed, dep.version_requirement
+                            ),
+                        ));
+                    }
+                    resolved.push(dep.plugin_id);
+                }
+                None if dep.optional => {
+                    tracing::warn!(
+                        "Plugin {}: optional dependency {} is not installed",
+                        plugin.info().id,
+                        dep.plugin_id
+                    );
+                }
+                None => {
+                    return Err(Error::plugin(
+                        &plugin.info().id,
+                        format!("Required dependency not found: {}", dep.plugin_id),
+                    ));
+                }
             }
         }
 
         Ok(resolved)
     }
 
-    /// Check version compatibility
+    /// Check whether `available` satisfies a SemVer requirement.
+    ///
+    /// Requirements use Cargo's syntax as parsed by the `semver` crate: a bare
+    /// `1.2` means `^1.2`, and comparators are separated by commas. An empty
+    /// requirement matches any version.
     pub fn check_version_compatibility(&self, required: &str, available: &str) -> bool {
-        // Simple version check - in practice would use semver
-        required == available || required == "*"
+        let Some(available) = parse_version(available) else {
+            return false;
+        };
+        let required = required.trim();
+        if required.is_empty() {
+            return true;
+        }
+
+        semver::VersionReq::parse(required).is_ok_and(|requirement| requirement.matches(&available))
+    }
+}
+
+/// Parse a SemVer version, allowing a leading `v`
+fn parse_version(version: &str) -> Option<semver::Version> {
+    semver::Version::parse(version.trim().trim_start_matches('v')).ok()
+}
+
+/// Default time allowed for a single plugin's health checks
+pub const DEFAULT_PLUGIN_HEALTH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default time allowed for a single plugin's `shutdown()`
+pub const DEFAULT_PLUGIN_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default time an API request may take, matching `NetworkConfig::request_timeout_secs`
+pub const DEFAULT_PLUGIN_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default largest API request body, matching `NetworkConfig::max_request_size`
+pub const DEFAULT_PLUGIN_MAX_REQUEST_SIZE: u64 = 16 * 1024 * 1024;
+
+/// Default number of tables a plugin may create
+pub const DEFAULT_PLUGIN_MAX_TABLES: u32 = 10;
+
+/// Default bytes of database storage a plugin may use
+pub const DEFAULT_PLUGIN_MAX_STORAGE: u64 = 100 * 1024 * 1024;
+
+/// Operator policy deciding which plugins may load.
+///
+/// Deny entries take precedence over allow entries. Empty lists impose no
+/// restriction. The publisher is the plugin's declared `author`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PluginPolicy {
+    /// Plugin ID globs allowed to load
+    pub allowlist: Vec<String>,
+    /// Plugin ID globs that are always refused
+    pub denylist: Vec<String>,
+    /// Publisher globs allowed to load
+    pub trusted_publishers: Vec<String>,
+}
+
+impl PluginPolicy {
+    /// Build a policy from the `plugins` configuration section
+    pub fn from_config(config: &crate::config::PluginConfig) -> Self {
+        Self {
+            allowlist: config.allowlist.clone(),
+            denylist: config.denylist.clone(),
+            trusted_publishers: config.trusted_publishers.clone(),
+        }
+    }
+
+    /// Check a plugin against the policy, returning the reason it is denied
+    pub fn check(&self, info: &PluginInfo) -> std::result::Result<(), String> {
+        if let Some(pattern) = self.denylist.iter().find(|p| glob_matches(p, &info.id)) {
+            return Err(format!("Plugin id matches denylist entry '{}'", pattern));
+        }
+
+        if !self.allowlist.is_empty() && !self.allowlist.iter().any(|p| glob_matches(p, &info.id)) {
+            return Err("Plugin id is not on the allowlist".to_string());
+        }
+
+        if !self.trusted_publishers.is_empty()
+            && !self
+                .trusted_publishers
+                .iter()
+                .any(|p| glob_matches(p, &info.author))
+        {
+            return Err(format!("Publisher '{}' is not trusted", info.author));
+        }
+
+        Ok(())
+    }
+}
+
+/// Match `text` against a glob where `*` matches any run and `?` one character
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    backtrack = Some((star, matched + 1));
+                    p = star + 1;
+                    t = matched + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// A plugin refused by the [`PluginPolicy`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeniedPlugin {
+    pub plugin_id: String,
+    pub reason: String,
+}
+
+/// Snapshot of the plugins known to a [`PluginManager`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginStats {
+    /// Registered plugins, sorted by ID
+    pub loaded: Vec<String>,
+    /// Plugins refused by the policy, with the reason
+    pub denied: Vec<DeniedPlugin>,
+    /// Status and activity counters of each registered plugin
+    #[serde(default)]
+    pub per_plugin: HashMap<String, PluginRuntimeStats>,
+}
+
+/// Outcome of [`PluginManager::shutdown_all`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginShutdownSummary {
+    /// Plugins that shut down without error
+    pub clean: Vec<String>,
+    /// Plugins whose `shutdown()` returned an error, with the message
+    pub failed: Vec<(String, String)>,
+    /// Plugins that did not finish within the timeout
+    pub abandoned: Vec<String>,
+}
+
+impl PluginShutdownSummary {
+    /// Whether every plugin shut down cleanly
+    pub fn is_clean(&self) -> bool {
+        self.failed.is_empty() && self.abandoned.is_empty()
+    }
+}
+
+/// An uncacheable response carrying `{"error": message}`
+fn error_response(status_code: u16, description: &str, message: String) -> ApiResponse {
+    ApiResponse {
+        status_code,
+        description: description.to_string(),
+        schema: Some(serde_json::json!({ "error": message })),
+        cache: Some(CacheDirective::NoStore),
+        retry_after_secs: None,
+        headers: Vec::new(),
+    }
+}
+
+/// The error response for a failed request, with the status from
+/// [`Error::http_status`].
+///
+/// Server errors (5xx) report only the status text so internal details stay
+/// in the logs; rate-limited errors carry their `Retry-After`.
+pub fn api_error_response(error: &Error) -> ApiResponse {
+    let status_code = error.http_status();
+    let description = http_status_text(status_code);
+    let message = if status_code >= 500 {
+        tracing::error!("API request failed with {}: {}", status_code, error);
+        description.to_string()
+    } else {
+        error.message.clone()
+    };
+
+    let mut response = error_response(status_code, description, message);
+    if let crate::error::ErrorKind::RateLimited {
+        retry_after_secs: Some(retry_after_secs),
+    } = error.kind
+    {
+        response.schema = Some(serde_json::json!({
+            "error": error.message,
+            "retry_after_secs": retry_after_secs,
+        }));
+        response.retry_after_secs = Some(retry_after_secs);
+    }
+    response
+}
+
+fn http_status_text(status_code: u16) -> &'static str {
+    match status_code {
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        413 => "Payload Too Large",
+        429 => "Too Many Requests",
+        501 => "Not Implemented",
+        502 => "Bad Gateway",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ if status_code < 500 => "Client Error",
+        _ => "Internal Server Error",
+    }
+}
+
+/// Who a route's rate limit counts against: the user, else the client IP.
+///
+/// The IP is the peer's, unless the peer is one of `trusted_proxies`; then it
+/// is the nearest `X-Forwarded-For` hop that is not a trusted proxy, or
+/// `X-Real-IP`. Requests from an unknown peer share one anonymous bucket.
+fn rate_limit_client(request: &ApiRequest, trusted_proxies: &[IpAddr]) -> String {
+    if let Some(user) = &request.user {
+        return user.id.to_string();
+    }
+
+    request
+        .remote_addr
+        .map(|peer| {
+            if trusted_proxies.contains(&peer) {
+                forwarded_client(request, trusted_proxies).unwrap_or(peer)
+            } else {
+                peer
+            }
+        })
+        .map(|ip| format!("ip:{}", ip))
+        .unwrap_or_else(|| "anonymous".to_string())
+}
+
+/// Client address reported by a trusted proxy, if it sent a valid one
+fn forwarded_client(request: &ApiRequest, trusted_proxies: &[IpAddr]) -> Option<IpAddr> {
+    if let Some(hops) = request.header("x-forwarded-for") {
+        let hops: Vec<IpAddr> = hops
+            .split(',')
+            .map(|hop| hop.trim().parse().ok())
+            .collect::<Option<_>>()?;
+        // Hops are appended by each proxy, so the client is left of the last trusted one
+        return hops
+            .iter()
+            .rev()
+            .find(|hop| !trusted_proxies.contains(hop))
+            .or(hops.first())
+            .copied();
+    }
+    request.header("x-real-ip")?.trim().parse().ok()
+}
+
+/// Run a plugin handler, turning a panic into an [`Error::plugin`].
+///
+/// The panic is logged and the plugin is marked [`PluginStatus::Failed`] in
+/// its runtime stats. Failed plugins get no further requests or events and
+/// report unhealthy until they are initialized or reloaded again.
+async fn catch_handler_panic<T>(
+    plugin_id: &str,
+    handler_id: &str,
+    counters: &PluginRuntimeCounters,
+    handler: impl std::future::Future<Output = Result<T>>,
+) -> Result<T> {
+    use futures::FutureExt;
+
+    match std::panic::AssertUnwindSafe(handler).catch_unwind().await {
+        Ok(result) => result,
+        Err(panic) => {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            tracing::error!(
+                plugin_id,
+                handler_id,
+                "Plugin handler panicked: {}",
+                message
+            );
+            counters.set_status(PluginStatus::Failed);
+            Err(Error::plugin(
+                plugin_id,
+                format!("Handler '{}' panicked: {}", handler_id, message),
+            ))
+        }
+    }
+}
+
+/// Run a future with a deadline, returning `None` if it does not finish in time
+async fn with_timeout<F: std::future::Future>(duration: Duration, future: F) -> Option<F::Output> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        tokio::time::timeout(duration, future).await.ok()
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        use futures::future::{select, Either};
+        let future = std::pin::pin!(future);
+        match select(future, gloo_timers::future::sleep(duration)).await {
+            Either::Left((output, _)) => Some(output),
+            Either::Right(_) => None,
+        }
+    }
+}
+
+/// Poll a plugin's providers together, reporting it unhealthy if they overrun `timeout`
+async fn check_plugin_health(plugin: &dyn Plugin, timeout: Duration) -> ProviderHealth {
+    let providers = plugin.search_providers();
+    let started = Time::now_millis();
+    let checks =
+        futures::future::join_all(providers.iter().map(|provider| provider.health_check()));
+
+    match with_timeout(timeout, checks).await {
+        Some(results) => {
+            aggregate_provider_health(results, Time::now_millis().saturating_sub(started))
+        }
+        None => ProviderHealth {
+            is_healthy: false,
+            response_time_ms: None,
+            error_message: Some(format!(
+                "Health check timed out after {}ms",
+                timeout.as_millis()
+            )),
+            last_check: Time::now(),
+        },
+    }
+}
+
+/// Combine the health of a plugin's providers into a single report
+fn aggregate_provider_health(
+    results: Vec<Result<ProviderHealth>>,
+    elapsed_ms: u64,
+) -> ProviderHealth {
+    let mut errors = Vec::new();
+
+    for result in results {
+        match result {
+            Ok(health) if health.is_healthy => {}
+            Ok(health) => errors.push(
+                health
+                    .error_message
+                    .unwrap_or_else(|| "Provider reported unhealthy".to_string()),
+            ),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+
+    ProviderHealth {
+        is_healthy: errors.is_empty(),
+        response_time_ms: Some(elapsed_ms),
+        error_message: (!errors.is_empty()).then(|| errors.join("; ")),
+        last_check: Time::now(),
     }
 }
 
@@ -654,6 +2696,28 @@ pub struct PluginManager {
     api_provider: PluginApiProvider,
     dependency_resolver: DependencyResolver,
     plugin_contexts: HashMap<String, PluginContext>,
+    shutdown_timeout: Duration,
+    request_timeout: Duration,
+    max_request_size: u64,
+    cors: CorsPolicy,
+    trusted_proxies: Vec<IpAddr>,
+    api_timeouts: std::sync::atomic::AtomicU64,
+    policy: PluginPolicy,
+    denied: Vec<DeniedPlugin>,
+    components: UIComponentRegistry,
+    commands: CommandRegistry,
+    reports: ReportScheduler,
+    permission_hierarchy: crate::auth::PermissionHierarchy,
+    rate_limiter: Arc<dyn RateLimitBackend>,
+    database: Option<DatabaseArc>,
+    event_bus: Arc<EventBusManager>,
+    search: Arc<search::SearchCoordinator>,
+    /// Provider IDs each running plugin registered with `search`
+    search_providers: HashMap<String, Vec<String>>,
+    runtime: PluginRuntimeTracker,
+    payload_guard: EventPayloadGuard,
+    #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+    hot_reload: bool,
 }
 
 impl std::fmt::Debug for PluginManager {
@@ -665,17 +2729,35 @@ impl std::fmt::Debug for PluginManager {
 }
 
 /// API provider for plugins
-pub struct PluginApiProvider;
+pub struct PluginApiProvider {
+    config: Option<Arc<RwLock<TieredConfigManager>>>,
+    accounts: Option<Arc<RwLock<AccountManager>>>,
+    state: Option<StorageArc>,
+}
 
 impl PluginApiProvider {
     /// Create a new API provider
     pub fn new() -> Self {
-        Self
+        Self {
+            config: None,
+            accounts: None,
+            state: None,
+        }
     }
 
     /// Create an API client for a plugin
     pub fn create_client(&self, plugin_id: String) -> PluginApiClient {
-        PluginApiClient::new(plugin_id)
+        let mut client = PluginApiClient::new(plugin_id);
+        if let Some(config) = &self.config {
+            client = client.with_config(Arc::clone(config));
+        }
+        if let Some(accounts) = &self.accounts {
+            client = client.with_account_manager(Arc::clone(accounts));
+        }
+        if let Some(state) = &self.state {
+            client = client.with_state_store(Arc::clone(state));
+        }
+        client
     }
 }
 
@@ -689,37 +2771,282 @@ impl PluginManager {
     /// Create a new plugin manager
     pub fn new(loader: Box<dyn PluginLoader>) -> Self {
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "plugin_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "plugin_manager"),
             registry: PluginRegistry::new(),
             loader,
             sandbox: PluginSandbox::new(ResourceLimits::default(), Vec::new()),
             api_provider: PluginApiProvider::new(),
             dependency_resolver: DependencyResolver::new(),
             plugin_contexts: HashMap::new(),
+            shutdown_timeout: DEFAULT_PLUGIN_SHUTDOWN_TIMEOUT,
+            request_timeout: DEFAULT_PLUGIN_REQUEST_TIMEOUT,
+            max_request_size: DEFAULT_PLUGIN_MAX_REQUEST_SIZE,
+            cors: CorsPolicy::default(),
+            trusted_proxies: Vec::new(),
+            api_timeouts: std::sync::atomic::AtomicU64::new(0),
+            policy: PluginPolicy::default(),
+            denied: Vec::new(),
+            components: UIComponentRegistry::shared(),
+            commands: CommandRegistry::shared(),
+            reports: ReportScheduler::shared(),
+            permission_hierarchy: crate::auth::PermissionHierarchy::default(),
+            rate_limiter: Arc::new(RateLimiterStore::shared()),
+            database: None,
+            event_bus: Arc::new(EventBusManager::new(crate::event::EventBusConfig::default())),
+            search: Arc::new(search::SearchCoordinator::new()),
+            search_providers: HashMap::new(),
+            runtime: PluginRuntimeTracker::new(),
+            payload_guard: EventPayloadGuard::default(),
+            #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+            hot_reload: false,
         }
     }
 
-    /// Load a plugin from a path
-    pub async fn load_plugin(&mut self, path: &str) -> Result<()> {
+    /// Use a different component registry than the process-wide one
+    pub fn set_component_registry(&mut self, components: UIComponentRegistry) {
+        self.components = components;
+    }
+
+    /// Use a different command palette registry than the process-wide one
+    pub fn set_command_registry(&mut self, commands: CommandRegistry) {
+        self.commands = commands;
+    }
+
+    /// Use a different report scheduler than the process-wide one
+    pub fn set_report_scheduler(&mut self, reports: ReportScheduler) {
+        self.reports = reports;
+    }
+
+    /// Check route and declared permissions with the configured hierarchy
+    pub fn set_permission_hierarchy(&mut self, hierarchy: crate::auth::PermissionHierarchy) {
+        self.permission_hierarchy = hierarchy;
+    }
+
+    /// Replace the backend that enforces per-route `RateLimit`s
+    pub fn set_rate_limiter(&mut self, backend: Arc<dyn RateLimitBackend>) {
+        self.rate_limiter = backend;
+    }
+
+    /// Share a config manager with plugins created from now on
+    pub fn set_config_manager(&mut self, config: Arc<RwLock<TieredConfigManager>>) {
+        self.api_provider.config = Some(config);
+    }
+
+    /// Share an account manager with plugins created from now on
+    pub fn set_account_manager(&mut self, accounts: Arc<RwLock<AccountManager>>) {
+        self.api_provider.accounts = Some(accounts);
+    }
+
+    /// Share the application's event bus with every plugin context, so
+    /// plugins and core managers see each other's events
+    pub fn set_event_bus(&mut self, event_bus: Arc<EventBusManager>) {
+        // Providers register once plugins initialize, so the coordinator is still empty here
+        self.search =
+            Arc::new(search::SearchCoordinator::new().with_event_bus(Arc::clone(&event_bus)));
+        self.event_bus = event_bus;
+    }
+
+    /// Federated search over the providers of every running plugin
+    pub fn search_coordinator(&self) -> Arc<search::SearchCoordinator> {
+        Arc::clone(&self.search)
+    }
+
+    /// Give plugins sandboxed access to `database` through their context
+    pub fn set_database(&mut self, database: DatabaseArc) {
+        self.database = Some(database);
+    }
+
+    /// Share the key-value store backing plugin runtime state
+    pub fn set_state_store(&mut self, state: StorageArc) {
+        self.api_provider.state = Some(state);
+    }
+
+    /// Replace the guard that screens event payloads before delivery
+    pub fn set_payload_guard(&mut self, payload_guard: EventPayloadGuard) {
+        self.payload_guard = payload_guard;
+    }
+
+    /// Guard that screens event payloads, e.g. to register payload schemas
+    pub fn payload_guard(&self) -> &EventPayloadGuard {
+        &self.payload_guard
+    }
+
+    /// Set which plugins may load
+    pub fn set_policy(&mut self, policy: PluginPolicy) {
+        self.policy = policy;
+    }
+
+    /// Loaded plugins, plugins refused by the policy and per-plugin counters
+    pub fn stats(&self) -> PluginStats {
+        let mut loaded: Vec<String> = self
+            .registry
+            .list()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        loaded.sort();
+
+        PluginStats {
+            loaded,
+            denied: self.denied.clone(),
+            per_plugin: self.runtime.snapshot(),
+        }
+    }
+
+    /// Whether `plugin_id` failed, e.g. by panicking, and is cut off from
+    /// requests and events
+    pub fn is_failed(&self, plugin_id: &str) -> bool {
+        self.runtime.counters(plugin_id).status() == PluginStatus::Failed
+    }
+
+    /// Record a policy denial, returning the error to report
+    fn deny_plugin(&mut self, plugin_id: &str, reason: String) -> Error {
+        tracing::warn!("Plugin {} denied by policy: {}", plugin_id, reason);
+        let error = Error::permission(
+            "plugin.load",
+            format!("Plugin {} denied by policy: {}", plugin_id, reason),
+        );
+
+        self.denied.retain(|d| d.plugin_id != plugin_id);
+        self.denied.push(DeniedPlugin {
+            plugin_id: plugin_id.to_string(),
+            reason,
+        });
+        error
+    }
+
+    /// Set how long each plugin may take to shut down before it is abandoned
+    pub fn set_shutdown_timeout(&mut self, timeout: Duration) {
+        self.shutdown_timeout = timeout;
+    }
+
+    /// Set how long an API request may take unless its route overrides it
+    pub fn set_request_timeout(&mut self, timeout: Duration) {
+        self.request_timeout = timeout;
+    }
+
+    /// Set the largest request body, in bytes, unless a route overrides it
+    pub fn set_max_request_size(&mut self, bytes: u64) {
+        self.max_request_size = bytes;
+    }
+
+    /// Set which browser origins may call plugin API routes
+    pub fn set_cors_policy(&mut self, cors: CorsPolicy) {
+        self.cors = cors;
+    }
+
+    /// Set the reverse proxies trusted to report client addresses
+    pub fn set_trusted_proxies(&mut self, proxies: Vec<IpAddr>) {
+        self.trusted_proxies = proxies;
+    }
+
+    /// Number of API requests that were cut off by their timeout
+    pub fn api_timeout_count(&self) -> u64 {
+        self.api_timeouts.load(std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Offer a running plugin's routed menu items in the command palette
+    fn register_palette_commands(&self, plugin_id: &str) {
+        if let Some(plugin) = self.registry.get(plugin_id) {
+            self.commands
+                .register_plugin_menu(plugin_id, &plugin.menu_items());
+        }
+    }
+
+    /// Register a running plugin's search providers with the coordinator
+    async fn register_search_providers(&mut self, plugin_id: &str) {
+        let Some(plugin) = self.registry.get(plugin_id) else {
+            return;
+        };
+        let mut registered = Vec::new();
+        for provider in plugin.search_providers() {
+            let provider_id = provider.provider_id().to_string();
+            match self.search.register_provider(provider).await {
+                Ok(()) => registered.push(provider_id),
+                Err(e) => tracing::warn!(
+                    "Plugin {} search provider {} failed to register: {}",
+                    plugin_id,
+                    provider_id,
+                    e
+                ),
+            }
+        }
+        self.search_providers
+            .insert(plugin_id.to_string(), registered);
+    }
+
+    /// Withdraw the search providers a plugin registered
+    async fn unregister_search_providers(&mut self, plugin_id: &str) {
+        for provider_id in self.search_providers.remove(plugin_id).unwrap_or_default() {
+            if let Err(e) = self.search.unregister_provider(&provider_id).await {
+                tracing::warn!(
+                    "Plugin {} search provider {} failed to unregister: {}",
+                    plugin_id,
+                    provider_id,
+                    e
+                );
+            }
+        }
+    }
+
+    /// Load a plugin from a path
+    pub async fn load_plugin(&mut self, path: &str) -> Result<()> {
         let plugin = self.loader.load_plugin(path).await?;
+        self.register_plugin(plugin).await
+    }
+
+    /// Registers plugins compiled into the host, returning their IDs.
+    ///
+    /// Each ID is looked up in `factories`, where the host and `export_plugin!`
+    /// register compiled-in plugins; an ID without a factory is an error.
+    pub async fn register_builtin_plugins(
+        &mut self,
+        factories: &PluginFactoryRegistry,
+        plugin_ids: &[String],
+    ) -> Result<Vec<String>> {
+        let mut registered = Vec::with_capacity(plugin_ids.len());
+        for plugin_id in plugin_ids {
+            let factory = factories.get(plugin_id).ok_or_else(|| {
+                Error::plugin(plugin_id, "No factory is registered for built-in plugin")
+            })?;
+            tracing::debug!("Registering built-in plugin '{}'", plugin_id);
+            self.register_plugin(factory()).await?;
+            registered.push(plugin_id.clone());
+        }
+        Ok(registered)
+    }
+
+    /// Checks a constructed plugin's core compatibility, validity and policy.
+    ///
+    /// Loading, built-in registration, onboarding and hot reload all go
+    /// through here rather than checking separately. Native libraries are
+    /// also gated on their exported info before any plugin code runs.
+    async fn admit_plugin(&mut self, plugin: &dyn Plugin) -> Result<()> {
+        let info = plugin.info();
+        check_core_compatibility(&info)?;
 
-        // Validate plugin
-        let validation = self.loader.validate_plugin(plugin.as_ref()).await?;
+        let validation = self.loader.validate_plugin(plugin).await?;
         if !validation.is_valid {
             return Err(Error::plugin(
-                &plugin.info().id,
+                &info.id,
                 format!("Plugin validation failed: {:?}", validation.errors),
             ));
         }
 
-        // Check dependencies
-        let _resolved_deps = self
-            .dependency_resolver
-            .resolve(plugin.as_ref(), &self.registry)?;
+        if let Err(reason) = self.policy.check(&info) {
+            return Err(self.deny_plugin(&info.id, reason));
+        }
+        Ok(())
+    }
 
-        // Register plugin
+    /// Admits and registers an already constructed plugin
+    async fn register_plugin(&mut self, plugin: Box<dyn Plugin>) -> Result<()> {
+        self.admit_plugin(plugin.as_ref()).await?;
         let plugin_id = plugin.info().id.clone();
+
+        // Register plugin
         self.registry.register(plugin)?;
+        self.runtime.set_status(&plugin_id, PluginStatus::Loaded);
 
         // Create plugin context
         let context = self.create_plugin_context(&plugin_id).await?;
@@ -728,37 +3055,285 @@ impl PluginManager {
         Ok(())
     }
 
+    /// Install a plugin an admin walked through the onboarding wizard for.
+    ///
+    /// The plugin must ask for no permission beyond those the admin approved.
+    /// Collected settings are written to its config namespace before it is
+    /// initialized; its health is returned once it is running.
+    pub async fn install_onboarded(
+        &mut self,
+        plugin: Box<dyn Plugin>,
+        onboarding: &PluginOnboarding,
+    ) -> Result<ProviderHealth> {
+        let plugin_id = plugin.info().id;
+        if onboarding.info.id != plugin_id {
+            return Err(Error::plugin(
+                &plugin_id,
+                format!("Onboarding was completed for '{}'", onboarding.info.id),
+            ));
+        }
+        if !onboarding.is_complete() {
+            return Err(Error::plugin(&plugin_id, "Onboarding is not complete"));
+        }
+        if !onboarding.values().is_empty() && self.api_provider.config.is_none() {
+            return Err(Error::plugin(
+                &plugin_id,
+                "No config manager is available to store the plugin's settings",
+            ));
+        }
+        if plugin
+            .required_permissions()
+            .iter()
+            .any(|permission| !onboarding.permissions.contains(permission))
+        {
+            return Err(Error::permission(
+                "plugin.install",
+                format!(
+                    "Plugin {} requests permissions that were not approved",
+                    plugin_id
+                ),
+            ));
+        }
+        self.dependency_resolver
+            .resolve(plugin.as_ref(), &self.registry)?;
+
+        self.register_plugin(plugin).await?;
+        let context = self
+            .plugin_contexts
+            .get(&plugin_id)
+            .cloned()
+            .ok_or_else(|| Error::plugin(&plugin_id, "Plugin context missing after install"))?;
+
+        let mut initialized = Ok(());
+        let mut written = Vec::new();
+        for (key, value) in onboarding.values() {
+            initialized = context.api_client.set_config(key, value.clone()).await;
+            if initialized.is_err() {
+                break;
+            }
+            written.push(key);
+        }
+        if initialized.is_ok() {
+            if let Some(plugin) = self.registry.plugins.get_mut(&plugin_id) {
+                initialized = plugin.initialize(context).await;
+            }
+        }
+
+        if let Err(e) = initialized {
+            // Leave nothing half-installed behind
+            for key in written {
+                if let Err(e) = context.api_client.remove_config(key).await {
+                    tracing::warn!(
+                        "Failed to remove setting {} of plugin {}: {}",
+                        key,
+                        plugin_id,
+                        e
+                    );
+                }
+            }
+            self.registry.remove(&plugin_id);
+            self.registry.dependencies.remove(&plugin_id);
+            self.plugin_contexts.remove(&plugin_id);
+            self.components.remove_plugin(&plugin_id);
+            self.commands.remove_plugin_commands(&plugin_id);
+            self.reports.cancel_plugin(&plugin_id);
+            return Err(Error::plugin(
+                &plugin_id,
+                format!("Plugin initialization failed: {}", e),
+            ));
+        }
+
+        self.register_search_providers(&plugin_id).await;
+        self.register_palette_commands(&plugin_id);
+        match self.registry.get(&plugin_id) {
+            Some(plugin) => Ok(check_plugin_health(plugin, DEFAULT_PLUGIN_HEALTH_TIMEOUT).await),
+            None => Err(Error::plugin(&plugin_id, "Plugin missing after install")),
+        }
+    }
+
+    /// Install a request handed over by a [`PluginInstaller`] and answer it
+    pub async fn handle_install(&mut self, request: InstallRequest) {
+        let result = self
+            .install_onboarded(request.plugin, &request.onboarding)
+            .await;
+        if let Err(e) = &result {
+            tracing::warn!("Onboarded install failed: {}", e);
+        }
+        let _ = request.reply.send(result);
+    }
+
     /// Unload a plugin
     pub async fn unload_plugin(&mut self, plugin_id: &str) -> Result<()> {
+        self.unregister_search_providers(plugin_id).await;
         if let Some(plugin) = self.registry.plugins.get_mut(plugin_id) {
             plugin.shutdown().await?;
         }
 
-        self.registry.plugins.remove(plugin_id);
+        self.registry.remove(plugin_id);
         self.plugin_contexts.remove(plugin_id);
+        self.runtime.remove(plugin_id);
+        self.components.remove_plugin(plugin_id);
+        self.commands.remove_plugin_commands(plugin_id);
+        self.reports.cancel_plugin(plugin_id);
         self.loader.unload_plugin(plugin_id).await?;
 
         Ok(())
     }
 
-    /// Initialize all plugins
+    /// Initialize all plugins in dependency order
     pub async fn initialize_plugins(&mut self) -> Result<()> {
-        let load_order = self.registry.load_order().to_vec();
+        // Drop registered plugins the policy refuses before anything runs
+        let refused: Vec<(String, String)> = self
+            .registry
+            .plugins
+            .values()
+            .filter_map(|plugin| {
+                let info = plugin.info();
+                self.policy
+                    .check(&info)
+                    .err()
+                    .map(|reason| (info.id, reason))
+            })
+            .collect();
+        for (plugin_id, reason) in refused {
+            self.registry.remove(&plugin_id);
+            self.plugin_contexts.remove(&plugin_id);
+            self.runtime.remove(&plugin_id);
+            self.deny_plugin(&plugin_id, reason);
+        }
+
+        let load_order = self.registry.resolve_load_order()?.to_vec();
 
         for plugin_id in load_order {
             if let (Some(plugin), Some(context)) = (
                 self.registry.plugins.get_mut(&plugin_id),
                 self.plugin_contexts.get(&plugin_id).cloned(),
             ) {
-                plugin.initialize(context).await.map_err(|e| {
-                    Error::plugin(&plugin_id, format!("Plugin initialization failed: {}", e))
-                })?;
+                let counters = self.runtime.counters(&plugin_id);
+                if let Err(e) = plugin.initialize(context).await {
+                    counters.set_status(PluginStatus::Failed);
+                    counters.record_error();
+                    return Err(Error::plugin(
+                        &plugin_id,
+                        format!("Plugin initialization failed: {}", e),
+                    ));
+                }
+                counters.set_status(PluginStatus::Running);
+                self.register_search_providers(&plugin_id).await;
+                self.register_palette_commands(&plugin_id);
             }
         }
 
         Ok(())
     }
 
+    /// Shut down every plugin in reverse dependency order.
+    ///
+    /// Each plugin gets the configured shutdown timeout; plugins that fail or
+    /// hang are logged and skipped so the rest can still shut down.
+    pub async fn shutdown_all(&mut self) -> PluginShutdownSummary {
+        let mut order: Vec<String> = self.registry.load_order().iter().rev().cloned().collect();
+        let mut uninitialized: Vec<String> = self
+            .registry
+            .plugins
+            .keys()
+            .filter(|id| !order.contains(id))
+            .cloned()
+            .collect();
+        uninitialized.sort();
+        order.extend(uninitialized);
+
+        let mut summary = PluginShutdownSummary::default();
+
+        for plugin_id in order {
+            self.unregister_search_providers(&plugin_id).await;
+            let Some(mut plugin) = self.registry.remove(&plugin_id) else {
+                continue;
+            };
+
+            let counters = self.runtime.counters(&plugin_id);
+            counters.set_status(PluginStatus::Stopping);
+            match with_timeout(self.shutdown_timeout, plugin.shutdown()).await {
+                Some(Ok(())) => {
+                    counters.set_status(PluginStatus::Stopped);
+                    summary.clean.push(plugin_id.clone());
+                }
+                Some(Err(e)) => {
+                    tracing::error!("Plugin {} failed to shut down: {}", plugin_id, e);
+                    counters.set_status(PluginStatus::Failed);
+                    counters.record_error();
+                    summary.failed.push((plugin_id.clone(), e.to_string()));
+                }
+                None => {
+                    counters.set_status(PluginStatus::Failed);
+                    counters.record_error();
+                    tracing::warn!(
+                        "Plugin {} did not shut down within {:?}; abandoning it",
+                        plugin_id,
+                        self.shutdown_timeout
+                    );
+                    summary.abandoned.push(plugin_id.clone());
+                }
+            }
+
+            // Drop render closures and stop reports even for abandoned plugins
+            // so nothing keeps calling into them
+            self.registry.dependencies.remove(&plugin_id);
+            self.plugin_contexts.remove(&plugin_id);
+            self.components.remove_plugin(&plugin_id);
+            self.commands.remove_plugin_commands(&plugin_id);
+            self.reports.cancel_plugin(&plugin_id);
+            if let Err(e) = self.loader.unload_plugin(&plugin_id).await {
+                tracing::error!("Failed to unload plugin {}: {}", plugin_id, e);
+            }
+        }
+
+        self.registry.load_order.clear();
+        summary
+    }
+
+    /// Check the health of every loaded plugin using the default timeout
+    pub async fn health_check_all(&self) -> HashMap<String, ProviderHealth> {
+        self.health_check_all_with_timeout(DEFAULT_PLUGIN_HEALTH_TIMEOUT)
+            .await
+    }
+
+    /// Check the health of every loaded plugin concurrently.
+    ///
+    /// Each plugin's providers are polled together; a plugin whose checks do
+    /// not finish within `timeout`, or that is [`PluginStatus::Failed`], is
+    /// reported unhealthy.
+    pub async fn health_check_all_with_timeout(
+        &self,
+        timeout: Duration,
+    ) -> HashMap<String, ProviderHealth> {
+        let checks = self
+            .registry
+            .plugins
+            .iter()
+            .map(|(plugin_id, plugin)| async move {
+                let health = if self.is_failed(plugin_id) {
+                    ProviderHealth {
+                        is_healthy: false,
+                        response_time_ms: None,
+                        error_message: Some(format!(
+                            "Plugin {} failed and is disabled until it is reloaded",
+                            plugin_id
+                        )),
+                        last_check: Time::now(),
+                    }
+                } else {
+                    check_plugin_health(plugin.as_ref(), timeout).await
+                };
+                (plugin_id.clone(), health)
+            });
+
+        futures::future::join_all(checks)
+            .await
+            .into_iter()
+            .collect()
+    }
+
     /// Get UI components from all plugins
     pub fn get_ui_components(&self) -> Vec<(String, UIComponent)> {
         let mut components = Vec::new();
@@ -785,6 +3360,23 @@ impl PluginManager {
         items
     }
 
+    /// Plugin menu items merged into one tree, sorted by `order`.
+    ///
+    /// Plugins are merged in ID order, so a plugin adding children to
+    /// another's item (by reusing its ID) cannot replace the item itself.
+    /// The layout manager shows the result in the sidebar, which hides items
+    /// the current user lacks permissions for.
+    pub fn menu_items(&self) -> Vec<MenuItem> {
+        let mut plugins: Vec<_> = self.registry.plugins.iter().collect();
+        plugins.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        crate::ui::merge_menu_items(
+            plugins
+                .into_iter()
+                .flat_map(|(_, plugin)| plugin.menu_items()),
+        )
+    }
+
     /// Render a component from a plugin
     pub fn render_component(
         &self,
@@ -800,9 +3392,374 @@ impl PluginManager {
         plugin.render_component(component_id, props)
     }
 
+    /// Dispatch an API request to the plugin route matching its method and path.
+    ///
+    /// The handler runs under the route's `timeout_secs`, or the manager-wide
+    /// request timeout; a handler that overruns is abandoned and answered with 504.
+    /// The body arrives parsed; [`Self::route_raw_api_request`] is the entry
+    /// point that enforces body size limits. CORS preflights are answered by
+    /// the manager's [`CorsPolicy`], which also adds its headers to every
+    /// response.
+    pub async fn handle_api_request(
+        &self,
+        plugin_id: &str,
+        request: ApiRequest,
+    ) -> Result<ApiResponse> {
+        if let Some(response) = self.cors.preflight(&request) {
+            return Ok(response);
+        }
+        let origin = request.header("Origin").map(str::to_string);
+
+        let plugin = self
+            .registry
+            .get(plugin_id)
+            .ok_or_else(|| Error::plugin(plugin_id, "Plugin not found"))?;
+
+        let route = plugin
+            .api_routes()
+            .into_iter()
+            .find(|r| r.path == request.path && r.method.matches(&request.method))
+            .ok_or_else(|| {
+                Error::plugin(
+                    plugin_id,
+                    format!(
+                        "No API route registered for {} {}",
+                        request.method, request.path
+                    ),
+                )
+            })?;
+
+        let response = self
+            .dispatch_api_request(plugin_id, plugin, &route, request)
+            .await?;
+        Ok(self.cors.apply(origin.as_deref(), response))
+    }
+
+    /// Deliver an event to every plugin handler registered for its type,
+    /// returning how many ran.
+    ///
+    /// Events refused by the payload guard reach no handler. Handlers run by
+    /// descending priority, ties in plugin ID order, until one returns
+    /// [`EventPropagation::Stop`]. Failures, including panics, are logged and
+    /// recorded in the plugin's runtime stats without stopping propagation;
+    /// plugins that have [`PluginStatus::Failed`] are skipped.
+    pub async fn dispatch_event(&self, event: &dyn Event) -> usize {
+        if !self.payload_guard.admit(event) {
+            return 0;
+        }
+
+        let mut handlers: Vec<(&str, &dyn Plugin, EventHandler)> = Vec::new();
+        for (plugin_id, plugin) in &self.registry.plugins {
+            if self.is_failed(plugin_id) {
+                continue;
+            }
+            handlers.extend(
+                plugin
+                    .event_handlers()
+                    .into_iter()
+                    .filter(|h| h.handles(event.event_type()))
+                    .map(|h| (plugin_id.as_str(), plugin.as_ref(), h)),
+            );
+        }
+        handlers.sort_by(|(a_id, _, a), (b_id, _, b)| {
+            b.priority.cmp(&a.priority).then_with(|| a_id.cmp(b_id))
+        });
+
+        let mut ran = 0;
+        for (plugin_id, plugin, handler) in handlers {
+            ran += 1;
+            let counters = self.runtime.counters(plugin_id);
+            counters.record_event();
+
+            match catch_handler_panic(
+                plugin_id,
+                &handler.handler_id,
+                &counters,
+                plugin.handle_event_propagation(&handler.handler_id, event),
+            )
+            .await
+            {
+                Ok(EventPropagation::Continue) => {}
+                Ok(EventPropagation::Stop) => break,
+                Err(e) => {
+                    counters.record_error();
+                    tracing::error!(
+                        "Plugin {} failed to handle event {}: {}",
+                        plugin_id,
+                        event.event_type(),
+                        e
+                    );
+                }
+            }
+        }
+        ran
+    }
+
+    /// All plugins' API routes, ready to match incoming requests
+    pub fn api_router(&self) -> &ApiRouter {
+        self.registry.router()
+    }
+
+    /// Route an API request to whichever plugin serves its method and path.
+    ///
+    /// Path parameters are filled in from the matched pattern. Requests with
+    /// no matching route get 404, and users lacking any of the route's
+    /// `required_permissions` get 403. Matched requests are then handled as
+    /// in [`Self::handle_api_request`], including CORS. `GET` requests for
+    /// [`crate::event::EVENT_POLL_PATH`] are answered by the event bus long-poll.
+    pub async fn route_api_request(&self, request: ApiRequest) -> Result<ApiResponse> {
+        if let Some(response) = self.cors.preflight(&request) {
+            return Ok(response);
+        }
+        let origin = request.header("Origin").map(str::to_string);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if request.path == EVENT_POLL_PATH && HttpMethod::Get.matches(&request.method) {
+            let response = self.poll_event_bus(&request).await;
+            return Ok(self.cors.apply(origin.as_deref(), response));
+        }
+
+        let response = self.match_api_request(request).await?;
+        Ok(self.cors.apply(origin.as_deref(), response))
+    }
+
+    /// Route a request whose JSON body is still raw text.
+    ///
+    /// Bodies longer than the matched route's `max_request_size`, or the
+    /// manager-wide limit, are answered with 413 before they are parsed;
+    /// bodies that fail to parse get 400. The request is then routed as in
+    /// [`Self::route_api_request`].
+    pub async fn route_raw_api_request(
+        &self,
+        mut request: ApiRequest,
+        raw_body: &str,
+    ) -> Result<ApiResponse> {
+        if let Some(response) = self.cors.preflight(&request) {
+            return Ok(response);
+        }
+        let origin = request.header("Origin").map(str::to_string);
+
+        let max_request_size = self
+            .api_router()
+            .match_route(&request.method, &request.path)
+            .and_then(|matched| matched.route.max_request_size)
+            .unwrap_or(self.max_request_size);
+        let body_size = raw_body.len() as u64;
+        if body_size > max_request_size {
+            let response = error_response(
+                413,
+                "Payload Too Large",
+                format!(
+                    "Request body is {} bytes; the limit is {}",
+                    body_size, max_request_size
+                ),
+            );
+            return Ok(self.cors.apply(origin.as_deref(), response));
+        }
+
+        request.body = match ApiRequest::parse_body(raw_body, &JsonLimits::default()) {
+            Ok(body) => body,
+            Err(e) => return Ok(self.cors.apply(origin.as_deref(), api_error_response(&e))),
+        };
+        self.route_api_request(request).await
+    }
+
+    /// Answer a long-poll for bus events the requesting user may see.
+    ///
+    /// Dropping the returned future, e.g. when the client disconnects, ends
+    /// the wait and releases the poll.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn poll_event_bus(&self, request: &ApiRequest) -> ApiResponse {
+        let Some(user) = &request.user else {
+            return api_error_response(&Error::authentication("Event polling requires a user"));
+        };
+        let query = match EventPollQuery::from_query_params(&request.query_params) {
+            Ok(query) => query,
+            Err(e) => return api_error_response(&e),
+        };
+
+        let cancellation = crate::task::CancellationToken::new();
+        match self
+            .event_bus
+            .poll_events(
+                &query,
+                Some(user),
+                &self.permission_hierarchy,
+                &cancellation,
+            )
+            .await
+        {
+            Ok(poll) => ApiResponse {
+                status_code: 200,
+                description: "OK".to_string(),
+                schema: serde_json::to_value(&poll).ok(),
+                cache: Some(CacheDirective::NoStore),
+                retry_after_secs: None,
+                headers: Vec::new(),
+            },
+            Err(e) => api_error_response(&e),
+        }
+    }
+
+    /// Find the route for a request and dispatch it
+    async fn match_api_request(&self, mut request: ApiRequest) -> Result<ApiResponse> {
+        let Some(matched) = self
+            .api_router()
+            .match_route(&request.method, &request.path)
+        else {
+            return Ok(error_response(
+                404,
+                "Not Found",
+                format!("No API route for {} {}", request.method, request.path),
+            ));
+        };
+
+        let plugin = self
+            .registry
+            .get(matched.plugin_id)
+            .ok_or_else(|| Error::plugin(matched.plugin_id, "Plugin not found"))?;
+        request.path_params = matched.params;
+        self.dispatch_api_request(matched.plugin_id, plugin, matched.route, request)
+            .await
+    }
+
+    /// Check permissions, rate limit and run a route's handler under its
+    /// timeout.
+    ///
+    /// Every entry point goes through here, so users lacking any of the
+    /// route's `required_permissions` always get 403, and plugins that have
+    /// [`PluginStatus::Failed`] always get 503.
+    async fn dispatch_api_request(
+        &self,
+        plugin_id: &str,
+        plugin: &dyn Plugin,
+        route: &ApiRoute,
+        request: ApiRequest,
+    ) -> Result<ApiResponse> {
+        if self.is_failed(plugin_id) {
+            return Ok(error_response(
+                503,
+                "Service Unavailable",
+                format!(
+                    "Plugin {} failed and is disabled until it is reloaded",
+                    plugin_id
+                ),
+            ));
+        }
+
+        let allowed =
+            crate::ui::user_permission_check(request.user.as_ref(), &self.permission_hierarchy);
+        if let Some(missing) = route
+            .required_permissions
+            .iter()
+            .find(|permission| !allowed(permission))
+        {
+            return Ok(error_response(
+                403,
+                "Forbidden",
+                format!("Missing permission {}.{}", missing.resource, missing.action),
+            ));
+        }
+
+        if let Some(limit) = &route.rate_limit {
+            let key = route_bucket_key(
+                plugin_id,
+                &request.method,
+                &route.path,
+                &rate_limit_client(&request, &self.trusted_proxies),
+            );
+
+            if let RateDecision::Limited { retry_after } = self
+                .rate_limiter
+                .acquire(&key, limit.token_bucket())
+                .await?
+            {
+                // Round up so clients never retry before a token is available
+                let retry_after_secs =
+                    retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                return Ok(api_error_response(&Error::rate_limited(
+                    Some(retry_after_secs),
+                    "Rate limit exceeded",
+                )));
+            }
+        }
+
+        let timeout = route
+            .timeout_secs
+            .map(Duration::from_secs)
+            .unwrap_or(self.request_timeout);
+        let (method, path) = (request.method.clone(), request.path.clone());
+        let counters = self.runtime.counters(plugin_id);
+        counters.record_api_request();
+
+        match with_timeout(
+            timeout,
+            catch_handler_panic(
+                plugin_id,
+                &route.handler_id,
+                &counters,
+                plugin.handle_api_request(&route.handler_id, request),
+            ),
+        )
+        .await
+        {
+            Some(Ok(response)) => Ok(response),
+            Some(Err(error)) => {
+                counters.record_error();
+                Ok(api_error_response(&error))
+            }
+            None => {
+                counters.record_error();
+                let total = self
+                    .api_timeouts
+                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+                    + 1;
+                tracing::warn!(
+                    metric = "plugin.api.timeout",
+                    total,
+                    plugin_id,
+                    "{} {} timed out after {}ms",
+                    method,
+                    path,
+                    timeout.as_millis()
+                );
+
+                Ok(error_response(
+                    504,
+                    "Gateway Timeout",
+                    format!("Request timed out after {}ms", timeout.as_millis()),
+                ))
+            }
+        }
+    }
+
     async fn create_plugin_context(&self, plugin_id: &str) -> Result<PluginContext> {
+        let declared = DeclaredPermissions::new(
+            self.registry
+                .get(plugin_id)
+                .map(|plugin| plugin.required_permissions())
+                .unwrap_or_default(),
+        )
+        .with_hierarchy(self.permission_hierarchy.clone());
+
+        let database = self.database.as_ref().map(|provider| {
+            let manage = declared.allows("database", "manage");
+            PluginDatabase::new(
+                plugin_id.to_string(),
+                Arc::clone(provider),
+                DatabasePermissions {
+                    can_create_tables: manage,
+                    can_drop_tables: manage,
+                    can_modify_schema: manage,
+                    max_table_count: Some(DEFAULT_PLUGIN_MAX_TABLES),
+                    max_storage_size: Some(DEFAULT_PLUGIN_MAX_STORAGE),
+                },
+            )
+            .with_declared_permissions(declared.clone())
+        });
+
         // This is a simplified implementation
-        // In a real system, this would create proper filesystem and database access
+        // In a real system, this would create proper filesystem access
         Ok(PluginContext {
             plugin_id: plugin_id.to_string(),
             config: PluginConfig {
@@ -813,14 +3770,28 @@ impl PluginManager {
                 user_overrides: serde_json::json!({}),
                 validation_rules: Vec::new(),
             },
-            api_client: self.api_provider.create_client(plugin_id.to_string()),
-            event_bus: Arc::new(EventBusManager::new(crate::event::EventBusConfig::default())),
-            database: None,
-            file_system: PluginFileSystem {
-                plugin_id: plugin_id.to_string(),
-                provider: Arc::new(crate::platform::MockFileSystem::new()),
-                base_path: format!("plugins/{}/", plugin_id),
-            },
+            api_client: match self
+                .registry
+                .get(plugin_id)
+                .and_then(|plugin| plugin.settings_schema())
+            {
+                Some(schema) => self
+                    .api_provider
+                    .create_client(plugin_id.to_string())
+                    .with_settings_schema(schema),
+                None => self.api_provider.create_client(plugin_id.to_string()),
+            }
+            .with_declared_permissions(declared.clone()),
+            event_bus: Arc::clone(&self.event_bus),
+            database,
+            file_system: PluginFileSystem::new(
+                plugin_id.to_string(),
+                Arc::new(crate::platform::MockFileSystem::new()),
+            )
+            .with_declared_permissions(declared.clone()),
+            components: self.components.scoped(plugin_id),
+            reports: self.reports.scoped(plugin_id),
+            declared_permissions: declared,
         })
     }
 }
@@ -855,14 +3826,13 @@ impl Manager for PluginManager {
             .set_state(crate::manager::ManagerState::ShuttingDown)
             .await;
 
-        // Shutdown all plugins in reverse order
-        let mut load_order = self.registry.load_order().to_vec();
-        load_order.reverse();
-
-        for plugin_id in load_order {
-            if let Err(e) = self.unload_plugin(&plugin_id).await {
-                tracing::error!("Failed to unload plugin {}: {}", plugin_id, e);
-            }
+        let summary = self.shutdown_all().await;
+        if !summary.is_clean() {
+            tracing::warn!(
+                "Plugin shutdown incomplete: {} failed, {} abandoned",
+                summary.failed.len(),
+                summary.abandoned.len()
+            );
         }
 
         self.state
@@ -889,6 +3859,15 @@ impl Manager for PluginManager {
             ),
         );
 
+        status.add_metadata(
+            "denied_plugins",
+            serde_json::to_value(&self.denied).unwrap_or_default(),
+        );
+        status.add_metadata(
+            "rejected_events",
+            serde_json::Value::from(self.payload_guard.rejected_count()),
+        );
+
         status
     }
 
@@ -910,6 +3889,18 @@ mod tests {
     #[derive(Debug)]
     struct TestPlugin {
         info: PluginInfo,
+        dependencies: Vec<PluginDependency>,
+        providers: Vec<Arc<dyn SearchProvider>>,
+        shutdown_delay: Duration,
+        routes: Vec<ApiRoute>,
+        api_delay: Duration,
+        permissions: Vec<Permission>,
+        settings: Option<SettingsSchema>,
+        initialized: Arc<std::sync::atomic::AtomicBool>,
+        fail_initialize: bool,
+        menus: Vec<MenuItem>,
+        event_handlers: Vec<EventHandler>,
+        handled: Arc<std::sync::Mutex<Vec<String>>>,
     }
 
     impl TestPlugin {
@@ -924,11 +3915,134 @@ mod tests {
                     license: "MIT".to_string(),
                     homepage: None,
                     repository: None,
-                    minimum_core_version: "1.0.0".to_string(),
+                    minimum_core_version: crate::VERSION.to_string(),
                     supported_platforms: vec![Platform::All],
                 },
+                dependencies: Vec::new(),
+                providers: Vec::new(),
+                shutdown_delay: Duration::ZERO,
+                routes: Vec::new(),
+                api_delay: Duration::ZERO,
+                permissions: Vec::new(),
+                settings: None,
+                initialized: Arc::new(std::sync::atomic::AtomicBool::new(false)),
+                fail_initialize: false,
+                menus: Vec::new(),
+                event_handlers: Vec::new(),
+                handled: Arc::new(std::sync::Mutex::new(Vec::new())),
             }
         }
+
+        /// Handle `event_type`; handlers whose ID starts with "fail" return an
+        /// error and ones starting with "panic" panic
+        fn with_event_handler(self, event_type: &str, handler_id: &str) -> Self {
+            self.with_prioritized_handler(event_type, handler_id, 0)
+        }
+
+        /// Like [`Self::with_event_handler`]; handlers whose ID starts with
+        /// "stop" consume the event
+        fn with_prioritized_handler(
+            mut self,
+            event_type: &str,
+            handler_id: &str,
+            priority: i32,
+        ) -> Self {
+            self.event_handlers.push(EventHandler {
+                event_type: event_type.to_string(),
+                handler_id: handler_id.to_string(),
+                priority,
+            });
+            self
+        }
+
+        /// Record the IDs of handlers that ran in `handled`
+        fn with_handled_log(mut self, handled: &Arc<std::sync::Mutex<Vec<String>>>) -> Self {
+            self.handled = Arc::clone(handled);
+            self
+        }
+
+        fn with_failing_initialize(mut self) -> Self {
+            self.fail_initialize = true;
+            self
+        }
+
+        fn with_menu_item(mut self, item: MenuItem) -> Self {
+            self.menus.push(item);
+            self
+        }
+
+        fn with_permission(mut self, resource: &str, action: &str) -> Self {
+            self.permissions.push(Permission {
+                resource: resource.to_string(),
+                action: action.to_string(),
+                scope: crate::auth::PermissionScope::Global,
+            });
+            self
+        }
+
+        fn with_settings(mut self, schema: serde_json::Value) -> Self {
+            self.settings = Some(SettingsSchema {
+                version: "1".to_string(),
+                schema,
+                defaults: serde_json::json!({}),
+            });
+            self
+        }
+
+        fn with_rate_limited_route(mut self, path: &str, rate_limit: RateLimit) -> Self {
+            self = self.with_api_route(path, None);
+            self.routes.last_mut().unwrap().rate_limit = Some(rate_limit);
+            self
+        }
+
+        fn with_api_route(mut self, path: &str, timeout_secs: Option<u64>) -> Self {
+            self.routes.push(ApiRoute {
+                path: path.to_string(),
+                method: HttpMethod::Get,
+                handler_id: path.to_string(),
+                required_permissions: Vec::new(),
+                rate_limit: None,
+                timeout_secs,
+                max_request_size: None,
+                documentation: ApiDocumentation {
+                    summary: String::new(),
+                    description: String::new(),
+                    parameters: Vec::new(),
+                    responses: Vec::new(),
+                    examples: Vec::new(),
+                },
+            });
+            self
+        }
+
+        fn with_api_delay(mut self, delay: Duration) -> Self {
+            self.api_delay = delay;
+            self
+        }
+
+        fn with_shutdown_delay(mut self, delay: Duration) -> Self {
+            self.shutdown_delay = delay;
+            self
+        }
+
+        fn with_provider(mut self, provider: Arc<dyn SearchProvider>) -> Self {
+            self.providers.push(provider);
+            self
+        }
+
+        fn with_version(mut self, version: &str) -> Self {
+            self.info.version = version.to_string();
+            self
+        }
+
+        fn with_dependency(mut self, plugin_id: &str, requirement: &str, optional: bool) -> Self {
+            self.dependencies.push(PluginDependency {
+                plugin_id: plugin_id.to_string(),
+                version_requirement: requirement.to_string(),
+                optional,
+            });
+            self
+        }
     }
 
     #[async_trait]
@@ -938,18 +4052,24 @@ mod tests {
         }
 
         fn required_dependencies(&self) -> Vec<PluginDependency> {
-            Vec::new()
+            self.dependencies.clone()
         }
 
         fn required_permissions(&self) -> Vec<Permission> {
-            Vec::new()
+            self.permissions.clone()
         }
 
         async fn initialize(&mut self, _context: PluginContext) -> Result<()> {
+            if self.fail_initialize {
+                return Err(Error::plugin(&self.info.id, "initialize failed"));
+            }
+            self.initialized
+                .store(true, std::sync::atomic::Ordering::SeqCst);
             Ok(())
         }
 
         async fn shutdown(&mut self) -> Result<()> {
+            tokio::time::sleep(self.shutdown_delay).await;
             Ok(())
         }
 
@@ -958,19 +4078,23 @@ mod tests {
         }
 
         fn menu_items(&self) -> Vec<MenuItem> {
-            Vec::new()
+            self.menus.clone()
         }
 
         fn settings_schema(&self) -> Option<SettingsSchema> {
-            None
+            self.settings.clone()
         }
 
         fn api_routes(&self) -> Vec<ApiRoute> {
-            Vec::new()
+            self.routes.clone()
         }
 
         fn event_handlers(&self) -> Vec<EventHandler> {
-            Vec::new()
+            self.event_handlers.clone()
+        }
+
+        fn search_providers(&self) -> Vec<Arc<dyn SearchProvider>> {
+            self.providers.clone()
         }
 
         fn render_component(
@@ -986,17 +4110,241 @@ mod tests {
 
         async fn handle_api_request(
             &self,
-            _route_id: &str,
+            route_id: &str,
             _request: ApiRequest,
         ) -> Result<ApiResponse> {
-            Err(Error::plugin(&self.info.id, "API handling not implemented"))
+            tokio::time::sleep(self.api_delay).await;
+            if route_id == "/panic" {
+                panic!("supplier feed returned garbage");
+            }
+            if route_id == "/throttled" {
+                return Err(Error::rate_limited(Some(30), "Supplier quota exhausted"));
+            }
+            Ok(ApiResponse {
+                status_code: 200,
+                description: route_id.to_string(),
+                schema: None,
+                cache: None,
+                retry_after_secs: None,
+                headers: Vec::new(),
+            })
+        }
+
+        async fn handle_event(&self, handler_id: &str, _event: &dyn Event) -> Result<()> {
+            self.handled.lock().unwrap().push(handler_id.to_string());
+            if handler_id.starts_with("fail") {
+                return Err(Error::plugin(&self.info.id, "Handler failed"));
+            }
+            if handler_id.starts_with("panic") {
+                panic!("handler {} panicked", handler_id);
+            }
+            Ok(())
+        }
+
+        async fn handle_event_propagation(
+            &self,
+            handler_id: &str,
+            event: &dyn Event,
+        ) -> Result<EventPropagation> {
+            self.handle_event(handler_id, event).await?;
+            if handler_id.starts_with("stop") {
+                Ok(EventPropagation::Stop)
+            } else {
+                Ok(EventPropagation::Continue)
+            }
+        }
+    }
+
+    /// Search provider whose health check takes `delay` to answer
+    #[derive(Debug)]
+    struct SlowProvider {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl SearchProvider for SlowProvider {
+        fn provider_id(&self) -> &str {
+            "slow"
+        }
+
+        fn provider_name(&self) -> &str {
+            "Slow Provider"
+        }
+
+        fn description(&self) -> &str {
+            "Answers health checks slowly"
+        }
+
+        fn priority(&self) -> i32 {
+            0
+        }
+
+        fn supported_result_types(&self) -> Vec<String> {
+            Vec::new()
+        }
+
+        fn supports_facets(&self) -> bool {
+            false
+        }
+
+        fn supports_suggestions(&self) -> bool {
+            false
+        }
+
+        async fn search(&self, _query: &search::SearchQuery) -> Result<Vec<search::SearchResult>> {
+            Ok(Vec::new())
+        }
+
+        async fn health_check(&self) -> Result<ProviderHealth> {
+            tokio::time::sleep(self.delay).await;
+            Ok(ProviderHealth {
+                is_healthy: true,
+                response_time_ms: Some(self.delay.as_millis() as u64),
+                error_message: None,
+                last_check: Time::now(),
+            })
+        }
+    }
+
+    struct NoopLoader;
+
+    #[async_trait]
+    impl PluginLoader for NoopLoader {
+        async fn load_plugin(&self, path: &str) -> Result<Box<dyn Plugin>> {
+            Err(Error::plugin(path, "Loading not supported"))
+        }
+
+        async fn validate_plugin(&self, _plugin: &dyn Plugin) -> Result<ValidationResult> {
+            Ok(ValidationResult {
+                is_valid: true,
+                errors: Vec::new(),
+                warnings: Vec::new(),
+            })
         }
 
-        async fn handle_event(&self, _handler_id: &str, _event: &dyn Event) -> Result<()> {
+        async fn unload_plugin(&self, _plugin_id: &str) -> Result<()> {
             Ok(())
         }
     }
 
+    /// Loads a "catalog" plugin whose version is the artifact's contents
+    #[cfg(feature = "hot-reload")]
+    struct ArtifactLoader;
+
+    #[cfg(feature = "hot-reload")]
+    #[async_trait]
+    impl PluginLoader for ArtifactLoader {
+        async fn load_plugin(&self, path: &str) -> Result<Box<dyn Plugin>> {
+            let version = std::fs::read_to_string(path)?;
+            Ok(Box::new(
+                TestPlugin::new("catalog".to_string()).with_version(version.trim()),
+            ))
+        }
+
+        async fn validate_plugin(&self, _plugin: &dyn Plugin) -> Result<ValidationResult> {
+            Ok(ValidationResult {
+                is_valid: true,
+                errors: Vec::new(),
+                warnings: Vec::new(),
+            })
+        }
+
+        async fn unload_plugin(&self, _plugin_id: &str) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "hot-reload")]
+    #[tokio::test]
+    async fn test_reload_plugin_swaps_instance_and_keeps_config() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let artifact = temp_dir.path().join("catalog.wasm");
+        std::fs::write(&artifact, "1.0.0").unwrap();
+
+        let mut manager = PluginManager::new(Box::new(ArtifactLoader));
+        manager
+            .load_plugin(&artifact.to_string_lossy())
+            .await
+            .unwrap();
+        manager.initialize_plugins().await.unwrap();
+        manager
+            .plugin_contexts
+            .get_mut("catalog")
+            .unwrap()
+            .config
+            .user_overrides = serde_json::json!({ "currency": "EUR" });
+
+        std::fs::write(&artifact, "1.1.0").unwrap();
+        assert!(manager.reload_plugin(&artifact).await.is_err());
+        assert_eq!(
+            manager.registry.get("catalog").unwrap().info().version,
+            "1.0.0"
+        );
+
+        manager.set_hot_reload(true);
+        assert_eq!(manager.reload_plugin(&artifact).await.unwrap(), "catalog");
+        assert_eq!(
+            manager.registry.get("catalog").unwrap().info().version,
+            "1.1.0"
+        );
+        assert_eq!(
+            manager.plugin_contexts["catalog"].config.user_overrides["currency"],
+            "EUR"
+        );
+        assert_eq!(
+            manager.stats().per_plugin["catalog"].status,
+            PluginStatus::Running
+        );
+
+        // A broken artifact leaves the running instance alone
+        std::fs::remove_file(&artifact).unwrap();
+        assert!(manager.reload_plugin(&artifact).await.is_err());
+        assert_eq!(
+            manager.registry.get("catalog").unwrap().info().version,
+            "1.1.0"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_health_check_all() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager
+            .registry
+            .register(Box::new(TestPlugin::new("idle".to_string())))
+            .unwrap();
+        manager
+            .registry
+            .register(Box::new(TestPlugin::new("fast".to_string()).with_provider(
+                Arc::new(search::ExampleSearchProvider::new(
+                    "fast".to_string(),
+                    "Fast".to_string(),
+                )),
+            )))
+            .unwrap();
+        manager
+            .registry
+            .register(Box::new(TestPlugin::new("slow".to_string()).with_provider(
+                Arc::new(SlowProvider {
+                    delay: Duration::from_secs(5),
+                }),
+            )))
+            .unwrap();
+
+        let health = manager
+            .health_check_all_with_timeout(Duration::from_millis(50))
+            .await;
+
+        assert_eq!(health.len(), 3);
+        assert!(health["idle"].is_healthy);
+        assert!(health["fast"].is_healthy);
+        assert!(!health["slow"].is_healthy);
+        assert!(health["slow"]
+            .error_message
+            .as_deref()
+            .unwrap()
+            .contains("timed out"));
+    }
+
     #[test]
     fn test_plugin_registry() {
         let mut registry = PluginRegistry::new();
@@ -1008,6 +4356,262 @@ mod tests {
         assert_eq!(registry.list().len(), 1);
     }
 
+    #[test]
+    fn test_plugin_file_system_rejects_traversal() {
+        let fs = PluginFileSystem::new(
+            "sandboxed".to_string(),
+            Arc::new(crate::platform::MockFileSystem::new()),
+        );
+
+        for path in [
+            "..\\..\\etc",
+            "C:\\Windows",
+            "C:/Windows",
+            "\\\\server\\share",
+            "foo/../../bar",
+            "../secrets",
+            "/etc/passwd",
+            "%2e%2e/secrets",
+            "data\0.txt",
+            "",
+        ] {
+            assert!(fs.make_safe_path(path).is_err(), "{:?} was accepted", path);
+        }
+
+        assert_eq!(
+            fs.make_safe_path("foo/./bar/../data.json").unwrap(),
+            "plugins/sandboxed/foo/data.json"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_sandbox_paths_resolve_symlinks_on_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let sandbox = dir.path().join("plugins/sandboxed");
+        std::fs::create_dir_all(&sandbox).unwrap();
+        std::fs::create_dir_all(dir.path().join("secrets")).unwrap();
+        std::os::unix::fs::symlink(dir.path().join("secrets"), sandbox.join("link")).unwrap();
+
+        let within = |path: &str| resolve_existing(&sandbox.join(path)).unwrap();
+        let root = resolve_existing(&sandbox).unwrap();
+        assert!(within("notes/new.txt").starts_with(&root));
+        assert!(!within("link/key.pem").starts_with(&root));
+
+        // A sandbox that doesn't exist yet resolves through its existing parent
+        let fresh = resolve_existing(&dir.path().join("plugins/fresh/data.json")).unwrap();
+        assert!(fresh.starts_with(resolve_existing(dir.path()).unwrap()));
+    }
+
+    #[test]
+    fn test_statement_classification() {
+        let action = |query: &str| SqlStatement::parse(query).unwrap().action();
+
+        assert_eq!(action("SELECT * FROM items"), "read");
+        assert_eq!(action("  select 1;  -- trailing comment"), "read");
+        assert_eq!(action("PRAGMA table_info(items)"), "read");
+        assert_eq!(action("PRAGMA journal_mode = DELETE"), "write");
+        assert_eq!(action("/* DROP */ SELECT 'DELETE'"), "read");
+        assert_eq!(action("/*x*/ DROP TABLE items"), "manage");
+        assert_eq!(action("-- note\ncreate index idx on items (id)"), "manage");
+        assert_eq!(
+            action("WITH old AS (SELECT replace(name, 'a', 'b') FROM items) SELECT * FROM old"),
+            "read"
+        );
+        assert_eq!(
+            action("WITH old AS (SELECT id FROM items) DELETE FROM items WHERE id IN old"),
+            "write"
+        );
+        assert_eq!(action("EXPLAIN ANALYZE DELETE FROM items"), "write");
+        assert_eq!(action("EXPLAIN QUERY PLAN SELECT 1"), "read");
+
+        assert!(SqlStatement::parse("SELECT 1; DROP TABLE items").is_none());
+        assert!(SqlStatement::parse("SELECT 1;; ").is_none());
+        assert!(SqlStatement::parse("SELECT ';' FROM items; /* done */").is_some());
+
+        let statement = SqlStatement::parse("create temp table if not exists t (id int)").unwrap();
+        assert!(statement.is_table_ddl("CREATE"));
+        assert!(!statement.is_table_ddl("DROP"));
+        assert!(!SqlStatement::parse("CREATE INDEX t ON items (id)")
+            .unwrap()
+            .is_table_ddl("CREATE"));
+    }
+
+    #[test]
+    fn test_unsupported_statements() {
+        let supported = |query: &str| SqlStatement::parse(query).unwrap().is_supported();
+
+        assert!(supported("SELECT * FROM items"));
+        assert!(supported("EXPLAIN QUERY PLAN SELECT * FROM items"));
+        assert!(supported("INSERT OR REPLACE INTO items VALUES (1)"));
+        assert!(supported("CREATE UNIQUE INDEX idx ON items (id)"));
+        assert!(supported("CREATE TEMP VIEW v AS SELECT 1"));
+        assert!(supported("ALTER TABLE items ADD COLUMN note TEXT"));
+        assert!(supported("PRAGMA table_info(items)"));
+
+        assert!(!supported("ATTACH DATABASE 'other.db' AS other"));
+        assert!(!supported("DETACH other"));
+        assert!(!supported("VACUUM"));
+        assert!(!supported("VACUUM INTO '/tmp/copy.db'"));
+        assert!(!supported("PRAGMA journal_mode = DELETE"));
+        assert!(!supported("PRAGMA database_list"));
+        assert!(!supported("PRAGMA main.table_info(items)"));
+        assert!(!supported("PRAGMA table_info(items, other)"));
+        assert!(!supported(
+            "CREATE TRIGGER t AFTER INSERT ON items BEGIN SELECT 1"
+        ));
+        assert!(!supported("BEGIN TRANSACTION"));
+        assert!(!supported("REINDEX items"));
+        assert!(!supported("-- nothing"));
+    }
+
+    #[test]
+    fn test_prefix_table_names() {
+        let prefix = |query: &str| prefix_table_names(query, &plugin_table_prefix("x")).unwrap();
+
+        assert_eq!(
+            prefix("CREATE TABLE foo (id INTEGER)"),
+            "CREATE TABLE plugin_x__foo (id INTEGER)"
+        );
+        assert_eq!(
+            prefix("create table if not exists foo (id integer)"),
+            "create table if not exists plugin_x__foo (id integer)"
+        );
+        assert_eq!(prefix("DROP TABLE foo"), "DROP TABLE plugin_x__foo");
+        assert_eq!(
+            prefix("SELECT a.id FROM foo a JOIN bar b ON a.id = b.foo_id WHERE a.x = ?"),
+            "SELECT a.id FROM plugin_x__foo a JOIN plugin_x__bar b ON a.id = b.foo_id WHERE a.x = ?"
+        );
+        assert_eq!(
+            prefix("SELECT * FROM foo, bar AS b WHERE foo.id = b.id"),
+            "SELECT * FROM plugin_x__foo, plugin_x__bar AS b WHERE plugin_x__foo.id = b.id"
+        );
+        assert_eq!(
+            prefix("INSERT INTO foo (name) VALUES ('FROM users')"),
+            "INSERT INTO plugin_x__foo (name) VALUES ('FROM users')"
+        );
+        assert_eq!(
+            prefix("UPDATE foo SET note = 'it''s INTO bar' WHERE id = 1"),
+            "UPDATE plugin_x__foo SET note = 'it''s INTO bar' WHERE id = 1"
+        );
+        assert_eq!(
+            prefix("DELETE FROM \"foo\" -- FROM users\nWHERE id IN (SELECT id FROM bar)"),
+            "DELETE FROM \"plugin_x__foo\" -- FROM users\nWHERE id IN (SELECT id FROM plugin_x__bar)"
+        );
+        assert_eq!(
+            prefix("SELECT foo.id, \"foo\".name FROM foo WHERE foo.id > a.foo"),
+            "SELECT plugin_x__foo.id, \"plugin_x__foo\".name FROM plugin_x__foo WHERE plugin_x__foo.id > a.foo"
+        );
+
+        // Already-prefixed names are prefixed again rather than trusted
+        assert_eq!(
+            prefix("SELECT * FROM plugin_y__foo"),
+            "SELECT * FROM plugin_x__plugin_y__foo"
+        );
+    }
+
+    #[test]
+    fn test_prefix_table_names_cannot_be_escaped() {
+        let prefix = |query: &str| prefix_table_names(query, &plugin_table_prefix("x"));
+
+        // FROM lists continue past subqueries and join constraints
+        assert_eq!(
+            prefix("SELECT * FROM (SELECT a, b FROM foo) AS t, users").unwrap(),
+            "SELECT * FROM (SELECT a, b FROM plugin_x__foo) AS t, plugin_x__users"
+        );
+        assert_eq!(
+            prefix("SELECT * FROM foo JOIN bar ON foo.id = bar.id, users").unwrap(),
+            "SELECT * FROM plugin_x__foo JOIN plugin_x__bar ON plugin_x__foo.id = plugin_x__bar.id, plugin_x__users"
+        );
+        assert_eq!(
+            prefix("SELECT * FROM (users)").unwrap(),
+            "SELECT * FROM (plugin_x__users)"
+        );
+        assert_eq!(
+            prefix("SELECT * FROM [users]").unwrap(),
+            "SELECT * FROM [plugin_x__users]"
+        );
+
+        // Schema changes name their targets too
+        assert_eq!(
+            prefix("ALTER TABLE foo RENAME TO users").unwrap(),
+            "ALTER TABLE plugin_x__foo RENAME TO plugin_x__users"
+        );
+        assert_eq!(
+            prefix("ALTER TABLE foo RENAME COLUMN a TO b").unwrap(),
+            "ALTER TABLE plugin_x__foo RENAME COLUMN a TO b"
+        );
+        assert_eq!(
+            prefix("CREATE UNIQUE INDEX IF NOT EXISTS idx ON foo (id)").unwrap(),
+            "CREATE UNIQUE INDEX IF NOT EXISTS plugin_x__idx ON plugin_x__foo (id)"
+        );
+        assert_eq!(
+            prefix("SELECT * FROM foo INDEXED BY idx").unwrap(),
+            "SELECT * FROM plugin_x__foo INDEXED BY plugin_x__idx"
+        );
+        assert_eq!(
+            prefix("CREATE TABLE foo (user_id INTEGER REFERENCES users(id))").unwrap(),
+            "CREATE TABLE plugin_x__foo (user_id INTEGER REFERENCES plugin_x__users(id))"
+        );
+        assert_eq!(
+            prefix("PRAGMA table_info(users)").unwrap(),
+            "PRAGMA table_info(plugin_x__users)"
+        );
+
+        // Keywords that don't name a table here
+        assert_eq!(
+            prefix("INSERT INTO foo (id) VALUES (1) ON CONFLICT (id) DO UPDATE SET n = n + 1")
+                .unwrap(),
+            "INSERT INTO plugin_x__foo (id) VALUES (1) ON CONFLICT (id) DO UPDATE SET n = n + 1"
+        );
+        assert_eq!(
+            prefix("SELECT EXTRACT(YEAR FROM created) FROM foo").unwrap(),
+            "SELECT EXTRACT(YEAR FROM created) FROM plugin_x__foo"
+        );
+        assert_eq!(
+            prefix("SELECT * FROM foo WHERE a IS DISTINCT FROM b").unwrap(),
+            "SELECT * FROM plugin_x__foo WHERE a IS DISTINCT FROM b"
+        );
+
+        // CTE names are prefixed where they are defined and where they are used
+        assert_eq!(
+            prefix("WITH recent AS (SELECT id FROM foo), old AS (SELECT id FROM foo) SELECT recent.id FROM recent, old").unwrap(),
+            "WITH plugin_x__recent AS (SELECT id FROM plugin_x__foo), plugin_x__old AS (SELECT id FROM plugin_x__foo) SELECT plugin_x__recent.id FROM plugin_x__recent, plugin_x__old"
+        );
+
+        // Schema qualifiers and strings in table position are refused
+        assert!(prefix("SELECT * FROM main.users").is_none());
+        assert!(prefix("SELECT * FROM \"temp\" . users").is_none());
+        assert!(prefix("INSERT INTO foo SELECT * FROM foo, main.users").is_none());
+        assert!(prefix("SELECT * FROM 'users'").is_none());
+    }
+
+    #[test]
+    fn test_plugin_table_prefixes_are_unambiguous() {
+        assert_eq!(plugin_table_prefix("notes"), "plugin_notes__");
+        assert_eq!(plugin_table_prefix("my_app"), "plugin_my_5fapp__");
+        assert_eq!(plugin_table_prefix("my-app"), "plugin_my_2dapp__");
+
+        // Plugin `a` writing to `b_items` cannot land in plugin `a_b`'s `items`
+        let a = prefix_table_names("INSERT INTO b_items VALUES (1)", &plugin_table_prefix("a"));
+        let a_b = prefix_table_names("INSERT INTO items VALUES (1)", &plugin_table_prefix("a_b"));
+        assert_ne!(a, a_b);
+        assert!(!plugin_table_prefix("a_b").starts_with(&plugin_table_prefix("a")));
+    }
+
+    #[test]
+    fn test_api_request_body_limits() {
+        let limits = JsonLimits::default();
+
+        let body = ApiRequest::parse_body(r#"{"name": "widget"}"#, &limits).unwrap();
+        assert_eq!(body.unwrap()["name"], "widget");
+        assert!(ApiRequest::parse_body("  ", &limits).unwrap().is_none());
+
+        let depth = 10_000;
+        let deep = format!("{}1{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(ApiRequest::parse_body(&deep, &limits).is_err());
+    }
+
     #[test]
     fn test_dependency_resolution() {
         let resolver = DependencyResolver::new();
@@ -1017,4 +4621,1731 @@ mod tests {
         let resolved = resolver.resolve(&plugin, &registry).unwrap();
         assert!(resolved.is_empty()); // No dependencies
     }
+
+    #[test]
+    fn test_load_order_respects_dependencies() {
+        let mut registry = PluginRegistry::new();
+        registry
+            .register(Box::new(
+                TestPlugin::new("app".to_string()).with_dependency("ui", "^1.0", false),
+            ))
+            .unwrap();
+        registry
+            .register(Box::new(
+                TestPlugin::new("ui".to_string()).with_dependency("core", ">=1.0.0", false),
+            ))
+            .unwrap();
+        registry
+            .register(Box::new(TestPlugin::new("core".to_string())))
+            .unwrap();
+
+        let order = registry.resolve_load_order().unwrap();
+        assert_eq!(order, ["core", "ui", "app"]);
+    }
+
+    #[test]
+    fn test_circular_dependency_names_cycle() {
+        let mut registry = PluginRegistry::new();
+        registry
+            .register(Box::new(
+                TestPlugin::new("a".to_string()).with_dependency("b", "*", false),
+            ))
+            .unwrap();
+        registry
+            .register(Box::new(
+                TestPlugin::new("b".to_string()).with_dependency("a", "*", false),
+            ))
+            .unwrap();
+
+        let err = registry.resolve_load_order().unwrap_err();
+        assert!(err.message.contains("a -> b -> a"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_dependency_version_mismatch() {
+        let mut registry = PluginRegistry::new();
+        registry
+            .register(Box::new(
+                TestPlugin::new("app".to_string()).with_dependency("core", "^2.0", false),
+            ))
+            .unwrap();
+        registry
+            .register(Box::new(
+                TestPlugin::new("core".to_string()).with_version("1.4.0"),
+            ))
+            .unwrap();
+
+        assert!(registry.resolve_load_order().is_err());
+    }
+
+    #[test]
+    fn test_missing_dependencies() {
+        let mut registry = PluginRegistry::new();
+        registry
+            .register(Box::new(
+                TestPlugin::new("app".to_string()).with_dependency("extras", "*", true),
+            ))
+            .unwrap();
+        assert_eq!(registry.resolve_load_order().unwrap(), ["app"]);
+
+        registry
+            .register(Box::new(
+                TestPlugin::new("tool".to_string()).with_dependency("core", "*", false),
+            ))
+            .unwrap();
+        let err = registry.resolve_load_order().unwrap_err();
+        assert!(matches!(
+            err.kind,
+            crate::error::ErrorKind::Plugin {
+                dependency_missing: Some(ref dep),
+                ..
+            } if dep == "core"
+        ));
+    }
+
+    #[test]
+    fn test_version_requirements() {
+        let resolver = DependencyResolver::new();
+        assert!(resolver.check_version_compatibility("*", "3.1.4"));
+        assert!(resolver.check_version_compatibility("^1.2", "1.9.0"));
+        assert!(!resolver.check_version_compatibility("^1.2", "2.0.0"));
+        assert!(!resolver.check_version_compatibility("^0.2.1", "0.3.0"));
+        assert!(resolver.check_version_compatibility("~1.2.3", "1.2.9"));
+        assert!(!resolver.check_version_compatibility("~1.2.3", "1.3.0"));
+        assert!(resolver.check_version_compatibility(">=1.0.0, <2.0.0", "1.5.0"));
+        assert!(!resolver.check_version_compatibility(">=1.0.0, <2.0.0", "2.0.0"));
+        assert!(!resolver.check_version_compatibility("=1.0.0", "1.0.0-beta"));
+        assert!(resolver.check_version_compatibility("=1.0.0-beta", "1.0.0-beta"));
+        assert!(resolver.check_version_compatibility("~1", "1.9.0"));
+        assert!(!resolver.check_version_compatibility("~1", "2.0.0"));
+        assert!(resolver.check_version_compatibility("1.0", "1.0.7"));
+        assert!(resolver.check_version_compatibility("", "1.0.7"));
+        assert!(!resolver.check_version_compatibility("^1.0", "not-a-version"));
+        assert!(!resolver.check_version_compatibility("not a requirement", "1.0.0"));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_all_reverse_order_with_timeout() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_shutdown_timeout(Duration::from_millis(50));
+        manager
+            .registry
+            .register(Box::new(
+                TestPlugin::new("app".to_string()).with_dependency("core", "*", false),
+            ))
+            .unwrap();
+        manager
+            .registry
+            .register(Box::new(
+                TestPlugin::new("stuck".to_string())
+                    .with_dependency("core", "*", false)
+                    .with_shutdown_delay(Duration::from_secs(5)),
+            ))
+            .unwrap();
+        manager
+            .registry
+            .register(Box::new(TestPlugin::new("core".to_string())))
+            .unwrap();
+        manager.initialize_plugins().await.unwrap();
+
+        let summary = manager.shutdown_all().await;
+
+        assert_eq!(summary.clean, ["app", "core"]);
+        assert_eq!(summary.abandoned, ["stuck"]);
+        assert!(summary.failed.is_empty());
+        assert!(!summary.is_clean());
+        assert!(manager.registry.list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_api_request_timeout_returns_504() {
+        let get = |path: &str| ApiRequest::new("GET", path);
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_request_timeout(Duration::from_millis(50));
+        manager
+            .registry
+            .register(Box::new(
+                TestPlugin::new("slow".to_string())
+                    .with_api_route("/slow", None)
+                    .with_api_route("/patient", Some(5))
+                    .with_api_delay(Duration::from_millis(200)),
+            ))
+            .unwrap();
+
+        let response = manager
+            .handle_api_request("slow", get("/slow"))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 504);
+        assert_eq!(response.cache, Some(CacheDirective::NoStore));
+        assert_eq!(manager.api_timeout_count(), 1);
+
+        // The route's own timeout outlasts the handler
+        let response = manager
+            .handle_api_request("slow", get("/patient"))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(manager.api_timeout_count(), 1);
+
+        assert!(manager
+            .handle_api_request("slow", get("/missing"))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_api_rate_limit_returns_429() {
+        let from = |ip: &str| {
+            ApiRequest::new("GET", "/limited")
+                .with_header("X-Forwarded-For", &format!("{}, 10.0.0.1", ip))
+                .with_remote_addr("10.0.0.1")
+        };
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_rate_limiter(Arc::new(RateLimiterStore::new()));
+        manager.set_trusted_proxies(vec!["10.0.0.1".parse().unwrap()]);
+        manager
+            .registry
+            .register(Box::new(
+                TestPlugin::new("limited".to_string()).with_rate_limited_route(
+                    "/limited",
+                    RateLimit {
+                        requests_per_minute: 1,
+                        burst_limit: 2,
+                    },
+                ),
+            ))
+            .unwrap();
+
+        for _ in 0..2 {
+            let response = manager
+                .handle_api_request("limited", from("192.0.2.1"))
+                .await
+                .unwrap();
+            assert_eq!(response.status_code, 200);
+        }
+        let response = manager
+            .handle_api_request("limited", from("192.0.2.1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 429);
+        let retry_after = response.retry_after_secs.unwrap();
+        assert!(retry_after > 0 && retry_after <= 60);
+
+        // Other clients have their own bucket
+        let response = manager
+            .handle_api_request("limited", from("192.0.2.2"))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+
+        // Untrusted peers can't pick a fresh bucket by forging the header
+        let spoofed = |ip: &str| from(ip).with_remote_addr("198.51.100.7");
+        for _ in 0..2 {
+            let response = manager
+                .handle_api_request("limited", spoofed("192.0.2.3"))
+                .await
+                .unwrap();
+            assert_eq!(response.status_code, 200);
+        }
+        let response = manager
+            .handle_api_request("limited", spoofed("192.0.2.4"))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 429);
+    }
+
+    #[tokio::test]
+    async fn test_api_handler_errors_map_to_http_status() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager
+            .registry
+            .register(Box::new(
+                TestPlugin::new("supplier".to_string()).with_api_route("/throttled", None),
+            ))
+            .unwrap();
+
+        let response = manager
+            .handle_api_request("supplier", ApiRequest::new("GET", "/throttled"))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 429);
+        assert_eq!(response.retry_after_secs, Some(30));
+        assert_eq!(
+            response.schema.unwrap()["error"],
+            "Supplier quota exhausted"
+        );
+
+        let response = api_error_response(&Error::plugin("supplier", "Connection pool leaked"));
+        assert_eq!(response.status_code, 500);
+        assert_eq!(response.schema.unwrap()["error"], "Internal Server Error");
+    }
+
+    #[tokio::test]
+    async fn test_runtime_stats_count_dispatched_work() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("supplier".to_string())
+                    .with_api_route("/stock", None)
+                    .with_api_route("/throttled", None)
+                    .with_event_handler("inventory.updated", "on_stock"),
+            ))
+            .await
+            .unwrap();
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("pricing".to_string())
+                    .with_event_handler("inventory.updated", "fail_reprice"),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            manager.stats().per_plugin["supplier"].status,
+            PluginStatus::Loaded
+        );
+        manager.initialize_plugins().await.unwrap();
+
+        let get = |path: &str| ApiRequest::new("GET", path);
+        manager
+            .handle_api_request("supplier", get("/stock"))
+            .await
+            .unwrap();
+        let event = sdk::PluginEvent::new(
+            "inventory.updated",
+            "supplier",
+            "supplier",
+            serde_json::json!({ "sku": "A-1" }),
+        );
+        assert_eq!(manager.dispatch_event(&event).await, 2);
+
+        let stats = manager.stats().per_plugin;
+        assert_eq!(stats["supplier"].status, PluginStatus::Running);
+        assert_eq!(stats["supplier"].api_requests, 1);
+        assert_eq!(stats["supplier"].events_handled, 1);
+        assert!(stats["supplier"].last_error.is_none());
+        assert_eq!(stats["pricing"].events_handled, 1);
+        assert!(stats["pricing"].last_error.is_some());
+
+        manager
+            .handle_api_request("supplier", get("/throttled"))
+            .await
+            .unwrap();
+        let supplier = &manager.stats().per_plugin["supplier"];
+        assert_eq!(supplier.api_requests, 2);
+        assert!(supplier.last_error.is_some());
+
+        manager.shutdown_all().await;
+        assert_eq!(
+            manager.stats().per_plugin["pricing"].status,
+            PluginStatus::Stopped
+        );
+    }
+
+    #[tokio::test]
+    async fn test_event_handlers_run_by_priority_until_stopped() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        let handled = Arc::new(std::sync::Mutex::new(Vec::new()));
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("audit".to_string())
+                    .with_prioritized_handler("inventory.updated", "audit_stock", -5)
+                    .with_handled_log(&handled),
+            ))
+            .await
+            .unwrap();
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("pricing".to_string())
+                    .with_prioritized_handler("inventory.updated", "reprice", 10)
+                    .with_prioritized_handler("*", "fail_any", 10)
+                    .with_handled_log(&handled),
+            ))
+            .await
+            .unwrap();
+        manager.initialize_plugins().await.unwrap();
+
+        let event = sdk::PluginEvent::new(
+            "inventory.updated",
+            "supplier",
+            "supplier",
+            serde_json::json!({ "sku": "A-1" }),
+        );
+        // A failing handler does not stop propagation
+        assert_eq!(manager.dispatch_event(&event).await, 3);
+        assert_eq!(
+            *handled.lock().unwrap(),
+            vec!["reprice", "fail_any", "audit_stock"]
+        );
+
+        handled.lock().unwrap().clear();
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("reservations".to_string())
+                    .with_prioritized_handler("inventory.updated", "stop_reserve", 50)
+                    .with_handled_log(&handled),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(manager.dispatch_event(&event).await, 1);
+        assert_eq!(*handled.lock().unwrap(), vec!["stop_reserve"]);
+        assert_eq!(manager.stats().per_plugin["pricing"].events_handled, 1);
+    }
+
+    #[tokio::test]
+    async fn test_oversized_events_are_not_dispatched() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_payload_guard(EventPayloadGuard::new(1024));
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("supplier".to_string())
+                    .with_event_handler("inventory.updated", "on_stock"),
+            ))
+            .await
+            .unwrap();
+        manager.initialize_plugins().await.unwrap();
+
+        let event = |payload: serde_json::Value| {
+            sdk::PluginEvent::new("inventory.updated", "supplier", "supplier", payload)
+        };
+        assert_eq!(
+            manager
+                .dispatch_event(&event(serde_json::json!({ "sku": "A-1" })))
+                .await,
+            1
+        );
+        assert_eq!(
+            manager
+                .dispatch_event(&event(serde_json::json!({ "notes": "x".repeat(4096) })))
+                .await,
+            0
+        );
+
+        assert_eq!(manager.payload_guard().rejected_count(), 1);
+        assert_eq!(manager.stats().per_plugin["supplier"].events_handled, 1);
+        let status = manager.status().await;
+        assert_eq!(
+            status.metadata.get("rejected_events"),
+            Some(&serde_json::Value::from(1u64))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_panicking_handlers_are_isolated() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("supplier".to_string())
+                    .with_api_route("/panic", None)
+                    .with_api_route("/stock", None)
+                    .with_event_handler("inventory.updated", "panic_on_stock"),
+            ))
+            .await
+            .unwrap();
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("pricing".to_string())
+                    .with_event_handler("inventory.updated", "reprice"),
+            ))
+            .await
+            .unwrap();
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("warehouse".to_string())
+                    .with_event_handler("inventory.updated", "panic_on_stock"),
+            ))
+            .await
+            .unwrap();
+        manager.initialize_plugins().await.unwrap();
+
+        let get = |path: &str| ApiRequest::new("GET", path);
+        let response = manager
+            .handle_api_request("supplier", get("/panic"))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 500);
+        assert_eq!(
+            manager.stats().per_plugin["supplier"].status,
+            PluginStatus::Failed
+        );
+        assert!(manager.is_failed("supplier"));
+
+        // The failed plugin gets no more requests or events, and reports it
+        let response = manager
+            .handle_api_request("supplier", get("/stock"))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 503);
+        let event = sdk::PluginEvent::new(
+            "inventory.updated",
+            "supplier",
+            "supplier",
+            serde_json::json!({ "sku": "A-1" }),
+        );
+        let health = manager.health_check_all().await;
+        assert!(!health["supplier"].is_healthy);
+        assert!(health["pricing"].is_healthy);
+
+        // A panicking event handler fails its plugin too; the others still run
+        assert_eq!(manager.dispatch_event(&event).await, 2);
+        assert_eq!(manager.stats().per_plugin["supplier"].events_handled, 0);
+        assert!(manager.is_failed("warehouse"));
+        assert!(manager.stats().per_plugin["warehouse"].last_error.is_some());
+        assert!(manager.stats().per_plugin["pricing"].last_error.is_none());
+        assert_eq!(manager.dispatch_event(&event).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_api_request_body_over_limit_returns_413() {
+        let post = |path: &str| ApiRequest::new("POST", path);
+        // `{"data":"..."}` is the string's length plus 11 bytes
+        let body = |len: usize| format!(r#"{{"data":"{}"}}"#, "x".repeat(len));
+        assert_eq!(body(89).len(), 100);
+
+        let mut plugin = TestPlugin::new("uploads".to_string())
+            .with_api_route("/upload", None)
+            .with_api_route("/bulk", None);
+        for route in &mut plugin.routes {
+            route.method = HttpMethod::Post;
+        }
+        plugin.routes[1].max_request_size = Some(1000);
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_max_request_size(100);
+        manager.registry.register(Box::new(plugin)).unwrap();
+
+        let response = manager
+            .route_raw_api_request(post("/upload"), &body(89))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+
+        let response = manager
+            .route_raw_api_request(post("/upload"), &body(90))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 413);
+        assert_eq!(response.cache, Some(CacheDirective::NoStore));
+
+        // The limit applies to the raw text, before any parsing
+        let response = manager
+            .route_raw_api_request(post("/upload"), &"[".repeat(101))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 413);
+        let response = manager
+            .route_raw_api_request(post("/upload"), "{not json")
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 400);
+
+        // The route's own limit replaces the manager's
+        let response = manager
+            .route_raw_api_request(post("/bulk"), &body(500))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+        let response = manager
+            .route_raw_api_request(post("/bulk"), &body(990))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 413);
+    }
+
+    #[tokio::test]
+    async fn test_api_requests_apply_cors_policy() {
+        let request = |method: &str, headers: &[(&str, &str)]| {
+            headers
+                .iter()
+                .fold(ApiRequest::new(method, "/api/items"), |request, (k, v)| {
+                    request.with_header(k, v)
+                })
+        };
+        let allow_origin = |response: &ApiResponse| {
+            response
+                .http_headers()
+                .into_iter()
+                .find(|(name, _)| name == "Access-Control-Allow-Origin")
+                .map(|(_, value)| value)
+        };
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_cors_policy(
+            CorsPolicy::from_config(&crate::config::SecurityConfig {
+                cors_origins: vec!["https://app.example.com".to_string()],
+                ..Default::default()
+            })
+            .unwrap(),
+        );
+        manager
+            .registry
+            .register(Box::new(
+                TestPlugin::new("items".to_string()).with_api_route("/api/items", None),
+            ))
+            .unwrap();
+
+        let preflight = request(
+            "OPTIONS",
+            &[
+                ("Origin", "https://app.example.com"),
+                ("Access-Control-Request-Method", "GET"),
+            ],
+        );
+        let response = manager.route_api_request(preflight.clone()).await.unwrap();
+        assert_eq!(response.status_code, 204);
+        assert_eq!(
+            allow_origin(&response).as_deref(),
+            Some("https://app.example.com")
+        );
+        // Preflights never reach the plugin, even without an OPTIONS route
+        let response = manager
+            .handle_api_request("items", preflight)
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 204);
+
+        let response = manager
+            .route_api_request(request("GET", &[("Origin", "https://app.example.com")]))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(
+            allow_origin(&response).as_deref(),
+            Some("https://app.example.com")
+        );
+
+        let response = manager
+            .route_api_request(request("GET", &[("Origin", "https://evil.example.com")]))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(allow_origin(&response), None);
+    }
+
+    #[tokio::test]
+    async fn test_route_api_request_matches_patterns_and_permissions() {
+        let request = |method: &str, path: &str, user: Option<User>| {
+            ApiRequest::new(method, path).with_user(user)
+        };
+        let grant = |action: &str| Permission {
+            resource: "products".to_string(),
+            action: action.to_string(),
+            scope: crate::auth::PermissionScope::Global,
+        };
+
+        let mut plugin = TestPlugin::new("catalog".to_string())
+            .with_api_route("/api/products/{id}", None)
+            .with_api_route("/api/products/search", None);
+        plugin.routes[0].required_permissions = vec![grant("read")];
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.registry.register(Box::new(plugin)).unwrap();
+        assert_eq!(manager.api_router().len(), 2);
+
+        let reader = crate::auth::test_user("reader", vec![grant("read")]);
+        let response = manager
+            .route_api_request(request("GET", "/api/products/42", Some(reader)))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.description, "/api/products/{id}");
+
+        let response = manager
+            .route_api_request(request("GET", "/api/products/42", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 403);
+
+        // Calling the plugin directly enforces the same permissions
+        let response = manager
+            .handle_api_request("catalog", request("GET", "/api/products/{id}", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 403);
+
+        let response = manager
+            .route_api_request(request("GET", "/api/products/search", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.description, "/api/products/search");
+
+        let response = manager
+            .route_api_request(request("POST", "/api/products/42", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 404);
+    }
+
+    #[tokio::test]
+    async fn test_route_api_request_serves_event_poll() {
+        let mut event_bus = EventBusManager::new(crate::event::EventBusConfig::default());
+        event_bus.initialize().await.unwrap();
+        let event_bus = Arc::new(event_bus);
+        event_bus
+            .publish(sdk::PluginEvent::new(
+                "inventory.updated",
+                "inventory",
+                "stock_sync",
+                serde_json::json!({ "sku": "A-1" }),
+            ))
+            .await
+            .unwrap();
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_event_bus(Arc::clone(&event_bus));
+        let poll = |user: Option<User>| {
+            ApiRequest::new("GET", EVENT_POLL_PATH)
+                .with_query_params(HashMap::from([(
+                    "timeout_ms".to_string(),
+                    "10".to_string(),
+                )]))
+                .with_user(user)
+        };
+
+        let response = manager.route_api_request(poll(None)).await.unwrap();
+        assert_eq!(response.status_code, 401);
+
+        let reader = crate::auth::test_user(
+            "reader",
+            vec![Permission {
+                resource: "events".to_string(),
+                action: "read".to_string(),
+                scope: crate::auth::PermissionScope::Global,
+            }],
+        );
+        let response = manager.route_api_request(poll(Some(reader))).await.unwrap();
+        assert_eq!(response.status_code, 200);
+        let body = response.schema.unwrap();
+        assert_eq!(body["cursor"], 1);
+        assert_eq!(body["events"][0]["event_type"], "inventory.updated");
+    }
+
+    #[derive(Debug, Default)]
+    struct QuotaDatabase {
+        tables: std::sync::atomic::AtomicU64,
+        bytes: u64,
+        queries: std::sync::atomic::AtomicUsize,
+        /// Behave like a backend that can't measure usage
+        unmeasured: bool,
+    }
+
+    impl crate::platform::database::DatabaseBounds for QuotaDatabase {}
+
+    #[async_trait]
+    impl crate::platform::database::DatabaseProvider for QuotaDatabase {
+        async fn execute(
+            &self,
+            query: &str,
+            _params: &[serde_json::Value],
+        ) -> Result<crate::platform::database::QueryResult> {
+            if query.starts_with("CREATE TABLE") {
+                self.tables
+                    .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            }
+            Ok(crate::platform::database::QueryResult {
+                rows_affected: 1,
+                last_insert_id: None,
+            })
+        }
+
+        async fn query(
+            &self,
+            _query: &str,
+            _params: &[serde_json::Value],
+        ) -> Result<Vec<crate::platform::database::Row>> {
+            Ok(Vec::new())
+        }
+
+        async fn migrate(
+            &self,
+            _migrations: &[crate::platform::database::Migration],
+        ) -> Result<()> {
+            Ok(())
+        }
+
+        async fn table_count(&self, prefix: &str) -> Result<Option<u64>> {
+            assert_eq!(prefix, plugin_table_prefix("quota"));
+            self.queries
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok((!self.unmeasured).then(|| self.tables.load(std::sync::atomic::Ordering::SeqCst)))
+        }
+
+        async fn storage_used(&self, prefix: &str) -> Result<Option<u64>> {
+            assert_eq!(prefix, plugin_table_prefix("quota"));
+            Ok((!self.unmeasured).then_some(self.bytes))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_undeclared_permissions_are_refused() {
+        let required = |error: &Error| match &error.kind {
+            crate::error::ErrorKind::Permission {
+                required_permission,
+                ..
+            } => required_permission.clone(),
+            other => panic!("expected a permission error, got {:?}", other),
+        };
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_database(Arc::new(QuotaDatabase::default()));
+        manager
+            .registry
+            .register(Box::new(
+                TestPlugin::new("scoped".to_string())
+                    .with_permission("files", "read")
+                    .with_permission("products", "read"),
+            ))
+            .unwrap();
+        let context = manager.create_plugin_context("scoped").await.unwrap();
+
+        // The context's database is sandboxed by the same declarations
+        let error = context
+            .database
+            .as_ref()
+            .expect("database access from the manager")
+            .query("SELECT * FROM items", &[])
+            .await
+            .unwrap_err();
+        assert_eq!(required(&error), "database.read");
+
+        assert!(context.has_declared_permission("files", "read"));
+        assert!(!context.has_declared_permission("files", "write"));
+
+        // Declared permissions are still subject to the user's grants
+        assert!(!context
+            .api_client
+            .check_permission("products", "read")
+            .await
+            .unwrap());
+        let error = context
+            .api_client
+            .check_permission("users", "read")
+            .await
+            .unwrap_err();
+        assert_eq!(required(&error), "users.read");
+        assert!(error.message.contains("scoped"));
+
+        let error = context
+            .file_system
+            .write_file("notes.txt", b"hello")
+            .await
+            .unwrap_err();
+        assert_eq!(required(&error), "files.write");
+
+        let db = PluginDatabase::new(
+            "quota".to_string(),
+            Arc::new(QuotaDatabase::default()),
+            DatabasePermissions {
+                can_create_tables: true,
+                can_drop_tables: true,
+                can_modify_schema: true,
+                max_table_count: None,
+                max_storage_size: None,
+            },
+        )
+        .with_declared_permissions(DeclaredPermissions::new(vec![Permission {
+            resource: "database".to_string(),
+            action: "write".to_string(),
+            scope: crate::auth::PermissionScope::Global,
+        }]));
+        db.execute("INSERT INTO items (id) VALUES (1)", &[])
+            .await
+            .unwrap();
+        let error = db
+            .execute("CREATE TABLE items (id INTEGER)", &[])
+            .await
+            .unwrap_err();
+        assert_eq!(required(&error), "database.manage");
+        let error = db
+            .execute("/* cleanup */ DROP TABLE items", &[])
+            .await
+            .unwrap_err();
+        assert_eq!(required(&error), "database.manage");
+        let error = db
+            .execute("DELETE FROM items; DROP TABLE items", &[])
+            .await
+            .unwrap_err();
+        assert_eq!(required(&error), "database.statement");
+    }
+
+    #[test]
+    fn test_declared_permissions_follow_configured_hierarchy() {
+        let declared = DeclaredPermissions::new(vec![Permission {
+            resource: "database".to_string(),
+            action: "write".to_string(),
+            scope: crate::auth::PermissionScope::Global,
+        }]);
+        assert!(declared.allows("database", "read"));
+
+        let flat = declared.with_hierarchy(crate::auth::PermissionHierarchy::flat());
+        assert!(flat.allows("database", "write"));
+        assert!(!flat.allows("database", "read"));
+    }
+
+    #[tokio::test]
+    async fn test_plugin_database_schema_permissions() {
+        let required = |error: &Error| match &error.kind {
+            crate::error::ErrorKind::Permission {
+                required_permission,
+                ..
+            } => required_permission.clone(),
+            other => panic!("expected a permission error, got {:?}", other),
+        };
+        let db = PluginDatabase::new(
+            "quota".to_string(),
+            Arc::new(QuotaDatabase::default()),
+            DatabasePermissions {
+                can_create_tables: true,
+                can_drop_tables: false,
+                can_modify_schema: false,
+                max_table_count: None,
+                max_storage_size: None,
+            },
+        );
+
+        db.execute("CREATE TABLE items (id INTEGER)", &[])
+            .await
+            .unwrap();
+        let error = db.execute("DROP TABLE items", &[]).await.unwrap_err();
+        assert_eq!(required(&error), "database.drop_table");
+        for query in [
+            "ALTER TABLE items RENAME TO other",
+            "CREATE INDEX idx ON items (id)",
+            "DROP VIEW recent",
+        ] {
+            let error = db.execute(query, &[]).await.unwrap_err();
+            assert_eq!(required(&error), "database.modify_schema", "{}", query);
+        }
+        // Schema changes are refused through `query` as well
+        let error = db
+            .query("EXPLAIN CREATE INDEX idx ON items (id)", &[])
+            .await
+            .unwrap_err();
+        assert_eq!(required(&error), "database.modify_schema");
+
+        for query in [
+            "ATTACH DATABASE 'other.db' AS other",
+            "VACUUM",
+            "PRAGMA writable_schema = ON",
+            "SELECT * FROM main.items",
+        ] {
+            let error = db.query(query, &[]).await.unwrap_err();
+            assert_eq!(required(&error), "database.statement", "{}", query);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_plugin_database_quotas() {
+        let provider = Arc::new(QuotaDatabase {
+            bytes: 1_000,
+            ..Default::default()
+        });
+        let db = PluginDatabase::new(
+            "quota".to_string(),
+            provider.clone(),
+            DatabasePermissions {
+                can_create_tables: true,
+                can_drop_tables: true,
+                can_modify_schema: true,
+                max_table_count: Some(1),
+                max_storage_size: Some(1_100),
+            },
+        );
+
+        db.execute("CREATE TABLE items (id INTEGER)", &[])
+            .await
+            .unwrap();
+        let err = db
+            .execute("CREATE TABLE more (id INTEGER)", &[])
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("limit of 1 tables"));
+
+        // The count is cached until the next DDL statement
+        let queries = provider.queries.load(std::sync::atomic::Ordering::SeqCst);
+        assert_eq!(db.table_count().await.unwrap(), Some(1));
+        assert_eq!(
+            provider.queries.load(std::sync::atomic::Ordering::SeqCst),
+            queries
+        );
+
+        db.execute("INSERT INTO items (id) VALUES (?)", &[serde_json::json!(1)])
+            .await
+            .unwrap();
+        let err = db
+            .execute(
+                "INSERT INTO items (id) VALUES (?)",
+                &[serde_json::json!("x".repeat(100))],
+            )
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("storage limit of 1100 bytes"));
+
+        // Quotas are skipped on backends that can't measure usage
+        let db = PluginDatabase::new(
+            "quota".to_string(),
+            Arc::new(QuotaDatabase {
+                unmeasured: true,
+                ..Default::default()
+            }),
+            DatabasePermissions {
+                can_create_tables: true,
+                can_drop_tables: true,
+                can_modify_schema: true,
+                max_table_count: Some(0),
+                max_storage_size: Some(0),
+            },
+        );
+        db.execute("CREATE TABLE items (id INTEGER)", &[])
+            .await
+            .unwrap();
+        db.execute("INSERT INTO items (id) VALUES (?)", &[serde_json::json!(1)])
+            .await
+            .unwrap();
+        assert_eq!(db.table_count().await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_plugin_policy_allow_and_deny() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_policy(PluginPolicy {
+            allowlist: vec!["acme-*".to_string()],
+            denylist: vec!["acme-legacy*".to_string()],
+            trusted_publishers: Vec::new(),
+        });
+        for id in ["acme-reports", "acme-legacy-sync", "other"] {
+            manager
+                .registry
+                .register(Box::new(TestPlugin::new(id.to_string())))
+                .unwrap();
+        }
+
+        manager.initialize_plugins().await.unwrap();
+
+        let stats = manager.stats();
+        assert_eq!(stats.loaded, ["acme-reports"]);
+        let denied: HashMap<_, _> = stats
+            .denied
+            .iter()
+            .map(|d| (d.plugin_id.as_str(), d.reason.as_str()))
+            .collect();
+        assert_eq!(denied.len(), 2);
+        // Deny wins even though the id also matches the allowlist
+        assert!(denied["acme-legacy-sync"].contains("denylist entry 'acme-legacy*'"));
+        assert!(denied["other"].contains("allowlist"));
+    }
+
+    #[test]
+    fn test_plugin_policy_trusted_publishers() {
+        let policy = PluginPolicy {
+            trusted_publishers: vec!["Test *".to_string()],
+            ..Default::default()
+        };
+        let mut info = TestPlugin::new("any".to_string()).info;
+        assert!(policy.check(&info).is_ok());
+
+        info.author = "Someone Else".to_string();
+        assert!(policy.check(&info).unwrap_err().contains("not trusted"));
+
+        assert!(glob_matches("a?c*", "abcdef"));
+        assert!(!glob_matches("a?c", "abcd"));
+    }
+
+    /// Config manager with in-memory system and user tiers
+    fn memory_config() -> Arc<RwLock<TieredConfigManager>> {
+        let mut config = TieredConfigManager::new();
+        for tier in [ConfigurationTier::System, ConfigurationTier::User] {
+            config.add_store(tier, Box::new(crate::config::MemoryConfigStore::new(tier)));
+        }
+        Arc::new(RwLock::new(config))
+    }
+
+    #[tokio::test]
+    async fn test_api_client_delegates_to_core_managers() {
+        let config = memory_config();
+        let accounts = Arc::new(RwLock::new(AccountManager::new(
+            Box::new(crate::auth::MemorySessionStore::new()),
+            Box::new(crate::auth::MemoryUserStore::new()),
+            crate::auth::SecurityPolicy::default(),
+        )));
+
+        let mut provider = PluginApiProvider::new();
+        provider.config = Some(config.clone());
+        provider.accounts = Some(accounts);
+        let alpha = provider.create_client("alpha".to_string());
+        let beta = provider.create_client("beta".to_string());
+
+        alpha
+            .set_config("theme", serde_json::json!("dark"))
+            .await
+            .unwrap();
+        assert_eq!(
+            alpha.get_config("theme").await.unwrap(),
+            Some(serde_json::json!("dark"))
+        );
+        // Writes stay inside the plugin's own namespace
+        assert_eq!(beta.get_config("theme").await.unwrap(), None);
+        assert_eq!(
+            config
+                .read()
+                .await
+                .get::<String>("plugin_settings.alpha.theme")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("dark")
+        );
+        // The plugin manager's own `plugins` section is left alone
+        assert!(config
+            .read()
+            .await
+            .get::<serde_json::Value>("plugins.alpha.theme")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(alpha
+            .set_config("theme..x", serde_json::json!(1))
+            .await
+            .is_err());
+
+        // Values that break the settings schema are refused
+        let gamma = provider
+            .create_client("gamma".to_string())
+            .with_settings_schema(SettingsSchema {
+                version: "1".to_string(),
+                schema: serde_json::json!({
+                    "type": "object",
+                    "required": ["region"],
+                    "properties": {
+                        "limits": {
+                            "type": "object",
+                            "properties": { "batch": { "type": "integer", "maximum": 10 } }
+                        }
+                    }
+                }),
+                defaults: serde_json::json!({}),
+            });
+        let error = gamma
+            .set_config("limits.batch", serde_json::json!(50))
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("must be at most 10"));
+        assert!(gamma
+            .set_config("limits.batch", serde_json::json!(5))
+            .await
+            .is_ok());
+        assert_eq!(
+            config
+                .read()
+                .await
+                .get::<u32>("plugin_settings.gamma.limits.batch")
+                .await
+                .unwrap(),
+            Some(5)
+        );
+
+        assert!(alpha.get_current_user().await.unwrap().is_none());
+        assert!(alpha.check_permission("plugins", "read").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_plugin_state_is_isolated() {
+        let mut provider = PluginApiProvider::new();
+        provider.state = Some(Arc::new(crate::platform::MemoryStorage::new()));
+        let alpha = provider.create_client("alpha".to_string());
+        let beta = provider.create_client("beta".to_string());
+
+        alpha
+            .state_set("etag", serde_json::json!("W/\"abc\""))
+            .await
+            .unwrap();
+        assert_eq!(
+            alpha.state_get("etag").await.unwrap(),
+            Some(serde_json::json!("W/\"abc\""))
+        );
+        assert_eq!(beta.state_get("etag").await.unwrap(), None);
+
+        // Crafted IDs and keys cannot reach into another namespace
+        let a = provider.create_client("a".to_string());
+        let a_b = provider.create_client("a_b".to_string());
+        a.state_set("b_cursor", serde_json::json!(42))
+            .await
+            .unwrap();
+        assert_eq!(a_b.state_get("cursor").await.unwrap(), None);
+        assert_eq!(beta.state_get("../alpha/etag").await.unwrap(), None);
+        assert!(alpha.state_get(" ").await.is_err());
+
+        // State is not configuration
+        assert_eq!(alpha.get_config("etag").await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_ui_component_props_and_render_fn() {
+        let renders = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::clone(&renders);
+        let component = UIComponent::new("list", "List", UIComponentType::Page)
+            .with_route("/plugin/demo/list")
+            .with_props(serde_json::json!({ "title": "Items" }))
+            .with_render_fn(move |props| {
+                assert_eq!(props["title"], "Items");
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                VNode::empty()
+            });
+
+        assert!(component.render().is_some());
+        assert_eq!(renders.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // The render function is never serialized
+        let json = serde_json::to_value(&component).unwrap();
+        assert!(json.get("render_fn").is_none());
+        let restored: UIComponent = serde_json::from_value(json).unwrap();
+        assert!(restored.render().is_none());
+        assert_eq!(restored.route.as_deref(), Some("/plugin/demo/list"));
+
+        // Manifests written against the old `Sidebar` name still load
+        let legacy: UIComponent = serde_json::from_value(serde_json::json!({
+            "id": "panel",
+            "name": "Panel",
+            "component_type": "Sidebar",
+        }))
+        .unwrap();
+        assert_eq!(legacy.component_type, UIComponentType::SidebarPanel);
+        assert!(legacy.props.is_null());
+    }
+
+    #[test]
+    fn test_menu_item_builder_nested() {
+        let menu = MenuItem::builder("products", "Products")
+            .icon("shopping-bag")
+            .route("/products")
+            .permission("products", "read")
+            .order(10)
+            .child(
+                MenuItem::builder("products_list", "All Products")
+                    .route("/products/list")
+                    .order(1)
+                    .build(),
+            )
+            .child(
+                MenuItem::builder("products_export", "Export")
+                    .action("export_products")
+                    .order(2)
+                    .child(MenuItem::builder("export_csv", "CSV").build())
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(menu.route.as_deref(), Some("/products"));
+        assert!(menu.action.is_none());
+        assert_eq!(menu.required_permissions.len(), 1);
+        assert_eq!(menu.children.len(), 2);
+        assert_eq!(menu.children[0].route.as_deref(), Some("/products/list"));
+        assert!(menu.children[0].children.is_empty());
+        assert_eq!(menu.children[1].action.as_deref(), Some("export_products"));
+        assert!(menu.children[1].route.is_none());
+        assert_eq!(menu.children[1].children[0].id, "export_csv");
+
+        // Optional fields may be omitted from manifests
+        let parsed: MenuItem = serde_json::from_value(serde_json::json!({
+            "id": "products",
+            "label": "Products",
+            "route": "/products",
+            "children": [{ "id": "products_list", "label": "All Products" }],
+        }))
+        .unwrap();
+        assert!(parsed.action.is_none());
+        assert_eq!(parsed.children[0].order, 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drops_plugin_components() {
+        let components = UIComponentRegistry::new();
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_component_registry(components.clone());
+        manager
+            .registry
+            .register(Box::new(TestPlugin::new("ui".to_string())))
+            .unwrap();
+
+        let context = manager.create_plugin_context("ui").await.unwrap();
+        context
+            .components
+            .register(DEFAULT_PLUGIN_PAGE, |_| VNode::empty());
+        assert!(components.resolve_route("ui", None).is_some());
+
+        let summary = manager.shutdown_all().await;
+        assert!(summary.is_clean());
+        assert!(components.resolve_route("ui", None).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_builtin_plugins_come_from_factory_registry() {
+        fn catalog() -> Box<dyn Plugin> {
+            Box::new(TestPlugin::new("catalog".to_string()))
+        }
+        fn reports() -> Box<dyn Plugin> {
+            Box::new(TestPlugin::new("reports".to_string()))
+        }
+
+        let factories = PluginFactoryRegistry::new();
+        factories.register("catalog", catalog);
+        // Clones share registrations, as the shared registry relies on
+        factories.clone().register("reports", reports);
+        assert_eq!(factories.plugin_ids(), ["catalog", "reports"]);
+        assert_eq!(factories.get("catalog").unwrap()().info().id, "catalog");
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        let registered = manager
+            .register_builtin_plugins(&factories, &["reports".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(registered, ["reports"]);
+        assert!(manager.registry.plugins.contains_key("reports"));
+        assert!(!manager.registry.plugins.contains_key("catalog"));
+
+        fn future_core() -> Box<dyn Plugin> {
+            let mut plugin = TestPlugin::new("future".to_string());
+            plugin.info.minimum_core_version = "999.0.0".to_string();
+            Box::new(plugin)
+        }
+        factories.register("future", future_core);
+        let error = manager
+            .register_builtin_plugins(&factories, &["future".to_string()])
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("requires core 999.0.0"));
+        assert!(!manager.registry.plugins.contains_key("future"));
+
+        let error = manager
+            .register_builtin_plugins(&factories, &["missing".to_string()])
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("No factory"));
+    }
+
+    #[tokio::test]
+    async fn test_menu_items_merge_and_sort() {
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("inventory".to_string()).with_menu_item(
+                    MenuItem::builder("inventory", "Inventory")
+                        .order(20)
+                        .child(
+                            MenuItem::builder("stock", "Stock")
+                                .route("/plugin/inventory/stock")
+                                .order(2)
+                                .build(),
+                        )
+                        .build(),
+                ),
+            ))
+            .await
+            .unwrap();
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("products".to_string())
+                    .with_menu_item(
+                        MenuItem::builder("products", "Products")
+                            .route("/plugin/products")
+                            .order(10)
+                            .build(),
+                    )
+                    // Adds a child to the "inventory" plugin's item
+                    .with_menu_item(
+                        MenuItem::builder("inventory", "Ignored label")
+                            .child(
+                                MenuItem::builder("counts", "Counts")
+                                    .route("/plugin/products/counts")
+                                    .order(1)
+                                    .build(),
+                            )
+                            .build(),
+                    ),
+            ))
+            .await
+            .unwrap();
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("reports".to_string()).with_menu_item(
+                    MenuItem::builder("reports", "Reports")
+                        .route("/plugin/reports")
+                        .permission("reports", "read")
+                        .order(5)
+                        .build(),
+                ),
+            ))
+            .await
+            .unwrap();
+
+        let ids = |items: &[MenuItem]| items.iter().map(|i| i.id.clone()).collect::<Vec<_>>();
+
+        let all = manager.menu_items();
+        assert_eq!(ids(&all), ["reports", "products", "inventory"]);
+        assert_eq!(all[2].label, "Inventory");
+        assert_eq!(ids(&all[2].children), ["counts", "stock"]);
+        assert_eq!(all[0].required_permissions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_search_providers_follow_plugin_lifecycle() {
+        let mut event_bus = EventBusManager::new(crate::event::EventBusConfig::default());
+        event_bus.initialize().await.unwrap();
+        let event_bus = Arc::new(event_bus);
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_event_bus(Arc::clone(&event_bus));
+        manager
+            .registry
+            .register(Box::new(
+                TestPlugin::new("catalog".to_string()).with_provider(Arc::new(
+                    search::ExampleSearchProvider::new(
+                        "catalog-search".to_string(),
+                        "Catalog".to_string(),
+                    ),
+                )),
+            ))
+            .unwrap();
+        let context = manager.create_plugin_context("catalog").await.unwrap();
+        manager
+            .plugin_contexts
+            .insert("catalog".to_string(), context);
+
+        manager.initialize_plugins().await.unwrap();
+        assert_eq!(
+            manager.search_coordinator().list_providers().await,
+            ["catalog-search"]
+        );
+
+        manager.unload_plugin("catalog").await.unwrap();
+        assert!(manager
+            .search_coordinator()
+            .list_providers()
+            .await
+            .is_empty());
+
+        let events = event_bus.recent_events(10).await;
+        let types: Vec<_> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(
+            types,
+            vec![SEARCH_PROVIDER_REGISTERED, SEARCH_PROVIDER_UNREGISTERED]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_palette_commands_follow_plugin_lifecycle() {
+        let commands = CommandRegistry::new();
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_command_registry(commands.clone());
+        manager
+            .register_plugin(Box::new(
+                TestPlugin::new("catalog".to_string()).with_menu_item(
+                    MenuItem::builder("products", "Products")
+                        .route("/plugin/catalog/products")
+                        .build(),
+                ),
+            ))
+            .await
+            .unwrap();
+        assert!(commands.matches("", None, &[]).is_empty());
+
+        manager.initialize_plugins().await.unwrap();
+        let ids: Vec<String> = commands
+            .matches("prod", None, &[])
+            .into_iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(ids, ["plugin:catalog:products"]);
+
+        manager.unload_plugin("catalog").await.unwrap();
+        assert!(commands.matches("", None, &[]).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_context_publish_reaches_other_plugin_subscriptions() {
+        let mut event_bus = EventBusManager::new(crate::event::EventBusConfig::default());
+        event_bus.initialize().await.unwrap();
+        let event_bus = Arc::new(event_bus);
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_event_bus(Arc::clone(&event_bus));
+        for plugin_id in ["inventory", "pricing"] {
+            manager
+                .registry
+                .register(Box::new(TestPlugin::new(plugin_id.to_string())))
+                .unwrap();
+        }
+        let inventory = manager.create_plugin_context("inventory").await.unwrap();
+        let pricing = manager.create_plugin_context("pricing").await.unwrap();
+        assert!(Arc::ptr_eq(&inventory.event_bus, &event_bus));
+        assert!(Arc::ptr_eq(&pricing.event_bus, &event_bus));
+
+        let mut updates = pricing.subscribe("inventory.").await.unwrap();
+        inventory
+            .publish(sdk::PluginEvent::new(
+                "product.price_changed",
+                "inventory",
+                "inventory",
+                serde_json::json!({ "sku": "A-1" }),
+            ))
+            .await
+            .unwrap();
+        inventory
+            .publish(sdk::PluginEvent::new(
+                "inventory.updated",
+                "inventory",
+                "stock_sync",
+                serde_json::json!({ "sku": "A-1", "quantity": 3 }),
+            ))
+            .await
+            .unwrap();
+
+        let event = tokio::time::timeout(Duration::from_secs(1), updates.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.event_type(), "inventory.updated");
+        assert_eq!(event.source(), "inventory");
+        assert_eq!(event_source_plugin(event.as_ref()), Some("inventory"));
+        let published = event.as_any().downcast_ref::<sdk::PluginEvent>().unwrap();
+        assert_eq!(published.data["quantity"], 3);
+        assert_eq!(published.source, "stock_sync");
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_report_notifies_eligible_users() {
+        let stock_read = Permission {
+            resource: "plugin.inventory".to_string(),
+            action: "read".to_string(),
+            scope: crate::auth::PermissionScope::Global,
+        };
+        let accounts = AccountManager::new(
+            Box::new(crate::auth::MemorySessionStore::new()),
+            Box::new(crate::auth::MemoryUserStore::new()),
+            crate::auth::SecurityPolicy::default(),
+        );
+        accounts
+            .create_user(crate::auth::test_user("buyer", vec![stock_read.clone()]))
+            .await
+            .unwrap();
+        accounts
+            .create_user(crate::auth::test_user("guest", vec![]))
+            .await
+            .unwrap();
+        let buyer = accounts
+            .get_user_by_username("buyer")
+            .await
+            .unwrap()
+            .unwrap();
+
+        let mut event_bus = EventBusManager::new(crate::event::EventBusConfig::default());
+        event_bus.initialize().await.unwrap();
+        let event_bus = Arc::new(event_bus);
+
+        let reports = ReportScheduler::new();
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_report_scheduler(reports.clone());
+        manager.set_account_manager(Arc::new(RwLock::new(accounts)));
+        manager.set_event_bus(Arc::clone(&event_bus));
+        manager
+            .registry
+            .register(Box::new(TestPlugin::new("inventory".to_string())))
+            .unwrap();
+        let context = manager.create_plugin_context("inventory").await.unwrap();
+
+        context
+            .register_report(
+                ReportSchedule::every(Duration::from_millis(20)),
+                stock_read,
+                || async {
+                    Ok(Report::new(
+                        "Low stock",
+                        serde_json::json!({ "sku": "A-1" }),
+                    ))
+                },
+            )
+            .unwrap();
+        assert_eq!(reports.report_count("inventory"), 1);
+
+        let mut delivered = Vec::new();
+        for _ in 0..100 {
+            delivered = event_bus.recent_events(10).await;
+            if !delivered.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let event = delivered.first().expect("report was never delivered");
+        assert_eq!(event.event_type, PLUGIN_REPORT_DELIVERED);
+        assert_eq!(event.metadata["user_id"], serde_json::json!(buyer.id));
+        assert_eq!(event.metadata["title"], "Low stock");
+        assert!(delivered
+            .iter()
+            .all(|e| e.metadata["user_id"] == serde_json::json!(buyer.id)));
+
+        let summary = manager.shutdown_all().await;
+        assert!(summary.is_clean());
+        assert_eq!(reports.report_count("inventory"), 0);
+    }
+
+    #[tokio::test]
+    async fn test_onboarding_wizard_installs_configured_plugin() {
+        let config = memory_config();
+
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_config_manager(Arc::clone(&config));
+
+        let plugin = TestPlugin::new("inventory".to_string())
+            .with_permission("plugin.inventory", "write")
+            .with_settings(serde_json::json!({
+                "type": "object",
+                "required": ["warehouse"],
+                "properties": {
+                    "warehouse": { "type": "string" },
+                    "threshold": { "type": "integer", "default": 5 }
+                }
+            }));
+        let initialized = Arc::clone(&plugin.initialized);
+
+        let mut wizard = PluginOnboarding::for_plugin(&plugin);
+        assert_eq!(wizard.step(), OnboardingStep::Review);
+        wizard.next().unwrap();
+
+        // Consent is required before settings
+        assert!(wizard.next().is_err());
+        wizard.approve_permissions(true);
+        assert_eq!(wizard.next().unwrap(), OnboardingStep::Settings);
+
+        assert_eq!(wizard.missing_settings(), vec!["warehouse".to_string()]);
+        assert!(wizard.next().is_err());
+        wizard.set_value("warehouse", serde_json::json!("north"));
+        assert_eq!(wizard.next().unwrap(), OnboardingStep::Confirm);
+
+        // Nothing installs until the admin confirms
+        assert!(manager
+            .install_onboarded(Box::new(TestPlugin::new("inventory".to_string())), &wizard)
+            .await
+            .is_err());
+        wizard.confirm().unwrap();
+
+        let health = manager
+            .install_onboarded(Box::new(plugin), &wizard)
+            .await
+            .unwrap();
+        assert!(health.is_healthy);
+        assert!(initialized.load(std::sync::atomic::Ordering::SeqCst));
+        assert_eq!(manager.stats().loaded, vec!["inventory".to_string()]);
+        let config = config.read().await;
+        assert_eq!(
+            config
+                .get::<String>("plugin_settings.inventory.warehouse")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("north")
+        );
+        assert_eq!(
+            config
+                .get::<i64>("plugin_settings.inventory.threshold")
+                .await
+                .unwrap(),
+            Some(5)
+        );
+    }
+
+    /// A confirmed onboarding for `plugin` with `warehouse` set
+    fn confirmed_onboarding(plugin: &TestPlugin) -> PluginOnboarding {
+        let mut wizard = PluginOnboarding::for_plugin(plugin);
+        wizard.approve_permissions(true);
+        wizard.set_value("warehouse", serde_json::json!("north"));
+        wizard.go_to(OnboardingStep::Confirm).unwrap();
+        wizard.confirm().unwrap();
+        wizard
+    }
+
+    #[tokio::test]
+    async fn test_failed_onboarded_install_removes_settings() {
+        let config = memory_config();
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_config_manager(Arc::clone(&config));
+
+        let plugin = TestPlugin::new("inventory".to_string())
+            .with_settings(serde_json::json!({
+                "type": "object",
+                "properties": { "warehouse": { "type": "string" } }
+            }))
+            .with_failing_initialize();
+        let wizard = confirmed_onboarding(&plugin);
+
+        assert!(manager
+            .install_onboarded(Box::new(plugin), &wizard)
+            .await
+            .is_err());
+        assert!(manager.stats().loaded.is_empty());
+        assert_eq!(
+            config
+                .read()
+                .await
+                .get::<String>("plugin_settings.inventory.warehouse")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_plugin_installer_hands_requests_to_manager() {
+        let config = memory_config();
+        let mut manager = PluginManager::new(Box::new(NoopLoader));
+        manager.set_config_manager(Arc::clone(&config));
+
+        let plugin = TestPlugin::new("inventory".to_string()).with_settings(serde_json::json!({
+            "type": "object",
+            "properties": { "warehouse": { "type": "string" } }
+        }));
+        let wizard = confirmed_onboarding(&plugin);
+
+        let installer = PluginInstaller::new();
+        assert!(!installer.is_listening());
+        assert!(installer
+            .install(
+                Box::new(TestPlugin::new("inventory".to_string())),
+                wizard.clone()
+            )
+            .await
+            .is_err());
+
+        let mut requests = installer.listen();
+        let install = tokio::spawn({
+            let installer = installer.clone();
+            async move { installer.install(Box::new(plugin), wizard).await }
+        });
+        manager.handle_install(requests.recv().await.unwrap()).await;
+
+        assert!(install.await.unwrap().unwrap().is_healthy);
+        assert_eq!(manager.stats().loaded, vec!["inventory".to_string()]);
+    }
+
+    #[test]
+    fn test_http_method_parsing_and_serde() {
+        assert_eq!("get".parse::<HttpMethod>().unwrap(), HttpMethod::Get);
+        assert_eq!(
+            " DELETE ".parse::<HttpMethod>().unwrap(),
+            HttpMethod::Delete
+        );
+        assert!("TRACE".parse::<HttpMethod>().is_err());
+        assert_eq!(HttpMethod::Options.to_string(), "OPTIONS");
+
+        assert_eq!(
+            serde_json::to_value(HttpMethod::Post).unwrap(),
+            serde_json::json!("POST")
+        );
+        for legacy in ["\"POST\"", "\"Post\""] {
+            assert_eq!(
+                serde_json::from_str::<HttpMethod>(legacy).unwrap(),
+                HttpMethod::Post
+            );
+        }
+    }
 }
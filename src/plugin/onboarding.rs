@@ -0,0 +1,504 @@
+// src/plugin/onboarding.rs - Guided install state for the plugin onboarding wizard
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+
+use super::{Plugin, PluginInfo, ProviderHealth};
+use crate::auth::Permission;
+use crate::config::SettingsSchema;
+use crate::error::{Error, Result};
+
+/// Steps of the onboarding wizard, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OnboardingStep {
+    /// Read the plugin's name, author, version and license
+    Review,
+    /// Consent to the permissions the plugin asks for
+    Permissions,
+    /// Fill the settings its schema declares
+    Settings,
+    /// Confirm the install
+    Confirm,
+}
+
+impl OnboardingStep {
+    pub const ALL: [OnboardingStep; 4] = [
+        OnboardingStep::Review,
+        OnboardingStep::Permissions,
+        OnboardingStep::Settings,
+        OnboardingStep::Confirm,
+    ];
+
+    /// Stable ID, e.g. for a tab
+    pub fn id(&self) -> &'static str {
+        match self {
+            Self::Review => "review",
+            Self::Permissions => "permissions",
+            Self::Settings => "settings",
+            Self::Confirm => "confirm",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Review => "Review",
+            Self::Permissions => "Permissions",
+            Self::Settings => "Settings",
+            Self::Confirm => "Confirm",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|step| step == self).unwrap_or(0)
+    }
+}
+
+/// Input a setting is edited with, from its JSON schema `type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SettingKind {
+    Text,
+    Number,
+    Integer,
+    Boolean,
+    /// A string restricted to the schema's `enum`
+    Choice,
+    /// A nested object, edited through its [`SettingField::fields`]
+    Group,
+}
+
+/// One property of a plugin's settings schema.
+///
+/// Nested objects are read one level deep; their properties get dotted
+/// keys such as `sync.interval`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SettingField {
+    pub key: String,
+    /// The schema `title`, falling back to the key
+    pub label: String,
+    pub description: Option<String>,
+    pub kind: SettingKind,
+    pub required: bool,
+    /// Allowed values of a [`SettingKind::Choice`]
+    pub options: Vec<String>,
+    pub default: Option<Value>,
+    /// Bounds of a [`SettingKind::Number`] or [`SettingKind::Integer`]
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    /// Properties of a [`SettingKind::Group`]
+    pub fields: Vec<SettingField>,
+}
+
+impl SettingField {
+    /// Read the fields of a settings schema, required ones first.
+    ///
+    /// Defaults come from the property's `default`, then from the schema's
+    /// `defaults` object.
+    pub fn from_schema(schema: &SettingsSchema) -> Vec<Self> {
+        Self::from_object(&schema.schema, &schema.defaults, None)
+    }
+
+    fn from_object(object: &Value, defaults: &Value, parent: Option<&str>) -> Vec<Self> {
+        let required: Vec<&str> = object["required"]
+            .as_array()
+            .map(|keys| keys.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let Some(properties) = object["properties"].as_object() else {
+            return Vec::new();
+        };
+
+        let mut fields: Vec<Self> = properties
+            .iter()
+            .filter_map(|(name, property)| {
+                let options: Vec<String> = property["enum"]
+                    .as_array()
+                    .map(|values| {
+                        values
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                let kind = match property["type"].as_str() {
+                    _ if !options.is_empty() => SettingKind::Choice,
+                    Some("boolean") => SettingKind::Boolean,
+                    Some("integer") => SettingKind::Integer,
+                    Some("number") => SettingKind::Number,
+                    // Only one level of nesting is editable
+                    Some("object") if parent.is_some() => return None,
+                    Some("object") => SettingKind::Group,
+                    _ => SettingKind::Text,
+                };
+                let key = match parent {
+                    Some(parent) => format!("{}.{}", parent, name),
+                    None => name.clone(),
+                };
+                let fields = match kind {
+                    SettingKind::Group => Self::from_object(property, &defaults[name], Some(&key)),
+                    _ => Vec::new(),
+                };
+
+                Some(Self {
+                    label: property["title"].as_str().unwrap_or(name).to_string(),
+                    description: property["description"].as_str().map(str::to_string),
+                    kind,
+                    required: required.contains(&name.as_str()),
+                    options,
+                    default: property
+                        .get("default")
+                        .or_else(|| defaults.get(name))
+                        .filter(|_| kind != SettingKind::Group)
+                        .cloned(),
+                    minimum: property["minimum"].as_f64(),
+                    maximum: property["maximum"].as_f64(),
+                    fields,
+                    key,
+                })
+            })
+            .collect();
+        fields.sort_by_key(|field| !field.required);
+        fields
+    }
+
+    /// Fields holding a value: `fields` with groups replaced by their members
+    pub fn leaves(fields: &[Self]) -> impl Iterator<Item = &Self> {
+        fields.iter().flat_map(|field| match field.kind {
+            SettingKind::Group => field.fields.iter().collect::<Vec<_>>(),
+            _ => vec![field],
+        })
+    }
+
+    /// Convert text typed into the field to a setting value.
+    ///
+    /// Empty or unparseable input becomes `null`, which counts as missing.
+    pub fn parse(&self, raw: &str) -> Value {
+        let raw = raw.trim();
+        match self.kind {
+            _ if raw.is_empty() => Value::Null,
+            SettingKind::Text => Value::from(raw),
+            SettingKind::Choice if self.options.iter().any(|o| o == raw) => Value::from(raw),
+            SettingKind::Choice | SettingKind::Group => Value::Null,
+            SettingKind::Boolean => raw.parse::<bool>().map_or(Value::Null, Value::from),
+            SettingKind::Integer => raw.parse::<i64>().map_or(Value::Null, Value::from),
+            SettingKind::Number => raw.parse::<f64>().map_or(Value::Null, Value::from),
+        }
+    }
+}
+
+/// An admin's progress through installing one plugin.
+///
+/// Steps only advance once the current one is satisfied: permissions must be
+/// approved and required settings filled before the install can be
+/// confirmed. [`PluginManager::install_onboarded`](super::PluginManager::install_onboarded)
+/// then applies the settings and initializes the plugin.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluginOnboarding {
+    pub info: PluginInfo,
+    /// Permissions the plugin asks for
+    pub permissions: Vec<Permission>,
+    pub fields: Vec<SettingField>,
+    step: OnboardingStep,
+    permissions_approved: bool,
+    values: Map<String, Value>,
+    confirmed: bool,
+}
+
+impl PluginOnboarding {
+    /// Start onboarding `plugin`, pre-filling settings with their defaults
+    pub fn for_plugin(plugin: &dyn Plugin) -> Self {
+        let fields = plugin
+            .settings_schema()
+            .map(|schema| SettingField::from_schema(&schema))
+            .unwrap_or_default();
+        let values = SettingField::leaves(&fields)
+            .filter_map(|field| Some((field.key.clone(), field.default.clone()?)))
+            .collect();
+
+        Self {
+            info: plugin.info(),
+            permissions: plugin.required_permissions(),
+            fields,
+            step: OnboardingStep::Review,
+            permissions_approved: false,
+            values,
+            confirmed: false,
+        }
+    }
+
+    pub fn step(&self) -> OnboardingStep {
+        self.step
+    }
+
+    /// Record the admin's answer to the permission request
+    pub fn approve_permissions(&mut self, approved: bool) {
+        self.permissions_approved = approved;
+        if !approved {
+            self.confirmed = false;
+        }
+    }
+
+    /// Whether the admin consented, or there was nothing to consent to
+    pub fn permissions_approved(&self) -> bool {
+        self.permissions_approved || self.permissions.is_empty()
+    }
+
+    /// Set a setting; `null` clears it
+    pub fn set_value(&mut self, key: &str, value: Value) {
+        if value.is_null() {
+            self.values.remove(key);
+        } else {
+            self.values.insert(key.to_string(), value);
+        }
+        self.confirmed = false;
+    }
+
+    /// Settings collected so far, keyed by setting name
+    pub fn values(&self) -> &Map<String, Value> {
+        &self.values
+    }
+
+    /// Keys of required settings that have no value yet
+    pub fn missing_settings(&self) -> Vec<String> {
+        SettingField::leaves(&self.fields)
+            .filter(|field| field.required)
+            .filter(|field| match self.values.get(&field.key) {
+                None | Some(Value::Null) => true,
+                Some(Value::String(s)) => s.trim().is_empty(),
+                Some(_) => false,
+            })
+            .map(|field| field.key.clone())
+            .collect()
+    }
+
+    /// Why the wizard cannot leave `step`, if it cannot
+    pub fn blocker(&self, step: OnboardingStep) -> Option<String> {
+        match step {
+            OnboardingStep::Review | OnboardingStep::Confirm => None,
+            OnboardingStep::Permissions if !self.permissions_approved() => {
+                Some("Approve the requested permissions to continue".to_string())
+            }
+            OnboardingStep::Permissions => None,
+            OnboardingStep::Settings => {
+                let missing = self.missing_settings();
+                (!missing.is_empty())
+                    .then(|| format!("Required settings missing: {}", missing.join(", ")))
+            }
+        }
+    }
+
+    /// Move to the next step, failing if the current one is not satisfied
+    pub fn next(&mut self) -> Result<OnboardingStep> {
+        if let Some(reason) = self.blocker(self.step) {
+            return Err(Error::plugin(&self.info.id, reason));
+        }
+        if let Some(&next) = OnboardingStep::ALL.get(self.step.index() + 1) {
+            self.step = next;
+        }
+        Ok(self.step)
+    }
+
+    pub fn back(&mut self) -> OnboardingStep {
+        if let Some(previous) = self.step.index().checked_sub(1) {
+            self.step = OnboardingStep::ALL[previous];
+        }
+        self.confirmed = false;
+        self.step
+    }
+
+    /// Jump to `step` if every step before it is satisfied
+    pub fn go_to(&mut self, step: OnboardingStep) -> Result<OnboardingStep> {
+        if let Some(reason) = OnboardingStep::ALL[..step.index()]
+            .iter()
+            .find_map(|earlier| self.blocker(*earlier))
+        {
+            return Err(Error::plugin(&self.info.id, reason));
+        }
+        self.step = step;
+        if step != OnboardingStep::Confirm {
+            self.confirmed = false;
+        }
+        Ok(self.step)
+    }
+
+    /// Confirm the install from the last step
+    pub fn confirm(&mut self) -> Result<()> {
+        if self.step != OnboardingStep::Confirm {
+            return Err(Error::plugin(
+                &self.info.id,
+                "Finish the earlier steps before confirming",
+            ));
+        }
+        if let Some(reason) = OnboardingStep::ALL
+            .iter()
+            .find_map(|step| self.blocker(*step))
+        {
+            return Err(Error::plugin(&self.info.id, reason));
+        }
+        self.confirmed = true;
+        Ok(())
+    }
+
+    /// Whether the admin confirmed an install that is ready to run
+    pub fn is_complete(&self) -> bool {
+        self.confirmed
+            && OnboardingStep::ALL
+                .iter()
+                .all(|step| self.blocker(*step).is_none())
+    }
+}
+
+/// A confirmed onboarding waiting for the plugin manager to install it
+#[derive(Debug)]
+pub struct InstallRequest {
+    pub(crate) plugin: Box<dyn Plugin>,
+    pub(crate) onboarding: PluginOnboarding,
+    pub(crate) reply: oneshot::Sender<Result<ProviderHealth>>,
+}
+
+/// Hands onboardings confirmed in the UI to the plugin manager.
+///
+/// The wizard runs in the UI while the plugin manager belongs to the
+/// application core, which [`listen`](Self::listen)s and installs each
+/// request from its serve loop. Installs fail straight away while nothing
+/// listens.
+#[derive(Debug, Clone, Default)]
+pub struct PluginInstaller {
+    sender: Arc<Mutex<Option<mpsc::UnboundedSender<InstallRequest>>>>,
+}
+
+impl PluginInstaller {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide installer the plugins page submits to
+    pub fn shared() -> Self {
+        static SHARED: std::sync::OnceLock<PluginInstaller> = std::sync::OnceLock::new();
+        SHARED.get_or_init(Self::new).clone()
+    }
+
+    /// Start receiving install requests, replacing any earlier listener
+    pub fn listen(&self) -> mpsc::UnboundedReceiver<InstallRequest> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        *self.sender.lock().unwrap_or_else(|e| e.into_inner()) = Some(sender);
+        receiver
+    }
+
+    /// Whether a plugin manager is accepting installs
+    pub fn is_listening(&self) -> bool {
+        self.sender
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .is_some_and(|sender| !sender.is_closed())
+    }
+
+    /// Install `plugin` as onboarded and wait for its health
+    pub async fn install(
+        &self,
+        plugin: Box<dyn Plugin>,
+        onboarding: PluginOnboarding,
+    ) -> Result<ProviderHealth> {
+        let plugin_id = onboarding.info.id.clone();
+        let unavailable = || Error::plugin(&plugin_id, "No plugin manager is accepting installs");
+
+        let sender = self
+            .sender
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .ok_or_else(unavailable)?;
+        let (reply, response) = oneshot::channel();
+        sender
+            .send(InstallRequest {
+                plugin,
+                onboarding,
+                reply,
+            })
+            .map_err(|_| unavailable())?;
+        response.await.map_err(|_| unavailable())?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setting_fields_from_schema() {
+        let schema = SettingsSchema {
+            version: "1".to_string(),
+            schema: serde_json::json!({
+                "type": "object",
+                "required": ["warehouse"],
+                "properties": {
+                    "alerts": { "type": "boolean", "title": "Stock alerts" },
+                    "unit": { "type": "string", "enum": ["kg", "lb"] },
+                    "warehouse": { "type": "string", "description": "Default location" },
+                    "threshold": { "type": "integer" }
+                }
+            }),
+            defaults: serde_json::json!({ "threshold": 5 }),
+        };
+
+        let fields = SettingField::from_schema(&schema);
+        assert_eq!(fields[0].key, "warehouse");
+        assert!(fields[0].required);
+
+        let by_key = |key: &str| fields.iter().find(|f| f.key == key).unwrap();
+        assert_eq!(by_key("alerts").label, "Stock alerts");
+        assert_eq!(by_key("alerts").kind, SettingKind::Boolean);
+        assert_eq!(by_key("unit").kind, SettingKind::Choice);
+        assert_eq!(by_key("threshold").default, Some(serde_json::json!(5)));
+
+        assert_eq!(by_key("threshold").parse("12"), serde_json::json!(12));
+        assert_eq!(by_key("threshold").parse("twelve"), Value::Null);
+        assert_eq!(by_key("unit").parse("oz"), Value::Null);
+    }
+
+    #[test]
+    fn test_nested_setting_fields() {
+        let schema = SettingsSchema {
+            version: "1".to_string(),
+            schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "sync": {
+                        "type": "object",
+                        "title": "Sync",
+                        "required": ["interval"],
+                        "properties": {
+                            "interval": { "type": "integer", "minimum": 1, "maximum": 60 },
+                            "remote": {
+                                "type": "object",
+                                "properties": { "url": { "type": "string" } }
+                            }
+                        }
+                    }
+                }
+            }),
+            defaults: serde_json::json!({ "sync": { "interval": 15 } }),
+        };
+
+        let fields = SettingField::from_schema(&schema);
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].kind, SettingKind::Group);
+        assert_eq!(fields[0].default, None);
+
+        // Deeper objects are left out
+        let interval = &fields[0].fields;
+        assert_eq!(interval.len(), 1);
+        assert_eq!(interval[0].key, "sync.interval");
+        assert!(interval[0].required);
+        assert_eq!(interval[0].minimum, Some(1.0));
+        assert_eq!(interval[0].maximum, Some(60.0));
+        assert_eq!(interval[0].default, Some(serde_json::json!(15)));
+
+        let leaves: Vec<&str> = SettingField::leaves(&fields)
+            .map(|field| field.key.as_str())
+            .collect();
+        assert_eq!(leaves, vec!["sync.interval"]);
+    }
+}
@@ -0,0 +1,237 @@
+// src/plugin/payload.rs - Guard that screens event payloads before plugin handlers see them
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+
+use serde_json::Value;
+
+use crate::event::Event;
+use crate::utils::JsonLimits;
+
+/// Default largest payload, in bytes, delivered to plugin event handlers
+pub const DEFAULT_MAX_EVENT_PAYLOAD_BYTES: usize = 256 * 1024;
+
+/// Metric name logged for every event dropped by the guard
+pub const EVENT_REJECTED_METRIC: &str = "event.rejected";
+
+/// Why an event was not delivered to plugin handlers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadRejection {
+    /// Serialized payload and metadata exceed the configured size
+    TooLarge { size: usize, limit: usize },
+    /// Payload nesting or value count exceeds the JSON limits
+    TooComplex(String),
+    /// Payload does not match the schema declared for the event type
+    SchemaMismatch(String),
+}
+
+impl fmt::Display for PayloadRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge { size, limit } => {
+                write!(f, "payload is {} bytes, limit is {}", size, limit)
+            }
+            Self::TooComplex(reason) => write!(f, "payload is too complex: {}", reason),
+            Self::SchemaMismatch(reason) => write!(f, "payload does not match schema: {}", reason),
+        }
+    }
+}
+
+/// Screens events before they are handed to `Plugin::handle_event`.
+///
+/// The size covers the serialized payload and metadata. Schemas are a JSON
+/// Schema subset: `type`, `required`, `properties` and `items`.
+#[derive(Debug)]
+pub struct EventPayloadGuard {
+    max_payload_bytes: usize,
+    limits: JsonLimits,
+    schemas: RwLock<HashMap<String, Value>>,
+    rejected: AtomicU64,
+}
+
+impl Default for EventPayloadGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_EVENT_PAYLOAD_BYTES)
+    }
+}
+
+impl EventPayloadGuard {
+    pub fn new(max_payload_bytes: usize) -> Self {
+        Self {
+            max_payload_bytes,
+            limits: JsonLimits::default(),
+            schemas: RwLock::new(HashMap::new()),
+            rejected: AtomicU64::new(0),
+        }
+    }
+
+    /// Build a guard from the `plugins` configuration section
+    pub fn from_config(config: &crate::config::PluginConfig) -> Self {
+        Self::new(config.max_event_payload_bytes)
+    }
+
+    /// Set the structural limits checked on payloads
+    pub fn with_limits(mut self, limits: JsonLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Declare the schema payloads of `event_type` must match
+    pub fn register_schema(&self, event_type: impl Into<String>, schema: Value) {
+        self.schemas
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(event_type.into(), schema);
+    }
+
+    /// Check an event without recording anything
+    pub fn check(&self, event: &dyn Event) -> std::result::Result<(), PayloadRejection> {
+        let payload = event.payload();
+
+        let size = payload
+            .as_ref()
+            .map_or(0, |p| serde_json::to_vec(p).map_or(0, |b| b.len()))
+            + serde_json::to_vec(event.metadata()).map_or(0, |b| b.len());
+        if size > self.max_payload_bytes {
+            return Err(PayloadRejection::TooLarge {
+                size,
+                limit: self.max_payload_bytes,
+            });
+        }
+
+        if let Some(payload) = &payload {
+            self.limits
+                .check(payload)
+                .map_err(|e| PayloadRejection::TooComplex(e.message))?;
+        }
+
+        let schemas = self.schemas.read().unwrap_or_else(|e| e.into_inner());
+        if let Some(schema) = schemas.get(event.event_type()) {
+            let payload = payload.unwrap_or(Value::Null);
+            matches_schema(&payload, schema, "$").map_err(PayloadRejection::SchemaMismatch)?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether the event may be delivered; rejections are logged and counted
+    pub fn admit(&self, event: &dyn Event) -> bool {
+        match self.check(event) {
+            Ok(()) => true,
+            Err(rejection) => {
+                let total = self.rejected.fetch_add(1, Ordering::Relaxed) + 1;
+                tracing::warn!(
+                    metric = EVENT_REJECTED_METRIC,
+                    total,
+                    event_type = event.event_type(),
+                    source = event.source(),
+                    "Dropped event before delivery to plugins: {}",
+                    rejection
+                );
+                false
+            }
+        }
+    }
+
+    /// Number of events dropped so far
+    pub fn rejected_count(&self) -> u64 {
+        self.rejected.load(Ordering::Relaxed)
+    }
+}
+
+/// Check `value` against the supported schema keywords, returning the first mismatch
+fn matches_schema(value: &Value, schema: &Value, path: &str) -> std::result::Result<(), String> {
+    if let Some(expected) = schema.get("type").and_then(Value::as_str) {
+        let actual_matches = match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            _ => true,
+        };
+        if !actual_matches {
+            return Err(format!("{} is not of type {}", path, expected));
+        }
+    }
+
+    if let Some(object) = value.as_object() {
+        for field in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !object.contains_key(field) {
+                return Err(format!("{}.{} is required", path, field));
+            }
+        }
+
+        if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+            for (name, property_schema) in properties {
+                if let Some(property) = object.get(name) {
+                    matches_schema(property, property_schema, &format!("{}.{}", path, name))?;
+                }
+            }
+        }
+    }
+
+    if let (Some(items), Some(item_schema)) = (value.as_array(), schema.get("items")) {
+        for (i, item) in items.iter().enumerate() {
+            matches_schema(item, item_schema, &format!("{}[{}]", path, i))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::sdk::PluginEvent;
+
+    #[test]
+    fn test_schema_mismatch_is_rejected() {
+        let guard = EventPayloadGuard::default();
+        guard.register_schema(
+            "inventory.updated",
+            serde_json::json!({
+                "type": "object",
+                "required": ["sku", "quantity"],
+                "properties": { "quantity": { "type": "integer" } }
+            }),
+        );
+
+        let valid = PluginEvent::new(
+            "inventory.updated",
+            "inventory",
+            "inventory",
+            serde_json::json!({ "sku": "A-1", "quantity": 3 }),
+        );
+        assert!(guard.admit(&valid));
+
+        let wrong_type = PluginEvent::new(
+            "inventory.updated",
+            "inventory",
+            "inventory",
+            serde_json::json!({ "sku": "A-1", "quantity": "three" }),
+        );
+        assert_eq!(
+            guard.check(&wrong_type),
+            Err(PayloadRejection::SchemaMismatch(
+                "$.quantity is not of type integer".to_string()
+            ))
+        );
+        assert!(!guard.admit(&wrong_type));
+        assert_eq!(guard.rejected_count(), 1);
+
+        // Event types without a schema only get the size and structure checks
+        let other = PluginEvent::new("other", "inventory", "inventory", serde_json::json!([1]));
+        assert!(guard.admit(&other));
+    }
+}
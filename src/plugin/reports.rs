@@ -0,0 +1,478 @@
+// src/plugin/reports.rs - Recurring reports scheduled by plugins
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::auth::{AccountManager, Permission};
+use crate::error::{Error, Result};
+use crate::event::{Event, EventBusManager};
+use crate::task::CancellationToken;
+use crate::types::Metadata;
+use crate::utils::Time;
+
+/// Event type published once per recipient each time a report runs
+pub const PLUGIN_REPORT_DELIVERED: &str = "report.delivered";
+
+/// Future returned by a report generator
+pub type ReportFuture = Pin<Box<dyn Future<Output = Result<Report>> + Send>>;
+
+/// Computes a report each time its schedule fires
+pub type ReportGenerator = Arc<dyn Fn() -> ReportFuture + Send + Sync>;
+
+/// Output of a single report run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    /// Short headline shown in the notification
+    pub title: String,
+    /// Report data, e.g. the items running low on stock
+    pub payload: serde_json::Value,
+}
+
+impl Report {
+    pub fn new(title: impl Into<String>, payload: serde_json::Value) -> Self {
+        Self {
+            title: title.into(),
+            payload,
+        }
+    }
+}
+
+/// When a report runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportSchedule {
+    /// Every `Duration`, starting one interval after registration
+    Every(Duration),
+    /// On a five-field cron expression, evaluated in UTC
+    Cron(CronSchedule),
+}
+
+impl ReportSchedule {
+    /// Run every `interval`
+    pub fn every(interval: Duration) -> Self {
+        Self::Every(interval)
+    }
+
+    /// Run on a cron expression such as `"0 8 * * 1-5"`
+    pub fn cron(expression: &str) -> Result<Self> {
+        CronSchedule::parse(expression).map(Self::Cron)
+    }
+
+    /// Time to wait from `now` until the next run, `None` if it never runs again
+    pub fn delay_from(&self, now: DateTime<Utc>) -> Option<Duration> {
+        match self {
+            Self::Every(interval) if interval.is_zero() => None,
+            Self::Every(interval) => Some(*interval),
+            Self::Cron(cron) => cron
+                .next_after(now)
+                .and_then(|next| (next - now).to_std().ok()),
+        }
+    }
+}
+
+/// A parsed `minute hour day-of-month month day-of-week` expression.
+///
+/// Parsing and matching are done by the `croner` crate. Fields accept `*`,
+/// values, names, ranges (`1-5`), lists (`1,15`) and steps (`*/15`,
+/// `0-30/10`); both 0 and 7 mean Sunday. As in classic cron, a run matches
+/// when either day field matches if both are restricted.
+#[derive(Clone)]
+pub struct CronSchedule {
+    expression: String,
+    cron: croner::Cron,
+}
+
+impl std::fmt::Debug for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CronSchedule")
+            .field(&self.expression)
+            .finish()
+    }
+}
+
+impl PartialEq for CronSchedule {
+    fn eq(&self, other: &Self) -> bool {
+        self.expression == other.expression
+    }
+}
+
+impl Eq for CronSchedule {}
+
+impl CronSchedule {
+    /// Parse a five-field cron expression
+    pub fn parse(expression: &str) -> Result<Self> {
+        let cron = croner::Cron::new(expression).parse().map_err(|e| {
+            Error::config(format!("Invalid cron expression '{}': {}", expression, e))
+        })?;
+
+        Ok(Self {
+            expression: expression.to_string(),
+            cron,
+        })
+    }
+
+    /// The expression this schedule was parsed from
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// First matching minute strictly after `after`, `None` if there is none
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.cron.find_next_occurrence(&after, false).ok()
+    }
+}
+
+/// Published to each eligible user when a plugin report runs.
+///
+/// The recipient and report identifiers are also copied into the metadata so
+/// they survive in the bus history and poll responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportDeliveredEvent {
+    pub report_id: Uuid,
+    pub plugin_id: String,
+    pub user_id: Uuid,
+    pub title: String,
+    pub payload: serde_json::Value,
+    pub timestamp: DateTime<Utc>,
+    pub source: String,
+    pub metadata: Metadata,
+}
+
+impl ReportDeliveredEvent {
+    pub fn new(report_id: Uuid, plugin_id: &str, user_id: Uuid, report: &Report) -> Self {
+        let mut metadata = Metadata::new();
+        metadata.insert("report_id".to_string(), serde_json::json!(report_id));
+        metadata.insert("plugin_id".to_string(), serde_json::json!(plugin_id));
+        metadata.insert("user_id".to_string(), serde_json::json!(user_id));
+        metadata.insert("title".to_string(), serde_json::json!(report.title));
+
+        Self {
+            report_id,
+            plugin_id: plugin_id.to_string(),
+            user_id,
+            title: report.title.clone(),
+            payload: report.payload.clone(),
+            timestamp: Time::now(),
+            source: format!("plugin.{}", plugin_id),
+            metadata,
+        }
+    }
+}
+
+impl Event for ReportDeliveredEvent {
+    fn event_type(&self) -> &'static str {
+        PLUGIN_REPORT_DELIVERED
+    }
+
+    fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn timestamp(&self) -> DateTime<Utc> {
+        self.timestamp
+    }
+
+    fn payload(&self) -> Option<serde_json::Value> {
+        Some(self.payload.clone())
+    }
+}
+
+/// Where a report's output goes
+#[derive(Debug, Clone)]
+pub(crate) struct ReportDelivery {
+    pub(crate) accounts: Arc<tokio::sync::RwLock<AccountManager>>,
+    pub(crate) event_bus: Arc<EventBusManager>,
+    pub(crate) permission: Permission,
+}
+
+/// Cancellation tokens of running reports, keyed by plugin and report ID.
+///
+/// Reports run on background tasks until their plugin shuts down; the plugin
+/// manager then calls [`ReportScheduler::cancel_plugin`].
+#[derive(Clone, Default)]
+pub struct ReportScheduler {
+    reports: Arc<Mutex<HashMap<String, HashMap<Uuid, CancellationToken>>>>,
+}
+
+impl std::fmt::Debug for ReportScheduler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reports = self.lock();
+        f.debug_struct("ReportScheduler")
+            .field("plugins", &reports.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl PartialEq for ReportScheduler {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.reports, &other.reports)
+    }
+}
+
+impl ReportScheduler {
+    /// Creates a scheduler with no reports
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide scheduler shared by plugin managers
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<ReportScheduler> = OnceLock::new();
+        SHARED.get_or_init(Self::new).clone()
+    }
+
+    /// A handle that can only schedule reports for `plugin_id`
+    pub fn scoped(&self, plugin_id: impl Into<String>) -> PluginReports {
+        PluginReports {
+            plugin_id: plugin_id.into(),
+            scheduler: self.clone(),
+        }
+    }
+
+    /// Number of reports currently scheduled by a plugin
+    pub fn report_count(&self, plugin_id: &str) -> usize {
+        self.lock().get(plugin_id).map_or(0, HashMap::len)
+    }
+
+    /// Stops every report scheduled by a plugin, returning how many
+    pub fn cancel_plugin(&self, plugin_id: &str) -> usize {
+        let Some(reports) = self.lock().remove(plugin_id) else {
+            return 0;
+        };
+        for token in reports.values() {
+            token.cancel();
+        }
+        reports.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, HashMap<Uuid, CancellationToken>>> {
+        self.reports.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A plugin's view of the [`ReportScheduler`], handed out in its `PluginContext`
+#[derive(Clone, Debug, PartialEq)]
+pub struct PluginReports {
+    plugin_id: String,
+    scheduler: ReportScheduler,
+}
+
+impl PluginReports {
+    /// Start running `generator` on `schedule`, returning the report ID
+    pub(crate) fn register(
+        &self,
+        schedule: ReportSchedule,
+        delivery: ReportDelivery,
+        generator: ReportGenerator,
+    ) -> Result<Uuid> {
+        if schedule.delay_from(Time::now()).is_none() {
+            return Err(Error::plugin(
+                &self.plugin_id,
+                "Report schedule never fires",
+            ));
+        }
+
+        let report_id = crate::utils::rand::uuid();
+        let token = CancellationToken::new();
+        self.scheduler
+            .lock()
+            .entry(self.plugin_id.clone())
+            .or_default()
+            .insert(report_id, token.clone());
+
+        let run = run_report(
+            report_id,
+            self.plugin_id.clone(),
+            schedule,
+            delivery,
+            generator,
+            token,
+        );
+        #[cfg(not(target_arch = "wasm32"))]
+        tokio::spawn(run);
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(run);
+
+        Ok(report_id)
+    }
+
+    /// Stops one report, returning whether it was scheduled
+    pub fn cancel(&self, report_id: Uuid) -> bool {
+        let mut reports = self.scheduler.lock();
+        let Some(plugin_reports) = reports.get_mut(&self.plugin_id) else {
+            return false;
+        };
+        match plugin_reports.remove(&report_id) {
+            Some(token) => {
+                token.cancel();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Wait for each scheduled time, then generate and deliver the report
+async fn run_report(
+    report_id: Uuid,
+    plugin_id: String,
+    schedule: ReportSchedule,
+    delivery: ReportDelivery,
+    generator: ReportGenerator,
+    token: CancellationToken,
+) {
+    while let Some(delay) = schedule.delay_from(Time::now()) {
+        let cancelled = token.cancelled();
+        let tick = sleep(delay);
+        futures::pin_mut!(cancelled, tick);
+        if let futures::future::Either::Left(_) = futures::future::select(cancelled, tick).await {
+            break;
+        }
+
+        let report = match generator().await {
+            Ok(report) => report,
+            Err(e) => {
+                tracing::warn!("Report {} of plugin {} failed: {}", report_id, plugin_id, e);
+                continue;
+            }
+        };
+        if token.is_cancelled() {
+            break;
+        }
+
+        if let Err(e) = deliver(report_id, &plugin_id, &report, &delivery).await {
+            tracing::warn!(
+                "Failed to deliver report {} of plugin {}: {}",
+                report_id,
+                plugin_id,
+                e
+            );
+        }
+    }
+}
+
+/// Publish `report` to every active user holding the report's permission
+/// who has not turned notifications off
+async fn deliver(
+    report_id: Uuid,
+    plugin_id: &str,
+    report: &Report,
+    delivery: &ReportDelivery,
+) -> Result<()> {
+    let recipients = delivery
+        .accounts
+        .read()
+        .await
+        .users_with_permission(&delivery.permission.resource, &delivery.permission.action)
+        .await?;
+
+    for user in recipients
+        .iter()
+        .filter(|user| user.preferences.notifications_enabled)
+    {
+        delivery
+            .event_bus
+            .publish(ReportDeliveredEvent::new(
+                report_id, plugin_id, user.id, report,
+            ))
+            .await?;
+    }
+    Ok(())
+}
+
+async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(raw: &str) -> DateTime<Utc> {
+        raw.parse().unwrap()
+    }
+
+    #[test]
+    fn test_cron_next_after() {
+        let daily = CronSchedule::parse("30 8 * * *").unwrap();
+        assert_eq!(
+            daily.next_after(at("2026-03-01T08:29:59Z")),
+            Some(at("2026-03-01T08:30:00Z"))
+        );
+        assert_eq!(
+            daily.next_after(at("2026-03-01T08:30:00Z")),
+            Some(at("2026-03-02T08:30:00Z"))
+        );
+
+        let quarter_hours = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(
+            quarter_hours.next_after(at("2026-12-31T23:50:00Z")),
+            Some(at("2027-01-01T00:00:00Z"))
+        );
+
+        // 2026-03-02 is a Monday; 7 also means Sunday
+        let weekdays = CronSchedule::parse("0 9 * * 1-5").unwrap();
+        assert_eq!(
+            weekdays.next_after(at("2026-02-28T10:00:00Z")),
+            Some(at("2026-03-02T09:00:00Z"))
+        );
+        let sundays = CronSchedule::parse("0 0 * * 7").unwrap();
+        assert_eq!(
+            sundays.next_after(at("2026-02-28T10:00:00Z")),
+            Some(at("2026-03-01T00:00:00Z"))
+        );
+
+        // Restricted day-of-month and day-of-week match either
+        let either = CronSchedule::parse("0 0 15 * 1").unwrap();
+        assert_eq!(
+            either.next_after(at("2026-03-03T00:00:00Z")),
+            Some(at("2026-03-09T00:00:00Z"))
+        );
+
+        // Steps run across the whole range
+        let odd_days = CronSchedule::parse("0 0 */2 * *").unwrap();
+        assert_eq!(
+            odd_days.next_after(at("2026-03-01T12:00:00Z")),
+            Some(at("2026-03-03T00:00:00Z"))
+        );
+
+        assert_eq!(
+            CronSchedule::parse("0 0 30 2 *")
+                .unwrap()
+                .next_after(at("2026-01-01T00:00:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cron_rejects_invalid_expressions() {
+        for expression in ["* * * *", "60 * * * *", "*/0 * * * *", "a * * * *"] {
+            assert!(
+                CronSchedule::parse(expression).is_err(),
+                "{} should be rejected",
+                expression
+            );
+        }
+        assert_eq!(
+            ReportSchedule::every(Duration::ZERO).delay_from(Time::now()),
+            None
+        );
+    }
+}
@@ -0,0 +1,251 @@
+// src/plugin/router.rs - Maps incoming API requests to plugin route handlers
+
+use std::collections::HashMap;
+
+use super::ApiRoute;
+
+/// One `/`-separated piece of a route pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    /// `{name}`, matching any single non-empty segment
+    Param(String),
+}
+
+fn parse_pattern(path: &str) -> Vec<Segment> {
+    split_path(path)
+        .map(|segment| {
+            match segment
+                .strip_prefix('{')
+                .and_then(|rest| rest.strip_suffix('}'))
+            {
+                Some(name) if !name.is_empty() => Segment::Param(name.to_string()),
+                _ => Segment::Literal(segment.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Path segments without the query string, ignoring empty and trailing slashes
+fn split_path(path: &str) -> impl Iterator<Item = &str> {
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    path.split('/').filter(|segment| !segment.is_empty())
+}
+
+/// Decode `%XX` escapes in a path segment; malformed escapes are kept as-is
+fn percent_decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%')
+            .then(|| bytes.get(i + 1..i + 3))
+            .flatten()
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[derive(Debug, Clone)]
+struct RouteEntry {
+    plugin_id: String,
+    route: ApiRoute,
+    segments: Vec<Segment>,
+}
+
+impl RouteEntry {
+    /// Path parameters if `path` fits this route's pattern
+    fn capture(&self, path: &[&str]) -> Option<HashMap<String, String>> {
+        if path.len() != self.segments.len() {
+            return None;
+        }
+
+        let mut params = HashMap::new();
+        for (segment, part) in self.segments.iter().zip(path) {
+            match segment {
+                Segment::Literal(literal) if literal == part => {}
+                Segment::Literal(_) => return None,
+                Segment::Param(name) => {
+                    params.insert(name.clone(), percent_decode(part));
+                }
+            }
+        }
+        Some(params)
+    }
+
+    fn literal_count(&self) -> usize {
+        self.segments
+            .iter()
+            .filter(|s| matches!(s, Segment::Literal(_)))
+            .count()
+    }
+}
+
+/// A route chosen for a request, with the path parameters it captured
+#[derive(Debug, Clone)]
+pub struct RouteMatch<'a> {
+    pub plugin_id: &'a str,
+    pub route: &'a ApiRoute,
+    pub params: HashMap<String, String>,
+}
+
+/// Every plugin's API routes, matched by method and path pattern.
+///
+/// Patterns may contain `{name}` segments, which match one path segment and
+/// are returned, percent-decoded, as path parameters. When several routes fit, the one with
+/// the most literal segments wins, so `/api/products/search` is preferred
+/// over `/api/products/{id}`; remaining ties go to the earliest registered.
+#[derive(Debug, Clone, Default)]
+pub struct ApiRouter {
+    routes: Vec<RouteEntry>,
+}
+
+impl ApiRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `routes` for `plugin_id`, replacing any it registered before
+    pub fn register(&mut self, plugin_id: &str, routes: Vec<ApiRoute>) {
+        self.unregister(plugin_id);
+        self.routes
+            .extend(routes.into_iter().map(|route| RouteEntry {
+                plugin_id: plugin_id.to_string(),
+                segments: parse_pattern(&route.path),
+                route,
+            }));
+    }
+
+    /// Remove every route registered for `plugin_id`
+    pub fn unregister(&mut self, plugin_id: &str) {
+        self.routes.retain(|entry| entry.plugin_id != plugin_id);
+    }
+
+    /// Registered routes with their owning plugin, in registration order
+    pub fn routes(&self) -> impl Iterator<Item = (&str, &ApiRoute)> {
+        self.routes
+            .iter()
+            .map(|entry| (entry.plugin_id.as_str(), &entry.route))
+    }
+
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// The route serving `method` and `path`, if any
+    pub fn match_route(&self, method: &str, path: &str) -> Option<RouteMatch<'_>> {
+        let parts: Vec<&str> = split_path(path).collect();
+
+        let mut best: Option<(&RouteEntry, HashMap<String, String>)> = None;
+        for entry in &self.routes {
+            if !entry.route.method.matches(method) {
+                continue;
+            }
+            let Some(params) = entry.capture(&parts) else {
+                continue;
+            };
+            if best
+                .as_ref()
+                .is_none_or(|(current, _)| entry.literal_count() > current.literal_count())
+            {
+                best = Some((entry, params));
+            }
+        }
+
+        best.map(|(entry, params)| RouteMatch {
+            plugin_id: &entry.plugin_id,
+            route: &entry.route,
+            params,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::{ApiDocumentation, HttpMethod};
+
+    fn route(method: HttpMethod, path: &str) -> ApiRoute {
+        ApiRoute {
+            path: path.to_string(),
+            method,
+            handler_id: path.to_string(),
+            required_permissions: Vec::new(),
+            rate_limit: None,
+            timeout_secs: None,
+            max_request_size: None,
+            documentation: ApiDocumentation {
+                summary: String::new(),
+                description: String::new(),
+                parameters: Vec::new(),
+                responses: Vec::new(),
+                examples: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_match_route_with_params() {
+        let mut router = ApiRouter::new();
+        router.register(
+            "catalog",
+            vec![
+                route(HttpMethod::Get, "/api/products/{id}"),
+                route(HttpMethod::Get, "/api/products/search"),
+                route(HttpMethod::Delete, "/api/products/{id}"),
+            ],
+        );
+        router.register(
+            "orders",
+            vec![route(HttpMethod::Get, "/api/orders/{order}/items/{item}")],
+        );
+        assert_eq!(router.len(), 4);
+
+        let matched = router
+            .match_route("GET", "/api/products/42?fields=name")
+            .unwrap();
+        assert_eq!(matched.plugin_id, "catalog");
+        assert_eq!(matched.route.handler_id, "/api/products/{id}");
+        assert_eq!(matched.params["id"], "42");
+
+        // Literal segments win over parameters
+        let matched = router.match_route("get", "/api/products/search/").unwrap();
+        assert_eq!(matched.route.handler_id, "/api/products/search");
+        assert!(matched.params.is_empty());
+
+        let matched = router
+            .match_route("GET", "/api/products/red%20shoes%2Fsize%3D9%zz")
+            .unwrap();
+        assert_eq!(matched.params["id"], "red shoes/size=9%zz");
+
+        let matched = router.match_route("GET", "/api/orders/7/items/3").unwrap();
+        assert_eq!(matched.plugin_id, "orders");
+        assert_eq!(matched.params["order"], "7");
+        assert_eq!(matched.params["item"], "3");
+
+        assert!(router.match_route("POST", "/api/products/42").is_none());
+        assert!(router.match_route("GET", "/api/products").is_none());
+        assert!(router
+            .match_route("GET", "/api/products/42/extra")
+            .is_none());
+
+        router.unregister("catalog");
+        assert!(router.match_route("GET", "/api/products/42").is_none());
+        assert_eq!(router.routes().count(), 1);
+    }
+}
@@ -73,20 +73,97 @@ impl Event for PluginEvent {
     fn timestamp(&self) -> DateTime<Utc> {
         self.timestamp
     }
+
+    fn payload(&self) -> Option<serde_json::Value> {
+        Some(self.data.clone())
+    }
 }
 
-/// Export the plugin creation function for native plugins
+/// Export a plugin to the host.
+///
+/// Every target gets the Rust-level `plugin_info()`. What else is emitted
+/// depends on how the target loads plugins:
+///
+/// - Native targets get the C ABI entry points resolved by
+///   [`NativePluginLibrary`](crate::plugin::loader::NativePluginLibrary):
+///   `create_plugin`, `destroy_plugin`, `get_plugin_info` and
+///   `qorzen_plugin_abi_version`, which stamps [`PLUGIN_ABI_VERSION`](crate::plugin::PLUGIN_ABI_VERSION)
+///   so a library built against another ABI is refused. Plugins cross
+///   the boundary as a thin `*mut c_void` to a boxed `Box<dyn Plugin>`, never
+///   as a trait-object pointer, and only `destroy_plugin` may free them. None
+///   of the functions unwind; a panic is reported to the host as a null pointer.
+///   Host and plugin must be built with the same compiler and `qorzen_oxide`.
+/// - WASM targets have no dynamic loading, so the plugin is compiled into the
+///   host instead. A `register_plugin()` function adds it to
+///   [`PluginFactoryRegistry::shared`](crate::plugin::PluginFactoryRegistry::shared),
+///   and the host calls it before loading plugins.
 #[macro_export]
 macro_rules! export_plugin {
     ($plugin_type:ty) => {
-        // For safe plugin loading, we register the plugin factory function
-        pub fn create_plugin() -> Box<dyn $crate::plugin::Plugin> {
-            Box::new(<$plugin_type>::new())
-        }
-
         // Export plugin info for discovery
         pub fn plugin_info() -> $crate::plugin::PluginInfo {
-            <$plugin_type>::new().info()
+            $crate::plugin::Plugin::info(&<$plugin_type>::new())
+        }
+
+        /// Registers the plugin's factory for the WASM plugin loader
+        #[cfg(target_arch = "wasm32")]
+        pub fn register_plugin() {
+            $crate::plugin::PluginFactoryRegistry::shared()
+                .register(plugin_info().id, || -> Box<dyn $crate::plugin::Plugin> {
+                    Box::new(<$plugin_type>::new())
+                });
+        }
+
+        /// Creates the plugin; the host releases it with `destroy_plugin`
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub extern "C" fn create_plugin() -> *mut ::std::ffi::c_void {
+            ::std::panic::catch_unwind(|| {
+                let plugin: Box<dyn $crate::plugin::Plugin> = Box::new(<$plugin_type>::new());
+                // Box the trait object again so the host gets a thin pointer
+                Box::into_raw(Box::new(plugin)) as *mut ::std::ffi::c_void
+            })
+            .unwrap_or(::std::ptr::null_mut())
+        }
+
+        /// Drops a plugin returned by `create_plugin`
+        ///
+        /// # Safety
+        /// `plugin` must come from this library's `create_plugin` and not be used afterwards.
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub unsafe extern "C" fn destroy_plugin(plugin: *mut ::std::ffi::c_void) {
+            if !plugin.is_null() {
+                let _ = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+                    drop(Box::from_raw(
+                        plugin as *mut Box<dyn $crate::plugin::Plugin>,
+                    ));
+                }));
+            }
+        }
+
+        /// Reports the plugin ABI this library was compiled against
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub extern "C" fn qorzen_plugin_abi_version() -> u32 {
+            $crate::plugin::PLUGIN_ABI_VERSION
+        }
+
+        /// Returns the plugin info as a NUL-terminated JSON string owned by the library
+        #[cfg(not(target_arch = "wasm32"))]
+        #[no_mangle]
+        pub extern "C" fn get_plugin_info() -> *const ::std::ffi::c_char {
+            static INFO: ::std::sync::OnceLock<Option<::std::ffi::CString>> =
+                ::std::sync::OnceLock::new();
+
+            INFO.get_or_init(|| {
+                ::std::panic::catch_unwind(plugin_info)
+                    .ok()
+                    .and_then(|info| $crate::__private::serde_json::to_string(&info).ok())
+                    .and_then(|json| ::std::ffi::CString::new(json).ok())
+            })
+            .as_ref()
+            .map_or(::std::ptr::null(), |info| info.as_ptr())
         }
     };
 }
@@ -293,6 +370,8 @@ macro_rules! ui_component {
                 id: stringify!($id).to_string(),
                 name: $name.to_string(),
                 component_type: $comp_type,
+                route: None,
+                icon: None,
                 props: serde_json::Value::Object(serde_json::Map::new()),
                 required_permissions: vec![
                     $($(
@@ -314,6 +393,7 @@ macro_rules! ui_component {
                         }
                     ),*)?
                 ],
+                render_fn: None,
             }
         }
     };
@@ -387,6 +467,8 @@ macro_rules! api_route {
             requests_per_minute: $rpm:expr,
             burst_limit: $burst:expr
         },)?
+        $(timeout_secs: $timeout:expr,)?
+        $(max_request_size: $max_request_size:expr,)?
         documentation: {
             summary: $summary:expr,
             description: $description:expr,
@@ -419,6 +501,8 @@ macro_rules! api_route {
                 ),*)?
             ],
             rate_limit: api_route!(@rate_limit $($rpm, $burst)?),
+            timeout_secs: api_route!(@timeout $($timeout)?),
+            max_request_size: api_route!(@max_request_size $($max_request_size)?),
             documentation: $crate::plugin::ApiDocumentation {
                 summary: $summary.to_string(),
                 description: $description.to_string(),
@@ -433,6 +517,12 @@ macro_rules! api_route {
         }
     };
 
+    (@timeout) => { None };
+    (@timeout $timeout:expr) => { Some($timeout) };
+
+    (@max_request_size) => { None };
+    (@max_request_size $bytes:expr) => { Some($bytes) };
+
     (@rate_limit) => { None };
     (@rate_limit $rpm:expr, $burst:expr) => {
         Some($crate::plugin::RateLimit {
@@ -4,11 +4,14 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
 use crate::error::Result;
+use crate::event::{Event, EventBusManager};
 use crate::types::Metadata;
+use crate::utils::Time;
 
 /// Search query structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,7 +20,10 @@ pub struct SearchQuery {
     pub query: String,
     /// Maximum number of results to return
     pub limit: Option<usize>,
-    /// Offset for pagination
+    /// Number of merged results to skip, for pagination.
+    ///
+    /// Providers rank independently, so the coordinator asks each one for the
+    /// first `offset + limit` results and applies the offset after merging.
     pub offset: Option<usize>,
     /// Filters to apply
     pub filters: HashMap<String, SearchFilter>,
@@ -106,11 +112,27 @@ pub struct SearchSuggestion {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResponse {
     pub results: Vec<SearchResult>,
+    /// Merged results before the offset and limit were applied
     pub total_count: usize,
     pub facets: Vec<SearchFacet>,
     pub suggestions: Vec<SearchSuggestion>,
     pub query_time_ms: u64,
     pub sources: Vec<String>,
+    /// Sum of the providers' total estimates; providers without one count
+    /// the results they returned
+    #[serde(default)]
+    pub total_estimated: usize,
+    /// Whether a later page may hold more results
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// One provider's answer to a search
+#[derive(Debug, Clone, Default)]
+pub struct SearchPage {
+    pub results: Vec<SearchResult>,
+    /// How many results match in total, if the provider can tell
+    pub total_estimated: Option<usize>,
 }
 
 /// Plugin search provider trait
@@ -142,6 +164,17 @@ pub trait SearchProvider: Send + Sync + std::fmt::Debug {
     /// Perform a search
     async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>>;
 
+    /// Perform a search, also reporting how many results match in total.
+    ///
+    /// This is what the coordinator calls; the default runs [`Self::search`]
+    /// without an estimate. Override it if the provider can count matches.
+    async fn search_page(&self, query: &SearchQuery) -> Result<SearchPage> {
+        Ok(SearchPage {
+            results: self.search(query).await?,
+            total_estimated: None,
+        })
+    }
+
     /// Get facets for a query (if supported)
     async fn get_facets(&self, query: &SearchQuery) -> Result<Vec<SearchFacet>> {
         let _ = query;
@@ -185,7 +218,7 @@ pub struct IndexableContent {
 }
 
 /// Health status of a search provider
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProviderHealth {
     pub is_healthy: bool,
     pub response_time_ms: Option<u64>,
@@ -193,12 +226,323 @@ pub struct ProviderHealth {
     pub last_check: chrono::DateTime<chrono::Utc>,
 }
 
+/// Default time a provider has to answer a search before it is skipped
+pub const DEFAULT_PROVIDER_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Scale applied to provider priorities when weighting scores.
+///
+/// A provider with priority 100 gets a 1.1x multiplier, enough to break ties
+/// between similarly relevant results without swamping relevance.
+const PRIORITY_SCALE: f64 = 1000.0;
+
+/// Score multiplier derived from a provider's priority
+fn priority_multiplier(priority: i32) -> f64 {
+    (1.0 + f64::from(priority) / PRIORITY_SCALE).max(0.0)
+}
+
+/// Rescale one provider's scores to 0-1 by dividing by its best score
+fn normalize_scores(mut results: Vec<SearchResult>) -> Vec<SearchResult> {
+    let max = results
+        .iter()
+        .map(|r| r.score)
+        .filter(|s| s.is_finite())
+        .fold(0.0_f64, f64::max);
+
+    for result in &mut results {
+        result.score = if max > 0.0 && result.score.is_finite() {
+            (result.score / max).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+    }
+
+    results
+}
+
+/// Default number of values kept per merged facet
+pub const DEFAULT_MAX_FACET_VALUES: usize = 20;
+
+/// Merge facets that share a `field`, summing counts for equal values.
+///
+/// Values are matched by their serialized JSON, sorted by descending count
+/// and capped at `max_values` per facet. Facets are ordered by field.
+fn merge_facets(
+    facet_sets: impl IntoIterator<Item = Vec<SearchFacet>>,
+    max_values: usize,
+) -> Vec<SearchFacet> {
+    let mut merged: HashMap<String, (SearchFacet, HashMap<String, usize>)> = HashMap::new();
+
+    for facet in facet_sets.into_iter().flatten() {
+        let (target, index) = merged.entry(facet.field.clone()).or_insert_with(|| {
+            (
+                SearchFacet {
+                    field: facet.field.clone(),
+                    name: facet.name.clone(),
+                    values: Vec::new(),
+                },
+                HashMap::new(),
+            )
+        });
+
+        for value in facet.values {
+            let key = serde_json::to_string(&value.value).unwrap_or_default();
+            match index.get(&key) {
+                Some(&position) => target.values[position].count += value.count,
+                None => {
+                    index.insert(key, target.values.len());
+                    target.values.push(value);
+                }
+            }
+        }
+    }
+
+    let mut facets: Vec<SearchFacet> = merged
+        .into_values()
+        .map(|(mut facet, _)| {
+            facet.values.sort_by(|a, b| {
+                b.count
+                    .cmp(&a.count)
+                    .then_with(|| a.display_name.cmp(&b.display_name))
+            });
+            facet.values.truncate(max_values);
+            facet
+        })
+        .collect();
+    facets.sort_by(|a, b| a.field.cmp(&b.field));
+    facets
+}
+
+/// Default time cached suggestions stay valid
+pub const DEFAULT_SUGGESTION_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Default number of suggestion lists kept in the cache
+pub const DEFAULT_SUGGESTION_CACHE_CAPACITY: usize = 256;
+
+/// Default minimum query length, in characters, before providers are asked
+pub const DEFAULT_MIN_SUGGESTION_QUERY_LEN: usize = 2;
+
+/// Number of suggestions returned when a query has no limit
+const DEFAULT_SUGGESTION_LIMIT: usize = 10;
+
+/// Snapshot of the suggestion cache
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuggestionCacheStats {
+    pub entries: usize,
+    pub capacity: usize,
+    pub ttl_ms: u64,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Status of the search coordinator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchCoordinatorStatus {
+    pub providers: usize,
+    pub healthy_providers: usize,
+    pub suggestion_cache: SuggestionCacheStats,
+}
+
+#[derive(Debug)]
+struct CachedSuggestions {
+    suggestions: Vec<SearchSuggestion>,
+    expires_at: u64,
+    last_used: u64,
+}
+
+/// LRU cache of merged suggestions keyed by `(query, limit)`.
+///
+/// Guarded by a plain mutex: it is never held across an await, so type-ahead
+/// requests firing concurrently only contend for the lookup itself.
+#[derive(Debug)]
+struct SuggestionCache {
+    entries: std::sync::Mutex<HashMap<(String, usize), CachedSuggestions>>,
+    capacity: usize,
+    ttl: Duration,
+    clock: std::sync::atomic::AtomicU64,
+    hits: std::sync::atomic::AtomicU64,
+    misses: std::sync::atomic::AtomicU64,
+}
+
+impl SuggestionCache {
+    fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            entries: std::sync::Mutex::new(HashMap::new()),
+            capacity,
+            ttl,
+            clock: std::sync::atomic::AtomicU64::new(0),
+            hits: std::sync::atomic::AtomicU64::new(0),
+            misses: std::sync::atomic::AtomicU64::new(0),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(String, usize), CachedSuggestions>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    }
+
+    fn get(&self, key: &(String, usize)) -> Option<Vec<SearchSuggestion>> {
+        use std::sync::atomic::Ordering;
+
+        let now = Time::now_millis();
+        let mut entries = self.lock();
+        match entries.get_mut(key) {
+            Some(entry) if entry.expires_at > now => {
+                entry.last_used = self.tick();
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                Some(entry.suggestions.clone())
+            }
+            Some(_) => {
+                entries.remove(key);
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    fn insert(&self, key: (String, usize), suggestions: Vec<SearchSuggestion>) {
+        if self.capacity == 0 || self.ttl.is_zero() {
+            return;
+        }
+
+        let now = Time::now_millis();
+        let mut entries = self.lock();
+        entries.retain(|_, entry| entry.expires_at > now);
+
+        if !entries.contains_key(&key) && entries.len() >= self.capacity {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(
+            key,
+            CachedSuggestions {
+                suggestions,
+                expires_at: now.saturating_add(self.ttl.as_millis() as u64),
+                last_used: self.tick(),
+            },
+        );
+    }
+
+    fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn stats(&self) -> SuggestionCacheStats {
+        use std::sync::atomic::Ordering;
+
+        SuggestionCacheStats {
+            entries: self.lock().len(),
+            capacity: self.capacity,
+            ttl_ms: self.ttl.as_millis() as u64,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Event type published when a search provider is registered
+pub const SEARCH_PROVIDER_REGISTERED: &str = "search.provider_registered";
+
+/// Event type published when a search provider is unregistered
+pub const SEARCH_PROVIDER_UNREGISTERED: &str = "search.provider_unregistered";
+
+/// Whether a search provider was added or removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SearchProviderChange {
+    Registered,
+    Unregistered,
+}
+
+/// Published by [`SearchCoordinator`] when its set of providers changes.
+///
+/// The provider details are also copied into the metadata so they survive in
+/// the bus history and poll responses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchProviderEvent {
+    pub change: SearchProviderChange,
+    pub provider_id: String,
+    pub provider_name: String,
+    pub result_types: Vec<String>,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub source: String,
+    pub metadata: Metadata,
+}
+
+impl SearchProviderEvent {
+    pub fn new(change: SearchProviderChange, provider: &dyn SearchProvider) -> Self {
+        let provider_id = provider.provider_id().to_string();
+        let provider_name = provider.provider_name().to_string();
+        let result_types = provider.supported_result_types();
+
+        let mut metadata = Metadata::new();
+        metadata.insert("provider_id".to_string(), serde_json::json!(provider_id));
+        metadata.insert(
+            "provider_name".to_string(),
+            serde_json::json!(provider_name),
+        );
+        metadata.insert("result_types".to_string(), serde_json::json!(result_types));
+
+        Self {
+            change,
+            provider_id,
+            provider_name,
+            result_types,
+            timestamp: Time::now(),
+            source: "search_coordinator".to_string(),
+            metadata,
+        }
+    }
+}
+
+impl Event for SearchProviderEvent {
+    fn event_type(&self) -> &'static str {
+        match self.change {
+            SearchProviderChange::Registered => SEARCH_PROVIDER_REGISTERED,
+            SearchProviderChange::Unregistered => SEARCH_PROVIDER_UNREGISTERED,
+        }
+    }
+
+    fn source(&self) -> &str {
+        &self.source
+    }
+
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn timestamp(&self) -> chrono::DateTime<chrono::Utc> {
+        self.timestamp
+    }
+}
+
 /// Central search coordinator that manages all search providers
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct SearchCoordinator {
     providers: Arc<RwLock<HashMap<String, Arc<dyn SearchProvider>>>>,
     provider_health: Arc<RwLock<HashMap<String, ProviderHealth>>>,
+    provider_timeout: Duration,
+    max_facet_values: usize,
+    suggestion_cache: Arc<SuggestionCache>,
+    min_suggestion_query_len: usize,
+    event_bus: Option<Arc<EventBusManager>>,
 }
 
 #[allow(dead_code)]
@@ -208,6 +552,61 @@ impl SearchCoordinator {
         Self {
             providers: Arc::new(RwLock::new(HashMap::new())),
             provider_health: Arc::new(RwLock::new(HashMap::new())),
+            provider_timeout: DEFAULT_PROVIDER_TIMEOUT,
+            max_facet_values: DEFAULT_MAX_FACET_VALUES,
+            suggestion_cache: Arc::new(SuggestionCache::new(
+                DEFAULT_SUGGESTION_CACHE_CAPACITY,
+                DEFAULT_SUGGESTION_CACHE_TTL,
+            )),
+            min_suggestion_query_len: DEFAULT_MIN_SUGGESTION_QUERY_LEN,
+            event_bus: None,
+        }
+    }
+
+    /// Set how long each provider may take to return results
+    pub fn with_provider_timeout(mut self, timeout: Duration) -> Self {
+        self.provider_timeout = timeout;
+        self
+    }
+
+    /// Set the maximum number of values returned per facet
+    pub fn with_max_facet_values(mut self, max_values: usize) -> Self {
+        self.max_facet_values = max_values;
+        self
+    }
+
+    /// Set the size and lifetime of the suggestion cache; either at zero disables it
+    pub fn with_suggestion_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.suggestion_cache = Arc::new(SuggestionCache::new(capacity, ttl));
+        self
+    }
+
+    /// Set the shortest query, in characters, that is sent to providers for suggestions
+    pub fn with_min_suggestion_query_len(mut self, min_len: usize) -> Self {
+        self.min_suggestion_query_len = min_len;
+        self
+    }
+
+    /// Publish provider (de)registration events to this bus
+    pub fn with_event_bus(mut self, event_bus: Arc<EventBusManager>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    async fn publish_provider_change(
+        &self,
+        change: SearchProviderChange,
+        provider: &dyn SearchProvider,
+    ) {
+        if let Some(event_bus) = &self.event_bus {
+            let event = SearchProviderEvent::new(change, provider);
+            if let Err(e) = event_bus.publish(event).await {
+                tracing::warn!(
+                    "Failed to publish search provider event for {}: {}",
+                    provider.provider_id(),
+                    e
+                );
+            }
         }
     }
 
@@ -227,47 +626,73 @@ impl SearchCoordinator {
         self.providers
             .write()
             .await
-            .insert(provider_id.clone(), provider);
+            .insert(provider_id.clone(), Arc::clone(&provider));
         self.provider_health
             .write()
             .await
             .insert(provider_id, health);
+        self.suggestion_cache.clear();
 
+        self.publish_provider_change(SearchProviderChange::Registered, provider.as_ref())
+            .await;
         Ok(())
     }
 
     /// Unregister a search provider
     pub async fn unregister_provider(&self, provider_id: &str) -> Result<()> {
-        self.providers.write().await.remove(provider_id);
+        let removed = self.providers.write().await.remove(provider_id);
         self.provider_health.write().await.remove(provider_id);
+        self.suggestion_cache.clear();
+
+        if let Some(provider) = removed {
+            self.publish_provider_change(SearchProviderChange::Unregistered, provider.as_ref())
+                .await;
+        }
         Ok(())
     }
 
-    /// Perform a federated search across all providers
+    /// Perform a federated search across all providers.
+    ///
+    /// Providers are queried concurrently. Each provider's scores are
+    /// normalized to 0-1, weighted by its priority, and the merged list is
+    /// sorted by weighted score. Providers that fail or time out are skipped.
+    ///
+    /// With a limit, `has_more` is set when the merged results run past the
+    /// page or any provider returned a full page it did not report as complete.
     pub async fn search(&self, query: SearchQuery) -> Result<SearchResponse> {
         let start_time = std::time::Instant::now();
         let providers = self.providers.read().await;
 
-        let mut all_results = Vec::new();
         let mut all_facets = Vec::new();
         let mut all_suggestions = Vec::new();
         let mut sources = Vec::new();
 
+        let offset = query.offset.unwrap_or(0);
+        let page_end = query.limit.map(|limit| offset.saturating_add(limit));
+        let mut provider_query = query.clone();
+        provider_query.offset = None;
+        provider_query.limit = page_end;
+
         // Search all providers concurrently
+        let timeout = self.provider_timeout;
         let search_tasks: Vec<_> = providers
             .values()
             .map(|provider| {
                 let provider = Arc::clone(provider);
-                let query = query.clone();
+                let query = provider_query.clone();
                 async move {
                     let provider_id = provider.provider_id().to_string();
-                    match provider.search(&query).await {
-                        Ok(results) => Some((provider_id, results)),
-                        Err(e) => {
+                    match super::with_timeout(timeout, provider.search_page(&query)).await {
+                        Some(Ok(page)) => Some((provider_id, provider.priority(), page)),
+                        Some(Err(e)) => {
+                            tracing::warn!("Search provider {} failed: {}", provider_id, e);
+                            None
+                        }
+                        None => {
                             tracing::warn!(
-                                "Search provider {} failed: {}",
-                                provider.provider_id(),
-                                e
+                                "Search provider {} timed out after {:?}",
+                                provider_id,
+                                timeout
                             );
                             None
                         }
@@ -279,70 +704,53 @@ impl SearchCoordinator {
         // Execute all searches
         let search_results = futures::future::join_all(search_tasks).await;
 
-        // Collect results
-        for (provider_id, mut results) in search_results.into_iter().flatten() {
+        // Normalize per provider, then weight by priority
+        let mut ranked = Vec::new();
+        let mut total_estimated = 0;
+        let mut provider_has_more = false;
+        for (provider_id, priority, page) in search_results.into_iter().flatten() {
             sources.push(provider_id);
-            all_results.append(&mut results);
+            let returned = page.results.len();
+            total_estimated += page.total_estimated.unwrap_or(returned).max(returned);
+            if let Some(end) = page_end {
+                provider_has_more |= page
+                    .total_estimated
+                    .map_or(returned >= end, |total| total > returned);
+            }
+
+            let multiplier = priority_multiplier(priority);
+            for result in normalize_scores(page.results) {
+                ranked.push((result.score * multiplier, priority, result));
+            }
         }
 
+        ranked.sort_by(|a, b| {
+            b.0.partial_cmp(&a.0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(b.1.cmp(&a.1))
+        });
+        let mut all_results: Vec<SearchResult> =
+            ranked.into_iter().map(|(_, _, result)| result).collect();
+
         // Get facets if requested
         if !query.facets.is_empty() {
-            let facet_tasks: Vec<_> = providers
-                .values()
-                .filter(|p| p.supports_facets())
-                .map(|provider| {
-                    let provider = Arc::clone(provider);
-                    let query = query.clone();
-                    async move { provider.get_facets(&query).await.unwrap_or_default() }
-                })
-                .collect();
-
-            let facet_results = futures::future::join_all(facet_tasks).await;
-            for mut facets in facet_results {
-                all_facets.append(&mut facets);
-            }
+            all_facets = self.collect_facets(&providers, &query).await;
         }
 
         // Get suggestions if requested
         if query.include_suggestions {
-            let suggestion_tasks: Vec<_> = providers
-                .values()
-                .filter(|p| p.supports_suggestions())
-                .map(|provider| {
-                    let provider = Arc::clone(provider);
-                    let query = query.clone();
-                    async move { provider.get_suggestions(&query).await.unwrap_or_default() }
-                })
-                .collect();
-
-            let suggestion_results = futures::future::join_all(suggestion_tasks).await;
-            for mut suggestions in suggestion_results {
-                all_suggestions.append(&mut suggestions);
-            }
+            all_suggestions = self.collect_suggestions(&providers, &query).await;
         }
 
-        // Sort results by score (descending)
-        all_results.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
         // Apply pagination
         let total_count = all_results.len();
-        if let (Some(offset), Some(limit)) = (query.offset, query.limit) {
-            all_results = all_results.into_iter().skip(offset).take(limit).collect();
-        } else if let Some(limit) = query.limit {
+        let has_more = page_end.is_some_and(|end| provider_has_more || total_count > end);
+        all_results.drain(..offset.min(total_count));
+        if let Some(limit) = query.limit {
             all_results.truncate(limit);
         }
 
-        // Sort suggestions by score
-        all_suggestions.sort_by(|a, b| {
-            b.score
-                .partial_cmp(&a.score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        all_suggestions.truncate(10); // Limit suggestions
+        all_suggestions.truncate(DEFAULT_SUGGESTION_LIMIT);
 
         let query_time = start_time.elapsed().as_millis() as u64;
 
@@ -353,9 +761,134 @@ impl SearchCoordinator {
             suggestions: all_suggestions,
             query_time_ms: query_time,
             sources,
+            total_estimated,
+            has_more,
         })
     }
 
+    /// Get facets from every facet-capable provider, merged by field
+    pub async fn get_facets(&self, query: &SearchQuery) -> Result<Vec<SearchFacet>> {
+        let providers = self.providers.read().await;
+        Ok(self.collect_facets(&providers, query).await)
+    }
+
+    async fn collect_facets(
+        &self,
+        providers: &HashMap<String, Arc<dyn SearchProvider>>,
+        query: &SearchQuery,
+    ) -> Vec<SearchFacet> {
+        let timeout = self.provider_timeout;
+        let facet_tasks: Vec<_> = providers
+            .values()
+            .filter(|p| p.supports_facets())
+            .map(|provider| {
+                let provider = Arc::clone(provider);
+                async move {
+                    match super::with_timeout(timeout, provider.get_facets(query)).await {
+                        Some(Ok(facets)) => facets,
+                        Some(Err(e)) => {
+                            tracing::warn!(
+                                "Facet provider {} failed: {}",
+                                provider.provider_id(),
+                                e
+                            );
+                            Vec::new()
+                        }
+                        None => {
+                            tracing::warn!(
+                                "Facet provider {} timed out after {:?}",
+                                provider.provider_id(),
+                                timeout
+                            );
+                            Vec::new()
+                        }
+                    }
+                }
+            })
+            .collect();
+
+        let facet_results = futures::future::join_all(facet_tasks).await;
+        merge_facets(facet_results, self.max_facet_values)
+    }
+
+    /// Get type-ahead suggestions from every suggestion-capable provider.
+    ///
+    /// Queries shorter than the minimum length return nothing without reaching
+    /// providers, and merged results are cached per `(query, limit)`.
+    pub async fn get_suggestions(&self, query: &SearchQuery) -> Result<Vec<SearchSuggestion>> {
+        if query.query.trim().chars().count() < self.min_suggestion_query_len {
+            return Ok(Vec::new());
+        }
+
+        let limit = query.limit.unwrap_or(DEFAULT_SUGGESTION_LIMIT);
+        let key = (query.query.clone(), limit);
+        if let Some(suggestions) = self.suggestion_cache.get(&key) {
+            return Ok(suggestions);
+        }
+
+        let mut suggestions = {
+            let providers = self.providers.read().await;
+            self.collect_suggestions(&providers, query).await
+        };
+        suggestions.truncate(limit);
+
+        self.suggestion_cache.insert(key, suggestions.clone());
+        Ok(suggestions)
+    }
+
+    /// Drop every cached suggestion list
+    pub fn clear_suggestion_cache(&self) {
+        self.suggestion_cache.clear();
+    }
+
+    async fn collect_suggestions(
+        &self,
+        providers: &HashMap<String, Arc<dyn SearchProvider>>,
+        query: &SearchQuery,
+    ) -> Vec<SearchSuggestion> {
+        let timeout = self.provider_timeout;
+        let suggestion_tasks: Vec<_> = providers
+            .values()
+            .filter(|p| p.supports_suggestions())
+            .map(|provider| {
+                let provider = Arc::clone(provider);
+                async move {
+                    match super::with_timeout(timeout, provider.get_suggestions(query)).await {
+                        Some(Ok(suggestions)) => suggestions,
+                        Some(Err(e)) => {
+                            tracing::warn!(
+                                "Suggestion provider {} failed: {}",
+                                provider.provider_id(),
+                                e
+                            );
+                            Vec::new()
+                        }
+                        None => {
+                            tracing::warn!(
+                                "Suggestion provider {} timed out after {:?}",
+                                provider.provider_id(),
+                                timeout
+                            );
+                            Vec::new()
+                        }
+                    }
+                }
+            })
+            .collect();
+
+        let mut suggestions: Vec<SearchSuggestion> = futures::future::join_all(suggestion_tasks)
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+        suggestions.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        suggestions
+    }
+
     /// Index content across relevant providers
     pub async fn index_content(&self, content: IndexableContent) -> Result<()> {
         let providers = self.providers.read().await;
@@ -423,6 +956,24 @@ impl SearchCoordinator {
     pub async fn list_providers(&self) -> Vec<String> {
         self.providers.read().await.keys().cloned().collect()
     }
+
+    /// Report provider counts and suggestion cache statistics
+    pub async fn status(&self) -> SearchCoordinatorStatus {
+        let providers = self.providers.read().await.len();
+        let healthy_providers = self
+            .provider_health
+            .read()
+            .await
+            .values()
+            .filter(|h| h.is_healthy)
+            .count();
+
+        SearchCoordinatorStatus {
+            providers,
+            healthy_providers,
+            suggestion_cache: self.suggestion_cache.stats(),
+        }
+    }
 }
 
 impl Default for SearchCoordinator {
@@ -484,6 +1035,10 @@ impl SearchProvider for ExampleSearchProvider {
     }
 
     async fn search(&self, query: &SearchQuery) -> Result<Vec<SearchResult>> {
+        Ok(self.search_page(query).await?.results)
+    }
+
+    async fn search_page(&self, query: &SearchQuery) -> Result<SearchPage> {
         let content = self.indexed_content.read().await;
         let query_lower = query.query.to_lowercase();
 
@@ -515,7 +1070,20 @@ impl SearchProvider for ExampleSearchProvider {
             }
         }
 
-        Ok(results)
+        let total_estimated = Some(results.len());
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        if let Some(limit) = query.limit {
+            results.truncate(limit);
+        }
+
+        Ok(SearchPage {
+            results,
+            total_estimated,
+        })
     }
 
     async fn get_suggestions(&self, query: &SearchQuery) -> Result<Vec<SearchSuggestion>> {
@@ -570,6 +1138,150 @@ impl SearchProvider for ExampleSearchProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::manager::Manager;
+
+    /// Provider returning fixed results, optionally failing or stalling
+    #[derive(Debug)]
+    struct StaticProvider {
+        id: String,
+        priority: i32,
+        scores: Vec<(&'static str, f64)>,
+        fail: bool,
+        delay: Duration,
+        facets: Vec<SearchFacet>,
+        suggestions: Vec<&'static str>,
+        suggestion_calls: std::sync::atomic::AtomicUsize,
+        /// Reported total; the results are cut to the query limit when set
+        total: Option<usize>,
+    }
+
+    impl StaticProvider {
+        fn new(id: &str, priority: i32, scores: Vec<(&'static str, f64)>) -> Self {
+            Self {
+                id: id.to_string(),
+                priority,
+                scores,
+                fail: false,
+                delay: Duration::ZERO,
+                facets: Vec::new(),
+                suggestions: Vec::new(),
+                suggestion_calls: std::sync::atomic::AtomicUsize::new(0),
+                total: None,
+            }
+        }
+    }
+
+    #[async_trait]
+    impl SearchProvider for StaticProvider {
+        fn provider_id(&self) -> &str {
+            &self.id
+        }
+
+        fn provider_name(&self) -> &str {
+            &self.id
+        }
+
+        fn description(&self) -> &str {
+            "Static test provider"
+        }
+
+        fn priority(&self) -> i32 {
+            self.priority
+        }
+
+        fn supported_result_types(&self) -> Vec<String> {
+            vec!["static".to_string()]
+        }
+
+        fn supports_facets(&self) -> bool {
+            !self.facets.is_empty()
+        }
+
+        fn supports_suggestions(&self) -> bool {
+            !self.suggestions.is_empty()
+        }
+
+        async fn get_facets(&self, _query: &SearchQuery) -> Result<Vec<SearchFacet>> {
+            Ok(self.facets.clone())
+        }
+
+        async fn get_suggestions(&self, query: &SearchQuery) -> Result<Vec<SearchSuggestion>> {
+            self.suggestion_calls
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(self
+                .suggestions
+                .iter()
+                .enumerate()
+                .map(|(i, text)| SearchSuggestion {
+                    text: text.to_string(),
+                    completion: format!("{}{}", query.query, text),
+                    category: None,
+                    score: 1.0 / (i + 1) as f64,
+                })
+                .collect())
+        }
+
+        async fn search(&self, _query: &SearchQuery) -> Result<Vec<SearchResult>> {
+            tokio::time::sleep(self.delay).await;
+            if self.fail {
+                return Err(crate::error::Error::plugin(&self.id, "search failed"));
+            }
+
+            Ok(self
+                .scores
+                .iter()
+                .map(|(id, score)| SearchResult {
+                    id: id.to_string(),
+                    result_type: "static".to_string(),
+                    title: id.to_string(),
+                    description: None,
+                    score: *score,
+                    url: None,
+                    thumbnail: None,
+                    metadata: HashMap::new(),
+                    source_plugin: self.id.clone(),
+                    timestamp: chrono::Utc::now(),
+                })
+                .collect())
+        }
+
+        async fn search_page(&self, query: &SearchQuery) -> Result<SearchPage> {
+            let mut results = self.search(query).await?;
+            if self.total.is_some() {
+                results.truncate(query.limit.unwrap_or(usize::MAX));
+            }
+            Ok(SearchPage {
+                results,
+                total_estimated: self.total,
+            })
+        }
+
+        async fn health_check(&self) -> Result<ProviderHealth> {
+            Ok(ProviderHealth {
+                is_healthy: true,
+                response_time_ms: None,
+                error_message: None,
+                last_check: chrono::Utc::now(),
+            })
+        }
+    }
+
+    fn query(text: &str, limit: Option<usize>) -> SearchQuery {
+        SearchQuery {
+            query: text.to_string(),
+            limit,
+            offset: None,
+            filters: HashMap::new(),
+            facets: vec![],
+            include_suggestions: false,
+            context: SearchContext {
+                user_id: None,
+                permissions: vec![],
+                preferences: HashMap::new(),
+                metadata: HashMap::new(),
+            },
+        }
+    }
 
     #[tokio::test]
     async fn test_search_coordinator() {
@@ -620,6 +1332,35 @@ mod tests {
         assert_eq!(response.results[0].title, "Test Document");
     }
 
+    #[tokio::test]
+    async fn test_register_provider_publishes_event() {
+        let mut event_bus = EventBusManager::new(crate::event::EventBusConfig::default());
+        event_bus.initialize().await.unwrap();
+        let event_bus = Arc::new(event_bus);
+        let coordinator = SearchCoordinator::new().with_event_bus(Arc::clone(&event_bus));
+
+        coordinator
+            .register_provider(Arc::new(StaticProvider::new("catalog", 0, vec![])))
+            .await
+            .unwrap();
+        coordinator.unregister_provider("catalog").await.unwrap();
+        // Unknown providers publish nothing
+        coordinator.unregister_provider("missing").await.unwrap();
+
+        let events = event_bus.recent_events(10).await;
+        let types: Vec<_> = events.iter().map(|e| e.event_type.as_str()).collect();
+        assert_eq!(
+            types,
+            vec![SEARCH_PROVIDER_REGISTERED, SEARCH_PROVIDER_UNREGISTERED]
+        );
+        assert_eq!(events[0].metadata["provider_id"], "catalog");
+        assert_eq!(
+            events[0].metadata["result_types"],
+            serde_json::json!(["static"])
+        );
+        assert_eq!(events[0].source, "search_coordinator");
+    }
+
     #[tokio::test]
     async fn test_search_provider_suggestions() {
         let provider = ExampleSearchProvider::new("test".to_string(), "Test Provider".to_string());
@@ -656,4 +1397,267 @@ mod tests {
         let suggestions = provider.get_suggestions(&query).await.unwrap();
         assert!(!suggestions.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_search_merges_normalized_scores() {
+        let coordinator = SearchCoordinator::new().with_provider_timeout(Duration::from_millis(50));
+
+        // Raw scores on very different scales
+        coordinator
+            .register_provider(Arc::new(StaticProvider::new(
+                "large",
+                0,
+                vec![("l1", 80.0), ("l2", 40.0)],
+            )))
+            .await
+            .unwrap();
+        coordinator
+            .register_provider(Arc::new(StaticProvider::new(
+                "small",
+                100,
+                vec![("s1", 0.5), ("s2", 0.1)],
+            )))
+            .await
+            .unwrap();
+
+        let mut failing = StaticProvider::new("failing", 500, vec![("f1", 1.0)]);
+        failing.fail = true;
+        coordinator
+            .register_provider(Arc::new(failing))
+            .await
+            .unwrap();
+
+        let mut slow = StaticProvider::new("slow", 500, vec![("x1", 1.0)]);
+        slow.delay = Duration::from_secs(5);
+        coordinator.register_provider(Arc::new(slow)).await.unwrap();
+
+        let response = coordinator.search(query("any", Some(3))).await.unwrap();
+
+        let ids: Vec<_> = response.results.iter().map(|r| r.id.as_str()).collect();
+        // Both top results normalize to 1.0; priority breaks the tie
+        assert_eq!(ids, ["s1", "l1", "l2"]);
+        assert_eq!(response.total_count, 4);
+        assert!(response
+            .results
+            .iter()
+            .all(|r| (0.0..=1.0).contains(&r.score)));
+        assert!((response.results[2].score - 0.5).abs() < f64::EPSILON);
+
+        let mut sources = response.sources.clone();
+        sources.sort();
+        assert_eq!(sources, ["large", "small"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_pagination_metadata() {
+        let coordinator = SearchCoordinator::new();
+        let mut counted = StaticProvider::new(
+            "counted",
+            0,
+            vec![("c1", 0.9), ("c2", 0.8), ("c3", 0.7), ("c4", 0.6)],
+        );
+        counted.total = Some(40);
+        coordinator
+            .register_provider(Arc::new(counted))
+            .await
+            .unwrap();
+        coordinator
+            .register_provider(Arc::new(StaticProvider::new(
+                "plain",
+                -500,
+                vec![("p1", 0.5)],
+            )))
+            .await
+            .unwrap();
+
+        let mut second_page = query("any", Some(2));
+        second_page.offset = Some(2);
+        let response = coordinator.search(second_page).await.unwrap();
+
+        // "counted" was asked for offset + limit results and cut to them
+        let ids: Vec<_> = response.results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["c3", "c4"]);
+        assert_eq!(response.total_count, 5);
+        assert_eq!(response.total_estimated, 41);
+        assert!(response.has_more);
+
+        let mut everything = query("any", Some(10));
+        everything.offset = Some(3);
+        let response = coordinator.search(everything).await.unwrap();
+        assert_eq!(response.results.len(), 2);
+        // "counted" reports 40 matches but returned only 4 of them
+        assert!(response.has_more);
+
+        let coordinator = SearchCoordinator::new();
+        coordinator
+            .register_provider(Arc::new(StaticProvider::new(
+                "plain",
+                0,
+                vec![("p1", 0.5), ("p2", 0.4)],
+            )))
+            .await
+            .unwrap();
+
+        let full_page = coordinator.search(query("any", Some(2))).await.unwrap();
+        assert_eq!(full_page.total_estimated, 2);
+        assert!(full_page.has_more, "a full page may be followed by more");
+
+        let last_page = coordinator.search(query("any", Some(3))).await.unwrap();
+        assert!(!last_page.has_more);
+
+        let unlimited = coordinator.search(query("any", None)).await.unwrap();
+        assert!(!unlimited.has_more);
+
+        // An offset near usize::MAX does not overflow the page end
+        let mut far_page = query("any", Some(10));
+        far_page.offset = Some(usize::MAX - 1);
+        let response = coordinator.search(far_page).await.unwrap();
+        assert!(response.results.is_empty());
+        assert!(!response.has_more);
+    }
+
+    #[test]
+    fn test_normalize_scores() {
+        let result = |score| SearchResult {
+            id: "r".to_string(),
+            result_type: "static".to_string(),
+            title: "r".to_string(),
+            description: None,
+            score,
+            url: None,
+            thumbnail: None,
+            metadata: HashMap::new(),
+            source_plugin: "p".to_string(),
+            timestamp: chrono::Utc::now(),
+        };
+
+        let normalized = normalize_scores(vec![result(4.0), result(1.0), result(f64::NAN)]);
+        let scores: Vec<_> = normalized.iter().map(|r| r.score).collect();
+        assert_eq!(scores, [1.0, 0.25, 0.0]);
+
+        assert_eq!(normalize_scores(vec![result(0.0)])[0].score, 0.0);
+        assert!(priority_multiplier(100) > priority_multiplier(0));
+    }
+
+    fn facet(field: &str, values: &[(&str, usize)]) -> SearchFacet {
+        SearchFacet {
+            field: field.to_string(),
+            name: field.to_string(),
+            values: values
+                .iter()
+                .map(|(value, count)| FacetValue {
+                    value: serde_json::json!(value),
+                    display_name: value.to_string(),
+                    count: *count,
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_facets_merges_by_field() {
+        let coordinator = SearchCoordinator::new().with_max_facet_values(2);
+
+        let mut products = StaticProvider::new("products", 0, vec![]);
+        products.facets = vec![
+            facet("category", &[("tools", 5), ("paint", 2)]),
+            facet("brand", &[("acme", 4)]),
+        ];
+        let mut catalog = StaticProvider::new("catalog", 0, vec![]);
+        catalog.facets = vec![facet("category", &[("paint", 7), ("garden", 1)])];
+
+        coordinator
+            .register_provider(Arc::new(products))
+            .await
+            .unwrap();
+        coordinator
+            .register_provider(Arc::new(catalog))
+            .await
+            .unwrap();
+
+        let facets = coordinator.get_facets(&query("any", None)).await.unwrap();
+
+        assert_eq!(facets.len(), 2);
+        assert_eq!(facets[0].field, "brand");
+
+        let category = &facets[1];
+        let values: Vec<_> = category
+            .values
+            .iter()
+            .map(|v| (v.display_name.as_str(), v.count))
+            .collect();
+        // paint: 2 + 7, capped at two values
+        assert_eq!(values, [("paint", 9), ("tools", 5)]);
+    }
+
+    #[tokio::test]
+    async fn test_get_suggestions_uses_cache() {
+        use std::sync::atomic::Ordering;
+
+        let coordinator = SearchCoordinator::new();
+        let mut provider = StaticProvider::new("products", 0, vec![]);
+        provider.suggestions = vec!["ammer", "acksaw", "inge"];
+        let provider = Arc::new(provider);
+        coordinator
+            .register_provider(Arc::clone(&provider) as Arc<dyn SearchProvider>)
+            .await
+            .unwrap();
+
+        // Below the minimum length providers are never asked
+        let short = coordinator
+            .get_suggestions(&query("h", None))
+            .await
+            .unwrap();
+        assert!(short.is_empty());
+        assert_eq!(provider.suggestion_calls.load(Ordering::SeqCst), 0);
+
+        let first = coordinator
+            .get_suggestions(&query("ha", Some(2)))
+            .await
+            .unwrap();
+        let second = coordinator
+            .get_suggestions(&query("ha", Some(2)))
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 2);
+        assert_eq!(first[0].text, second[0].text);
+        assert_eq!(provider.suggestion_calls.load(Ordering::SeqCst), 1);
+
+        // A different limit is a different cache entry
+        coordinator
+            .get_suggestions(&query("ha", Some(3)))
+            .await
+            .unwrap();
+        assert_eq!(provider.suggestion_calls.load(Ordering::SeqCst), 2);
+
+        let stats = coordinator.status().await.suggestion_cache;
+        assert_eq!((stats.entries, stats.hits, stats.misses), (2, 1, 2));
+
+        coordinator.clear_suggestion_cache();
+        coordinator
+            .get_suggestions(&query("ha", Some(2)))
+            .await
+            .unwrap();
+        assert_eq!(provider.suggestion_calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_suggestion_cache_expiry_and_eviction() {
+        let cache = SuggestionCache::new(2, Duration::from_millis(20));
+        let key = |q: &str| (q.to_string(), 10);
+
+        cache.insert(key("a"), vec![]);
+        cache.insert(key("b"), vec![]);
+        assert!(cache.get(&key("a")).is_some());
+
+        // "b" is least recently used and makes room for "c"
+        cache.insert(key("c"), vec![]);
+        assert!(cache.get(&key("b")).is_none());
+        assert!(cache.get(&key("a")).is_some());
+        assert!(cache.get(&key("c")).is_some());
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert!(cache.get(&key("a")).is_none());
+        assert_eq!(cache.stats().entries, 1);
+    }
 }
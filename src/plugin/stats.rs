@@ -0,0 +1,192 @@
+// src/plugin/stats.rs - Per-plugin runtime counters updated by the dispatchers
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::{Arc, RwLock};
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::loader::PluginStatus;
+use crate::utils::Time;
+
+/// Snapshot of one plugin's activity since it was registered
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginRuntimeStats {
+    pub status: PluginStatus,
+    /// Event handler invocations, including failed ones
+    pub events_handled: u64,
+    /// API requests handed to the plugin's handlers
+    pub api_requests: u64,
+    /// When a handler last failed or timed out
+    pub last_error: Option<DateTime<Utc>>,
+}
+
+/// Counters for one plugin, cheap to update from concurrent handlers
+#[derive(Debug)]
+pub struct PluginRuntimeCounters {
+    status: AtomicU8,
+    events_handled: AtomicU64,
+    api_requests: AtomicU64,
+    /// Milliseconds since the epoch, 0 when no error was recorded
+    last_error_ms: AtomicU64,
+}
+
+const STATUSES: [PluginStatus; 10] = [
+    PluginStatus::Discovered,
+    PluginStatus::Installing,
+    PluginStatus::Installed,
+    PluginStatus::Loading,
+    PluginStatus::Loaded,
+    PluginStatus::Running,
+    PluginStatus::Stopping,
+    PluginStatus::Stopped,
+    PluginStatus::Uninstalling,
+    PluginStatus::Failed,
+];
+
+fn status_index(status: PluginStatus) -> u8 {
+    STATUSES.iter().position(|s| *s == status).unwrap_or(0) as u8
+}
+
+impl PluginRuntimeCounters {
+    fn new(status: PluginStatus) -> Self {
+        Self {
+            status: AtomicU8::new(status_index(status)),
+            events_handled: AtomicU64::new(0),
+            api_requests: AtomicU64::new(0),
+            last_error_ms: AtomicU64::new(0),
+        }
+    }
+
+    pub fn set_status(&self, status: PluginStatus) {
+        self.status.store(status_index(status), Ordering::Relaxed);
+    }
+
+    pub fn status(&self) -> PluginStatus {
+        STATUSES[usize::from(self.status.load(Ordering::Relaxed))]
+    }
+
+    pub fn record_event(&self) {
+        self.events_handled.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_api_request(&self) {
+        self.api_requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_error(&self) {
+        self.last_error_ms
+            .store(Time::now_millis().max(1), Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self) -> PluginRuntimeStats {
+        let last_error_ms = self.last_error_ms.load(Ordering::Relaxed);
+        PluginRuntimeStats {
+            status: self.status(),
+            events_handled: self.events_handled.load(Ordering::Relaxed),
+            api_requests: self.api_requests.load(Ordering::Relaxed),
+            last_error: match last_error_ms {
+                0 => None,
+                ms => Utc.timestamp_millis_opt(ms as i64).single(),
+            },
+        }
+    }
+}
+
+/// Runtime counters of every plugin a manager knows about.
+///
+/// Clones share the counters, so dispatchers can hold their own handle.
+#[derive(Debug, Clone, Default)]
+pub struct PluginRuntimeTracker {
+    plugins: Arc<RwLock<HashMap<String, Arc<PluginRuntimeCounters>>>>,
+}
+
+impl PluginRuntimeTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The counters for `plugin_id`, created as [`PluginStatus::Loaded`] if missing
+    pub fn counters(&self, plugin_id: &str) -> Arc<PluginRuntimeCounters> {
+        if let Some(counters) = self
+            .plugins
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(plugin_id)
+        {
+            return Arc::clone(counters);
+        }
+
+        let mut plugins = self.plugins.write().unwrap_or_else(|e| e.into_inner());
+        Arc::clone(
+            plugins
+                .entry(plugin_id.to_string())
+                .or_insert_with(|| Arc::new(PluginRuntimeCounters::new(PluginStatus::Loaded))),
+        )
+    }
+
+    pub fn set_status(&self, plugin_id: &str, status: PluginStatus) {
+        self.counters(plugin_id).set_status(status);
+    }
+
+    /// Forget a plugin's counters
+    pub fn remove(&self, plugin_id: &str) {
+        self.plugins
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(plugin_id);
+    }
+
+    pub fn snapshot(&self) -> HashMap<String, PluginRuntimeStats> {
+        self.plugins
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(id, counters)| (id.clone(), counters.snapshot()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_counters() {
+        let tracker = PluginRuntimeTracker::new();
+        let counters = tracker.counters("inventory");
+        assert_eq!(
+            counters.snapshot(),
+            PluginRuntimeStats {
+                status: PluginStatus::Loaded,
+                events_handled: 0,
+                api_requests: 0,
+                last_error: None,
+            }
+        );
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                let counters = tracker.counters("inventory");
+                scope.spawn(move || {
+                    for _ in 0..100 {
+                        counters.record_event();
+                        counters.record_api_request();
+                    }
+                });
+            }
+        });
+        tracker.counters("inventory").record_error();
+        tracker.set_status("inventory", PluginStatus::Running);
+
+        let stats = &tracker.snapshot()["inventory"];
+        assert_eq!(stats.status, PluginStatus::Running);
+        assert_eq!(stats.events_handled, 400);
+        assert_eq!(stats.api_requests, 400);
+        assert!(stats.last_error.is_some());
+
+        tracker.remove("inventory");
+        assert!(tracker.snapshot().is_empty());
+    }
+}
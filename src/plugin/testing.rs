@@ -0,0 +1,526 @@
+// src/plugin/testing.rs - Test harness for plugin authors
+
+//! Helpers for exercising a plugin against in-memory core services.
+//!
+//! Enable the `testing` feature to use this module from a plugin crate's tests.
+//! The harness wires the same services the core hands to plugins (config,
+//! event bus, sandboxed file system) without starting the full application.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, RwLock};
+
+use super::{
+    ApiRequest, ApiResponse, DeclaredPermissions, EventPayloadGuard, EventPropagation, HttpMethod,
+    Plugin, PluginApiClient, PluginConfig, PluginContext, PluginFileSystem, ReportScheduler,
+    PLUGIN_SETTINGS_NAMESPACE,
+};
+use crate::auth::User;
+use crate::config::tiered::ConfigChangeEvent;
+use crate::config::{ConfigurationTier, MemoryConfigStore, TieredConfigManager};
+use crate::error::{Error, Result};
+use crate::event::{Event, EventBusConfig, EventBusManager, EventRecord};
+use crate::manager::Manager;
+use crate::platform::MockFileSystem;
+use crate::ui::UIComponentRegistry;
+
+/// Runs a single plugin against in-memory core services
+pub struct TestHarness {
+    plugin: Box<dyn Plugin>,
+    context: PluginContext,
+    event_bus: Arc<EventBusManager>,
+    config: Arc<RwLock<TieredConfigManager>>,
+    config_changes: broadcast::Receiver<ConfigChangeEvent>,
+    file_system: Arc<MockFileSystem>,
+    payload_guard: EventPayloadGuard,
+    user: Option<User>,
+}
+
+impl std::fmt::Debug for TestHarness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TestHarness")
+            .field("plugin_id", &self.context.plugin_id)
+            .finish()
+    }
+}
+
+impl TestHarness {
+    /// Set up core services and initialize the plugin under test
+    pub async fn new(plugin: Box<dyn Plugin>) -> Result<Self> {
+        Self::with_config(plugin, serde_json::json!({})).await
+    }
+
+    /// Like [`TestHarness::new`], seeding the plugin's settings with `plugin_config`
+    pub async fn with_config(
+        mut plugin: Box<dyn Plugin>,
+        plugin_config: serde_json::Value,
+    ) -> Result<Self> {
+        let plugin_id = plugin.info().id;
+
+        let mut event_bus = EventBusManager::new(EventBusConfig {
+            worker_count: 1,
+            ..EventBusConfig::default()
+        });
+        event_bus.initialize().await?;
+        let event_bus = Arc::new(event_bus);
+
+        let mut config = TieredConfigManager::new();
+        for tier in [ConfigurationTier::System, ConfigurationTier::User] {
+            config.add_store(tier, Box::new(MemoryConfigStore::new(tier)));
+        }
+        // Seed every nested key, as the store looks keys up verbatim
+        let mut seeds = vec![(
+            format!("{}.{}", PLUGIN_SETTINGS_NAMESPACE, plugin_id),
+            plugin_config.clone(),
+        )];
+        while let Some((key, value)) = seeds.pop() {
+            if let serde_json::Value::Object(fields) = &value {
+                seeds.extend(
+                    fields
+                        .iter()
+                        .map(|(field, value)| (format!("{}.{}", key, field), value.clone())),
+                );
+            }
+            config.set(&key, value, ConfigurationTier::System).await?;
+        }
+        let config_changes = config.subscribe_to_changes();
+        let config = Arc::new(RwLock::new(config));
+
+        let file_system = Arc::new(MockFileSystem::new());
+        let declared = DeclaredPermissions::new(plugin.required_permissions());
+
+        let context = PluginContext {
+            plugin_id: plugin_id.clone(),
+            config: PluginConfig {
+                plugin_id: plugin_id.clone(),
+                version: plugin.info().version,
+                config_schema: serde_json::json!({}),
+                default_values: plugin_config,
+                user_overrides: serde_json::json!({}),
+                validation_rules: Vec::new(),
+            },
+            api_client: PluginApiClient::new(plugin_id.clone())
+                .with_config(Arc::clone(&config))
+                .with_declared_permissions(declared.clone()),
+            event_bus: Arc::clone(&event_bus),
+            database: None,
+            file_system: PluginFileSystem::new(plugin_id.clone(), file_system.clone())
+                .with_declared_permissions(declared.clone()),
+            components: UIComponentRegistry::new().scoped(plugin_id.clone()),
+            reports: ReportScheduler::new().scoped(plugin_id.clone()),
+            declared_permissions: declared,
+        };
+
+        plugin.initialize(context.clone()).await?;
+
+        Ok(Self {
+            plugin,
+            context,
+            event_bus,
+            config,
+            config_changes,
+            file_system,
+            payload_guard: EventPayloadGuard::default(),
+            user: None,
+        })
+    }
+
+    /// Attach a user to subsequent API calls
+    pub fn with_user(mut self, user: User) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// Screen dispatched events with `payload_guard`, as the plugin manager does
+    pub fn with_payload_guard(mut self, payload_guard: EventPayloadGuard) -> Self {
+        self.payload_guard = payload_guard;
+        self
+    }
+
+    /// The plugin under test
+    pub fn plugin(&self) -> &dyn Plugin {
+        self.plugin.as_ref()
+    }
+
+    /// The context the plugin was initialized with
+    pub fn context(&self) -> &PluginContext {
+        &self.context
+    }
+
+    /// The event bus shared with the plugin
+    pub fn event_bus(&self) -> &Arc<EventBusManager> {
+        &self.event_bus
+    }
+
+    /// The in-memory file system backing the plugin sandbox
+    pub fn file_system(&self) -> &Arc<MockFileSystem> {
+        &self.file_system
+    }
+
+    /// The guard screening dispatched events
+    pub fn payload_guard(&self) -> &EventPayloadGuard {
+        &self.payload_guard
+    }
+
+    /// Deliver an event to the handlers the plugin registered for its type,
+    /// highest priority first, returning how many ran.
+    ///
+    /// Events the payload guard rejects reach no handler, and a handler
+    /// returning [`EventPropagation::Stop`] is the last to run.
+    pub async fn dispatch_event(&self, event: &dyn Event) -> Result<usize> {
+        if !self.payload_guard.admit(event) {
+            return Ok(0);
+        }
+
+        let mut handlers: Vec<_> = self
+            .plugin
+            .event_handlers()
+            .into_iter()
+            .filter(|h| h.handles(event.event_type()))
+            .collect();
+        handlers.sort_by_key(|h| std::cmp::Reverse(h.priority));
+
+        let mut ran = 0;
+        for handler in &handlers {
+            ran += 1;
+            let propagation = self
+                .plugin
+                .handle_event_propagation(&handler.handler_id, event)
+                .await?;
+            if propagation == EventPropagation::Stop {
+                break;
+            }
+        }
+
+        Ok(ran)
+    }
+
+    /// Call the API route registered for `method` and `path`
+    pub async fn call_api(
+        &self,
+        method: HttpMethod,
+        path: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<ApiResponse> {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let route = self
+            .plugin
+            .api_routes()
+            .into_iter()
+            .find(|r| r.path == path && r.method == method)
+            .ok_or_else(|| {
+                Error::plugin(
+                    &self.context.plugin_id,
+                    format!("No API route registered for {} {}", method, path),
+                )
+            })?;
+
+        let query_params = query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+
+        let request = ApiRequest::new(&method.to_string(), path)
+            .with_query_params(query_params)
+            .with_body(body)
+            .with_user(self.user.clone());
+
+        self.plugin
+            .handle_api_request(&route.handler_id, request)
+            .await
+    }
+
+    /// All events published on the bus so far, oldest first
+    pub async fn emitted_events(&self) -> Vec<EventRecord> {
+        self.event_bus.recent_events(usize::MAX).await
+    }
+
+    /// Panic unless an event of `event_type` was published, returning the latest one
+    pub async fn assert_event_emitted(&self, event_type: &str) -> EventRecord {
+        let events = self.emitted_events().await;
+        match events.iter().rev().find(|e| e.event_type == event_type) {
+            Some(record) => record.clone(),
+            None => panic!(
+                "expected event '{}' to be emitted, saw: {:?}",
+                event_type,
+                events.iter().map(|e| &e.event_type).collect::<Vec<_>>()
+            ),
+        }
+    }
+
+    /// Full config key of the plugin's setting `key`
+    fn settings_key(&self, key: &str) -> String {
+        format!(
+            "{}.{}.{}",
+            PLUGIN_SETTINGS_NAMESPACE, self.context.plugin_id, key
+        )
+    }
+
+    /// Set a key under the plugin's config namespace, as a user would
+    pub async fn set_config<T: Serialize>(&self, key: &str, value: T) -> Result<()> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| Error::config(format!("Invalid value for '{}': {}", key, e)))?;
+        self.config
+            .write()
+            .await
+            .set(&self.settings_key(key), value, ConfigurationTier::User)
+            .await
+    }
+
+    /// Read a key from the plugin's config namespace
+    pub async fn config<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Result<T> {
+        let full_key = self.settings_key(key);
+        self.config
+            .read()
+            .await
+            .get(&full_key)
+            .await?
+            .ok_or_else(|| Error::config(format!("Configuration key '{}' not found", full_key)))
+    }
+
+    /// Drain the config changes recorded since the last call
+    pub fn config_changes(&mut self) -> Vec<ConfigChangeEvent> {
+        let mut changes = Vec::new();
+        loop {
+            match self.config_changes.try_recv() {
+                Ok(change) => changes.push(change),
+                Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Err(_) => break,
+            }
+        }
+        changes
+    }
+
+    /// Panic unless the plugin's `key` changed, returning the latest change
+    pub fn assert_config_changed(&mut self, key: &str) -> ConfigChangeEvent {
+        let full_key = self.settings_key(key);
+        let changes = self.config_changes();
+        match changes.iter().rev().find(|c| c.key == full_key) {
+            Some(change) => change.clone(),
+            None => panic!(
+                "expected config key '{}' to change, saw: {:?}",
+                full_key,
+                changes.iter().map(|c| &c.key).collect::<Vec<_>>()
+            ),
+        }
+    }
+
+    /// Shut the plugin down
+    pub async fn shutdown(mut self) -> Result<()> {
+        self.plugin.shutdown().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SettingsSchema;
+    use crate::plugin::{
+        ApiDocumentation, ApiRoute, EventHandler, MenuItem, PluginDependency, PluginInfo,
+        UIComponent,
+    };
+    use crate::types::Metadata;
+    use async_trait::async_trait;
+    use dioxus::prelude::VNode;
+    use std::any::Any;
+
+    /// Event emitted by the greeter when it says hello
+    #[derive(Debug)]
+    struct GreetedEvent {
+        metadata: Metadata,
+    }
+
+    impl Event for GreetedEvent {
+        fn event_type(&self) -> &'static str {
+            "greeter.greeted"
+        }
+
+        fn source(&self) -> &str {
+            "greeter"
+        }
+
+        fn metadata(&self) -> &Metadata {
+            &self.metadata
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    /// Trivial plugin: greets over the API and counts greetings in config
+    #[derive(Debug, Default)]
+    struct GreeterPlugin {
+        context: Option<PluginContext>,
+    }
+
+    #[async_trait]
+    impl Plugin for GreeterPlugin {
+        fn info(&self) -> PluginInfo {
+            PluginInfo {
+                id: "greeter".to_string(),
+                name: "Greeter".to_string(),
+                version: "0.1.0".to_string(),
+                description: "Says hello".to_string(),
+                author: "Qorzen".to_string(),
+                license: "MIT".to_string(),
+                homepage: None,
+                repository: None,
+                minimum_core_version: "0.1.0".to_string(),
+                supported_platforms: vec![crate::plugin::Platform::All],
+            }
+        }
+
+        fn required_dependencies(&self) -> Vec<PluginDependency> {
+            Vec::new()
+        }
+
+        fn required_permissions(&self) -> Vec<crate::auth::Permission> {
+            Vec::new()
+        }
+
+        async fn initialize(&mut self, context: PluginContext) -> Result<()> {
+            self.context = Some(context);
+            Ok(())
+        }
+
+        async fn shutdown(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        fn ui_components(&self) -> Vec<UIComponent> {
+            Vec::new()
+        }
+
+        fn menu_items(&self) -> Vec<MenuItem> {
+            Vec::new()
+        }
+
+        fn settings_schema(&self) -> Option<SettingsSchema> {
+            None
+        }
+
+        fn api_routes(&self) -> Vec<ApiRoute> {
+            vec![ApiRoute {
+                path: "/greeter/hello".to_string(),
+                method: HttpMethod::Post,
+                handler_id: "hello".to_string(),
+                required_permissions: Vec::new(),
+                rate_limit: None,
+                timeout_secs: None,
+                max_request_size: None,
+                documentation: ApiDocumentation {
+                    summary: "Say hello".to_string(),
+                    description: "Greets the caller".to_string(),
+                    parameters: Vec::new(),
+                    responses: Vec::new(),
+                    examples: Vec::new(),
+                },
+            }]
+        }
+
+        fn event_handlers(&self) -> Vec<EventHandler> {
+            vec![EventHandler {
+                event_type: "greeter.greeted".to_string(),
+                handler_id: "on_greeted".to_string(),
+                priority: 0,
+            }]
+        }
+
+        fn render_component(
+            &self,
+            _component_id: &str,
+            _props: serde_json::Value,
+        ) -> Result<VNode> {
+            Err(Error::plugin("greeter", "No components"))
+        }
+
+        async fn handle_api_request(
+            &self,
+            _route_id: &str,
+            _request: ApiRequest,
+        ) -> Result<ApiResponse> {
+            let context = self.context.as_ref().expect("initialized");
+            context
+                .event_bus
+                .publish(GreetedEvent {
+                    metadata: Metadata::new(),
+                })
+                .await?;
+
+            Ok(ApiResponse {
+                status_code: 200,
+                description: "hello".to_string(),
+                schema: None,
+                cache: None,
+                retry_after_secs: None,
+                headers: Vec::new(),
+            })
+        }
+
+        async fn handle_event(&self, _handler_id: &str, _event: &dyn Event) -> Result<()> {
+            let api = &self.context.as_ref().expect("initialized").api_client;
+            let count = api
+                .get_config("greetings")
+                .await?
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0);
+            api.set_config("greetings", serde_json::json!(count + 1))
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_harness_with_trivial_plugin() {
+        let mut harness = TestHarness::with_config(
+            Box::new(GreeterPlugin::default()),
+            serde_json::json!({ "greetings": 0 }),
+        )
+        .await
+        .unwrap();
+
+        let response = harness
+            .call_api(HttpMethod::Post, "/greeter/hello", None)
+            .await
+            .unwrap();
+        assert_eq!(response.status_code, 200);
+        harness.assert_event_emitted("greeter.greeted").await;
+
+        let event = GreetedEvent {
+            metadata: Metadata::new(),
+        };
+        assert_eq!(harness.dispatch_event(&event).await.unwrap(), 1);
+
+        let change = harness.assert_config_changed("greetings");
+        assert_eq!(change.value, Some(serde_json::json!(1)));
+        assert_eq!(harness.config::<u64>("greetings").await.unwrap(), 1);
+
+        assert!(harness
+            .call_api(HttpMethod::Get, "/greeter/hello", None)
+            .await
+            .is_err());
+
+        harness.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_oversized_event_is_dropped_before_handler() {
+        let harness = TestHarness::with_config(
+            Box::new(GreeterPlugin::default()),
+            serde_json::json!({ "greetings": 0 }),
+        )
+        .await
+        .unwrap()
+        .with_payload_guard(EventPayloadGuard::new(1024));
+
+        let mut metadata = Metadata::new();
+        metadata.insert("blob".to_string(), serde_json::json!("x".repeat(4096)));
+        let event = GreetedEvent { metadata };
+
+        assert_eq!(harness.dispatch_event(&event).await.unwrap(), 0);
+        assert_eq!(harness.payload_guard().rejected_count(), 1);
+        assert_eq!(harness.config::<u64>("greetings").await.unwrap(), 0);
+    }
+}
@@ -15,8 +15,8 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use serde::{Deserialize, Serialize};
-use tokio::sync::{broadcast, RwLock, Semaphore};
-use tokio::time::{timeout, Instant};
+use tokio::sync::{broadcast, watch, RwLock, Semaphore};
+use tokio::time::{timeout, Instant, MissedTickBehavior};
 use uuid::Uuid;
 
 use crate::config::TaskConfig;
@@ -25,6 +25,9 @@ use crate::event::{Event, EventBusManager};
 use crate::manager::{ManagedState, Manager, ManagerStatus};
 use crate::types::{CorrelationId, Metadata};
 
+/// Timeout for tasks built outside a manager that do not set their own
+const DEFAULT_TASK_TIMEOUT: Duration = Duration::from_secs(300);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TaskStatus {
     Pending,
@@ -289,7 +292,8 @@ pub struct TaskDefinition {
     pub priority: TaskPriority,
     pub plugin_id: Option<String>,
     pub dependencies: Vec<Uuid>,
-    pub timeout: Duration,
+    /// `None` uses the manager's `default_timeout_ms`
+    pub timeout: Option<Duration>,
     pub max_retries: u32,
     pub cancellable: bool,
     pub metadata: Metadata,
@@ -355,7 +359,7 @@ impl TaskInfo {
             result: None,
             retry_count: 0,
             max_retries: definition.max_retries,
-            timeout: definition.timeout,
+            timeout: definition.timeout.unwrap_or(DEFAULT_TASK_TIMEOUT),
             cancellable: definition.cancellable,
             correlation_id: definition.correlation_id,
             metadata: definition.metadata.clone(),
@@ -392,6 +396,102 @@ struct TaskExecution {
     definition: TaskDefinition,
     cancellation_token: CancellationToken,
     progress_sender: broadcast::Sender<TaskProgress>,
+    progress_watch: watch::Sender<f32>,
+    finished: watch::Sender<Option<TaskInfo>>,
+}
+
+/// Handle to a task submitted with [`TaskManager::submit`]
+#[derive(Debug, Clone)]
+pub struct TaskHandle {
+    task_id: Uuid,
+    cancellable: bool,
+    cancellation_token: CancellationToken,
+    progress: watch::Receiver<f32>,
+    finished: watch::Receiver<Option<TaskInfo>>,
+}
+
+impl TaskHandle {
+    pub fn id(&self) -> Uuid {
+        self.task_id
+    }
+
+    /// Progress as a fraction from 0.0 to 1.0, updated whenever the task reports
+    pub fn progress(&self) -> watch::Receiver<f32> {
+        self.progress.clone()
+    }
+
+    /// Stop the task, returning `false` if it is not cancellable.
+    ///
+    /// A pending task never starts; a running task is dropped at its next await point.
+    pub fn cancel(&self) -> bool {
+        if !self.cancellable {
+            return false;
+        }
+        self.cancellation_token.cancel();
+        true
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished.borrow().is_some()
+    }
+
+    /// Wait for the task to finish and return the value it produced.
+    ///
+    /// Failed, timed out and cancelled tasks all return an error.
+    pub async fn await_result(&self) -> Result<serde_json::Value> {
+        let info = wait_for_finish(self.task_id, self.finished.clone()).await?;
+        let result = info.result.unwrap_or_else(|| TaskResult {
+            success: false,
+            data: None,
+            error: None,
+            duration: Duration::ZERO,
+            resource_usage: ResourceUsage::default(),
+            metadata: HashMap::new(),
+        });
+
+        match info.status {
+            TaskStatus::Completed => Ok(result.data.unwrap_or(serde_json::Value::Null)),
+            TaskStatus::TimedOut => Err(Error::timeout(format!(
+                "Task {} timed out after {:?}",
+                info.name, info.timeout
+            ))),
+            TaskStatus::Cancelled => Err(Error::task(
+                Some(info.id),
+                Some(info.name),
+                "Task was cancelled",
+            )),
+            _ => Err(Error::task(
+                Some(info.id),
+                Some(info.name),
+                result.error.unwrap_or_else(|| "Task failed".to_string()),
+            )),
+        }
+    }
+}
+
+async fn wait_for_finish(
+    task_id: Uuid,
+    mut finished: watch::Receiver<Option<TaskInfo>>,
+) -> Result<TaskInfo> {
+    let info = finished
+        .wait_for(Option::is_some)
+        .await
+        .ok()
+        .and_then(|info| (*info).clone());
+    info.ok_or_else(|| Error::task(Some(task_id), None, "Task completion wait failed"))
+}
+
+/// State shared by the task workers
+#[derive(Debug, Clone)]
+struct TaskWorkerShared {
+    tasks: Arc<DashMap<Uuid, TaskExecution>>,
+    completed: Arc<DashMap<Uuid, TaskInfo>>,
+    stats: Arc<RwLock<TaskManagerStats>>,
+    semaphore: Arc<Semaphore>,
+    event_bus: Option<Arc<EventBusManager>>,
+    shutdown_flag: Arc<RwLock<bool>>,
+    keep_completed: bool,
+    progress_interval: Duration,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -498,6 +598,7 @@ impl Event for TaskProgressEvent {
 struct TaskProgressReporter {
     task_id: Uuid,
     progress_sender: broadcast::Sender<TaskProgress>,
+    progress_watch: watch::Sender<f32>,
 }
 
 impl ProgressReporter for TaskProgressReporter {
@@ -508,6 +609,8 @@ impl ProgressReporter for TaskProgressReporter {
             progress.percent,
             progress.message
         );
+        self.progress_watch
+            .send_replace(f32::from(progress.percent.min(100)) / 100.0);
         let _ = self.progress_sender.send(progress);
     }
 }
@@ -515,9 +618,11 @@ impl ProgressReporter for TaskProgressReporter {
 #[derive(Debug)]
 pub struct TaskManager {
     state: ManagedState,
-    #[allow(dead_code)]
     config: TaskConfig,
+    /// Pending and running tasks
     tasks: Arc<DashMap<Uuid, TaskExecution>>,
+    /// Finished tasks, retained when `keep_completed` is set
+    completed: Arc<DashMap<Uuid, TaskInfo>>,
     stats: Arc<RwLock<TaskManagerStats>>,
     #[allow(dead_code)]
     task_counter: Arc<AtomicU64>,
@@ -531,9 +636,10 @@ impl TaskManager {
     pub fn new(config: TaskConfig) -> Self {
         let max_concurrent = config.max_concurrent;
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "task_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "task_manager"),
             config,
             tasks: Arc::new(DashMap::new()),
+            completed: Arc::new(DashMap::new()),
             stats: Arc::new(RwLock::new(TaskManagerStats {
                 total_created: 0,
                 total_completed: 0,
@@ -559,45 +665,70 @@ impl TaskManager {
     }
 
     pub async fn submit_task(&self, definition: TaskDefinition) -> Result<Uuid> {
+        self.submit(definition).await.map(|handle| handle.id())
+    }
+
+    /// Submit a task, returning a handle to follow its progress, cancel it or
+    /// wait for its result.
+    ///
+    /// Tasks without their own timeout get the configured `default_timeout_ms`.
+    pub async fn submit(&self, definition: TaskDefinition) -> Result<TaskHandle> {
         let task_id = definition.id;
-        let task_info = TaskInfo::from_definition(&definition);
+        let mut task_info = TaskInfo::from_definition(&definition);
+        task_info.timeout = definition
+            .timeout
+            .unwrap_or(Duration::from_millis(self.config.default_timeout_ms));
 
         tracing::info!("Submitting task {} ({})", task_info.name, task_id);
 
         // Check dependencies
         for dep_id in &task_info.dependencies {
-            if let Some(dep_task) = self.tasks.get(dep_id) {
-                if !dep_task.info.is_terminal() {
+            match self.get_task_info(*dep_id).await {
+                Some(dep_task) if !dep_task.is_terminal() => {
                     return Err(Error::task(
                         Some(task_id),
                         Some(format!("dep_{}", dep_id)),
                         format!("Dependency task {} is not completed", dep_id),
                     ));
                 }
-                if dep_task.info.status != TaskStatus::Completed {
+                Some(dep_task) if dep_task.status != TaskStatus::Completed => {
                     return Err(Error::task(
                         Some(task_id),
                         Some(format!("dep_{}", dep_id)),
                         format!("Dependency task {} failed", dep_id),
                     ));
                 }
-            } else {
-                return Err(Error::task(
-                    Some(task_id),
-                    Some(format!("dep_{}", dep_id)),
-                    format!("Dependency task {} not found", dep_id),
-                ));
+                Some(_) => {}
+                None => {
+                    return Err(Error::task(
+                        Some(task_id),
+                        Some(format!("dep_{}", dep_id)),
+                        format!("Dependency task {} not found", dep_id),
+                    ));
+                }
             }
         }
 
         let (progress_sender, _) = broadcast::channel(100);
+        let (progress_watch, progress) = watch::channel(0.0);
+        let (finished, finished_receiver) = watch::channel(None);
         let cancellation_token = CancellationToken::new();
 
+        let handle = TaskHandle {
+            task_id,
+            cancellable: task_info.cancellable,
+            cancellation_token: cancellation_token.clone(),
+            progress,
+            finished: finished_receiver,
+        };
+
         let execution = TaskExecution {
             info: task_info.clone(),
             definition,
             cancellation_token,
             progress_sender,
+            progress_watch,
+            finished,
         };
 
         // Add to tasks collection
@@ -636,51 +767,64 @@ impl TaskManager {
         }
 
         tracing::info!("Task {} submitted successfully", task_id);
-        Ok(task_id)
+        Ok(handle)
     }
 
     pub async fn cancel_task(&self, task_id: Uuid) -> Result<bool> {
-        if let Some(mut task) = self.tasks.get_mut(&task_id) {
-            if !task.info.cancellable {
-                return Err(Error::task(Some(task_id), None, "Task is not cancellable"));
-            }
-
-            if task.info.is_terminal() {
+        let Some(mut task) = self.tasks.get_mut(&task_id) else {
+            if self.completed.contains_key(&task_id) {
                 return Ok(false);
             }
+            return Err(Error::task(Some(task_id), None, "Task not found"));
+        };
 
-            // Cancel the task
-            task.cancellation_token.cancel();
-            task.info.status = TaskStatus::Cancelled;
-            task.info.completed_at = Some(Time::now());
-
-            // Update statistics
-            {
-                let mut stats = self.stats.write().await;
-                stats.total_cancelled += 1;
-                if task.info.status == TaskStatus::Running {
-                    stats.currently_running = stats.currently_running.saturating_sub(1);
-                } else {
-                    stats.currently_pending = stats.currently_pending.saturating_sub(1);
-                }
-            }
+        if !task.info.cancellable {
+            return Err(Error::task(Some(task_id), None, "Task is not cancellable"));
+        }
 
-            // Publish status change event
-            self.publish_status_change_event(
-                &task.info,
-                TaskStatus::Running,
-                TaskStatus::Cancelled,
-            )
-            .await;
+        if task.info.is_terminal() {
+            return Ok(false);
+        }
 
-            Ok(true)
-        } else {
-            Err(Error::task(Some(task_id), None, "Task not found"))
+        // Cancel the task
+        task.cancellation_token.cancel();
+
+        // A running task is stopped and recorded as cancelled by its worker
+        if task.info.status == TaskStatus::Running {
+            return Ok(true);
+        }
+
+        task.info.status = TaskStatus::Cancelled;
+        task.info.completed_at = Some(Time::now());
+        let task_info = task.info.clone();
+        drop(task);
+
+        // Update statistics
+        {
+            let mut stats = self.stats.write().await;
+            stats.total_cancelled += 1;
+            stats.currently_pending = stats.currently_pending.saturating_sub(1);
         }
+
+        Self::retire_task(
+            &self.tasks,
+            &self.completed,
+            self.config.keep_completed,
+            task_id,
+        );
+
+        // Publish status change event
+        self.publish_status_change_event(&task_info, TaskStatus::Pending, TaskStatus::Cancelled)
+            .await;
+
+        Ok(true)
     }
 
     pub async fn get_task_info(&self, task_id: Uuid) -> Option<TaskInfo> {
-        self.tasks.get(&task_id).map(|task| task.info.clone())
+        self.tasks
+            .get(&task_id)
+            .map(|task| task.info.clone())
+            .or_else(|| self.completed.get(&task_id).map(|info| info.clone()))
     }
 
     pub async fn list_tasks(
@@ -692,22 +836,22 @@ impl TaskManager {
         let tasks: Vec<TaskInfo> = self
             .tasks
             .iter()
-            .filter_map(|entry| {
-                let task_info = &entry.value().info;
-
+            .map(|entry| entry.value().info.clone())
+            .chain(self.completed.iter().map(|entry| entry.value().clone()))
+            .filter(|task_info| {
                 if let Some(status) = status_filter {
                     if task_info.status != status {
-                        return None;
+                        return false;
                     }
                 }
 
                 if let Some(category) = &category_filter {
                     if task_info.category != *category {
-                        return None;
+                        return false;
                     }
                 }
 
-                Some(task_info.clone())
+                true
             })
             .collect();
 
@@ -729,101 +873,36 @@ impl TaskManager {
             timeout_duration
         );
 
-        if let Some(task) = self.tasks.get(&task_id) {
-            if task.info.is_terminal() {
-                tracing::info!(
-                    "Task {} already completed with status: {:?}",
-                    task_id,
-                    task.info.status
-                );
-                return Ok(task.info.clone());
-            }
-
-            let progress_receiver = task.progress_sender.subscribe();
-            drop(task); // Release the DashMap reference
-
-            let wait_future = self.wait_for_completion(task_id, progress_receiver);
-
-            if let Some(timeout_duration) = timeout_duration {
-                match timeout(timeout_duration, wait_future).await {
-                    Ok(result) => result,
-                    Err(_) => {
-                        tracing::error!(
-                            "Task {} wait timed out after {:?}",
-                            task_id,
-                            timeout_duration
-                        );
-                        Err(Error::timeout("Task wait timeout"))
-                    }
-                }
-            } else {
-                wait_future.await
-            }
+        let finished = if let Some(task) = self.tasks.get(&task_id) {
+            task.finished.subscribe()
+        } else if let Some(task_info) = self.completed.get(&task_id) {
+            tracing::info!(
+                "Task {} already completed with status: {:?}",
+                task_id,
+                task_info.status
+            );
+            return Ok(task_info.clone());
         } else {
-            Err(Error::task(Some(task_id), None, "Task not found"))
-        }
-    }
+            return Err(Error::task(Some(task_id), None, "Task not found"));
+        };
 
-    async fn wait_for_completion(
-        &self,
-        task_id: Uuid,
-        mut progress_receiver: broadcast::Receiver<TaskProgress>,
-    ) -> Result<TaskInfo> {
-        loop {
-            // Check if task is completed
-            if let Some(updated_task) = self.tasks.get(&task_id) {
-                if updated_task.info.is_terminal() {
-                    tracing::info!(
-                        "Task {} completed with status: {:?}",
-                        task_id,
-                        updated_task.info.status
-                    );
-                    return Ok(updated_task.info.clone());
-                }
-            }
+        let wait_future = wait_for_finish(task_id, finished);
 
-            // Wait for progress update or timeout
-            match tokio::time::timeout(Duration::from_millis(500), progress_receiver.recv()).await {
-                Ok(Ok(progress)) => {
-                    tracing::debug!(
-                        "Task {} progress: {}% - {}",
+        if let Some(timeout_duration) = timeout_duration {
+            match timeout(timeout_duration, wait_future).await {
+                Ok(result) => result,
+                Err(_) => {
+                    tracing::error!(
+                        "Task {} wait timed out after {:?}",
                         task_id,
-                        progress.percent,
-                        progress.message
+                        timeout_duration
                     );
-                    continue;
-                }
-                Ok(Err(_)) => {
-                    // Channel closed, check final status
-                    if let Some(updated_task) = self.tasks.get(&task_id) {
-                        if updated_task.info.is_terminal() {
-                            return Ok(updated_task.info.clone());
-                        }
-                    }
-                    tracing::warn!("Progress channel closed for task {}", task_id);
-                    break;
-                }
-                Err(_) => {
-                    // Timeout on progress, check task status anyway
-                    if let Some(updated_task) = self.tasks.get(&task_id) {
-                        tracing::debug!(
-                            "Task {} current status: {:?}",
-                            task_id,
-                            updated_task.info.status
-                        );
-                        if updated_task.info.is_terminal() {
-                            return Ok(updated_task.info.clone());
-                        }
-                    }
+                    Err(Error::timeout("Task wait timeout"))
                 }
             }
+        } else {
+            wait_future.await
         }
-
-        Err(Error::task(
-            Some(task_id),
-            None,
-            "Task completion wait failed",
-        ))
     }
 
     pub async fn get_stats(&self) -> TaskManagerStats {
@@ -835,15 +914,13 @@ impl TaskManager {
         let mut removed_count = 0u64;
 
         let task_ids_to_remove: Vec<Uuid> = self
-            .tasks
+            .completed
             .iter()
             .filter_map(|entry| {
-                let task_info = &entry.value().info;
-                if task_info.is_terminal() {
-                    if let Some(completed_at) = task_info.completed_at {
-                        if completed_at < cutoff_time {
-                            return Some(task_info.id);
-                        }
+                let task_info = entry.value();
+                if let Some(completed_at) = task_info.completed_at {
+                    if completed_at < cutoff_time {
+                        return Some(task_info.id);
                     }
                 }
                 None
@@ -851,7 +928,7 @@ impl TaskManager {
             .collect();
 
         for task_id in task_ids_to_remove {
-            if self.tasks.remove(&task_id).is_some() {
+            if self.completed.remove(&task_id).is_some() {
                 removed_count += 1;
             }
         }
@@ -859,20 +936,95 @@ impl TaskManager {
         removed_count
     }
 
+    /// Move a finished task out of the active set and wake anyone awaiting it
+    fn retire_task(
+        tasks: &DashMap<Uuid, TaskExecution>,
+        completed: &DashMap<Uuid, TaskInfo>,
+        keep_completed: bool,
+        task_id: Uuid,
+    ) {
+        // Insert before removing so lookups never miss the task in between
+        if keep_completed {
+            if let Some(task) = tasks.get(&task_id) {
+                completed.insert(task_id, task.info.clone());
+            }
+        }
+        if let Some((_, task)) = tasks.remove(&task_id) {
+            task.finished.send_replace(Some(task.info));
+        }
+    }
+
+    /// Forward a running task's latest progress to its info and the event bus,
+    /// at most once per `interval`
+    fn spawn_progress_forwarder(
+        task_id: Uuid,
+        tasks: Arc<DashMap<Uuid, TaskExecution>>,
+        mut progress_receiver: broadcast::Receiver<TaskProgress>,
+        event_bus: Option<Arc<EventBusManager>>,
+        interval: Duration,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            let mut latest = None;
+
+            loop {
+                tokio::select! {
+                    received = progress_receiver.recv() => match received {
+                        Ok(progress) => latest = Some(progress),
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = ticker.tick() => {
+                        let Some(progress) = latest.take() else {
+                            continue;
+                        };
+                        let Some((name, metadata)) = tasks.get_mut(&task_id).map(|mut task| {
+                            task.info.progress = progress.clone();
+                            (task.info.name.clone(), task.info.metadata.clone())
+                        }) else {
+                            break;
+                        };
+
+                        if let Some(event_bus) = &event_bus {
+                            let event = TaskProgressEvent {
+                                task_id,
+                                name,
+                                progress,
+                                timestamp: Time::now(),
+                                source: "task_manager".to_string(),
+                                metadata,
+                            };
+                            let _ = event_bus.publish(event).await;
+                        }
+                    }
+                }
+            }
+        })
+    }
+
     async fn start_workers(&mut self) -> Result<()> {
         let worker_count = 4;
         tracing::info!("Starting {} task workers", worker_count);
 
+        let shared = TaskWorkerShared {
+            tasks: Arc::clone(&self.tasks),
+            completed: Arc::clone(&self.completed),
+            stats: Arc::clone(&self.stats),
+            semaphore: Arc::clone(&self.concurrency_semaphore),
+            event_bus: self.event_bus.clone(),
+            shutdown_flag: Arc::clone(&self.shutdown_flag),
+            keep_completed: self.config.keep_completed,
+            progress_interval: Duration::from_millis(
+                self.config.progress_update_interval_ms.max(1),
+            ),
+        };
+
         for worker_id in 0..worker_count {
-            let tasks = Arc::clone(&self.tasks);
-            let stats = Arc::clone(&self.stats);
-            let semaphore = Arc::clone(&self.concurrency_semaphore);
-            let event_bus = self.event_bus.clone();
-            let shutdown_flag = Arc::clone(&self.shutdown_flag);
+            let shared = shared.clone();
 
             let handle = tokio::spawn(async move {
-                Self::task_worker(worker_id, tasks, stats, semaphore, event_bus, shutdown_flag)
-                    .await;
+                Self::task_worker(worker_id, shared).await;
             });
 
             self.worker_handles.push(handle);
@@ -882,14 +1034,18 @@ impl TaskManager {
         Ok(())
     }
 
-    async fn task_worker(
-        worker_id: usize,
-        tasks: Arc<DashMap<Uuid, TaskExecution>>,
-        stats: Arc<RwLock<TaskManagerStats>>,
-        semaphore: Arc<Semaphore>,
-        event_bus: Option<Arc<EventBusManager>>,
-        shutdown_flag: Arc<tokio::sync::RwLock<bool>>,
-    ) {
+    async fn task_worker(worker_id: usize, shared: TaskWorkerShared) {
+        let TaskWorkerShared {
+            tasks,
+            completed,
+            stats,
+            semaphore,
+            event_bus,
+            shutdown_flag,
+            keep_completed,
+            progress_interval,
+        } = shared;
+
         tracing::info!("Task worker {} started", worker_id);
 
         loop {
@@ -963,6 +1119,7 @@ impl TaskManager {
                                 progress: Arc::new(TaskProgressReporter {
                                     task_id,
                                     progress_sender: task.progress_sender.clone(),
+                                    progress_watch: task.progress_watch.clone(),
                                 }),
                                 cancellation_token: task.cancellation_token.clone(),
                                 metadata: task.info.metadata.clone(),
@@ -973,15 +1130,33 @@ impl TaskManager {
                                 context,
                                 task.info.timeout,
                                 task.progress_sender.clone(),
+                                task.cancellation_token.clone(),
+                                task.info.name.clone(),
+                                task.info.metadata.clone(),
                             ))
                         } else {
                             None
                         }
                     };
 
-                    if let Some((function, context, task_timeout, progress_sender)) =
-                        task_execution_data
+                    if let Some((
+                        function,
+                        context,
+                        task_timeout,
+                        progress_sender,
+                        cancellation_token,
+                        task_name,
+                        task_metadata,
+                    )) = task_execution_data
                     {
+                        let progress_forwarder = Self::spawn_progress_forwarder(
+                            task_id,
+                            Arc::clone(&tasks),
+                            progress_sender.subscribe(),
+                            event_bus.clone(),
+                            progress_interval,
+                        );
+
                         // Execute the task function
                         let start_time = Instant::now();
                         tracing::info!(
@@ -994,9 +1169,14 @@ impl TaskManager {
                         // Call the function to get the future
                         let future = function(context);
 
-                        // Execute with timeout
-                        let execution_result = timeout(task_timeout, future).await;
+                        // Execute with timeout, dropping the task if it is cancelled
+                        let execution_result = tokio::select! {
+                            biased;
+                            _ = cancellation_token.cancelled() => None,
+                            result = timeout(task_timeout, future) => Some(result),
+                        };
                         let execution_duration = start_time.elapsed();
+                        progress_forwarder.abort();
 
                         tracing::info!(
                             "Task {} execution completed in {:?}",
@@ -1006,7 +1186,7 @@ impl TaskManager {
 
                         // Update task with result
                         let (new_status, result) = match execution_result {
-                            Ok(Ok(data)) => {
+                            Some(Ok(Ok(data))) => {
                                 tracing::info!("Task {} completed successfully", task_id);
                                 let result = TaskResult {
                                     success: true,
@@ -1018,7 +1198,7 @@ impl TaskManager {
                                 };
                                 (TaskStatus::Completed, Some(result))
                             }
-                            Ok(Err(error)) => {
+                            Some(Ok(Err(error))) => {
                                 tracing::error!("Task {} failed: {}", task_id, error);
                                 let result = TaskResult {
                                     success: false,
@@ -1030,7 +1210,7 @@ impl TaskManager {
                                 };
                                 (TaskStatus::Failed, Some(result))
                             }
-                            Err(_) => {
+                            Some(Err(_)) => {
                                 tracing::error!("Task {} timed out", task_id);
                                 let result = TaskResult {
                                     success: false,
@@ -1042,6 +1222,18 @@ impl TaskManager {
                                 };
                                 (TaskStatus::TimedOut, Some(result))
                             }
+                            None => {
+                                tracing::info!("Task {} cancelled", task_id);
+                                let result = TaskResult {
+                                    success: false,
+                                    data: None,
+                                    error: Some("Task cancelled".to_string()),
+                                    duration: execution_duration,
+                                    resource_usage: ResourceUsage::default(),
+                                    metadata: HashMap::new(),
+                                };
+                                (TaskStatus::Cancelled, Some(result))
+                            }
                         };
 
                         // Update task info
@@ -1052,8 +1244,12 @@ impl TaskManager {
                             task.info.result = result;
 
                             // Send final progress update
-                            let final_progress = TaskProgress::new(100, "Task completed");
-                            let _ = progress_sender.send(final_progress);
+                            if new_status == TaskStatus::Completed {
+                                let final_progress = TaskProgress::new(100, "Task completed");
+                                task.info.progress = final_progress.clone();
+                                task.progress_watch.send_replace(1.0);
+                                let _ = progress_sender.send(final_progress);
+                            }
 
                             tracing::info!("Task {} final status: {:?}", task_id, new_status);
                         }
@@ -1067,13 +1263,14 @@ impl TaskManager {
                                 TaskStatus::Completed => stats_guard.total_completed += 1,
                                 TaskStatus::Failed => stats_guard.total_failed += 1,
                                 TaskStatus::TimedOut => stats_guard.total_failed += 1,
+                                TaskStatus::Cancelled => stats_guard.total_cancelled += 1,
                                 _ => {}
                             }
 
                             // Update average execution time
                             let total_completed =
                                 stats_guard.total_completed + stats_guard.total_failed;
-                            if total_completed > 0 {
+                            if total_completed > 0 && new_status != TaskStatus::Cancelled {
                                 stats_guard.avg_execution_time_ms = (stats_guard
                                     .avg_execution_time_ms
                                     * (total_completed - 1) as f64
@@ -1089,19 +1286,18 @@ impl TaskManager {
                             );
                         }
 
+                        Self::retire_task(&tasks, &completed, keep_completed, task_id);
+
                         // Publish status change event
                         if let Some(event_bus) = &event_bus {
                             let event = TaskStatusChangedEvent {
                                 task_id,
-                                name: tasks
-                                    .get(&task_id)
-                                    .map(|t| t.info.name.clone())
-                                    .unwrap_or_default(),
+                                name: task_name,
                                 old_status: TaskStatus::Running,
                                 new_status,
                                 timestamp: Time::now(),
                                 source: "task_manager".to_string(),
-                                metadata: HashMap::new(),
+                                metadata: task_metadata,
                             };
                             let _ = event_bus.publish(event).await;
                         }
@@ -1161,7 +1357,7 @@ impl Manager for TaskManager {
     }
 
     fn id(&self) -> Uuid {
-        Uuid::new_v4() // Simplified
+        crate::utils::rand::uuid() // Simplified
     }
 
     async fn initialize(&mut self) -> Result<()> {
@@ -1252,7 +1448,7 @@ pub struct TaskBuilder {
     priority: TaskPriority,
     plugin_id: Option<String>,
     dependencies: Vec<Uuid>,
-    timeout: Duration,
+    timeout: Option<Duration>,
     max_retries: u32,
     cancellable: bool,
     metadata: Metadata,
@@ -1267,7 +1463,7 @@ impl TaskBuilder {
             priority: TaskPriority::Normal,
             plugin_id: None,
             dependencies: Vec::new(),
-            timeout: Duration::from_secs(300), // 5 minutes default
+            timeout: None, // Manager's default_timeout_ms
             max_retries: 0,
             cancellable: true,
             metadata: HashMap::new(),
@@ -1296,7 +1492,7 @@ impl TaskBuilder {
     }
 
     pub fn timeout(mut self, timeout: Duration) -> Self {
-        self.timeout = timeout;
+        self.timeout = Some(timeout);
         self
     }
 
@@ -1328,7 +1524,7 @@ impl TaskBuilder {
         let task_function: TaskFunction = Arc::new(move |ctx| Box::pin(function(ctx)));
 
         TaskDefinition {
-            id: Uuid::new_v4(),
+            id: crate::utils::rand::uuid(),
             name: self.name,
             category: self.category,
             priority: self.priority,
@@ -1377,7 +1573,7 @@ mod tests {
         assert_eq!(task.name, "test_task");
         assert_eq!(task.category, TaskCategory::User);
         assert_eq!(task.priority, TaskPriority::High);
-        assert_eq!(task.timeout, Duration::from_secs(60));
+        assert_eq!(task.timeout, Some(Duration::from_secs(60)));
         assert!(task.cancellable);
         assert!(task.metadata.contains_key("key"));
     }
@@ -1409,6 +1605,69 @@ mod tests {
         manager.shutdown().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_task_handle_progress_and_cancel() {
+        let config = TaskConfig {
+            progress_update_interval_ms: 10,
+            ..TaskConfig::default()
+        };
+        let mut manager = TaskManager::new(config);
+        manager.initialize().await.unwrap();
+
+        let task = TaskBuilder::new("long_task").build(|ctx| async move {
+            ctx.report_percent(50, "Half way done");
+            ctx.cancelled().await;
+            Ok(serde_json::Value::Null)
+        });
+        let handle = manager.submit(task).await.unwrap();
+
+        let mut progress = handle.progress();
+        timeout(Duration::from_secs(5), progress.wait_for(|p| *p >= 0.5))
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(handle.cancel());
+        assert!(handle.await_result().await.is_err());
+        assert!(handle.is_finished());
+
+        // keep_completed retains the finished task
+        let info = manager.get_task_info(handle.id()).await.unwrap();
+        assert_eq!(info.status, TaskStatus::Cancelled);
+        assert_eq!(manager.get_stats().await.total_cancelled, 1);
+
+        let task =
+            TaskBuilder::new("quick_task").build(|_ctx| async { Ok(serde_json::Value::from(42)) });
+        let handle = manager.submit(task).await.unwrap();
+        assert_eq!(handle.await_result().await.unwrap(), 42);
+        assert_eq!(*handle.progress().borrow(), 1.0);
+
+        manager.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_task_default_timeout_and_retention() {
+        let config = TaskConfig {
+            default_timeout_ms: 50,
+            keep_completed: false,
+            ..TaskConfig::default()
+        };
+        let mut manager = TaskManager::new(config);
+        manager.initialize().await.unwrap();
+
+        let task = TaskBuilder::new("slow_task").build(|_ctx| async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok(serde_json::Value::Null)
+        });
+        let handle = manager.submit(task).await.unwrap();
+
+        let error = handle.await_result().await.unwrap_err();
+        assert!(matches!(error.kind, crate::error::ErrorKind::Timeout));
+        assert!(manager.get_task_info(handle.id()).await.is_none());
+
+        manager.shutdown().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_task_progress() {
         let mut progress = TaskProgress::default();
@@ -4,18 +4,27 @@ use dioxus::prelude::*;
 #[allow(unused_imports)]
 use dioxus_router::prelude::*;
 
+use crate::plugin::PluginInstaller;
 use crate::ui::{
     layout::Layout,
     pages::{Dashboard, Login, NotFound, Profile},
+    palette::CommandRegistry,
+    registry::UIComponentRegistry,
     router::Route,
     state::AppStateProvider,
+    ThemeStyle,
 };
 
 /// Main application component that sets up routing and global state
 #[component]
 pub fn App() -> Element {
+    use_context_provider(UIComponentRegistry::shared);
+    use_context_provider(CommandRegistry::shared);
+    use_context_provider(PluginInstaller::shared);
+
     rsx! {
         AppStateProvider {
+            ThemeStyle {}
             Router::<Route> {}
         }
     }
@@ -0,0 +1,164 @@
+// src/ui/components/dropdown.rs - Dropdown menu with built-in dismiss behavior
+
+use dioxus::prelude::*;
+
+use super::focus::focus_step;
+
+/// Dropdown menu component.
+///
+/// With `on_toggle` the parent owns the open state through `open`. Without
+/// it the dropdown manages its own state and closes on a click outside the
+/// component or on `Escape`. Arrow keys move focus between menu items.
+#[component]
+pub fn Dropdown(
+    #[props(default = false)] open: bool,
+    #[props(default = None)] on_toggle: Option<Callback<()>>,
+    #[props(default = "".to_string())] button_class: String,
+    #[props(default = "".to_string())] menu_class: String,
+    trigger: Element,
+    children: Element,
+) -> Element {
+    let managed = on_toggle.is_none();
+    let mut self_open = use_signal(|| false);
+    let root_id = use_hook(|| format!("dropdown-{}", crate::utils::rand::uuid().simple()));
+    let is_open = if managed { self_open() } else { open };
+
+    let listener_id = root_id.clone();
+    use_effect(move || {
+        if !managed {
+            return;
+        }
+        let script = dismiss_listener_script(&listener_id);
+        spawn(async move {
+            let mut listener = document::eval(&script);
+            while listener.recv::<String>().await.is_ok() {
+                if *self_open.peek() {
+                    self_open.set(false);
+                }
+            }
+        });
+    });
+
+    let cleanup_id = root_id.clone();
+    use_drop(move || {
+        if managed {
+            document::eval(&remove_listener_script(&cleanup_id));
+        }
+    });
+
+    let menu_id = format!("{}-menu", root_id);
+    let focus_menu_id = menu_id.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let step = match event.key() {
+            Key::ArrowDown => 1,
+            Key::ArrowUp => -1,
+            _ => return,
+        };
+        event.prevent_default();
+        focus_step(&focus_menu_id, step);
+    };
+
+    rsx! {
+        div {
+            id: "{root_id}",
+            class: "relative inline-block text-left",
+
+            // Trigger button
+            button {
+                r#type: "button",
+                class: format!("inline-flex w-full justify-center gap-x-1.5 rounded-md bg-white px-3 py-2 text-sm font-semibold text-gray-900 shadow-sm ring-1 ring-inset ring-gray-300 hover:bg-gray-50 {}", button_class),
+                aria_haspopup: "menu",
+                aria_expanded: "{is_open}",
+                onclick: move |_| {
+                    match &on_toggle {
+                        Some(handler) => handler.call(()),
+                        None => self_open.toggle(),
+                    }
+                },
+                onkeydown: move |event: KeyboardEvent| {
+                    if is_open && event.key() == Key::ArrowDown {
+                        event.prevent_default();
+                        focus_step(&menu_id, 1);
+                    }
+                },
+                {trigger}
+                svg {
+                    class: "-mr-1 h-5 w-5 text-gray-400",
+                    xmlns: "http://www.w3.org/2000/svg",
+                    view_box: "0 0 20 20",
+                    fill: "currentColor",
+                    path {
+                        fill_rule: "evenodd",
+                        d: "M5.23 7.21a.75.75 0 011.06.02L10 11.168l3.71-3.938a.75.75 0 111.08 1.04l-4.25 4.5a.75.75 0 01-1.08 0l-4.25-4.5a.75.75 0 01.02-1.06z",
+                        clip_rule: "evenodd"
+                    }
+                }
+            }
+
+            // Dropdown menu
+            if is_open {
+                div {
+                    id: "{root_id}-menu",
+                    role: "menu",
+                    class: format!("absolute right-0 z-10 mt-2 w-56 origin-top-right divide-y divide-gray-100 rounded-md bg-white shadow-lg ring-1 ring-black ring-opacity-5 focus:outline-none {}", menu_class),
+                    onkeydown,
+                    {children}
+                }
+            }
+        }
+    }
+}
+
+/// Global registry of dropdown listeners so unmounting can remove them
+const LISTENER_REGISTRY: &str = "window.__qorzenDropdowns";
+
+/// Installs document-level listeners that send a message on outside clicks and `Escape`
+fn dismiss_listener_script(root_id: &str) -> String {
+    format!(
+        r#"
+        const onPointer = (event) => {{
+            const root = document.getElementById("{root_id}");
+            if (root && !root.contains(event.target)) dioxus.send("outside");
+        }};
+        const onKey = (event) => {{
+            if (event.key === "Escape") dioxus.send("escape");
+        }};
+        document.addEventListener("mousedown", onPointer);
+        document.addEventListener("keydown", onKey);
+        {registry} = {registry} || new Map();
+        {registry}.set("{root_id}", () => {{
+            document.removeEventListener("mousedown", onPointer);
+            document.removeEventListener("keydown", onKey);
+        }});
+        "#,
+        registry = LISTENER_REGISTRY,
+    )
+}
+
+/// Removes the listeners installed by [`dismiss_listener_script`]
+fn remove_listener_script(root_id: &str) -> String {
+    format!(
+        r#"
+        const remove = {registry} && {registry}.get("{root_id}");
+        if (remove) {{ remove(); {registry}.delete("{root_id}"); }}
+        "#,
+        registry = LISTENER_REGISTRY,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listener_scripts_share_registry_key() {
+        let install = dismiss_listener_script("dropdown-abc");
+        let remove = remove_listener_script("dropdown-abc");
+
+        assert!(install.contains(r#"getElementById("dropdown-abc")"#));
+        assert!(install.contains(r#".set("dropdown-abc""#));
+        assert!(install.contains(r#"removeEventListener("mousedown", onPointer)"#));
+        assert!(remove.contains(r#".get("dropdown-abc")"#));
+        assert!(remove.contains(r#".delete("dropdown-abc")"#));
+    }
+}
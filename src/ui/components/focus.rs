@@ -0,0 +1,54 @@
+// src/ui/components/focus.rs - Keyboard focus helpers shared by overlay components
+
+use dioxus::prelude::*;
+
+/// Elements that can receive keyboard focus
+pub const FOCUSABLE_SELECTOR: &str = "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1']), [role='menuitem']";
+
+/// Moves focus `step` focusable elements through the container, wrapping at either end.
+///
+/// When focus is outside the container it lands on the first element for a
+/// forward step and on the last one otherwise.
+pub fn focus_step(container_id: &str, step: i32) {
+    document::eval(&focus_step_script(container_id, step));
+}
+
+/// Moves focus to the element with `id`, if it exists
+pub fn focus_element(id: &str) {
+    document::eval(&format!(
+        r#"document.getElementById("{}")?.focus();"#,
+        id.replace('"', "\\\"")
+    ));
+}
+
+fn focus_step_script(container_id: &str, step: i32) -> String {
+    format!(
+        r#"
+        const container = document.getElementById("{container_id}");
+        if (container) {{
+            const items = Array.from(container.querySelectorAll("{selector}"));
+            if (items.length > 0) {{
+                const current = items.indexOf(document.activeElement);
+                const next = current < 0
+                    ? ({step} > 0 ? 0 : items.length - 1)
+                    : (current + {step} + items.length) % items.length;
+                items[next].focus();
+            }}
+        }}
+        "#,
+        selector = FOCUSABLE_SELECTOR.replace('"', "\\\""),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_focus_step_script_targets_container() {
+        let script = focus_step_script("modal-1", -1);
+        assert!(script.contains(r#"getElementById("modal-1")"#));
+        assert!(script.contains("(current + -1 + items.length)"));
+        assert!(script.contains("button:not([disabled])"));
+    }
+}
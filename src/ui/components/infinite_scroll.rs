@@ -0,0 +1,352 @@
+// src/ui/components/infinite_scroll.rs - Infinite scroll loading for paginated lists
+
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use super::Spinner;
+use crate::error::Result;
+
+/// Distance from the bottom, in pixels, at which the next page is requested
+pub const DEFAULT_LOAD_THRESHOLD_PX: f64 = 200.0;
+
+/// Detects when a scroll position crosses into the load threshold.
+///
+/// Fires once per crossing: after triggering, it stays disarmed until the
+/// position leaves the threshold again, so repeated scroll events near the
+/// bottom do not request the same page twice.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollTrigger {
+    threshold_px: f64,
+    armed: bool,
+}
+
+impl ScrollTrigger {
+    pub fn new(threshold_px: f64) -> Self {
+        Self {
+            threshold_px: threshold_px.max(0.0),
+            armed: true,
+        }
+    }
+
+    /// Feeds a scroll position, returning `true` when a load should start
+    pub fn on_scroll(&mut self, scroll_top: f64, client_height: f64, scroll_height: f64) -> bool {
+        let remaining = scroll_height - (scroll_top + client_height);
+        self.observe(remaining <= self.threshold_px)
+    }
+
+    /// Feeds whether the sentinel is near the viewport, returning `true` when a load should start
+    pub fn observe(&mut self, near_bottom: bool) -> bool {
+        if !near_bottom {
+            self.armed = true;
+            return false;
+        }
+
+        std::mem::replace(&mut self.armed, false)
+    }
+}
+
+/// Tracks the `limit`/`offset` of the next page and whether the list has ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageCursor {
+    page_size: usize,
+    offset: usize,
+    exhausted: bool,
+}
+
+impl PageCursor {
+    pub fn new(page_size: usize) -> Self {
+        Self {
+            page_size: page_size.max(1),
+            offset: 0,
+            exhausted: false,
+        }
+    }
+
+    /// `(limit, offset)` of the next page, or `None` once the list has ended
+    pub fn next_page(&self) -> Option<(usize, usize)> {
+        (!self.exhausted).then_some((self.page_size, self.offset))
+    }
+
+    /// Records a loaded page; a short page marks the end of the list
+    pub fn page_loaded(&mut self, len: usize) {
+        self.offset += len;
+        if len < self.page_size {
+            self.exhausted = true;
+        }
+    }
+
+    pub fn has_more(&self) -> bool {
+        !self.exhausted
+    }
+}
+
+type PageFetcher<T> = Rc<dyn Fn(usize, usize) -> Pin<Box<dyn Future<Output = Result<Vec<T>>>>>>;
+
+/// Items accumulated by [`use_paginated_list`]
+pub struct PaginatedList<T: 'static> {
+    pub items: Signal<Vec<T>>,
+    pub loading: Signal<bool>,
+    pub error: Signal<Option<String>>,
+    cursor: Signal<PageCursor>,
+    fetch: PageFetcher<T>,
+}
+
+impl<T: 'static> Clone for PaginatedList<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items,
+            loading: self.loading,
+            error: self.error,
+            cursor: self.cursor,
+            fetch: Rc::clone(&self.fetch),
+        }
+    }
+}
+
+impl<T: 'static> PaginatedList<T> {
+    pub fn has_more(&self) -> bool {
+        self.cursor.read().has_more()
+    }
+
+    /// Fetches and appends the next page unless a load is running or the list has ended
+    pub fn load_more(&self) {
+        if *self.loading.peek() {
+            return;
+        }
+        let Some((limit, offset)) = self.cursor.peek().next_page() else {
+            return;
+        };
+
+        let mut items = self.items;
+        let mut loading = self.loading;
+        let mut error = self.error;
+        let mut cursor = self.cursor;
+        let page = (self.fetch)(limit, offset);
+
+        loading.set(true);
+        spawn(async move {
+            match page.await {
+                Ok(page) => {
+                    cursor.write().page_loaded(page.len());
+                    items.write().extend(page);
+                    error.set(None);
+                }
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            loading.set(false);
+        });
+    }
+}
+
+/// Loads a list page by page with `fetch(limit, offset)`, starting with the first page.
+///
+/// Pair with [`InfiniteScroll`] by passing `move |_| list.load_more()` as `on_load_more`.
+pub fn use_paginated_list<T, F, Fut>(page_size: usize, fetch: F) -> PaginatedList<T>
+where
+    T: 'static,
+    F: Fn(usize, usize) -> Fut + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + 'static,
+{
+    let items = use_signal(Vec::new);
+    let loading = use_signal(|| false);
+    let error = use_signal(|| None);
+    let cursor = use_signal(|| PageCursor::new(page_size));
+    let fetch = use_hook(move || -> PageFetcher<T> {
+        Rc::new(move |limit, offset| Box::pin(fetch(limit, offset)))
+    });
+
+    let list = PaginatedList {
+        items,
+        loading,
+        error,
+        cursor,
+        fetch,
+    };
+
+    let first_page = list.clone();
+    use_hook(move || first_page.load_more());
+
+    list
+}
+
+/// Scroll container that calls `on_load_more` when the user nears the bottom.
+///
+/// The web build watches a sentinel below the content with an
+/// `IntersectionObserver`; desktop measures the container on scroll.
+#[component]
+pub fn InfiniteScroll(
+    on_load_more: EventHandler<()>,
+    #[props(default = false)] loading: bool,
+    #[props(default = true)] has_more: bool,
+    #[props(default = DEFAULT_LOAD_THRESHOLD_PX)] threshold_px: f64,
+    #[props(default = "No more items".to_string())] end_message: String,
+    #[props(default = "".to_string())] class: String,
+    children: Element,
+) -> Element {
+    let mut trigger = use_signal(|| ScrollTrigger::new(threshold_px));
+    let mut busy = use_hook(|| CopyValue::new(false));
+    busy.set(loading || !has_more);
+
+    let mut container = use_signal(|| None::<Rc<MountedData>>);
+    let (container_id, sentinel_id) = use_hook(|| {
+        let id = crate::utils::rand::uuid().simple().to_string();
+        (
+            format!("infinite-scroll-{}", id),
+            format!("infinite-scroll-sentinel-{}", id),
+        )
+    });
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        let observer = use_hook(|| Rc::new(std::cell::RefCell::new(None)));
+        let (root_id, target_id) = (container_id.clone(), sentinel_id.clone());
+        use_effect(move || {
+            *observer.borrow_mut() =
+                web::observe_sentinel(&root_id, &target_id, threshold_px, move |near| {
+                    if !*busy.peek() && trigger.write().observe(near) {
+                        on_load_more.call(());
+                    }
+                });
+        });
+    }
+
+    rsx! {
+        div {
+            id: "{container_id}",
+            class: format!("overflow-y-auto {}", class),
+            onmounted: move |event| container.set(Some(event.data())),
+            onscroll: move |_| async move {
+                if cfg!(target_arch = "wasm32") || *busy.peek() {
+                    return;
+                }
+                let Some(element) = container() else {
+                    return;
+                };
+                let (Ok(offset), Ok(size), Ok(rect)) = (
+                    element.get_scroll_offset().await,
+                    element.get_scroll_size().await,
+                    element.get_client_rect().await,
+                ) else {
+                    return;
+                };
+
+                if trigger.write().on_scroll(offset.y, rect.height(), size.height) {
+                    on_load_more.call(());
+                }
+            },
+
+            {children}
+
+            div {
+                id: "{sentinel_id}",
+                class: "flex justify-center py-4 text-sm text-gray-500",
+                if loading {
+                    Spinner { size: "md".to_string() }
+                } else if !has_more {
+                    "{end_message}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+    use web_sys::{IntersectionObserver, IntersectionObserverEntry, IntersectionObserverInit};
+
+    /// Disconnects its observer when dropped
+    pub struct SentinelObserver {
+        observer: IntersectionObserver,
+        _callback: Closure<dyn FnMut(js_sys::Array, IntersectionObserver)>,
+    }
+
+    impl Drop for SentinelObserver {
+        fn drop(&mut self) {
+            self.observer.disconnect();
+        }
+    }
+
+    /// Reports whether the sentinel is within `threshold_px` of the root's visible area
+    pub fn observe_sentinel(
+        root_id: &str,
+        sentinel_id: &str,
+        threshold_px: f64,
+        mut on_change: impl FnMut(bool) + 'static,
+    ) -> Option<SentinelObserver> {
+        let document = web_sys::window()?.document()?;
+        let root = document.get_element_by_id(root_id)?;
+        let sentinel = document.get_element_by_id(sentinel_id)?;
+
+        let callback = Closure::<dyn FnMut(js_sys::Array, IntersectionObserver)>::new(
+            move |entries: js_sys::Array, _observer| {
+                for entry in entries.iter() {
+                    let entry: IntersectionObserverEntry = entry.unchecked_into();
+                    on_change(entry.is_intersecting());
+                }
+            },
+        );
+
+        let init = IntersectionObserverInit::new();
+        init.set_root(Some(&root));
+        init.set_root_margin(&format!("0px 0px {}px 0px", threshold_px));
+
+        let observer =
+            IntersectionObserver::new_with_options(callback.as_ref().unchecked_ref(), &init)
+                .ok()?;
+        observer.observe(&sentinel);
+
+        Some(SentinelObserver {
+            observer,
+            _callback: callback,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scroll_trigger_fires_once_per_crossing() {
+        let mut trigger = ScrollTrigger::new(200.0);
+        let (client_height, scroll_height) = (600.0, 2000.0);
+
+        // Far from the bottom
+        assert!(!trigger.on_scroll(0.0, client_height, scroll_height));
+        assert!(!trigger.on_scroll(1000.0, client_height, scroll_height));
+
+        // Crossing into the threshold loads once, however many events follow
+        assert!(trigger.on_scroll(1250.0, client_height, scroll_height));
+        assert!(!trigger.on_scroll(1300.0, client_height, scroll_height));
+        assert!(!trigger.on_scroll(1400.0, client_height, scroll_height));
+
+        // The next page grows the list, moving the position out of the threshold
+        let scroll_height = 3000.0;
+        assert!(!trigger.on_scroll(1400.0, client_height, scroll_height));
+        assert!(trigger.on_scroll(2250.0, client_height, scroll_height));
+        assert!(!trigger.on_scroll(2400.0, client_height, scroll_height));
+
+        // Observer notifications follow the same rule
+        assert!(!trigger.observe(false));
+        assert!(trigger.observe(true));
+        assert!(!trigger.observe(true));
+    }
+
+    #[test]
+    fn test_page_cursor_detects_end_of_list() {
+        let mut cursor = PageCursor::new(20);
+        assert_eq!(cursor.next_page(), Some((20, 0)));
+
+        cursor.page_loaded(20);
+        assert_eq!(cursor.next_page(), Some((20, 20)));
+
+        cursor.page_loaded(7);
+        assert!(!cursor.has_more());
+        assert_eq!(cursor.next_page(), None);
+    }
+}
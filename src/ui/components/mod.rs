@@ -1,7 +1,29 @@
 // src/ui/components/mod.rs - Reusable UI components
 
+use std::rc::Rc;
+use std::time::Duration;
+
 use dioxus::prelude::*;
 
+use crate::ui::state::sleep;
+
+mod dropdown;
+mod focus;
+mod infinite_scroll;
+mod modal;
+mod pagination;
+mod toasts;
+
+pub use dropdown::Dropdown;
+pub use focus::{focus_element, focus_step, FOCUSABLE_SELECTOR};
+pub use infinite_scroll::{
+    use_paginated_list, InfiniteScroll, PageCursor, PaginatedList, ScrollTrigger,
+    DEFAULT_LOAD_THRESHOLD_PX,
+};
+pub use modal::Modal;
+pub use pagination::{page_items, PageItem, Pagination, DEFAULT_SIBLING_COUNT};
+pub use toasts::Toasts;
+
 /// Button component with consistent styling
 #[component]
 pub fn Button(
@@ -88,6 +110,11 @@ pub fn Button(
 }
 
 /// Input component with consistent styling
+///
+/// Set `error` to show the field as invalid; with a non-empty `id` the input
+/// is described by the matching [`FormField`] error message. `bind` keeps a
+/// signal in sync with the text, and a non-zero `debounce_ms` holds `oninput`
+/// back until typing pauses for that long.
 #[component]
 pub fn Input(
     #[props(default = "text".to_string())] input_type: String,
@@ -95,18 +122,32 @@ pub fn Input(
     #[props(default = "".to_string())] id: String,
     #[props(default = "".to_string())] placeholder: String,
     #[props(default = "".to_string())] value: String,
+    #[props(default = None)] bind: Option<Signal<String>>,
+    #[props(default = None)] error: Option<String>,
+    #[props(default = 0)] debounce_ms: u64,
+    #[props(default = None)] min: Option<String>,
+    #[props(default = None)] max: Option<String>,
     #[props(default = false)] required: bool,
     #[props(default = false)] disabled: bool,
     #[props(default = "".to_string())] class: String,
     #[props(default = None)] oninput: Option<Callback<FormEvent>>,
     #[props(default = None)] onchange: Option<Callback<FormEvent>>,
 ) -> Element {
-    let base_classes = "block w-full border-gray-300 rounded-md shadow-sm focus:ring-blue-500 focus:border-blue-500 sm:text-sm";
+    let mut pending_input = use_signal(|| 0u64);
+
+    let base_classes = "block w-full rounded-md shadow-sm sm:text-sm";
+    let state_classes = if error.is_some() {
+        "border-red-300 text-red-900 focus:ring-red-500 focus:border-red-500"
+    } else {
+        "border-gray-300 focus:ring-blue-500 focus:border-blue-500"
+    };
     let disabled_classes = if disabled {
         "bg-gray-50 text-gray-500"
     } else {
         ""
     };
+    let value = bind.map_or(value, |bound| bound());
+    let described_by = (error.is_some() && !id.is_empty()).then(|| field_error_id(&id));
 
     rsx! {
         input {
@@ -115,13 +156,34 @@ pub fn Input(
             id: "{id}",
             placeholder: "{placeholder}",
             value: "{value}",
+            min: min,
+            max: max,
             required: required,
             disabled: disabled,
-            class: format!("{} {} {}", base_classes, disabled_classes, class),
-            oninput: move |evt| {
-                if let Some(handler) = &oninput {
+            aria_invalid: if error.is_some() { "true" } else { "false" },
+            aria_describedby: described_by,
+            class: format!("{} {} {} {}", base_classes, state_classes, disabled_classes, class),
+            oninput: move |evt: FormEvent| {
+                if let Some(mut bound) = bind {
+                    bound.set(evt.value());
+                }
+                let Some(handler) = oninput else {
+                    return;
+                };
+                if debounce_ms == 0 {
                     handler.call(evt);
+                    return;
                 }
+
+                // Only the last keystroke of a burst reaches the handler
+                let ticket = *pending_input.peek() + 1;
+                pending_input.set(ticket);
+                spawn(async move {
+                    sleep(Duration::from_millis(debounce_ms)).await;
+                    if *pending_input.peek() == ticket {
+                        handler.call(evt);
+                    }
+                });
             },
             onchange: move |evt| {
                 if let Some(handler) = &onchange {
@@ -132,6 +194,83 @@ pub fn Input(
     }
 }
 
+/// Select component for picking one of `options`, given as `(value, label)`.
+///
+/// Styled and wired to [`FormField`] errors like [`Input`]. With a
+/// `placeholder`, an empty value shows a disabled prompt option. `onchange`
+/// receives the chosen value; `bind` keeps a signal in sync with it.
+#[component]
+pub fn Select(
+    options: Vec<(String, String)>,
+    #[props(default = "".to_string())] name: String,
+    #[props(default = "".to_string())] id: String,
+    #[props(default = "".to_string())] value: String,
+    #[props(default = None)] bind: Option<Signal<String>>,
+    #[props(default = None)] placeholder: Option<String>,
+    #[props(default = None)] error: Option<String>,
+    #[props(default = false)] required: bool,
+    #[props(default = false)] disabled: bool,
+    #[props(default = "".to_string())] class: String,
+    #[props(default = None)] onchange: Option<Callback<String>>,
+) -> Element {
+    let base_classes = "block w-full rounded-md shadow-sm sm:text-sm";
+    let state_classes = if error.is_some() {
+        "border-red-300 text-red-900 focus:ring-red-500 focus:border-red-500"
+    } else {
+        "border-gray-300 focus:ring-blue-500 focus:border-blue-500"
+    };
+    let disabled_classes = if disabled {
+        "bg-gray-50 text-gray-500"
+    } else {
+        ""
+    };
+    let value = bind.map_or(value, |bound| bound());
+    let described_by = (error.is_some() && !id.is_empty()).then(|| field_error_id(&id));
+
+    rsx! {
+        select {
+            name: "{name}",
+            id: "{id}",
+            value: "{value}",
+            required: required,
+            disabled: disabled,
+            aria_invalid: if error.is_some() { "true" } else { "false" },
+            aria_describedby: described_by,
+            class: format!("{} {} {} {}", base_classes, state_classes, disabled_classes, class),
+            onchange: move |evt: FormEvent| {
+                let selected = evt.value();
+                if let Some(mut bound) = bind {
+                    bound.set(selected.clone());
+                }
+                if let Some(handler) = &onchange {
+                    handler.call(selected);
+                }
+            },
+            if let Some(prompt) = placeholder {
+                option {
+                    value: "",
+                    disabled: true,
+                    selected: value.is_empty(),
+                    "{prompt}"
+                }
+            }
+            for (option_value, label) in options.iter() {
+                option {
+                    key: "{option_value}",
+                    value: "{option_value}",
+                    selected: *option_value == value,
+                    "{label}"
+                }
+            }
+        }
+    }
+}
+
+/// ID of the error message [`FormField`] renders for the field `id`
+pub fn field_error_id(id: &str) -> String {
+    format!("{}-error", id)
+}
+
 /// Label component
 #[component]
 pub fn Label(
@@ -182,6 +321,7 @@ pub fn FormField(
 
             if let Some(error_msg) = error {
                 p {
+                    id: field_error_id(&id),
                     class: "text-sm text-red-600",
                     "{error_msg}"
                 }
@@ -197,94 +337,6 @@ pub fn FormField(
     }
 }
 
-/// Modal component
-#[component]
-pub fn Modal(
-    #[props(default = false)] show: bool,
-    #[props(default = "".to_string())] title: String,
-    #[props(default = None)] on_close: Option<Callback<()>>,
-    #[props(default = "".to_string())] class: String,
-    children: Element,
-) -> Element {
-    if !show {
-        return rsx! { div { style: "display: none;" } };
-    }
-
-    rsx! {
-        div {
-            class: "fixed inset-0 z-50 overflow-y-auto",
-
-            // Backdrop
-            div {
-                class: "fixed inset-0 bg-gray-500 bg-opacity-75 transition-opacity",
-                onclick: move |_| {
-                    if let Some(handler) = &on_close {
-                        handler.call(());
-                    }
-                }
-            }
-
-            // Modal content
-            div {
-                class: "flex min-h-full items-end justify-center p-4 text-center sm:items-center sm:p-0",
-                div {
-                    class: format!(
-                        "relative transform overflow-hidden rounded-lg bg-white text-left shadow-xl transition-all sm:my-8 sm:w-full sm:max-w-lg {}",
-                        class
-                    ),
-                    onclick: |evt| evt.stop_propagation(),
-
-                    if !title.is_empty() {
-                        div {
-                            class: "bg-white px-4 pb-4 pt-5 sm:p-6 sm:pb-4",
-                            div {
-                                class: "flex items-start justify-between",
-                                h3 {
-                                    class: "text-lg font-medium leading-6 text-gray-900",
-                                    "{title}"
-                                }
-                                if on_close.is_some() {
-                                    button {
-                                        r#type: "button",
-                                        class: "rounded-md bg-white text-gray-400 hover:text-gray-600 focus:outline-none focus:ring-2 focus:ring-blue-500",
-                                        onclick: move |_| {
-                                            if let Some(handler) = &on_close {
-                                                handler.call(());
-                                            }
-                                        },
-                                        span {
-                                            class: "sr-only",
-                                            "Close"
-                                        }
-                                        svg {
-                                            class: "h-6 w-6",
-                                            xmlns: "http://www.w3.org/2000/svg",
-                                            fill: "none",
-                                            view_box: "0 0 24 24",
-                                            stroke: "currentColor",
-                                            path {
-                                                stroke_linecap: "round",
-                                                stroke_linejoin: "round",
-                                                stroke_width: "2",
-                                                d: "M6 18L18 6M6 6l12 12"
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-
-                    div {
-                        class: "px-4 pb-4 pt-5 sm:p-6",
-                        {children}
-                    }
-                }
-            }
-        }
-    }
-}
-
 /// Alert/Banner component
 #[component]
 pub fn Alert(
@@ -504,55 +556,11 @@ pub fn Card(
     }
 }
 
-/// Dropdown menu component
-#[component]
-pub fn Dropdown(
-    #[props(default = false)] open: bool,
-    #[props(default = None)] on_toggle: Option<Callback<()>>,
-    #[props(default = "".to_string())] button_class: String,
-    #[props(default = "".to_string())] menu_class: String,
-    trigger: Element,
-    children: Element,
-) -> Element {
-    rsx! {
-        div {
-            class: "relative inline-block text-left",
-
-            // Trigger button
-            button {
-                r#type: "button",
-                class: format!("inline-flex w-full justify-center gap-x-1.5 rounded-md bg-white px-3 py-2 text-sm font-semibold text-gray-900 shadow-sm ring-1 ring-inset ring-gray-300 hover:bg-gray-50 {}", button_class),
-                onclick: move |_| {
-                    if let Some(handler) = &on_toggle {
-                        handler.call(());
-                    }
-                },
-                {trigger}
-                svg {
-                    class: "-mr-1 h-5 w-5 text-gray-400",
-                    xmlns: "http://www.w3.org/2000/svg",
-                    view_box: "0 0 20 20",
-                    fill: "currentColor",
-                    path {
-                        fill_rule: "evenodd",
-                        d: "M5.23 7.21a.75.75 0 011.06.02L10 11.168l3.71-3.938a.75.75 0 111.08 1.04l-4.25 4.5a.75.75 0 01-1.08 0l-4.25-4.5a.75.75 0 01.02-1.06z",
-                        clip_rule: "evenodd"
-                    }
-                }
-            }
-
-            // Dropdown menu
-            if open {
-                div {
-                    class: format!("absolute right-0 z-10 mt-2 w-56 origin-top-right divide-y divide-gray-100 rounded-md bg-white shadow-lg ring-1 ring-black ring-opacity-5 focus:outline-none {}", menu_class),
-                    {children}
-                }
-            }
-        }
-    }
-}
-
 /// Tabs component
+///
+/// Renders an ARIA tablist. Only the active tab is in the tab order; Left
+/// and Right move to the previous or next tab, Home and End to the first or
+/// last, calling `on_tab_change` and moving focus along.
 #[component]
 pub fn Tabs(
     #[props(default = "".to_string())] active_tab: String,
@@ -560,15 +568,39 @@ pub fn Tabs(
     tabs: Vec<TabItem>,
     #[props(default = "".to_string())] class: String,
 ) -> Element {
+    let id_prefix = use_hook(|| format!("tabs-{}", crate::utils::rand::uuid().simple()));
+
+    let key_tabs = tabs.clone();
+    let key_active = active_tab.clone();
+    let key_prefix = id_prefix.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let Some(next) = tab_after_key(&key_tabs, &key_active, &event.key()) else {
+            return;
+        };
+        event.prevent_default();
+        let tab_id = key_tabs[next].id.clone();
+        focus_element(&format!("{}-{}", key_prefix, tab_id));
+        if let Some(handler) = &on_tab_change {
+            handler.call(tab_id);
+        }
+    };
+
     rsx! {
         div {
             class: format!("border-b border-gray-200 {}", class),
             nav {
                 class: "-mb-px flex space-x-8",
+                role: "tablist",
+                onkeydown,
                 for tab in tabs.iter() {  // Use .iter() instead of consuming
                     button {
                         key: "{tab.id}",
+                        id: "{id_prefix}-{tab.id}",
                         r#type: "button",
+                        role: "tab",
+                        aria_selected: "{active_tab == tab.id}",
+                        aria_label: tab.aria_label(),
+                        tabindex: if active_tab == tab.id { "0" } else { "-1" },
                         class: format!(
                             "py-2 px-1 border-b-2 font-medium text-sm {}",
                             if active_tab == tab.id {
@@ -589,6 +621,7 @@ pub fn Tabs(
                         "{tab.label}"
                         if let Some(count) = tab.count {
                             span {
+                                aria_hidden: "true",
                                 class: format!(
                                     "ml-2 inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium {}",
                                     if active_tab == tab.id {
@@ -607,6 +640,20 @@ pub fn Tabs(
     }
 }
 
+/// Index of the tab a navigation key moves to, `None` for other keys
+fn tab_after_key(tabs: &[TabItem], active_tab: &str, key: &Key) -> Option<usize> {
+    let last = tabs.len().checked_sub(1)?;
+    let current = tabs.iter().position(|tab| tab.id == active_tab);
+
+    match key {
+        Key::ArrowRight => Some(current.map_or(0, |i| if i == last { 0 } else { i + 1 })),
+        Key::ArrowLeft => Some(current.map_or(last, |i| if i == 0 { last } else { i - 1 })),
+        Key::Home => Some(0),
+        Key::End => Some(last),
+        _ => None,
+    }
+}
+
 /// Tab item data structure
 #[derive(Debug, Clone, PartialEq)]
 pub struct TabItem {
@@ -615,6 +662,234 @@ pub struct TabItem {
     pub count: Option<u32>,
 }
 
+impl TabItem {
+    /// Accessible name, including the count badge when present
+    pub fn aria_label(&self) -> String {
+        match self.count {
+            Some(count) => format!("{} ({})", self.label, count),
+            None => self.label.clone(),
+        }
+    }
+}
+
+/// Order a [`DataTable`] column is sorted in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    fn indicator(&self) -> &'static str {
+        match self {
+            Self::Ascending => "▲",
+            Self::Descending => "▼",
+        }
+    }
+
+    fn aria_sort(&self) -> &'static str {
+        match self {
+            Self::Ascending => "ascending",
+            Self::Descending => "descending",
+        }
+    }
+}
+
+/// How many rows of a [`DataTable`] can be selected at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    #[default]
+    None,
+    Single,
+    Multi,
+}
+
+/// Column of a [`DataTable`]: a header and how to render each row's cell
+#[derive(Clone)]
+pub struct TableColumn<T> {
+    pub header: String,
+    pub render: Rc<dyn Fn(&T) -> Element>,
+    /// Key passed to `on_sort`; columns without one cannot be sorted
+    pub sort_key: Option<String>,
+}
+
+impl<T> TableColumn<T> {
+    pub fn new(header: impl Into<String>, render: impl Fn(&T) -> Element + 'static) -> Self {
+        Self {
+            header: header.into(),
+            render: Rc::new(render),
+            sort_key: None,
+        }
+    }
+
+    pub fn sortable(mut self, sort_key: impl Into<String>) -> Self {
+        self.sort_key = Some(sort_key.into());
+        self
+    }
+}
+
+impl<T> PartialEq for TableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header
+            && self.sort_key == other.sort_key
+            && Rc::ptr_eq(&self.render, &other.render)
+    }
+}
+
+/// Table with sortable headers and optional row selection.
+///
+/// Sorting itself is left to the caller: clicking a sortable header calls
+/// `on_sort` with its key, ascending on first click and flipping on each
+/// further one, and the header shows the current direction. Rows are
+/// identified by `row_id`; clicking one calls `on_row_select` and updates
+/// the selection according to `selectable`.
+#[component]
+pub fn DataTable<T: Clone + PartialEq + 'static>(
+    columns: Vec<TableColumn<T>>,
+    rows: Vec<T>,
+    row_id: Callback<T, String>,
+    #[props(default = None)] sort: Option<(String, SortDirection)>,
+    #[props(default)] selectable: SelectionMode,
+    #[props(default = None)] on_sort: Option<Callback<(String, SortDirection)>>,
+    #[props(default = None)] on_row_select: Option<Callback<String>>,
+    #[props(default = "No data".to_string())] empty_message: String,
+    #[props(default = "".to_string())] class: String,
+) -> Element {
+    let mut current_sort = use_signal(|| sort.clone());
+    let mut selected = use_signal(Vec::<String>::new);
+
+    rsx! {
+        div {
+            class: format!("overflow-x-auto border border-gray-200 rounded-lg {}", class),
+            table {
+                class: "min-w-full divide-y divide-gray-200",
+                aria_multiselectable: if selectable == SelectionMode::Multi { "true" } else { "false" },
+                thead {
+                    class: "bg-gray-50",
+                    tr {
+                        for (index, column) in columns.iter().enumerate() {
+                            {
+                                let direction = column.sort_key.as_ref().and_then(|key| {
+                                    current_sort()
+                                        .filter(|(sorted, _)| sorted == key)
+                                        .map(|(_, direction)| direction)
+                                });
+                                let sort_key = column.sort_key.clone();
+                                rsx! {
+                                    th {
+                                        key: "{index}",
+                                        scope: "col",
+                                        aria_sort: direction.map_or("none", |d| d.aria_sort()),
+                                        class: format!(
+                                            "px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider {}",
+                                            if sort_key.is_some() { "cursor-pointer select-none hover:text-gray-700" } else { "" }
+                                        ),
+                                        onclick: move |_| {
+                                            let Some(key) = sort_key.clone() else {
+                                                return;
+                                            };
+                                            let direction = next_sort_direction(current_sort().as_ref(), &key);
+                                            current_sort.set(Some((key.clone(), direction)));
+                                            if let Some(handler) = &on_sort {
+                                                handler.call((key, direction));
+                                            }
+                                        },
+                                        "{column.header}"
+                                        if let Some(direction) = direction {
+                                            span {
+                                                class: "ml-1",
+                                                aria_hidden: "true",
+                                                "{direction.indicator()}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    class: "bg-white divide-y divide-gray-200",
+                    if rows.is_empty() {
+                        tr {
+                            td {
+                                colspan: "{columns.len()}",
+                                class: "px-4 py-6 text-center text-sm text-gray-500",
+                                "{empty_message}"
+                            }
+                        }
+                    }
+                    for row in rows.iter() {
+                        {
+                            let id = row_id.call(row.clone());
+                            let is_selected = selected.read().contains(&id);
+                            let select_id = id.clone();
+                            rsx! {
+                                tr {
+                                    key: "{id}",
+                                    aria_selected: if selectable == SelectionMode::None { None } else { Some(is_selected.to_string()) },
+                                    class: format!(
+                                        "{} {}",
+                                        if selectable == SelectionMode::None { "" } else { "cursor-pointer hover:bg-gray-50" },
+                                        if is_selected { "bg-blue-50" } else { "" }
+                                    ),
+                                    onclick: move |_| {
+                                        if selectable == SelectionMode::None {
+                                            return;
+                                        }
+                                        toggle_selection(&mut selected.write(), &select_id, selectable);
+                                        if let Some(handler) = &on_row_select {
+                                            handler.call(select_id.clone());
+                                        }
+                                    },
+                                    for (index, column) in columns.iter().enumerate() {
+                                        td {
+                                            key: "{index}",
+                                            class: "px-4 py-3 whitespace-nowrap text-sm text-gray-900",
+                                            {(column.render)(row)}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Direction a header click sorts `key` in, given the current sort
+fn next_sort_direction(current: Option<&(String, SortDirection)>, key: &str) -> SortDirection {
+    match current {
+        Some((sorted, direction)) if sorted == key => direction.toggled(),
+        _ => SortDirection::Ascending,
+    }
+}
+
+/// Apply a click on row `id` to the selected row IDs
+fn toggle_selection(selected: &mut Vec<String>, id: &str, mode: SelectionMode) {
+    let was_selected = selected.iter().any(|s| s == id);
+    match mode {
+        SelectionMode::None => {}
+        SelectionMode::Single => {
+            selected.clear();
+            if !was_selected {
+                selected.push(id.to_string());
+            }
+        }
+        SelectionMode::Multi if was_selected => selected.retain(|s| s != id),
+        SelectionMode::Multi => selected.push(id.to_string()),
+    }
+}
+
 /// Toggle/Switch component
 #[component]
 pub fn Toggle(
@@ -649,7 +924,11 @@ pub fn Toggle(
     }
 }
 
-/// Tooltip component (simple implementation)
+/// Hover tooltip shown on the `position` side of its children.
+///
+/// The bubble flips to the opposite side when the pointer is too close to
+/// that edge of the viewport. The native `title` stays as a fallback for
+/// assistive technology.
 #[component]
 pub fn Tooltip(
     #[props(default = "".to_string())] text: String,
@@ -657,15 +936,79 @@ pub fn Tooltip(
     #[props(default = "".to_string())] class: String,
     children: Element,
 ) -> Element {
+    let mut visible = use_signal(|| false);
+    let mut side = use_signal(|| position.clone());
+
+    let placement = match side().as_str() {
+        "bottom" => "top-full left-1/2 -translate-x-1/2 mt-2",
+        "left" => "right-full top-1/2 -translate-y-1/2 mr-2",
+        "right" => "left-full top-1/2 -translate-y-1/2 ml-2",
+        _ => "bottom-full left-1/2 -translate-x-1/2 mb-2",
+    };
+
     rsx! {
         div {
             class: format!("relative inline-block {}", class),
             title: "{text}",
+            onmouseenter: move |evt: MouseEvent| {
+                let pointer = evt.client_coordinates();
+                side.set(
+                    tooltip_side(&position, (pointer.x, pointer.y), viewport_size()).to_string(),
+                );
+                visible.set(true);
+            },
+            onmouseleave: move |_| visible.set(false),
             {children}
+            if visible() && !text.is_empty() {
+                div {
+                    class: format!(
+                        "absolute z-50 {} px-2 py-1 text-xs rounded shadow-lg pointer-events-none whitespace-normal",
+                        placement
+                    ),
+                    style: "background-color: var(--color-text-primary); color: var(--color-surface); width: max-content; max-width: min(16rem, calc(100vw - 1rem));",
+                    role: "tooltip",
+                    "{text}"
+                }
+            }
         }
     }
 }
 
+/// Space a tooltip needs between the pointer and the viewport edge, in pixels
+const TOOLTIP_EDGE_MARGIN: f64 = 48.0;
+
+/// Side to render a tooltip on, flipping `position` when the pointer is near that edge
+fn tooltip_side(position: &str, pointer: (f64, f64), viewport: Option<(f64, f64)>) -> &'static str {
+    let (x, y) = pointer;
+    let (width, height) = viewport.unwrap_or((f64::INFINITY, f64::INFINITY));
+    match position {
+        "bottom" if height - y < TOOLTIP_EDGE_MARGIN => "top",
+        "bottom" => "bottom",
+        "left" if x < TOOLTIP_EDGE_MARGIN * 4.0 => "right",
+        "left" => "left",
+        "right" if width - x < TOOLTIP_EDGE_MARGIN * 4.0 => "left",
+        "right" => "right",
+        _ if y < TOOLTIP_EDGE_MARGIN => "bottom",
+        _ => "top",
+    }
+}
+
+/// Inner size of the browser window, unknown on native
+fn viewport_size() -> Option<(f64, f64)> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let window = web_sys::window()?;
+        let width = window.inner_width().ok()?.as_f64()?;
+        let height = window.inner_height().ok()?.as_f64()?;
+        Some((width, height))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -690,6 +1033,41 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_input_with_error_and_debounce() {
+        let _input = rsx! {
+            FormField {
+                id: "search".to_string(),
+                error: "Too short".to_string(),
+                Input {
+                    id: "search".to_string(),
+                    error: "Too short".to_string(),
+                    debounce_ms: 300
+                }
+            }
+        };
+        assert_eq!(field_error_id("search"), "search-error");
+    }
+
+    #[test]
+    fn test_select_component() {
+        let _select = rsx! {
+            FormField {
+                label: "Status".to_string(),
+                id: "status".to_string(),
+                Select {
+                    id: "status".to_string(),
+                    options: vec![
+                        ("open".to_string(), "Open".to_string()),
+                        ("closed".to_string(), "Closed".to_string()),
+                    ],
+                    placeholder: "Choose a status".to_string(),
+                    required: true
+                }
+            }
+        };
+    }
+
     #[test]
     fn test_alert_component() {
         let _alert = rsx! {
@@ -710,5 +1088,84 @@ mod tests {
         };
         assert_eq!(tab.id, "test");
         assert_eq!(tab.count, Some(5));
+        assert_eq!(tab.aria_label(), "Test Tab (5)");
+    }
+
+    #[test]
+    fn test_data_table_component() {
+        let columns = vec![
+            TableColumn::new("Name", |row: &(String, u32)| rsx! { "{row.0}" }).sortable("name"),
+            TableColumn::new("Stock", |row: &(String, u32)| rsx! { "{row.1}" }),
+        ];
+        let _table = rsx! {
+            DataTable {
+                columns,
+                rows: vec![("Bolt".to_string(), 12), ("Nut".to_string(), 40)],
+                row_id: move |row: (String, u32)| row.0,
+                selectable: SelectionMode::Multi,
+                on_sort: move |_sort: (String, SortDirection)| {}
+            }
+        };
+    }
+
+    #[test]
+    fn test_data_table_sort_and_selection() {
+        let sorted = ("name".to_string(), SortDirection::Ascending);
+        assert_eq!(next_sort_direction(None, "name"), SortDirection::Ascending);
+        assert_eq!(
+            next_sort_direction(Some(&sorted), "name"),
+            SortDirection::Descending
+        );
+        assert_eq!(
+            next_sort_direction(Some(&sorted), "price"),
+            SortDirection::Ascending
+        );
+
+        let mut selected = Vec::new();
+        toggle_selection(&mut selected, "a", SelectionMode::Single);
+        toggle_selection(&mut selected, "b", SelectionMode::Single);
+        assert_eq!(selected, vec!["b".to_string()]);
+        toggle_selection(&mut selected, "b", SelectionMode::Single);
+        assert!(selected.is_empty());
+
+        toggle_selection(&mut selected, "a", SelectionMode::Multi);
+        toggle_selection(&mut selected, "b", SelectionMode::Multi);
+        toggle_selection(&mut selected, "a", SelectionMode::Multi);
+        assert_eq!(selected, vec!["b".to_string()]);
+
+        toggle_selection(&mut selected, "c", SelectionMode::None);
+        assert_eq!(selected, vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn test_tooltip_flips_away_from_viewport_edge() {
+        let viewport = Some((1024.0, 768.0));
+        assert_eq!(tooltip_side("top", (500.0, 400.0), viewport), "top");
+        assert_eq!(tooltip_side("top", (500.0, 10.0), viewport), "bottom");
+        assert_eq!(tooltip_side("bottom", (500.0, 760.0), viewport), "top");
+        assert_eq!(tooltip_side("right", (1000.0, 400.0), viewport), "left");
+        assert_eq!(tooltip_side("left", (20.0, 400.0), viewport), "right");
+        // Unknown viewport keeps the requested side
+        assert_eq!(tooltip_side("right", (1000.0, 400.0), None), "right");
+    }
+
+    #[test]
+    fn test_tab_keyboard_navigation_wraps() {
+        let tabs: Vec<TabItem> = ["one", "two", "three"]
+            .iter()
+            .map(|id| TabItem {
+                id: id.to_string(),
+                label: id.to_string(),
+                count: None,
+            })
+            .collect();
+
+        assert_eq!(tab_after_key(&tabs, "one", &Key::ArrowRight), Some(1));
+        assert_eq!(tab_after_key(&tabs, "three", &Key::ArrowRight), Some(0));
+        assert_eq!(tab_after_key(&tabs, "one", &Key::ArrowLeft), Some(2));
+        assert_eq!(tab_after_key(&tabs, "two", &Key::Home), Some(0));
+        assert_eq!(tab_after_key(&tabs, "two", &Key::End), Some(2));
+        assert_eq!(tab_after_key(&tabs, "two", &Key::Enter), None);
+        assert_eq!(tab_after_key(&[], "two", &Key::End), None);
     }
 }
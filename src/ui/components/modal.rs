@@ -0,0 +1,207 @@
+// src/ui/components/modal.rs - Accessible modal dialog
+
+use dioxus::prelude::*;
+
+use super::focus::focus_step;
+
+/// Modal component.
+///
+/// While shown, focus moves into the dialog and Tab/Shift+Tab cycle through
+/// its focusable children. `Escape` and the backdrop call `on_close`; when the
+/// modal closes, focus returns to the element that had it before.
+#[component]
+pub fn Modal(
+    #[props(default = false)] show: bool,
+    #[props(default = "".to_string())] title: String,
+    #[props(default = None)] on_close: Option<Callback<()>>,
+    #[props(default = "".to_string())] class: String,
+    children: Element,
+) -> Element {
+    if !show {
+        return rsx! { div { style: "display: none;" } };
+    }
+
+    rsx! {
+        ModalDialog { title, on_close, class, {children} }
+    }
+}
+
+/// Keys the modal handles itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModalKey {
+    Close,
+    FocusNext,
+    FocusPrevious,
+}
+
+fn modal_key(key: &Key, shift: bool) -> Option<ModalKey> {
+    match key {
+        Key::Escape => Some(ModalKey::Close),
+        Key::Tab if shift => Some(ModalKey::FocusPrevious),
+        Key::Tab => Some(ModalKey::FocusNext),
+        _ => None,
+    }
+}
+
+/// The open dialog; mounting and unmounting it moves focus in and back out
+#[component]
+fn ModalDialog(
+    title: String,
+    on_close: Option<Callback<()>>,
+    class: String,
+    children: Element,
+) -> Element {
+    let dialog_id = use_hook(|| format!("modal-{}", crate::utils::rand::uuid().simple()));
+    let title_id = format!("{}-title", dialog_id);
+
+    let open_id = dialog_id.clone();
+    use_effect(move || {
+        document::eval(&save_focus_script(&open_id));
+        focus_step(&open_id, 1);
+    });
+
+    let close_id = dialog_id.clone();
+    use_drop(move || {
+        document::eval(&restore_focus_script(&close_id));
+    });
+
+    let close = move || {
+        if let Some(handler) = &on_close {
+            handler.call(());
+        }
+    };
+
+    let trap_id = dialog_id.clone();
+    let onkeydown = move |event: KeyboardEvent| {
+        let Some(action) = modal_key(&event.key(), event.modifiers().contains(Modifiers::SHIFT))
+        else {
+            return;
+        };
+        event.prevent_default();
+        match action {
+            ModalKey::Close => close(),
+            ModalKey::FocusNext => focus_step(&trap_id, 1),
+            ModalKey::FocusPrevious => focus_step(&trap_id, -1),
+        }
+    };
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 overflow-y-auto",
+            onkeydown,
+
+            // Backdrop
+            div {
+                class: "fixed inset-0 bg-gray-500 bg-opacity-75 transition-opacity",
+                onclick: move |_| close()
+            }
+
+            // Modal content
+            div {
+                class: "flex min-h-full items-end justify-center p-4 text-center sm:items-center sm:p-0",
+                div {
+                    id: "{dialog_id}",
+                    role: "dialog",
+                    aria_modal: "true",
+                    aria_labelledby: if !title.is_empty() { Some(title_id.clone()) } else { None },
+                    tabindex: "-1",
+                    class: format!(
+                        "relative transform overflow-hidden rounded-lg bg-white text-left shadow-xl transition-all sm:my-8 sm:w-full sm:max-w-lg {}",
+                        class
+                    ),
+                    onclick: |evt| evt.stop_propagation(),
+
+                    if !title.is_empty() {
+                        div {
+                            class: "bg-white px-4 pb-4 pt-5 sm:p-6 sm:pb-4",
+                            div {
+                                class: "flex items-start justify-between",
+                                h3 {
+                                    id: "{title_id}",
+                                    class: "text-lg font-medium leading-6 text-gray-900",
+                                    "{title}"
+                                }
+                                if on_close.is_some() {
+                                    button {
+                                        r#type: "button",
+                                        class: "rounded-md bg-white text-gray-400 hover:text-gray-600 focus:outline-none focus:ring-2 focus:ring-blue-500",
+                                        onclick: move |_| close(),
+                                        span {
+                                            class: "sr-only",
+                                            "Close"
+                                        }
+                                        svg {
+                                            class: "h-6 w-6",
+                                            xmlns: "http://www.w3.org/2000/svg",
+                                            fill: "none",
+                                            view_box: "0 0 24 24",
+                                            stroke: "currentColor",
+                                            path {
+                                                stroke_linecap: "round",
+                                                stroke_linejoin: "round",
+                                                stroke_width: "2",
+                                                d: "M6 18L18 6M6 6l12 12"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div {
+                        class: "px-4 pb-4 pt-5 sm:p-6",
+                        {children}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Global map from dialog id to the element focused before it opened
+const FOCUS_REGISTRY: &str = "window.__qorzenModalFocus";
+
+fn save_focus_script(dialog_id: &str) -> String {
+    format!(
+        r#"
+        {registry} = {registry} || new Map();
+        {registry}.set("{dialog_id}", document.activeElement);
+        "#,
+        registry = FOCUS_REGISTRY,
+    )
+}
+
+fn restore_focus_script(dialog_id: &str) -> String {
+    format!(
+        r#"
+        const previous = {registry} && {registry}.get("{dialog_id}");
+        if (previous) {{
+            {registry}.delete("{dialog_id}");
+            if (document.contains(previous) && previous.focus) previous.focus();
+        }}
+        "#,
+        registry = FOCUS_REGISTRY,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modal_keys() {
+        assert_eq!(modal_key(&Key::Escape, false), Some(ModalKey::Close));
+        assert_eq!(modal_key(&Key::Tab, false), Some(ModalKey::FocusNext));
+        assert_eq!(modal_key(&Key::Tab, true), Some(ModalKey::FocusPrevious));
+        assert_eq!(modal_key(&Key::Enter, false), None);
+    }
+
+    #[test]
+    fn test_focus_is_restored_for_the_same_dialog() {
+        let save = save_focus_script("modal-1");
+        let restore = restore_focus_script("modal-1");
+        assert!(save.contains(r#".set("modal-1", document.activeElement)"#));
+        assert!(restore.contains(r#".get("modal-1")"#));
+    }
+}
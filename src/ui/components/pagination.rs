@@ -0,0 +1,224 @@
+// src/ui/components/pagination.rs - Numbered page navigation for list views
+
+use dioxus::prelude::*;
+
+/// Pages shown on each side of the current page by default
+pub const DEFAULT_SIBLING_COUNT: u32 = 1;
+
+/// One entry in a pagination bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageItem {
+    Page(u32),
+    /// A run of pages left out between two shown ones
+    Ellipsis,
+}
+
+/// Entries to show for `current_page` of `total_pages`.
+///
+/// The first and last page are always shown, together with `sibling_count`
+/// pages either side of the current one. Skipped pages collapse into an
+/// ellipsis, so the bar keeps the same width while paging.
+pub fn page_items(current_page: u32, total_pages: u32, sibling_count: u32) -> Vec<PageItem> {
+    if total_pages == 0 {
+        return Vec::new();
+    }
+
+    // First, last, current and two ellipses around the siblings
+    let slots = 2 * sibling_count + 5;
+    if total_pages <= slots {
+        return (1..=total_pages).map(PageItem::Page).collect();
+    }
+
+    let current = current_page.clamp(1, total_pages);
+    let left = current.saturating_sub(sibling_count).max(1);
+    let right = current.saturating_add(sibling_count).min(total_pages);
+    let edge_run = slots - 2;
+
+    let mut items = Vec::with_capacity(slots as usize);
+    match (left > 2, right < total_pages - 1) {
+        (false, _) => {
+            items.extend((1..=edge_run).map(PageItem::Page));
+            items.push(PageItem::Ellipsis);
+            items.push(PageItem::Page(total_pages));
+        }
+        (true, false) => {
+            items.push(PageItem::Page(1));
+            items.push(PageItem::Ellipsis);
+            items.extend((total_pages - edge_run + 1..=total_pages).map(PageItem::Page));
+        }
+        (true, true) => {
+            items.push(PageItem::Page(1));
+            items.push(PageItem::Ellipsis);
+            items.extend((left..=right).map(PageItem::Page));
+            items.push(PageItem::Ellipsis);
+            items.push(PageItem::Page(total_pages));
+        }
+    }
+    items
+}
+
+/// Previous/next buttons around numbered pages.
+///
+/// Pages are 1-based. Previous is disabled on the first page and next on the
+/// last; long ranges are truncated as described in [`page_items`]. Renders
+/// nothing when there are no pages.
+#[component]
+pub fn Pagination(
+    current_page: u32,
+    total_pages: u32,
+    on_page_change: Callback<u32>,
+    #[props(default = DEFAULT_SIBLING_COUNT)] sibling_count: u32,
+    #[props(default = "".to_string())] class: String,
+) -> Element {
+    if total_pages == 0 {
+        return rsx! {};
+    }
+
+    let current = current_page.clamp(1, total_pages);
+    let items = page_items(current, total_pages, sibling_count);
+    let has_previous = current > 1;
+    let has_next = current < total_pages;
+
+    let step_classes = "px-3 py-2 text-sm font-medium rounded-md border border-gray-300 bg-white text-gray-700 hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-blue-500 disabled:opacity-50 disabled:cursor-not-allowed";
+
+    rsx! {
+        nav {
+            class: format!("flex items-center justify-center space-x-1 {}", class),
+            aria_label: "Pagination",
+            button {
+                r#type: "button",
+                class: step_classes,
+                disabled: !has_previous,
+                aria_label: "Previous page",
+                onclick: move |_| {
+                    if has_previous {
+                        on_page_change.call(current - 1);
+                    }
+                },
+                "Previous"
+            }
+            for (index, item) in items.into_iter().enumerate() {
+                match item {
+                    PageItem::Page(page) => rsx! {
+                        button {
+                            key: "page-{page}",
+                            r#type: "button",
+                            class: format!(
+                                "px-3 py-2 text-sm font-medium rounded-md border focus:outline-none focus:ring-2 focus:ring-blue-500 {}",
+                                if page == current {
+                                    "border-blue-500 bg-blue-50 text-blue-600"
+                                } else {
+                                    "border-gray-300 bg-white text-gray-700 hover:bg-gray-50"
+                                }
+                            ),
+                            aria_label: "Page {page}",
+                            aria_current: if page == current { "page" } else { "false" },
+                            onclick: move |_| {
+                                if page != current {
+                                    on_page_change.call(page);
+                                }
+                            },
+                            "{page}"
+                        }
+                    },
+                    PageItem::Ellipsis => rsx! {
+                        span {
+                            key: "ellipsis-{index}",
+                            class: "px-2 py-2 text-sm text-gray-500",
+                            aria_hidden: "true",
+                            "…"
+                        }
+                    },
+                }
+            }
+            button {
+                r#type: "button",
+                class: step_classes,
+                disabled: !has_next,
+                aria_label: "Next page",
+                onclick: move |_| {
+                    if has_next {
+                        on_page_change.call(current + 1);
+                    }
+                },
+                "Next"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PageItem::{Ellipsis, Page};
+
+    #[test]
+    fn test_page_items_truncate_long_ranges() {
+        assert_eq!(page_items(1, 0, 1), vec![]);
+        assert_eq!(page_items(2, 5, 1), (1..=5).map(Page).collect::<Vec<_>>());
+
+        assert_eq!(
+            page_items(1, 20, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(20)
+            ]
+        );
+        assert_eq!(
+            page_items(10, 20, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(9),
+                Page(10),
+                Page(11),
+                Ellipsis,
+                Page(20)
+            ]
+        );
+        assert_eq!(
+            page_items(20, 20, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(16),
+                Page(17),
+                Page(18),
+                Page(19),
+                Page(20)
+            ]
+        );
+        assert_eq!(
+            page_items(10, 20, 2),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(8),
+                Page(9),
+                Page(10),
+                Page(11),
+                Page(12),
+                Ellipsis,
+                Page(20)
+            ]
+        );
+        // Out-of-range pages are clamped
+        assert_eq!(page_items(99, 20, 1), page_items(20, 20, 1));
+    }
+
+    #[test]
+    fn test_pagination_component() {
+        let _pagination = rsx! {
+            Pagination {
+                current_page: 3,
+                total_pages: 12,
+                on_page_change: move |_page: u32| {}
+            }
+        };
+    }
+}
@@ -0,0 +1,111 @@
+// src/ui/components/toasts.rs - Stacked toasts for app state notifications
+
+use dioxus::prelude::*;
+
+use super::{Alert, Button};
+use crate::ui::palette::parse_route;
+use crate::ui::state::{use_app_dispatch, use_app_state, AppAction};
+use crate::ui::Animations;
+
+/// Renders unread notifications as toasts stacked in the top-right corner.
+///
+/// Dismissing a toast removes the notification. Clicking an action passes
+/// `(notification id, action)` to `on_action` when given; otherwise actions
+/// that name an internal route navigate there. Either way the toast closes.
+#[component]
+pub fn Toasts(
+    #[props(default = None)] on_action: Option<EventHandler<(uuid::Uuid, String)>>,
+) -> Element {
+    let app_state = use_app_state();
+    let dispatch = use_app_dispatch();
+    let navigator = use_navigator();
+    let animation = slide_in_animation(&app_state.current_theme.animations);
+
+    rsx! {
+        style { {TOAST_KEYFRAMES} }
+        div {
+            class: "fixed top-4 right-4 z-50 flex w-80 max-w-full flex-col gap-3",
+            aria_live: "polite",
+            for notification in app_state.notifications.iter().filter(|n| !n.read).cloned() {
+                div {
+                    key: "{notification.id}",
+                    class: "shadow-lg rounded-md",
+                    style: "animation: {animation};",
+                    Alert {
+                        variant: notification.notification_type.alert_variant().to_string(),
+                        title: notification.title.clone(),
+                        dismissible: true,
+                        on_dismiss: move |_| dispatch(AppAction::RemoveNotification(notification.id)),
+                        p { "{notification.message}" }
+                        if !notification.actions.is_empty() {
+                            div {
+                                class: "mt-3 flex gap-2",
+                                for action in notification.actions.clone() {
+                                    Button {
+                                        variant: action.style.button_variant().to_string(),
+                                        size: "xs".to_string(),
+                                        onclick: move |_| {
+                                            match &on_action {
+                                                Some(handler) => handler.call((notification.id, action.action.clone())),
+                                                None => {
+                                                    if let Some(route) = parse_route(&action.action) {
+                                                        navigator.push(route);
+                                                    }
+                                                }
+                                            }
+                                            dispatch(AppAction::RemoveNotification(notification.id));
+                                        },
+                                        "{action.label}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+const TOAST_KEYFRAMES: &str = "@keyframes toast-slide-in { from { transform: translateX(110%); opacity: 0; } to { transform: translateX(0); opacity: 1; } }";
+
+/// CSS `animation` value for the slide-in, using the theme's timing
+fn slide_in_animation(animations: &Animations) -> String {
+    let duration = match animations.duration_normal.trim() {
+        "" => "300ms",
+        duration => duration,
+    };
+    let easing = match animations.easing.trim() {
+        "" => "ease-out",
+        easing => easing,
+    };
+    format!("toast-slide-in {} {}", duration, easing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{ActionStyle, NotificationType};
+
+    #[test]
+    fn test_variant_mapping_and_animation() {
+        assert_eq!(NotificationType::Error.alert_variant(), "error");
+        assert_eq!(NotificationType::Success.alert_variant(), "success");
+        assert_eq!(ActionStyle::Danger.button_variant(), "danger");
+        assert_eq!(ActionStyle::Link.button_variant(), "ghost");
+
+        let animations = Animations {
+            duration_normal: "250ms".to_string(),
+            easing: "linear".to_string(),
+            ..Animations::default()
+        };
+        assert_eq!(
+            slide_in_animation(&animations),
+            "toast-slide-in 250ms linear"
+        );
+        assert_eq!(
+            slide_in_animation(&Animations::default()),
+            "toast-slide-in 300ms ease-out"
+        );
+    }
+}
@@ -0,0 +1,472 @@
+// src/ui/forms.rs - Form state with draft autosave
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::SettingsSchema;
+use crate::error::{Error, ErrorKind, Result};
+use crate::platform::storage::StorageArc;
+use crate::platform::MemoryStorage;
+use crate::plugin::{SettingField, SettingKind};
+use crate::ui::components::{Alert, Button, FormField, Input, Select, Toggle};
+use crate::ui::state::{sleep, use_app_state};
+use crate::utils::Time;
+
+/// Field values of a form, keyed by field name
+pub type FormValues = BTreeMap<String, String>;
+
+/// Key prefix for form drafts in the platform key-value store
+pub const FORM_DRAFT_PREFIX: &str = "form_draft:";
+
+/// How often [`use_form`] saves unsubmitted changes
+pub const DEFAULT_AUTOSAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Unsubmitted form values saved by autosave
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FormDraft {
+    pub values: FormValues,
+    pub saved_at: DateTime<Utc>,
+}
+
+/// Saves form drafts to a key-value store, one per form and user
+#[derive(Clone)]
+pub struct DraftStore {
+    storage: StorageArc,
+}
+
+impl DraftStore {
+    pub fn new(storage: StorageArc) -> Self {
+        Self { storage }
+    }
+
+    /// Store backed by the platform's persistent storage, or memory if it is unavailable
+    pub fn platform_default() -> Self {
+        let storage = crate::platform::default_storage()
+            .unwrap_or_else(|_| std::sync::Arc::new(MemoryStorage::new()));
+        Self::new(storage)
+    }
+
+    /// Storage key of a draft; signed-out drafts share the `anonymous` namespace
+    pub fn draft_key(form_id: &str, user_id: Option<uuid::Uuid>) -> String {
+        let user = user_id.map_or_else(|| "anonymous".to_string(), |id| id.to_string());
+        format!("{}{}:{}", FORM_DRAFT_PREFIX, user, form_id)
+    }
+
+    pub async fn save(
+        &self,
+        form_id: &str,
+        user_id: Option<uuid::Uuid>,
+        values: &FormValues,
+    ) -> Result<FormDraft> {
+        let draft = FormDraft {
+            values: values.clone(),
+            saved_at: Time::now(),
+        };
+        let bytes = serde_json::to_vec(&draft)
+            .map_err(|e| Error::new(ErrorKind::Serialization, e.to_string()))?;
+        self.storage
+            .set(&Self::draft_key(form_id, user_id), &bytes)
+            .await?;
+        Ok(draft)
+    }
+
+    /// The saved draft, ignoring drafts that no longer parse
+    pub async fn load(
+        &self,
+        form_id: &str,
+        user_id: Option<uuid::Uuid>,
+    ) -> Result<Option<FormDraft>> {
+        let bytes = self.storage.get(&Self::draft_key(form_id, user_id)).await?;
+        Ok(bytes.and_then(|bytes| serde_json::from_slice(&bytes).ok()))
+    }
+
+    pub async fn clear(&self, form_id: &str, user_id: Option<uuid::Uuid>) -> Result<()> {
+        let key = Self::draft_key(form_id, user_id);
+        if self.storage.get(&key).await?.is_some() {
+            self.storage.delete(&key).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Form state returned by [`use_form`]
+#[derive(Clone)]
+pub struct UseForm {
+    form_id: String,
+    user_id: Option<uuid::Uuid>,
+    store: DraftStore,
+    pub values: Signal<FormValues>,
+    /// Draft found when the form opened, waiting for the user to restore or discard it
+    pub pending_draft: Signal<Option<FormDraft>>,
+    saved: Signal<FormValues>,
+}
+
+impl UseForm {
+    pub fn value(&self, field: &str) -> String {
+        self.values.read().get(field).cloned().unwrap_or_default()
+    }
+
+    pub fn set(&self, field: &str, value: impl Into<String>) {
+        let mut values = self.values;
+        values.write().insert(field.to_string(), value.into());
+    }
+
+    /// Replaces the current values with the pending draft
+    pub fn restore_draft(&self) {
+        let (mut values, mut pending) = (self.values, self.pending_draft);
+        if let Some(draft) = pending.take() {
+            values.set(draft.values);
+        }
+    }
+
+    /// Drops the pending draft and deletes it from storage
+    pub fn discard_draft(&self) {
+        let mut pending = self.pending_draft;
+        pending.set(None);
+        self.clear_draft();
+    }
+
+    /// Call after a successful submit so the draft is not offered again
+    pub fn submitted(&self) {
+        let mut saved = self.saved;
+        saved.set(self.values.peek().clone());
+        self.clear_draft();
+    }
+
+    fn clear_draft(&self) {
+        let (store, form_id, user_id) = (self.store.clone(), self.form_id.clone(), self.user_id);
+        spawn(async move {
+            if let Err(e) = store.clear(&form_id, user_id).await {
+                tracing::warn!("Failed to clear draft of form {}: {}", form_id, e);
+            }
+        });
+    }
+}
+
+/// Form values that autosave as a draft for the signed-in user.
+///
+/// Changes are saved every [`DEFAULT_AUTOSAVE_INTERVAL`]. When a draft exists
+/// on open it is offered through `pending_draft` (see [`DraftPrompt`]) and
+/// autosave waits until the user restores or discards it. The store comes
+/// from a `DraftStore` context, falling back to platform storage.
+pub fn use_form(form_id: &str, initial: FormValues) -> UseForm {
+    let user_id = use_app_state().current_user.map(|user| user.id);
+    let store = use_hook(|| {
+        try_consume_context::<DraftStore>().unwrap_or_else(DraftStore::platform_default)
+    });
+    let values = use_signal(|| initial.clone());
+    let saved = use_signal(|| initial);
+    let pending_draft = use_signal(|| None);
+
+    let form = UseForm {
+        form_id: form_id.to_string(),
+        user_id,
+        store,
+        values,
+        pending_draft,
+        saved,
+    };
+
+    let autosave = form.clone();
+    use_hook(move || {
+        spawn(async move {
+            let UseForm {
+                form_id,
+                user_id,
+                store,
+                values,
+                mut pending_draft,
+                mut saved,
+            } = autosave;
+
+            if let Ok(Some(draft)) = store.load(&form_id, user_id).await {
+                pending_draft.set(Some(draft));
+            }
+
+            loop {
+                sleep(DEFAULT_AUTOSAVE_INTERVAL).await;
+                if pending_draft.peek().is_some() {
+                    continue;
+                }
+
+                let current = values.peek().clone();
+                if current == *saved.peek() {
+                    continue;
+                }
+                match store.save(&form_id, user_id, &current).await {
+                    Ok(_) => saved.set(current),
+                    Err(e) => tracing::warn!("Failed to save draft of form {}: {}", form_id, e),
+                }
+            }
+        })
+    });
+
+    form
+}
+
+/// Asks whether to restore a draft left by an earlier, unsubmitted edit
+#[component]
+pub fn DraftPrompt(
+    draft: Option<FormDraft>,
+    on_restore: EventHandler<()>,
+    on_discard: EventHandler<()>,
+) -> Element {
+    let Some(draft) = draft else {
+        return rsx! {};
+    };
+    let saved_at = draft.saved_at.format("%b %-d, %H:%M").to_string();
+
+    rsx! {
+        Alert {
+            variant: "info".to_string(),
+            title: "Restore draft?".to_string(),
+            class: "mb-4".to_string(),
+            p { "You have unsaved changes from {saved_at}." }
+            div {
+                class: "mt-3 flex gap-2",
+                Button {
+                    size: "sm".to_string(),
+                    onclick: move |_| on_restore.call(()),
+                    "Restore"
+                }
+                Button {
+                    variant: "secondary".to_string(),
+                    size: "sm".to_string(),
+                    onclick: move |_| on_discard.call(()),
+                    "Discard"
+                }
+            }
+        }
+    }
+}
+
+/// Form for a plugin's settings, generated from its schema.
+///
+/// `current` is the settings object as stored; nested objects are edited one
+/// level deep and reported with dotted keys such as `sync.interval`.
+pub fn render_settings_form(
+    schema: &SettingsSchema,
+    current: &serde_json::Value,
+    on_change: Callback<(String, serde_json::Value)>,
+) -> Element {
+    let fields = SettingField::from_schema(schema);
+    let values = settings_values(&fields, current);
+
+    rsx! {
+        SchemaForm { fields, values, on_change }
+    }
+}
+
+/// Current values of `fields` in a stored settings object, by dotted key
+fn settings_values(
+    fields: &[SettingField],
+    current: &serde_json::Value,
+) -> serde_json::Map<String, serde_json::Value> {
+    SettingField::leaves(fields)
+        .filter_map(|field| {
+            let value = field
+                .key
+                .split('.')
+                .try_fold(current, |value, part| value.get(part))?;
+            Some((field.key.clone(), value.clone()))
+        })
+        .collect()
+}
+
+/// Form generated from settings schema fields.
+///
+/// `values` holds the current setting values; each edit calls `on_change`
+/// with the key and the parsed value, `null` when the input is empty or
+/// invalid. Keys in `missing` are shown as required-but-empty errors.
+/// Group fields render as a fieldset of their members.
+#[component]
+pub fn SchemaForm(
+    fields: Vec<SettingField>,
+    values: serde_json::Map<String, serde_json::Value>,
+    #[props(default = Vec::new())] missing: Vec<String>,
+    on_change: EventHandler<(String, serde_json::Value)>,
+) -> Element {
+    let field_view = |field: SettingField| {
+        rsx! {
+            SchemaFormField {
+                key: "{field.key}",
+                error: missing
+                    .contains(&field.key)
+                    .then(|| format!("{} is required", field.label)),
+                value: values.get(&field.key).cloned().unwrap_or_default(),
+                field,
+                on_change,
+            }
+        }
+    };
+
+    rsx! {
+        div {
+            class: "space-y-4",
+            for field in fields {
+                if field.kind == SettingKind::Group {
+                    fieldset {
+                        key: "{field.key}",
+                        class: "space-y-4 rounded-md border border-gray-200 p-4",
+                        legend {
+                            class: "px-1 text-sm font-medium text-gray-700",
+                            "{field.label}"
+                        }
+                        if let Some(description) = &field.description {
+                            p { class: "text-sm text-gray-500", "{description}" }
+                        }
+                        for member in field.fields.clone() {
+                            {field_view(member)}
+                        }
+                    }
+                } else {
+                    {field_view(field)}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn SchemaFormField(
+    field: SettingField,
+    value: serde_json::Value,
+    error: Option<String>,
+    on_change: EventHandler<(String, serde_json::Value)>,
+) -> Element {
+    let id = format!("setting-{}", field.key);
+    let text = match &value {
+        serde_json::Value::Null => String::new(),
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+
+    let input_type = match field.kind {
+        SettingKind::Text => "text",
+        _ => "number",
+    };
+    let key = field.key.clone();
+    let parser = field.clone();
+    let on_text = move |raw: String| on_change.call((key.clone(), parser.parse(&raw)));
+
+    rsx! {
+        FormField {
+            label: field.label.clone(),
+            id: id.clone(),
+            required: field.required,
+            error: error.clone(),
+            help_text: field.description.clone(),
+            {
+                match field.kind {
+                    SettingKind::Boolean => rsx! {
+                        Toggle {
+                            checked: value.as_bool().unwrap_or(false),
+                            on_change: {
+                                let key = field.key.clone();
+                                move |checked: bool| on_change.call((key.clone(), checked.into()))
+                            },
+                        }
+                    },
+                    SettingKind::Choice => rsx! {
+                        Select {
+                            id: id.clone(),
+                            options: field
+                                .options
+                                .iter()
+                                .map(|option| (option.clone(), option.clone()))
+                                .collect::<Vec<_>>(),
+                            value: text,
+                            placeholder: format!("Choose {}", field.label),
+                            required: field.required,
+                            error: error.clone(),
+                            onchange: on_text,
+                        }
+                    },
+                    SettingKind::Group => rsx! {},
+                    SettingKind::Text | SettingKind::Number | SettingKind::Integer => rsx! {
+                        Input {
+                            id: id.clone(),
+                            input_type: input_type.to_string(),
+                            value: text,
+                            min: field.minimum.map(|min| min.to_string()),
+                            max: field.maximum.map(|max| max.to_string()),
+                            required: field.required,
+                            error: error.clone(),
+                            oninput: move |evt: FormEvent| on_text(evt.value()),
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_unsubmitted_draft_is_restored() {
+        let store = DraftStore::new(Arc::new(MemoryStorage::new()));
+        let user = Some(uuid::Uuid::new_v4());
+        let values: FormValues = [
+            ("name".to_string(), "Widget".to_string()),
+            ("price".to_string(), "9.99".to_string()),
+        ]
+        .into();
+
+        store.save("product.create", user, &values).await.unwrap();
+
+        // Reopening the form finds the draft for the same user only
+        let draft = store.load("product.create", user).await.unwrap().unwrap();
+        assert_eq!(draft.values, values);
+        assert!(store.load("product.create", None).await.unwrap().is_none());
+        assert!(store.load("product.edit", user).await.unwrap().is_none());
+
+        // Submitting clears it
+        store.clear("product.create", user).await.unwrap();
+        assert!(store.load("product.create", user).await.unwrap().is_none());
+        store.clear("product.create", user).await.unwrap();
+    }
+
+    #[test]
+    fn test_settings_form_values() {
+        let schema = SettingsSchema {
+            version: "1".to_string(),
+            schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "enabled": { "type": "boolean", "title": "Enabled" },
+                    "unit": { "type": "string", "enum": ["kg", "lb"] },
+                    "sync": {
+                        "type": "object",
+                        "properties": {
+                            "interval": { "type": "integer", "minimum": 1, "maximum": 60 }
+                        }
+                    }
+                }
+            }),
+            defaults: serde_json::json!({}),
+        };
+        let current = serde_json::json!({ "enabled": true, "sync": { "interval": 5 } });
+
+        let fields = SettingField::from_schema(&schema);
+        let mut keys: Vec<_> = SettingField::leaves(&fields)
+            .map(|field| field.key.as_str())
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["enabled", "sync.interval", "unit"]);
+
+        // Nested values are read by dotted key; unset settings are left out
+        let values = settings_values(&fields, &current);
+        assert_eq!(values.len(), 2);
+        assert_eq!(values["enabled"], serde_json::json!(true));
+        assert_eq!(values["sync.interval"], serde_json::json!(5));
+        assert!(!values.contains_key("unit"));
+    }
+}
@@ -29,8 +29,7 @@ pub fn Header(props: HeaderProps) -> Element {
     let mut user_menu_open = use_signal(|| false);
     let mut notifications_open = use_signal(|| false);
 
-    // Count unread notifications
-    let unread_count = notifications.iter().filter(|n| !n.read).count();
+    let unread_count = app_state.unread_count();
 
     let left_side_mobile_button = rsx! {
         // Mobile menu button
@@ -3,7 +3,10 @@
 use dioxus::prelude::*;
 
 use crate::ui::{
+    components::Toasts,
     layout::{Footer, Header, Sidebar},
+    palette::{is_palette_shortcut, CommandPalette},
+    registry::{Slot, SLOT_FOOTER, SLOT_HEADER, SLOT_SIDEBAR},
     state::{ui::use_mobile_menu, ui::use_sidebar, use_app_state},
 };
 
@@ -19,14 +22,33 @@ pub fn Layout(#[props] children: Element) -> Element {
         set_mobile_menu_open(false);
     });
 
+    // Cmd/Ctrl+K toggles the command palette
+    let mut palette_open = use_signal(|| false);
+    let toggle_palette = move |event: KeyboardEvent| {
+        if is_palette_shortcut(&event) {
+            event.prevent_default();
+            palette_open.toggle();
+        }
+    };
+
     rsx! {
         div {
             class: "min-h-screen bg-gray-50 flex flex-col",
+            tabindex: "-1",
+            onkeydown: toggle_palette,
+
+            if palette_open() {
+                CommandPalette { on_close: move |_| palette_open.set(false) }
+            }
+
+            Toasts {}
 
             // Header
-            Header {
-                on_menu_toggle: toggle_mobile_menu,
-                on_sidebar_toggle: toggle_sidebar
+            Slot { id: SLOT_HEADER.to_string(),
+                Header {
+                    on_menu_toggle: toggle_mobile_menu,
+                    on_sidebar_toggle: toggle_sidebar
+                }
             }
 
             // Main content area with sidebar
@@ -34,10 +56,12 @@ pub fn Layout(#[props] children: Element) -> Element {
                 class: "flex flex-1 overflow-hidden",
 
                 // Sidebar
-                Sidebar {
-                    collapsed: sidebar_collapsed,
-                    mobile_open: mobile_menu_open,
-                    on_close: close_mobile_menu
+                Slot { id: SLOT_SIDEBAR.to_string(),
+                    Sidebar {
+                        collapsed: sidebar_collapsed,
+                        mobile_open: mobile_menu_open,
+                        on_close: close_mobile_menu
+                    }
                 }
 
                 // Main content
@@ -127,7 +151,9 @@ pub fn Layout(#[props] children: Element) -> Element {
             }
 
             // Footer
-            Footer {}
+            Slot { id: SLOT_FOOTER.to_string(),
+                Footer {}
+            }
         }
     }
 }
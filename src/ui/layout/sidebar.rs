@@ -5,8 +5,10 @@ use dioxus::prelude::*;
 use dioxus_router::prelude::*;
 
 use crate::ui::{
+    filter_navigation,
     router::{nav, Route},
-    state::auth::use_has_permission,
+    state::{auth::use_has_permission, use_app_state},
+    user_permission_check, NavigationItem as LayoutNavigationItem, UIComponentRegistry,
 };
 
 /// Sidebar component props
@@ -33,10 +35,30 @@ pub struct NavItem {
     pub external_url: Option<String>,
 }
 
+impl From<&LayoutNavigationItem> for NavItem {
+    fn from(item: &LayoutNavigationItem) -> Self {
+        let route = match item.route.parse::<Route>() {
+            Ok(Route::NotFound { .. }) | Err(_) => None,
+            Ok(route) => Some(route),
+        };
+
+        Self {
+            id: item.id.clone(),
+            label: item.label.clone(),
+            icon: item.icon.clone().unwrap_or_else(|| "🧩".to_string()),
+            route,
+            children: item.children.iter().map(NavItem::from).collect(),
+            required_permission: None,
+            badge: item.badge.as_ref().map(|badge| badge.text.clone()),
+            external_url: None,
+        }
+    }
+}
+
 /// Main sidebar component
 #[component]
 pub fn Sidebar(props: SidebarProps) -> Element {
-    // let app_state = use_app_state();
+    let app_state = use_app_state();
     let current_route = use_route::<Route>();
     let has_permission = use_has_permission();
 
@@ -55,6 +77,18 @@ pub fn Sidebar(props: SidebarProps) -> Element {
         })
         .collect::<Vec<_>>();
 
+    // Plugin menus merged into the layout, limited to what the user may see
+    let hierarchy = try_use_context::<UIComponentRegistry>()
+        .unwrap_or_else(UIComponentRegistry::shared)
+        .permission_hierarchy();
+    let plugin_nav_items = filter_navigation(
+        &app_state.current_layout.sidebar.navigation_items,
+        &user_permission_check(app_state.current_user.as_ref(), &hierarchy),
+    )
+    .iter()
+    .map(NavItem::from)
+    .collect::<Vec<_>>();
+
     rsx! {
         // Desktop sidebar
         div {
@@ -77,14 +111,24 @@ pub fn Sidebar(props: SidebarProps) -> Element {
                 }
 
                 // Plugin section
-                if !props.collapsed {
+                if !props.collapsed && !plugin_nav_items.is_empty() {
                     div {
                         class: "px-2 mt-6",
                         div {
                             class: "text-xs font-semibold text-gray-400 uppercase tracking-wide mb-2",
                             "Plugins"
                         }
-                        PluginNavigation {}
+                        div {
+                            class: "space-y-1",
+                            for item in &plugin_nav_items {
+                                NavigationItem {
+                                    key: "{item.id}",
+                                    item: item.clone(),
+                                    collapsed: false,
+                                    current_route: current_route.clone()
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -144,14 +188,25 @@ pub fn Sidebar(props: SidebarProps) -> Element {
                         }
 
                         // Mobile plugin section
-                        div {
-                            class: "px-2 mt-6",
+                        if !plugin_nav_items.is_empty() {
                             div {
-                                class: "text-xs font-semibold text-gray-400 uppercase tracking-wide mb-2",
-                                "Plugins"
-                            }
-                            PluginNavigation {
-                                on_click: Some(props.on_close)
+                                class: "px-2 mt-6",
+                                div {
+                                    class: "text-xs font-semibold text-gray-400 uppercase tracking-wide mb-2",
+                                    "Plugins"
+                                }
+                                div {
+                                    class: "space-y-1",
+                                    for item in &plugin_nav_items {
+                                        NavigationItem {
+                                            key: "{item.id}",
+                                            item: item.clone(),
+                                            collapsed: false,
+                                            current_route: current_route.clone(),
+                                            on_click: Some(props.on_close)
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
@@ -323,43 +378,6 @@ fn NavigationItem(
     }
 }
 
-/// Plugin navigation section
-#[component]
-fn PluginNavigation(
-    #[props(default = None)] on_click: Option<Callback<Event<MouseData>>>,
-) -> Element {
-    // Mock plugin data - in real app this would come from plugin manager
-    let plugins = vec![
-        ("inventory", "📦", "Inventory"),
-        ("sales", "💰", "Sales"),
-        ("reports", "📊", "Reports"),
-        ("users", "👥", "User Management"),
-    ];
-
-    rsx! {
-        div {
-            class: "space-y-1",
-            for (plugin_id, icon, label) in plugins {
-                Link {
-                    key: "{plugin_id}",
-                    to: Route::Plugin { plugin_id: plugin_id.to_string() },
-                    class: "group flex items-center px-2 py-2 text-sm font-medium rounded-md text-gray-600 hover:bg-gray-50 hover:text-gray-900",
-                    onclick: move |e| {
-                        if let Some(callback) = &on_click {
-                            callback.call(e);
-                        }
-                    },
-                    span {
-                        class: "text-lg mr-3",
-                        "{icon}"
-                    }
-                    "{label}"
-                }
-            }
-        }
-    }
-}
-
 /// Get the navigation items configuration
 fn get_navigation_items() -> Vec<NavItem> {
     vec![
@@ -470,6 +488,31 @@ mod tests {
         assert!(!help_item.unwrap().children.is_empty());
     }
 
+    #[test]
+    fn test_nav_item_from_layout_item() {
+        let item = LayoutNavigationItem {
+            id: "products".to_string(),
+            label: "Products".to_string(),
+            route: "/plugin/products".to_string(),
+            children: vec![LayoutNavigationItem {
+                id: "unknown".to_string(),
+                label: "Unknown".to_string(),
+                route: "/no/such/route".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let nav_item = NavItem::from(&item);
+        assert_eq!(
+            nav_item.route,
+            Some(Route::Plugin {
+                plugin_id: "products".to_string()
+            })
+        );
+        assert!(nav_item.children[0].route.is_none());
+    }
+
     #[test]
     fn test_sidebar_component_creation() {
         let on_close = Callback::new(|_| {});
@@ -8,8 +8,10 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
-use crate::auth::{Permission, User, UserSession};
-use crate::error::Result;
+use crate::auth::{
+    AccountManager, Permission, PermissionHierarchy, User, UserPreferences, UserSession,
+};
+use crate::error::{Error, Result};
 use crate::manager::{ManagedState, Manager, ManagerStatus, PlatformRequirements};
 use crate::plugin::MenuItem;
 
@@ -19,15 +21,23 @@ pub use app::App;
 // Module declarations
 pub mod app;
 pub mod components;
+pub mod forms;
 pub mod layout;
 pub mod pages;
+pub mod palette;
+pub mod registry;
 pub mod router;
 pub mod state;
 
 // Re-exports for convenience
 pub use components::*;
+pub use forms::{
+    render_settings_form, use_form, DraftPrompt, DraftStore, FormDraft, FormValues, UseForm,
+};
 pub use layout::*;
 pub use pages::{Admin, Dashboard, Login, NotFound, Plugins, Profile, Settings};
+pub use palette::{CommandPalette, CommandRegistry, PaletteCommand};
+pub use registry::{Slot, UIComponentRegistry, SLOT_FOOTER, SLOT_HEADER, SLOT_LOGIN, SLOT_SIDEBAR};
 pub use router::Route;
 pub use state::*;
 
@@ -94,6 +104,129 @@ pub struct NavigationItem {
     pub required_permissions: Vec<Permission>,
     pub badge: Option<Badge>,
     pub children: Vec<NavigationItem>,
+    /// Sort order among siblings
+    #[serde(default)]
+    pub order: i32,
+}
+
+impl From<&MenuItem> for NavigationItem {
+    fn from(item: &MenuItem) -> Self {
+        Self {
+            id: item.id.clone(),
+            label: item.label.clone(),
+            icon: item.icon.clone(),
+            route: item.route.clone().unwrap_or_default(),
+            required_permissions: item.required_permissions.clone(),
+            badge: None,
+            children: item.children.iter().map(NavigationItem::from).collect(),
+            order: item.order,
+        }
+    }
+}
+
+fn sort_navigation_items(items: &mut [NavigationItem]) {
+    items.sort_by_key(|item| item.order);
+    for item in items {
+        sort_navigation_items(&mut item.children);
+    }
+}
+
+/// Permission check against everything `user` is granted, directly or through roles.
+///
+/// Grants are evaluated with `hierarchy`, normally the configured one. Without
+/// a user every permission is refused.
+pub fn user_permission_check(
+    user: Option<&User>,
+    hierarchy: &PermissionHierarchy,
+) -> impl Fn(&Permission) -> bool {
+    let hierarchy = hierarchy.clone();
+    let granted: Vec<Permission> = user
+        .map(|user| {
+            user.permissions
+                .iter()
+                .chain(user.roles.iter().flat_map(|r| r.permissions.iter()))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    move |required: &Permission| {
+        granted
+            .iter()
+            .any(|p| hierarchy.grants(p, &required.resource, &required.action))
+    }
+}
+
+/// Navigation items whose required permissions all pass `check`.
+///
+/// Children are filtered recursively. A parent left with no accessible
+/// children is dropped too, unless it has a route of its own.
+pub fn filter_navigation(
+    items: &[NavigationItem],
+    check: &dyn Fn(&Permission) -> bool,
+) -> Vec<NavigationItem> {
+    items
+        .iter()
+        .filter(|item| item.required_permissions.iter().all(check))
+        .filter_map(|item| {
+            let children = filter_navigation(&item.children, check);
+            if !item.children.is_empty() && children.is_empty() && item.route.is_empty() {
+                return None;
+            }
+            Some(NavigationItem {
+                children,
+                ..item.clone()
+            })
+        })
+        .collect()
+}
+
+/// Menu items whose required permissions all pass `check`.
+///
+/// Pruned like [`filter_navigation`]; a parent with an action of its own is
+/// kept as well as one with a route.
+pub fn filter_menu_items(items: &[MenuItem], check: &dyn Fn(&Permission) -> bool) -> Vec<MenuItem> {
+    items
+        .iter()
+        .filter(|item| item.required_permissions.iter().all(check))
+        .filter_map(|item| {
+            let children = filter_menu_items(&item.children, check);
+            if !item.children.is_empty()
+                && children.is_empty()
+                && item.route.is_none()
+                && item.action.is_none()
+            {
+                return None;
+            }
+            Some(MenuItem {
+                children,
+                ..item.clone()
+            })
+        })
+        .collect()
+}
+
+/// Combine menu items from several sources into one tree sorted by `order`.
+///
+/// Items sharing an ID are merged: the first one keeps its label, route and
+/// permissions, and the children of both are merged the same way. Items
+/// with equal `order` keep their source order.
+pub fn merge_menu_items(items: impl IntoIterator<Item = MenuItem>) -> Vec<MenuItem> {
+    let mut merged: Vec<MenuItem> = Vec::new();
+    for item in items {
+        match merged.iter_mut().find(|existing| existing.id == item.id) {
+            Some(existing) => {
+                let children = std::mem::take(&mut existing.children);
+                existing.children = merge_menu_items(children.into_iter().chain(item.children));
+            }
+            None => merged.push(MenuItem {
+                children: merge_menu_items(item.children),
+                ..item
+            }),
+        }
+    }
+    merged.sort_by_key(|item| item.order);
+    merged
 }
 
 /// Badge for navigation items
@@ -159,6 +292,96 @@ pub struct Theme {
     pub animations: Animations,
 }
 
+impl Theme {
+    /// CSS custom properties for every theme field, in a stable order.
+    ///
+    /// Names are part of the public styling contract:
+    ///
+    /// | Section | Variables |
+    /// |---|---|
+    /// | colors | `--color-primary`, `--color-secondary`, `--color-accent`, `--color-background`, `--color-surface`, `--color-error`, `--color-warning`, `--color-success`, `--color-info`, `--color-text-primary`, `--color-text-secondary`, `--color-border` |
+    /// | typography | `--font-family`, `--font-size-base`, `--font-weight-normal`, `--font-weight-bold`, `--line-height`, `--heading-scale` |
+    /// | spacing | `--spacing-unit`, `--spacing-xs`, `--spacing-sm`, `--spacing-md`, `--spacing-lg`, `--spacing-xl` |
+    /// | shadows | `--shadow-sm`, `--shadow-md`, `--shadow-lg`, `--shadow-xl` |
+    /// | animations | `--duration-fast`, `--duration-normal`, `--duration-slow`, `--easing` |
+    pub fn css_variables(&self) -> Vec<(&'static str, String)> {
+        let c = &self.colors;
+        let t = &self.typography;
+        let s = &self.spacing;
+        let sh = &self.shadows;
+        let a = &self.animations;
+
+        vec![
+            ("--color-primary", c.primary.clone()),
+            ("--color-secondary", c.secondary.clone()),
+            ("--color-accent", c.accent.clone()),
+            ("--color-background", c.background.clone()),
+            ("--color-surface", c.surface.clone()),
+            ("--color-error", c.error.clone()),
+            ("--color-warning", c.warning.clone()),
+            ("--color-success", c.success.clone()),
+            ("--color-info", c.info.clone()),
+            ("--color-text-primary", c.text_primary.clone()),
+            ("--color-text-secondary", c.text_secondary.clone()),
+            ("--color-border", c.border.clone()),
+            ("--font-family", t.font_family.clone()),
+            ("--font-size-base", t.font_size_base.clone()),
+            ("--font-weight-normal", t.font_weight_normal.to_string()),
+            ("--font-weight-bold", t.font_weight_bold.to_string()),
+            ("--line-height", t.line_height.to_string()),
+            ("--heading-scale", t.heading_scale.to_string()),
+            ("--spacing-unit", s.unit.clone()),
+            ("--spacing-xs", s.xs.clone()),
+            ("--spacing-sm", s.sm.clone()),
+            ("--spacing-md", s.md.clone()),
+            ("--spacing-lg", s.lg.clone()),
+            ("--spacing-xl", s.xl.clone()),
+            ("--shadow-sm", sh.sm.clone()),
+            ("--shadow-md", sh.md.clone()),
+            ("--shadow-lg", sh.lg.clone()),
+            ("--shadow-xl", sh.xl.clone()),
+            ("--duration-fast", a.duration_fast.clone()),
+            ("--duration-normal", a.duration_normal.clone()),
+            ("--duration-slow", a.duration_slow.clone()),
+            ("--easing", a.easing.clone()),
+        ]
+    }
+
+    /// A `:root { ... }` block declaring [`Self::css_variables`].
+    ///
+    /// Empty values are left out so stylesheet fallbacks apply, and characters
+    /// that could end a declaration or the style element are dropped.
+    pub fn to_css_variables(&self) -> String {
+        let mut css = String::from(":root {\n");
+        for (name, value) in self.css_variables() {
+            let value: String = value
+                .chars()
+                .filter(|ch| !matches!(ch, ';' | '{' | '}' | '<' | '>'))
+                .collect();
+            let value = value.trim();
+            if !value.is_empty() {
+                css.push_str(&format!("  {}: {};\n", name, value));
+            }
+        }
+        css.push('}');
+        css
+    }
+}
+
+/// Injects the theme's CSS variables into the page.
+///
+/// Uses the current theme from the app state unless `theme` is given, so
+/// swapping themes at runtime restyles everything that reads the variables.
+#[component]
+pub fn ThemeStyle(#[props(default = None)] theme: Option<Theme>) -> Element {
+    let current = state::use_app_state().current_theme;
+    let css = theme.unwrap_or(current).to_css_variables();
+
+    rsx! {
+        style { dangerous_inner_html: "{css}" }
+    }
+}
+
 /// Color palette
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct ColorPalette {
@@ -238,6 +461,9 @@ pub struct Notification {
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub read: bool,
     pub actions: Vec<NotificationAction>,
+    /// Removes the notification automatically once this much time has passed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_dismiss_after: Option<std::time::Duration>,
 }
 
 /// Notification types
@@ -250,6 +476,19 @@ pub enum NotificationType {
     System,
 }
 
+impl NotificationType {
+    /// `Alert` variant used to render the notification
+    pub fn alert_variant(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Success => "success",
+            Self::Warning => "warning",
+            Self::Error => "error",
+            Self::System => "system",
+        }
+    }
+}
+
 /// Notification action
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationAction {
@@ -267,6 +506,46 @@ pub enum ActionStyle {
     Link,
 }
 
+impl ActionStyle {
+    /// `Button` variant used to render the action
+    pub fn button_variant(&self) -> &'static str {
+        match self {
+            Self::Primary => "primary",
+            Self::Secondary => "secondary",
+            Self::Danger => "danger",
+            Self::Link => "ghost",
+        }
+    }
+}
+
+/// ID of the built-in theme, used when a user's preferred theme is missing
+pub const DEFAULT_THEME_ID: &str = "default";
+
+/// ID of the built-in dark theme
+pub const DARK_THEME_ID: &str = "dark";
+
+/// Built-in theme matching the system color scheme.
+///
+/// On the web this follows `prefers-color-scheme`; elsewhere it is the light default.
+pub fn system_theme_id() -> &'static str {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let prefers_dark = web_sys::window()
+            .and_then(|window| {
+                window
+                    .match_media("(prefers-color-scheme: dark)")
+                    .ok()
+                    .flatten()
+            })
+            .is_some_and(|query| query.matches());
+        if prefers_dark {
+            return DARK_THEME_ID;
+        }
+    }
+
+    DEFAULT_THEME_ID
+}
+
 /// UI Layout Manager
 pub struct UILayoutManager {
     state: ManagedState,
@@ -292,7 +571,7 @@ impl UILayoutManager {
     /// Creates a new UI layout manager
     pub fn new() -> Self {
         Self {
-            state: ManagedState::new(Uuid::new_v4(), "ui_layout_manager"),
+            state: ManagedState::new(crate::utils::rand::uuid(), "ui_layout_manager"),
             layouts: Arc::new(RwLock::new(HashMap::new())),
             themes: Arc::new(RwLock::new(HashMap::new())),
             current_layout: Arc::new(RwLock::new(None)),
@@ -343,6 +622,83 @@ impl UILayoutManager {
         self.current_theme.read().await.clone()
     }
 
+    /// Makes a registered theme current
+    pub async fn select_theme(&self, theme_id: &str) -> Result<Theme> {
+        let theme = self
+            .get_theme(theme_id)
+            .await
+            .ok_or_else(|| Error::config(format!("Theme '{}' is not registered", theme_id)))?;
+        self.set_current_theme(theme.clone()).await;
+        Ok(theme)
+    }
+
+    /// Switches theme and saves the choice to the signed-in user's preferences
+    pub async fn set_user_theme(&self, accounts: &AccountManager, theme_id: &str) -> Result<Theme> {
+        let theme = self.select_theme(theme_id).await?;
+
+        if let Some(mut user) = accounts.current_user().await {
+            if user.preferences.theme != theme.id {
+                user.preferences.theme = theme.id.clone();
+                accounts.update_user(user).await?;
+            }
+        }
+
+        Ok(theme)
+    }
+
+    /// Applies the theme named in a user's preferences, e.g. after login.
+    ///
+    /// Without a preference the theme follows [`system_theme_id`]. Falls back
+    /// to the default theme when the preferred one is no longer registered.
+    pub async fn apply_preferred_theme(&self, preferences: &UserPreferences) -> Theme {
+        let theme_id = match preferences.theme.trim() {
+            "" => system_theme_id(),
+            theme_id => theme_id,
+        };
+        if let Ok(theme) = self.select_theme(theme_id).await {
+            return theme;
+        }
+
+        let theme = match self.get_theme(DEFAULT_THEME_ID).await {
+            Some(theme) => theme,
+            None => self.default_theme().await,
+        };
+        self.set_current_theme(theme.clone()).await;
+        theme
+    }
+
+    /// Merges plugin menu items into the current layout's sidebar.
+    ///
+    /// Items replace existing navigation items with the same ID and the
+    /// sidebar is re-sorted by `order`. Permissions are enforced when the
+    /// sidebar renders, through [`filter_navigation`].
+    pub async fn apply_plugin_menus(&self, items: Vec<MenuItem>) {
+        let mut current = self.current_layout.write().await;
+        let mut layout = match current.take() {
+            Some(layout) => layout,
+            None => self.default_layout().await,
+        };
+
+        let navigation = &mut layout.sidebar.navigation_items;
+        for item in &items {
+            let item = NavigationItem::from(item);
+            match navigation
+                .iter_mut()
+                .find(|existing| existing.id == item.id)
+            {
+                Some(existing) => *existing = item,
+                None => navigation.push(item),
+            }
+        }
+        sort_navigation_items(navigation);
+
+        self.layouts
+            .write()
+            .await
+            .insert(layout.layout_id.clone(), layout.clone());
+        *current = Some(layout);
+    }
+
     /// Finds appropriate layout for user and platform
     pub async fn find_layout_for_user(&self, user: &User, platform: Platform) -> Option<UILayout> {
         let layouts = self.layouts.read().await;
@@ -408,10 +764,42 @@ impl UILayoutManager {
         }
     }
 
+    /// Registers the light and dark themes that ship with the application
+    pub async fn register_builtin_themes(&self) {
+        self.register_theme(self.default_theme().await).await;
+        self.register_theme(self.dark_theme().await).await;
+    }
+
+    /// Gets the built-in dark theme.
+    ///
+    /// Only the palette differs from the default theme, so switching between
+    /// them never changes sizes, spacing or layout.
+    pub async fn dark_theme(&self) -> Theme {
+        Theme {
+            id: DARK_THEME_ID.to_string(),
+            name: "Dark Theme".to_string(),
+            colors: ColorPalette {
+                primary: "#60a5fa".to_string(),
+                secondary: "#94a3b8".to_string(),
+                accent: "#a78bfa".to_string(),
+                background: "#0f172a".to_string(),
+                surface: "#1e293b".to_string(),
+                error: "#f87171".to_string(),
+                warning: "#fbbf24".to_string(),
+                success: "#34d399".to_string(),
+                info: "#22d3ee".to_string(),
+                text_primary: "#f1f5f9".to_string(),
+                text_secondary: "#94a3b8".to_string(),
+                border: "#334155".to_string(),
+            },
+            ..self.default_theme().await
+        }
+    }
+
     /// Gets default theme
     pub async fn default_theme(&self) -> Theme {
         Theme {
-            id: "default".to_string(),
+            id: DEFAULT_THEME_ID.to_string(),
             name: "Default Theme".to_string(),
             colors: ColorPalette {
                 primary: "#3b82f6".to_string(),
@@ -476,15 +864,14 @@ impl Manager for UILayoutManager {
             .set_state(crate::manager::ManagerState::Initializing)
             .await;
 
-        // Register default layout and theme
+        // Register default layout and built-in themes
         let default_layout = self.default_layout().await;
-        let default_theme = self.default_theme().await;
-
         self.register_layout(default_layout.clone()).await;
-        self.register_theme(default_theme.clone()).await;
+        self.register_builtin_themes().await;
 
         self.set_current_layout(default_layout).await;
-        self.set_current_theme(default_theme).await;
+        self.apply_preferred_theme(&UserPreferences::default())
+            .await;
 
         self.state
             .set_state(crate::manager::ManagerState::Running)
@@ -555,15 +942,14 @@ impl Manager for UILayoutManager {
             .set_state(crate::manager::ManagerState::Initializing)
             .await;
 
-        // Register default layout and theme
+        // Register default layout and built-in themes
         let default_layout = self.default_layout().await;
-        let default_theme = self.default_theme().await;
-
         self.register_layout(default_layout.clone()).await;
-        self.register_theme(default_theme.clone()).await;
+        self.register_builtin_themes().await;
 
         self.set_current_layout(default_layout).await;
-        self.set_current_theme(default_theme).await;
+        self.apply_preferred_theme(&UserPreferences::default())
+            .await;
 
         self.state
             .set_state(crate::manager::ManagerState::Running)
@@ -640,6 +1026,207 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    async fn test_apply_plugin_menus() {
+        let manager = UILayoutManager::new();
+        manager
+            .apply_plugin_menus(vec![
+                MenuItem::builder("reports", "Reports")
+                    .route("/plugin/reports")
+                    .order(20)
+                    .build(),
+                MenuItem::builder("products", "Products")
+                    .icon("shopping-bag")
+                    .route("/plugin/products")
+                    .order(10)
+                    .child(
+                        MenuItem::builder("inventory", "Inventory")
+                            .permission("inventory", "read")
+                            .order(2)
+                            .build(),
+                    )
+                    .child(MenuItem::builder("list", "All Products").order(1).build())
+                    .build(),
+                MenuItem::builder("admin", "Admin")
+                    .permission("admin", "read")
+                    .order(5)
+                    .build(),
+            ])
+            .await;
+
+        let layout = manager.current_layout().await.unwrap();
+        let items = &layout.sidebar.navigation_items;
+        let ids: Vec<&str> = items.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, ["admin", "products", "reports"]);
+        assert_eq!(items[1].icon.as_deref(), Some("shopping-bag"));
+        assert_eq!(items[1].route, "/plugin/products");
+        assert_eq!(items[1].children[0].id, "list");
+
+        // Users without the permissions never see those items
+        let visible = filter_navigation(
+            items,
+            &user_permission_check(None, &PermissionHierarchy::default()),
+        );
+        let ids: Vec<&str> = visible.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, ["products", "reports"]);
+        assert_eq!(visible[0].children.len(), 1);
+        assert_eq!(visible[0].children[0].id, "list");
+    }
+
+    #[test]
+    fn test_filter_navigation_prunes_empty_parents() {
+        fn item(id: &str, route: &str, permission: Option<&str>) -> NavigationItem {
+            NavigationItem {
+                id: id.to_string(),
+                label: id.to_string(),
+                route: route.to_string(),
+                required_permissions: permission
+                    .map(|resource| Permission {
+                        resource: resource.to_string(),
+                        action: "read".to_string(),
+                        scope: crate::auth::PermissionScope::Global,
+                    })
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            }
+        }
+
+        // reports (no route)
+        //   sales (no route) -> [forecast: finance]
+        //   inventory (no route) -> [stock, audit: audit]
+        // admin (/admin) -> [users: admin]
+        let tree = vec![
+            NavigationItem {
+                children: vec![
+                    NavigationItem {
+                        children: vec![item("forecast", "/forecast", Some("finance"))],
+                        ..item("sales", "", None)
+                    },
+                    NavigationItem {
+                        children: vec![
+                            item("stock", "/stock", None),
+                            item("audit", "/audit", Some("audit")),
+                        ],
+                        ..item("inventory", "", None)
+                    },
+                ],
+                ..item("reports", "", None)
+            },
+            NavigationItem {
+                children: vec![item("users", "/admin/users", Some("admin"))],
+                ..item("admin", "/admin", None)
+            },
+        ];
+
+        let visible = filter_navigation(&tree, &|p: &Permission| p.resource == "audit");
+        let reports = &visible[0];
+        assert_eq!(reports.children.len(), 1);
+        assert_eq!(reports.children[0].id, "inventory");
+        let leaves: Vec<&str> = reports.children[0]
+            .children
+            .iter()
+            .map(|i| i.id.as_str())
+            .collect();
+        assert_eq!(leaves, ["stock", "audit"]);
+        // Routed parents stay even when every child is hidden
+        assert_eq!(visible[1].id, "admin");
+        assert!(visible[1].children.is_empty());
+
+        // With nothing granted, the branch holding only "stock" survives
+        let visible = filter_navigation(&tree, &|_: &Permission| false);
+        assert_eq!(visible[0].children[0].children[0].id, "stock");
+
+        let menus = vec![MenuItem::builder("reports", "Reports")
+            .child(
+                MenuItem::builder("sales", "Sales")
+                    .child(
+                        MenuItem::builder("forecast", "Forecast")
+                            .permission("finance", "read")
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build()];
+        assert!(filter_menu_items(&menus, &|_: &Permission| false).is_empty());
+        assert_eq!(filter_menu_items(&menus, &|_: &Permission| true), menus);
+    }
+
+    #[tokio::test]
+    async fn test_theme_css_variables() {
+        let theme = UILayoutManager::new().default_theme().await;
+        let css = theme.to_css_variables();
+
+        assert!(css.starts_with(":root {"));
+        assert!(css.contains("  --color-primary: #3b82f6;\n"));
+        assert!(css.contains("  --font-weight-bold: 600;\n"));
+        assert!(css.contains("  --line-height: 1.5;\n"));
+        assert!(css.contains("  --easing: cubic-bezier(0.4, 0, 0.2, 1);\n"));
+        // Every field is covered
+        assert_eq!(css.lines().count(), theme.css_variables().len() + 2);
+
+        let mut hostile = theme.clone();
+        hostile.colors.primary = "red; } body { display: none".to_string();
+        hostile.colors.accent = String::new();
+        let css = hostile.to_css_variables();
+        assert!(css.contains("--color-primary: red  body  display: none;"));
+        assert!(!css.contains("--color-accent"));
+    }
+
+    #[tokio::test]
+    async fn test_builtin_dark_theme_only_swaps_palette() {
+        let mut manager = UILayoutManager::new();
+        manager.initialize().await.unwrap();
+        let layout = manager.current_layout().await.unwrap();
+
+        // No preference follows the system scheme, which is light off the web
+        assert_eq!(manager.current_theme().await.unwrap().id, DEFAULT_THEME_ID);
+
+        let light = manager.get_theme(DEFAULT_THEME_ID).await.unwrap();
+        let dark = manager.select_theme(DARK_THEME_ID).await.unwrap();
+        assert_ne!(dark.colors, light.colors);
+        assert_eq!(
+            Theme {
+                id: light.id.clone(),
+                name: light.name.clone(),
+                colors: light.colors.clone(),
+                ..dark
+            },
+            light
+        );
+        assert_eq!(manager.current_layout().await.unwrap(), layout);
+    }
+
+    #[tokio::test]
+    async fn test_preferred_theme_falls_back_to_default() {
+        let manager = UILayoutManager::new();
+        let default_theme = manager.default_theme().await;
+        manager.register_theme(default_theme.clone()).await;
+        manager
+            .register_theme(Theme {
+                id: "dark".to_string(),
+                name: "Dark".to_string(),
+                ..default_theme.clone()
+            })
+            .await;
+
+        let mut preferences = UserPreferences {
+            theme: "dark".to_string(),
+            ..UserPreferences::default()
+        };
+        assert_eq!(manager.apply_preferred_theme(&preferences).await.id, "dark");
+        assert_eq!(manager.current_theme().await.unwrap().id, "dark");
+
+        // A theme that was removed since the user picked it
+        preferences.theme = "solarized".to_string();
+        assert_eq!(
+            manager.apply_preferred_theme(&preferences).await.id,
+            DEFAULT_THEME_ID
+        );
+        assert_eq!(manager.current_theme().await.unwrap().id, DEFAULT_THEME_ID);
+        assert!(manager.select_theme("solarized").await.is_err());
+    }
+
     #[test]
     fn test_platform_equality() {
         assert_eq!(Platform::Desktop, Platform::Desktop);
@@ -1,7 +1,12 @@
 // src/ui/pages/admin.rs - Administrative dashboard and user management
 
+use std::sync::Arc;
+
 use dioxus::prelude::*;
+use tokio::sync::RwLock;
 
+use crate::auth::rate_limit::LOGIN_BUCKET_PREFIX;
+use crate::auth::{AccountManager, RateLimitBucket};
 use crate::ui::pages::{EmptyState, PageWrapper, StatCard, StatTrend};
 
 /// Main admin page component
@@ -142,6 +147,16 @@ pub fn Admin() -> Element {
                     onclick: move |_| active_tab.set("logs".to_string()),
                     "Logs"
                 }
+                button {
+                    r#type: "button",
+                    class: if active_tab() == "rate_limits" {
+                        "py-2 px-1 border-b-2 font-medium text-sm border-blue-500 text-blue-600"
+                    } else {
+                        "py-2 px-1 border-b-2 font-medium text-sm border-transparent text-gray-500 hover:text-gray-700 hover:border-gray-300"
+                    },
+                    onclick: move |_| active_tab.set("rate_limits".to_string()),
+                    "Rate Limits"
+                }
             }
         }
     };
@@ -152,6 +167,7 @@ pub fn Admin() -> Element {
         "system" => rsx! { SystemTab {} },
         "plugins" => rsx! { PluginsTab {} },
         "logs" => rsx! { LogsTab {} },
+        "rate_limits" => rsx! { RateLimitsTab {} },
         _ => rsx! { div { "Unknown tab" } },
     };
 
@@ -642,6 +658,154 @@ fn LogsTab() -> Element {
     }
 }
 
+/// Active login throttling and API route buckets, with per-bucket reset.
+///
+/// Goes through the account manager in context, so listing needs the
+/// `rate_limits.read` permission and resetting `rate_limits.reset`.
+#[component]
+fn RateLimitsTab() -> Element {
+    let accounts = try_use_context::<Arc<RwLock<AccountManager>>>();
+    let mut refresh = use_signal(|| 0u32);
+    let mut reset_error = use_signal(|| None::<String>);
+
+    // Re-read the buckets whenever one is reset or the list is refreshed
+    let buckets = use_resource({
+        let accounts = accounts.clone();
+        move || {
+            let _ = refresh();
+            let accounts = accounts.clone();
+            async move {
+                let Some(accounts) = accounts else {
+                    return Err("No account manager is available".to_string());
+                };
+                let buckets = accounts.read().await.rate_limit_buckets("").await;
+                buckets.map_err(|e| e.to_string())
+            }
+        }
+    });
+
+    let content = match buckets.read().clone() {
+        None => rsx! {
+            p {
+                class: "px-4 py-5 sm:px-6 text-sm text-gray-500",
+                "Loading rate limits..."
+            }
+        },
+        Some(Err(error)) => rsx! {
+            p {
+                class: "px-4 py-5 sm:px-6 text-sm text-red-600",
+                "Rate limits are unavailable: {error}"
+            }
+        },
+        Some(Ok(buckets)) if buckets.is_empty() => rsx! {
+            EmptyState {
+                icon: "🚦".to_string(),
+                title: "No active rate limits".to_string(),
+                description: "No users or clients are currently being throttled.".to_string()
+            }
+        },
+        Some(Ok(buckets)) => rsx! {
+            ul {
+                class: "divide-y divide-gray-200",
+                for bucket in buckets {
+                    RateLimitBucketItem {
+                        key: "{bucket.key}",
+                        bucket: bucket.clone(),
+                        on_reset: {
+                            let accounts = accounts.clone();
+                            move |key: String| {
+                                let accounts = accounts.clone();
+                                spawn(async move {
+                                    let Some(accounts) = accounts else {
+                                        return;
+                                    };
+                                    let reset = accounts.read().await.reset_rate_limit(&key).await;
+                                    match reset {
+                                        Ok(_) => {
+                                            reset_error.set(None);
+                                            refresh += 1;
+                                        }
+                                        Err(e) => reset_error.set(Some(e.to_string())),
+                                    }
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    };
+
+    rsx! {
+        div {
+            class: "bg-white shadow rounded-lg",
+            div {
+                class: "px-4 py-5 sm:px-6 border-b border-gray-200 flex justify-between items-center",
+                h3 {
+                    class: "text-lg leading-6 font-medium text-gray-900",
+                    "Rate Limits"
+                }
+                button {
+                    r#type: "button",
+                    class: "inline-flex items-center px-3 py-2 border border-gray-300 shadow-sm text-sm leading-4 font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500",
+                    onclick: move |_| refresh += 1,
+                    "Refresh"
+                }
+            }
+            if let Some(error) = reset_error() {
+                p {
+                    class: "px-4 py-3 sm:px-6 text-sm text-red-600 border-b border-gray-200",
+                    "Reset failed: {error}"
+                }
+            }
+            {content}
+        }
+    }
+}
+
+/// One rate limit bucket with its remaining quota
+#[component]
+fn RateLimitBucketItem(bucket: RateLimitBucket, on_reset: EventHandler<String>) -> Element {
+    let kind = if bucket.key.starts_with(LOGIN_BUCKET_PREFIX) {
+        "Login"
+    } else {
+        "API route"
+    };
+    let reset_at = bucket.reset_at.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+
+    rsx! {
+        li {
+            class: "px-4 py-4 sm:px-6 flex items-center justify-between",
+            div {
+                class: "min-w-0 flex-1",
+                p {
+                    class: "text-sm font-mono text-gray-900 truncate",
+                    "{bucket.key}"
+                }
+                p {
+                    class: "mt-1 text-xs text-gray-500",
+                    "{kind} · {bucket.remaining}/{bucket.capacity} remaining · resets {reset_at}"
+                }
+            }
+            if bucket.is_exhausted() {
+                span {
+                    class: "mr-4 inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium bg-red-100 text-red-800",
+                    "Locked"
+                }
+            }
+            button {
+                r#type: "button",
+                class: "inline-flex items-center px-3 py-1.5 border border-gray-300 text-xs font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50",
+                onclick: {
+                    let key = bucket.key.clone();
+                    move |_| on_reset.call(key.clone())
+                },
+                "Reset"
+            }
+        }
+    }
+}
+
 /// Individual log entry component
 #[component]
 fn LogEntry(log: SystemLog) -> Element {
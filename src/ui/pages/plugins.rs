@@ -4,6 +4,13 @@ use dioxus::prelude::*;
 #[allow(unused_imports)]
 use dioxus_router::prelude::*;
 
+use crate::plugin::{
+    OnboardingStep, PluginComponentHost, PluginFactoryRegistry, PluginInstaller, PluginOnboarding,
+    ProviderHealth,
+};
+use crate::ui::components::{Alert, Button, Modal, TabItem, Tabs};
+use crate::ui::forms::SchemaForm;
+use crate::ui::registry::UIComponentRegistry;
 #[allow(unused_imports)]
 use crate::ui::{
     pages::{EmptyState, PageWrapper},
@@ -17,6 +24,7 @@ pub fn Plugins() -> Element {
     let mut active_tab = use_signal(|| "installed".to_string());
     let mut search_query = use_signal(String::new);
     let mut loading = use_signal(|| false);
+    let mut show_install = use_signal(|| false);
 
     // Mock plugin data
     let installed_plugins = get_installed_plugins();
@@ -82,6 +90,7 @@ pub fn Plugins() -> Element {
                     button {
                         r#type: "button",
                         class: "inline-flex items-center px-4 py-2 border border-transparent text-sm font-medium rounded-md shadow-sm text-white bg-blue-600 hover:bg-blue-700 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500",
+                        onclick: move |_| show_install.set(true),
                         svg {
                             class: "-ml-1 mr-2 h-4 w-4",
                             xmlns: "http://www.w3.org/2000/svg",
@@ -100,6 +109,10 @@ pub fn Plugins() -> Element {
                 }
             }),
 
+            if show_install() {
+                PluginInstallDialog { on_close: move |_| show_install.set(false) }
+            }
+
             // Search and filters
             div {
                 class: "mb-6",
@@ -569,9 +582,289 @@ fn UpdateCard(update: PluginUpdate) -> Element {
     }
 }
 
+/// Picks a registered plugin and walks it through [`PluginOnboardingWizard`].
+///
+/// Plugins come from the shared [`PluginFactoryRegistry`]; confirmed installs
+/// go to the [`PluginInstaller`] in context, which the application core
+/// drains.
+#[component]
+fn PluginInstallDialog(on_close: EventHandler<()>) -> Element {
+    let mut selected = use_signal(|| None::<PluginOnboarding>);
+    let plugin_ids = PluginFactoryRegistry::shared().plugin_ids();
+
+    if let Some(onboarding) = selected() {
+        let key = onboarding.info.id.clone();
+        return rsx! {
+            PluginInstallFlow { key: "{key}", initial: onboarding, on_close }
+        };
+    }
+
+    rsx! {
+        Modal {
+            show: true,
+            title: "Install Plugin".to_string(),
+            on_close: move |_| on_close.call(()),
+
+            if plugin_ids.is_empty() {
+                p { class: "py-4 text-sm text-gray-600", "No plugins are available to install." }
+            } else {
+                ul {
+                    class: "py-4 divide-y divide-gray-200",
+                    for plugin_id in plugin_ids {
+                        li {
+                            key: "{plugin_id}",
+                            class: "flex items-center justify-between py-2",
+                            span { class: "text-sm text-gray-900", "{plugin_id}" }
+                            Button {
+                                onclick: {
+                                    let plugin_id = plugin_id.clone();
+                                    move |_| {
+                                        if let Some(create) = PluginFactoryRegistry::shared().get(&plugin_id) {
+                                            selected.set(Some(PluginOnboarding::for_plugin(create().as_ref())));
+                                        }
+                                    }
+                                },
+                                "Set up"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Runs the wizard for one plugin and submits it to the [`PluginInstaller`]
+#[component]
+fn PluginInstallFlow(initial: PluginOnboarding, on_close: EventHandler<()>) -> Element {
+    let installer = try_use_context::<PluginInstaller>().unwrap_or_else(PluginInstaller::shared);
+    let onboarding = use_signal(|| initial.clone());
+    let mut installing = use_signal(|| false);
+    let mut health = use_signal(|| None::<ProviderHealth>);
+    let mut install_error = use_signal(|| None::<String>);
+
+    rsx! {
+        PluginOnboardingWizard {
+            onboarding,
+            show: true,
+            health: health(),
+            installing: installing(),
+            install_error: install_error(),
+            on_install: move |finished: PluginOnboarding| {
+                let installer = installer.clone();
+                installing.set(true);
+                install_error.set(None);
+                spawn(async move {
+                    let result = match PluginFactoryRegistry::shared().get(&finished.info.id) {
+                        Some(create) => installer.install(create(), finished).await,
+                        None => Err(crate::error::Error::plugin(
+                            &finished.info.id,
+                            "Plugin is no longer registered",
+                        )),
+                    };
+                    match result {
+                        Ok(result) => health.set(Some(result)),
+                        Err(e) => install_error.set(Some(e.message)),
+                    }
+                    installing.set(false);
+                });
+            },
+            on_close,
+        }
+    }
+}
+
+/// Multi-step dialog that walks an admin through installing a plugin.
+///
+/// Review the plugin, approve its permissions, fill required settings, then
+/// confirm. Confirming calls `on_install` with the finished onboarding, which
+/// the caller passes to a [`PluginInstaller`]; the resulting health is shown
+/// once handed back through `health`, or the failure through `install_error`.
+#[component]
+pub fn PluginOnboardingWizard(
+    onboarding: Signal<PluginOnboarding>,
+    #[props(default = false)] show: bool,
+    #[props(default = None)] health: Option<ProviderHealth>,
+    #[props(default = false)] installing: bool,
+    #[props(default = None)] install_error: Option<String>,
+    on_install: EventHandler<PluginOnboarding>,
+    on_close: EventHandler<()>,
+) -> Element {
+    let mut onboarding = onboarding;
+    let mut error = use_signal(|| None::<String>);
+    let mut show_missing = use_signal(|| false);
+
+    let current = onboarding.read().clone();
+    let step = current.step();
+    let configured = current.values().len();
+    let tabs: Vec<TabItem> = OnboardingStep::ALL
+        .iter()
+        .map(|step| TabItem {
+            id: step.id().to_string(),
+            label: step.label().to_string(),
+            count: None,
+        })
+        .collect();
+
+    let mut go_to = move |target: OnboardingStep| {
+        let result = onboarding.write().go_to(target);
+        show_missing.set(result.is_err());
+        error.set(result.err().map(|e| e.message));
+    };
+    let mut advance = move || {
+        let result = onboarding.write().next();
+        show_missing.set(result.is_err());
+        error.set(result.err().map(|e| e.message));
+    };
+    let mut install = move || {
+        let result = onboarding.write().confirm();
+        match result {
+            Ok(()) => {
+                error.set(None);
+                on_install.call(onboarding.read().clone());
+            }
+            Err(e) => error.set(Some(e.message)),
+        }
+    };
+
+    rsx! {
+        Modal {
+            show,
+            title: format!("Install {}", current.info.name),
+            on_close: move |_| on_close.call(()),
+
+            Tabs {
+                active_tab: step.id().to_string(),
+                tabs,
+                on_tab_change: move |id: String| {
+                    if let Some(target) = OnboardingStep::ALL.iter().find(|s| s.id() == id) {
+                        go_to(*target);
+                    }
+                },
+            }
+
+            div {
+                class: "py-4 space-y-4",
+                if let Some(message) = error() {
+                    Alert { variant: "error".to_string(), "{message}" }
+                }
+
+                {
+                    match step {
+                        OnboardingStep::Review => rsx! {
+                            dl {
+                                class: "grid grid-cols-3 gap-2 text-sm",
+                                dt { class: "font-medium text-gray-500", "Name" }
+                                dd { class: "col-span-2 text-gray-900", "{current.info.name}" }
+                                dt { class: "font-medium text-gray-500", "Version" }
+                                dd { class: "col-span-2 text-gray-900", "{current.info.version}" }
+                                dt { class: "font-medium text-gray-500", "Publisher" }
+                                dd { class: "col-span-2 text-gray-900", "{current.info.author}" }
+                                dt { class: "font-medium text-gray-500", "License" }
+                                dd { class: "col-span-2 text-gray-900", "{current.info.license}" }
+                            }
+                            p { class: "text-sm text-gray-600", "{current.info.description}" }
+                        },
+                        OnboardingStep::Permissions => rsx! {
+                            if current.permissions.is_empty() {
+                                p { class: "text-sm text-gray-600", "This plugin asks for no permissions." }
+                            } else {
+                                p { class: "text-sm text-gray-600", "This plugin asks to:" }
+                                ul {
+                                    class: "list-disc pl-5 text-sm text-gray-900",
+                                    for permission in current.permissions.iter() {
+                                        li { "{permission.action} {permission.resource}" }
+                                    }
+                                }
+                                label {
+                                    class: "flex items-center gap-2 text-sm text-gray-700",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: current.permissions_approved(),
+                                        onchange: move |evt: FormEvent| {
+                                            onboarding.write().approve_permissions(evt.checked());
+                                            error.set(None);
+                                        },
+                                    }
+                                    "I approve these permissions"
+                                }
+                            }
+                        },
+                        OnboardingStep::Settings => rsx! {
+                            if current.fields.is_empty() {
+                                p { class: "text-sm text-gray-600", "This plugin has no settings." }
+                            } else {
+                                SchemaForm {
+                                    fields: current.fields.clone(),
+                                    values: current.values().clone(),
+                                    missing: if show_missing() { current.missing_settings() } else { Vec::new() },
+                                    on_change: move |(key, value): (String, serde_json::Value)| {
+                                        onboarding.write().set_value(&key, value);
+                                    },
+                                }
+                            }
+                        },
+                        OnboardingStep::Confirm => rsx! {
+                            p {
+                                class: "text-sm text-gray-600",
+                                "Install {current.info.name} {current.info.version} with {configured} configured settings?"
+                            }
+                            if let Some(message) = &install_error {
+                                Alert { variant: "error".to_string(), title: "Install failed".to_string(), "{message}" }
+                            }
+                            if let Some(health) = &health {
+                                if health.is_healthy {
+                                    Alert { variant: "success".to_string(), title: "Plugin is running".to_string(), "Health check passed." }
+                                } else {
+                                    Alert {
+                                        variant: "warning".to_string(),
+                                        title: "Plugin is unhealthy".to_string(),
+                                        {health.error_message.clone().unwrap_or_default()}
+                                    }
+                                }
+                            }
+                        },
+                    }
+                }
+            }
+
+            div {
+                class: "flex justify-between",
+                Button {
+                    variant: "secondary".to_string(),
+                    disabled: step == OnboardingStep::Review || installing || health.is_some(),
+                    onclick: move |_| {
+                        onboarding.write().back();
+                        error.set(None);
+                    },
+                    "Back"
+                }
+                if health.is_some() {
+                    Button { onclick: move |_| on_close.call(()), "Done" }
+                } else if step == OnboardingStep::Confirm {
+                    Button { loading: installing, onclick: move |_| install(), "Install" }
+                } else {
+                    Button { onclick: move |_| advance(), "Next" }
+                }
+            }
+        }
+    }
+}
+
 /// Plugin view component for individual plugin pages
 #[component]
 pub fn PluginView(plugin_id: String, #[props(default = None)] page: Option<String>) -> Element {
+    let components =
+        try_use_context::<UIComponentRegistry>().unwrap_or_else(UIComponentRegistry::shared);
+
+    // Native components registered by the plugin replace the placeholder
+    if let Some(component) = components.resolve_route(&plugin_id, page.as_deref()) {
+        let key = format!("{}:{}", component.plugin_id, component.component_id);
+        return rsx! {
+            PluginComponentHost { key: "{key}", component }
+        };
+    }
+
     rsx! {
         PageWrapper {
             title: format!("Plugin: {}", plugin_id),
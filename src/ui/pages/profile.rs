@@ -3,10 +3,38 @@
 use dioxus::prelude::*;
 
 use crate::ui::{
+    forms::{use_form, DraftPrompt, FormValues},
     pages::PageWrapper,
     state::{use_app_dispatch, use_app_state},
 };
 
+/// Form ID the profile editor's drafts are saved under
+const PROFILE_FORM_ID: &str = "profile";
+
+/// Editable profile fields of `user`, keyed by input name
+fn profile_values(user: Option<&crate::ui::state::User>) -> FormValues {
+    let Some(user) = user else {
+        return FormValues::new();
+    };
+    [
+        ("display_name", user.profile.display_name.clone()),
+        ("email", user.email.clone()),
+        ("bio", user.profile.bio.clone().unwrap_or_default()),
+        (
+            "department",
+            user.profile.department.clone().unwrap_or_default(),
+        ),
+        ("title", user.profile.title.clone().unwrap_or_default()),
+        (
+            "phone",
+            user.profile.contact_info.phone.clone().unwrap_or_default(),
+        ),
+    ]
+    .into_iter()
+    .map(|(field, value)| (field.to_string(), value))
+    .collect()
+}
+
 /// Profile page component
 #[component]
 pub fn Profile() -> Element {
@@ -16,34 +44,16 @@ pub fn Profile() -> Element {
     // Clone user data to avoid borrowing issues
     let current_user = app_state.current_user.clone();
 
-    // Form state
-    let mut display_name = use_signal(String::new);
-    let mut email = use_signal(String::new);
-    let mut bio = use_signal(String::new);
-    let mut department = use_signal(String::new);
-    let mut title = use_signal(String::new);
-    let mut phone = use_signal(String::new);
+    // Form state, autosaved as a draft until it is submitted
+    let form = use_form(PROFILE_FORM_ID, profile_values(current_user.as_ref()));
     let mut saving = use_signal(|| false);
     let mut save_message = use_signal(|| None::<String>);
 
-    // Initialize form with current user data
-    use_effect({
-        let current_user = current_user.clone();
-        move || {
-            if let Some(user) = &current_user {
-                display_name.set(user.profile.display_name.clone());
-                email.set(user.email.clone());
-                bio.set(user.profile.bio.clone().unwrap_or_default());
-                department.set(user.profile.department.clone().unwrap_or_default());
-                title.set(user.profile.title.clone().unwrap_or_default());
-                phone.set(user.profile.contact_info.phone.clone().unwrap_or_default());
-            }
-        }
-    });
-
     let handle_save = {
         // let dispatch = dispatch.clone();
+        let form = form.clone();
         move |_| {
+            let form = form.clone();
             save_message.set(None);
             saving.set(true);
 
@@ -57,6 +67,7 @@ pub fn Profile() -> Element {
                     gloo_timers::future::TimeoutFuture::new(1000).await;
 
                     // In a real app, this would update the user via API
+                    form.submitted();
                     save_message.set(Some("Profile updated successfully!".to_string()));
                     saving.set(false);
                 }
@@ -73,13 +84,19 @@ pub fn Profile() -> Element {
                 class: "space-y-6",
 
                 ProfileOverviewCard { user: current_user.clone() }
+                DraftPrompt {
+                    draft: form.pending_draft.read().clone(),
+                    on_restore: {
+                        let form = form.clone();
+                        move |_| form.restore_draft()
+                    },
+                    on_discard: {
+                        let form = form.clone();
+                        move |_| form.discard_draft()
+                    },
+                }
                 EditProfileForm {
-                    display_name: display_name,
-                    email: email,
-                    bio: bio,
-                    department: department,
-                    title: title,
-                    phone: phone,
+                    values: form.values,
                     saving: saving,
                     save_message: save_message,
                     on_save: handle_save
@@ -178,12 +195,7 @@ fn ProfileOverviewCard(user: Option<crate::ui::state::User>) -> Element {
 /// Edit profile form component
 #[component]
 fn EditProfileForm(
-    display_name: Signal<String>,
-    email: Signal<String>,
-    bio: Signal<String>,
-    department: Signal<String>,
-    title: Signal<String>,
-    phone: Signal<String>,
+    values: Signal<FormValues>,
     saving: Signal<bool>,
     save_message: Signal<Option<String>>,
     on_save: Callback<Event<FormData>>,
@@ -222,6 +234,12 @@ fn EditProfileForm(
         rsx! {}
     };
 
+    let value = move |field: &str| values.read().get(field).cloned().unwrap_or_default();
+    let set = move |field: &str, value: String| {
+        let mut values = values;
+        values.write().insert(field.to_string(), value);
+    };
+
     let form_fields = rsx! {
         div {
             class: "grid grid-cols-1 gap-y-6 gap-x-4 sm:grid-cols-2",
@@ -241,8 +259,8 @@ fn EditProfileForm(
                         name: "display_name",
                         id: "display_name",
                         class: "shadow-sm focus:ring-blue-500 focus:border-blue-500 block w-full sm:text-sm border-gray-300 rounded-md",
-                        value: "{display_name}",
-                        oninput: move |e| display_name.set(e.value())
+                        value: value("display_name"),
+                        oninput: move |e| set("display_name", e.value())
                     }
                 }
             }
@@ -262,8 +280,8 @@ fn EditProfileForm(
                         name: "email",
                         id: "email",
                         class: "shadow-sm focus:ring-blue-500 focus:border-blue-500 block w-full sm:text-sm border-gray-300 rounded-md",
-                        value: "{email}",
-                        oninput: move |e| email.set(e.value())
+                        value: value("email"),
+                        oninput: move |e| set("email", e.value())
                     }
                 }
             }
@@ -283,8 +301,8 @@ fn EditProfileForm(
                         name: "department",
                         id: "department",
                         class: "shadow-sm focus:ring-blue-500 focus:border-blue-500 block w-full sm:text-sm border-gray-300 rounded-md",
-                        value: "{department}",
-                        oninput: move |e| department.set(e.value())
+                        value: value("department"),
+                        oninput: move |e| set("department", e.value())
                     }
                 }
             }
@@ -304,8 +322,8 @@ fn EditProfileForm(
                         name: "title",
                         id: "title",
                         class: "shadow-sm focus:ring-blue-500 focus:border-blue-500 block w-full sm:text-sm border-gray-300 rounded-md",
-                        value: "{title}",
-                        oninput: move |e| title.set(e.value())
+                        value: value("title"),
+                        oninput: move |e| set("title", e.value())
                     }
                 }
             }
@@ -325,8 +343,8 @@ fn EditProfileForm(
                         name: "phone",
                         id: "phone",
                         class: "shadow-sm focus:ring-blue-500 focus:border-blue-500 block w-full sm:text-sm border-gray-300 rounded-md",
-                        value: "{phone}",
-                        oninput: move |e| phone.set(e.value())
+                        value: value("phone"),
+                        oninput: move |e| set("phone", e.value())
                     }
                 }
             }
@@ -347,8 +365,8 @@ fn EditProfileForm(
                         rows: "3",
                         class: "shadow-sm focus:ring-blue-500 focus:border-blue-500 block w-full sm:text-sm border-gray-300 rounded-md",
                         placeholder: "Tell us about yourself...",
-                        value: "{bio}",
-                        oninput: move |e| bio.set(e.value())
+                        value: value("bio"),
+                        oninput: move |e| set("bio", e.value())
                     }
                 }
                 p {
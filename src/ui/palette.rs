@@ -0,0 +1,716 @@
+// src/ui/palette.rs - Command palette over routes, quick actions, plugin commands and search
+
+use std::sync::{Arc, OnceLock, RwLock};
+
+use dioxus::prelude::*;
+#[allow(unused_imports)]
+use dioxus_router::prelude::*;
+
+use crate::auth::{Permission, PermissionHierarchy, PermissionScope, User};
+use crate::plugin::search::{SearchContext, SearchCoordinator, SearchQuery, SearchResult};
+use crate::plugin::MenuItem;
+use crate::ui::router::{nav, Route};
+use crate::ui::state::use_app_state;
+use crate::ui::QuickAction;
+
+/// Maximum number of commands shown at once
+pub const MAX_PALETTE_RESULTS: usize = 20;
+
+/// Search results are only requested for queries at least this long
+const MIN_SEARCH_QUERY_LEN: usize = 2;
+
+/// Runs a command that is not a navigation
+pub type CommandHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// Where a palette command came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandSource {
+    Route,
+    QuickAction,
+    Plugin,
+    Search,
+}
+
+/// What happens when a command is executed
+#[derive(Clone)]
+pub enum CommandTarget {
+    /// Navigate to an application route
+    Navigate(Route),
+    /// Open an external URL
+    Open(String),
+    /// Call a registered handler
+    Invoke(CommandHandler),
+}
+
+impl std::fmt::Debug for CommandTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Navigate(route) => f.debug_tuple("Navigate").field(route).finish(),
+            Self::Open(url) => f.debug_tuple("Open").field(url).finish(),
+            Self::Invoke(_) => f.write_str("Invoke"),
+        }
+    }
+}
+
+/// A single entry in the command palette
+#[derive(Debug, Clone)]
+pub struct PaletteCommand {
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub icon: Option<String>,
+    pub source: CommandSource,
+    pub target: CommandTarget,
+    /// The user needs every one of these to see the command
+    pub required_permissions: Vec<Permission>,
+}
+
+impl PartialEq for PaletteCommand {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.source == other.source
+    }
+}
+
+impl PaletteCommand {
+    /// Creates a command with no subtitle, icon or permission requirements
+    pub fn new(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        source: CommandSource,
+        target: CommandTarget,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            subtitle: None,
+            icon: None,
+            source,
+            target,
+            required_permissions: Vec::new(),
+        }
+    }
+
+    pub fn with_subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    pub fn with_icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    pub fn with_permissions(mut self, permissions: Vec<Permission>) -> Self {
+        self.required_permissions = permissions;
+        self
+    }
+
+    /// Builds a command for a quick action whose `action` is a route path
+    pub fn from_quick_action(action: &QuickAction) -> Option<Self> {
+        let route = parse_route(&action.action)?;
+        Some(
+            Self::new(
+                format!("action:{}", action.id),
+                &action.label,
+                CommandSource::QuickAction,
+                CommandTarget::Navigate(route),
+            )
+            .with_icon(&action.icon)
+            .with_permissions(action.required_permissions.clone()),
+        )
+    }
+
+    /// Builds a command for a plugin menu item that has a route
+    pub fn from_menu_item(plugin_id: &str, item: &MenuItem) -> Option<Self> {
+        let route = parse_route(item.route.as_deref()?)?;
+        let mut command = Self::new(
+            format!("plugin:{}:{}", plugin_id, item.id),
+            &item.label,
+            CommandSource::Plugin,
+            CommandTarget::Navigate(route),
+        )
+        .with_subtitle(plugin_id)
+        .with_permissions(item.required_permissions.clone());
+        command.icon = item.icon.clone();
+        Some(command)
+    }
+
+    /// Builds a command that opens a search result
+    pub fn from_search_result(result: &SearchResult) -> Option<Self> {
+        let url = result.url.as_deref()?;
+        let target = match parse_route(url) {
+            Some(route) => CommandTarget::Navigate(route),
+            None => CommandTarget::Open(url.to_string()),
+        };
+
+        let mut command = Self::new(
+            format!("search:{}:{}", result.source_plugin, result.id),
+            &result.title,
+            CommandSource::Search,
+            target,
+        );
+        command.subtitle = result.description.clone();
+        Some(command)
+    }
+
+    /// Runs the command, passing navigation targets to `navigate`
+    pub fn execute(&self, navigate: impl FnOnce(NavigationTarget<Route>)) {
+        match &self.target {
+            CommandTarget::Navigate(route) => navigate(NavigationTarget::Internal(route.clone())),
+            CommandTarget::Open(url) => navigate(NavigationTarget::External(url.clone())),
+            CommandTarget::Invoke(handler) => handler(),
+        }
+    }
+}
+
+/// Parses an internal route, ignoring paths that only match the 404 catch-all
+pub(crate) fn parse_route(path: &str) -> Option<Route> {
+    match path.parse::<Route>() {
+        Ok(Route::NotFound { .. }) | Err(_) => None,
+        Ok(route) => Some(route),
+    }
+}
+
+/// Commands for the built-in navigable routes
+fn route_commands() -> Vec<PaletteCommand> {
+    let admin = Permission {
+        resource: "admin".to_string(),
+        action: "read".to_string(),
+        scope: PermissionScope::Global,
+    };
+
+    [
+        (Route::Dashboard {}, None),
+        (Route::Profile {}, None),
+        (Route::Plugins {}, None),
+        (Route::Settings {}, None),
+        (Route::Admin {}, Some(admin)),
+    ]
+    .into_iter()
+    .map(|(route, permission)| {
+        PaletteCommand::new(
+            format!("route:{}", route),
+            format!("Go to {}", nav::route_title(&route)),
+            CommandSource::Route,
+            CommandTarget::Navigate(route.clone()),
+        )
+        .with_icon(nav::route_icon(&route))
+        .with_permissions(permission.into_iter().collect())
+    })
+    .collect()
+}
+
+/// Registry of commands offered by the palette
+#[derive(Clone)]
+pub struct CommandRegistry {
+    commands: Arc<RwLock<Vec<PaletteCommand>>>,
+    hierarchy: Arc<RwLock<PermissionHierarchy>>,
+}
+
+impl std::fmt::Debug for CommandRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommandRegistry")
+            .field("commands", &self.read().len())
+            .finish()
+    }
+}
+
+impl PartialEq for CommandRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.commands, &other.commands)
+    }
+}
+
+impl Default for CommandRegistry {
+    fn default() -> Self {
+        Self {
+            commands: Arc::new(RwLock::new(Vec::new())),
+            hierarchy: Arc::new(RwLock::new(PermissionHierarchy::default())),
+        }
+    }
+}
+
+impl CommandRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry holding the built-in route commands
+    pub fn with_routes() -> Self {
+        let registry = Self::new();
+        for command in route_commands() {
+            registry.register(command);
+        }
+        registry
+    }
+
+    /// The process-wide registry used by the app shell
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<CommandRegistry> = OnceLock::new();
+        SHARED.get_or_init(Self::with_routes).clone()
+    }
+
+    /// Use the configured permission hierarchy, shared by every clone
+    pub fn set_permission_hierarchy(&self, hierarchy: PermissionHierarchy) {
+        *self.hierarchy.write().unwrap_or_else(|e| e.into_inner()) = hierarchy;
+    }
+
+    /// Registers a command, replacing any command with the same ID
+    pub fn register(&self, command: PaletteCommand) {
+        let mut commands = self.write();
+        commands.retain(|c| c.id != command.id);
+        commands.push(command);
+    }
+
+    /// Registers quick actions whose action is a route path
+    pub fn register_quick_actions(&self, actions: &[QuickAction]) {
+        for command in actions.iter().filter_map(PaletteCommand::from_quick_action) {
+            self.register(command);
+        }
+    }
+
+    /// Registers a plugin's menu items, including nested items
+    pub fn register_plugin_menu(&self, plugin_id: &str, items: &[MenuItem]) {
+        for item in items {
+            if let Some(command) = PaletteCommand::from_menu_item(plugin_id, item) {
+                self.register(command);
+            }
+            self.register_plugin_menu(plugin_id, &item.children);
+        }
+    }
+
+    /// Removes a command by ID
+    pub fn unregister(&self, id: &str) {
+        self.write().retain(|c| c.id != id);
+    }
+
+    /// Removes every command registered for a plugin
+    pub fn remove_plugin_commands(&self, plugin_id: &str) {
+        let prefix = format!("plugin:{}:", plugin_id);
+        self.write().retain(|c| !c.id.starts_with(&prefix));
+    }
+
+    /// Commands the user may run that fuzzy-match `query`, best match first.
+    ///
+    /// `extra` holds transient commands such as search results.
+    pub fn matches(
+        &self,
+        query: &str,
+        user: Option<&User>,
+        extra: &[PaletteCommand],
+    ) -> Vec<PaletteCommand> {
+        let commands = self.read();
+        let mut scored: Vec<(i64, &PaletteCommand)> = commands
+            .iter()
+            .chain(extra)
+            .filter(|command| self.is_permitted(command, user))
+            .filter_map(|command| fuzzy_score(query, &command.title).map(|s| (s, command)))
+            .collect();
+
+        // Stable sort keeps registration order between equal scores
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        scored
+            .into_iter()
+            .take(MAX_PALETTE_RESULTS)
+            .map(|(_, command)| command.clone())
+            .collect()
+    }
+
+    fn is_permitted(&self, command: &PaletteCommand, user: Option<&User>) -> bool {
+        if command.required_permissions.is_empty() {
+            return true;
+        }
+        let Some(user) = user else {
+            return false;
+        };
+
+        let granted: Vec<&Permission> = user
+            .permissions
+            .iter()
+            .chain(user.roles.iter().flat_map(|r| r.permissions.iter()))
+            .collect();
+        let hierarchy = self.hierarchy.read().unwrap_or_else(|e| e.into_inner());
+        command.required_permissions.iter().all(|required| {
+            granted
+                .iter()
+                .any(|p| hierarchy.grants(p, &required.resource, &required.action))
+        })
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Vec<PaletteCommand>> {
+        self.commands.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Vec<PaletteCommand>> {
+        self.commands.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Scores `text` against `query` as a case-insensitive subsequence.
+///
+/// Returns `None` if the query characters do not all appear in order.
+/// Consecutive matches and matches at word starts score higher.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let query: Vec<char> = query.trim().to_lowercase().chars().collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous: Option<usize> = None;
+
+    for (i, &c) in text.iter().enumerate() {
+        if next == query.len() {
+            break;
+        }
+        if c != query[next] {
+            continue;
+        }
+
+        score += 1;
+        if previous == Some(i.wrapping_sub(1)) {
+            score += 5;
+        }
+        if i == 0 || !text[i - 1].is_alphanumeric() {
+            score += 10;
+        }
+        if let Some(previous) = previous {
+            score -= (i - previous - 1) as i64;
+        }
+
+        previous = Some(i);
+        next += 1;
+    }
+
+    (next == query.len()).then_some(score)
+}
+
+/// Keyboard keys the palette responds to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteKey {
+    Up,
+    Down,
+    Enter,
+    Escape,
+}
+
+/// Query and selection state of an open palette
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteState {
+    registry: CommandRegistry,
+    user: Option<User>,
+    query: String,
+    search_results: Vec<PaletteCommand>,
+    results: Vec<PaletteCommand>,
+    selected: usize,
+    open: bool,
+}
+
+impl PaletteState {
+    /// Creates an open palette with an empty query
+    pub fn new(registry: CommandRegistry, user: Option<User>) -> Self {
+        let mut state = Self {
+            registry,
+            user,
+            query: String::new(),
+            search_results: Vec::new(),
+            results: Vec::new(),
+            selected: 0,
+            open: true,
+        };
+        state.refresh();
+        state
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn results(&self) -> &[PaletteCommand] {
+        &self.results
+    }
+
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Replaces the query and resets the selection
+    pub fn set_query(&mut self, query: impl Into<String>) {
+        self.query = query.into();
+        self.search_results.clear();
+        self.refresh();
+    }
+
+    /// Adds search results for the current query
+    pub fn set_search_results(&mut self, results: &[SearchResult]) {
+        self.search_results = results
+            .iter()
+            .filter_map(PaletteCommand::from_search_result)
+            .collect();
+        self.refresh();
+    }
+
+    /// Handles a key, returning the command to execute on `Enter`
+    pub fn handle_key(&mut self, key: PaletteKey) -> Option<PaletteCommand> {
+        match key {
+            PaletteKey::Down if !self.results.is_empty() => {
+                self.selected = (self.selected + 1) % self.results.len();
+            }
+            PaletteKey::Up if !self.results.is_empty() => {
+                self.selected = self
+                    .selected
+                    .checked_sub(1)
+                    .unwrap_or(self.results.len() - 1);
+            }
+            PaletteKey::Enter => {
+                let command = self.results.get(self.selected).cloned();
+                if command.is_some() {
+                    self.open = false;
+                }
+                return command;
+            }
+            PaletteKey::Escape => self.open = false,
+            _ => {}
+        }
+        None
+    }
+
+    fn refresh(&mut self) {
+        self.results = self
+            .registry
+            .matches(&self.query, self.user.as_ref(), &self.search_results);
+        self.selected = 0;
+    }
+}
+
+/// Whether a key event is the palette shortcut (`Cmd+K` or `Ctrl+K`)
+pub fn is_palette_shortcut(event: &KeyboardData) -> bool {
+    let modifiers = event.modifiers();
+    (modifiers.contains(Modifiers::META) || modifiers.contains(Modifiers::CONTROL))
+        && matches!(event.key(), Key::Character(ref c) if c.eq_ignore_ascii_case("k"))
+}
+
+fn palette_key(key: &Key) -> Option<PaletteKey> {
+    match key {
+        Key::ArrowUp => Some(PaletteKey::Up),
+        Key::ArrowDown => Some(PaletteKey::Down),
+        Key::Enter => Some(PaletteKey::Enter),
+        Key::Escape => Some(PaletteKey::Escape),
+        _ => None,
+    }
+}
+
+/// Modal command palette; `on_close` fires once a command runs or it is dismissed
+#[component]
+pub fn CommandPalette(on_close: EventHandler<()>) -> Element {
+    let registry = try_use_context::<CommandRegistry>().unwrap_or_else(CommandRegistry::shared);
+    let search = try_use_context::<Arc<SearchCoordinator>>();
+    let app_state = use_app_state();
+    let navigator = use_navigator();
+    let mut state = use_signal(|| {
+        registry.register_quick_actions(&app_state.current_layout.header.quick_actions);
+        PaletteState::new(registry.clone(), app_state.current_user.clone())
+    });
+    // Tracked separately so storing search results does not re-run the search
+    let mut query = use_signal(String::new);
+
+    let _search_task = use_resource(move || {
+        let search = search.clone();
+        let query = query();
+        let user_id = state.peek().user.as_ref().map(|u| u.id);
+        async move {
+            let Some(search) = search else {
+                return;
+            };
+            if query.trim().chars().count() < MIN_SEARCH_QUERY_LEN {
+                return;
+            }
+
+            let request = SearchQuery {
+                query: query.clone(),
+                limit: Some(MAX_PALETTE_RESULTS),
+                offset: None,
+                filters: Default::default(),
+                facets: Vec::new(),
+                include_suggestions: false,
+                context: SearchContext {
+                    user_id,
+                    permissions: Vec::new(),
+                    preferences: Default::default(),
+                    metadata: Default::default(),
+                },
+            };
+            if let Ok(response) = search.search(request).await {
+                // Ignore responses for a query the user has already changed
+                if state.peek().query() == query {
+                    state.write().set_search_results(&response.results);
+                }
+            }
+        }
+    });
+
+    let onkeydown = move |event: KeyboardEvent| {
+        let Some(key) = palette_key(&event.key()) else {
+            return;
+        };
+        event.prevent_default();
+
+        let command = state.write().handle_key(key);
+        if let Some(command) = command {
+            command.execute(|target| {
+                navigator.push(target);
+            });
+        }
+        if !state.peek().is_open() {
+            on_close.call(());
+        }
+    };
+
+    let snapshot = state.read().clone();
+
+    rsx! {
+        div {
+            class: "fixed inset-0 z-50 flex items-start justify-center bg-black bg-opacity-25 pt-24",
+            onclick: move |_| on_close.call(()),
+            div {
+                class: "w-full max-w-xl bg-white rounded-lg shadow-xl overflow-hidden",
+                onclick: move |event| event.stop_propagation(),
+                input {
+                    r#type: "text",
+                    autofocus: true,
+                    placeholder: "Type a command or search...",
+                    class: "w-full px-4 py-3 border-b border-gray-200 focus:outline-none sm:text-sm",
+                    value: "{snapshot.query()}",
+                    oninput: move |event| {
+                        state.write().set_query(event.value());
+                        query.set(event.value());
+                    },
+                    onkeydown,
+                }
+                if snapshot.results().is_empty() {
+                    p {
+                        class: "px-4 py-6 text-sm text-center text-gray-500",
+                        "No matching commands"
+                    }
+                }
+                ul {
+                    class: "max-h-80 overflow-y-auto py-2",
+                    for (index, command) in snapshot.results().iter().enumerate() {
+                        li {
+                            key: "{command.id}",
+                            class: if index == snapshot.selected() {
+                                "flex items-center px-4 py-2 bg-blue-50 text-blue-900 cursor-pointer"
+                            } else {
+                                "flex items-center px-4 py-2 text-gray-700 cursor-pointer"
+                            },
+                            onclick: {
+                                let command = command.clone();
+                                move |_| {
+                                    command.execute(|target| {
+                                        navigator.push(target);
+                                    });
+                                    on_close.call(());
+                                }
+                            },
+                            if let Some(icon) = &command.icon {
+                                span { class: "mr-3", "{icon}" }
+                            }
+                            div {
+                                class: "flex-1 min-w-0",
+                                p { class: "text-sm font-medium truncate", "{command.title}" }
+                                if let Some(subtitle) = &command.subtitle {
+                                    p { class: "text-xs text-gray-500 truncate", "{subtitle}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn invoke_command(id: &str, title: &str, calls: &Arc<AtomicUsize>) -> PaletteCommand {
+        let calls = Arc::clone(calls);
+        PaletteCommand::new(
+            id,
+            title,
+            CommandSource::Plugin,
+            CommandTarget::Invoke(Arc::new(move || {
+                calls.fetch_add(1, Ordering::SeqCst);
+            })),
+        )
+    }
+
+    #[test]
+    fn test_query_filters_and_enter_invokes() {
+        let registry = CommandRegistry::with_routes();
+        let export_calls = Arc::new(AtomicUsize::new(0));
+        let reindex_calls = Arc::new(AtomicUsize::new(0));
+        registry.register(invoke_command("export", "Export Products", &export_calls));
+        registry.register(invoke_command(
+            "reindex",
+            "Rebuild Search Index",
+            &reindex_calls,
+        ));
+
+        let mut state = PaletteState::new(registry, None);
+        // Admin requires a permission the anonymous user lacks
+        assert!(state.results().iter().all(|c| c.id != "route:/admin"));
+
+        state.set_query("exprod");
+        let titles: Vec<&str> = state.results().iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, ["Export Products"]);
+
+        let command = state.handle_key(PaletteKey::Enter).unwrap();
+        command.execute(|_| panic!("invoke commands do not navigate"));
+        assert_eq!(export_calls.load(Ordering::SeqCst), 1);
+        assert_eq!(reindex_calls.load(Ordering::SeqCst), 0);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn test_routes_and_plugin_menus_become_commands() {
+        let registry = CommandRegistry::with_routes();
+        registry.register_plugin_menu(
+            "catalog",
+            &[MenuItem {
+                id: "products".to_string(),
+                label: "Products".to_string(),
+                icon: None,
+                route: Some("/plugin/catalog/products".to_string()),
+                action: None,
+                required_permissions: Vec::new(),
+                order: 0,
+                children: Vec::new(),
+            }],
+        );
+
+        let results = registry.matches("products", None, &[]);
+        assert_eq!(results[0].id, "plugin:catalog:products");
+        assert!(matches!(
+            &results[0].target,
+            CommandTarget::Navigate(Route::PluginPage { plugin_id, page })
+                if plugin_id == "catalog" && page == "products"
+        ));
+
+        registry.remove_plugin_commands("catalog");
+        assert!(registry.matches("products", None, &[]).is_empty());
+
+        assert!(fuzzy_score("gts", "Go to Settings").is_some());
+        assert!(fuzzy_score("xyz", "Go to Settings").is_none());
+        assert!(fuzzy_score("set", "Go to Settings") > fuzzy_score("set", "Reset Theme"));
+    }
+}
@@ -0,0 +1,444 @@
+// src/ui/registry.rs - Overridable UI slots and plugin-supplied components
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use dioxus::prelude::*;
+
+use crate::auth::{Permission, PermissionHierarchy};
+use crate::error::{Error, Result};
+use crate::plugin::{ComponentRenderFn, ResolvedComponent, DEFAULT_PLUGIN_PAGE};
+
+/// Slot for the application header
+pub const SLOT_HEADER: &str = "core.header";
+/// Slot for the login form
+pub const SLOT_LOGIN: &str = "core.login";
+/// Slot for the sidebar
+pub const SLOT_SIDEBAR: &str = "core.sidebar";
+/// Slot for the footer
+pub const SLOT_FOOTER: &str = "core.footer";
+
+/// Permission resource prefix guarding slot overrides (`ui.slots.core.header`)
+pub const SLOT_PERMISSION_PREFIX: &str = "ui.slots";
+/// Permission action required to override a slot
+pub const SLOT_OVERRIDE_ACTION: &str = "override";
+
+/// Renders a replacement for a core component
+pub type SlotRenderer = Arc<dyn Fn() -> Element + Send + Sync>;
+
+/// A plugin-provided replacement for a slot
+#[derive(Clone)]
+pub struct ComponentOverride {
+    pub plugin_id: String,
+    pub component_id: String,
+    renderer: SlotRenderer,
+}
+
+impl std::fmt::Debug for ComponentOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ComponentOverride")
+            .field("plugin_id", &self.plugin_id)
+            .field("component_id", &self.component_id)
+            .finish()
+    }
+}
+
+impl PartialEq for ComponentOverride {
+    fn eq(&self, other: &Self) -> bool {
+        self.plugin_id == other.plugin_id
+            && self.component_id == other.component_id
+            && Arc::ptr_eq(&self.renderer, &other.renderer)
+    }
+}
+
+/// A plugin component rendered on plugin pages
+#[derive(Clone)]
+struct PluginComponent {
+    route: Option<String>,
+    props: serde_json::Value,
+    render: ComponentRenderFn,
+}
+
+type PluginComponentMap = HashMap<String, HashMap<String, PluginComponent>>;
+
+/// Registry of the UI components plugins supply.
+///
+/// Holds slot overrides keyed by slot ID, and render closures for plugin
+/// pages keyed by plugin and component ID. A render function is a synchronous
+/// `Fn(&serde_json::Value) -> Element` called inside its own Dioxus scope, so
+/// it may use hooks. Rendered components are looked up by ID on every render,
+/// so [`Self::remove_plugin`] drops the last reference to a plugin's closures.
+#[derive(Clone, Default)]
+pub struct UIComponentRegistry {
+    overrides: Arc<RwLock<HashMap<String, ComponentOverride>>>,
+    components: Arc<RwLock<PluginComponentMap>>,
+    hierarchy: Arc<RwLock<PermissionHierarchy>>,
+}
+
+impl std::fmt::Debug for UIComponentRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UIComponentRegistry")
+            .field("slots", &self.slots())
+            .field(
+                "plugins",
+                &self.read_components().keys().collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl PartialEq for UIComponentRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.overrides, &other.overrides)
+    }
+}
+
+impl UIComponentRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide registry used by the app shell
+    pub fn shared() -> Self {
+        static SHARED: OnceLock<UIComponentRegistry> = OnceLock::new();
+        SHARED.get_or_init(Self::new).clone()
+    }
+
+    /// The hierarchy slot-override grants are evaluated with
+    pub fn permission_hierarchy(&self) -> PermissionHierarchy {
+        self.hierarchy
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Use the configured permission hierarchy, shared by every clone
+    pub fn set_permission_hierarchy(&self, hierarchy: PermissionHierarchy) {
+        *self.hierarchy.write().unwrap_or_else(|e| e.into_inner()) = hierarchy;
+    }
+
+    /// Registers a plugin component in place of a slot's built-in component.
+    ///
+    /// `granted` are the permissions the host granted the plugin; one of them
+    /// must cover `override` on `ui.slots.<slot>`.
+    pub fn register_override(
+        &self,
+        plugin_id: &str,
+        granted: &[Permission],
+        slot: &str,
+        component_id: &str,
+        renderer: SlotRenderer,
+    ) -> Result<()> {
+        let resource = format!("{}.{}", SLOT_PERMISSION_PREFIX, slot);
+        let hierarchy = self.permission_hierarchy();
+        if !granted
+            .iter()
+            .any(|p| hierarchy.grants(p, &resource, SLOT_OVERRIDE_ACTION))
+        {
+            return Err(Error::permission(
+                &resource,
+                format!("Plugin {} may not override UI slot {}", plugin_id, slot),
+            ));
+        }
+
+        let mut overrides = self.overrides.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(existing) = overrides.get(slot) {
+            if existing.plugin_id != plugin_id {
+                tracing::warn!(
+                    "Plugin {} replaces override of UI slot {} from plugin {}",
+                    plugin_id,
+                    slot,
+                    existing.plugin_id
+                );
+            }
+        }
+
+        overrides.insert(
+            slot.to_string(),
+            ComponentOverride {
+                plugin_id: plugin_id.to_string(),
+                component_id: component_id.to_string(),
+                renderer,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Removes the override for a slot, restoring the built-in component
+    pub fn remove_override(&self, slot: &str) -> Option<ComponentOverride> {
+        self.overrides
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(slot)
+    }
+
+    /// Removes every override registered by a plugin
+    pub fn remove_plugin_overrides(&self, plugin_id: &str) {
+        self.overrides
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|_, o| o.plugin_id != plugin_id);
+    }
+
+    /// Gets the override registered for a slot
+    pub fn get_override(&self, slot: &str) -> Option<ComponentOverride> {
+        self.overrides
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(slot)
+            .cloned()
+    }
+
+    /// Lists slots that currently have an override
+    pub fn slots(&self) -> Vec<String> {
+        self.overrides
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Registers a plugin component's render closure, replacing any previous one
+    pub fn register_component(
+        &self,
+        plugin_id: &str,
+        component_id: &str,
+        route: Option<String>,
+        props: serde_json::Value,
+        render: ComponentRenderFn,
+    ) {
+        self.write_components()
+            .entry(plugin_id.to_string())
+            .or_default()
+            .insert(
+                component_id.to_string(),
+                PluginComponent {
+                    route,
+                    props,
+                    render,
+                },
+            );
+    }
+
+    /// Removes one plugin component
+    pub fn unregister_component(&self, plugin_id: &str, component_id: &str) {
+        if let Some(components) = self.write_components().get_mut(plugin_id) {
+            components.remove(component_id);
+        }
+    }
+
+    /// Looks up a plugin component by ID
+    pub fn resolve(&self, plugin_id: &str, component_id: &str) -> Option<ResolvedComponent> {
+        let components = self.read_components();
+        let component = components.get(plugin_id)?.get(component_id)?;
+        Some(ResolvedComponent {
+            plugin_id: plugin_id.to_string(),
+            component_id: component_id.to_string(),
+            props: component.props.clone(),
+        })
+    }
+
+    /// Resolves `/plugin/<plugin_id>[/<page>]` to a component.
+    ///
+    /// A component whose declared route equals the path wins; otherwise the
+    /// page name is used as the component ID, defaulting to [`DEFAULT_PLUGIN_PAGE`].
+    pub fn resolve_route(&self, plugin_id: &str, page: Option<&str>) -> Option<ResolvedComponent> {
+        let path = match page {
+            Some(page) => format!("/plugin/{}/{}", plugin_id, page),
+            None => format!("/plugin/{}", plugin_id),
+        };
+
+        let routed = self
+            .read_components()
+            .get(plugin_id)
+            .and_then(|components| {
+                components
+                    .iter()
+                    .find(|(_, c)| c.route.as_deref() == Some(path.as_str()))
+                    .map(|(id, _)| id.clone())
+            });
+
+        match routed {
+            Some(component_id) => self.resolve(plugin_id, &component_id),
+            None => self.resolve(plugin_id, page.unwrap_or(DEFAULT_PLUGIN_PAGE)),
+        }
+    }
+
+    /// Renders a resolved component with `props`, or nothing once it is removed
+    pub fn render_component(
+        &self,
+        component: &ResolvedComponent,
+        props: &serde_json::Value,
+    ) -> Element {
+        let render = self
+            .read_components()
+            .get(&component.plugin_id)
+            .and_then(|components| components.get(&component.component_id))
+            .map(|registered| Arc::clone(&registered.render));
+        match render {
+            Some(render) => render(props),
+            None => VNode::empty(),
+        }
+    }
+
+    /// IDs of the components registered by a plugin, sorted
+    pub fn component_ids(&self, plugin_id: &str) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .read_components()
+            .get(plugin_id)
+            .map(|components| components.keys().cloned().collect())
+            .unwrap_or_default();
+        ids.sort();
+        ids
+    }
+
+    /// Drops every override and component registered by a plugin, returning
+    /// how many components were removed
+    pub fn remove_plugin(&self, plugin_id: &str) -> usize {
+        self.remove_plugin_overrides(plugin_id);
+        self.write_components()
+            .remove(plugin_id)
+            .map_or(0, |components| components.len())
+    }
+
+    fn read_components(&self) -> std::sync::RwLockReadGuard<'_, PluginComponentMap> {
+        self.components.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_components(&self) -> std::sync::RwLockWriteGuard<'_, PluginComponentMap> {
+        self.components.write().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Renders the registered override for `id`, or `children` if there is none
+#[component]
+pub fn Slot(id: String, children: Element) -> Element {
+    let registry = try_use_context::<UIComponentRegistry>();
+
+    match registry.and_then(|r| r.get_override(&id)) {
+        Some(component) => {
+            let key = format!("{}:{}", component.plugin_id, component.component_id);
+            rsx! {
+                SlotOverride { key: "{key}", slot: id }
+            }
+        }
+        None => children,
+    }
+}
+
+/// Hosts an override in its own scope so its hooks never mix with the slot's.
+///
+/// The renderer is looked up on each render rather than held in props, so a
+/// removed override does not keep its plugin's code alive.
+#[component]
+fn SlotOverride(slot: String) -> Element {
+    let registry = try_use_context::<UIComponentRegistry>();
+    match registry.and_then(|r| r.get_override(&slot)) {
+        Some(component) => (component.renderer)(),
+        None => VNode::empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::PermissionScope;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[derive(Clone)]
+    struct Probe(Arc<AtomicUsize>);
+
+    impl PartialEq for Probe {
+        fn eq(&self, other: &Self) -> bool {
+            Arc::ptr_eq(&self.0, &other.0)
+        }
+    }
+
+    #[component]
+    fn DefaultHeader(probe: Probe) -> Element {
+        probe.0.fetch_add(1, Ordering::SeqCst);
+        rsx! { header { "default" } }
+    }
+
+    #[component]
+    fn Shell(registry: UIComponentRegistry, default_probe: Probe) -> Element {
+        use_context_provider(|| registry.clone());
+        rsx! {
+            Slot { id: SLOT_HEADER.to_string(),
+                DefaultHeader { probe: default_probe }
+            }
+        }
+    }
+
+    fn slot_grant() -> Permission {
+        Permission {
+            resource: SLOT_PERMISSION_PREFIX.to_string(),
+            action: SLOT_OVERRIDE_ACTION.to_string(),
+            scope: PermissionScope::Global,
+        }
+    }
+
+    fn render(registry: UIComponentRegistry) -> usize {
+        let default_probe = Probe(Arc::new(AtomicUsize::new(0)));
+        let mut dom = VirtualDom::new_with_props(
+            Shell,
+            ShellProps {
+                registry,
+                default_probe: default_probe.clone(),
+            },
+        );
+        dom.rebuild_in_place();
+        default_probe.0.load(Ordering::SeqCst)
+    }
+
+    #[test]
+    fn test_override_renders_instead_of_default() {
+        let registry = UIComponentRegistry::new();
+        assert_eq!(render(registry.clone()), 1);
+
+        let override_renders = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&override_renders);
+        registry
+            .register_override(
+                "branding",
+                &[slot_grant()],
+                SLOT_HEADER,
+                "custom_header",
+                Arc::new(move || {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    rsx! { header { "custom" } }
+                }),
+            )
+            .unwrap();
+
+        assert_eq!(render(registry.clone()), 0);
+        assert_eq!(override_renders.load(Ordering::SeqCst), 1);
+
+        registry.remove_plugin_overrides("branding");
+        assert_eq!(render(registry), 1);
+    }
+
+    #[test]
+    fn test_override_requires_permission() {
+        let registry = UIComponentRegistry::new();
+        let renderer: SlotRenderer = Arc::new(|| rsx! { div {} });
+
+        let other = Permission {
+            resource: "ui.slots.core.footer".to_string(),
+            action: SLOT_OVERRIDE_ACTION.to_string(),
+            scope: PermissionScope::Global,
+        };
+        assert!(registry
+            .register_override("p", &[other], SLOT_HEADER, "c", renderer.clone())
+            .is_err());
+        assert!(registry.get_override(SLOT_HEADER).is_none());
+
+        assert!(registry
+            .register_override("p", &[slot_grant()], SLOT_HEADER, "c", renderer)
+            .is_ok());
+        assert_eq!(registry.slots(), [SLOT_HEADER]);
+    }
+}
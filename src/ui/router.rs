@@ -10,6 +10,7 @@ use crate::ui::{
         Dashboard as DashboardPage, Login as LoginPage, NotFound as NotFoundPage,
         Plugins as PluginsPage, Profile as ProfilePage, Settings as SettingPage,
     },
+    registry::{Slot, SLOT_LOGIN},
     state::use_app_state,
 };
 
@@ -59,7 +60,9 @@ pub fn Login() -> Element {
     rsx! {
         div {
             class: "min-h-screen flex items-center justify-center bg-gray-50 py-12 px-4 sm:px-6 lg:px-8",
-            LoginPage {}
+            Slot { id: SLOT_LOGIN.to_string(),
+                LoginPage {}
+            }
         }
     }
 }
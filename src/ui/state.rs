@@ -6,7 +6,10 @@ pub(crate) use crate::auth::{User, UserSession};
 use crate::ui::{Notification, Theme, UILayout};
 use crate::utils::Time;
 
-#[derive(Debug, Clone, Default)]
+/// Notifications kept before the oldest are dropped
+pub const DEFAULT_MAX_NOTIFICATIONS: usize = 50;
+
+#[derive(Debug, Clone)]
 pub struct AppStateContext {
     pub current_user: Option<User>,
     pub current_session: Option<UserSession>,
@@ -15,10 +18,63 @@ pub struct AppStateContext {
     pub is_loading: bool,
     pub error_message: Option<String>,
     pub notifications: Vec<Notification>,
+    /// Queue length enforced by [`AppStateContext::push_notification`]
+    pub max_notifications: usize,
     pub sidebar_collapsed: bool,
     pub mobile_menu_open: bool,
 }
 
+impl Default for AppStateContext {
+    fn default() -> Self {
+        Self {
+            current_user: None,
+            current_session: None,
+            current_layout: UILayout::default(),
+            current_theme: Theme::default(),
+            is_loading: false,
+            error_message: None,
+            notifications: Vec::new(),
+            max_notifications: DEFAULT_MAX_NOTIFICATIONS,
+            sidebar_collapsed: false,
+            mobile_menu_open: false,
+        }
+    }
+}
+
+impl AppStateContext {
+    /// Queues a notification, dropping the oldest ones beyond `max_notifications`
+    pub fn push_notification(&mut self, notification: Notification) {
+        self.notifications.push(notification);
+        self.trim_notifications();
+    }
+
+    /// Removes a notification, returning whether it was present
+    pub fn dismiss_notification(&mut self, id: uuid::Uuid) -> bool {
+        let before = self.notifications.len();
+        self.notifications.retain(|n| n.id != id);
+        self.notifications.len() != before
+    }
+
+    pub fn mark_read(&mut self, id: uuid::Uuid) {
+        if let Some(notification) = self.notifications.iter_mut().find(|n| n.id == id) {
+            notification.read = true;
+        }
+    }
+
+    /// Number of unread notifications, for the header badge
+    pub fn unread_count(&self) -> usize {
+        self.notifications.iter().filter(|n| !n.read).count()
+    }
+
+    fn trim_notifications(&mut self) {
+        let excess = self
+            .notifications
+            .len()
+            .saturating_sub(self.max_notifications);
+        self.notifications.drain(..excess);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum AppAction {
     SetUser(Option<User>),
@@ -31,6 +87,7 @@ pub enum AppAction {
     RemoveNotification(uuid::Uuid),
     MarkNotificationRead(uuid::Uuid),
     ClearNotifications,
+    SetMaxNotifications(usize),
     ToggleSidebar,
     SetSidebarCollapsed(bool),
     ToggleMobileMenu,
@@ -60,19 +117,21 @@ pub fn app_state_reducer(state: &AppStateContext, action: AppAction) -> AppState
             new_state.error_message = error;
         }
         AppAction::AddNotification(notification) => {
-            new_state.notifications.push(notification);
+            new_state.push_notification(notification);
         }
         AppAction::RemoveNotification(id) => {
-            new_state.notifications.retain(|n| n.id != id);
+            new_state.dismiss_notification(id);
         }
         AppAction::MarkNotificationRead(id) => {
-            if let Some(notification) = new_state.notifications.iter_mut().find(|n| n.id == id) {
-                notification.read = true;
-            }
+            new_state.mark_read(id);
         }
         AppAction::ClearNotifications => {
             new_state.notifications.clear();
         }
+        AppAction::SetMaxNotifications(max) => {
+            new_state.max_notifications = max;
+            new_state.trim_notifications();
+        }
         AppAction::ToggleSidebar => {
             new_state.sidebar_collapsed = !new_state.sidebar_collapsed;
         }
@@ -95,8 +154,23 @@ pub fn AppStateProvider(children: Element) -> Element {
     // Use a single signal for the entire state
     let mut app_state = use_signal(AppStateContext::default);
 
-    // Create dispatch function that updates the state
+    // Create dispatch function that updates the state. Every action is applied
+    // inside a single `with_mut`, so pushes from concurrent event handlers
+    // cannot interleave and lose notifications.
     let dispatch = use_callback(move |action: AppAction| {
+        if let AppAction::AddNotification(notification) = &action {
+            if let Some(delay) = notification.auto_dismiss_after {
+                let id = notification.id;
+                let mut app_state = app_state;
+                spawn(async move {
+                    sleep(delay).await;
+                    app_state.with_mut(|state| {
+                        state.dismiss_notification(id);
+                    });
+                });
+            }
+        }
+
         app_state.with_mut(|state| {
             *state = app_state_reducer(state, action);
         });
@@ -115,23 +189,25 @@ pub fn AppStateProvider(children: Element) -> Element {
             let two_hours_ago = now - Time::duration_hours(2);
 
             dispatch(AppAction::AddNotification(Notification {
-                id: uuid::Uuid::new_v4(),
+                id: crate::utils::rand::uuid(),
                 title: "Welcome to Qorzen!".to_string(),
                 message: "Your application is ready to use.".to_string(),
                 notification_type: crate::ui::NotificationType::Info,
                 timestamp: now,
                 read: false,
                 actions: vec![],
+                auto_dismiss_after: None,
             }));
 
             dispatch(AppAction::AddNotification(Notification {
-                id: uuid::Uuid::new_v4(),
+                id: crate::utils::rand::uuid(),
                 title: "System Update".to_string(),
                 message: "A new version is available for download.".to_string(),
                 notification_type: crate::ui::NotificationType::System,
                 timestamp: two_hours_ago,
                 read: false,
                 actions: vec![],
+                auto_dismiss_after: None,
             }));
         });
     });
@@ -141,6 +217,13 @@ pub fn AppStateProvider(children: Element) -> Element {
     }
 }
 
+pub(crate) async fn sleep(duration: std::time::Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+}
+
 /// Hook to get the current app state (read-only)
 pub fn use_app_state() -> AppStateContext {
     let state_signal = use_context::<Signal<AppStateContext>>();
@@ -186,7 +269,7 @@ pub mod auth {
                     let eight_hours_from_now = now + Time::duration_hours(8);
 
                     let mock_user = User {
-                        id: uuid::Uuid::new_v4(),
+                        id: crate::utils::rand::uuid(),
                         username: "demo_user".to_string(),
                         email: "demo@qorzen.com".to_string(),
                         roles: vec![],
@@ -210,7 +293,7 @@ pub mod auth {
                     };
 
                     let mock_session = UserSession {
-                        id: uuid::Uuid::new_v4(),
+                        id: crate::utils::rand::uuid(),
                         user_id: mock_user.id,
                         created_at: now,
                         expires_at: eight_hours_from_now,
@@ -224,13 +307,14 @@ pub mod auth {
                     dispatch(AppAction::SetSession(Some(mock_session)));
                     dispatch(AppAction::SetLoading(false));
                     dispatch(AppAction::AddNotification(Notification {
-                        id: uuid::Uuid::new_v4(),
+                        id: crate::utils::rand::uuid(),
                         title: "Login Successful".to_string(),
                         message: "Welcome back! You have been successfully logged in.".to_string(),
                         notification_type: crate::ui::NotificationType::Success,
                         timestamp: Time::now(),
                         read: false,
                         actions: vec![],
+                        auto_dismiss_after: Some(std::time::Duration::from_secs(5)),
                     }));
                 }
             });
@@ -391,4 +475,46 @@ mod tests {
             app_state_reducer(&initial_state, AppAction::SetError(Some(error_msg.clone())));
         assert_eq!(new_state.error_message, Some(error_msg));
     }
+
+    fn notification(title: &str) -> Notification {
+        Notification {
+            id: uuid::Uuid::new_v4(),
+            title: title.to_string(),
+            message: String::new(),
+            notification_type: crate::ui::NotificationType::Info,
+            timestamp: Time::now(),
+            read: false,
+            actions: vec![],
+            auto_dismiss_after: None,
+        }
+    }
+
+    #[test]
+    fn test_notification_queue_drops_oldest() {
+        let mut state = AppStateContext {
+            max_notifications: 3,
+            ..AppStateContext::default()
+        };
+        for i in 0..5 {
+            state.push_notification(notification(&format!("n{}", i)));
+        }
+
+        let titles: Vec<_> = state
+            .notifications
+            .iter()
+            .map(|n| n.title.as_str())
+            .collect();
+        assert_eq!(titles, ["n2", "n3", "n4"]);
+        assert_eq!(state.unread_count(), 3);
+
+        let first = state.notifications[0].id;
+        state.mark_read(first);
+        assert_eq!(state.unread_count(), 2);
+        assert!(state.dismiss_notification(first));
+        assert!(!state.dismiss_notification(first));
+
+        let state = app_state_reducer(&state, AppAction::SetMaxNotifications(1));
+        assert_eq!(state.notifications.len(), 1);
+        assert_eq!(state.notifications[0].title, "n4");
+    }
 }
@@ -0,0 +1,192 @@
+// src/utils/json.rs - Depth and size guards for untrusted JSON
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{Error, ErrorKind, Result};
+
+/// Structural limits applied to JSON before it is merged or traversed recursively
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonLimits {
+    /// Maximum nesting depth of objects and arrays
+    pub max_depth: usize,
+    /// Maximum total number of values in the document
+    pub max_nodes: usize,
+}
+
+impl Default for JsonLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 64,
+            max_nodes: 100_000,
+        }
+    }
+}
+
+impl JsonLimits {
+    /// Create limits with the given depth and node count
+    pub fn new(max_depth: usize, max_nodes: usize) -> Self {
+        Self {
+            max_depth,
+            max_nodes,
+        }
+    }
+
+    /// Check a parsed value without recursing
+    pub fn check(&self, value: &Value) -> Result<()> {
+        let mut stack = vec![(value, 1usize)];
+        let mut nodes = 0usize;
+
+        while let Some((current, depth)) = stack.pop() {
+            nodes += 1;
+            if nodes > self.max_nodes {
+                return Err(self.node_error());
+            }
+            if depth > self.max_depth {
+                return Err(self.depth_error());
+            }
+
+            match current {
+                Value::Object(map) => stack.extend(map.values().map(|v| (v, depth + 1))),
+                Value::Array(items) => stack.extend(items.iter().map(|v| (v, depth + 1))),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the bracket nesting of raw text before handing it to a recursive parser.
+    ///
+    /// Works for JSON as well as TOML inline tables and YAML flow collections.
+    /// Quotes only open a string where a scalar can start, so apostrophes in
+    /// plain YAML scalars such as `it's` do not hide the brackets after them;
+    /// `#` comments are skipped the same way.
+    pub fn check_text(&self, input: &str) -> Result<()> {
+        let mut depth = 0usize;
+        let mut in_string: Option<char> = None;
+        let mut in_comment = false;
+        let mut escaped = false;
+        let mut prev: Option<char> = None;
+        let mut chars = input.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if in_comment {
+                in_comment = c != '\n';
+            } else if let Some(quote) = in_string {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && quote == '"' {
+                    escaped = true;
+                } else if c == quote {
+                    // A doubled quote is an escaped quote inside a YAML single-quoted string
+                    if quote == '\'' && chars.peek() == Some(&'\'') {
+                        chars.next();
+                    } else {
+                        in_string = None;
+                    }
+                }
+            } else {
+                let after_space = prev.is_none() || prev.is_some_and(char::is_whitespace);
+                let at_token_start = after_space || prev.is_some_and(|p| "[{,:=".contains(p));
+                match c {
+                    '"' | '\'' if at_token_start => in_string = Some(c),
+                    '#' if after_space => in_comment = true,
+                    '{' | '[' => {
+                        depth += 1;
+                        if depth > self.max_depth {
+                            return Err(self.depth_error());
+                        }
+                    }
+                    '}' | ']' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+            prev = Some(c);
+        }
+
+        Ok(())
+    }
+
+    /// Parse JSON text, rejecting documents that exceed the limits
+    pub fn parse(&self, input: &str) -> Result<Value> {
+        self.check_text(input)?;
+
+        let value: Value = serde_json::from_str(input)
+            .map_err(|e| Error::new(ErrorKind::Serialization, format!("Invalid JSON: {}", e)))?;
+
+        self.check(&value)?;
+        Ok(value)
+    }
+
+    fn depth_error(&self) -> Error {
+        Error::new(
+            ErrorKind::Validation {
+                field: None,
+                rules: vec![format!("max_depth={}", self.max_depth)],
+            },
+            format!(
+                "JSON document exceeds the maximum nesting depth of {}",
+                self.max_depth
+            ),
+        )
+    }
+
+    fn node_error(&self) -> Error {
+        Error::new(
+            ErrorKind::Validation {
+                field: None,
+                rules: vec![format!("max_nodes={}", self.max_nodes)],
+            },
+            format!(
+                "JSON document exceeds the maximum of {} values",
+                self.max_nodes
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deeply_nested(depth: usize) -> String {
+        format!("{}1{}", "{\"a\":".repeat(depth), "}".repeat(depth))
+    }
+
+    #[test]
+    fn test_rejects_deep_nesting_without_overflow() {
+        let limits = JsonLimits::default();
+        let err = limits.parse(&deeply_nested(10_000)).unwrap_err();
+        assert!(matches!(err.kind, ErrorKind::Validation { .. }));
+    }
+
+    #[test]
+    fn test_accepts_document_within_limits() {
+        let limits = JsonLimits::default();
+        let value = limits.parse(&deeply_nested(10)).unwrap();
+        assert!(value.is_object());
+    }
+
+    #[test]
+    fn test_rejects_too_many_nodes() {
+        let limits = JsonLimits::new(8, 5);
+        let value = serde_json::json!([1, 2, 3, 4, 5, 6]);
+        assert!(limits.check(&value).is_err());
+    }
+
+    #[test]
+    fn test_brackets_inside_strings_are_ignored() {
+        let limits = JsonLimits::new(2, 100);
+        assert!(limits.check_text(r#"{"a": "[[[[{{{{"}"#).is_ok());
+    }
+
+    #[test]
+    fn test_apostrophes_in_plain_scalars_do_not_hide_nesting() {
+        let limits = JsonLimits::new(2, 100);
+        assert!(limits.check_text("note: it's [[[1]]]").is_err());
+        assert!(limits.check_text("# it's a comment\nkey: [[[1]]]").is_err());
+        assert!(limits.check_text("key: 'it''s [[[1]]]'").is_ok());
+        assert!(limits.check_text("key: [a, 'b [[[c']").is_ok());
+    }
+}
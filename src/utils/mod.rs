@@ -1,5 +1,8 @@
 // src/utils/mod.rs - Utility modules
 
+pub mod json;
+pub mod rand;
 pub mod time;
 
+pub use json::JsonLimits;
 pub use time::Time;
@@ -0,0 +1,75 @@
+// src/utils/rand.rs - Cryptographically secure tokens and ids
+
+//! All randomness here comes from the operating system CSPRNG through
+//! `getrandom`, which uses `crypto.getRandomValues` on the web. Use these
+//! helpers for anything an attacker must not guess: session ids, reset and
+//! CSRF tokens, idempotency keys.
+
+use uuid::Uuid;
+
+/// Characters a [`secure_token`] is drawn from; safe in URLs and headers
+const TOKEN_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// Fill `buf` from the OS random source.
+///
+/// # Panics
+///
+/// If the platform has no random source, as handing out predictable
+/// tokens would be worse than failing.
+pub fn fill_bytes(buf: &mut [u8]) {
+    getrandom::getrandom(buf).expect("operating system random source is unavailable");
+}
+
+/// Random alphanumeric token of exactly `len` characters (about 5.95 bits each)
+pub fn secure_token(len: usize) -> String {
+    // Largest multiple of the alphabet size that fits in a byte, so every
+    // character is equally likely
+    let limit = (256 / TOKEN_ALPHABET.len() * TOKEN_ALPHABET.len()) as u8;
+    let mut token = String::with_capacity(len);
+    let mut buf = [0u8; 64];
+
+    while token.len() < len {
+        fill_bytes(&mut buf);
+        token.extend(
+            buf.iter()
+                .filter(|&&b| b < limit)
+                .map(|&b| TOKEN_ALPHABET[b as usize % TOKEN_ALPHABET.len()] as char)
+                .take(len - token.len()),
+        );
+    }
+
+    token
+}
+
+/// Random (version 4) UUID
+pub fn uuid() -> Uuid {
+    let mut bytes = [0u8; 16];
+    fill_bytes(&mut bytes);
+    uuid::Builder::from_random_bytes(bytes).into_uuid()
+}
+
+/// New id tying together the logs and events of one request or operation
+pub fn correlation_id() -> Uuid {
+    uuid()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secure_tokens_differ_and_have_requested_length() {
+        let first = secure_token(32);
+        let second = secure_token(32);
+
+        assert_eq!(first.len(), 32);
+        assert_eq!(second.len(), 32);
+        assert_ne!(first, second);
+        assert!(first.bytes().all(|b| TOKEN_ALPHABET.contains(&b)));
+        assert_eq!(secure_token(0), "");
+        assert_eq!(secure_token(200).len(), 200);
+
+        assert_eq!(uuid().get_version_num(), 4);
+        assert_ne!(uuid(), uuid());
+    }
+}
@@ -42,6 +42,36 @@ impl Time {
         }
     }
 
+    /// Milliseconds on a monotonic clock, for measuring elapsed time.
+    ///
+    /// Only differences between two readings are meaningful; unlike
+    /// [`now_millis`](Self::now_millis) the clock never jumps with the system
+    /// time. On WASM this is `performance.now()`.
+    pub fn monotonic_millis() -> f64 {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            use std::sync::OnceLock;
+            use std::time::Instant;
+
+            static ORIGIN: OnceLock<Instant> = OnceLock::new();
+            ORIGIN.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            web_sys::window()
+                .and_then(|window| window.performance())
+                .map_or_else(js_sys::Date::now, |performance| performance.now())
+        }
+    }
+
+    /// Time passed since a [`monotonic_millis`](Self::monotonic_millis) reading
+    pub fn elapsed_since(start_millis: f64) -> std::time::Duration {
+        std::time::Duration::from_secs_f64(
+            (Self::monotonic_millis() - start_millis).max(0.0) / 1000.0,
+        )
+    }
+
     /// Create a DateTime from milliseconds since epoch
     pub fn from_millis(millis: i64) -> DateTime<Utc> {
         DateTime::from_timestamp_millis(millis).unwrap_or_else(|| {
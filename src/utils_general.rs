@@ -326,19 +326,9 @@ pub mod strings {
             .collect()
     }
 
+    /// Random alphanumeric string from the OS CSPRNG, see [`crate::utils::rand::secure_token`]
     pub fn random_string(length: usize) -> String {
-        use rand::Rng;
-        const CHARSET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ\
-                               abcdefghijklmnopqrstuvwxyz\
-                               0123456789";
-
-        let mut rng = rand::thread_rng();
-        (0..length)
-            .map(|_| {
-                let idx = rng.gen_range(0..CHARSET.len());
-                CHARSET[idx] as char
-            })
-            .collect()
+        crate::utils::rand::secure_token(length)
     }
 }
 